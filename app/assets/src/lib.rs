@@ -14,7 +14,8 @@ pub struct GameAssetsPlugin;
 
 impl Plugin for GameAssetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, sprites::load_fruit_sprites);
+        app.add_systems(Startup, sprites::load_fruit_sprites)
+            .add_systems(Update, sprites::detect_fruit_sheets);
         info!("GameAssetsPlugin initialized");
     }
 }
@@ -8,18 +8,45 @@
 //!
 //! 1. Place the image at `assets/images/fruits/<name>.png`.
 //! 2. Add a `fruit_sprites.insert(FruitType::Name, asset_server.load("..."))` line below.
+//!
+//! # Mods
+//!
+//! An enabled user mod (see [`suika_game_core::mods`]) may ship
+//! `images/fruits/<name>.png` for any fruit, bundled artwork or not; its
+//! image replaces the bundled one or the placeholder.
+//!
+//! # Sprite sheets
+//!
+//! An image exactly [`FruitFrame::COUNT`] times as wide as it is tall is a
+//! sheet of square frames, left to right: idle, squish (landing) and merge
+//! pop.  [`detect_fruit_sheets`] cuts it into a [`TextureAtlasLayout`] once
+//! it has loaded (and again on hot-reload); any other image is a single
+//! frame.
+//!
+//! ```text
+//! +-------+-------+-------+
+//! | idle  | squish|  pop  |   cherry.png, 3h × h
+//! +-------+-------+-------+
+//! ```
+
+use std::iter;
 
 use bevy::prelude::*;
 use suika_game_core::fruit::FruitType;
-use suika_game_core::resources::FruitSprites;
+use suika_game_core::mods::ModList;
+use suika_game_core::resources::{FruitFrame, FruitSprites};
 
 /// Loads available fruit sprites into the [`FruitSprites`] resource.
 ///
 /// Registered on `Startup` by [`crate::GameAssetsPlugin`].
 ///
 /// Currently only `cherry.png` exists; more sprites will be added as artwork
-/// is created.
-pub fn load_fruit_sprites(asset_server: Res<AssetServer>, mut fruit_sprites: ResMut<FruitSprites>) {
+/// is created.  Sprites from enabled mods are queued on top.
+pub fn load_fruit_sprites(
+    asset_server: Res<AssetServer>,
+    mods: Option<Res<ModList>>,
+    mut fruit_sprites: ResMut<FruitSprites>,
+) {
     // Cherry — experimental first sprite to validate the pipeline.
     fruit_sprites.insert(
         FruitType::Cherry,
@@ -27,4 +54,94 @@ pub fn load_fruit_sprites(asset_server: Res<AssetServer>, mut fruit_sprites: Res
     );
 
     info!("Fruit sprites queued for loading: cherry");
+
+    let Some(mods) = mods else {
+        return;
+    };
+    for fruit in iter::successors(Some(FruitType::Cherry), FruitType::next) {
+        if let Some(path) = mods.override_path(&sprite_path(fruit)) {
+            info!("Fruit sprite from mod: {path}");
+            fruit_sprites.insert(fruit, asset_server.load(path));
+        }
+    }
+}
+
+/// Registers a frame layout for each fruit sprite that turns out to be a
+/// sheet of [`FruitFrame`]s.
+///
+/// Registered on `Update` by [`crate::GameAssetsPlugin`].
+pub fn detect_fruit_sheets(
+    mut events: MessageReader<AssetEvent<Image>>,
+    images: Res<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut fruit_sprites: ResMut<FruitSprites>,
+) {
+    for event in events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = *event
+        else {
+            continue;
+        };
+        let Some(frame_size) = images
+            .get(id)
+            .and_then(|image| sheet_frame_size(image.size()))
+        else {
+            continue;
+        };
+        for fruit in iter::successors(Some(FruitType::Cherry), FruitType::next) {
+            if fruit_sprites
+                .get(fruit)
+                .is_some_and(|handle| handle.id() == id)
+            {
+                let layout = TextureAtlasLayout::from_grid(
+                    frame_size,
+                    FruitFrame::COUNT as u32,
+                    1,
+                    None,
+                    None,
+                );
+                fruit_sprites.insert_layout(fruit, layouts.add(layout));
+                info!("Fruit sprite sheet detected: {fruit:?}");
+            }
+        }
+    }
+}
+
+/// Bundled asset path of `fruit`'s sprite, e.g. `images/fruits/cherry.png`.
+fn sprite_path(fruit: FruitType) -> String {
+    format!("images/fruits/{}.png", format!("{fruit:?}").to_lowercase())
+}
+
+/// Size of one frame when an image of `size` is a sheet of
+/// [`FruitFrame::COUNT`] square frames side by side.
+fn sheet_frame_size(size: UVec2) -> Option<UVec2> {
+    (size.y > 0 && size.x == size.y * FruitFrame::COUNT as u32).then(|| UVec2::splat(size.y))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sprite_path() {
+        assert_eq!(sprite_path(FruitType::Cherry), "images/fruits/cherry.png");
+        assert_eq!(
+            sprite_path(FruitType::Watermelon),
+            "images/fruits/watermelon.png"
+        );
+    }
+
+    #[test]
+    fn test_sheet_frame_size() {
+        assert_eq!(
+            sheet_frame_size(UVec2::new(192, 64)),
+            Some(UVec2::splat(64))
+        );
+        assert_eq!(sheet_frame_size(UVec2::new(64, 64)), None);
+        assert_eq!(sheet_frame_size(UVec2::new(128, 64)), None);
+        assert_eq!(sheet_frame_size(UVec2::ZERO), None);
+    }
 }
@@ -1,31 +1,45 @@
 //! BGM (background-music) management system.
 //!
-//! Listens for [`AppState`] transitions and switches BGM tracks accordingly,
-//! with fade-in on the incoming track and fade-out on the outgoing one.
+//! Listens for [`AppState`] transitions and switches BGM tracks accordingly.
+//! Every start, stop and switch goes through the track's
+//! [`VolumeEnvelope`](crate::envelope::VolumeEnvelope)
+//! from `bgm.ron`: the outgoing track fades out over its release time, the
+//! incoming one fades in over its attack time.
 //!
 //! # Track mapping
 //!
-//! | [`AppState`]          | [`BgmTrack`] | Loop | Fade-in |
-//! |-----------------------|-------------|------|---------|
-//! | [`Loading`]           | `None`      | —    | —       |
-//! | [`Title`]             | `Title`     | ✓    | 1.0 s   |
-//! | [`Playing`] / [`Paused`] | `Game`  | ✓    | 1.5 s   |
-//! | [`GameOver`]          | `GameOver`  | ✗    | —       |
+//! | [`AppState`]          | [`BgmTrack`] | Loop | Envelope            |
+//! |-----------------------|-------------|------|---------------------|
+//! | [`Loading`]           | `None`      | —    | —                   |
+//! | [`Title`]             | `Title`     | ✓    | `title_envelope`    |
+//! | [`Playing`] / [`Paused`] | `Game`  | ✓    | `game_envelope`     |
+//! | [`Settings`] from [`Paused`] | `Game` | ✓ | `game_envelope`     |
+//! | [`GameOver`]          | `GameOver`  | ✗    | `gameover_envelope` |
+//!
+//! # Gameplay playlist
+//!
+//! The `Game` track is driven by [`BgmPlaylist`].  With the default config it
+//! loops the built-in game track; when `bgm.ron` lists several
+//! `game_tracks` they play in (optionally shuffled) turn, and
+//! `intensity_tracks` swap in a more intense track once the score passes
+//! each threshold.
 //!
 //! [`Loading`]: AppState::Loading
 //! [`Title`]: AppState::Title
 //! [`Playing`]: AppState::Playing
 //! [`Paused`]: AppState::Paused
+//! [`Settings`]: AppState::Settings
 //! [`GameOver`]: AppState::GameOver
 
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
-use std::time::Duration;
-use suika_game_core::prelude::AppState;
+use rand::RngExt;
+use suika_game_core::prelude::{AppState, GameState, ReturnTo};
 use suika_game_core::resources::settings::SettingsResource;
 
 use crate::channels::{BgmChannel, volume_to_db};
-use crate::config::{AudioConfig, AudioConfigHandle};
+use crate::config::{BgmConfig, BgmParams, IntensityTrack};
+use crate::envelope::EnvelopeExt;
 use crate::handles::BgmHandles;
 
 // ---------------------------------------------------------------------------
@@ -55,6 +69,35 @@ pub enum BgmTrack {
 pub struct CurrentBgm {
     /// The track that is currently playing (or was last requested).
     pub track: BgmTrack,
+    /// Source and instance of the music started last, read by the
+    /// [`meter`](crate::meter).
+    pub playing: Option<PlayingBgm>,
+}
+
+/// A started BGM sound.
+#[derive(Debug, Clone)]
+pub struct PlayingBgm {
+    /// The sound's audio data.
+    pub source: Handle<AudioSource>,
+    /// The playing instance, for its playback position.
+    pub instance: Handle<AudioInstance>,
+}
+
+/// Resource driving the gameplay ([`BgmTrack::Game`]) music.
+///
+/// Rebuilt by [`switch_bgm_on_state_change`] every time the game track
+/// starts, then advanced by [`advance_bgm_playlist`] and
+/// [`raise_bgm_intensity`].
+#[derive(Resource, Default, Debug)]
+pub struct BgmPlaylist {
+    /// Tracks of the current lap, in play order.
+    queue: Vec<Handle<AudioSource>>,
+    /// Index into `queue` of the playing track.
+    position: usize,
+    /// Number of intensity thresholds passed this game (`0` = playlist).
+    intensity: usize,
+    /// Instance of the playing gameplay track.
+    instance: Option<Handle<AudioInstance>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -65,19 +108,119 @@ pub struct CurrentBgm {
 ///
 /// This is a pure function with no side effects — useful for unit testing.
 ///
-/// Settings and HowToPlay share the Title track so navigating those screens
-/// does not restart the music.
-pub fn desired_track(state: &AppState) -> BgmTrack {
+/// Settings (with its effects page), HowToPlay and Stats share the Title
+/// track so navigating those screens does not restart the music.  Settings
+/// opened from the pause menu (`return_to` is [`AppState::Paused`]) keeps the
+/// Game track instead, so the game music and its playlist carry on.
+pub fn desired_track(state: &AppState, return_to: &ReturnTo) -> BgmTrack {
     match state {
+        AppState::Settings | AppState::EffectsSettings if return_to.0 == AppState::Paused => {
+            BgmTrack::Game
+        }
         AppState::Loading => BgmTrack::None,
-        // Settings / HowToPlay are menu overlays — keep the title music running.
-        AppState::Title | AppState::Settings | AppState::HowToPlay => BgmTrack::Title,
+        // The metronome must be heard on its own.
+        AppState::AudioCalibration => BgmTrack::None,
+        // Settings / HowToPlay / Stats are menu overlays — keep the title music running.
+        AppState::Title
+        | AppState::Settings
+        | AppState::EffectsSettings
+        | AppState::HowToPlay
+        | AppState::Stats => BgmTrack::Title,
         // Paused keeps the game track so the music doesn't cut out on pause.
         AppState::Playing | AppState::Paused => BgmTrack::Game,
         AppState::GameOver => BgmTrack::GameOver,
     }
 }
 
+/// Number of `intensity_tracks` whose threshold `score` has reached.
+///
+/// Thresholds are in ascending order (enforced by the config loader), so the
+/// result minus one is the index of the track that should play.
+pub fn intensity_level(thresholds: &[IntensityTrack], score: u32) -> usize {
+    thresholds.iter().take_while(|t| score >= t.score).count()
+}
+
+/// Shuffles `items` in place (Fisher–Yates).
+///
+/// If `avoid_first` would open the new lap it is swapped away, so a
+/// reshuffled playlist never repeats the track that just ended.
+fn shuffle_tracks<T: PartialEq>(items: &mut [T], avoid_first: Option<&T>) {
+    let mut rng = rand::rng();
+    for i in (1..items.len()).rev() {
+        let j = rng.random_range(0..=i);
+        items.swap(i, j);
+    }
+    if items.len() > 1 && avoid_first.is_some_and(|last| items[0] == *last) {
+        items.swap(0, 1);
+    }
+}
+
+/// Starts `source` on the BGM channel at the game volume with the game
+/// envelope's attack.
+fn play_game_track(
+    bgm_channel: &AudioChannel<BgmChannel>,
+    source: Handle<AudioSource>,
+    looped: bool,
+    cfg: &BgmConfig,
+    settings: &SettingsResource,
+) -> PlayingBgm {
+    let mut command = bgm_channel.play_with_envelope(source.clone(), &cfg.game_envelope);
+    if looped {
+        command.looped();
+    }
+    let instance = command
+        .with_volume(cfg.game_volume + volume_to_db(settings.bgm_volume))
+        .handle();
+    PlayingBgm { source, instance }
+}
+
+impl BgmPlaylist {
+    /// Builds a fresh playlist from `cfg` and starts its first track.
+    ///
+    /// A single track loops; a longer playlist plays each track once and
+    /// relies on [`advance_bgm_playlist`] to move on.
+    fn start(
+        &mut self,
+        cfg: &BgmConfig,
+        default_track: &Handle<AudioSource>,
+        asset_server: &AssetServer,
+        bgm_channel: &AudioChannel<BgmChannel>,
+        settings: &SettingsResource,
+    ) -> PlayingBgm {
+        self.queue = if cfg.game_tracks.is_empty() {
+            vec![default_track.clone()]
+        } else {
+            cfg.game_tracks
+                .iter()
+                .map(|path| asset_server.load(path.as_str()))
+                .collect()
+        };
+        if cfg.shuffle {
+            shuffle_tracks(&mut self.queue, None);
+        }
+        self.position = 0;
+        self.intensity = 0;
+
+        let looped = self.queue.len() == 1;
+        let playing = play_game_track(bgm_channel, self.queue[0].clone(), looped, cfg, settings);
+        self.instance = Some(playing.instance.clone());
+        playing
+    }
+
+    /// Moves to the next track, reshuffling when the lap wraps around.
+    fn next_track(&mut self, shuffle: bool) -> Handle<AudioSource> {
+        self.position += 1;
+        if self.position >= self.queue.len() {
+            self.position = 0;
+            if shuffle {
+                let last = self.queue.last().cloned();
+                shuffle_tracks(&mut self.queue, last.as_ref());
+            }
+        }
+        self.queue[self.position].clone()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // System
 // ---------------------------------------------------------------------------
@@ -96,85 +239,153 @@ pub fn desired_track(state: &AppState) -> BgmTrack {
 ///
 /// # Behaviour
 /// - If the desired track is the same as the current one (e.g. `Playing →
-///   Paused` both map to `Game`) the function returns early.
-/// - The outgoing track fades out over **0.5 s**.
-/// - Incoming `Title` / `Game` tracks fade in over 1.0 s / 1.5 s respectively.
-/// - `GameOver` plays immediately (no fade-in) and does not loop.
-/// - If [`BgmHandles`] has not yet been inserted (asset loading still in
-///   progress) the system returns early rather than panicking.
+///   Paused` both map to `Game`, as does Settings opened from the pause
+///   menu) the function returns early.
+/// - The outgoing track fades out over its envelope's release time.
+/// - The incoming track fades in over its envelope's attack time.
+/// - `GameOver` does not loop.
+/// - `Game` (re)starts the [`BgmPlaylist`] from its first track.
+#[allow(clippy::too_many_arguments)]
 pub fn switch_bgm_on_state_change(
     current_state: Res<State<AppState>>,
     mut current_bgm: ResMut<CurrentBgm>,
+    mut playlist: ResMut<BgmPlaylist>,
     bgm_channel: Res<AudioChannel<BgmChannel>>,
-    bgm_handles: Option<Res<BgmHandles>>,
-    audio_config_handle: Option<Res<AudioConfigHandle>>,
-    audio_config_assets: Res<Assets<AudioConfig>>,
+    bgm_handles: Res<BgmHandles>,
+    bgm_config: BgmParams,
+    asset_server: Res<AssetServer>,
     settings: Res<SettingsResource>,
+    return_to: Res<ReturnTo>,
 ) {
-    let Some(bgm_handles) = bgm_handles else {
-        return;
-    };
-
-    let desired = desired_track(current_state.get());
+    let desired = desired_track(current_state.get(), &return_to);
 
-    // Nothing to do if the track hasn't changed (e.g. Playing → Paused).
+    // Nothing to do if the track hasn't changed (e.g. Playing → Paused, or
+    // Paused → Settings and back).
     if current_bgm.track == desired {
         return;
     }
 
     // Resolve audio config, falling back to defaults if not yet loaded.
-    let default_cfg = AudioConfig::default();
-    let cfg = audio_config_handle
-        .as_ref()
-        .and_then(|h| audio_config_assets.get(&h.0))
-        .unwrap_or(&default_cfg);
-
-    // Fade out the currently-playing track.
-    bgm_channel
-        .stop()
-        .fade_out(AudioTween::linear(Duration::from_secs_f32(
-            cfg.bgm_fade_out_secs,
-        )));
-
-    // Start the new track.  Combine the designer's dB offset (from AudioConfig)
+    let default_cfg = BgmConfig::default();
+    let cfg = bgm_config.get().unwrap_or(&default_cfg);
+
+    // Release the currently-playing track.
+    bgm_channel.stop_with_envelope(&cfg.track_envelope(current_bgm.track));
+
+    // Start the new track.  Combine the designer's dB offset (from BgmConfig)
     // with the user's channel volume (from SettingsResource) so that the saved
     // volume preference is always applied — even on the very first BGM start.
     let user_bgm_db = volume_to_db(settings.bgm_volume);
-    match desired {
-        BgmTrack::None => {
-            // Already stopped above; nothing more to do.
-        }
+    current_bgm.playing = match desired {
+        // Already stopped above; nothing more to do.
+        BgmTrack::None => None,
         BgmTrack::Title => {
-            bgm_channel
-                .play(bgm_handles.title.clone())
+            let source = bgm_handles.title.clone();
+            let instance = bgm_channel
+                .play_with_envelope(source.clone(), &cfg.title_envelope)
                 .looped()
-                .with_volume(cfg.bgm_title_volume + user_bgm_db)
-                .fade_in(AudioTween::linear(Duration::from_secs_f32(
-                    cfg.bgm_title_fade_in_secs,
-                )));
-        }
-        BgmTrack::Game => {
-            bgm_channel
-                .play(bgm_handles.game.clone())
-                .looped()
-                .with_volume(cfg.bgm_game_volume + user_bgm_db)
-                .fade_in(AudioTween::linear(Duration::from_secs_f32(
-                    cfg.bgm_game_fade_in_secs,
-                )));
+                .with_volume(cfg.title_volume + user_bgm_db)
+                .handle();
+            Some(PlayingBgm { source, instance })
         }
+        BgmTrack::Game => Some(playlist.start(
+            cfg,
+            &bgm_handles.game,
+            &asset_server,
+            &bgm_channel,
+            &settings,
+        )),
         BgmTrack::GameOver => {
-            // One-shot: no loop, no fade-in.
-            bgm_channel
-                .play(bgm_handles.gameover.clone())
-                .with_volume(cfg.bgm_gameover_volume + user_bgm_db);
+            // One-shot: no loop.
+            let source = bgm_handles.gameover.clone();
+            let instance = bgm_channel
+                .play_with_envelope(source.clone(), &cfg.gameover_envelope)
+                .with_volume(cfg.gameover_volume + user_bgm_db)
+                .handle();
+            Some(PlayingBgm { source, instance })
         }
-    }
+    };
 
     let prev = current_bgm.track;
     current_bgm.track = desired;
     info!("BGM: {:?} → {:?}", prev, desired);
 }
 
+/// Starts the next playlist track once the current one has finished.
+///
+/// Only relevant for multi-track playlists — a single track loops, and an
+/// intensity track replaces the playlist for the rest of the game.
+pub fn advance_bgm_playlist(
+    mut current_bgm: ResMut<CurrentBgm>,
+    mut playlist: ResMut<BgmPlaylist>,
+    bgm_channel: Res<AudioChannel<BgmChannel>>,
+    bgm_config: BgmParams,
+    settings: Res<SettingsResource>,
+) {
+    if current_bgm.track != BgmTrack::Game || playlist.intensity > 0 || playlist.queue.len() < 2 {
+        return;
+    }
+    let Some(instance) = playlist.instance.as_ref() else {
+        return;
+    };
+    if !matches!(bgm_channel.state(instance), PlaybackState::Stopped) {
+        return;
+    }
+
+    let default_cfg = BgmConfig::default();
+    let cfg = bgm_config.get().unwrap_or(&default_cfg);
+
+    let next = playlist.next_track(cfg.shuffle);
+    let playing = play_game_track(&bgm_channel, next, false, cfg, &settings);
+    playlist.instance = Some(playing.instance.clone());
+    current_bgm.playing = Some(playing);
+    debug!("BGM playlist: track {}", playlist.position);
+}
+
+/// Cross-fades to a more intense track when the score passes the next
+/// `intensity_tracks` threshold.
+///
+/// The level only ever rises during a game; it resets when the game track
+/// restarts.
+pub fn raise_bgm_intensity(
+    game_state: Res<GameState>,
+    mut current_bgm: ResMut<CurrentBgm>,
+    mut playlist: ResMut<BgmPlaylist>,
+    bgm_channel: Res<AudioChannel<BgmChannel>>,
+    bgm_config: BgmParams,
+    asset_server: Res<AssetServer>,
+    settings: Res<SettingsResource>,
+) {
+    if current_bgm.track != BgmTrack::Game {
+        return;
+    }
+    let Some(cfg) = bgm_config.get() else {
+        return;
+    };
+
+    let level = intensity_level(&cfg.intensity_tracks, game_state.score);
+    if level <= playlist.intensity {
+        return;
+    }
+    playlist.intensity = level;
+
+    let track = &cfg.intensity_tracks[level - 1];
+    bgm_channel.stop_with_envelope(&cfg.game_envelope);
+    let playing = play_game_track(
+        &bgm_channel,
+        asset_server.load(track.track.as_str()),
+        true,
+        cfg,
+        &settings,
+    );
+    playlist.instance = Some(playing.instance.clone());
+    current_bgm.playing = Some(playing);
+    info!(
+        "BGM intensity {} at {} points: {}",
+        level, game_state.score, track.track
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -220,64 +431,163 @@ mod tests {
 
     #[test]
     fn test_desired_track_loading_is_none() {
-        assert_eq!(desired_track(&AppState::Loading), BgmTrack::None);
+        assert_eq!(
+            desired_track(&AppState::Loading, &ReturnTo::default()),
+            BgmTrack::None
+        );
     }
 
     #[test]
     fn test_desired_track_title_is_title() {
-        assert_eq!(desired_track(&AppState::Title), BgmTrack::Title);
+        assert_eq!(
+            desired_track(&AppState::Title, &ReturnTo::default()),
+            BgmTrack::Title
+        );
     }
 
     #[test]
     fn test_desired_track_settings_is_title() {
-        // Settings and HowToPlay share the Title track to avoid music restart.
-        assert_eq!(desired_track(&AppState::Settings), BgmTrack::Title);
+        // Settings, HowToPlay and Stats share the Title track to avoid music restart.
+        assert_eq!(
+            desired_track(&AppState::Settings, &ReturnTo::default()),
+            BgmTrack::Title
+        );
     }
 
     #[test]
     fn test_desired_track_how_to_play_is_title() {
-        assert_eq!(desired_track(&AppState::HowToPlay), BgmTrack::Title);
+        assert_eq!(
+            desired_track(&AppState::HowToPlay, &ReturnTo::default()),
+            BgmTrack::Title
+        );
     }
 
     #[test]
     fn test_desired_track_playing_is_game() {
-        assert_eq!(desired_track(&AppState::Playing), BgmTrack::Game);
+        assert_eq!(
+            desired_track(&AppState::Playing, &ReturnTo::default()),
+            BgmTrack::Game
+        );
     }
 
     #[test]
     fn test_desired_track_paused_is_game() {
         // Paused must share the Game track so BGM continues during pause.
-        assert_eq!(desired_track(&AppState::Paused), BgmTrack::Game);
+        assert_eq!(
+            desired_track(&AppState::Paused, &ReturnTo::default()),
+            BgmTrack::Game
+        );
     }
 
     #[test]
     fn test_desired_track_gameover_is_gameover() {
-        assert_eq!(desired_track(&AppState::GameOver), BgmTrack::GameOver);
+        assert_eq!(
+            desired_track(&AppState::GameOver, &ReturnTo::default()),
+            BgmTrack::GameOver
+        );
     }
 
     #[test]
     fn test_playing_and_paused_share_same_track() {
         // Ensures the BGM doesn't restart when the player pauses and resumes.
         assert_eq!(
-            desired_track(&AppState::Playing),
-            desired_track(&AppState::Paused),
+            desired_track(&AppState::Playing, &ReturnTo::default()),
+            desired_track(&AppState::Paused, &ReturnTo::default()),
         );
     }
 
+    #[test]
+    fn test_settings_from_pause_keeps_game_track() {
+        // Paused → Settings → Paused: the pause menu sets ReturnTo before
+        // opening Settings and takes it back when leaving, so every step
+        // maps to Game and the playlist is never restarted.
+        let mut return_to = ReturnTo::default();
+        assert_eq!(desired_track(&AppState::Paused, &return_to), BgmTrack::Game);
+        return_to.0 = AppState::Paused;
+        assert_eq!(
+            desired_track(&AppState::Settings, &return_to),
+            BgmTrack::Game
+        );
+        assert_eq!(
+            desired_track(&AppState::EffectsSettings, &return_to),
+            BgmTrack::Game
+        );
+        assert_eq!(return_to.take(), AppState::Paused);
+        assert_eq!(desired_track(&AppState::Paused, &return_to), BgmTrack::Game);
+    }
+
+    // ------------------------------------------------------------------
+    // Playlist
+    // ------------------------------------------------------------------
+
+    fn thresholds(scores: &[u32]) -> Vec<IntensityTrack> {
+        scores
+            .iter()
+            .map(|&score| IntensityTrack {
+                score,
+                track: format!("sounds/bgm/intense_{score}.ogg"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_intensity_level() {
+        let t = thresholds(&[1000, 3000]);
+        assert_eq!(intensity_level(&t, 0), 0);
+        assert_eq!(intensity_level(&t, 999), 0);
+        assert_eq!(intensity_level(&t, 1000), 1);
+        assert_eq!(intensity_level(&t, 2999), 1);
+        assert_eq!(intensity_level(&t, 5000), 2);
+        assert_eq!(intensity_level(&[], 5000), 0);
+    }
+
+    #[test]
+    fn test_shuffle_tracks_keeps_every_track() {
+        let mut items: Vec<u32> = (0..8).collect();
+        shuffle_tracks(&mut items, None);
+        let mut sorted = items.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffle_tracks_never_repeats_last_track() {
+        for _ in 0..50 {
+            let mut items = vec![1, 2, 3];
+            shuffle_tracks(&mut items, Some(&3));
+            assert_ne!(items[0], 3);
+        }
+    }
+
+    #[test]
+    fn test_playlist_next_track_wraps() {
+        let mut playlist = BgmPlaylist {
+            queue: vec![Handle::default(), Handle::default()],
+            ..default()
+        };
+        playlist.next_track(false);
+        assert_eq!(playlist.position, 1);
+        playlist.next_track(false);
+        assert_eq!(playlist.position, 0);
+    }
+
     #[test]
     fn test_all_states_have_a_mapping() {
         let states = [
             AppState::Loading,
             AppState::Title,
             AppState::Settings,
+            AppState::AudioCalibration,
+            AppState::EffectsSettings,
             AppState::HowToPlay,
+            AppState::Stats,
             AppState::Playing,
             AppState::Paused,
             AppState::GameOver,
         ];
         // Just confirm every state returns *some* (non-panicking) track.
         for state in &states {
-            let _ = desired_track(state);
+            let _ = desired_track(state, &ReturnTo::default());
         }
     }
 }
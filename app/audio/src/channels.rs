@@ -8,7 +8,7 @@
 //! # Effective-volume model
 //!
 //! ```text
-//! effective_dB = design_dB (AudioConfig)  +  user_dB (SettingsResource)
+//! effective_dB = design_dB (audio config)  +  user_dB (SettingsResource)
 //! ```
 //!
 //! This combined value is passed to `.with_volume(effective_dB)` each time a
@@ -29,10 +29,10 @@
 
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
-use suika_game_core::resources::settings::SettingsResource;
+use suika_game_core::resources::settings::{SFX_LATENCY_MAX_MS, SettingsResource};
 
-use crate::bgm::{BgmTrack, CurrentBgm};
-use crate::config::{AudioConfig, AudioConfigHandle};
+use crate::bgm::CurrentBgm;
+use crate::config::{BgmConfig, BgmParams};
 
 // ---------------------------------------------------------------------------
 // Channel marker types
@@ -76,6 +76,22 @@ pub fn volume_to_db(vol: u8) -> f32 {
     (clamped as f32 / 10.0 - 1.0) * 40.0
 }
 
+// ---------------------------------------------------------------------------
+// Latency helper
+// ---------------------------------------------------------------------------
+
+/// Position, in seconds, to start a gameplay one-shot from so it makes up
+/// for [`SettingsResource::sfx_latency_ms`].
+///
+/// A sound cannot start before the event that triggers it, so the clip is
+/// entered that far in instead: by the time it reaches the speakers it is
+/// where it would have been without the delay.  Only the first few
+/// milliseconds of the attack are lost.  Capped at
+/// [`SFX_LATENCY_MAX_MS`] for hand-edited settings.
+pub fn sfx_start_secs(settings: &SettingsResource) -> f64 {
+    f64::from(settings.sfx_latency_ms.min(SFX_LATENCY_MAX_MS)) / 1000.0
+}
+
 // ---------------------------------------------------------------------------
 // Resources
 // ---------------------------------------------------------------------------
@@ -123,24 +139,17 @@ pub fn apply_volume_settings(
     sfx_channel: Res<AudioChannel<SfxChannel>>,
     mut prev: ResMut<PreviousVolume>,
     current_bgm: Res<CurrentBgm>,
-    audio_config_handle: Option<Res<AudioConfigHandle>>,
-    audio_config_assets: Res<Assets<AudioConfig>>,
+    bgm_config: BgmParams,
 ) {
     if settings.bgm_volume != prev.bgm {
-        // Combine design dB (track-specific offset from AudioConfig) with the
+        // Combine design dB (track-specific offset from BgmConfig) with the
         // user's volume preference so that already-playing BGM stays consistent
         // with the volume used when the track was started.
-        let default_cfg = AudioConfig::default();
-        let cfg = audio_config_handle
-            .as_ref()
-            .and_then(|h| audio_config_assets.get(&h.0))
-            .unwrap_or(&default_cfg);
-        let design_db = match current_bgm.track {
-            BgmTrack::Title => cfg.bgm_title_volume,
-            BgmTrack::Game => cfg.bgm_game_volume,
-            BgmTrack::GameOver => cfg.bgm_gameover_volume,
-            BgmTrack::None => 0.0,
-        };
+        let default_cfg = BgmConfig::default();
+        let design_db = bgm_config
+            .get()
+            .unwrap_or(&default_cfg)
+            .track_volume(current_bgm.track);
         bgm_channel.set_volume(design_db + volume_to_db(settings.bgm_volume));
         prev.bgm = settings.bgm_volume;
     }
@@ -201,4 +210,14 @@ mod tests {
         assert_eq!(volume_to_db(11), volume_to_db(10));
         assert_eq!(volume_to_db(255), volume_to_db(10));
     }
+
+    #[test]
+    fn test_sfx_start_secs() {
+        let mut settings = SettingsResource::default();
+        assert_eq!(sfx_start_secs(&settings), 0.0);
+        settings.sfx_latency_ms = 150;
+        assert!((sfx_start_secs(&settings) - 0.15).abs() < 1e-9);
+        settings.sfx_latency_ms = u16::MAX;
+        assert!((sfx_start_secs(&settings) - 0.3).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,318 @@
+//! BGM configuration loaded from `assets/config/audio/bgm.ron`.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{Asset, AssetEvent, AssetLoader, Assets, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+use serde::Deserialize;
+use suika_game_core::config::{ConfigError, read_ron_asset};
+use suika_game_core::resources::settings::SettingsResource;
+
+use crate::bgm::{BgmTrack, CurrentBgm};
+use crate::channels::{BgmChannel, volume_to_db};
+use crate::envelope::VolumeEnvelope;
+
+// ---------------------------------------------------------------------------
+// Config struct
+// ---------------------------------------------------------------------------
+
+/// Track volumes, envelopes and the gameplay playlist.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct BgmConfig {
+    /// Volume for the title-screen track (dB, 0 = full).
+    pub title_volume: f32,
+    /// Volume for the in-game track (dB, 0 = full).
+    pub game_volume: f32,
+    /// Volume for the game-over track (dB, 0 = full).
+    pub gameover_volume: f32,
+
+    // --- Envelopes (attack = fade-in on start, release = fade-out on stop) ---
+    /// Envelope of the title-screen track.
+    pub title_envelope: VolumeEnvelope,
+    /// Envelope of the in-game tracks, including playlist and intensity
+    /// changes.
+    pub game_envelope: VolumeEnvelope,
+    /// Envelope of the game-over track.
+    pub gameover_envelope: VolumeEnvelope,
+
+    // --- Gameplay playlist ---
+    /// Asset paths of the gameplay tracks, played one after another.
+    ///
+    /// Empty plays the built-in game track on a loop.
+    pub game_tracks: Vec<String>,
+    /// Reshuffle `game_tracks` every time the playlist wraps around.
+    pub shuffle: bool,
+    /// Tracks that take over once the score passes a threshold.
+    ///
+    /// Must be listed in ascending `score` order.
+    pub intensity_tracks: Vec<IntensityTrack>,
+}
+
+/// A more intense gameplay track that replaces the playlist once the score
+/// reaches `score`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct IntensityTrack {
+    /// Score at which this track starts.
+    pub score: u32,
+    /// Asset path of the track.
+    pub track: String,
+}
+
+// Default values — these match the hard-coded constants that bgm.rs used
+// before the config was introduced, so existing behaviour is preserved when
+// the RON file is absent or a field is omitted.
+const DEFAULT_TITLE_VOLUME: f32 = -4.0;
+const DEFAULT_GAME_VOLUME: f32 = -8.0;
+const DEFAULT_GAMEOVER_VOLUME: f32 = -6.0;
+const DEFAULT_TITLE_ENVELOPE: VolumeEnvelope = VolumeEnvelope::new(0.3, 0.5);
+const DEFAULT_GAME_ENVELOPE: VolumeEnvelope = VolumeEnvelope::new(0.3, 0.5);
+/// Short attack so the one-shot sting starts promptly without a click.
+const DEFAULT_GAMEOVER_ENVELOPE: VolumeEnvelope = VolumeEnvelope::new(0.05, 0.5);
+const DEFAULT_SHUFFLE: bool = true;
+
+impl Default for BgmConfig {
+    fn default() -> Self {
+        Self {
+            title_volume: DEFAULT_TITLE_VOLUME,
+            game_volume: DEFAULT_GAME_VOLUME,
+            gameover_volume: DEFAULT_GAMEOVER_VOLUME,
+            title_envelope: DEFAULT_TITLE_ENVELOPE,
+            game_envelope: DEFAULT_GAME_ENVELOPE,
+            gameover_envelope: DEFAULT_GAMEOVER_ENVELOPE,
+            game_tracks: Vec::new(),
+            shuffle: DEFAULT_SHUFFLE,
+            intensity_tracks: Vec::new(),
+        }
+    }
+}
+
+impl BgmConfig {
+    /// Design volume (dB) of `track`; `0.0` for [`BgmTrack::None`].
+    pub fn track_volume(&self, track: BgmTrack) -> f32 {
+        match track {
+            BgmTrack::Title => self.title_volume,
+            BgmTrack::Game => self.game_volume,
+            BgmTrack::GameOver => self.gameover_volume,
+            BgmTrack::None => 0.0,
+        }
+    }
+
+    /// Envelope of `track`; the default envelope for [`BgmTrack::None`].
+    pub fn track_envelope(&self, track: BgmTrack) -> VolumeEnvelope {
+        match track {
+            BgmTrack::Title => self.title_envelope,
+            BgmTrack::Game => self.game_envelope,
+            BgmTrack::GameOver => self.gameover_envelope,
+            BgmTrack::None => VolumeEnvelope::default(),
+        }
+    }
+
+    /// Checks that `intensity_tracks` are in ascending score order.
+    ///
+    /// The playlist only ever moves *up* the list, so an out-of-order entry
+    /// would never be reached.
+    pub fn validate(&self) -> Result<(), String> {
+        for pair in self.intensity_tracks.windows(2) {
+            if pair[1].score <= pair[0].score {
+                return Err(format!(
+                    "intensity_tracks must be in ascending score order, got {} after {}",
+                    pair[1].score, pair[0].score
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Asset loader (RON)
+// ---------------------------------------------------------------------------
+
+/// RON-based [`AssetLoader`] for [`BgmConfig`] that rejects unordered
+/// intensity tracks.
+#[derive(Default)]
+pub(super) struct BgmConfigLoader;
+
+impl AssetLoader for BgmConfigLoader {
+    type Asset = BgmConfig;
+    type Settings = ();
+    type Error = ConfigError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let cfg: BgmConfig = read_ron_asset(reader, load_context.path()).await?;
+        cfg.validate().map_err(|message| ConfigError::Invalid {
+            path: load_context.path().to_path_buf(),
+            message,
+        })?;
+        Ok(cfg)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Handle resource / SystemParam
+// ---------------------------------------------------------------------------
+
+/// Resource holding the handle to the loaded [`BgmConfig`] asset.
+#[derive(Resource)]
+pub struct BgmConfigHandle(pub Handle<BgmConfig>);
+
+/// SystemParam bundle for accessing [`BgmConfig`].
+#[derive(SystemParam)]
+pub struct BgmParams<'w> {
+    handle: Option<Res<'w, BgmConfigHandle>>,
+    assets: Option<Res<'w, Assets<BgmConfig>>>,
+}
+
+impl<'w> BgmParams<'w> {
+    /// Returns the currently loaded [`BgmConfig`], or `None` while loading.
+    pub fn get(&self) -> Option<&BgmConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hot-reload system
+// ---------------------------------------------------------------------------
+
+/// Applies a changed `bgm.ron` to the playing track's volume.
+///
+/// Envelopes and the playlist are read when a track starts or stops, so
+/// they take effect on the next switch.
+pub fn hot_reload_bgm_config(
+    mut events: MessageReader<AssetEvent<BgmConfig>>,
+    bgm_config: BgmParams,
+    bgm_channel: Res<AudioChannel<BgmChannel>>,
+    current_bgm: Res<CurrentBgm>,
+    settings: Res<SettingsResource>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ BGM config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                if let Some(config) = bgm_config.get() {
+                    bgm_channel.set_volume(
+                        config.track_volume(current_bgm.track) + volume_to_db(settings.bgm_volume),
+                    );
+                    info!(
+                        "🔥 BGM config hot-reloaded (game={:.1} dB, game release={:.2}s)",
+                        config.game_volume, config.game_envelope.release_secs,
+                    );
+                }
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ BGM config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bgm_config_default_values() {
+        let cfg = BgmConfig::default();
+        // Track volumes should be negative dB (quieter than full)
+        assert!(cfg.title_volume < 0.0, "title BGM should be < 0 dB");
+        assert!(cfg.game_volume < 0.0, "game BGM should be < 0 dB");
+        assert!(cfg.gameover_volume < 0.0, "gameover BGM should be < 0 dB");
+        for env in [cfg.title_envelope, cfg.game_envelope, cfg.gameover_envelope] {
+            assert!(env.attack_secs > 0.0, "every start should fade in");
+            assert!(env.release_secs > 0.0, "every stop should fade out");
+        }
+    }
+
+    #[test]
+    fn test_bgm_config_ron_partial_fields_use_defaults() {
+        let cfg: BgmConfig =
+            ron::de::from_str("BgmConfig(title_volume: -10.0)").expect("RON parse must succeed");
+        assert_eq!(cfg.title_volume, -10.0);
+        assert_eq!(cfg.game_volume, DEFAULT_GAME_VOLUME);
+        assert_eq!(cfg.game_envelope, DEFAULT_GAME_ENVELOPE);
+    }
+
+    #[test]
+    fn test_track_volume() {
+        let cfg = BgmConfig::default();
+        assert_eq!(cfg.track_volume(BgmTrack::Title), cfg.title_volume);
+        assert_eq!(cfg.track_volume(BgmTrack::Game), cfg.game_volume);
+        assert_eq!(cfg.track_volume(BgmTrack::GameOver), cfg.gameover_volume);
+        assert_eq!(cfg.track_volume(BgmTrack::None), 0.0);
+    }
+
+    #[test]
+    fn test_track_envelope() {
+        let cfg: BgmConfig = ron::de::from_str("BgmConfig(title_envelope: (attack_secs: 2.0))")
+            .expect("RON parse must succeed");
+        let title = cfg.track_envelope(BgmTrack::Title);
+        assert_eq!(title.attack_secs, 2.0);
+        assert_eq!(title.release_secs, VolumeEnvelope::default().release_secs);
+        assert_eq!(
+            cfg.track_envelope(BgmTrack::GameOver),
+            DEFAULT_GAMEOVER_ENVELOPE
+        );
+    }
+
+    #[test]
+    fn test_bgm_config_ron_playlist() {
+        let ron_str = r#"
+BgmConfig(
+    game_tracks: ["sounds/bgm/game_a.ogg", "sounds/bgm/game_b.ogg"],
+    shuffle: false,
+    intensity_tracks: [
+        (score: 1000, track: "sounds/bgm/game_intense.ogg"),
+    ],
+)
+"#;
+        let cfg: BgmConfig = ron::de::from_str(ron_str).expect("RON parse must succeed");
+        assert_eq!(cfg.game_tracks.len(), 2);
+        assert!(!cfg.shuffle);
+        assert_eq!(
+            cfg.intensity_tracks,
+            vec![IntensityTrack {
+                score: 1000,
+                track: "sounds/bgm/game_intense.ogg".into(),
+            }]
+        );
+        assert_eq!(cfg.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_unordered_intensity_tracks_are_rejected() {
+        let cfg = BgmConfig {
+            intensity_tracks: vec![
+                IntensityTrack {
+                    score: 2000,
+                    track: "a.ogg".into(),
+                },
+                IntensityTrack {
+                    score: 1000,
+                    track: "b.ogg".into(),
+                },
+            ],
+            ..default()
+        };
+        assert!(cfg.validate().is_err());
+    }
+}
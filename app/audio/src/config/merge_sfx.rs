@@ -0,0 +1,257 @@
+//! Gameplay SFX configuration loaded from `assets/config/audio/merge_sfx.ron`.
+//!
+//! Covers the merge, watermelon, combo and combo-break sounds plus the drop
+//! and game-over sounds.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{Asset, AssetEvent, AssetLoader, Assets, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+use suika_game_core::config::{ConfigError, read_ron_asset};
+
+// ---------------------------------------------------------------------------
+// Config struct
+// ---------------------------------------------------------------------------
+
+/// Volumes and pitches of the gameplay sound effects.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MergeSfxConfig {
+    /// Volume for the small-fruit merge sound (dB, 0 = full).
+    pub merge_small_volume: f32,
+    /// Volume for the medium-fruit merge sound (dB, 0 = full).
+    pub merge_medium_volume: f32,
+    /// Volume for the large-fruit merge sound (dB, 0 = full).
+    pub merge_large_volume: f32,
+    /// Volume for the watermelon-merge fanfare (dB, 0 = full).
+    pub watermelon_volume: f32,
+    /// Volume for the combo-chain sound (dB, 0 = full).
+    pub combo_volume: f32,
+    /// Volume for the fruit-drop sound (dB, 0 = full).
+    pub drop_volume: f32,
+    /// Volume for the game-over sting (dB, 0 = full).
+    pub gameover_volume: f32,
+    /// Volume for the combo-break sound, a muted replay of the drop clip
+    /// (dB, 0 = full).
+    pub combo_break_volume: f32,
+
+    // --- Pitch (playback rate multiplier; 1.0 = original pitch) ---
+    /// Playback rate for the small-fruit merge sound (Cherry, Strawberry, Grape).
+    pub merge_small_pitch: f64,
+    /// Playback rate for the medium-fruit merge sound (Dekopon through Pear).
+    pub merge_medium_pitch: f64,
+    /// Playback rate for the large-fruit merge sound (Peach, Pineapple).
+    pub merge_large_pitch: f64,
+    /// Pitch increment added per combo count for the combo sound.
+    ///
+    /// Combo pitch = `1.0 + (combo_count × combo_pitch_step).min(combo_pitch_cap)`.
+    pub combo_pitch_step: f64,
+    /// Maximum pitch offset added on top of 1.0 for the combo sound.
+    ///
+    /// Caps the value of `combo_count × combo_pitch_step` so the pitch
+    /// does not grow unboundedly at very high combo counts.
+    pub combo_pitch_cap: f64,
+    /// Playback rate for the combo-break sound (below 1.0 for a falling "drop").
+    pub combo_break_pitch: f64,
+}
+
+const DEFAULT_MERGE_SMALL_VOLUME: f32 = 0.0;
+const DEFAULT_MERGE_MEDIUM_VOLUME: f32 = 0.0;
+const DEFAULT_MERGE_LARGE_VOLUME: f32 = 0.0;
+const DEFAULT_WATERMELON_VOLUME: f32 = 0.0;
+const DEFAULT_COMBO_VOLUME: f32 = 0.0;
+const DEFAULT_DROP_VOLUME: f32 = 0.0;
+const DEFAULT_GAMEOVER_VOLUME: f32 = 0.0;
+/// Subtle: the break should register without sounding like a penalty.
+const DEFAULT_COMBO_BREAK_VOLUME: f32 = -10.0;
+const DEFAULT_MERGE_SMALL_PITCH: f64 = 1.2;
+const DEFAULT_MERGE_MEDIUM_PITCH: f64 = 1.0;
+const DEFAULT_MERGE_LARGE_PITCH: f64 = 0.8;
+/// Pitch added per combo count (e.g. 0.1 → combo 2 = 1.2×, combo 5 = 1.5×).
+const DEFAULT_COMBO_PITCH_STEP: f64 = 0.1;
+/// Maximum pitch offset above 1.0 for the combo sound (caps the step scaling).
+const DEFAULT_COMBO_PITCH_CAP: f64 = 0.5;
+const DEFAULT_COMBO_BREAK_PITCH: f64 = 0.7;
+
+impl Default for MergeSfxConfig {
+    fn default() -> Self {
+        Self {
+            merge_small_volume: DEFAULT_MERGE_SMALL_VOLUME,
+            merge_medium_volume: DEFAULT_MERGE_MEDIUM_VOLUME,
+            merge_large_volume: DEFAULT_MERGE_LARGE_VOLUME,
+            watermelon_volume: DEFAULT_WATERMELON_VOLUME,
+            combo_volume: DEFAULT_COMBO_VOLUME,
+            drop_volume: DEFAULT_DROP_VOLUME,
+            gameover_volume: DEFAULT_GAMEOVER_VOLUME,
+            combo_break_volume: DEFAULT_COMBO_BREAK_VOLUME,
+            merge_small_pitch: DEFAULT_MERGE_SMALL_PITCH,
+            merge_medium_pitch: DEFAULT_MERGE_MEDIUM_PITCH,
+            merge_large_pitch: DEFAULT_MERGE_LARGE_PITCH,
+            combo_pitch_step: DEFAULT_COMBO_PITCH_STEP,
+            combo_pitch_cap: DEFAULT_COMBO_PITCH_CAP,
+            combo_break_pitch: DEFAULT_COMBO_BREAK_PITCH,
+        }
+    }
+}
+
+impl MergeSfxConfig {
+    /// Checks that every pitch value is positive.
+    ///
+    /// Zero or negative playback rates would produce silence or undefined
+    /// behaviour in the audio backend, and the combo formula
+    /// `1.0 + (count × step).min(cap)` must always produce a pitch ≥ 1.0.
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("merge_small_pitch", self.merge_small_pitch),
+            ("merge_medium_pitch", self.merge_medium_pitch),
+            ("merge_large_pitch", self.merge_large_pitch),
+            ("combo_pitch_step", self.combo_pitch_step),
+            ("combo_pitch_cap", self.combo_pitch_cap),
+            ("combo_break_pitch", self.combo_break_pitch),
+        ] {
+            if value <= 0.0 {
+                return Err(format!("{name} must be > 0.0, got {value}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Asset loader (RON)
+// ---------------------------------------------------------------------------
+
+/// RON-based [`AssetLoader`] for [`MergeSfxConfig`] that rejects invalid pitches.
+#[derive(Default)]
+pub(super) struct MergeSfxConfigLoader;
+
+impl AssetLoader for MergeSfxConfigLoader {
+    type Asset = MergeSfxConfig;
+    type Settings = ();
+    type Error = ConfigError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let cfg: MergeSfxConfig = read_ron_asset(reader, load_context.path()).await?;
+        cfg.validate().map_err(|message| ConfigError::Invalid {
+            path: load_context.path().to_path_buf(),
+            message,
+        })?;
+        Ok(cfg)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Handle resource / SystemParam
+// ---------------------------------------------------------------------------
+
+/// Resource holding the handle to the loaded [`MergeSfxConfig`] asset.
+#[derive(Resource)]
+pub struct MergeSfxConfigHandle(pub Handle<MergeSfxConfig>);
+
+/// SystemParam bundle for accessing [`MergeSfxConfig`].
+#[derive(SystemParam)]
+pub struct MergeSfxParams<'w> {
+    handle: Option<Res<'w, MergeSfxConfigHandle>>,
+    assets: Option<Res<'w, Assets<MergeSfxConfig>>>,
+}
+
+impl<'w> MergeSfxParams<'w> {
+    /// Returns the currently loaded [`MergeSfxConfig`], or `None` while loading.
+    pub fn get(&self) -> Option<&MergeSfxConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hot-reload system
+// ---------------------------------------------------------------------------
+
+/// Logs changes to `merge_sfx.ron`; the SFX systems read the config on every
+/// play, so the next sound already uses the new values.
+pub fn hot_reload_merge_sfx_config(
+    mut events: MessageReader<AssetEvent<MergeSfxConfig>>,
+    merge_sfx_config: MergeSfxParams,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ Merge SFX config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                if let Some(config) = merge_sfx_config.get() {
+                    info!(
+                        "🔥 Merge SFX config hot-reloaded (small_pitch={:.2}, combo_step={:.2})",
+                        config.merge_small_pitch, config.combo_pitch_step,
+                    );
+                }
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ Merge SFX config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_sfx_config_ron_roundtrip() {
+        let ron_str = r#"
+MergeSfxConfig(
+    merge_small_volume: -1.0,
+    watermelon_volume: 3.0,
+    gameover_volume: -2.0,
+    merge_small_pitch: 1.1,
+)
+"#;
+        let cfg: MergeSfxConfig = ron::de::from_str(ron_str).expect("RON parse must succeed");
+        assert_eq!(cfg.merge_small_volume, -1.0);
+        assert_eq!(cfg.watermelon_volume, 3.0);
+        assert_eq!(cfg.merge_small_pitch, 1.1);
+        // Omitted fields fall back to serde defaults.
+        assert_eq!(cfg.merge_medium_pitch, DEFAULT_MERGE_MEDIUM_PITCH);
+        assert_eq!(cfg.combo_pitch_cap, DEFAULT_COMBO_PITCH_CAP);
+    }
+
+    #[test]
+    fn test_default_config_is_valid() {
+        assert_eq!(MergeSfxConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_non_positive_pitch_is_rejected() {
+        let cfg = MergeSfxConfig {
+            merge_small_pitch: 0.0,
+            ..default()
+        };
+        assert_eq!(
+            cfg.validate(),
+            Err("merge_small_pitch must be > 0.0, got 0".into())
+        );
+
+        let cfg = MergeSfxConfig {
+            combo_pitch_cap: -0.5,
+            ..default()
+        };
+        assert!(cfg.validate().is_err());
+    }
+}
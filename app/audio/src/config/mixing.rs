@@ -0,0 +1,155 @@
+//! SFX mixing configuration loaded from `assets/config/audio/mixing.ron`.
+//!
+//! Every sound effect belongs to a [`SfxGroup`](crate::mixer::SfxGroup);
+//! each group has its own volume and priority.  A sound of a higher-priority
+//! group ducks the lower-priority groups for a moment (see
+//! [`crate::mixer`]).
+
+use bevy::asset::io::Reader;
+use bevy::asset::{Asset, AssetEvent, AssetLoader, Assets, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+use suika_game_core::config::{ConfigError, read_ron_asset};
+
+// ---------------------------------------------------------------------------
+// Config struct
+// ---------------------------------------------------------------------------
+
+/// Volume and priority of one mixing group; an omitted field is `0`.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(default)]
+pub struct MixGroupConfig {
+    /// Group volume added to every sound of the group (dB, 0 = unchanged).
+    pub volume: f32,
+    /// Sounds of this group duck every group with a lower priority.
+    pub priority: u8,
+}
+
+/// Mixing groups and ducking of the sound effects.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MixingConfig {
+    /// Merge pops, combo breaks and the game-over sting.
+    pub gameplay: MixGroupConfig,
+    /// Menu button clicks and hovers.
+    pub ui: MixGroupConfig,
+    /// Watermelon fanfare and combo chimes.
+    pub celebration: MixGroupConfig,
+    /// Volume change of a ducked group (dB, negative = quieter).
+    pub duck_volume: f32,
+    /// How long a ducked group stays ducked after the last louder sound
+    /// (seconds).
+    pub duck_secs: f32,
+}
+
+const DEFAULT_GAMEPLAY: MixGroupConfig = MixGroupConfig {
+    volume: 0.0,
+    priority: 1,
+};
+const DEFAULT_UI: MixGroupConfig = MixGroupConfig {
+    volume: 0.0,
+    priority: 0,
+};
+const DEFAULT_CELEBRATION: MixGroupConfig = MixGroupConfig {
+    volume: 0.0,
+    priority: 2,
+};
+const DEFAULT_DUCK_VOLUME: f32 = -8.0;
+const DEFAULT_DUCK_SECS: f32 = 0.6;
+
+impl Default for MixingConfig {
+    fn default() -> Self {
+        Self {
+            gameplay: DEFAULT_GAMEPLAY,
+            ui: DEFAULT_UI,
+            celebration: DEFAULT_CELEBRATION,
+            duck_volume: DEFAULT_DUCK_VOLUME,
+            duck_secs: DEFAULT_DUCK_SECS,
+        }
+    }
+}
+
+ron_asset_loader!(MixingConfigLoader, MixingConfig);
+
+// ---------------------------------------------------------------------------
+// Handle resource / SystemParam
+// ---------------------------------------------------------------------------
+
+/// Resource holding the handle to the loaded [`MixingConfig`] asset.
+#[derive(Resource)]
+pub struct MixingConfigHandle(pub Handle<MixingConfig>);
+
+/// SystemParam bundle for accessing [`MixingConfig`].
+#[derive(SystemParam)]
+pub struct MixingParams<'w> {
+    handle: Option<Res<'w, MixingConfigHandle>>,
+    assets: Option<Res<'w, Assets<MixingConfig>>>,
+}
+
+impl<'w> MixingParams<'w> {
+    /// Returns the currently loaded [`MixingConfig`], or `None` while loading.
+    pub fn get(&self) -> Option<&MixingConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hot-reload system
+// ---------------------------------------------------------------------------
+
+/// Logs changes to `mixing.ron`; the next sound uses the new values.
+pub fn hot_reload_mixing_config(
+    mut events: MessageReader<AssetEvent<MixingConfig>>,
+    mixing_config: MixingParams,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ SFX mixing config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                if let Some(config) = mixing_config.get() {
+                    info!(
+                        "🔥 SFX mixing config hot-reloaded (duck={:.1} dB for {:.2}s)",
+                        config.duck_volume, config.duck_secs,
+                    );
+                }
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ SFX mixing config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixing_config_ron_partial_fields_use_defaults() {
+        let cfg: MixingConfig =
+            ron::de::from_str("MixingConfig(ui: (volume: -4.0), duck_secs: 1.0)")
+                .expect("RON parse must succeed");
+        assert_eq!(cfg.ui.volume, -4.0);
+        assert_eq!(cfg.ui.priority, 0);
+        assert_eq!(cfg.duck_secs, 1.0);
+        assert_eq!(cfg.celebration, DEFAULT_CELEBRATION);
+        assert_eq!(cfg.duck_volume, DEFAULT_DUCK_VOLUME);
+    }
+
+    #[test]
+    fn test_default_celebration_outranks_gameplay() {
+        let cfg = MixingConfig::default();
+        assert!(cfg.celebration.priority > cfg.gameplay.priority);
+        assert!(cfg.gameplay.priority > cfg.ui.priority);
+    }
+}
@@ -0,0 +1,146 @@
+//! Audio configuration loaded from RON files.
+//!
+//! Each category has its own file, loader and hot-reload system, so editing
+//! one never re-parses or disturbs the others:
+//!
+//! | File                           | Config type          | Controls                                |
+//! |--------------------------------|----------------------|-----------------------------------------|
+//! | `config/audio/bgm.ron`          | [`BgmConfig`]        | Track volumes, envelopes, playlist      |
+//! | `config/audio/merge_sfx.ron`    | [`MergeSfxConfig`]   | Merge / combo / drop / game-over sounds |
+//! | `config/audio/ui_sfx.ron`       | [`UiSfxConfig`]      | Button click / hover sounds             |
+//! | `config/audio/mixing.ron`       | [`MixingConfig`]     | SFX group volumes, priorities, ducking  |
+//!
+//! # Volume units
+//!
+//! Volumes are expressed in **decibels relative to full-scale** as expected by
+//! [`bevy_kira_audio`]:
+//! - `0.0 dB` — full volume (unchanged)
+//! - `-6.0 dB` — roughly half perceived loudness
+//! - `-20.0 dB` — very quiet
+//!
+//! # Hot-reload
+//!
+//! Edit any of the files while the game is running.  BGM volume changes are
+//! applied to the playing track at once; everything else takes effect the
+//! next time it is used.  Systems fall back to each config's `Default` while
+//! its file is still loading.
+
+use bevy::prelude::*;
+use suika_game_core::config::{report_config_load_errors, report_config_reloads};
+use suika_game_core::events::{ConfigErrorEvent, ConfigReloadedEvent};
+
+/// Generates a RON-based `AssetLoader` for a config type without extra
+/// validation (mirrors the macro in `suika-game-core`'s config module).
+///
+/// The expanding module must import `AssetLoader`, `LoadContext`, `Reader`,
+/// `ConfigError` and `read_ron_asset`.
+macro_rules! ron_asset_loader {
+    ($loader:ident, $asset:ty) => {
+        #[derive(Default)]
+        pub(super) struct $loader;
+
+        impl AssetLoader for $loader {
+            type Asset = $asset;
+            type Settings = ();
+            type Error = ConfigError;
+
+            async fn load(
+                &self,
+                reader: &mut dyn Reader,
+                _settings: &Self::Settings,
+                load_context: &mut LoadContext<'_>,
+            ) -> Result<Self::Asset, Self::Error> {
+                read_ron_asset(reader, load_context.path()).await
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["ron"]
+            }
+        }
+    };
+}
+
+pub mod bgm;
+pub mod merge_sfx;
+pub mod mixing;
+pub mod ui_sfx;
+
+pub use bgm::*;
+pub use merge_sfx::*;
+pub use mixing::*;
+pub use ui_sfx::*;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Startup system — queues the four audio config files for loading and
+/// inserts their handle resources.
+pub fn load_audio_configs(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(BgmConfigHandle(asset_server.load("config/audio/bgm.ron")));
+    commands.insert_resource(MergeSfxConfigHandle(
+        asset_server.load("config/audio/merge_sfx.ron"),
+    ));
+    commands.insert_resource(UiSfxConfigHandle(
+        asset_server.load("config/audio/ui_sfx.ron"),
+    ));
+    commands.insert_resource(MixingConfigHandle(
+        asset_server.load("config/audio/mixing.ron"),
+    ));
+    info!("Audio configs queued for loading");
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Registers the audio config asset types, starts loading the files, and
+/// adds their hot-reload systems.
+///
+/// Added automatically by [`crate::GameAudioPlugin`].
+pub struct AudioConfigPlugin;
+
+impl Plugin for AudioConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<BgmConfig>()
+            .register_asset_loader(bgm::BgmConfigLoader)
+            .init_asset::<MergeSfxConfig>()
+            .register_asset_loader(merge_sfx::MergeSfxConfigLoader)
+            .init_asset::<UiSfxConfig>()
+            .register_asset_loader(ui_sfx::UiSfxConfigLoader)
+            .init_asset::<MixingConfig>()
+            .register_asset_loader(mixing::MixingConfigLoader)
+            .add_systems(Startup, load_audio_configs)
+            .add_systems(
+                Update,
+                (
+                    hot_reload_bgm_config,
+                    hot_reload_merge_sfx_config,
+                    hot_reload_ui_sfx_config,
+                    hot_reload_mixing_config,
+                ),
+            );
+
+        // Report broken files (initial load and hot-reload) to the player
+        app.add_message::<ConfigErrorEvent>().add_systems(
+            Update,
+            (
+                report_config_load_errors::<BgmConfig>,
+                report_config_load_errors::<MergeSfxConfig>,
+                report_config_load_errors::<UiSfxConfig>,
+                report_config_load_errors::<MixingConfig>,
+            ),
+        );
+
+        // Confirm successful hot-reloads (shown as toasts in debug builds)
+        app.add_message::<ConfigReloadedEvent>().add_systems(
+            Update,
+            (
+                report_config_reloads::<BgmConfig>,
+                report_config_reloads::<MergeSfxConfig>,
+                report_config_reloads::<UiSfxConfig>,
+                report_config_reloads::<MixingConfig>,
+            ),
+        );
+    }
+}
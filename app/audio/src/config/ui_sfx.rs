@@ -0,0 +1,107 @@
+//! UI SFX configuration loaded from `assets/config/audio/ui_sfx.ron`.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{Asset, AssetEvent, AssetLoader, Assets, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+use suika_game_core::config::{ConfigError, read_ron_asset};
+
+// ---------------------------------------------------------------------------
+// Config struct
+// ---------------------------------------------------------------------------
+
+/// Volumes of the menu button sounds.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct UiSfxConfig {
+    /// Volume for UI button-click sounds (dB, 0 = full).
+    pub button_click_volume: f32,
+    /// Volume for UI button-hover sounds (dB, 0 = full).
+    pub button_hover_volume: f32,
+}
+
+const DEFAULT_BUTTON_CLICK_VOLUME: f32 = 0.0;
+const DEFAULT_BUTTON_HOVER_VOLUME: f32 = 0.0;
+
+impl Default for UiSfxConfig {
+    fn default() -> Self {
+        Self {
+            button_click_volume: DEFAULT_BUTTON_CLICK_VOLUME,
+            button_hover_volume: DEFAULT_BUTTON_HOVER_VOLUME,
+        }
+    }
+}
+
+ron_asset_loader!(UiSfxConfigLoader, UiSfxConfig);
+
+// ---------------------------------------------------------------------------
+// Handle resource / SystemParam
+// ---------------------------------------------------------------------------
+
+/// Resource holding the handle to the loaded [`UiSfxConfig`] asset.
+#[derive(Resource)]
+pub struct UiSfxConfigHandle(pub Handle<UiSfxConfig>);
+
+/// SystemParam bundle for accessing [`UiSfxConfig`].
+#[derive(SystemParam)]
+pub struct UiSfxParams<'w> {
+    handle: Option<Res<'w, UiSfxConfigHandle>>,
+    assets: Option<Res<'w, Assets<UiSfxConfig>>>,
+}
+
+impl<'w> UiSfxParams<'w> {
+    /// Returns the currently loaded [`UiSfxConfig`], or `None` while loading.
+    pub fn get(&self) -> Option<&UiSfxConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hot-reload system
+// ---------------------------------------------------------------------------
+
+/// Logs changes to `ui_sfx.ron`; the next button sound uses the new values.
+pub fn hot_reload_ui_sfx_config(
+    mut events: MessageReader<AssetEvent<UiSfxConfig>>,
+    ui_sfx_config: UiSfxParams,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ UI SFX config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                if let Some(config) = ui_sfx_config.get() {
+                    info!(
+                        "🔥 UI SFX config hot-reloaded (click={:.1} dB, hover={:.1} dB)",
+                        config.button_click_volume, config.button_hover_volume,
+                    );
+                }
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ UI SFX config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ui_sfx_config_ron_partial_fields_use_defaults() {
+        let cfg: UiSfxConfig = ron::de::from_str("UiSfxConfig(button_hover_volume: -8.0)")
+            .expect("RON parse must succeed");
+        assert_eq!(cfg.button_hover_volume, -8.0);
+        assert_eq!(cfg.button_click_volume, DEFAULT_BUTTON_CLICK_VOLUME);
+    }
+}
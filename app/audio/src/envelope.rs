@@ -0,0 +1,138 @@
+//! Volume envelopes for starting and stopping sounds smoothly.
+//!
+//! A [`VolumeEnvelope`] holds the attack (fade-in) and release (fade-out)
+//! times of a sound.  It is embedded in the audio configs so it can be tuned
+//! in RON, and applied through [`EnvelopeExt`], which works on any
+//! [`AudioChannel`]:
+//!
+//! ```rust,ignore
+//! // BGM: every start / stop / switch goes through the track's envelope.
+//! bgm_channel.stop_with_envelope(&cfg.title_envelope);
+//! bgm_channel
+//!     .play_with_envelope(handles.game.clone(), &cfg.game_envelope)
+//!     .looped();
+//!
+//! // SFX: a looping alarm can fade out the same way.
+//! sfx_channel.stop_with_envelope(&VolumeEnvelope::new(0.0, 0.4));
+//! ```
+
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+use serde::Deserialize;
+use std::time::Duration;
+
+// ---------------------------------------------------------------------------
+// VolumeEnvelope
+// ---------------------------------------------------------------------------
+
+/// Attack and release times of a sound, in seconds.
+///
+/// Written in RON as `(attack_secs: 0.3, release_secs: 0.5)`; omitted fields
+/// use the defaults.  `0.0` starts or stops the sound immediately.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct VolumeEnvelope {
+    /// Fade-in duration when the sound starts (seconds).
+    pub attack_secs: f32,
+    /// Fade-out duration when the sound stops (seconds).
+    pub release_secs: f32,
+}
+
+const DEFAULT_ATTACK_SECS: f32 = 0.3;
+const DEFAULT_RELEASE_SECS: f32 = 0.5;
+
+impl Default for VolumeEnvelope {
+    fn default() -> Self {
+        Self::new(DEFAULT_ATTACK_SECS, DEFAULT_RELEASE_SECS)
+    }
+}
+
+impl VolumeEnvelope {
+    /// Envelope with the given attack and release times.
+    pub const fn new(attack_secs: f32, release_secs: f32) -> Self {
+        Self {
+            attack_secs,
+            release_secs,
+        }
+    }
+
+    /// Tween for the attack phase.
+    pub fn attack(&self) -> AudioTween {
+        AudioTween::linear(secs(self.attack_secs))
+    }
+
+    /// Tween for the release phase.
+    pub fn release(&self) -> AudioTween {
+        AudioTween::linear(secs(self.release_secs))
+    }
+}
+
+/// `value` seconds as a [`Duration`]; negative or non-finite values become zero.
+fn secs(value: f32) -> Duration {
+    if value.is_finite() && value > 0.0 {
+        Duration::from_secs_f32(value)
+    } else {
+        Duration::ZERO
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Channel extension
+// ---------------------------------------------------------------------------
+
+/// Envelope-aware play / stop for audio channels.
+pub trait EnvelopeExt {
+    /// Starts `source` with the envelope's attack.
+    ///
+    /// The returned command can be configured further (`.looped()`,
+    /// `.with_volume()`, …) before it is queued.
+    fn play_with_envelope(
+        &self,
+        source: Handle<AudioSource>,
+        envelope: &VolumeEnvelope,
+    ) -> PlayAudioCommand<'_>;
+
+    /// Stops everything on the channel with the envelope's release.
+    fn stop_with_envelope(&self, envelope: &VolumeEnvelope);
+}
+
+impl<T: Resource> EnvelopeExt for AudioChannel<T> {
+    fn play_with_envelope(
+        &self,
+        source: Handle<AudioSource>,
+        envelope: &VolumeEnvelope,
+    ) -> PlayAudioCommand<'_> {
+        let mut command = self.play(source);
+        command.fade_in(envelope.attack());
+        command
+    }
+
+    fn stop_with_envelope(&self, envelope: &VolumeEnvelope) {
+        self.stop().fade_out(envelope.release());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_ron_partial_fields_use_defaults() {
+        let env: VolumeEnvelope =
+            ron::de::from_str("(release_secs: 1.5)").expect("RON parse must succeed");
+        assert_eq!(env.release_secs, 1.5);
+        assert_eq!(env.attack_secs, DEFAULT_ATTACK_SECS);
+    }
+
+    #[test]
+    fn test_secs_clamps_invalid_values() {
+        assert_eq!(secs(0.5), Duration::from_millis(500));
+        assert_eq!(secs(0.0), Duration::ZERO);
+        assert_eq!(secs(-1.0), Duration::ZERO);
+        assert_eq!(secs(f32::NAN), Duration::ZERO);
+    }
+}
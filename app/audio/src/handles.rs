@@ -2,9 +2,11 @@
 //!
 //! Defines [`BgmHandles`] and [`SfxHandles`] resources that hold pre-loaded
 //! [`Handle<AudioSource>`] values for every audio file used in the game.
-//! Loading happens once at [`Startup`] via [`load_audio_assets`]; all
-//! subsequent audio systems read these handles rather than hitting the asset
-//! server each frame.
+//! Both are [`AudioCollection`]s: [`load_collection`] queues them at
+//! [`Startup`] and registers every handle with [`TrackedAssets`], so the
+//! Loading screen waits for them and a missing file shows up as a load error
+//! on the title screen.  All subsequent audio systems read these handles
+//! rather than hitting the asset server each frame.
 //!
 //! # Asset paths (relative to the `assets/` directory)
 //!
@@ -27,9 +29,32 @@
 //! | `gameover`      | `sounds/sfx/gameover.wav`      |
 //! | `button_click`  | `sounds/sfx/button_click.wav`  |
 //! | `button_hover`  | `sounds/sfx/button_hover.wav`  |
+//!
+//! An enabled user mod that ships a file at the same path replaces it (see
+//! [`suika_game_core::mods`]).
 
 use bevy::prelude::*;
 use bevy_kira_audio::AudioSource;
+use suika_game_core::loading::TrackedAssets;
+use suika_game_core::mods::ModList;
+
+// ---------------------------------------------------------------------------
+// Collection trait
+// ---------------------------------------------------------------------------
+
+/// A resource of audio handles that is loaded at startup and tracked by the
+/// Loading screen.
+pub trait AudioCollection: Resource + Sized {
+    /// Collection name used in load-error messages.
+    const NAME: &'static str;
+
+    /// Queues every file of the collection on the asset server, taking each
+    /// from an enabled mod where one ships it.
+    fn load(asset_server: &AssetServer, mods: &ModList) -> Self;
+
+    /// Every handle in the collection.
+    fn handles(&self) -> Vec<&Handle<AudioSource>>;
+}
 
 // ---------------------------------------------------------------------------
 // Resources
@@ -37,7 +62,7 @@ use bevy_kira_audio::AudioSource;
 
 /// Handles for all background-music tracks.
 ///
-/// Inserted as a [`Resource`] by [`load_audio_assets`] at startup.
+/// Inserted as a [`Resource`] by [`load_collection`] at startup.
 #[derive(Resource, Debug)]
 pub struct BgmHandles {
     /// Title-screen BGM (`sounds/bgm/title_bgm.ogg`).
@@ -50,7 +75,7 @@ pub struct BgmHandles {
 
 /// Handles for all sound-effect clips.
 ///
-/// Inserted as a [`Resource`] by [`load_audio_assets`] at startup.
+/// Inserted as a [`Resource`] by [`load_collection`] at startup.
 #[derive(Resource, Debug)]
 pub struct SfxHandles {
     /// Fruit-drop sound (`sounds/sfx/drop.wav`).
@@ -77,40 +102,82 @@ pub struct SfxHandles {
     pub button_hover: Handle<AudioSource>,
 }
 
+impl AudioCollection for BgmHandles {
+    const NAME: &'static str = "BGM";
+
+    fn load(asset_server: &AssetServer, mods: &ModList) -> Self {
+        Self {
+            title: asset_server.load(mods.resolve("sounds/bgm/title_bgm.ogg")),
+            game: asset_server.load(mods.resolve("sounds/bgm/game_bgm.ogg")),
+            gameover: asset_server.load(mods.resolve("sounds/bgm/gameover_bgm.ogg")),
+        }
+    }
+
+    fn handles(&self) -> Vec<&Handle<AudioSource>> {
+        vec![&self.title, &self.game, &self.gameover]
+    }
+}
+
+impl AudioCollection for SfxHandles {
+    const NAME: &'static str = "SFX";
+
+    fn load(asset_server: &AssetServer, mods: &ModList) -> Self {
+        Self {
+            drop: asset_server.load(mods.resolve("sounds/sfx/drop.wav")),
+            merge_small: asset_server.load(mods.resolve("sounds/sfx/merge_small.wav")),
+            merge_medium: asset_server.load(mods.resolve("sounds/sfx/merge_medium.wav")),
+            merge_large: asset_server.load(mods.resolve("sounds/sfx/merge_large.wav")),
+            watermelon: asset_server.load(mods.resolve("sounds/sfx/watermelon.wav")),
+            combo: asset_server.load(mods.resolve("sounds/sfx/combo.wav")),
+            gameover: asset_server.load(mods.resolve("sounds/sfx/gameover.wav")),
+            button_click: asset_server.load(mods.resolve("sounds/sfx/button_click.wav")),
+            button_hover: asset_server.load(mods.resolve("sounds/sfx/button_hover.wav")),
+        }
+    }
+
+    fn handles(&self) -> Vec<&Handle<AudioSource>> {
+        vec![
+            &self.drop,
+            &self.merge_small,
+            &self.merge_medium,
+            &self.merge_large,
+            &self.watermelon,
+            &self.combo,
+            &self.gameover,
+            &self.button_click,
+            &self.button_hover,
+        ]
+    }
+}
+
 // ---------------------------------------------------------------------------
 // System
 // ---------------------------------------------------------------------------
 
-/// Startup system — loads all audio assets and inserts [`BgmHandles`] and
-/// [`SfxHandles`] as resources.
+/// Startup system — loads collection `C`, registers its handles with
+/// [`TrackedAssets`] and inserts it as a resource.
 ///
 /// The `AssetServer` returns strong handles immediately; the actual audio data
-/// is loaded asynchronously in the background.  The resources keep the assets
-/// alive for the lifetime of the application (weak handles require explicitly
-/// calling `.clone_weak()` and may allow assets to be unloaded prematurely).
-/// Audio systems that use these handles will silently skip playback if the
-/// asset has not yet finished loading (this is the default bevy_kira_audio
-/// behaviour).
-pub fn load_audio_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
-    commands.insert_resource(BgmHandles {
-        title: asset_server.load("sounds/bgm/title_bgm.ogg"),
-        game: asset_server.load("sounds/bgm/game_bgm.ogg"),
-        gameover: asset_server.load("sounds/bgm/gameover_bgm.ogg"),
-    });
-
-    commands.insert_resource(SfxHandles {
-        drop: asset_server.load("sounds/sfx/drop.wav"),
-        merge_small: asset_server.load("sounds/sfx/merge_small.wav"),
-        merge_medium: asset_server.load("sounds/sfx/merge_medium.wav"),
-        merge_large: asset_server.load("sounds/sfx/merge_large.wav"),
-        watermelon: asset_server.load("sounds/sfx/watermelon.wav"),
-        combo: asset_server.load("sounds/sfx/combo.wav"),
-        gameover: asset_server.load("sounds/sfx/gameover.wav"),
-        button_click: asset_server.load("sounds/sfx/button_click.wav"),
-        button_hover: asset_server.load("sounds/sfx/button_hover.wav"),
-    });
-
-    info!("Audio assets queued for loading (BGM: 3, SFX: 9)");
+/// is loaded asynchronously in the background while the Loading screen waits.
+/// The resource keeps the assets alive for the lifetime of the application.
+pub fn load_collection<C: AudioCollection>(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mods: Option<Res<ModList>>,
+    mut tracked: ResMut<TrackedAssets>,
+) {
+    let no_mods = ModList::default();
+    let collection = C::load(&asset_server, mods.as_deref().unwrap_or(&no_mods));
+    let handles = collection.handles();
+    for handle in &handles {
+        tracked.track(C::NAME, (*handle).clone());
+    }
+    info!(
+        "Audio collection {} queued for loading ({} files)",
+        C::NAME,
+        handles.len()
+    );
+    commands.insert_resource(collection);
 }
 
 // ---------------------------------------------------------------------------
@@ -129,7 +196,11 @@ mod tests {
         // Register the AudioSource asset type so that AssetServer::load can
         // allocate handles in tests (no audio hardware is initialized here).
         app.init_asset::<AudioSource>();
-        app.add_systems(Startup, load_audio_assets);
+        app.init_resource::<TrackedAssets>();
+        app.add_systems(
+            Startup,
+            (load_collection::<BgmHandles>, load_collection::<SfxHandles>),
+        );
         app
     }
 
@@ -140,7 +211,7 @@ mod tests {
 
         assert!(
             app.world().get_resource::<BgmHandles>().is_some(),
-            "BgmHandles resource must exist after load_audio_assets runs"
+            "BgmHandles resource must exist after load_collection runs"
         );
     }
 
@@ -151,7 +222,7 @@ mod tests {
 
         assert!(
             app.world().get_resource::<SfxHandles>().is_some(),
-            "SfxHandles resource must exist after load_audio_assets runs"
+            "SfxHandles resource must exist after load_collection runs"
         );
     }
 
@@ -274,4 +345,13 @@ mod tests {
             "title and gameover BGM handles must differ"
         );
     }
+
+    #[test]
+    fn test_every_handle_is_tracked() {
+        let mut app = setup_app();
+        app.update();
+
+        // 3 BGM tracks + 9 SFX clips
+        assert_eq!(app.world().resource::<TrackedAssets>().len(), 12);
+    }
 }
@@ -11,16 +11,23 @@
 //! |--------|---------------|
 //! | `handles` | Load & store `Handle<AudioSource>` for every asset |
 //! | `bgm`     | BGM playback, state-driven track switching |
+//! | `envelope` | Attack / release volume envelopes for any channel |
+//! | `meter`   | BGM band levels for the HUD visualizer |
+//! | `mixer`   | SFX mixing groups and ducking |
 //! | `sfx`     | SFX playback (merge, combo, UI, game-over) |
 
 use bevy::prelude::*;
 use bevy_kira_audio::{AudioApp, AudioPlugin as KiraAudioPlugin};
-use suika_game_core::prelude::{AppState, SettingsResource};
+use suika_game_core::prelude::{AppState, SettingsResource, TrackedAssets};
+use suika_game_ui::screens::hud::visualizer::{BgmLevels, visualizer_enabled};
 
 pub mod bgm;
 pub mod channels;
 pub mod config;
+pub mod envelope;
 pub mod handles;
+pub mod meter;
+pub mod mixer;
 pub mod sfx;
 
 /// Integrates [`bevy_kira_audio`] into the game and registers all audio systems.
@@ -48,16 +55,23 @@ impl Plugin for GameAudioPlugin {
             // individual sound volumes remain the designer's RON-defined levels.
             .add_audio_channel::<channels::BgmChannel>()
             .add_audio_channel::<channels::SfxChannel>()
-            // Audio config asset type + loader
-            .init_asset::<config::AudioConfig>()
-            .register_asset_loader(config::AudioConfigLoader)
+            // Per-category audio configs (bgm / merge_sfx / ui_sfx) + hot-reload
+            .add_plugins(config::AudioConfigPlugin)
             // Resources
             .init_resource::<bgm::CurrentBgm>()
+            .init_resource::<bgm::BgmPlaylist>()
             .init_resource::<channels::PreviousVolume>()
+            .init_resource::<BgmLevels>()
+            .init_resource::<mixer::SfxMixer>()
             // Startup systems
+            // Audio collections, tracked by the Loading screen
+            .init_resource::<TrackedAssets>()
             .add_systems(
                 Startup,
-                (handles::load_audio_assets, config::load_audio_config),
+                (
+                    handles::load_collection::<handles::BgmHandles>,
+                    handles::load_collection::<handles::SfxHandles>,
+                ),
             )
             // Update systems
             .add_systems(
@@ -67,11 +81,20 @@ impl Plugin for GameAudioPlugin {
                     // (also fires on the first frame after SettingsResource loads).
                     channels::apply_volume_settings.run_if(resource_changed::<SettingsResource>),
                     bgm::switch_bgm_on_state_change.run_if(state_changed::<AppState>),
-                    config::hot_reload_audio_config,
-                    sfx::play_merge_sfx,
+                    (bgm::advance_bgm_playlist, bgm::raise_bgm_intensity)
+                        .after(bgm::switch_bgm_on_state_change),
+                    meter::meter_bgm
+                        .after(bgm::advance_bgm_playlist)
+                        .after(bgm::raise_bgm_intensity)
+                        .run_if(visualizer_enabled),
+                    mixer::tick_sfx_ducking.before(sfx::play_combo_sfx),
+                    // Celebrations first, so pops of the same frame are ducked
+                    sfx::play_merge_sfx.after(sfx::play_combo_sfx),
                     sfx::play_combo_sfx,
+                    sfx::play_combo_broken_sfx.run_if(in_state(AppState::Playing)),
                     sfx::play_ui_sfx,
                     sfx::play_keyboard_ui_sfx,
+                    sfx::play_metronome_tick,
                 ),
             )
             // One-shot systems triggered by state transitions
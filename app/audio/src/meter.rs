@@ -0,0 +1,204 @@
+//! BGM level metering for the HUD visualizer.
+//!
+//! Feeds [`BgmLevels`] while the visualizer is switched on.  kira plays the
+//! music on its own thread and exposes no meter, so the levels are measured
+//! from the audio data instead:
+//! [`CurrentBgm::playing`] names the source and instance of the music, the
+//! instance's playback position says where in the source the listener is,
+//! and the window of frames just before that position is analysed.
+//!
+//! ```text
+//! source frames  ──────────[ METER_WINDOW ]▲──────────
+//!                                          playback position
+//!                      │ Goertzel filter per band
+//!                      ▼
+//! BgmLevels.bars   ▂▅█▆▃▂▁▁   (63 Hz … 8 kHz, scaled by the BGM volume)
+//! ```
+//!
+//! Each band is one Goertzel filter — a single-frequency DFT bin — so the
+//! whole meter costs a few thousand multiply-adds per frame.  Bars jump up
+//! at once and fall back at [`FALL_PER_SEC`], which reads better than raw
+//! per-frame values.
+
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+use suika_game_core::resources::settings::SettingsResource;
+use suika_game_ui::screens::hud::visualizer::{BgmLevels, VISUALIZER_BARS};
+
+use crate::bgm::CurrentBgm;
+use crate::channels::BgmChannel;
+
+/// Frames analysed per update (about 23 ms at 44.1 kHz).
+pub const METER_WINDOW: usize = 1024;
+
+/// Centre frequency of each visualizer band, in Hz.
+pub const BAND_FREQUENCIES: [f32; VISUALIZER_BARS] = [
+    63.0, 125.0, 250.0, 500.0, 1_000.0, 2_000.0, 4_000.0, 8_000.0,
+];
+
+/// Band amplitude shown as an empty bar, in dBFS.
+const FLOOR_DB: f32 = -60.0;
+
+/// How fast a bar falls back, in levels per second.
+pub const FALL_PER_SEC: f32 = 2.5;
+
+// ---------------------------------------------------------------------------
+// System
+// ---------------------------------------------------------------------------
+
+/// Measures the playing BGM and writes the band levels to [`BgmLevels`].
+///
+/// Register with `run_if(visualizer_enabled)`.  Silence (no music, a paused
+/// or stopping track, a source still loading, BGM volume 0) lets the bars
+/// fall to zero.
+pub fn meter_bgm(
+    time: Res<Time>,
+    current_bgm: Res<CurrentBgm>,
+    bgm_channel: Res<AudioChannel<BgmChannel>>,
+    sources: Res<Assets<AudioSource>>,
+    settings: Res<SettingsResource>,
+    mut levels: ResMut<BgmLevels>,
+) {
+    let mut target = [0.0; VISUALIZER_BARS];
+    if let Some(playing) = current_bgm.playing.as_ref()
+        && let PlaybackState::Playing { position } = bgm_channel.state(&playing.instance)
+        && let Some(source) = sources.get(&playing.source)
+    {
+        let sound = &source.sound;
+        let end = ((position * f64::from(sound.sample_rate)) as usize).min(sound.frames.len());
+        let start = end.saturating_sub(METER_WINDOW);
+        let samples: Vec<f32> = sound.frames[start..end]
+            .iter()
+            .map(|frame| (frame.left + frame.right) * 0.5)
+            .collect();
+        let volume = f32::from(settings.bgm_volume) / 10.0;
+        for (level, band) in target
+            .iter_mut()
+            .zip(band_levels(&samples, sound.sample_rate))
+        {
+            *level = band * volume;
+        }
+    }
+
+    let fall = FALL_PER_SEC * time.delta_secs();
+    let next = fall_towards(&levels.bars, &target, fall);
+    if next != levels.bars {
+        levels.bars = next;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Level of each [`BAND_FREQUENCIES`] band in `samples`, `0.0` at
+/// [`FLOOR_DB`] or below and `1.0` for a full-scale sine.
+pub fn band_levels(samples: &[f32], sample_rate: u32) -> [f32; VISUALIZER_BARS] {
+    let mut levels = [0.0; VISUALIZER_BARS];
+    if samples.is_empty() || sample_rate == 0 {
+        return levels;
+    }
+    for (level, &frequency) in levels.iter_mut().zip(BAND_FREQUENCIES.iter()) {
+        let amplitude = goertzel(samples, frequency, sample_rate as f32);
+        *level = amplitude_to_level(amplitude);
+    }
+    levels
+}
+
+/// Amplitude of the `frequency` component of `samples` (Goertzel
+/// algorithm), `1.0` for a full-scale sine.
+fn goertzel(samples: &[f32], frequency: f32, sample_rate: f32) -> f32 {
+    let coeff = 2.0 * (std::f32::consts::TAU * frequency / sample_rate).cos();
+    let (mut s1, mut s2) = (0.0_f32, 0.0_f32);
+    for &sample in samples {
+        let s0 = sample + coeff * s1 - s2;
+        s2 = s1;
+        s1 = s0;
+    }
+    let power = (s1 * s1 + s2 * s2 - coeff * s1 * s2).max(0.0);
+    2.0 * power.sqrt() / samples.len() as f32
+}
+
+/// Maps an amplitude onto `0.0..=1.0` on a decibel scale.
+fn amplitude_to_level(amplitude: f32) -> f32 {
+    if amplitude <= 0.0 {
+        return 0.0;
+    }
+    let db = 20.0 * amplitude.log10();
+    ((db - FLOOR_DB) / -FLOOR_DB).clamp(0.0, 1.0)
+}
+
+/// Each bar rises to its target at once and falls by at most `fall`.
+fn fall_towards(
+    current: &[f32; VISUALIZER_BARS],
+    target: &[f32; VISUALIZER_BARS],
+    fall: f32,
+) -> [f32; VISUALIZER_BARS] {
+    std::array::from_fn(|i| target[i].max(current[i] - fall).max(0.0))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 44_100;
+
+    fn sine(frequency: f32, amplitude: f32) -> Vec<f32> {
+        (0..METER_WINDOW)
+            .map(|i| {
+                let t = i as f32 / RATE as f32;
+                amplitude * (std::f32::consts::TAU * frequency * t).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sine_lights_its_own_band() {
+        let levels = band_levels(&sine(1_000.0, 1.0), RATE);
+        let loudest = levels
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| i);
+        assert_eq!(loudest, Some(4));
+        assert!(levels[4] > 0.9);
+        assert!(levels[0] < levels[4]);
+    }
+
+    #[test]
+    fn test_silence_is_empty() {
+        assert_eq!(
+            band_levels(&[0.0; METER_WINDOW], RATE),
+            [0.0; VISUALIZER_BARS]
+        );
+        assert_eq!(band_levels(&[], RATE), [0.0; VISUALIZER_BARS]);
+    }
+
+    #[test]
+    fn test_amplitude_to_level_uses_decibels() {
+        assert_eq!(amplitude_to_level(1.0), 1.0);
+        assert!((amplitude_to_level(0.001) - 0.0).abs() < 1e-4);
+        assert!((amplitude_to_level(0.0316) - 0.5).abs() < 0.01);
+        assert_eq!(amplitude_to_level(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_bars_rise_at_once_and_fall_slowly() {
+        let mut current = [0.0; VISUALIZER_BARS];
+        let mut target = [0.0; VISUALIZER_BARS];
+        target[0] = 0.8;
+        current[1] = 0.5;
+
+        let next = fall_towards(&current, &target, 0.1);
+        assert_eq!(next[0], 0.8);
+        assert!((next[1] - 0.4).abs() < 1e-6);
+        assert_eq!(
+            fall_towards(&next, &[0.0; VISUALIZER_BARS], 1.0),
+            [0.0; VISUALIZER_BARS]
+        );
+    }
+}
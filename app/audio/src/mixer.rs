@@ -0,0 +1,181 @@
+//! SFX mixing groups and ducking.
+//!
+//! Every sound effect is played in one of three [`SfxGroup`]s, whose volume
+//! and priority come from `mixing.ron` ([`MixingConfig`]):
+//!
+//! | Group                     | Sounds                                   | Default priority |
+//! |---------------------------|------------------------------------------|------------------|
+//! | [`SfxGroup::Celebration`] | watermelon fanfare, combo chime          | 2                |
+//! | [`SfxGroup::Gameplay`]    | merge pops, combo break, game-over sting | 1                |
+//! | [`SfxGroup::Ui`]          | button click / hover                     | 0                |
+//!
+//! Playing a sound ducks every group of lower priority by
+//! [`MixingConfig::duck_volume`] for [`MixingConfig::duck_secs`], so a
+//! watermelon fanfare is not buried under the merge pops of the same chain.
+//! Ducking applies to sounds started while it lasts; sounds already playing
+//! keep their volume, which is fine for short one-shots.
+//!
+//! SFX systems ask [`SfxMixer::play`] for the group's volume offset right
+//! before starting a sound and add it to the designer and user volumes:
+//!
+//! ```text
+//! effective_dB = design_dB + user_sfx_dB + group volume (+ duck_volume while ducked)
+//! ```
+
+use bevy::prelude::*;
+
+use crate::config::{MixGroupConfig, MixingConfig};
+
+/// Mixing group of a sound effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SfxGroup {
+    /// Merge pops and other sounds of ordinary play.
+    Gameplay,
+    /// Menu sounds.
+    Ui,
+    /// Big moments: watermelon fanfare and combo chimes.
+    Celebration,
+}
+
+impl SfxGroup {
+    /// Every group, in [`SfxMixer`] slot order.
+    pub const ALL: [SfxGroup; 3] = [Self::Gameplay, Self::Ui, Self::Celebration];
+
+    /// The group's settings in `cfg`.
+    pub fn config(self, cfg: &MixingConfig) -> MixGroupConfig {
+        match self {
+            Self::Gameplay => cfg.gameplay,
+            Self::Ui => cfg.ui,
+            Self::Celebration => cfg.celebration,
+        }
+    }
+
+    /// Slot of the group in [`SfxMixer`].
+    fn index(self) -> usize {
+        match self {
+            Self::Gameplay => 0,
+            Self::Ui => 1,
+            Self::Celebration => 2,
+        }
+    }
+}
+
+/// Seconds each [`SfxGroup`] stays ducked.
+///
+/// Set by [`SfxMixer::play`]; counted down by [`tick_sfx_ducking`].
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct SfxMixer {
+    duck_remaining: [f32; 3],
+}
+
+impl SfxMixer {
+    /// Volume offset (dB) for a sound of `group` about to play, ducking the
+    /// groups of lower priority.
+    pub fn play(&mut self, group: SfxGroup, cfg: &MixingConfig) -> f32 {
+        let offset = self.volume(group, cfg);
+        let priority = group.config(cfg).priority;
+        for other in SfxGroup::ALL {
+            if other.config(cfg).priority < priority {
+                let remaining = &mut self.duck_remaining[other.index()];
+                *remaining = remaining.max(cfg.duck_secs);
+            }
+        }
+        offset
+    }
+
+    /// Current volume offset (dB) of `group`, including ducking.
+    pub fn volume(&self, group: SfxGroup, cfg: &MixingConfig) -> f32 {
+        let duck = if self.is_ducked(group) {
+            cfg.duck_volume
+        } else {
+            0.0
+        };
+        group.config(cfg).volume + duck
+    }
+
+    /// Whether `group` is ducked right now.
+    pub fn is_ducked(&self, group: SfxGroup) -> bool {
+        self.duck_remaining[group.index()] > 0.0
+    }
+}
+
+/// Counts the [`SfxMixer`] duck timers down.
+pub fn tick_sfx_ducking(time: Res<Time>, mut mixer: ResMut<SfxMixer>) {
+    let dt = time.delta_secs();
+    if mixer.duck_remaining.iter().all(|&t| t <= 0.0) {
+        return;
+    }
+    for remaining in &mut mixer.duck_remaining {
+        *remaining = (*remaining - dt).max(0.0);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_celebration_ducks_lower_groups() {
+        let cfg = MixingConfig::default();
+        let mut mixer = SfxMixer::default();
+        assert_eq!(mixer.play(SfxGroup::Gameplay, &cfg), 0.0);
+        assert!(mixer.is_ducked(SfxGroup::Ui));
+        assert!(!mixer.is_ducked(SfxGroup::Celebration));
+
+        assert_eq!(mixer.play(SfxGroup::Celebration, &cfg), 0.0);
+        assert!(mixer.is_ducked(SfxGroup::Gameplay));
+        assert_eq!(
+            mixer.play(SfxGroup::Gameplay, &cfg),
+            cfg.duck_volume,
+            "a merge pop during a fanfare is ducked"
+        );
+    }
+
+    #[test]
+    fn test_group_volume_is_added() {
+        let cfg = MixingConfig {
+            ui: MixGroupConfig {
+                volume: -3.0,
+                priority: 5,
+            },
+            ..default()
+        };
+        let mut mixer = SfxMixer::default();
+        assert_eq!(mixer.play(SfxGroup::Ui, &cfg), -3.0);
+        // UI now outranks everything
+        assert!(mixer.is_ducked(SfxGroup::Celebration));
+    }
+
+    #[test]
+    fn test_ducking_wears_off() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<SfxMixer>()
+            .add_systems(Update, tick_sfx_ducking);
+        let cfg = MixingConfig::default();
+        app.world_mut()
+            .resource_mut::<SfxMixer>()
+            .play(SfxGroup::Celebration, &cfg);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<SfxMixer>()
+                .is_ducked(SfxGroup::Gameplay)
+        );
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(cfg.duck_secs * 2.0));
+        app.update();
+        assert!(
+            !app.world()
+                .resource::<SfxMixer>()
+                .is_ducked(SfxGroup::Gameplay)
+        );
+    }
+}
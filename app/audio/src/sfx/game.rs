@@ -1,14 +1,18 @@
-//! Game SFX: merge, combo, and game-over sounds.
+//! Game SFX: merge, combo, combo-break and game-over sounds.
+//!
+//! Every one starts [`sfx_start_secs`] into its clip, making up for the
+//! player's SFX delay (see the calibration screen).
 
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
-use suika_game_core::events::{FruitMergeEvent, ScoreEarnedEvent};
+use suika_game_core::events::{ComboBrokenEvent, FruitMergeEvent, ScoreEarnedEvent};
 use suika_game_core::resources::settings::SettingsResource;
 
 use super::MergeSfxCategory;
-use crate::channels::{SfxChannel, volume_to_db};
-use crate::config::{AudioConfig, AudioConfigHandle};
+use crate::channels::{SfxChannel, sfx_start_secs, volume_to_db};
+use crate::config::{MergeSfxConfig, MergeSfxParams, MixingConfig, MixingParams};
 use crate::handles::SfxHandles;
+use crate::mixer::{SfxGroup, SfxMixer};
 
 /// Plays a merge sound effect in response to each [`FruitMergeEvent`].
 ///
@@ -17,54 +21,69 @@ use crate::handles::SfxHandles;
 /// playback-rate (pitch) shift.  When two Melons merge into a Watermelon,
 /// the special `watermelon.wav` fanfare is played at full pitch instead.
 ///
-/// Volume and pitch values are read from [`AudioConfig`] at call time, so
+/// Volume and pitch values are read from [`MergeSfxConfig`] at call time, so
 /// they take effect immediately on the next merge after editing
-/// `assets/config/audio.ron` (hot-reload).
+/// `assets/config/audio/merge_sfx.ron` (hot-reload).
+///
+/// Pops play in [`SfxGroup::Gameplay`], the fanfare in
+/// [`SfxGroup::Celebration`].  Fanfares are started first, so pops of the
+/// same frame are already ducked.
 pub fn play_merge_sfx(
     mut merge_events: MessageReader<FruitMergeEvent>,
     sfx_channel: Res<AudioChannel<SfxChannel>>,
-    sfx_handles: Option<Res<SfxHandles>>,
-    audio_config_handle: Option<Res<AudioConfigHandle>>,
-    audio_config_assets: Res<Assets<AudioConfig>>,
+    sfx_handles: Res<SfxHandles>,
+    merge_sfx_config: MergeSfxParams,
+    mixing_config: MixingParams,
+    mut mixer: ResMut<SfxMixer>,
     settings: Res<SettingsResource>,
 ) {
-    let Some(sfx_handles) = sfx_handles else {
-        return;
-    };
-
     // Resolve config, falling back to defaults while the asset loads.
-    let default_cfg = AudioConfig::default();
-    let cfg = audio_config_handle
-        .as_ref()
-        .and_then(|h| audio_config_assets.get(&h.0))
-        .unwrap_or(&default_cfg);
+    let default_cfg = MergeSfxConfig::default();
+    let cfg = merge_sfx_config.get().unwrap_or(&default_cfg);
+    let default_mixing = MixingConfig::default();
+    let mixing = mixing_config.get().unwrap_or(&default_mixing);
 
     let user_sfx_db = volume_to_db(settings.sfx_volume);
-    for event in merge_events.read() {
-        match MergeSfxCategory::from_fruit(event.fruit_type) {
+    let start = sfx_start_secs(&settings);
+    let mut categories: Vec<MergeSfxCategory> = merge_events
+        .read()
+        .map(|event| MergeSfxCategory::from_fruit(event.fruit_type))
+        .collect();
+    categories.sort_by_key(|category| !matches!(category, MergeSfxCategory::Watermelon));
+    for category in categories {
+        let group = match category {
+            MergeSfxCategory::Watermelon => SfxGroup::Celebration,
+            _ => SfxGroup::Gameplay,
+        };
+        let group_db = mixer.play(group, mixing);
+        match category {
             MergeSfxCategory::Small => {
                 sfx_channel
                     .play(sfx_handles.merge_small.clone())
-                    .with_volume(cfg.sfx_merge_small_volume + user_sfx_db)
-                    .with_playback_rate(cfg.sfx_merge_small_pitch);
+                    .with_volume(cfg.merge_small_volume + user_sfx_db + group_db)
+                    .start_from(start)
+                    .with_playback_rate(cfg.merge_small_pitch);
             }
             MergeSfxCategory::Medium => {
                 sfx_channel
                     .play(sfx_handles.merge_medium.clone())
-                    .with_volume(cfg.sfx_merge_medium_volume + user_sfx_db)
-                    .with_playback_rate(cfg.sfx_merge_medium_pitch);
+                    .with_volume(cfg.merge_medium_volume + user_sfx_db + group_db)
+                    .start_from(start)
+                    .with_playback_rate(cfg.merge_medium_pitch);
             }
             MergeSfxCategory::Large => {
                 sfx_channel
                     .play(sfx_handles.merge_large.clone())
-                    .with_volume(cfg.sfx_merge_large_volume + user_sfx_db)
-                    .with_playback_rate(cfg.sfx_merge_large_pitch);
+                    .with_volume(cfg.merge_large_volume + user_sfx_db + group_db)
+                    .start_from(start)
+                    .with_playback_rate(cfg.merge_large_pitch);
             }
             MergeSfxCategory::Watermelon => {
                 // Special fanfare — no pitch shift, played at full original pitch.
                 sfx_channel
                     .play(sfx_handles.watermelon.clone())
-                    .with_volume(cfg.sfx_watermelon_volume + user_sfx_db);
+                    .with_volume(cfg.watermelon_volume + user_sfx_db + group_db)
+                    .start_from(start);
                 info!("Watermelon! Playing fanfare SFX");
             }
         }
@@ -79,26 +98,24 @@ pub fn play_merge_sfx(
 ///
 /// **Pitch formula:**
 /// ```text
-/// pitch = 1.0 + (combo_count × sfx_combo_pitch_step).min(sfx_combo_pitch_cap)
+/// pitch = 1.0 + (combo_count × combo_pitch_step).min(combo_pitch_cap)
 /// ```
 /// With defaults: combo 2 → 1.2×, combo 3 → 1.3×, combo 5+ → 1.5×.
+///
+/// The chime is a [`SfxGroup::Celebration`] sound.
 pub fn play_combo_sfx(
     mut score_events: MessageReader<ScoreEarnedEvent>,
     sfx_channel: Res<AudioChannel<SfxChannel>>,
-    sfx_handles: Option<Res<SfxHandles>>,
-    audio_config_handle: Option<Res<AudioConfigHandle>>,
-    audio_config_assets: Res<Assets<AudioConfig>>,
+    sfx_handles: Res<SfxHandles>,
+    merge_sfx_config: MergeSfxParams,
+    mixing_config: MixingParams,
+    mut mixer: ResMut<SfxMixer>,
     settings: Res<SettingsResource>,
 ) {
-    let Some(sfx_handles) = sfx_handles else {
-        return;
-    };
-
-    let default_cfg = AudioConfig::default();
-    let cfg = audio_config_handle
-        .as_ref()
-        .and_then(|h| audio_config_assets.get(&h.0))
-        .unwrap_or(&default_cfg);
+    let default_cfg = MergeSfxConfig::default();
+    let cfg = merge_sfx_config.get().unwrap_or(&default_cfg);
+    let default_mixing = MixingConfig::default();
+    let mixing = mixing_config.get().unwrap_or(&default_mixing);
 
     let user_sfx_db = volume_to_db(settings.sfx_volume);
     for event in score_events.read() {
@@ -108,17 +125,53 @@ pub fn play_combo_sfx(
         }
 
         let pitch_offset =
-            (event.combo_count as f64 * cfg.sfx_combo_pitch_step).min(cfg.sfx_combo_pitch_cap);
+            (event.combo_count as f64 * cfg.combo_pitch_step).min(cfg.combo_pitch_cap);
         // Guard against misconfigured negative offsets; pitch must stay > 0.
         let pitch = (1.0_f64 + pitch_offset).max(0.1);
 
+        let group_db = mixer.play(SfxGroup::Celebration, mixing);
         sfx_channel
             .play(sfx_handles.combo.clone())
-            .with_volume(cfg.sfx_combo_volume + user_sfx_db)
+            .with_volume(cfg.combo_volume + user_sfx_db + group_db)
+            .start_from(sfx_start_secs(&settings))
             .with_playback_rate(pitch);
     }
 }
 
+/// Plays a subtle falling "drop" when a long combo expires.
+///
+/// Reuses the drop clip at [`MergeSfxConfig::combo_break_volume`] and
+/// [`MergeSfxConfig::combo_break_pitch`] so the break is noticeable but
+/// quieter than any merge sound.
+pub fn play_combo_broken_sfx(
+    mut broken_events: MessageReader<ComboBrokenEvent>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+    sfx_handles: Res<SfxHandles>,
+    merge_sfx_config: MergeSfxParams,
+    mixing_config: MixingParams,
+    mut mixer: ResMut<SfxMixer>,
+    settings: Res<SettingsResource>,
+) {
+    // At most one break can happen per frame; drain the reader regardless.
+    if broken_events.read().last().is_none() {
+        return;
+    }
+
+    let default_cfg = MergeSfxConfig::default();
+    let cfg = merge_sfx_config.get().unwrap_or(&default_cfg);
+    let default_mixing = MixingConfig::default();
+    let group_db = mixer.play(
+        SfxGroup::Gameplay,
+        mixing_config.get().unwrap_or(&default_mixing),
+    );
+
+    sfx_channel
+        .play(sfx_handles.drop.clone())
+        .with_volume(cfg.combo_break_volume + volume_to_db(settings.sfx_volume) + group_db)
+        .start_from(sfx_start_secs(&settings))
+        .with_playback_rate(cfg.combo_break_pitch);
+}
+
 /// Plays the game-over sound effect once when the game transitions to
 /// [`AppState::GameOver`].
 ///
@@ -126,24 +179,24 @@ pub fn play_combo_sfx(
 /// exactly once per game-over, regardless of frame rate.
 pub fn play_gameover_sfx(
     sfx_channel: Res<AudioChannel<SfxChannel>>,
-    sfx_handles: Option<Res<SfxHandles>>,
-    audio_config_handle: Option<Res<AudioConfigHandle>>,
-    audio_config_assets: Res<Assets<AudioConfig>>,
+    sfx_handles: Res<SfxHandles>,
+    merge_sfx_config: MergeSfxParams,
+    mixing_config: MixingParams,
+    mut mixer: ResMut<SfxMixer>,
     settings: Res<SettingsResource>,
 ) {
-    let Some(sfx_handles) = sfx_handles else {
-        return;
-    };
-
-    let default_cfg = AudioConfig::default();
-    let cfg = audio_config_handle
-        .as_ref()
-        .and_then(|h| audio_config_assets.get(&h.0))
-        .unwrap_or(&default_cfg);
+    let default_cfg = MergeSfxConfig::default();
+    let cfg = merge_sfx_config.get().unwrap_or(&default_cfg);
+    let default_mixing = MixingConfig::default();
+    let group_db = mixer.play(
+        SfxGroup::Gameplay,
+        mixing_config.get().unwrap_or(&default_mixing),
+    );
 
     sfx_channel
         .play(sfx_handles.gameover.clone())
-        .with_volume(cfg.sfx_gameover_volume + volume_to_db(settings.sfx_volume));
+        .with_volume(cfg.gameover_volume + volume_to_db(settings.sfx_volume) + group_db)
+        .start_from(sfx_start_secs(&settings));
 
     info!("Game-over SFX playing");
 }
@@ -236,28 +289,25 @@ mod tests {
 
     #[test]
     fn test_default_pitch_values() {
-        let cfg = AudioConfig::default();
+        let cfg = MergeSfxConfig::default();
         assert!(
-            cfg.sfx_merge_small_pitch > cfg.sfx_merge_medium_pitch,
+            cfg.merge_small_pitch > cfg.merge_medium_pitch,
             "small pitch must be higher than medium"
         );
         assert!(
-            cfg.sfx_merge_medium_pitch > cfg.sfx_merge_large_pitch,
+            cfg.merge_medium_pitch > cfg.merge_large_pitch,
             "medium pitch must be higher than large"
         );
-        assert!(
-            cfg.sfx_merge_large_pitch > 0.0,
-            "large pitch must be positive"
-        );
+        assert!(cfg.merge_large_pitch > 0.0, "large pitch must be positive");
     }
 
     #[test]
     fn test_combo_pitch_formula_at_various_counts() {
-        let cfg = AudioConfig::default();
+        let cfg = MergeSfxConfig::default();
 
         // Helper that mirrors the in-system formula.
         let combo_pitch = |count: u32| -> f64 {
-            1.0 + (count as f64 * cfg.sfx_combo_pitch_step).min(cfg.sfx_combo_pitch_cap)
+            1.0 + (count as f64 * cfg.combo_pitch_step).min(cfg.combo_pitch_cap)
         };
 
         // combo_count = 1 is skipped by the system guard (`< 2`), but the
@@ -270,8 +320,8 @@ mod tests {
             "pitch must increase with combo"
         );
 
-        // Pitch caps at 1.0 + sfx_combo_pitch_cap.
-        let max = 1.0 + cfg.sfx_combo_pitch_cap;
+        // Pitch caps at 1.0 + combo_pitch_cap.
+        let max = 1.0 + cfg.combo_pitch_cap;
         assert!(
             (combo_pitch(100) - max).abs() < f64::EPSILON,
             "pitch must be capped at {max}"
@@ -280,11 +330,8 @@ mod tests {
 
     #[test]
     fn test_combo_pitch_step_and_cap_are_positive() {
-        let cfg = AudioConfig::default();
-        assert!(
-            cfg.sfx_combo_pitch_step > 0.0,
-            "pitch step must be positive"
-        );
-        assert!(cfg.sfx_combo_pitch_cap > 0.0, "pitch cap must be positive");
+        let cfg = MergeSfxConfig::default();
+        assert!(cfg.combo_pitch_step > 0.0, "pitch step must be positive");
+        assert!(cfg.combo_pitch_cap > 0.0, "pitch cap must be positive");
     }
 }
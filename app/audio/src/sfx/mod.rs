@@ -7,8 +7,8 @@
 //!
 //! | Module | Systems |
 //! |--------|---------|
-//! | [`game`] | [`play_merge_sfx`], [`play_combo_sfx`], [`play_gameover_sfx`] |
-//! | [`ui`]   | [`play_ui_sfx`], [`play_keyboard_ui_sfx`] |
+//! | [`game`] | [`play_merge_sfx`], [`play_combo_sfx`], [`play_combo_broken_sfx`], [`play_gameover_sfx`] |
+//! | [`ui`]   | [`play_ui_sfx`], [`play_keyboard_ui_sfx`], [`play_metronome_tick`] |
 
 pub mod game;
 pub mod ui;
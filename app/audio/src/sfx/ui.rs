@@ -1,13 +1,16 @@
-//! UI SFX: button hover and click sounds.
+//! UI SFX: button hover and click sounds, and the calibration metronome.
 
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
 use suika_game_core::resources::settings::SettingsResource;
 use suika_game_ui::components::{KeyboardFocusIndex, MenuButton};
+use suika_game_ui::focus::confirm_pressed;
+use suika_game_ui::screens::calibration::MetronomeBeat;
 
 use crate::channels::{SfxChannel, volume_to_db};
-use crate::config::{AudioConfig, AudioConfigHandle};
+use crate::config::{MixingConfig, MixingParams, UiSfxConfig, UiSfxParams};
 use crate::handles::SfxHandles;
+use crate::mixer::{SfxGroup, SfxMixer};
 
 /// Plays sound effects in response to button hover and click interactions.
 ///
@@ -16,36 +19,36 @@ use crate::handles::SfxHandles;
 ///
 /// - [`Interaction::Hovered`] → `button_hover.wav`
 /// - [`Interaction::Pressed`] → `button_click.wav`
+///
+/// Both are [`SfxGroup::Ui`] sounds.
 pub fn play_ui_sfx(
     interaction_query: Query<&Interaction, (Changed<Interaction>, With<MenuButton>)>,
     sfx_channel: Res<AudioChannel<SfxChannel>>,
-    sfx_handles: Option<Res<SfxHandles>>,
-    audio_config_handle: Option<Res<AudioConfigHandle>>,
-    audio_config_assets: Res<Assets<AudioConfig>>,
+    sfx_handles: Res<SfxHandles>,
+    ui_sfx_config: UiSfxParams,
+    mixing_config: MixingParams,
+    mut mixer: ResMut<SfxMixer>,
     settings: Res<SettingsResource>,
 ) {
-    let Some(sfx_handles) = sfx_handles else {
-        return;
-    };
-
-    let default_cfg = AudioConfig::default();
-    let cfg = audio_config_handle
-        .as_ref()
-        .and_then(|h| audio_config_assets.get(&h.0))
-        .unwrap_or(&default_cfg);
+    let default_cfg = UiSfxConfig::default();
+    let cfg = ui_sfx_config.get().unwrap_or(&default_cfg);
+    let default_mixing = MixingConfig::default();
+    let mixing = mixing_config.get().unwrap_or(&default_mixing);
 
     let user_sfx_db = volume_to_db(settings.sfx_volume);
     for interaction in interaction_query.iter() {
         match *interaction {
             Interaction::Pressed => {
+                let group_db = mixer.play(SfxGroup::Ui, mixing);
                 sfx_channel
                     .play(sfx_handles.button_click.clone())
-                    .with_volume(cfg.sfx_button_click_volume + user_sfx_db);
+                    .with_volume(cfg.button_click_volume + user_sfx_db + group_db);
             }
             Interaction::Hovered => {
+                let group_db = mixer.play(SfxGroup::Ui, mixing);
                 sfx_channel
                     .play(sfx_handles.button_hover.clone())
-                    .with_volume(cfg.sfx_button_hover_volume + user_sfx_db);
+                    .with_volume(cfg.button_hover_volume + user_sfx_db + group_db);
             }
             Interaction::None => {}
         }
@@ -56,21 +59,25 @@ pub fn play_ui_sfx(
 ///
 /// This system is the keyboard counterpart to [`play_ui_sfx`].  Bevy's
 /// [`Interaction`] component is only updated by pointer devices, so
-/// W / S / Arrow keys and Enter must be handled independently.
+/// W / A / S / D / Arrow keys, the gamepad D-pad / stick and the confirm
+/// buttons must be handled independently.
 ///
-/// - W / S / Up / Down → `button_hover.wav`, **only when the focused button
-///   index actually changes**.
-/// - Enter → `button_click.wav` (confirms the currently focused button).
+/// - Focus moves → `button_hover.wav`, **only when the focused button index
+///   actually changes**.
+/// - Enter / gamepad south → `button_click.wav` (confirms the currently
+///   focused button).
 #[allow(clippy::too_many_arguments)]
 pub fn play_keyboard_ui_sfx(
     keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
     button_query: Query<(), With<MenuButton>>,
     focus: Option<Res<KeyboardFocusIndex>>,
     mut prev_focus: Local<Option<usize>>,
     sfx_channel: Res<AudioChannel<SfxChannel>>,
-    sfx_handles: Option<Res<SfxHandles>>,
-    audio_config_handle: Option<Res<AudioConfigHandle>>,
-    audio_config_assets: Res<Assets<AudioConfig>>,
+    sfx_handles: Res<SfxHandles>,
+    ui_sfx_config: UiSfxParams,
+    mixing_config: MixingParams,
+    mut mixer: ResMut<SfxMixer>,
     settings: Res<SettingsResource>,
 ) {
     // No menu buttons on screen — reset tracking and bail.
@@ -81,56 +88,79 @@ pub fn play_keyboard_ui_sfx(
 
     let current = focus.as_ref().map(|r| r.0).unwrap_or(0);
 
-    let Some(sfx_handles) = sfx_handles else {
-        *prev_focus = Some(current);
-        return;
-    };
-
-    let default_cfg = AudioConfig::default();
-    let cfg = audio_config_handle
-        .as_ref()
-        .and_then(|h| audio_config_assets.get(&h.0))
-        .unwrap_or(&default_cfg);
+    let default_cfg = UiSfxConfig::default();
+    let cfg = ui_sfx_config.get().unwrap_or(&default_cfg);
+    let default_mixing = MixingConfig::default();
+    let mixing = mixing_config.get().unwrap_or(&default_mixing);
 
     let user_sfx_db = volume_to_db(settings.sfx_volume);
 
     // Hover sound: only when the focus index actually moved.
     let old = prev_focus.replace(current);
     if old.is_some_and(|p| p != current) {
+        let group_db = mixer.play(SfxGroup::Ui, mixing);
         sfx_channel
             .play(sfx_handles.button_hover.clone())
-            .with_volume(cfg.sfx_button_hover_volume + user_sfx_db);
+            .with_volume(cfg.button_hover_volume + user_sfx_db + group_db);
     }
 
-    // Confirm key → click sound.
-    if keyboard.just_pressed(KeyCode::Enter) {
+    // Confirm key / button → click sound.
+    if confirm_pressed(&keyboard, gamepads.iter()) {
+        let group_db = mixer.play(SfxGroup::Ui, mixing);
         sfx_channel
             .play(sfx_handles.button_click.clone())
-            .with_volume(cfg.sfx_button_click_volume + user_sfx_db);
+            .with_volume(cfg.button_click_volume + user_sfx_db + group_db);
     }
 }
 
+/// Plays a click on every [`MetronomeBeat`] of the calibration screen.
+///
+/// Deliberately ignores [`SettingsResource::sfx_latency_ms`]: the screen
+/// measures the raw delay by showing its flash that much after the beat.
+pub fn play_metronome_tick(
+    mut beats: MessageReader<MetronomeBeat>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+    sfx_handles: Res<SfxHandles>,
+    ui_sfx_config: UiSfxParams,
+    mixing_config: MixingParams,
+    mut mixer: ResMut<SfxMixer>,
+    settings: Res<SettingsResource>,
+) {
+    if beats.read().last().is_none() {
+        return;
+    }
+
+    let default_cfg = UiSfxConfig::default();
+    let cfg = ui_sfx_config.get().unwrap_or(&default_cfg);
+    let default_mixing = MixingConfig::default();
+    let group_db = mixer.play(SfxGroup::Ui, mixing_config.get().unwrap_or(&default_mixing));
+
+    sfx_channel
+        .play(sfx_handles.button_click.clone())
+        .with_volume(cfg.button_click_volume + volume_to_db(settings.sfx_volume) + group_db);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_ui_sfx_volumes_are_audible_and_quiet() {
-        let cfg = AudioConfig::default();
+        let cfg = UiSfxConfig::default();
         assert!(
-            cfg.sfx_button_click_volume <= 0.0,
+            cfg.button_click_volume <= 0.0,
             "button click volume should be ≤ 0 dB (quiet)"
         );
         assert!(
-            cfg.sfx_button_click_volume > -30.0,
+            cfg.button_click_volume > -30.0,
             "button click volume should be > -30 dB (audible)"
         );
         assert!(
-            cfg.sfx_button_hover_volume <= 0.0,
+            cfg.button_hover_volume <= 0.0,
             "button hover volume should be ≤ 0 dB (quiet)"
         );
         assert!(
-            cfg.sfx_button_hover_volume > -30.0,
+            cfg.button_hover_volume > -30.0,
             "button hover volume should be > -30 dB (audible)"
         );
     }
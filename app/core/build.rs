@@ -0,0 +1,51 @@
+//! Embeds the git commit of the build as `SUIKA_GIT_HASH` for `BuildInfo`.
+//!
+//! Builds from a source tarball (no `.git`) can pass the hash in through the
+//! `SUIKA_GIT_HASH` environment variable; otherwise `"unknown"` is used.
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=SUIKA_GIT_HASH");
+
+    // Re-run when HEAD moves (checkout, commit) — only watch paths that exist,
+    // a missing path would make cargo re-run the script on every build.
+    for path in [
+        "../../.git/HEAD",
+        "../../.git/refs",
+        "../../.git/packed-refs",
+    ] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+
+    let hash = std::env::var("SUIKA_GIT_HASH")
+        .ok()
+        .filter(|h| !h.is_empty())
+        .or_else(git_hash)
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=SUIKA_GIT_HASH={hash}");
+}
+
+/// Short hash of `HEAD`, with a `-dirty` suffix for uncommitted changes.
+fn git_hash() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=10", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let mut hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|o| o.status.success() && !o.stdout.is_empty());
+    if dirty {
+        hash.push_str("-dirty");
+    }
+    Some(hash)
+}
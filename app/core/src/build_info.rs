@@ -0,0 +1,67 @@
+//! Version and commit of the running build
+//!
+//! [`BuildInfo`] is inserted by [`GameCorePlugin`](crate::GameCorePlugin) so
+//! the title screen can show it and the session summaries can record it;
+//! a bug report with either identifies the exact build.  The git hash is
+//! embedded at compile time by `build.rs`.
+
+use std::fmt;
+
+use bevy::prelude::*;
+
+/// Crate version and git commit this binary was built from.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// Workspace version from `Cargo.toml`
+    pub version: &'static str,
+    /// Short commit hash (`-dirty` when built with local changes), or
+    /// `"unknown"` when built outside a git checkout
+    pub git_hash: &'static str,
+}
+
+impl BuildInfo {
+    /// Build info of the running binary.
+    pub const CURRENT: Self = Self {
+        version: env!("CARGO_PKG_VERSION"),
+        git_hash: env!("SUIKA_GIT_HASH"),
+    };
+}
+
+impl Default for BuildInfo {
+    fn default() -> Self {
+        Self::CURRENT
+    }
+}
+
+/// Formats as `v0.1.0 (1a2b3c4d5e)`.
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{} ({})", self.version, self.git_hash)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_build_info() {
+        let info = BuildInfo::default();
+        assert_eq!(info, BuildInfo::CURRENT);
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert!(!info.git_hash.is_empty());
+    }
+
+    #[test]
+    fn test_build_info_display() {
+        let info = BuildInfo {
+            version: "1.2.3",
+            git_hash: "abc1234",
+        };
+        assert_eq!(info.to_string(), "v1.2.3 (abc1234)");
+    }
+}
@@ -108,12 +108,19 @@ pub struct BoundaryLine;
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct Dropping;
 
-/// Next fruit preview marker component
+/// Merge ghost marker component
 ///
-/// Marks the entity displaying the preview of the next fruit
-/// that will be spawned. This is typically shown in the UI.
+/// Marks the translucent sprite that shows which fruit the held fruit would
+/// merge into (the merge ghost assist).
 #[derive(Component, Debug, Clone, Copy, Default)]
-pub struct NextFruitPreview;
+pub struct MergeGhost;
+
+/// Landing preview marker component
+///
+/// Marks the faint silhouette that shows where the held fruit would first
+/// touch down (the landing preview assist).
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct LandingPreview;
 
 /// Merge candidate marker component
 ///
@@ -126,6 +133,34 @@ pub struct NextFruitPreview;
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct MergeCandidate;
 
+/// Marks a fruit that was just born from a merge.
+///
+/// While `remaining` is positive, a merge involving this fruit counts as a
+/// chain merge one step deeper than `depth`.  Inserted by the merge handler
+/// and removed by `tick_chain_links` once the window from
+/// `game_rules.ron` `chain_window` has passed.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ChainLink {
+    /// Chain depth of the merge that produced this fruit (0 = ordinary merge)
+    pub depth: u32,
+    /// Seconds left before this fruit can no longer extend the chain
+    pub remaining: f32,
+}
+
+impl ChainLink {
+    /// Chain depth of a merge between two fruits with the given links.
+    ///
+    /// `0` when neither fruit came from a recent merge, otherwise one more
+    /// than the deeper of the two links.
+    pub fn merge_depth(a: Option<&ChainLink>, b: Option<&ChainLink>) -> u32 {
+        a.into_iter()
+            .chain(b)
+            .map(|link| link.depth + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
 /// Fruit spawn state component
 ///
 /// Tracks whether a fruit has been dropped and landed.
@@ -170,9 +205,18 @@ mod tests {
     }
 
     #[test]
-    fn test_next_fruit_preview_component_default() {
-        let preview = NextFruitPreview::default();
-        assert_eq!(format!("{:?}", preview), "NextFruitPreview");
+    fn test_chain_link_merge_depth() {
+        let fresh = ChainLink {
+            depth: 0,
+            remaining: 0.2,
+        };
+        let chained = ChainLink {
+            depth: 2,
+            remaining: 0.2,
+        };
+        assert_eq!(ChainLink::merge_depth(None, None), 0);
+        assert_eq!(ChainLink::merge_depth(Some(&fresh), None), 1);
+        assert_eq!(ChainLink::merge_depth(Some(&fresh), Some(&chained)), 3);
     }
 
     #[test]
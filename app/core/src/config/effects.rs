@@ -1,4 +1,4 @@
-//! Effect configuration: bounce, droplet, flash, shake, watermelon
+//! Effect configuration: bounce, droplet, dust, flash, shake, watermelon, boundary
 //!
 //! Loaded from `assets/config/effects/*.ron`.
 
@@ -103,6 +103,51 @@ impl<'w> DropletParams<'w> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// DustConfig
+// ---------------------------------------------------------------------------
+
+/// Landing dust-puff effect configuration
+///
+/// Loaded from `assets/config/effects/dust.ron`.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+pub struct DustConfig {
+    /// Puffs spawned per landing
+    pub count: u32,
+    /// Puff radius when spawned, in pixels
+    pub radius: f32,
+    /// Puff radius multiplier reached at the end of its lifetime
+    pub growth: f32,
+    pub color: crate::config::gameplay::RonColor,
+    /// Sideways speed of the outermost puffs (pixels/s)
+    pub spread_speed: f32,
+    /// Upward drift speed (pixels/s)
+    pub rise_speed: f32,
+    /// Fraction of the velocity lost per second (0.0–1.0)
+    pub drag: f32,
+    /// Seconds before a puff has faded out
+    pub lifetime: f32,
+}
+
+/// Resource holding the handle to the loaded dust configuration
+#[derive(Resource)]
+pub struct DustConfigHandle(pub Handle<DustConfig>);
+
+/// SystemParam bundle for accessing [`DustConfig`].
+#[derive(SystemParam)]
+pub struct DustParams<'w> {
+    handle: Option<Res<'w, DustConfigHandle>>,
+    assets: Option<Res<'w, Assets<DustConfig>>>,
+}
+
+impl<'w> DustParams<'w> {
+    pub fn get(&self) -> Option<&DustConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // FlashConfig
 // ---------------------------------------------------------------------------
@@ -152,6 +197,34 @@ pub struct ShakeConfig {
     pub max_offset: f32,
     pub min_fruit_index: usize,
     pub intensity_step: f32,
+    /// Zoom punch on big merges; omitted in older files, so it has defaults
+    #[serde(default)]
+    pub punch: PunchConfig,
+}
+
+/// Camera zoom punch configuration, the `punch` section of `shake.ron`
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct PunchConfig {
+    /// Minimum fruit index whose merge punches the camera
+    pub min_fruit_index: usize,
+    /// Zoom added per index step above `min_fruit_index` (0.05 = 5 % closer)
+    pub zoom_step: f32,
+    /// Largest zoom of a single punch
+    pub max_zoom: f32,
+    /// Seconds from the start of a punch until the camera has settled
+    pub duration: f32,
+}
+
+impl Default for PunchConfig {
+    fn default() -> Self {
+        Self {
+            min_fruit_index: 8,
+            zoom_step: 0.02,
+            max_zoom: 0.08,
+            duration: 0.35,
+        }
+    }
 }
 
 /// Resource holding the handle to the loaded shake configuration
@@ -173,6 +246,52 @@ impl<'w> ShakeParams<'w> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// BoundaryConfig
+// ---------------------------------------------------------------------------
+
+/// Danger-line (boundary line) style configuration
+///
+/// Loaded from `assets/config/effects/boundary.ron`.  Colour and pulse rate
+/// are interpolated from the `idle_*` to the `danger_*` values as the
+/// game-over warning progresses.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+pub struct BoundaryConfig {
+    /// Line thickness in pixels
+    pub thickness: f32,
+    /// Length of each dash in pixels
+    pub dash_length: f32,
+    /// Gap between dashes in pixels
+    pub gap_length: f32,
+    /// Speed the dashes drift sideways (pixels/s); doubled at full warning
+    pub scroll_speed: f32,
+    pub idle_color: crate::config::gameplay::RonColor,
+    pub danger_color: crate::config::gameplay::RonColor,
+    pub idle_pulse_hz: f32,
+    pub danger_pulse_hz: f32,
+    /// Fraction of the alpha removed at the low point of a pulse (0.0–1.0)
+    pub pulse_depth: f32,
+}
+
+/// Resource holding the handle to the loaded boundary configuration
+#[derive(Resource)]
+pub struct BoundaryConfigHandle(pub Handle<BoundaryConfig>);
+
+/// SystemParam bundle for accessing [`BoundaryConfig`].
+#[derive(SystemParam)]
+pub struct BoundaryParams<'w> {
+    handle: Option<Res<'w, BoundaryConfigHandle>>,
+    assets: Option<Res<'w, Assets<BoundaryConfig>>>,
+}
+
+impl<'w> BoundaryParams<'w> {
+    pub fn get(&self) -> Option<&BoundaryConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // WatermelonConfig
 // ---------------------------------------------------------------------------
@@ -270,6 +389,33 @@ pub fn hot_reload_droplet_config(
     }
 }
 
+/// Handles hot-reloading of dust effect configuration
+pub fn hot_reload_dust_config(
+    mut events: MessageReader<AssetEvent<DustConfig>>,
+    config_assets: Res<Assets<DustConfig>>,
+    config_handle: Res<DustConfigHandle>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ Dust effect config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                if let Some(config) = config_assets.get(&config_handle.0) {
+                    info!(
+                        "🔥 Hot-reloading dust config! count={}, lifetime={}",
+                        config.count, config.lifetime
+                    );
+                }
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ Dust effect config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Handles hot-reloading of flash effect configuration
 pub fn hot_reload_flash_config(
     mut events: MessageReader<AssetEvent<FlashConfig>>,
@@ -311,8 +457,11 @@ pub fn hot_reload_shake_config(
             AssetEvent::Modified { id: _ } => {
                 if let Some(config) = config_assets.get(&config_handle.0) {
                     info!(
-                        "🔥 Hot-reloading shake config! decay={}, max_offset={}, min_fruit_index={}",
-                        config.decay, config.max_offset, config.min_fruit_index
+                        "🔥 Hot-reloading shake config! decay={}, max_offset={}, min_fruit_index={}, punch_max_zoom={}",
+                        config.decay,
+                        config.max_offset,
+                        config.min_fruit_index,
+                        config.punch.max_zoom
                     );
                 }
             }
@@ -324,6 +473,36 @@ pub fn hot_reload_shake_config(
     }
 }
 
+/// Handles hot-reloading of boundary line configuration
+pub fn hot_reload_boundary_config(
+    mut events: MessageReader<AssetEvent<BoundaryConfig>>,
+    config_assets: Res<Assets<BoundaryConfig>>,
+    config_handle: Res<BoundaryConfigHandle>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ Boundary line config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                if let Some(config) = config_assets.get(&config_handle.0) {
+                    info!(
+                        "🔥 Hot-reloading boundary config! dash={}/{}, pulse={}–{} Hz",
+                        config.dash_length,
+                        config.gap_length,
+                        config.idle_pulse_hz,
+                        config.danger_pulse_hz
+                    );
+                }
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ Boundary line config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Handles hot-reloading of watermelon effect configuration
 pub fn hot_reload_watermelon_config(
     mut events: MessageReader<AssetEvent<WatermelonConfig>>,
@@ -408,6 +587,27 @@ DropletConfig(
         assert!((config.color.r - 0.5).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_dust_config_deserialization() {
+        let ron_data = r#"
+DustConfig(
+    count: 6,
+    radius: 4.0,
+    growth: 2.5,
+    color: (r: 0.85, g: 0.8, b: 0.7, a: 0.6),
+    spread_speed: 90.0,
+    rise_speed: 20.0,
+    drag: 0.9,
+    lifetime: 0.5,
+)
+"#;
+        let config: DustConfig = ron::de::from_str(ron_data).unwrap();
+        assert_eq!(config.count, 6);
+        assert_eq!(config.growth, 2.5);
+        assert_eq!(config.spread_speed, 90.0);
+        assert!((config.lifetime - 0.5).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn test_flash_config_deserialization() {
         let ron_data = r#"
@@ -467,5 +667,47 @@ ShakeConfig(
         assert_eq!(config.max_offset, 15.0);
         assert_eq!(config.min_fruit_index, 5);
         assert!((config.intensity_step - 0.15).abs() < f32::EPSILON);
+        assert_eq!(config.punch, PunchConfig::default());
+    }
+
+    #[test]
+    fn test_shake_config_punch_section() {
+        let ron_data = r#"
+ShakeConfig(
+    decay: 5.0,
+    max_offset: 15.0,
+    min_fruit_index: 5,
+    intensity_step: 0.15,
+    punch: (min_fruit_index: 9, max_zoom: 0.1),
+)
+"#;
+        let config: ShakeConfig = ron::de::from_str(ron_data).unwrap();
+        assert_eq!(config.punch.min_fruit_index, 9);
+        assert_eq!(config.punch.max_zoom, 0.1);
+        assert_eq!(config.punch.duration, PunchConfig::default().duration);
+    }
+
+    #[test]
+    fn test_boundary_config_deserialization() {
+        let ron_data = r#"
+BoundaryConfig(
+    thickness: 4.0,
+    dash_length: 16.0,
+    gap_length: 10.0,
+    scroll_speed: 24.0,
+    idle_color: (r: 1.0, g: 0.3, b: 0.3, a: 0.5),
+    danger_color: (r: 1.0, g: 0.0, b: 0.0, a: 1.0),
+    idle_pulse_hz: 0.5,
+    danger_pulse_hz: 5.0,
+    pulse_depth: 0.6,
+)
+"#;
+        let config: BoundaryConfig = ron::de::from_str(ron_data).unwrap();
+        assert_eq!(config.thickness, 4.0);
+        assert_eq!(config.dash_length, 16.0);
+        assert_eq!(config.gap_length, 10.0);
+        assert_eq!(config.danger_color.a, 1.0);
+        assert_eq!(config.danger_pulse_hz, 5.0);
+        assert!((config.pulse_depth - 0.6).abs() < f32::EPSILON);
     }
 }
@@ -0,0 +1,185 @@
+//! Errors reported by the RON config loaders
+//!
+//! Every RON loader in the workspace (core, UI and audio configs) returns a
+//! [`ConfigError`] so a broken file is reported as
+//! `config/physics.ron line 4: expected float` instead of a bare
+//! `InvalidData` I/O error.  [`report_config_load_errors`] forwards failed
+//! loads to the UI as [`ConfigErrorEvent`]s, and [`report_config_reloads`]
+//! announces successful hot-reloads as [`ConfigReloadedEvent`]s.
+
+use std::path::{Path, PathBuf};
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoadError, AssetLoadFailedEvent};
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use crate::events::{ConfigErrorEvent, ConfigReloadedEvent};
+
+/// Why a config file could not be turned into its asset.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// The file could not be read.
+    #[error("{}: {source}", .path.display())]
+    Io {
+        /// Asset path of the file.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+    /// The file is not valid RON for the config type.
+    #[error("{} line {line}: {message}", .path.display())]
+    Parse {
+        /// Asset path of the file.
+        path: PathBuf,
+        /// 1-based line of the error.
+        line: usize,
+        /// 1-based column of the error.
+        column: usize,
+        /// What the parser expected or rejected.
+        message: String,
+    },
+    /// The file parsed but holds a value the game cannot use.
+    #[error("{}: {message}", .path.display())]
+    Invalid {
+        /// Asset path of the file.
+        path: PathBuf,
+        /// Which value is wrong and why.
+        message: String,
+    },
+}
+
+impl ConfigError {
+    /// Wraps a RON parse error with the file it came from.
+    pub fn from_ron(path: impl Into<PathBuf>, error: ron::error::SpannedError) -> Self {
+        Self::Parse {
+            path: path.into(),
+            line: error.span.start.line,
+            column: error.span.start.col,
+            message: error.code.to_string(),
+        }
+    }
+
+    /// Asset path of the file the error belongs to.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Io { path, .. } | Self::Parse { path, .. } | Self::Invalid { path, .. } => path,
+        }
+    }
+}
+
+/// Parses `bytes` as RON, attributing errors to `path`.
+pub fn parse_ron<T: DeserializeOwned>(path: &Path, bytes: &[u8]) -> Result<T, ConfigError> {
+    ron::de::from_bytes(bytes).map_err(|e| ConfigError::from_ron(path, e))
+}
+
+/// Reads the whole asset and parses it as RON.
+///
+/// Shared body of every RON `AssetLoader::load` in the workspace; `path` is
+/// the asset path from the `LoadContext`.
+pub async fn read_ron_asset<T: DeserializeOwned>(
+    reader: &mut dyn Reader,
+    path: &Path,
+) -> Result<T, ConfigError> {
+    let mut bytes = Vec::new();
+    if let Err(source) = reader.read_to_end(&mut bytes).await {
+        return Err(ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        });
+    }
+    parse_ron(path, &bytes)
+}
+
+/// Turns a failed config load into a user-facing message.
+///
+/// Uses the loader's [`ConfigError`] when there is one, so the message
+/// carries the line number; other failures (missing file, unknown
+/// extension) fall back to the asset server's description.
+pub fn config_error_message(path: &impl std::fmt::Display, error: &AssetLoadError) -> String {
+    if let AssetLoadError::AssetLoaderError(loader_error) = error
+        && let Some(config_error) = loader_error.error().downcast_ref::<ConfigError>()
+    {
+        return config_error.to_string();
+    }
+    format!("{path}: {error}")
+}
+
+/// Sends a [`ConfigErrorEvent`] for every failed load of config asset `A`.
+///
+/// Registered by [`GameConfigPlugin`](super::GameConfigPlugin) for the core
+/// configs; other crates add it for their own config types.
+pub fn report_config_load_errors<A: Asset>(
+    mut failed: MessageReader<AssetLoadFailedEvent<A>>,
+    mut errors: MessageWriter<ConfigErrorEvent>,
+) {
+    for event in failed.read() {
+        let message = config_error_message(&event.path, &event.error);
+        error!("Config error: {message}");
+        errors.write(ConfigErrorEvent { message });
+    }
+}
+
+/// Sends a [`ConfigReloadedEvent`] every time config asset `A` is modified
+/// on disk and re-read successfully.
+///
+/// Registered next to [`report_config_load_errors`] for the same types.
+pub fn report_config_reloads<A: Asset>(
+    mut events: MessageReader<AssetEvent<A>>,
+    asset_server: Res<AssetServer>,
+    mut reloads: MessageWriter<ConfigReloadedEvent>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event
+            && let Some(path) = asset_server.get_path(*id)
+        {
+            reloads.write(ConfigReloadedEvent {
+                path: path.to_string(),
+            });
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PhysicsConfig;
+
+    #[test]
+    fn test_parse_error_carries_path_and_line() {
+        let source = "(\n    gravity: -980.0,\n    container_width: \"wide\",\n)";
+        let err = parse_ron::<PhysicsConfig>(Path::new("config/physics.ron"), source.as_bytes())
+            .unwrap_err();
+
+        match &err {
+            ConfigError::Parse { path, line, .. } => {
+                assert_eq!(path, Path::new("config/physics.ron"));
+                assert_eq!(*line, 3);
+            }
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+        assert!(
+            err.to_string().starts_with("config/physics.ron line 3: "),
+            "unexpected message: {err}"
+        );
+    }
+
+    #[test]
+    fn test_invalid_error_message() {
+        let err = ConfigError::Invalid {
+            path: "config/audio/merge_sfx.ron".into(),
+            message: "merge_small_pitch must be > 0.0, got 0".to_string(),
+        };
+        assert_eq!(err.path(), Path::new("config/audio/merge_sfx.ron"));
+        assert_eq!(
+            err.to_string(),
+            "config/audio/merge_sfx.ron: merge_small_pitch must be > 0.0, got 0"
+        );
+    }
+}
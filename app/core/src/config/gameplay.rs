@@ -12,9 +12,7 @@ use bevy_rapier2d::prelude::{
 use serde::Deserialize;
 use std::collections::HashMap;
 
-use crate::components::{
-    BottomWall, BoundaryLine, Container, Fruit, FruitSpawnState, LeftWall, NextFruitPreview,
-};
+use crate::components::{BottomWall, BoundaryLine, Container, Fruit, FruitSpawnState, LeftWall};
 
 // ---------------------------------------------------------------------------
 // Shared color type
@@ -142,6 +140,53 @@ pub struct PhysicsConfig {
     pub fruit_angular_damping: f32,
     /// Keyboard movement speed in pixels per second
     pub keyboard_move_speed: f32,
+    /// Fruit speed (pixels per second) above which continuous collision
+    /// detection is switched on for that fruit.
+    /// Defaults to [`DEFAULT_CCD_SPEED_THRESHOLD`] when omitted from the RON file.
+    #[serde(default = "default_ccd_speed_threshold")]
+    pub ccd_speed_threshold: f32,
+    /// Maximum fruit speed in pixels per second; faster fruits are slowed down.
+    /// Defaults to [`DEFAULT_MAX_FRUIT_SPEED`] when omitted from the RON file.
+    #[serde(default = "default_max_fruit_speed")]
+    pub max_fruit_speed: f32,
+    /// Rapier sleep threshold for linear motion, normalized by the physics
+    /// length unit.  Fruits slower than this (and [`Self::sleep_angular_threshold`])
+    /// for a short while are put to sleep and skipped by the solver.
+    /// Defaults to [`DEFAULT_SLEEP_LINEAR_THRESHOLD`] when omitted from the RON file.
+    #[serde(default = "default_sleep_linear_threshold")]
+    pub sleep_linear_threshold: f32,
+    /// Rapier sleep threshold for angular motion in radians per second.
+    /// Defaults to [`DEFAULT_SLEEP_ANGULAR_THRESHOLD`] when omitted from the RON file.
+    #[serde(default = "default_sleep_angular_threshold")]
+    pub sleep_angular_threshold: f32,
+}
+
+/// Fallback for [`PhysicsConfig::ccd_speed_threshold`] (mirrors `physics.ron`).
+pub const DEFAULT_CCD_SPEED_THRESHOLD: f32 = 600.0;
+
+/// Fallback for [`PhysicsConfig::max_fruit_speed`] (mirrors `physics.ron`).
+pub const DEFAULT_MAX_FRUIT_SPEED: f32 = 2500.0;
+
+/// Fallback for [`PhysicsConfig::sleep_linear_threshold`] (mirrors `physics.ron`).
+pub const DEFAULT_SLEEP_LINEAR_THRESHOLD: f32 = 0.6;
+
+/// Fallback for [`PhysicsConfig::sleep_angular_threshold`] (mirrors `physics.ron`).
+pub const DEFAULT_SLEEP_ANGULAR_THRESHOLD: f32 = 0.8;
+
+fn default_ccd_speed_threshold() -> f32 {
+    DEFAULT_CCD_SPEED_THRESHOLD
+}
+
+fn default_max_fruit_speed() -> f32 {
+    DEFAULT_MAX_FRUIT_SPEED
+}
+
+fn default_sleep_linear_threshold() -> f32 {
+    DEFAULT_SLEEP_LINEAR_THRESHOLD
+}
+
+fn default_sleep_angular_threshold() -> f32 {
+    DEFAULT_SLEEP_ANGULAR_THRESHOLD
 }
 
 /// Resource holding the handle to the loaded physics configuration
@@ -165,12 +210,99 @@ pub struct GameRulesConfig {
     pub game_over_timer: f32,
     /// Combo bonus multipliers (combo count -> multiplier)
     pub combo_bonuses: HashMap<u32, f32>,
-    /// X offset from container edge for next fruit preview
-    pub preview_x_offset: f32,
-    /// Y offset from container top for next fruit preview
-    pub preview_y_offset: f32,
-    /// Size multiplier for preview display
-    pub preview_scale: f32,
+    /// Seconds a fruit born from a merge can still set off a chain merge.
+    /// Defaults to [`DEFAULT_CHAIN_WINDOW`] when omitted from the RON file.
+    #[serde(default = "default_chain_window")]
+    pub chain_window: f32,
+    /// Chain bonus multipliers (chain depth -> multiplier).
+    /// No chain bonus when omitted from the RON file.
+    #[serde(default)]
+    pub chain_bonuses: HashMap<u32, f32>,
+    /// Optional overfill pressure rule.
+    /// Disabled when omitted from the RON file.
+    #[serde(default)]
+    pub overfill_pressure: OverfillPressureConfig,
+    /// Optional adaptive spawn rule.
+    /// Disabled when omitted from the RON file.
+    #[serde(default)]
+    pub adaptive_spawn: AdaptiveSpawnConfig,
+}
+
+/// Overfill pressure rule of [`GameRulesConfig`]
+///
+/// Fruits resting above a soft line (a little below the boundary line) for
+/// longer than `delay` are pushed gently downward, ramping up to
+/// `acceleration` over `ramp_time`.  Gives a nearly full board a chance to
+/// settle before the game-over timer runs out.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct OverfillPressureConfig {
+    /// Whether the rule is active
+    pub enabled: bool,
+    /// Distance of the soft line below the boundary line in pixels
+    pub soft_line_offset: f32,
+    /// Seconds a fruit may rest above the soft line before it is pushed
+    pub delay: f32,
+    /// Seconds over which the push ramps up to full strength
+    pub ramp_time: f32,
+    /// Downward acceleration at full strength in pixels/s²
+    pub acceleration: f32,
+}
+
+impl Default for OverfillPressureConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            soft_line_offset: 60.0,
+            delay: 2.0,
+            ramp_time: 1.5,
+            acceleration: 400.0,
+        }
+    }
+}
+
+/// Adaptive spawn rule of [`GameRulesConfig`]
+///
+/// Once the container fill level reaches `nearly_full`, new draws lean
+/// toward the smaller spawnable fruits by up to `max_bias`; the draws go
+/// back to normal when the fill level drops to `healthy`.  `max_bias` is
+/// capped at [`MAX_ADAPTIVE_BIAS`] whatever the file says.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct AdaptiveSpawnConfig {
+    /// Whether the rule is active
+    pub enabled: bool,
+    /// Fill level (0.0–1.0) at which the nudge switches on
+    pub nearly_full: f32,
+    /// Fill level (0.0–1.0) at which the nudge switches off again
+    pub healthy: f32,
+    /// Strength of the nudge at a full board (0.0 = uniform draws)
+    pub max_bias: f32,
+}
+
+impl Default for AdaptiveSpawnConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            nearly_full: 0.8,
+            healthy: 0.6,
+            max_bias: 0.3,
+        }
+    }
+}
+
+/// Upper bound on [`AdaptiveSpawnConfig::max_bias`].
+///
+/// Even at this bias the largest spawnable fruit keeps half its normal
+/// chance, so a deliberately crowded board cannot turn into a stream of
+/// Cherries.
+pub const MAX_ADAPTIVE_BIAS: f32 = 0.5;
+
+/// Fallback for [`GameRulesConfig::chain_window`] (mirrors `game_rules.ron`).
+pub const DEFAULT_CHAIN_WINDOW: f32 = 0.25;
+
+fn default_chain_window() -> f32 {
+    DEFAULT_CHAIN_WINDOW
 }
 
 /// Resource holding the handle to the loaded game rules configuration
@@ -294,35 +426,6 @@ pub fn is_out_of_bounds(position: Vec3, radius: f32, config: &PhysicsConfig) ->
     position.x.abs() + radius > max_x || position.y.abs() + radius > max_y
 }
 
-/// Updates the preview display position and size when config changes
-pub fn update_preview(
-    transform: &mut Transform,
-    sprite: &mut Sprite,
-    physics_config: &PhysicsConfig,
-    rules_config: &GameRulesConfig,
-    fruits_config: &FruitsConfig,
-    next_fruit_type: crate::fruit::FruitType,
-) {
-    let new_x = physics_config.container_width / 2.0 + rules_config.preview_x_offset;
-    let new_y = physics_config.container_height / 2.0 + rules_config.preview_y_offset;
-    transform.translation.x = new_x;
-    transform.translation.y = new_y;
-
-    if let Some(params) = next_fruit_type.try_parameters_from_config(fruits_config) {
-        let preview_size = params.radius * 2.0 * rules_config.preview_scale;
-        sprite.custom_size = Some(Vec2::splat(preview_size));
-        info!(
-            "🎨 Preview display updated to position ({:.1}, {:.1}), size={:.1}",
-            new_x, new_y, preview_size
-        );
-    } else {
-        warn!(
-            "⚠️ No config entry for preview fruit {:?}, keeping previous size",
-            next_fruit_type
-        );
-    }
-}
-
 /// Updates game timer resources when game rules config changes
 pub fn update_game_timers(
     combo_timer: &mut crate::resources::ComboTimer,
@@ -569,15 +672,10 @@ pub fn hot_reload_physics_config(
 }
 
 /// Handles hot-reloading of game rules configuration
-#[allow(clippy::too_many_arguments)]
 pub fn hot_reload_game_rules_config(
     mut events: MessageReader<AssetEvent<GameRulesConfig>>,
     config_assets: Res<Assets<GameRulesConfig>>,
     config_handle: Res<GameRulesConfigHandle>,
-    mut preview_query: Query<(&mut Transform, &mut Sprite), With<NextFruitPreview>>,
-    physics: PhysicsParams,
-    fruits: FruitsParams,
-    next_fruit: Res<crate::resources::NextFruitType>,
     mut combo_timer: ResMut<crate::resources::ComboTimer>,
     mut game_over_timer: ResMut<crate::resources::GameOverTimer>,
 ) {
@@ -598,20 +696,6 @@ pub fn hot_reload_game_rules_config(
                     );
 
                     update_game_timers(&mut combo_timer, &mut game_over_timer, config);
-
-                    if let Some(physics_config) = physics.get()
-                        && let Some(fruits_config) = fruits.get()
-                        && let Ok((mut transform, mut sprite)) = preview_query.single_mut()
-                    {
-                        update_preview(
-                            &mut transform,
-                            &mut sprite,
-                            physics_config,
-                            config,
-                            fruits_config,
-                            next_fruit.get(),
-                        );
-                    }
                 }
             }
             AssetEvent::Removed { id: _ } => {
@@ -677,6 +761,17 @@ PhysicsConfig(
         assert_eq!(config.container_height, 800.0);
         assert_eq!(config.wall_thickness, 20.0);
         assert_eq!(config.boundary_line_y, 300.0);
+        // Omitted anti-tunneling fields fall back to their defaults
+        assert_eq!(config.ccd_speed_threshold, DEFAULT_CCD_SPEED_THRESHOLD);
+        assert_eq!(config.max_fruit_speed, DEFAULT_MAX_FRUIT_SPEED);
+        assert_eq!(
+            config.sleep_linear_threshold,
+            DEFAULT_SLEEP_LINEAR_THRESHOLD
+        );
+        assert_eq!(
+            config.sleep_angular_threshold,
+            DEFAULT_SLEEP_ANGULAR_THRESHOLD
+        );
     }
 
     #[test]
@@ -693,9 +788,6 @@ GameRulesConfig(
         4: 1.3,
         5: 1.5,
     },
-    preview_x_offset: 120.0,
-    preview_y_offset: -100.0,
-    preview_scale: 1.5,
 )
 "#;
         let config: GameRulesConfig = ron::de::from_str(ron_data).unwrap();
@@ -705,6 +797,11 @@ GameRulesConfig(
         assert_eq!(config.game_over_timer, 3.0);
         assert_eq!(config.combo_bonuses.get(&2), Some(&1.1));
         assert_eq!(config.combo_bonuses.get(&5), Some(&1.5));
+        // Chain and pressure fields are optional
+        assert_eq!(config.chain_window, DEFAULT_CHAIN_WINDOW);
+        assert!(config.chain_bonuses.is_empty());
+        assert!(!config.overfill_pressure.enabled);
+        assert!(!config.adaptive_spawn.enabled);
     }
 
     #[test]
@@ -735,6 +832,10 @@ GameRulesConfig(
             fruit_linear_damping: 0.5,
             fruit_angular_damping: 1.0,
             keyboard_move_speed: 300.0,
+            ccd_speed_threshold: 600.0,
+            max_fruit_speed: 2500.0,
+            sleep_linear_threshold: 0.6,
+            sleep_angular_threshold: 0.8,
         };
 
         let radius = 20.0;
@@ -6,33 +6,59 @@
 //! Supports hot-reloading: Edit config files while the game is running
 //! and changes will be applied automatically.
 //!
+//! If a config has not loaded after [`CONFIG_LOAD_TIMEOUT_SECS`] (or fails
+//! to load), the copy of the shipped RON file compiled into the binary is
+//! used instead, a [`ConfigLoadFailedEvent`] is sent, and the game continues
+//! to the title screen with [`ConfigLoadStatus`] recording the fallback.
+//!
+//! `Loading` also waits for the asset collections registered in
+//! [`TrackedAssets`](crate::loading::TrackedAssets) (e.g. the sound files).
+//!
+//! With a `--config-dir` [`LaunchOptions`] the files are read from that
+//! directory (same layout as `assets/config`) instead of the assets folder.
+//! A config shipped by an enabled user mod (e.g. a custom `fruits.ron`, see
+//! [`crate::mods`]) wins over both.
+//!
 //! # Sub-modules
 //!
 //! | Module | Contents |
 //! |--------|----------|
 //! | [`gameplay`] | `FruitsConfig`, `PhysicsConfig`, `GameRulesConfig` + SystemParam bundles |
-//! | [`effects`]  | `BounceConfig`, `DropletConfig`, `FlashConfig`, `ShakeConfig`, `WatermelonConfig` + SystemParam bundles |
+//! | [`effects`]  | `BounceConfig`, `DropletConfig`, `DustConfig`, `FlashConfig`, `ShakeConfig`, `WatermelonConfig`, `BoundaryConfig` + SystemParam bundles |
+//! | [`error`]    | `ConfigError`, the shared RON loading helpers and load-failure reporting |
 
 pub mod effects;
+pub mod error;
 pub mod gameplay;
 
 pub use effects::*;
+pub use error::*;
 pub use gameplay::*;
 
 use bevy::asset::io::Reader;
 use bevy::asset::{AssetLoader, LoadContext};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use serde::de::DeserializeOwned;
 
+use crate::events::{
+    AssetErrorEvent, ConfigErrorEvent, ConfigLoadFailedEvent, ConfigReloadedEvent, SaveDataLoaded,
+};
+use crate::launch::{CONFIG_ASSET_SOURCE, LaunchOptions};
+use crate::loading::{AssetLoadStatus, LoadingProgress, TrackedAssets};
+use crate::mods::ModList;
 use crate::states::AppState;
 
+/// Seconds to wait in `Loading` before falling back to built-in configs.
+pub const CONFIG_LOAD_TIMEOUT_SECS: f32 = 10.0;
+
 // ---------------------------------------------------------------------------
 // RON asset loader macro
 // ---------------------------------------------------------------------------
 
 /// Generates a RON-based `AssetLoader` implementation for a config type.
 ///
-/// All game config assets use identical loading logic (read bytes → `ron::de::from_bytes`),
+/// All game config assets use identical loading logic ([`read_ron_asset`]),
 /// so this macro eliminates the repetition while keeping each loader a distinct type.
 ///
 /// # Usage
@@ -47,18 +73,15 @@ macro_rules! ron_asset_loader {
         impl AssetLoader for $loader {
             type Asset = $asset;
             type Settings = ();
-            type Error = std::io::Error;
+            type Error = ConfigError;
 
             async fn load(
                 &self,
                 reader: &mut dyn Reader,
                 _settings: &Self::Settings,
-                _load_context: &mut LoadContext<'_>,
+                load_context: &mut LoadContext<'_>,
             ) -> Result<Self::Asset, Self::Error> {
-                let mut bytes = Vec::new();
-                reader.read_to_end(&mut bytes).await?;
-                ron::de::from_bytes(&bytes)
-                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+                read_ron_asset(reader, load_context.path()).await
             }
 
             fn extensions(&self) -> &[&str] {
@@ -74,9 +97,70 @@ ron_asset_loader!(PhysicsConfigLoader, PhysicsConfig);
 ron_asset_loader!(GameRulesConfigLoader, GameRulesConfig);
 ron_asset_loader!(BounceConfigLoader, BounceConfig);
 ron_asset_loader!(DropletConfigLoader, DropletConfig);
+ron_asset_loader!(DustConfigLoader, DustConfig);
 ron_asset_loader!(FlashConfigLoader, FlashConfig);
 ron_asset_loader!(ShakeConfigLoader, ShakeConfig);
 ron_asset_loader!(WatermelonConfigLoader, WatermelonConfig);
+ron_asset_loader!(BoundaryConfigLoader, BoundaryConfig);
+
+// ---------------------------------------------------------------------------
+// Built-in configs
+// ---------------------------------------------------------------------------
+
+/// A config file's asset path plus the shipped file compiled into the binary.
+struct BuiltinConfig {
+    path: &'static str,
+    source: &'static str,
+}
+
+/// Builds a [`BuiltinConfig`] for a path under `suika-game/assets/`.
+macro_rules! builtin_config {
+    ($path:literal) => {
+        BuiltinConfig {
+            path: $path,
+            source: include_str!(concat!("../../../suika-game/assets/", $path)),
+        }
+    };
+}
+
+const FRUITS_CONFIG: BuiltinConfig = builtin_config!("config/fruits.ron");
+const PHYSICS_CONFIG: BuiltinConfig = builtin_config!("config/physics.ron");
+const GAME_RULES_CONFIG: BuiltinConfig = builtin_config!("config/game_rules.ron");
+const BOUNCE_CONFIG: BuiltinConfig = builtin_config!("config/effects/bounce.ron");
+const DROPLET_CONFIG: BuiltinConfig = builtin_config!("config/effects/droplet.ron");
+const DUST_CONFIG: BuiltinConfig = builtin_config!("config/effects/dust.ron");
+const FLASH_CONFIG: BuiltinConfig = builtin_config!("config/effects/flash.ron");
+const SHAKE_CONFIG: BuiltinConfig = builtin_config!("config/effects/shake.ron");
+const WATERMELON_CONFIG: BuiltinConfig = builtin_config!("config/effects/watermelon.ron");
+const BOUNDARY_CONFIG: BuiltinConfig = builtin_config!("config/effects/boundary.ron");
+
+impl BuiltinConfig {
+    /// Asset path to load: an enabled mod's copy, the
+    /// [`CONFIG_ASSET_SOURCE`] directory when `--config-dir` was given, or
+    /// the default source.
+    fn asset_path(&self, launch: &LaunchOptions, mods: &ModList) -> String {
+        if let Some(path) = mods.override_path(self.path) {
+            return path;
+        }
+        match &launch.config_dir {
+            Some(_) => {
+                let relative = self.path.strip_prefix("config/").unwrap_or(self.path);
+                format!("{CONFIG_ASSET_SOURCE}://{relative}")
+            }
+            None => self.path.to_string(),
+        }
+    }
+}
+
+/// Records which configs fell back to their built-in copies.
+///
+/// Empty when every RON file loaded normally.  The UI shows a warning banner
+/// on the title screen while it is non-empty.
+#[derive(Resource, Debug, Default)]
+pub struct ConfigLoadStatus {
+    /// Asset paths of the configs that were replaced by built-in defaults.
+    pub fallback: Vec<&'static str>,
+}
 
 // ---------------------------------------------------------------------------
 // AllConfigs — private SystemParam for wait_for_configs
@@ -87,21 +171,162 @@ ron_asset_loader!(WatermelonConfigLoader, WatermelonConfig);
 #[derive(SystemParam)]
 struct AllConfigs<'w> {
     physics_handle: Res<'w, PhysicsConfigHandle>,
-    physics_assets: Res<'w, Assets<PhysicsConfig>>,
+    physics_assets: ResMut<'w, Assets<PhysicsConfig>>,
     fruits_handle: Res<'w, FruitsConfigHandle>,
-    fruits_assets: Res<'w, Assets<FruitsConfig>>,
+    fruits_assets: ResMut<'w, Assets<FruitsConfig>>,
     game_rules_handle: Res<'w, GameRulesConfigHandle>,
-    game_rules_assets: Res<'w, Assets<GameRulesConfig>>,
+    game_rules_assets: ResMut<'w, Assets<GameRulesConfig>>,
     bounce_handle: Res<'w, BounceConfigHandle>,
-    bounce_assets: Res<'w, Assets<BounceConfig>>,
+    bounce_assets: ResMut<'w, Assets<BounceConfig>>,
     droplet_handle: Res<'w, DropletConfigHandle>,
-    droplet_assets: Res<'w, Assets<DropletConfig>>,
+    droplet_assets: ResMut<'w, Assets<DropletConfig>>,
+    dust_handle: Res<'w, DustConfigHandle>,
+    dust_assets: ResMut<'w, Assets<DustConfig>>,
     flash_handle: Res<'w, FlashConfigHandle>,
-    flash_assets: Res<'w, Assets<FlashConfig>>,
+    flash_assets: ResMut<'w, Assets<FlashConfig>>,
     shake_handle: Res<'w, ShakeConfigHandle>,
-    shake_assets: Res<'w, Assets<ShakeConfig>>,
+    shake_assets: ResMut<'w, Assets<ShakeConfig>>,
     watermelon_handle: Res<'w, WatermelonConfigHandle>,
-    watermelon_assets: Res<'w, Assets<WatermelonConfig>>,
+    watermelon_assets: ResMut<'w, Assets<WatermelonConfig>>,
+    boundary_handle: Res<'w, BoundaryConfigHandle>,
+    boundary_assets: ResMut<'w, Assets<BoundaryConfig>>,
+}
+
+impl AllConfigs<'_> {
+    /// Number of configs bundled here.
+    const COUNT: usize = 10;
+
+    /// Number of config assets that are available.
+    fn loaded_count(&self) -> usize {
+        [
+            self.physics_assets.contains(&self.physics_handle.0),
+            self.fruits_assets.contains(&self.fruits_handle.0),
+            self.game_rules_assets.contains(&self.game_rules_handle.0),
+            self.bounce_assets.contains(&self.bounce_handle.0),
+            self.droplet_assets.contains(&self.droplet_handle.0),
+            self.dust_assets.contains(&self.dust_handle.0),
+            self.flash_assets.contains(&self.flash_handle.0),
+            self.shake_assets.contains(&self.shake_handle.0),
+            self.watermelon_assets.contains(&self.watermelon_handle.0),
+            self.boundary_assets.contains(&self.boundary_handle.0),
+        ]
+        .into_iter()
+        .filter(|&loaded| loaded)
+        .count()
+    }
+
+    /// Returns `true` once every config asset is available.
+    fn all_loaded(&self) -> bool {
+        self.loaded_count() == Self::COUNT
+    }
+
+    /// Returns `true` if the asset server gave up on any config.
+    fn any_failed(&self, asset_server: &AssetServer) -> bool {
+        [
+            self.physics_handle.0.id().untyped(),
+            self.fruits_handle.0.id().untyped(),
+            self.game_rules_handle.0.id().untyped(),
+            self.bounce_handle.0.id().untyped(),
+            self.droplet_handle.0.id().untyped(),
+            self.dust_handle.0.id().untyped(),
+            self.flash_handle.0.id().untyped(),
+            self.shake_handle.0.id().untyped(),
+            self.watermelon_handle.0.id().untyped(),
+            self.boundary_handle.0.id().untyped(),
+        ]
+        .into_iter()
+        .any(|id| asset_server.load_state(id).is_failed())
+    }
+
+    /// Inserts the built-in copy of every config that is still missing.
+    ///
+    /// Returns the asset paths that fell back.
+    fn insert_builtin_defaults(&mut self) -> Vec<&'static str> {
+        let mut fallback = Vec::new();
+        insert_builtin(
+            &mut self.physics_assets,
+            &self.physics_handle.0,
+            &PHYSICS_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.fruits_assets,
+            &self.fruits_handle.0,
+            &FRUITS_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.game_rules_assets,
+            &self.game_rules_handle.0,
+            &GAME_RULES_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.bounce_assets,
+            &self.bounce_handle.0,
+            &BOUNCE_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.droplet_assets,
+            &self.droplet_handle.0,
+            &DROPLET_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.dust_assets,
+            &self.dust_handle.0,
+            &DUST_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.flash_assets,
+            &self.flash_handle.0,
+            &FLASH_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.shake_assets,
+            &self.shake_handle.0,
+            &SHAKE_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.watermelon_assets,
+            &self.watermelon_handle.0,
+            &WATERMELON_CONFIG,
+            &mut fallback,
+        );
+        insert_builtin(
+            &mut self.boundary_assets,
+            &self.boundary_handle.0,
+            &BOUNDARY_CONFIG,
+            &mut fallback,
+        );
+        fallback
+    }
+}
+
+/// Inserts `builtin` under `handle` when the asset is missing.
+fn insert_builtin<A: Asset + DeserializeOwned>(
+    assets: &mut Assets<A>,
+    handle: &Handle<A>,
+    builtin: &BuiltinConfig,
+    fallback: &mut Vec<&'static str>,
+) {
+    if assets.contains(handle) {
+        return;
+    }
+    match ron::de::from_str::<A>(builtin.source) {
+        Ok(config) => {
+            if let Err(e) = assets.insert(handle, config) {
+                error!("Failed to insert built-in {}: {e}", builtin.path);
+            }
+        }
+        // Only possible if the shipped file itself is broken; covered by tests.
+        Err(e) => error!("Built-in {} does not parse: {e}", builtin.path),
+    }
+    fallback.push(builtin.path);
 }
 
 // ---------------------------------------------------------------------------
@@ -126,34 +351,61 @@ impl Plugin for GameConfigPlugin {
             .register_asset_loader(BounceConfigLoader)
             .init_asset::<DropletConfig>()
             .register_asset_loader(DropletConfigLoader)
+            .init_asset::<DustConfig>()
+            .register_asset_loader(DustConfigLoader)
             .init_asset::<FlashConfig>()
             .register_asset_loader(FlashConfigLoader)
             .init_asset::<ShakeConfig>()
             .register_asset_loader(ShakeConfigLoader)
             .init_asset::<WatermelonConfig>()
-            .register_asset_loader(WatermelonConfigLoader);
+            .register_asset_loader(WatermelonConfigLoader)
+            .init_asset::<BoundaryConfig>()
+            .register_asset_loader(BoundaryConfigLoader);
 
         // Load all configs and insert handles immediately
+        let launch = app
+            .world()
+            .get_resource::<LaunchOptions>()
+            .cloned()
+            .unwrap_or_default();
+        let mods = app
+            .world()
+            .get_resource::<ModList>()
+            .cloned()
+            .unwrap_or_default();
         let asset_server = app.world_mut().resource::<AssetServer>();
 
-        let fruits_handle: Handle<FruitsConfig> = asset_server.load("config/fruits.ron");
-        let physics_handle: Handle<PhysicsConfig> = asset_server.load("config/physics.ron");
-        let game_rules_handle: Handle<GameRulesConfig> = asset_server.load("config/game_rules.ron");
-        let bounce_handle: Handle<BounceConfig> = asset_server.load("config/effects/bounce.ron");
-        let droplet_handle: Handle<DropletConfig> = asset_server.load("config/effects/droplet.ron");
-        let flash_handle: Handle<FlashConfig> = asset_server.load("config/effects/flash.ron");
-        let shake_handle: Handle<ShakeConfig> = asset_server.load("config/effects/shake.ron");
+        let fruits_handle: Handle<FruitsConfig> =
+            asset_server.load(FRUITS_CONFIG.asset_path(&launch, &mods));
+        let physics_handle: Handle<PhysicsConfig> =
+            asset_server.load(PHYSICS_CONFIG.asset_path(&launch, &mods));
+        let game_rules_handle: Handle<GameRulesConfig> =
+            asset_server.load(GAME_RULES_CONFIG.asset_path(&launch, &mods));
+        let bounce_handle: Handle<BounceConfig> =
+            asset_server.load(BOUNCE_CONFIG.asset_path(&launch, &mods));
+        let droplet_handle: Handle<DropletConfig> =
+            asset_server.load(DROPLET_CONFIG.asset_path(&launch, &mods));
+        let dust_handle: Handle<DustConfig> =
+            asset_server.load(DUST_CONFIG.asset_path(&launch, &mods));
+        let flash_handle: Handle<FlashConfig> =
+            asset_server.load(FLASH_CONFIG.asset_path(&launch, &mods));
+        let shake_handle: Handle<ShakeConfig> =
+            asset_server.load(SHAKE_CONFIG.asset_path(&launch, &mods));
         let watermelon_handle: Handle<WatermelonConfig> =
-            asset_server.load("config/effects/watermelon.ron");
+            asset_server.load(WATERMELON_CONFIG.asset_path(&launch, &mods));
+        let boundary_handle: Handle<BoundaryConfig> =
+            asset_server.load(BOUNDARY_CONFIG.asset_path(&launch, &mods));
 
         app.insert_resource(FruitsConfigHandle(fruits_handle))
             .insert_resource(PhysicsConfigHandle(physics_handle))
             .insert_resource(GameRulesConfigHandle(game_rules_handle))
             .insert_resource(BounceConfigHandle(bounce_handle))
             .insert_resource(DropletConfigHandle(droplet_handle))
+            .insert_resource(DustConfigHandle(dust_handle))
             .insert_resource(FlashConfigHandle(flash_handle))
             .insert_resource(ShakeConfigHandle(shake_handle))
-            .insert_resource(WatermelonConfigHandle(watermelon_handle));
+            .insert_resource(WatermelonConfigHandle(watermelon_handle))
+            .insert_resource(BoundaryConfigHandle(boundary_handle));
 
         // Add hot-reload systems (run in all states so live-edit always works)
         app.add_systems(
@@ -164,18 +416,63 @@ impl Plugin for GameConfigPlugin {
                 hot_reload_game_rules_config,
                 hot_reload_bounce_config,
                 hot_reload_droplet_config,
+                hot_reload_dust_config,
                 hot_reload_flash_config,
                 hot_reload_shake_config,
                 hot_reload_watermelon_config,
+                hot_reload_boundary_config,
             ),
         );
 
         // Transition Loading → Title once all required configs are ready
+        // (or once the built-in fallbacks have been inserted) and the
+        // tracked asset collections have settled, and the save data is read
+        app.add_message::<ConfigLoadFailedEvent>()
+            .add_message::<AssetErrorEvent>()
+            .add_message::<SaveDataLoaded>()
+            .init_resource::<ConfigLoadStatus>()
+            .init_resource::<TrackedAssets>()
+            .init_resource::<LoadingProgress>()
+            .init_resource::<AssetLoadStatus>();
+
+        // Tell the player which file is broken instead of silently falling back
+        app.add_message::<ConfigErrorEvent>().add_systems(
+            Update,
+            (
+                report_config_load_errors::<FruitsConfig>,
+                report_config_load_errors::<PhysicsConfig>,
+                report_config_load_errors::<GameRulesConfig>,
+                report_config_load_errors::<BounceConfig>,
+                report_config_load_errors::<DropletConfig>,
+                report_config_load_errors::<DustConfig>,
+                report_config_load_errors::<FlashConfig>,
+                report_config_load_errors::<ShakeConfig>,
+                report_config_load_errors::<WatermelonConfig>,
+                report_config_load_errors::<BoundaryConfig>,
+            ),
+        );
+
+        // Confirm successful hot-reloads (shown as toasts in debug builds)
+        app.add_message::<ConfigReloadedEvent>().add_systems(
+            Update,
+            (
+                report_config_reloads::<FruitsConfig>,
+                report_config_reloads::<PhysicsConfig>,
+                report_config_reloads::<GameRulesConfig>,
+                report_config_reloads::<BounceConfig>,
+                report_config_reloads::<DropletConfig>,
+                report_config_reloads::<DustConfig>,
+                report_config_reloads::<FlashConfig>,
+                report_config_reloads::<ShakeConfig>,
+                report_config_reloads::<WatermelonConfig>,
+                report_config_reloads::<BoundaryConfig>,
+            ),
+        );
         app.add_systems(Update, wait_for_configs.run_if(in_state(AppState::Loading)));
 
         info!("✅ GameConfigPlugin initialized");
         info!(
-            "🔍 All configs load requested (fruits, physics, game_rules, bounce, droplet, flash, shake, watermelon)"
+            "🔍 All configs load requested (fruits, physics, game_rules, bounce, droplet, dust, flash, shake, watermelon, boundary)"
         );
     }
 }
@@ -184,38 +481,176 @@ impl Plugin for GameConfigPlugin {
 // wait_for_configs
 // ---------------------------------------------------------------------------
 
-/// Transitions from `Loading` → `Title` once all required RON configs are ready.
-fn wait_for_configs(configs: AllConfigs, mut next_state: ResMut<NextState<AppState>>) {
-    if configs
-        .physics_assets
-        .get(&configs.physics_handle.0)
-        .is_some()
-        && configs
-            .fruits_assets
-            .get(&configs.fruits_handle.0)
-            .is_some()
-        && configs
-            .game_rules_assets
-            .get(&configs.game_rules_handle.0)
-            .is_some()
-        && configs
-            .bounce_assets
-            .get(&configs.bounce_handle.0)
-            .is_some()
-        && configs
-            .droplet_assets
-            .get(&configs.droplet_handle.0)
-            .is_some()
-        && configs.flash_assets.get(&configs.flash_handle.0).is_some()
-        && configs.shake_assets.get(&configs.shake_handle.0).is_some()
-        && configs
-            .watermelon_assets
-            .get(&configs.watermelon_handle.0)
-            .is_some()
-    {
-        info!(
-            "✅ All configs loaded (physics, fruits, game_rules, bounce, droplet, flash, shake, watermelon), transitioning to Title"
+/// Transitions from `Loading` → `Title` once all required RON configs are
+/// ready, every [`TrackedAssets`] entry has loaded or failed, and
+/// [`SaveDataLoaded`] has arrived, so the title screen shows the real best
+/// score and "Continue" option.
+///
+/// If any config is still missing after [`CONFIG_LOAD_TIMEOUT_SECS`], or the
+/// asset server reports a load failure, the built-in copies are inserted for
+/// the missing ones, a [`ConfigLoadFailedEvent`] is sent, and the game moves
+/// on to `Title` anyway instead of hanging on `Loading`.  Tracked assets that
+/// failed are recorded in [`AssetLoadStatus`] and reported with one
+/// [`AssetErrorEvent`] each; ones still loading at the timeout keep loading
+/// in the background, and missing save data leaves the defaults in place.
+/// [`LoadingProgress`] is refreshed every frame for the loading screen.
+#[allow(clippy::too_many_arguments)]
+fn wait_for_configs(
+    mut configs: AllConfigs,
+    tracked: Res<TrackedAssets>,
+    mut loading: ResMut<LoadingProgress>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut elapsed: Local<f32>,
+    mut status: ResMut<ConfigLoadStatus>,
+    mut asset_status: ResMut<AssetLoadStatus>,
+    mut failed_events: MessageWriter<ConfigLoadFailedEvent>,
+    mut asset_errors: MessageWriter<AssetErrorEvent>,
+    mut save_data: MessageReader<SaveDataLoaded>,
+    mut save_data_loaded: Local<bool>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    *elapsed += time.delta_secs();
+    let timed_out = *elapsed >= CONFIG_LOAD_TIMEOUT_SECS;
+    if save_data.read().last().is_some() {
+        *save_data_loaded = true;
+    }
+
+    *loading = LoadingProgress {
+        configs_loaded: configs.loaded_count(),
+        configs_total: AllConfigs::COUNT,
+        assets: tracked.progress(&asset_server),
+    };
+
+    if !configs.all_loaded() {
+        if !timed_out && !configs.any_failed(&asset_server) {
+            return;
+        }
+        let fallback = configs.insert_builtin_defaults();
+        warn!(
+            "⚠️ Configs not loaded after {:.1}s, using built-in defaults for: {}",
+            *elapsed,
+            fallback.join(", ")
+        );
+        failed_events.write(ConfigLoadFailedEvent {
+            missing: fallback.clone(),
+        });
+        status.fallback = fallback;
+    }
+
+    let progress = loading.assets.clone();
+    if (!progress.is_settled() || !*save_data_loaded) && !timed_out {
+        return;
+    }
+    if !*save_data_loaded {
+        warn!("⚠️ Save data not loaded after {:.1}s, continuing", *elapsed);
+    }
+    if !progress.is_settled() {
+        warn!(
+            "⚠️ {} of {} assets still loading after {:.1}s, continuing",
+            progress.total - progress.loaded - progress.failed.len(),
+            progress.total,
+            *elapsed
+        );
+    }
+    for (collection, path) in &progress.failed {
+        error!("❌ Failed to load {collection} asset {path}");
+        asset_errors.write(AssetErrorEvent {
+            message: format!("{path}: failed to load ({collection})"),
+        });
+    }
+    asset_status.failed = progress.failed.into_iter().map(|(_, path)| path).collect();
+
+    info!(
+        "✅ Configs and {} tracked assets ready, transitioning to Title",
+        progress.loaded
+    );
+    next_state.set(AppState::Title);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parses<A: DeserializeOwned>(builtin: &BuiltinConfig) {
+        if let Err(e) = ron::de::from_str::<A>(builtin.source) {
+            panic!("built-in {} does not parse: {e}", builtin.path);
+        }
+    }
+
+    #[test]
+    fn test_builtin_configs_parse() {
+        parses::<FruitsConfig>(&FRUITS_CONFIG);
+        parses::<PhysicsConfig>(&PHYSICS_CONFIG);
+        parses::<GameRulesConfig>(&GAME_RULES_CONFIG);
+        parses::<BounceConfig>(&BOUNCE_CONFIG);
+        parses::<DropletConfig>(&DROPLET_CONFIG);
+        parses::<DustConfig>(&DUST_CONFIG);
+        parses::<FlashConfig>(&FLASH_CONFIG);
+        parses::<ShakeConfig>(&SHAKE_CONFIG);
+        parses::<WatermelonConfig>(&WATERMELON_CONFIG);
+        parses::<BoundaryConfig>(&BOUNDARY_CONFIG);
+    }
+
+    #[test]
+    fn test_insert_builtin_only_fills_missing() {
+        let mut assets = Assets::<PhysicsConfig>::default();
+        let handle = assets.reserve_handle();
+        let mut fallback = Vec::new();
+
+        insert_builtin(&mut assets, &handle, &PHYSICS_CONFIG, &mut fallback);
+        assert!(assets.contains(&handle));
+        assert_eq!(fallback, vec![PHYSICS_CONFIG.path]);
+
+        // Already present: left untouched and not reported again
+        insert_builtin(&mut assets, &handle, &PHYSICS_CONFIG, &mut fallback);
+        assert_eq!(fallback.len(), 1);
+    }
+
+    #[test]
+    fn test_asset_path_follows_config_dir() {
+        let default = LaunchOptions::default();
+        let no_mods = ModList::default();
+        assert_eq!(
+            SHAKE_CONFIG.asset_path(&default, &no_mods),
+            "config/effects/shake.ron"
+        );
+
+        let custom = LaunchOptions {
+            config_dir: Some("mods/config".into()),
+            ..default()
+        };
+        assert_eq!(
+            SHAKE_CONFIG.asset_path(&custom, &no_mods),
+            "config://effects/shake.ron"
+        );
+    }
+
+    #[test]
+    fn test_asset_path_prefers_enabled_mod() {
+        let mods = ModList {
+            mods: vec![crate::mods::ModInfo {
+                name: "pastel".to_string(),
+                path: "mods/pastel".into(),
+                files: ["config/fruits.ron".to_string()].into(),
+                enabled: true,
+            }],
+        };
+        let custom = LaunchOptions {
+            config_dir: Some("mods/config".into()),
+            ..default()
+        };
+        assert_eq!(
+            FRUITS_CONFIG.asset_path(&custom, &mods),
+            "mods://pastel/config/fruits.ron"
+        );
+        assert_eq!(
+            PHYSICS_CONFIG.asset_path(&custom, &mods),
+            "config://physics.ron"
         );
-        next_state.set(AppState::Title);
     }
 }
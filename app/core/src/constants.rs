@@ -13,6 +13,18 @@ pub mod storage {
     /// This directory will be created if it doesn't exist when
     /// saving game data (e.g., highscore).
     pub const SAVE_DIR: &str = "save";
+
+    /// Directory scanned for user mods at startup
+    ///
+    /// Optional; without it only the bundled assets load.
+    pub const MODS_DIR: &str = "mods";
+
+    /// Directory inside [`SAVE_DIR`] holding one directory per player
+    /// profile
+    pub const PROFILES_DIR: &str = "profiles";
+
+    /// Profile used until the player creates or picks another one
+    pub const DEFAULT_PROFILE: &str = "default";
 }
 
 #[cfg(test)]
@@ -23,5 +35,8 @@ mod tests {
     fn test_storage_constants() {
         // Verify storage directory path
         assert_eq!(storage::SAVE_DIR, "save");
+        assert_eq!(storage::MODS_DIR, "mods");
+        assert_eq!(storage::PROFILES_DIR, "profiles");
+        assert_eq!(storage::DEFAULT_PROFILE, "default");
     }
 }
@@ -0,0 +1,365 @@
+//! Game-specific diagnostics
+//!
+//! [`GameDiagnosticsPlugin`] registers custom counters in Bevy's
+//! [`DiagnosticsStore`] so they can be read by any diagnostics consumer
+//! (`LogDiagnosticsPlugin`, the UI performance overlay, tests):
+//!
+//! | Path                      | Meaning                                     |
+//! |---------------------------|---------------------------------------------|
+//! | [`LIVE_FRUITS`]           | Fruits currently on the board (incl. held)  |
+//! | [`LIVE_PARTICLES`]        | Droplet and watermelon burst particles      |
+//! | [`MERGES_PER_SEC`]        | Merges during the last second               |
+//! | [`AWAKE_BODIES`]          | Rapier bodies that are not asleep           |
+//!
+//! With `overlay` enabled, [`DIAGNOSTICS_OVERLAY_KEY`] toggles a small text
+//! overlay listing the counters.  With `heatmap` enabled,
+//! [`heatmap::HEATMAP_OVERLAY_KEY`] toggles the [`heatmap`] of merge
+//! positions drawn over the container.  With `fruit_tooltip` enabled,
+//! hovering a fruit shows its [`inspect`] tooltip.
+//!
+//! ```ignore
+//! app.add_plugins(GameDiagnosticsPlugin::default().with_overlay(true));
+//! ```
+
+pub mod heatmap;
+pub mod inspect;
+
+use std::collections::VecDeque;
+
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, RegisterDiagnostic,
+};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Sleeping;
+
+use crate::components::Fruit;
+use crate::events::FruitMergeEvent;
+use crate::systems::effects::droplet::WaterDroplet;
+use crate::systems::effects::watermelon::WatermelonBurstParticle;
+
+// ---------------------------------------------------------------------------
+// Diagnostic paths
+// ---------------------------------------------------------------------------
+
+/// Number of fruit entities.
+pub const LIVE_FRUITS: DiagnosticPath = DiagnosticPath::const_new("game/live_fruits");
+
+/// Number of particle entities spawned by merge effects.
+pub const LIVE_PARTICLES: DiagnosticPath = DiagnosticPath::const_new("game/live_particles");
+
+/// Merges in the last [`MERGE_RATE_WINDOW`] seconds, scaled to per second.
+pub const MERGES_PER_SEC: DiagnosticPath = DiagnosticPath::const_new("game/merges_per_sec");
+
+/// Number of rigid bodies that are currently awake.
+pub const AWAKE_BODIES: DiagnosticPath = DiagnosticPath::const_new("physics/awake_bodies");
+
+/// Every path registered by [`GameDiagnosticsPlugin`], in overlay order.
+pub const GAME_DIAGNOSTICS: [DiagnosticPath; 4] =
+    [LIVE_FRUITS, LIVE_PARTICLES, MERGES_PER_SEC, AWAKE_BODIES];
+
+/// Sliding window used for [`MERGES_PER_SEC`], in seconds.
+pub const MERGE_RATE_WINDOW: f64 = 1.0;
+
+/// Key that shows / hides the diagnostics overlay.
+pub const DIAGNOSTICS_OVERLAY_KEY: KeyCode = KeyCode::F3;
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Registers the game's custom diagnostics and, optionally, their overlay.
+#[derive(Debug, Clone)]
+pub struct GameDiagnosticsPlugin {
+    /// Adds the text overlay toggled by [`DIAGNOSTICS_OVERLAY_KEY`].
+    /// Defaults to `true` in debug builds only.
+    pub overlay: bool,
+    /// Adds the merge heatmap toggled by [`heatmap::HEATMAP_OVERLAY_KEY`].
+    /// Defaults to `true` in debug builds only.
+    pub heatmap: bool,
+    /// Shows the [`inspect`] tooltip for the fruit under the cursor.
+    /// Defaults to `true` in debug builds only.
+    pub fruit_tooltip: bool,
+}
+
+impl Default for GameDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            overlay: cfg!(debug_assertions),
+            heatmap: cfg!(debug_assertions),
+            fruit_tooltip: cfg!(debug_assertions),
+        }
+    }
+}
+
+impl GameDiagnosticsPlugin {
+    /// Enables or disables the on-screen overlay.
+    pub fn with_overlay(mut self, overlay: bool) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    /// Enables or disables the merge heatmap.
+    pub fn with_heatmap(mut self, heatmap: bool) -> Self {
+        self.heatmap = heatmap;
+        self
+    }
+
+    /// Enables or disables the fruit inspection tooltip.
+    pub fn with_fruit_tooltip(mut self, fruit_tooltip: bool) -> Self {
+        self.fruit_tooltip = fruit_tooltip;
+        self
+    }
+}
+
+impl Plugin for GameDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for path in GAME_DIAGNOSTICS {
+            // Counts are exact; smoothing would only blur them.
+            app.register_diagnostic(Diagnostic::new(path).with_smoothing_factor(0.0));
+        }
+
+        app.add_systems(
+            Update,
+            (
+                measure_entity_counts,
+                measure_awake_bodies,
+                measure_merge_rate,
+            ),
+        );
+
+        if self.overlay {
+            app.init_resource::<DiagnosticsOverlayVisible>();
+            app.add_systems(Startup, spawn_diagnostics_overlay);
+            app.add_systems(
+                Update,
+                (toggle_diagnostics_overlay, update_diagnostics_overlay).chain(),
+            );
+        }
+
+        if self.heatmap {
+            app.init_resource::<heatmap::MergeHeatmap>()
+                .init_resource::<heatmap::HeatmapOverlayVisible>();
+            app.add_systems(
+                Update,
+                (
+                    heatmap::record_merge_heatmap,
+                    heatmap::toggle_heatmap_overlay,
+                    heatmap::update_heatmap_overlay,
+                )
+                    .chain(),
+            );
+        }
+
+        if self.fruit_tooltip {
+            app.add_systems(Startup, inspect::spawn_fruit_tooltip);
+            app.add_systems(Update, inspect::update_fruit_tooltip);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Measurement systems
+// ---------------------------------------------------------------------------
+
+/// Records [`LIVE_FRUITS`] and [`LIVE_PARTICLES`].
+pub fn measure_entity_counts(
+    mut diagnostics: Diagnostics,
+    fruit_query: Query<(), With<Fruit>>,
+    particle_query: Query<(), Or<(With<WaterDroplet>, With<WatermelonBurstParticle>)>>,
+) {
+    diagnostics.add_measurement(&LIVE_FRUITS, || fruit_query.iter().count() as f64);
+    diagnostics.add_measurement(&LIVE_PARTICLES, || particle_query.iter().count() as f64);
+}
+
+/// Records [`AWAKE_BODIES`].
+pub fn measure_awake_bodies(mut diagnostics: Diagnostics, sleeping_query: Query<&Sleeping>) {
+    diagnostics.add_measurement(&AWAKE_BODIES, || {
+        sleeping_query.iter().filter(|s| !s.sleeping).count() as f64
+    });
+}
+
+/// Timestamps of recent merges for [`MERGES_PER_SEC`].
+#[derive(Debug, Default)]
+pub struct MergeRateWindow {
+    timestamps: VecDeque<f64>,
+}
+
+impl MergeRateWindow {
+    /// Records `count` merges at time `now`.
+    pub fn record(&mut self, now: f64, count: usize) {
+        self.timestamps.extend(std::iter::repeat_n(now, count));
+    }
+
+    /// Merges per second over the [`MERGE_RATE_WINDOW`] ending at `now`.
+    ///
+    /// Drops timestamps that have left the window.
+    pub fn rate(&mut self, now: f64) -> f64 {
+        while self
+            .timestamps
+            .front()
+            .is_some_and(|&t| now - t > MERGE_RATE_WINDOW)
+        {
+            self.timestamps.pop_front();
+        }
+        self.timestamps.len() as f64 / MERGE_RATE_WINDOW
+    }
+}
+
+/// Records [`MERGES_PER_SEC`].
+pub fn measure_merge_rate(
+    mut diagnostics: Diagnostics,
+    mut merge_events: MessageReader<FruitMergeEvent>,
+    time: Res<Time>,
+    mut window: Local<MergeRateWindow>,
+) {
+    let now = time.elapsed_secs_f64();
+    window.record(now, merge_events.read().count());
+    let rate = window.rate(now);
+    diagnostics.add_measurement(&MERGES_PER_SEC, || rate);
+}
+
+// ---------------------------------------------------------------------------
+// Overlay
+// ---------------------------------------------------------------------------
+
+/// Whether the diagnostics overlay is shown.
+#[derive(Resource, Debug, Default)]
+pub struct DiagnosticsOverlayVisible(pub bool);
+
+/// Marks the overlay's text node.
+#[derive(Component, Debug)]
+pub struct DiagnosticsOverlay;
+
+/// Spawns the (hidden) overlay in the top-left corner above all other UI.
+fn spawn_diagnostics_overlay(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(4.0),
+            left: Val::Px(4.0),
+            padding: UiRect::all(Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        GlobalZIndex(i32::MAX),
+        Visibility::Hidden,
+        DiagnosticsOverlay,
+    ));
+}
+
+/// Flips [`DiagnosticsOverlayVisible`] on [`DIAGNOSTICS_OVERLAY_KEY`].
+fn toggle_diagnostics_overlay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut visible: ResMut<DiagnosticsOverlayVisible>,
+) {
+    if keyboard.just_pressed(DIAGNOSTICS_OVERLAY_KEY) {
+        visible.0 = !visible.0;
+    }
+}
+
+/// Shows / hides the overlay and refreshes its text while visible.
+fn update_diagnostics_overlay(
+    visible: Res<DiagnosticsOverlayVisible>,
+    store: Res<DiagnosticsStore>,
+    mut overlay_query: Query<(&mut Text, &mut Visibility), With<DiagnosticsOverlay>>,
+) {
+    let Ok((mut text, mut visibility)) = overlay_query.single_mut() else {
+        return;
+    };
+
+    visibility.set_if_neq(if visible.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    });
+    if !visible.0 {
+        return;
+    }
+
+    text.0 = format_overlay(&store);
+}
+
+/// One `path: value` line per registered game diagnostic.
+fn format_overlay(store: &DiagnosticsStore) -> String {
+    GAME_DIAGNOSTICS
+        .iter()
+        .map(|path| {
+            let value = store.get(path).and_then(Diagnostic::value);
+            match value {
+                Some(v) => format!("{path}: {v:.0}"),
+                None => format!("{path}: -"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_diagnostics_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<FruitMergeEvent>();
+        app.add_plugins(
+            GameDiagnosticsPlugin::default()
+                .with_overlay(false)
+                .with_heatmap(false)
+                .with_fruit_tooltip(false),
+        );
+        app
+    }
+
+    fn value(app: &App, path: &DiagnosticPath) -> Option<f64> {
+        app.world()
+            .resource::<DiagnosticsStore>()
+            .get(path)
+            .and_then(Diagnostic::value)
+    }
+
+    #[test]
+    fn test_entity_counts() {
+        let mut app = setup_diagnostics_app();
+        app.world_mut().spawn(Fruit);
+        app.world_mut().spawn(Fruit);
+        app.world_mut().spawn(Sleeping::default());
+        app.world_mut().spawn(Sleeping {
+            sleeping: true,
+            ..default()
+        });
+        app.update();
+
+        assert_eq!(value(&app, &LIVE_FRUITS), Some(2.0));
+        assert_eq!(value(&app, &LIVE_PARTICLES), Some(0.0));
+        assert_eq!(value(&app, &AWAKE_BODIES), Some(1.0));
+    }
+
+    #[test]
+    fn test_merge_rate_window_expires_old_merges() {
+        let mut window = MergeRateWindow::default();
+        window.record(0.0, 3);
+        window.record(0.5, 1);
+        assert_eq!(window.rate(0.5), 4.0);
+        assert_eq!(window.rate(1.2), 1.0);
+        assert_eq!(window.rate(3.0), 0.0);
+    }
+
+    #[test]
+    fn test_format_overlay_lists_every_counter() {
+        let app = setup_diagnostics_app();
+        let text = format_overlay(app.world().resource::<DiagnosticsStore>());
+        assert_eq!(text.lines().count(), GAME_DIAGNOSTICS.len());
+        assert!(text.contains("game/live_fruits: -"));
+    }
+}
@@ -0,0 +1,332 @@
+//! Merge heatmap
+//!
+//! Every [`FruitMergeEvent`] position is binned into a grid of
+//! [`HEATMAP_CELL_SIZE`] cells covering the container, accumulated over the
+//! whole app session (runs are not reset).  With the heatmap enabled,
+//! [`HEATMAP_OVERLAY_KEY`] shows the grid as a translucent texture drawn over
+//! the container — cold cells in blue, the hottest in yellow — which makes it
+//! easy to see whether merges pile up against a wall or under the spawn
+//! point when tuning container dimensions and spawn offsets.
+//!
+//! The grid follows `physics.ron`: when the container size changes the
+//! heatmap restarts for the new dimensions.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::config::PhysicsParams;
+use crate::events::FruitMergeEvent;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Key that shows / hides the heatmap overlay.
+pub const HEATMAP_OVERLAY_KEY: KeyCode = KeyCode::F5;
+
+/// Edge length of one heatmap cell in world units (pixels).
+pub const HEATMAP_CELL_SIZE: f32 = 20.0;
+
+/// Z layer of the overlay sprite — above fruits and the drop preview.
+const HEATMAP_Z: f32 = 50.0;
+
+/// Overlay alpha of the coldest non-empty cell.
+const MIN_ALPHA: f32 = 0.25;
+
+/// Overlay alpha of the hottest cell.
+const MAX_ALPHA: f32 = 0.7;
+
+// ---------------------------------------------------------------------------
+// Resources / components
+// ---------------------------------------------------------------------------
+
+/// Merge counts binned over the container.
+///
+/// Row 0 is the top of the container, matching image row order.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MergeHeatmap {
+    size: Vec2,
+    columns: u32,
+    rows: u32,
+    counts: Vec<u32>,
+}
+
+impl MergeHeatmap {
+    /// An empty heatmap covering a container of `size`, centred on the origin.
+    pub fn new(size: Vec2) -> Self {
+        let columns = (size.x / HEATMAP_CELL_SIZE).ceil().max(1.0) as u32;
+        let rows = (size.y / HEATMAP_CELL_SIZE).ceil().max(1.0) as u32;
+        Self {
+            size,
+            columns,
+            rows,
+            counts: vec![0; (columns * rows) as usize],
+        }
+    }
+
+    /// Container size covered by the grid.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Grid dimensions as `(columns, rows)`.
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.columns, self.rows)
+    }
+
+    /// Merges recorded in the cell at `column`, `row`.
+    pub fn count(&self, column: u32, row: u32) -> u32 {
+        self.counts[(row * self.columns + column) as usize]
+    }
+
+    /// Highest count of any cell.
+    pub fn max_count(&self) -> u32 {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Total number of recorded merges.
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Cell containing world `position`, or `None` outside the container.
+    pub fn cell_at(&self, position: Vec2) -> Option<(u32, u32)> {
+        if self.counts.is_empty() {
+            return None;
+        }
+        let local = Vec2::new(
+            position.x + self.size.x / 2.0,
+            self.size.y / 2.0 - position.y,
+        );
+        if local.x < 0.0 || local.y < 0.0 || local.x > self.size.x || local.y > self.size.y {
+            return None;
+        }
+        let column = ((local.x / HEATMAP_CELL_SIZE) as u32).min(self.columns - 1);
+        let row = ((local.y / HEATMAP_CELL_SIZE) as u32).min(self.rows - 1);
+        Some((column, row))
+    }
+
+    /// Counts a merge at `position`; returns `false` if it lies outside the
+    /// container (e.g. above the top edge) and was ignored.
+    pub fn record(&mut self, position: Vec2) -> bool {
+        let Some((column, row)) = self.cell_at(position) else {
+            return false;
+        };
+        self.counts[(row * self.columns + column) as usize] += 1;
+        true
+    }
+}
+
+/// Whether the heatmap overlay is shown.
+#[derive(Resource, Debug, Default)]
+pub struct HeatmapOverlayVisible(pub bool);
+
+/// Marks the overlay sprite.
+#[derive(Component, Debug)]
+pub struct HeatmapOverlay;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Adds every merge position to [`MergeHeatmap`].
+///
+/// Rebuilds the grid first if the container in `physics.ron` changed size.
+pub fn record_merge_heatmap(
+    mut merge_events: MessageReader<FruitMergeEvent>,
+    physics: PhysicsParams,
+    mut heatmap: ResMut<MergeHeatmap>,
+) {
+    if let Some(config) = physics.get() {
+        let size = Vec2::new(config.container_width, config.container_height);
+        if heatmap.size() != size {
+            *heatmap = MergeHeatmap::new(size);
+        }
+    }
+    for event in merge_events.read() {
+        heatmap.record(event.position);
+    }
+}
+
+/// Flips [`HeatmapOverlayVisible`] on [`HEATMAP_OVERLAY_KEY`].
+pub(super) fn toggle_heatmap_overlay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut visible: ResMut<HeatmapOverlayVisible>,
+) {
+    if keyboard.just_pressed(HEATMAP_OVERLAY_KEY) {
+        visible.0 = !visible.0;
+    }
+}
+
+/// Shows / hides the overlay and redraws its texture when the heatmap changed.
+///
+/// The sprite is spawned the first time the overlay is shown once the grid
+/// has been sized from `physics.ron`.
+pub(super) fn update_heatmap_overlay(
+    mut commands: Commands,
+    visible: Res<HeatmapOverlayVisible>,
+    heatmap: Res<MergeHeatmap>,
+    mut images: ResMut<Assets<Image>>,
+    mut overlay_query: Query<(&mut Sprite, &mut Visibility), With<HeatmapOverlay>>,
+) {
+    let Ok((mut sprite, mut visibility)) = overlay_query.single_mut() else {
+        if visible.0 && !heatmap.counts.is_empty() {
+            commands.spawn((
+                Sprite {
+                    image: images.add(render_heatmap(&heatmap)),
+                    custom_size: Some(heatmap.size()),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, HEATMAP_Z),
+                HeatmapOverlay,
+            ));
+        }
+        return;
+    };
+
+    visibility.set_if_neq(if visible.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    });
+    if !visible.0 || !(heatmap.is_changed() || visible.is_changed()) {
+        return;
+    }
+
+    sprite.custom_size = Some(heatmap.size());
+    if let Some(image) = images.get_mut(&sprite.image) {
+        *image = render_heatmap(&heatmap);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Draws one pixel per cell, scaled against the hottest cell.
+fn render_heatmap(heatmap: &MergeHeatmap) -> Image {
+    let (columns, rows) = heatmap.dimensions();
+    let max = heatmap.max_count();
+    let mut data = Vec::with_capacity((columns * rows * 4) as usize);
+    for row in 0..rows {
+        for column in 0..columns {
+            let count = heatmap.count(column, row);
+            let color = if count == 0 {
+                Color::NONE
+            } else {
+                heat_color(count as f32 / max as f32)
+            };
+            data.extend_from_slice(&color.to_srgba().to_u8_array());
+        }
+    }
+
+    let mut image = Image::new(
+        Extent3d {
+            width: columns,
+            height: rows,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    // Keep cells crisp instead of smearing them across the container
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+/// Colour for heat `t` in `(0, 1]`: blue → red → yellow, growing more opaque.
+fn heat_color(t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let (r, g, b) = if t < 0.5 {
+        let k = t * 2.0;
+        (k, 0.0, 1.0 - k)
+    } else {
+        (1.0, (t - 0.5) * 2.0, 0.0)
+    };
+    Color::srgba(r, g, b, MIN_ALPHA + (MAX_ALPHA - MIN_ALPHA) * t)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_covers_container() {
+        let heatmap = MergeHeatmap::new(Vec2::new(600.0, 810.0));
+        assert_eq!(heatmap.dimensions(), (30, 41));
+        assert_eq!(heatmap.total(), 0);
+    }
+
+    #[test]
+    fn test_cell_at_maps_corners() {
+        let heatmap = MergeHeatmap::new(Vec2::new(100.0, 100.0));
+        assert_eq!(heatmap.cell_at(Vec2::new(-50.0, 50.0)), Some((0, 0)));
+        assert_eq!(heatmap.cell_at(Vec2::new(50.0, -50.0)), Some((4, 4)));
+        assert_eq!(heatmap.cell_at(Vec2::ZERO), Some((2, 2)));
+        assert_eq!(heatmap.cell_at(Vec2::new(0.0, 80.0)), None);
+        assert_eq!(MergeHeatmap::default().cell_at(Vec2::ZERO), None);
+    }
+
+    #[test]
+    fn test_record_accumulates_and_ignores_outside() {
+        let mut heatmap = MergeHeatmap::new(Vec2::new(100.0, 100.0));
+        assert!(heatmap.record(Vec2::new(-45.0, -45.0)));
+        assert!(heatmap.record(Vec2::new(-41.0, -42.0)));
+        assert!(!heatmap.record(Vec2::new(200.0, 0.0)));
+
+        assert_eq!(heatmap.count(0, 4), 2);
+        assert_eq!(heatmap.max_count(), 2);
+        assert_eq!(heatmap.total(), 2);
+    }
+
+    #[test]
+    fn test_record_merge_heatmap_reads_events() {
+        let mut app = App::new();
+        app.add_message::<FruitMergeEvent>()
+            .insert_resource(MergeHeatmap::new(Vec2::new(100.0, 100.0)))
+            .add_systems(Update, record_merge_heatmap);
+
+        let entity = app.world_mut().spawn_empty().id();
+        app.world_mut().write_message(FruitMergeEvent {
+            entity1: entity,
+            entity2: entity,
+            fruit_type: crate::fruit::FruitType::Cherry,
+            position: Vec2::new(10.0, 10.0),
+        });
+        app.update();
+
+        assert_eq!(app.world().resource::<MergeHeatmap>().count(3, 2), 1);
+    }
+
+    #[test]
+    fn test_render_heatmap_scales_to_hottest_cell() {
+        let mut heatmap = MergeHeatmap::new(Vec2::new(40.0, 20.0));
+        heatmap.record(Vec2::new(-10.0, 0.0));
+        heatmap.record(Vec2::new(-10.0, 0.0));
+        let image = render_heatmap(&heatmap);
+
+        assert_eq!(image.width(), 2);
+        assert_eq!(image.height(), 1);
+        let hot = image.get_color_at(0, 0).unwrap().to_srgba();
+        let empty = image.get_color_at(1, 0).unwrap().to_srgba();
+        assert!((hot.alpha - MAX_ALPHA).abs() < 0.01);
+        assert_eq!(empty.alpha, 0.0);
+    }
+
+    #[test]
+    fn test_heat_color_ramp() {
+        let cold = heat_color(0.0).to_srgba();
+        let hot = heat_color(1.0).to_srgba();
+        assert_eq!((cold.red, cold.blue), (0.0, 1.0));
+        assert_eq!((hot.red, hot.green, hot.blue), (1.0, 1.0, 0.0));
+        assert!(hot.alpha > cold.alpha);
+    }
+}
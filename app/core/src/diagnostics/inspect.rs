@@ -0,0 +1,211 @@
+//! Fruit inspection tooltip
+//!
+//! Hovering the cursor over a fruit shows a small world-space label above it
+//! with the fruit's type, entity id, velocity and Rapier sleep state — handy
+//! when diagnosing fruits that never settle or jitter in place.
+//!
+//! ```text
+//!   Melon #42v1
+//!   v (3, -120) |120|
+//!   awake
+//!      (●)
+//! ```
+
+use bevy::prelude::*;
+use bevy::sprite::{Anchor, Text2dShadow};
+use bevy::window::PrimaryWindow;
+use bevy_rapier2d::prelude::{Sleeping, Velocity};
+
+use crate::components::Fruit;
+use crate::config::FruitsParams;
+use crate::fruit::FruitType;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Gap between the top of the fruit and the tooltip (pixels).
+const TOOLTIP_GAP: f32 = 6.0;
+
+/// Z layer of the tooltip — above fruits, the preview and the heatmap.
+const TOOLTIP_Z: f32 = 60.0;
+
+const TOOLTIP_FONT_SIZE: f32 = 13.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the tooltip text entity.
+#[derive(Component, Debug)]
+pub struct FruitTooltip;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the (hidden) tooltip.
+pub(super) fn spawn_fruit_tooltip(mut commands: Commands) {
+    commands.spawn((
+        Text2d::new(""),
+        TextFont {
+            font_size: TOOLTIP_FONT_SIZE,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Text2dShadow {
+            offset: Vec2::new(1.0, -1.0),
+            color: Color::BLACK,
+        },
+        Anchor::BOTTOM_CENTER,
+        Transform::from_xyz(0.0, 0.0, TOOLTIP_Z),
+        Visibility::Hidden,
+        FruitTooltip,
+    ));
+}
+
+/// Moves the tooltip onto the fruit under the cursor, or hides it.
+#[allow(clippy::type_complexity)]
+pub(super) fn update_fruit_tooltip(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    fruits: FruitsParams,
+    fruit_query: Query<
+        (
+            Entity,
+            &FruitType,
+            &GlobalTransform,
+            Option<&Velocity>,
+            Option<&Sleeping>,
+        ),
+        With<Fruit>,
+    >,
+    mut tooltip_query: Query<(&mut Text2d, &mut Transform, &mut Visibility), With<FruitTooltip>>,
+) {
+    let Ok((mut text, mut transform, mut visibility)) = tooltip_query.single_mut() else {
+        return;
+    };
+
+    let cursor = windows
+        .single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .zip(camera_query.single().ok())
+        .and_then(|(cursor, (camera, camera_transform))| {
+            camera.viewport_to_world_2d(camera_transform, cursor).ok()
+        });
+    let hovered = cursor.zip(fruits.get()).and_then(|(cursor, config)| {
+        let candidates = fruit_query.iter().filter_map(|(entity, fruit, gt, ..)| {
+            let radius = fruit.try_parameters_from_config(config)?.radius;
+            let (scale, _, center) = gt.to_scale_rotation_translation();
+            Some((entity, center.truncate(), radius * scale.x))
+        });
+        find_hovered(cursor, candidates)
+    });
+
+    let Some((entity, fruit, gt, velocity, sleeping)) =
+        hovered.and_then(|(entity, _)| fruit_query.get(entity).ok())
+    else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    let radius = hovered.map_or(0.0, |(_, r)| r);
+    let center = gt.translation().truncate();
+
+    text.0 = format_tooltip(
+        entity,
+        *fruit,
+        velocity.map(|v| v.linvel),
+        sleeping.map(|s| s.sleeping),
+    );
+    transform.translation = center.extend(TOOLTIP_Z) + Vec3::Y * (radius + TOOLTIP_GAP);
+    visibility.set_if_neq(Visibility::Inherited);
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// The fruit whose circle contains `cursor`, preferring the nearest centre
+/// where circles overlap.  Candidates are `(entity, centre, radius)`;
+/// returns the entity with its radius.
+fn find_hovered(
+    cursor: Vec2,
+    candidates: impl Iterator<Item = (Entity, Vec2, f32)>,
+) -> Option<(Entity, f32)> {
+    candidates
+        .filter(|&(_, center, radius)| cursor.distance_squared(center) <= radius * radius)
+        .min_by(|a, b| {
+            cursor
+                .distance_squared(a.1)
+                .total_cmp(&cursor.distance_squared(b.1))
+        })
+        .map(|(entity, _, radius)| (entity, radius))
+}
+
+/// Tooltip text: type and entity, velocity, sleep state.
+fn format_tooltip(
+    entity: Entity,
+    fruit: FruitType,
+    velocity: Option<Vec2>,
+    sleeping: Option<bool>,
+) -> String {
+    let velocity = match velocity {
+        Some(v) => format!("v ({:.0}, {:.0}) |{:.0}|", v.x, v.y, v.length()),
+        None => "v -".to_string(),
+    };
+    let sleep = match sleeping {
+        Some(true) => "asleep",
+        Some(false) => "awake",
+        None => "no sleep state",
+    };
+    format!("{fruit:?} #{entity}\n{velocity}\n{sleep}")
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_hovered_picks_nearest_containing_fruit() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let candidates = [(a, Vec2::ZERO, 30.0), (b, Vec2::new(20.0, 0.0), 30.0)];
+
+        assert_eq!(
+            find_hovered(Vec2::new(15.0, 0.0), candidates.into_iter()),
+            Some((b, 30.0))
+        );
+        assert_eq!(
+            find_hovered(Vec2::new(-10.0, 0.0), candidates.into_iter()),
+            Some((a, 30.0))
+        );
+        assert_eq!(
+            find_hovered(Vec2::new(0.0, 100.0), candidates.into_iter()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_format_tooltip() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let text = format_tooltip(
+            entity,
+            FruitType::Melon,
+            Some(Vec2::new(3.0, -4.0)),
+            Some(false),
+        );
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], format!("Melon #{entity}"));
+        assert_eq!(lines[1], "v (3, -4) |5|");
+        assert_eq!(lines[2], "awake");
+        assert!(format_tooltip(entity, FruitType::Cherry, None, Some(true)).ends_with("asleep"));
+    }
+}
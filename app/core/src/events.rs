@@ -27,6 +27,55 @@ pub struct ScoreEarnedEvent {
     pub fruit_type: FruitType,
 }
 
+/// Event emitted by `tick_combo_timer` when a combo of at least
+/// `COMBO_BREAK_MIN` merges runs out of time.
+///
+/// Lets the audio and HUD give feedback that the chain window closed.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComboBrokenEvent {
+    /// Combo count the chain had reached before it expired.
+    pub combo: u32,
+}
+
+/// Event emitted when the boundary-overflow warning turns on or off.
+///
+/// Sent by `check_boundary_overflow` only on transitions, so listeners can
+/// start and stop warning feedback (vignette, audio) without polling
+/// `GameOverTimer` every frame.  The warning's progress toward game over is
+/// still read from `GameOverTimer::warning_progress()`.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryWarningEvent {
+    /// A fruit has risen above the boundary line.
+    Started,
+    /// Every fruit is back below the boundary line.
+    Cleared,
+}
+
+/// Event emitted when the player drops the held fruit.
+///
+/// Sent by `handle_fruit_drop_input` at the moment the fruit switches from
+/// `Held` to `Falling`.  Lets UI and statistics systems count drops without
+/// polling fruit state.
+#[derive(Message, Debug, Clone)]
+pub struct FruitDroppedEvent {
+    /// Type of the dropped fruit
+    pub fruit_type: FruitType,
+    /// World position the fruit was released from
+    pub position: Vec2,
+}
+
+/// Event emitted when the pile has come to rest.
+///
+/// Sent by `detect_board_settled` once per settle: after a drop or merge
+/// set the board moving, the first frame on which no fruit is falling and
+/// every landed fruit has been nearly still for a moment.  Marks a safe
+/// point where the board can be saved or inspected without fruits in flight.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardSettledEvent {
+    /// Number of landed fruits on the settled board
+    pub fruit_count: usize,
+}
+
 /// Event triggered when two fruits of the same type collide and merge
 ///
 /// This event is sent by the collision detection system when it detects
@@ -71,3 +120,93 @@ pub struct FruitMergeEvent {
     /// World position where the merge occurs (typically the midpoint)
     pub position: Vec2,
 }
+
+/// Event emitted alongside a [`FruitMergeEvent`] when the merge was set off
+/// by an earlier merge.
+///
+/// Sent by the collision detection system when at least one of the merging
+/// fruits was itself born from a merge moments ago (see `ChainLink`).  The
+/// score system looks it up by entity pair and applies the chain multiplier
+/// from `game_rules.ron` `chain_bonuses`.
+#[derive(Message, Debug, Clone)]
+pub struct ChainMergeEvent {
+    /// First fruit entity of the chained merge
+    pub entity1: Entity,
+    /// Second fruit entity of the chained merge
+    pub entity2: Entity,
+    /// Type of fruits being merged
+    pub fruit_type: FruitType,
+    /// World position where the merge occurs
+    pub position: Vec2,
+    /// Chain depth: 1 for a merge caused by a merge, 2 for one caused by a
+    /// chained merge, and so on
+    pub depth: u32,
+}
+
+/// Event emitted when one or more RON configs could not be loaded in time.
+///
+/// Sent once by `wait_for_configs` after the built-in copies of the missing
+/// configs have been inserted.  The same list is kept in
+/// `ConfigLoadStatus::fallback` for screens spawned later.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ConfigLoadFailedEvent {
+    /// Asset paths of the configs that fell back to built-in defaults.
+    pub missing: Vec<&'static str>,
+}
+
+/// Event emitted when a config file fails to load or hot-reload.
+///
+/// Sent by `report_config_load_errors` with the loader's
+/// [`ConfigError`](crate::config::ConfigError) rendered for the player,
+/// e.g. `config/physics.ron line 4: expected float`.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ConfigErrorEvent {
+    /// Human-readable description including the file and line.
+    pub message: String,
+}
+
+/// Event emitted when a config file was edited and re-read successfully.
+///
+/// Sent by [`report_config_reloads`](crate::config::report_config_reloads);
+/// a reload that fails to parse sends a [`ConfigErrorEvent`] instead.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ConfigReloadedEvent {
+    /// Asset path of the file, e.g. `config/physics.ron`.
+    pub path: String,
+}
+
+/// Event emitted when a tracked asset (e.g. a sound file) fails to load.
+///
+/// Sent once per file by `wait_for_configs` when leaving `Loading`; the
+/// paths are also kept in [`AssetLoadStatus`](crate::loading::AssetLoadStatus).
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct AssetErrorEvent {
+    /// Human-readable description including the collection and file.
+    pub message: String,
+}
+
+/// Event emitted when a save file cannot be read or written.
+///
+/// Sent by the persistence systems with the
+/// [`SaveError`](crate::persistence::SaveError) rendered for the player,
+/// e.g. `save/settings.json line 4: invalid value`.  The game keeps running
+/// on defaults; the event only makes the problem visible.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SaveErrorEvent {
+    /// Human-readable description including the file and line.
+    pub message: String,
+}
+
+/// Event emitted once the save files have been read at startup.
+///
+/// Sent by [`load_save_data_startup`](crate::persistence::load_save_data_startup)
+/// after highscore, settings, lifetime stats and the saved-session flag are
+/// in their resources — also when a file was corrupted or persistence is
+/// disabled.  `Loading` waits for it before showing the title screen.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SaveDataLoaded {
+    /// All-time best score read from `highscore.json`.
+    pub highscore: u32,
+    /// Whether a saved session can be continued.
+    pub session_available: bool,
+}
@@ -5,9 +5,10 @@
 
 use crate::config::FruitsConfig;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Represents the 11 fruit types in the evolution chain
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Serialize, Deserialize)]
 pub enum FruitType {
     /// Cherry - smallest fruit (stage 1), spawnable
     Cherry,
@@ -0,0 +1,283 @@
+//! Launch options from the command line and environment
+//!
+//! Lets itch builds, CI runs and speedrunners change paths and seeds without
+//! editing assets:
+//!
+//! | Flag                 | Environment        | Effect                                    |
+//! |----------------------|--------------------|-------------------------------------------|
+//! | `--config-dir <DIR>` | `SUIKA_CONFIG_DIR` | Load the `config/*.ron` files from `DIR`  |
+//! | `--save-dir <DIR>`   | `SUIKA_SAVE_DIR`   | Read and write save files in `DIR`        |
+//! | `--mods-dir <DIR>`   | `SUIKA_MODS_DIR`   | Load user mods from `DIR`                 |
+//! | `--seed <N>`         | `SUIKA_SEED`       | Seed every game's fruit sequence with `N` |
+//! | `--mode <MODE>`      | `SUIKA_MODE`       | Play with [`GameMode`] `MODE`             |
+//!
+//! User mods are described in [`crate::mods`].
+//!
+//! Flags accept both `--flag value` and `--flag=value`; a flag wins over its
+//! environment variable.  The parsed [`LaunchOptions`] must be inserted as a
+//! resource **before** [`GameCorePlugin`](crate::GameCorePlugin) and
+//! [`GameConfigPlugin`](crate::config::GameConfigPlugin) are added, and
+//! [`LaunchOptions::register_asset_sources`] must run before `AssetPlugin`:
+//!
+//! ```ignore
+//! let launch = LaunchOptions::from_env()?;
+//! let mut app = App::new();
+//! launch.register_asset_sources(&mut app);
+//! app.insert_resource(launch)
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(GameConfigPlugin)
+//!     .add_plugins(GameCorePlugin::default());
+//! ```
+
+use std::fmt;
+use std::path::PathBuf;
+
+use bevy::asset::io::AssetSourceBuilder;
+use bevy::prelude::*;
+
+use crate::resources::GameMode;
+
+/// Asset source the config files are loaded from when `--config-dir` is set.
+pub const CONFIG_ASSET_SOURCE: &str = "config";
+
+/// Environment variable for `--config-dir`.
+pub const CONFIG_DIR_ENV: &str = "SUIKA_CONFIG_DIR";
+/// Environment variable for `--save-dir`.
+pub const SAVE_DIR_ENV: &str = "SUIKA_SAVE_DIR";
+/// Environment variable for `--mods-dir`.
+pub const MODS_DIR_ENV: &str = "SUIKA_MODS_DIR";
+/// Environment variable for `--seed`.
+pub const SEED_ENV: &str = "SUIKA_SEED";
+/// Environment variable for `--mode`.
+pub const MODE_ENV: &str = "SUIKA_MODE";
+
+// ---------------------------------------------------------------------------
+// LaunchOptions
+// ---------------------------------------------------------------------------
+
+/// Overrides given at startup; `None` keeps the built-in default.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaunchOptions {
+    /// Directory holding the config RON files (same layout as `assets/config`).
+    pub config_dir: Option<PathBuf>,
+    /// Directory for highscore, settings and session files.
+    pub save_dir: Option<PathBuf>,
+    /// Directory scanned for user mods instead of
+    /// [`MODS_DIR`](crate::constants::storage::MODS_DIR).
+    pub mods_dir: Option<PathBuf>,
+    /// Fixed [`SpawnRng`](crate::resources::SpawnRng) seed for every game.
+    pub seed: Option<u64>,
+    /// Game mode for the whole run.
+    pub mode: Option<GameMode>,
+}
+
+/// Why the launch options could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchOptionsError {
+    /// An argument that is not one of the known flags.
+    UnknownFlag(String),
+    /// A flag given without its value.
+    MissingValue(&'static str),
+    /// A flag or environment variable with a malformed value.
+    InvalidValue {
+        /// Flag or environment variable name.
+        name: &'static str,
+        /// Why the value was rejected.
+        reason: String,
+    },
+}
+
+impl fmt::Display for LaunchOptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFlag(flag) => write!(f, "unknown argument '{flag}'"),
+            Self::MissingValue(flag) => write!(f, "{flag} needs a value"),
+            Self::InvalidValue { name, reason } => write!(f, "invalid {name}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for LaunchOptionsError {}
+
+impl LaunchOptions {
+    /// Parses the process arguments and environment.
+    pub fn from_env() -> Result<Self, LaunchOptionsError> {
+        Self::parse(std::env::args().skip(1), |key| std::env::var(key).ok())
+    }
+
+    /// Parses `args` (without the program name), falling back to `env` for
+    /// every option the arguments leave unset.
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, LaunchOptionsError> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let flag: &'static str = match flag.as_str() {
+                "--config-dir" => "--config-dir",
+                "--save-dir" => "--save-dir",
+                "--mods-dir" => "--mods-dir",
+                "--seed" => "--seed",
+                "--mode" => "--mode",
+                _ => return Err(LaunchOptionsError::UnknownFlag(flag)),
+            };
+            let value = inline_value
+                .or_else(|| args.next())
+                .ok_or(LaunchOptionsError::MissingValue(flag))?;
+            options.set(flag, &value)?;
+        }
+
+        for (flag, key) in [
+            ("--config-dir", CONFIG_DIR_ENV),
+            ("--save-dir", SAVE_DIR_ENV),
+            ("--mods-dir", MODS_DIR_ENV),
+            ("--seed", SEED_ENV),
+            ("--mode", MODE_ENV),
+        ] {
+            if options.is_set(flag) {
+                continue;
+            }
+            if let Some(value) = env(key).filter(|v| !v.is_empty()) {
+                options.set(flag, &value).map_err(|e| match e {
+                    LaunchOptionsError::InvalidValue { reason, .. } => {
+                        LaunchOptionsError::InvalidValue { name: key, reason }
+                    }
+                    other => other,
+                })?;
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Registers the [`CONFIG_ASSET_SOURCE`] asset source for `--config-dir`
+    /// and the [`MODS_ASSET_SOURCE`](crate::mods::MODS_ASSET_SOURCE) source
+    /// for the mods directory, and inserts the scanned
+    /// [`ModList`](crate::mods::ModList).
+    ///
+    /// Asset sources are frozen once `AssetPlugin` is built, so call this on
+    /// the fresh `App` before adding `DefaultPlugins`.  Registers neither
+    /// source when no config directory was given and no mods were found.
+    pub fn register_asset_sources(&self, app: &mut App) {
+        crate::mods::register_mods(app, self);
+        if let Some(dir) = &self.config_dir {
+            info!("📂 Loading configs from {}", dir.display());
+            app.register_asset_source(
+                CONFIG_ASSET_SOURCE,
+                AssetSourceBuilder::platform_default(&dir.to_string_lossy(), None),
+            );
+        }
+    }
+
+    fn is_set(&self, flag: &str) -> bool {
+        match flag {
+            "--config-dir" => self.config_dir.is_some(),
+            "--save-dir" => self.save_dir.is_some(),
+            "--mods-dir" => self.mods_dir.is_some(),
+            "--seed" => self.seed.is_some(),
+            "--mode" => self.mode.is_some(),
+            _ => false,
+        }
+    }
+
+    fn set(&mut self, flag: &'static str, value: &str) -> Result<(), LaunchOptionsError> {
+        let invalid = |reason: String| LaunchOptionsError::InvalidValue { name: flag, reason };
+        match flag {
+            "--config-dir" => self.config_dir = Some(value.into()),
+            "--save-dir" => self.save_dir = Some(value.into()),
+            "--mods-dir" => self.mods_dir = Some(value.into()),
+            "--seed" => self.seed = Some(value.parse().map_err(|e| invalid(format!("{e}")))?),
+            "--mode" => self.mode = Some(value.parse().map_err(invalid)?),
+            _ => unreachable!("flags are validated by the caller"),
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str], env: &[(&str, &str)]) -> Result<LaunchOptions, LaunchOptionsError> {
+        let env: Vec<(String, String)> = env
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        LaunchOptions::parse(args.iter().map(|a| a.to_string()), |key| {
+            env.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+        })
+    }
+
+    #[test]
+    fn test_no_arguments_is_default() {
+        assert_eq!(parse(&[], &[]), Ok(LaunchOptions::default()));
+    }
+
+    #[test]
+    fn test_parses_separate_and_inline_values() {
+        let options = parse(
+            &[
+                "--config-dir",
+                "mods/cfg",
+                "--save-dir=/tmp/save",
+                "--mods-dir=/tmp/mods",
+                "--seed",
+                "42",
+                "--mode=no-combo",
+            ],
+            &[],
+        )
+        .unwrap();
+        assert_eq!(options.config_dir, Some(PathBuf::from("mods/cfg")));
+        assert_eq!(options.save_dir, Some(PathBuf::from("/tmp/save")));
+        assert_eq!(options.mods_dir, Some(PathBuf::from("/tmp/mods")));
+        assert_eq!(options.seed, Some(42));
+        assert_eq!(options.mode, Some(GameMode::NoCombo));
+    }
+
+    #[test]
+    fn test_environment_fills_unset_options() {
+        let options = parse(
+            &["--seed", "7"],
+            &[(SEED_ENV, "99"), (SAVE_DIR_ENV, "ci-save")],
+        )
+        .unwrap();
+        assert_eq!(options.seed, Some(7), "flag wins over environment");
+        assert_eq!(options.save_dir, Some(PathBuf::from("ci-save")));
+    }
+
+    #[test]
+    fn test_empty_environment_value_is_ignored() {
+        assert_eq!(parse(&[], &[(SEED_ENV, "")]), Ok(LaunchOptions::default()));
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        assert_eq!(
+            parse(&["--fullscreen"], &[]),
+            Err(LaunchOptionsError::UnknownFlag("--fullscreen".to_string()))
+        );
+        assert_eq!(
+            parse(&["--seed"], &[]),
+            Err(LaunchOptionsError::MissingValue("--seed"))
+        );
+        assert!(matches!(
+            parse(&["--seed", "abc"], &[]),
+            Err(LaunchOptionsError::InvalidValue { name: "--seed", .. })
+        ));
+        assert!(matches!(
+            parse(&[], &[(MODE_ENV, "hard")]),
+            Err(LaunchOptionsError::InvalidValue { name: MODE_ENV, .. })
+        ));
+    }
+}
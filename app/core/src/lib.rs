@@ -21,33 +21,56 @@
 //! fn main() {
 //!     App::new()
 //!         .add_plugins(DefaultPlugins)
-//!         .add_plugins(GameCorePlugin)
+//!         .add_plugins(GameCorePlugin::default())
 //!         .run();
 //! }
 //! ```
 //!
 //! ## Module Organization
 //!
+//! - [`build_info`]: Version and git commit of the build
 //! - [`components`]: ECS components for game entities
 //! - [`constants`]: Game configuration constants
 //! - [`events`]: Custom game events for event-driven architecture
 //! - [`fruit`]: Fruit type definitions and parameters
+//! - [`launch`]: Command-line / environment overrides
+//! - [`loading`]: Asset collections tracked by the Loading screen
+//! - [`mods`]: User mods overriding bundled assets
 //! - [`persistence`]: Highscore save/load functionality
+//! - [`platform`]: Store-platform hooks (achievements, cloud saves)
+//! - [`profiles`]: Player profiles, each with its own save files
 //! - [`resources`]: Bevy resources for game state
+//! - `scripting`: Rhai rules scripts from user mods (`scripting` feature)
+//! - [`seed_code`]: Shareable seed / mode / difficulty codes
 //! - [`states`]: Application state definitions
+//! - [`transition`]: Animated screen transitions between states
 
 use bevy::prelude::*;
 
 // Module declarations
+pub mod build_info;
 pub mod components;
 pub mod config;
 pub mod constants;
+pub mod diagnostics;
 pub mod events;
 pub mod fruit;
+pub mod launch;
+pub mod loading;
+pub mod mods;
 pub mod persistence;
+pub mod platform;
+pub mod profiles;
 pub mod resources;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+pub mod seed_code;
 pub mod states;
 pub mod systems;
+pub mod transition;
+
+#[cfg(test)]
+mod proptests;
 
 // Re-export commonly used types for convenience
 pub mod prelude {
@@ -58,8 +81,8 @@ pub mod prelude {
 
     // Components
     pub use crate::components::{
-        BottomWall, BoundaryLine, Container, Dropping, Fruit, FruitSpawnState, LeftWall,
-        MergeCandidate, NextFruitPreview, RightWall,
+        BottomWall, BoundaryLine, ChainLink, Container, Dropping, Fruit, FruitSpawnState,
+        LandingPreview, LeftWall, MergeCandidate, MergeGhost, RightWall,
     };
 
     // Fruit system
@@ -68,37 +91,79 @@ pub mod prelude {
     // Resources
     pub use crate::resources::settings::{Language, SettingsResource};
     pub use crate::resources::{
-        CircleTexture, ComboTimer, FruitSprites, GameOverTimer, GameState, NextFruitType,
+        CircleTexture, ComboTimer, FruitQueue, FruitSprites, GameMode, GameOverTimer, GameState,
+        NextFruitType, ScoreStrategy, SessionStats, SpawnRng, StatsResource,
     };
+    pub use crate::systems::achievements::{Achievement, AchievementUnlockedEvent};
+    pub use crate::systems::action::{ActionState, InputAction, OnScreenActions, PointerOverUi};
+    pub use crate::systems::adaptive_spawn::{AdaptiveSpawn, ContainerFillLevel};
+    pub use crate::systems::attract::AttractMode;
+    pub use crate::systems::background::BackgroundThrottle;
+    pub use crate::systems::control::ControlSource;
     pub use crate::systems::input::{InputMode, LastCursorPosition, SpawnPosition};
+    pub use crate::systems::melon_link::MelonPair;
+    pub use crate::systems::safe_mode::SafeMode;
+    pub use crate::systems::sandbox::SandboxMode;
+    pub use crate::systems::settle::BoardSettled;
 
     // States
-    pub use crate::states::AppState;
+    pub use crate::states::{AppState, ReturnTo};
+
+    // Launch options
+    pub use crate::launch::{LaunchOptions, LaunchOptionsError};
+
+    // User mods
+    pub use crate::mods::{ModInfo, ModList};
+
+    // Loading
+    pub use crate::loading::{AssetLoadStatus, LoadProgress, LoadingProgress, TrackedAssets};
+
+    // Screen transitions
+    pub use crate::transition::{ScreenTransition, TransitionStyle, screen_transition_idle};
+
+    // Build info
+    pub use crate::build_info::BuildInfo;
+
+    // Seed codes
+    pub use crate::seed_code::{ChallengeSeed, RetrySeed, SeedCode, SeedCodeError};
 
     // Constants (re-export module for namespaced access)
     pub use crate::constants;
 
     // Persistence
     pub use crate::persistence::{
-        HighscoreData, load_highscore, load_settings, save_highscore, save_settings,
-        update_highscore,
+        BestRun, DropSample, ExportFormat, HighscoreData, HistoryExport, LeaderboardEntry,
+        PendingSessionRestore, SaveDir, SaveError, SavedFruit, ScoreSample, SessionAvailable,
+        SessionData, SessionSummary, delete_session, export_history, load_highscore, load_session,
+        load_session_summaries, load_settings, load_stats, report_save_error, save_highscore,
+        save_session, save_session_summary, save_settings, save_stats, session_exists,
+        try_load_highscore, try_load_session, try_load_settings, try_load_stats, update_highscore,
     };
 
+    // Profiles
+    pub use crate::profiles::CurrentProfile;
+
     // Systems
     pub use crate::systems;
+    pub use crate::systems::board::{BoardCommands, BoardFruit, BoardSnapshot, capture_board};
 
     // Config
     pub use crate::config::{
-        BounceConfig, BounceConfigHandle, BounceParams, DropletColorMode, DropletConfig,
-        DropletConfigHandle, DropletParams, FlashConfig, FlashConfigHandle, FlashParams,
-        FruitConfigEntry, FruitsConfig, FruitsConfigHandle, FruitsParams, GameConfigPlugin,
-        GameRulesConfig, GameRulesConfigHandle, GameRulesParams, PhysicsConfig,
-        PhysicsConfigHandle, PhysicsParams, RonColor, ShakeConfig, ShakeConfigHandle, ShakeParams,
-        WatermelonConfig, WatermelonConfigHandle, WatermelonParams,
+        BounceConfig, BounceConfigHandle, BounceParams, BoundaryConfig, BoundaryConfigHandle,
+        BoundaryParams, ConfigError, ConfigLoadStatus, DropletColorMode, DropletConfig,
+        DropletConfigHandle, DropletParams, DustConfig, DustConfigHandle, DustParams, FlashConfig,
+        FlashConfigHandle, FlashParams, FruitConfigEntry, FruitsConfig, FruitsConfigHandle,
+        FruitsParams, GameConfigPlugin, GameRulesConfig, GameRulesConfigHandle, GameRulesParams,
+        PhysicsConfig, PhysicsConfigHandle, PhysicsParams, PunchConfig, RonColor, ShakeConfig,
+        ShakeConfigHandle, ShakeParams, WatermelonConfig, WatermelonConfigHandle, WatermelonParams,
     };
 
     // Events
-    pub use crate::events::{FruitMergeEvent, ScoreEarnedEvent};
+    pub use crate::events::{
+        AssetErrorEvent, BoardSettledEvent, BoundaryWarningEvent, ComboBrokenEvent,
+        ConfigErrorEvent, ConfigLoadFailedEvent, ConfigReloadedEvent, FruitDroppedEvent,
+        FruitMergeEvent, SaveDataLoaded, SaveErrorEvent, ScoreEarnedEvent,
+    };
 
     // Collision
     pub use crate::systems::collision::ProcessedCollisions;
@@ -114,13 +179,20 @@ pub mod prelude {
     pub use crate::systems::effects::bounce::SquashStretchAnimation;
     pub use crate::systems::effects::droplet::WaterDroplet;
     pub use crate::systems::effects::flash::{LocalFlashAnimation, ScreenFlashAnimation};
+    pub use crate::systems::effects::punch::CameraPunch;
     pub use crate::systems::effects::shake::CameraShake;
     pub use crate::systems::effects::watermelon::{
         WatermelonBurstParticle, WatermelonExplosionRing,
     };
 
+    // Platform
+    pub use crate::platform::{
+        NullPlatform, Platform, PlatformError, PlatformPlugin, PlatformServices,
+    };
+
     // Plugin
     pub use crate::GameCorePlugin;
+    pub use crate::diagnostics::GameDiagnosticsPlugin;
 }
 
 /// Core game plugin
@@ -128,15 +200,26 @@ pub mod prelude {
 /// This plugin initializes the core game systems and registers
 /// all necessary resources and states with the Bevy app.
 ///
-/// # What it does
+/// # Configuration
+///
+/// `GameCorePlugin::default()` enables everything and saves to
+/// [`constants::storage::SAVE_DIR`].  Tests, the headless simulator and
+/// embedders can switch subsystems off with the builder methods:
 ///
-/// Currently, this plugin serves as a placeholder for future system registration.
-/// In upcoming phases, it will:
-/// - Initialize application state (`AppState`)
-/// - Register game resources (`GameState`, `ComboTimer`, etc.)
-/// - Set up physics systems
-/// - Configure collision detection
-/// - Register game logic systems
+/// | Option               | Default     | Effect when changed |
+/// |----------------------|-------------|---------------------|
+/// | [`headless`]         | `false`     | Skips texture generation and all visual effect systems |
+/// | [`with_effects`]     | `true`      | `false` skips particle / flash / shake / watermelon effects |
+/// | [`with_persistence`] | `true`      | `false` never reads or writes save files |
+/// | [`with_save_dir`]    | `save`      | Directory used for all save files |
+///
+/// A [`launch::LaunchOptions`] resource inserted before the plugin is added
+/// overrides the save directory and selects the [`resources::GameMode`].
+///
+/// [`headless`]: GameCorePlugin::headless
+/// [`with_effects`]: GameCorePlugin::with_effects
+/// [`with_persistence`]: GameCorePlugin::with_persistence
+/// [`with_save_dir`]: GameCorePlugin::with_save_dir
 ///
 /// # Example
 ///
@@ -147,11 +230,68 @@ pub mod prelude {
 /// fn main() {
 ///     App::new()
 ///         .add_plugins(DefaultPlugins)
-///         .add_plugins(GameCorePlugin)
+///         .add_plugins(GameCorePlugin::default())
 ///         .run();
 /// }
 /// ```
-pub struct GameCorePlugin;
+///
+/// Headless, with no disk I/O:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use suika_game_core::GameCorePlugin;
+/// App::new()
+///     .add_plugins(MinimalPlugins)
+///     .add_plugins(GameCorePlugin::default().headless(true).with_persistence(false));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GameCorePlugin {
+    /// Run without rendering: no generated textures, no visual effects.
+    pub headless: bool,
+    /// Register the optional visual effect systems.
+    pub enable_effects: bool,
+    /// Load and save highscore, settings and sessions on disk.
+    pub enable_persistence: bool,
+    /// Directory used for save files when persistence is enabled.
+    pub save_dir: std::path::PathBuf,
+}
+
+impl Default for GameCorePlugin {
+    fn default() -> Self {
+        Self {
+            headless: false,
+            enable_effects: true,
+            enable_persistence: true,
+            save_dir: constants::storage::SAVE_DIR.into(),
+        }
+    }
+}
+
+impl GameCorePlugin {
+    /// Runs without rendering-dependent systems.
+    pub fn headless(mut self, headless: bool) -> Self {
+        self.headless = headless;
+        self
+    }
+
+    /// Enables or disables the optional visual effect systems.
+    pub fn with_effects(mut self, enabled: bool) -> Self {
+        self.enable_effects = enabled;
+        self
+    }
+
+    /// Enables or disables all save-file reads and writes.
+    pub fn with_persistence(mut self, enabled: bool) -> Self {
+        self.enable_persistence = enabled;
+        self
+    }
+
+    /// Uses `dir` instead of [`constants::storage::SAVE_DIR`] for save files.
+    pub fn with_save_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.save_dir = dir.into();
+        self
+    }
+}
 
 impl Plugin for GameCorePlugin {
     fn build(&self, app: &mut App) {
@@ -160,21 +300,76 @@ impl Plugin for GameCorePlugin {
         // Initialize application state
         app.init_state::<states::AppState>();
 
+        let launch = app
+            .world()
+            .get_resource::<launch::LaunchOptions>()
+            .cloned()
+            .unwrap_or_default();
+
+        // Save location; `None` turns every persistence system into a no-op
+        let save_dir = launch.save_dir.unwrap_or_else(|| self.save_dir.clone());
+        let save_dir = persistence::SaveDir(self.enable_persistence.then_some(save_dir));
+        // Every save file is read from and written to the selected profile
+        let profile = profiles::CurrentProfile::load(save_dir.path());
+        // Read now so the PreStartup cloud pull already honours it
+        app.insert_resource(systems::safe_mode::SafeMode::load(profile.path()))
+            .insert_resource(profile)
+            .insert_resource(save_dir);
+
+        // The mode decides the score rules unless a strategy was inserted already
+        let mode = launch.mode.unwrap_or_default();
+        app.insert_resource(mode);
+        if !app.world().contains_resource::<resources::ScoreStrategy>() {
+            app.insert_resource(mode.score_strategy());
+        }
+        let effects = self.enable_effects && !self.headless;
+
+        let build_info = build_info::BuildInfo::CURRENT;
+        info!("Suika Game {build_info}");
+        app.insert_resource(build_info);
+
         // Initialize game resources
         app.init_resource::<resources::GameState>()
             .init_resource::<resources::ComboTimer>()
             .init_resource::<resources::GameOverTimer>()
             .init_resource::<resources::NextFruitType>()
+            .init_resource::<resources::FruitQueue>()
+            .init_resource::<resources::SpawnRng>()
+            .init_resource::<resources::SessionStats>()
+            .init_resource::<resources::StatsResource>()
+            .init_resource::<seed_code::ChallengeSeed>()
+            .init_resource::<seed_code::RetrySeed>()
+            .init_resource::<systems::sandbox::SandboxMode>()
+            .init_resource::<systems::attract::AttractMode>()
+            .init_resource::<systems::control::ControlSource>()
+            .init_resource::<systems::background::BackgroundThrottle>()
             .init_resource::<resources::SettingsResource>()
             .init_resource::<systems::input::SpawnPosition>()
             .init_resource::<systems::input::InputMode>()
-            .init_resource::<systems::input::LastCursorPosition>();
+            .init_resource::<systems::input::LastCursorPosition>()
+            .init_resource::<systems::action::ActionState>()
+            .init_resource::<systems::action::PointerOverUi>()
+            .init_resource::<systems::action::OnScreenActions>()
+            .init_resource::<systems::assist::AssistState>()
+            .init_resource::<systems::settle::BoardSettled>()
+            .init_resource::<systems::recap::RecapBuffer>()
+            .init_resource::<systems::recap::RecapPlayback>()
+            .init_resource::<systems::melon_link::MelonPair>()
+            .init_resource::<systems::adaptive_spawn::ContainerFillLevel>()
+            .init_resource::<systems::adaptive_spawn::AdaptiveSpawn>()
+            .init_resource::<persistence::SessionAvailable>()
+            .init_resource::<persistence::BestRun>()
+            .init_resource::<persistence::PendingSessionRestore>()
+            .init_resource::<transition::ScreenTransition>()
+            .init_resource::<states::ReturnTo>();
 
         // Register CircleTexture immediately (default = invalid handle) so any
         // Startup system can safely declare Res<CircleTexture> without ordering
         // constraints.  setup_circle_texture then fills in the real texture.
         app.init_resource::<resources::CircleTexture>();
-        app.add_systems(Startup, systems::spawn::setup_circle_texture);
+        if !self.headless {
+            app.add_systems(Startup, systems::spawn::setup_circle_texture);
+        }
 
         // FruitSprites is populated at Startup by the assets crate's load_fruit_sprites.
         // Initialise the empty resource here so core systems can always use
@@ -182,17 +377,34 @@ impl Plugin for GameCorePlugin {
         app.init_resource::<resources::FruitSprites>();
 
         // Load persisted data into resources at startup
-        app.add_systems(
-            Startup,
-            (
-                persistence::load_highscore_startup,
-                persistence::load_settings_startup,
-            ),
-        );
+        app.add_systems(Startup, persistence::load_save_data_startup)
+            // ...and again when the player switches profiles, before the
+            // next state transition redraws the screen
+            .add_systems(
+                PreUpdate,
+                persistence::load_save_data_startup.run_if(
+                    resource_changed::<profiles::CurrentProfile>
+                        .and(not(resource_added::<profiles::CurrentProfile>)),
+                ),
+            )
+            .add_systems(
+                PreUpdate,
+                systems::safe_mode::sync_safe_mode
+                    .after(persistence::load_save_data_startup)
+                    .run_if(resource_changed::<resources::SettingsResource>),
+            );
 
         // Register events
         app.add_message::<events::FruitMergeEvent>();
+        app.add_message::<events::ChainMergeEvent>();
         app.add_message::<events::ScoreEarnedEvent>();
+        app.add_message::<events::ComboBrokenEvent>();
+        app.add_message::<systems::achievements::AchievementUnlockedEvent>();
+        app.add_message::<events::FruitDroppedEvent>();
+        app.add_message::<events::BoundaryWarningEvent>();
+        app.add_message::<events::BoardSettledEvent>();
+        app.add_message::<events::SaveErrorEvent>();
+        app.add_message::<events::SaveDataLoaded>();
 
         // Initialize collision detection resources
         app.init_resource::<systems::collision::ProcessedCollisions>();
@@ -211,64 +423,170 @@ impl Plugin for GameCorePlugin {
             ),
         );
 
-        // Combo timer tick (must run after merge scoring to avoid premature combo resets)
+        // Combo timer tick (must run after merge scoring to avoid premature combo resets).
+        // Only while playing: a combo live at pause or game over must not
+        // expire (and report "combo broken") on those screens.
         app.add_systems(
             Update,
-            systems::score::tick_combo_timer.after(systems::score::update_score_on_merge),
+            systems::score::tick_combo_timer
+                .after(systems::score::update_score_on_merge)
+                .run_if(in_state(states::AppState::Playing)),
+        );
+
+        // Chain windows only run down while the board is live
+        app.add_systems(
+            Update,
+            systems::merge::tick_chain_links
+                .after(systems::merge::handle_fruit_merge)
+                .run_if(in_state(states::AppState::Playing)),
         );
 
         // Visual effects — all gated on Playing so they freeze during Paused.
         //
         // Two groups:
         //   1. Always-on: squash-stretch bounce (preserves physical feel)
-        //   2. Effects-gated: particles, flash, shake, watermelon burst
-        //      (disabled when SettingsResource::effects_enabled is false)
+        //   2. Effects-gated: particles, flash, shake, punch, watermelon burst,
+        //      fireworks
+        //      (disabled when SettingsResource::effects_enabled is false;
+        //      droplets, flashes and shake / punch also have their own toggle)
+        //
+        // Neither group is registered when headless; group 2 is also skipped
+        // when the plugin was built with effects disabled.
+        if !self.headless {
+            app.add_systems(
+                Update,
+                (
+                    // Merge scale animation (always on while Playing)
+                    systems::effects::animate_merge_scale.after(systems::merge::handle_fruit_merge),
+                    // Squash-and-stretch bounce (always on — physical feel)
+                    systems::effects::bounce::animate_squash_stretch
+                        .after(systems::merge::handle_fruit_merge),
+                    // Sprite-sheet frames (squish / merge pop)
+                    systems::effects::frames::animate_fruit_frames
+                        .after(systems::merge::handle_fruit_merge),
+                )
+                    .run_if(in_state(states::AppState::Playing)),
+            );
+        }
+
+        // Particle / flash / shake effects — gated on both Playing AND effects_enabled.
+        if effects {
+            app.add_systems(
+                Update,
+                (
+                    // Water droplet particles
+                    systems::effects::droplet::spawn_merge_droplets
+                        .after(systems::merge::handle_fruit_merge)
+                        .run_if(systems::effects::droplets_enabled),
+                    systems::effects::droplet::handle_fruit_landing,
+                    systems::effects::droplet::update_water_droplets,
+                    // Landing dust puffs
+                    systems::effects::dust::spawn_landing_dust,
+                    systems::effects::dust::update_dust_puffs,
+                    // Flash effects
+                    systems::effects::flash::spawn_merge_flash
+                        .after(systems::merge::handle_fruit_merge)
+                        .run_if(systems::effects::flashes_enabled),
+                    systems::effects::flash::animate_local_flash,
+                    systems::effects::flash::animate_screen_flash,
+                    // Camera shake — trauma accumulates on merge (Playing only)
+                    systems::effects::shake::add_camera_shake
+                        .after(systems::merge::handle_fruit_merge)
+                        .run_if(systems::effects::shake_enabled),
+                    // Camera zoom punch on big merges
+                    systems::effects::punch::add_camera_punch
+                        .after(systems::merge::handle_fruit_merge)
+                        .run_if(systems::effects::shake_enabled),
+                    // Watermelon special effects
+                    systems::effects::watermelon::spawn_watermelon_effects
+                        .after(systems::merge::handle_fruit_merge),
+                    systems::effects::watermelon::animate_watermelon_explosion,
+                    systems::effects::watermelon::update_watermelon_burst_particles,
+                    // Fireworks for a new Watermelon
+                    systems::effects::fireworks::spawn_watermelon_fireworks
+                        .after(systems::merge::handle_fruit_merge),
+                    systems::effects::fireworks::burst_firework_shells,
+                    systems::effects::fireworks::update_firework_sparks,
+                )
+                    .run_if(in_state(states::AppState::Playing))
+                    .run_if(|settings: Res<resources::SettingsResource>| settings.effects_enabled)
+                    .run_if(not(systems::background::background_throttled)),
+            );
+
+            // Camera shake / punch apply runs every frame (not gated on Playing) so
+            // that trauma decays and the camera settles even while Paused or in GameOver.
+            app.add_systems(
+                Update,
+                (
+                    systems::effects::shake::apply_camera_shake,
+                    systems::effects::punch::apply_camera_punch,
+                )
+                    .run_if(not(systems::background::background_throttled)),
+            );
+        }
+
+        // Background throttling: cap the frame rate and pause effects while
+        // the window is unfocused or minimized.  Needs a real window.
+        if !self.headless {
+            app.add_systems(
+                Update,
+                (
+                    systems::background::track_window_background,
+                    systems::background::apply_background_throttle
+                        .run_if(resource_changed::<systems::background::BackgroundThrottle>),
+                )
+                    .chain(),
+            );
+        }
+
+        // Frame pacing: vsync and FPS cap from the settings.  The present mode
+        // follows the background throttle too, so it runs after it.
+        if !self.headless {
+            app.add_systems(
+                Update,
+                systems::frame_pacing::apply_present_mode
+                    .after(systems::background::track_window_background)
+                    .run_if(
+                        resource_changed::<resources::SettingsResource>
+                            .or(resource_changed::<systems::background::BackgroundThrottle>),
+                    ),
+            );
+            #[cfg(not(target_arch = "wasm32"))]
+            app.add_systems(Last, systems::frame_pacing::limit_frame_rate);
+        }
+
+        // Anti-tunneling: CCD for fast fruits and a speed cap (physics.ron)
         app.add_systems(
             Update,
-            (
-                // Merge scale animation (always on while Playing)
-                systems::effects::animate_merge_scale.after(systems::merge::handle_fruit_merge),
-                // Squash-and-stretch bounce (always on — physical feel)
-                systems::effects::bounce::animate_squash_stretch
-                    .after(systems::merge::handle_fruit_merge),
-            )
-                .run_if(in_state(states::AppState::Playing)),
+            systems::velocity::regulate_fruit_velocity.run_if(in_state(states::AppState::Playing)),
         );
 
-        // Particle / flash / shake effects — gated on both Playing AND effects_enabled.
+        // Stuck-fruit watchdog: separate or respawn fruits the solver left
+        // embedded in a wall or another fruit
+        app.init_resource::<systems::watchdog::StuckFruitWatchdog>()
+            .add_systems(
+                Update,
+                systems::watchdog::watch_stuck_fruits.run_if(in_state(states::AppState::Playing)),
+            );
+
+        // Escape recovery: return (or remove with a refund) fruits that left
+        // the container during play
         app.add_systems(
             Update,
-            (
-                // Water droplet particles
-                systems::effects::droplet::spawn_merge_droplets
-                    .after(systems::merge::handle_fruit_merge),
-                systems::effects::droplet::handle_fruit_landing,
-                systems::effects::droplet::update_water_droplets,
-                // Flash effects
-                systems::effects::flash::spawn_merge_flash
-                    .after(systems::merge::handle_fruit_merge),
-                systems::effects::flash::animate_local_flash,
-                systems::effects::flash::animate_screen_flash,
-                // Camera shake — trauma accumulates on merge (Playing only)
-                systems::effects::shake::add_camera_shake.after(systems::merge::handle_fruit_merge),
-                // Watermelon special effects
-                systems::effects::watermelon::spawn_watermelon_effects
-                    .after(systems::merge::handle_fruit_merge),
-                systems::effects::watermelon::animate_watermelon_explosion,
-                systems::effects::watermelon::update_watermelon_burst_particles,
-            )
-                .run_if(in_state(states::AppState::Playing))
-                .run_if(|settings: Res<resources::SettingsResource>| settings.effects_enabled),
+            systems::escape::recover_escaped_fruits.run_if(in_state(states::AppState::Playing)),
         );
 
-        // Camera shake apply runs every frame (not gated on Playing) so that
-        // trauma decays and the camera snaps back even while Paused or in GameOver.
-        app.add_systems(Update, systems::effects::shake::apply_camera_shake);
+        // Rapier sleep thresholds from physics.ron
+        app.add_systems(Update, systems::sleep::apply_fruit_sleep_thresholds);
 
-        // Elapsed-time tick (Playing state only)
+        // Elapsed-time tick and drop counter (Playing state only)
         app.add_systems(
             Update,
-            systems::game_over::tick_elapsed_time.run_if(in_state(states::AppState::Playing)),
+            (
+                systems::game_over::tick_elapsed_time,
+                systems::game_over::count_drops,
+            )
+                .run_if(in_state(states::AppState::Playing)),
         );
 
         // Phase 6: boundary overflow detection and game-over transition
@@ -279,9 +597,29 @@ impl Plugin for GameCorePlugin {
                 systems::boundary::check_boundary_overflow,
                 systems::boundary::trigger_game_over
                     .after(systems::boundary::check_boundary_overflow),
-                systems::boundary::animate_boundary_warning
-                    .after(systems::boundary::check_boundary_overflow),
             )
+                .run_if(in_state(states::AppState::Playing))
+                .run_if(systems::sandbox::boundary_enabled),
+        );
+
+        // Optional overfill pressure (game_rules.ron), alongside the boundary check
+        app.add_systems(
+            Update,
+            systems::pressure::apply_overfill_pressure
+                .run_if(in_state(states::AppState::Playing))
+                .run_if(systems::sandbox::boundary_enabled),
+        );
+
+        // Container fill level and the optional adaptive spawn rule
+        // (game_rules.ron), settled before the next fruit is drawn
+        app.add_systems(
+            Update,
+            (
+                systems::adaptive_spawn::update_container_fill_level,
+                systems::adaptive_spawn::update_adaptive_spawn,
+            )
+                .chain()
+                .before(systems::input::spawn_held_fruit)
                 .run_if(in_state(states::AppState::Playing)),
         );
 
@@ -292,7 +630,9 @@ impl Plugin for GameCorePlugin {
         app.add_systems(
             OnEnter(states::AppState::GameOver),
             systems::game_over::save_highscore_on_game_over
-                .in_set(systems::game_over::GameOverSet::SaveHighscore),
+                .in_set(systems::game_over::GameOverSet::SaveHighscore)
+                .run_if(not(systems::sandbox::sandbox_active))
+                .run_if(not(systems::attract::attract_active)),
         );
 
         // Reset game state in two places to cover all "new game" entry paths
@@ -303,23 +643,115 @@ impl Plugin for GameCorePlugin {
         // session is preserved on resume.
         app.add_systems(
             OnExit(states::AppState::GameOver),
-            systems::game_over::reset_game_state,
+            (
+                systems::game_over::reset_game_state,
+                systems::session_log::begin_session_stats,
+                systems::effects::cleanup_effect_entities,
+                systems::settle::reset_board_settled,
+                systems::recap::reset_recap,
+                systems::adaptive_spawn::reset_adaptive_spawn,
+            ),
         );
         app.add_systems(
             OnExit(states::AppState::Title),
-            systems::game_over::reset_game_state,
+            (
+                systems::game_over::reset_game_state,
+                systems::session_log::begin_session_stats,
+                systems::effects::cleanup_effect_entities,
+                systems::settle::reset_board_settled,
+                systems::recap::reset_recap,
+                systems::adaptive_spawn::reset_adaptive_spawn,
+            ),
+        );
+
+        // Session summary log: tally drops / merges while playing and write
+        // the opt-in JSON summary once the highscore has been settled.
+        // Sandbox games feed neither the log nor the lifetime stats (which
+        // also hold the unlocked achievements).
+        app.add_systems(
+            Update,
+            (
+                systems::session_log::record_session_drops,
+                systems::session_log::record_session_merges,
+                (
+                    systems::session_log::record_lifetime_merges,
+                    systems::achievements::track_achievements,
+                )
+                    .run_if(not(systems::sandbox::sandbox_active))
+                    .run_if(not(systems::attract::attract_active)),
+            )
+                .run_if(in_state(states::AppState::Playing)),
+        );
+        app.add_systems(
+            OnEnter(states::AppState::GameOver),
+            (
+                systems::session_log::export_session_summary,
+                systems::session_log::save_lifetime_stats,
+                systems::session_log::keep_best_run,
+            )
+                .after(systems::game_over::GameOverSet::SaveHighscore)
+                .run_if(not(systems::sandbox::sandbox_active))
+                .run_if(not(systems::attract::attract_active)),
         );
 
+        // Unfinished-session save / restore.
+        //   • Paused → Title   — snapshot the board to save/session.json
+        //   • Title → Playing  — restore it ("Continue") or delete it ("New Game")
+        // OnTransition runs after OnExit, so the restore sees the board already
+        // cleared by reset_game_state above.
+        app.add_systems(
+            OnTransition {
+                exited: states::AppState::Paused,
+                entered: states::AppState::Title,
+            },
+            systems::session::save_session_on_quit
+                .run_if(not(systems::sandbox::sandbox_active))
+                .run_if(not(systems::attract::attract_active)),
+        );
+        app.add_systems(
+            OnTransition {
+                exited: states::AppState::Title,
+                entered: states::AppState::Playing,
+            },
+            // A sandbox leaves the saved session for a later "Continue"
+            systems::session::restore_or_discard_session
+                .run_if(not(systems::sandbox::sandbox_active))
+                .run_if(not(systems::attract::attract_active)),
+        );
+
+        // Crash-safe emergency save (native only): keep a snapshot of the
+        // running game that the panic hook writes to disk.
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.enable_persistence {
+            systems::emergency_save::install_panic_hook();
+            app.add_systems(
+                Update,
+                systems::emergency_save::update_emergency_snapshot
+                    .run_if(in_state(states::AppState::Playing))
+                    .run_if(not(systems::sandbox::sandbox_active))
+                    .run_if(not(systems::attract::attract_active)),
+            );
+            app.add_systems(
+                OnEnter(states::AppState::GameOver),
+                systems::emergency_save::clear_emergency_snapshot,
+            );
+            app.add_systems(
+                OnEnter(states::AppState::Title),
+                systems::emergency_save::clear_emergency_snapshot,
+            );
+        }
+
         // Pause / resume: freeze the physics pipeline while paused.
         // All gameplay input and scoring systems already gate on Playing, so
         // this is the only change needed to fully suspend the simulation.
+        // Opening the settings from the pause menu keeps the game frozen.
         app.add_systems(
             OnEnter(states::AppState::Paused),
             systems::pause::pause_physics,
         );
         app.add_systems(
             OnExit(states::AppState::Paused),
-            systems::pause::resume_physics,
+            systems::pause::resume_physics.run_if(not(states::settings_over_pause)),
         );
 
         // Spawn the physics container walls once all configs are loaded
@@ -328,18 +760,161 @@ impl Plugin for GameCorePlugin {
             systems::container::setup_container,
         );
 
-        // Gameplay input systems — only active while Playing
+        // Screen transitions: the state switches while the screen is covered
+        app.add_systems(Update, transition::advance_screen_transition);
+
+        // Device input is mapped into ActionState every frame, in every
+        // state, before any system reads it
+        app.add_systems(
+            PreUpdate,
+            systems::action::collect_input_actions.after(bevy::input::InputSystems),
+        );
+
+        // Gameplay input systems — only active while Playing, and only once
+        // the transition into the board has finished.  The player's aim and
+        // drop input only run under ControlSource::Local; during an
+        // attract-mode demo the bot replaces them, and a replay or network
+        // source leaves the board to be watched.  The one-button assists
+        // (SettingsResource::sweep_assist / auto_drop_secs) join them.
         app.add_systems(
             Update,
             (
-                systems::input::update_spawn_position,
-                systems::input::handle_fruit_drop_input
-                    .after(systems::input::update_spawn_position),
+                (
+                    systems::input::update_spawn_position
+                        .run_if(not(systems::assist::sweep_assist_enabled)),
+                    systems::assist::sweep_held_fruit
+                        .after(systems::input::spawn_held_fruit)
+                        .run_if(systems::assist::sweep_assist_enabled),
+                    systems::input::handle_fruit_drop_input
+                        .after(systems::input::update_spawn_position)
+                        .after(systems::assist::sweep_held_fruit),
+                    systems::assist::auto_drop_held_fruit
+                        .after(systems::input::handle_fruit_drop_input)
+                        .run_if(systems::assist::auto_drop_enabled),
+                )
+                    .run_if(systems::control::local_control),
+                systems::attract::drive_attract_bot
+                    .after(systems::input::spawn_held_fruit)
+                    .run_if(systems::control::bot_control),
                 systems::input::detect_fruit_landing,
                 systems::input::spawn_held_fruit.after(systems::input::detect_fruit_landing),
+                systems::settle::detect_board_settled.after(systems::input::detect_fruit_landing),
+            )
+                .run_if(in_state(states::AppState::Playing))
+                .run_if(transition::screen_transition_idle),
+        );
+
+        // Attract mode: an idle title screen starts a bot-played demo; any
+        // input, the demo's game over or its time limit returns to Title
+        app.add_systems(
+            Update,
+            (
+                systems::attract::track_title_idle.run_if(in_state(states::AppState::Title)),
+                systems::attract::end_attract_on_input
+                    .run_if(in_state(states::AppState::Playing))
+                    .run_if(systems::attract::attract_active),
             )
+                .run_if(transition::screen_transition_idle),
+        );
+        app.add_systems(
+            OnEnter(states::AppState::GameOver),
+            systems::attract::end_attract_on_game_over.run_if(systems::attract::attract_active),
+        );
+        app.add_systems(
+            OnEnter(states::AppState::Title),
+            systems::attract::reset_attract,
+        );
+        app.add_systems(
+            Update,
+            systems::control::sync_attract_control
+                .run_if(resource_changed::<systems::attract::AttractMode>)
+                .before(systems::input::update_spawn_position)
+                .before(systems::attract::drive_attract_bot),
+        );
+
+        // Sandbox: number-key fruit selection and gravity / boundary toggles.
+        // Gravity is applied in every state so leaving the sandbox restores it.
+        app.add_systems(
+            Update,
+            (
+                systems::sandbox::handle_sandbox_input.after(systems::input::spawn_held_fruit),
+                systems::sandbox::settle_weightless_fruits.before(systems::input::spawn_held_fruit),
+            )
+                .run_if(in_state(states::AppState::Playing))
+                .run_if(transition::screen_transition_idle),
+        );
+        app.add_systems(Update, systems::sandbox::apply_sandbox_gravity);
+
+        // Imminent-watermelon detection (two Melons on the board), read by
+        // the sparkle link below and the HUD hint
+        app.add_systems(
+            Update,
+            systems::melon_link::detect_melon_pair
+                .after(systems::merge::handle_fruit_merge)
                 .run_if(in_state(states::AppState::Playing)),
         );
+
+        // Merge ghost assist (SettingsResource::merge_ghost)
+        if !self.headless {
+            app.add_systems(
+                Update,
+                systems::merge_ghost::update_merge_ghost
+                    .after(systems::input::spawn_held_fruit)
+                    .after(systems::input::update_spawn_position)
+                    .run_if(in_state(states::AppState::Playing)),
+            );
+
+            // Sparkle link between the two Melons of a MelonPair
+            app.add_systems(
+                Update,
+                systems::melon_link::update_melon_link
+                    .after(systems::melon_link::detect_melon_pair)
+                    .run_if(in_state(states::AppState::Playing)),
+            );
+
+            // Landing preview assist (SettingsResource::landing_preview)
+            app.add_systems(
+                Update,
+                systems::landing_preview::update_landing_preview
+                    .after(systems::input::spawn_held_fruit)
+                    .after(systems::input::update_spawn_position)
+                    .run_if(in_state(states::AppState::Playing)),
+            );
+
+            // Ghost run racing the highscore game (SettingsResource::ghost_run)
+            app.add_systems(
+                Update,
+                (
+                    systems::ghost_run::spawn_ghost_markers
+                        .run_if(in_state(states::AppState::Playing))
+                        .run_if(systems::ghost_run::ghost_run_enabled)
+                        .run_if(not(systems::sandbox::sandbox_active))
+                        .run_if(not(systems::attract::attract_active)),
+                    systems::ghost_run::fade_ghost_markers,
+                ),
+            );
+
+            // Game-over recap: record the last seconds of play, replay them
+            // behind the game-over panel
+            app.add_systems(
+                Update,
+                systems::recap::record_recap_frames
+                    .run_if(in_state(states::AppState::Playing))
+                    .run_if(not(systems::attract::attract_active)),
+            );
+            app.add_systems(
+                OnEnter(states::AppState::GameOver),
+                systems::recap::start_recap.run_if(not(systems::attract::attract_active)),
+            );
+            app.add_systems(
+                Update,
+                systems::recap::play_recap.run_if(in_state(states::AppState::GameOver)),
+            );
+        }
+        app.add_systems(
+            OnEnter(states::AppState::Title),
+            systems::sandbox::end_sandbox,
+        );
     }
 }
 
@@ -356,10 +931,37 @@ mod tests {
             .add_plugins(bevy::state::app::StatesPlugin)
             // AssetPlugin required by setup_circle_texture (ResMut<Assets<Image>>)
             .add_plugins(bevy::asset::AssetPlugin::default());
-        app.add_plugins(GameCorePlugin);
+        app.add_plugins(GameCorePlugin::default());
         // Plugin should build without panicking
     }
 
+    #[test]
+    fn test_plugin_builds_headless_without_asset_plugin() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin);
+        app.add_plugins(
+            GameCorePlugin::default()
+                .headless(true)
+                .with_persistence(false),
+        );
+        assert_eq!(app.world().resource::<persistence::SaveDir>().path(), None);
+        assert_eq!(
+            app.world().resource::<profiles::CurrentProfile>().path(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_plugin_builder_sets_save_dir() {
+        let plugin = GameCorePlugin::default()
+            .with_effects(false)
+            .with_save_dir("custom_save");
+        assert!(!plugin.enable_effects);
+        assert!(plugin.enable_persistence);
+        assert_eq!(plugin.save_dir, std::path::PathBuf::from("custom_save"));
+    }
+
     #[test]
     fn test_prelude_imports() {
         // Verify that prelude imports work
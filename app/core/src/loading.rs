@@ -0,0 +1,183 @@
+//! Asset collections tracked by the Loading screen.
+//!
+//! Plugins register the handles they load at startup with
+//! [`TrackedAssets`].  `Loading` only moves on to `Title` once every tracked
+//! asset has loaded or failed (or the config timeout passes), and the files
+//! that failed are kept in [`AssetLoadStatus`] so the title screen can show
+//! them instead of the game silently playing without them.  While it waits,
+//! [`LoadingProgress`] tells the loading screen how far along it is.
+
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// Handles the `Loading` state waits for, grouped into named collections.
+#[derive(Resource, Debug, Default)]
+pub struct TrackedAssets {
+    entries: Vec<TrackedAsset>,
+}
+
+/// One tracked handle and the collection it belongs to.
+#[derive(Debug)]
+struct TrackedAsset {
+    collection: &'static str,
+    handle: UntypedHandle,
+}
+
+/// Records which tracked assets failed to load.
+///
+/// Empty when everything loaded.  The UI shows a warning banner on the title
+/// screen while it is non-empty.
+#[derive(Resource, Debug, Default)]
+pub struct AssetLoadStatus {
+    /// Asset paths of the files that failed to load.
+    pub failed: Vec<String>,
+}
+
+/// Overall progress of the `Loading` state, updated every frame while it is
+/// active.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoadingProgress {
+    /// RON configs that are available (loaded or replaced by the built-in
+    /// copy).
+    pub configs_loaded: usize,
+    /// Number of RON configs the game needs.
+    pub configs_total: usize,
+    /// Load states of the [`TrackedAssets`].
+    pub assets: LoadProgress,
+}
+
+impl LoadingProgress {
+    /// Finished share of all configs and tracked assets, from `0.0` to `1.0`.
+    ///
+    /// Failed assets count as finished; `1.0` when there is nothing to load.
+    pub fn fraction(&self) -> f32 {
+        let total = self.configs_total + self.assets.total;
+        if total == 0 {
+            return 1.0;
+        }
+        let done = self.configs_loaded + self.assets.loaded + self.assets.failed.len();
+        (done as f32 / total as f32).min(1.0)
+    }
+}
+
+/// Snapshot of the tracked assets' load states.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// Number of tracked assets.
+    pub total: usize,
+    /// Assets that finished loading.
+    pub loaded: usize,
+    /// `(collection, path)` of every asset that failed to load.
+    pub failed: Vec<(&'static str, String)>,
+}
+
+impl LoadProgress {
+    /// Returns `true` once no tracked asset is still loading.
+    pub fn is_settled(&self) -> bool {
+        self.loaded + self.failed.len() >= self.total
+    }
+}
+
+impl TrackedAssets {
+    /// Adds `handle` to `collection`.
+    pub fn track(&mut self, collection: &'static str, handle: impl Into<UntypedHandle>) {
+        self.entries.push(TrackedAsset {
+            collection,
+            handle: handle.into(),
+        });
+    }
+
+    /// Number of tracked assets.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Current load state of every tracked asset.
+    ///
+    /// Handles that were never queued on the asset server (e.g. inserted
+    /// directly into `Assets`) have nothing to wait for and count as loaded.
+    pub fn progress(&self, asset_server: &AssetServer) -> LoadProgress {
+        let mut progress = LoadProgress {
+            total: self.entries.len(),
+            ..default()
+        };
+        for entry in &self.entries {
+            match asset_server.load_state(entry.handle.id()) {
+                LoadState::Loaded | LoadState::NotLoaded => progress.loaded += 1,
+                LoadState::Failed(_) => {
+                    let path = entry
+                        .handle
+                        .path()
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| format!("{:?}", entry.handle.id()));
+                    progress.failed.push((entry.collection, path));
+                }
+                LoadState::Loading => {}
+            }
+        }
+        progress
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_progress_is_settled() {
+        assert!(LoadProgress::default().is_settled());
+    }
+
+    #[test]
+    fn test_progress_settles_with_failures() {
+        let mut progress = LoadProgress {
+            total: 3,
+            loaded: 1,
+            failed: vec![("sfx", "sounds/sfx/drop.wav".into())],
+        };
+        assert!(!progress.is_settled());
+
+        progress.loaded = 2;
+        assert!(progress.is_settled());
+    }
+
+    #[test]
+    fn test_loading_fraction() {
+        assert_eq!(LoadingProgress::default().fraction(), 1.0);
+
+        let progress = LoadingProgress {
+            configs_loaded: 4,
+            configs_total: 8,
+            assets: LoadProgress {
+                total: 12,
+                loaded: 5,
+                failed: vec![("sfx", "sounds/sfx/drop.wav".into())],
+            },
+        };
+        assert_eq!(progress.fraction(), 0.5);
+    }
+
+    #[test]
+    fn test_track_counts_handles() {
+        let mut assets = Assets::<Image>::default();
+        let mut tracked = TrackedAssets::default();
+        assert!(tracked.is_empty());
+
+        tracked.track("images", assets.reserve_handle());
+        tracked.track("images", assets.reserve_handle());
+        assert_eq!(tracked.len(), 2);
+    }
+}
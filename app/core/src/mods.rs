@@ -0,0 +1,248 @@
+//! User mods
+//!
+//! A mod is a directory inside the mods directory ([`MODS_DIR`], or
+//! `--mods-dir`) laid out like `assets/`.  Every file it ships replaces the
+//! bundled asset at the same path:
+//!
+//! ```text
+//! mods/
+//! └── pastel/
+//!     ├── config/fruits.ron          fruit table
+//!     ├── images/fruits/grape.png    sprite (or sprite sheet) of a fruit
+//!     ├── sounds/sfx/merge_small.wav sound effect
+//!     └── scripts/rules.rhai         rules script (`scripting` feature)
+//! ```
+//!
+//! The directory is scanned once at startup, before `AssetPlugin` is built,
+//! and registered as the [`MODS_ASSET_SOURCE`] asset source.  Loaders pass
+//! each bundled path through [`ModList::resolve`]: the first enabled mod (by
+//! name) that ships the file wins, otherwise the bundled asset loads.  A mod
+//! override also wins over `--config-dir`.
+//!
+//! Mods are enabled unless listed in
+//! [`SettingsResource::disabled_mods`](crate::resources::settings::SettingsResource::disabled_mods).
+//! Switching one on or off on the settings screen, or switching profiles,
+//! takes effect on the next launch.  The web build has no mods directory.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use bevy::asset::io::AssetSourceBuilder;
+use bevy::prelude::*;
+
+use crate::constants::storage::{MODS_DIR, SAVE_DIR};
+use crate::launch::LaunchOptions;
+use crate::persistence::load_settings;
+use crate::profiles::CurrentProfile;
+
+/// Asset source the mods directory is registered as.
+pub const MODS_ASSET_SOURCE: &str = "mods";
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// One directory of the mods directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModInfo {
+    /// Directory name
+    pub name: String,
+    /// The mod's directory on disk
+    pub path: PathBuf,
+    /// Files the mod ships, as `/`-separated paths relative to its directory
+    pub files: BTreeSet<String>,
+    /// Whether the mod's files are used in this run
+    pub enabled: bool,
+}
+
+/// Mods found at startup, sorted by name.
+///
+/// Inserted by [`LaunchOptions::register_asset_sources`]; empty when there
+/// is no mods directory.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModList {
+    /// Every mod, enabled or not
+    pub mods: Vec<ModInfo>,
+}
+
+impl ModList {
+    /// Scans `dir` for mods; those named in `disabled` are listed but not
+    /// used.
+    ///
+    /// A missing directory is an empty list; an unreadable mod is skipped
+    /// with a warning.
+    pub fn scan(dir: &Path, disabled: &[String]) -> Self {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                if e.kind() != io::ErrorKind::NotFound {
+                    warn!("Cannot read mods directory {}: {e}", dir.display());
+                }
+                return Self::default();
+            }
+        };
+
+        let mut mods: Vec<ModInfo> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .filter_map(|path| {
+                let name = path.file_name()?.to_string_lossy().into_owned();
+                let mut files = BTreeSet::new();
+                if let Err(e) = list_files(&path, &path, &mut files) {
+                    warn!("Skipping mod '{name}': {e}");
+                    return None;
+                }
+                let enabled = !disabled.contains(&name);
+                Some(ModInfo {
+                    name,
+                    path,
+                    files,
+                    enabled,
+                })
+            })
+            .collect();
+        mods.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { mods }
+    }
+
+    /// Asset path of the enabled mod file replacing bundled `path`, if any.
+    pub fn override_path(&self, path: &str) -> Option<String> {
+        self.mods
+            .iter()
+            .filter(|m| m.enabled)
+            .find(|m| m.files.contains(path))
+            .map(|m| format!("{MODS_ASSET_SOURCE}://{}/{path}", m.name))
+    }
+
+    /// Asset path to load for bundled `path`: a mod's copy if one ships it,
+    /// otherwise `path` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use suika_game_core::mods::ModList;
+    /// assert_eq!(ModList::default().resolve("sounds/sfx/drop.wav"), "sounds/sfx/drop.wav");
+    /// ```
+    pub fn resolve(&self, path: &str) -> String {
+        self.override_path(path).unwrap_or_else(|| path.to_string())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Startup
+// ---------------------------------------------------------------------------
+
+/// Scans the mods directory, registers it as [`MODS_ASSET_SOURCE`] and
+/// inserts the [`ModList`].
+///
+/// Called by [`LaunchOptions::register_asset_sources`].  The enabled state
+/// comes from the saved settings of the selected profile, read here because
+/// the settings resource is only loaded at `Startup`, after the configs have
+/// been queued.
+pub(crate) fn register_mods(app: &mut App, launch: &LaunchOptions) {
+    let dir = launch.mods_dir.clone().unwrap_or_else(|| MODS_DIR.into());
+    if cfg!(target_arch = "wasm32") || !dir.is_dir() {
+        app.init_resource::<ModList>();
+        return;
+    }
+
+    let save_dir = launch.save_dir.clone().unwrap_or_else(|| SAVE_DIR.into());
+    let profile = CurrentProfile::load(Some(&save_dir));
+    let disabled = profile
+        .path()
+        .map(|dir| load_settings(dir).disabled_mods)
+        .unwrap_or_default();
+    let mods = ModList::scan(&dir, &disabled);
+    for info in &mods.mods {
+        info!(
+            "🧩 Mod '{}' ({} files){}",
+            info.name,
+            info.files.len(),
+            if info.enabled { "" } else { " — disabled" }
+        );
+    }
+    if !mods.mods.is_empty() {
+        app.register_asset_source(
+            MODS_ASSET_SOURCE,
+            AssetSourceBuilder::platform_default(&dir.to_string_lossy(), None),
+        );
+    }
+    app.insert_resource(mods);
+}
+
+/// Adds every file under `dir` to `files`, relative to `root`.
+fn list_files(root: &Path, dir: &Path, files: &mut BTreeSet<String>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            list_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let parts: Vec<_> = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect();
+            files.insert(parts.join("/"));
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+    }
+
+    #[test]
+    fn test_scan_lists_mods_by_name() {
+        let dir = TempDir::new().unwrap();
+        write(&dir.path().join("retro/config/fruits.ron"));
+        write(&dir.path().join("pastel/images/fruits/grape.png"));
+        write(&dir.path().join("readme.txt"));
+
+        let mods = ModList::scan(dir.path(), &["retro".to_string()]);
+        let names: Vec<_> = mods.mods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["pastel", "retro"]);
+        assert!(mods.mods[0].enabled);
+        assert!(!mods.mods[1].enabled);
+        assert!(mods.mods[0].files.contains("images/fruits/grape.png"));
+    }
+
+    #[test]
+    fn test_scan_missing_directory_is_empty() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(
+            ModList::scan(&dir.path().join("mods"), &[]),
+            ModList::default()
+        );
+    }
+
+    #[test]
+    fn test_resolve_prefers_first_enabled_mod() {
+        let info = |name: &str, enabled| ModInfo {
+            name: name.to_string(),
+            path: PathBuf::from(name),
+            files: BTreeSet::from(["config/fruits.ron".to_string()]),
+            enabled,
+        };
+        let mods = ModList {
+            mods: vec![info("a-off", false), info("b", true), info("c", true)],
+        };
+        assert_eq!(
+            mods.resolve("config/fruits.ron"),
+            "mods://b/config/fruits.ron"
+        );
+        assert_eq!(mods.resolve("config/physics.ron"), "config/physics.ron");
+    }
+}
@@ -7,30 +7,241 @@
 //!
 //! | File | Content |
 //! |------|---------|
-//! | `save/highscore.json` | All-time best score |
-//! | `save/settings.json`  | User preferences (volume, effects, language) |
+//! | `highscore.json` | All-time best score |
+//! | `settings.json`  | User preferences (volume, effects, language) |
+//! | `stats.json`     | Lifetime stats (games, playtime, merges, score distribution) |
+//! | `session.json`   | Board snapshot of an unfinished game (deleted once resumed) |
+//! | `best_run.json`  | Drops and score timeline of the highscore game, for the ghost run |
+//! | `sessions/*.json` | Per-game summary logs (opt-in via `export_session_logs`) |
+//! | `exports/history-*.{json,csv}` | Highscore and game history, written on request by [`export_history`] |
 //!
-//! ## Startup systems
+//! Each player profile has its own set of these files in
+//! `save/profiles/<name>/` (see [`crate::profiles`]).
 //!
-//! - [`load_highscore_startup`] — reads highscore into [`GameState`]
-//! - [`load_settings_startup`]  — reads settings into [`SettingsResource`]
+//! ## Save directory
+//!
+//! The functions here take the directory to use.  Systems read it from the
+//! [`CurrentProfile`] resource, a profile directory inside [`SaveDir`]
+//! (default [`SAVE_DIR`]); `GameCorePlugin` inserts both.  A `SaveDir(None)`
+//! turns every load into a default and every save into a no-op.
+//!
+//! ## Errors
+//!
+//! Every fallible function returns a [`SaveError`] naming the file and, for
+//! malformed JSON, the line (`save/settings.json line 4: invalid type ...`).
+//! The `try_load_*` functions report a corrupted file; the plain `load_*`
+//! wrappers log it and fall back to defaults.  Systems forward failures to
+//! the UI as [`SaveErrorEvent`]s.
+//!
+//! ## Startup loading
+//!
+//! [`load_save_data_startup`] reads highscore, settings, lifetime stats, the
+//! best run and the saved-session flag in one pass and then sends [`SaveDataLoaded`].  It
+//! runs again whenever the player switches profiles.
 
 use bevy::prelude::*;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
 
 use crate::constants::storage::SAVE_DIR;
-use crate::resources::GameState;
+use crate::events::{SaveDataLoaded, SaveErrorEvent};
+use crate::fruit::FruitType;
+use crate::profiles::CurrentProfile;
 use crate::resources::settings::SettingsResource;
+use crate::resources::{GameState, StatsResource};
+use crate::systems::board::{BoardFruit, BoardSnapshot};
+
+/// Root save directory, holding the profiles.
+///
+/// Save files are read from and written to the [`CurrentProfile`] directory
+/// inside it.  `None` disables persistence entirely (tests, headless
+/// simulation).
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct SaveDir(pub Option<PathBuf>);
+
+impl Default for SaveDir {
+    fn default() -> Self {
+        Self(Some(PathBuf::from(SAVE_DIR)))
+    }
+}
+
+impl SaveDir {
+    /// The save directory, or `None` when persistence is disabled.
+    pub fn path(&self) -> Option<&Path> {
+        self.0.as_deref()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Why a save file could not be read or written.
+#[derive(Debug, Error)]
+pub enum SaveError {
+    /// The file or its directory could not be accessed.
+    #[error("{}: {source}", .path.display())]
+    Io {
+        /// File or directory that failed.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
+    /// The file exists but is not valid JSON for its data type.
+    #[error("{} line {line}: {message}", .path.display())]
+    Parse {
+        /// The corrupted file.
+        path: PathBuf,
+        /// 1-based line of the error.
+        line: usize,
+        /// 1-based column of the error.
+        column: usize,
+        /// What was wrong, without serde's position suffix.
+        message: String,
+    },
+    /// The data could not be turned into JSON.
+    #[error("{}: {source}", .path.display())]
+    Serialize {
+        /// File that was about to be written.
+        path: PathBuf,
+        /// Underlying serializer error.
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl SaveError {
+    /// Wraps a JSON parse error with the file it came from.
+    fn parse(path: &Path, error: serde_json::Error) -> Self {
+        let message = error.to_string();
+        // serde_json appends " at line L column C"; the variant carries those
+        let message = match message.rfind(" at line ") {
+            Some(end) => message[..end].to_string(),
+            None => message,
+        };
+        Self::Parse {
+            path: path.to_path_buf(),
+            line: error.line(),
+            column: error.column(),
+            message,
+        }
+    }
+
+    /// The file (or directory) the error belongs to.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Io { path, .. } | Self::Parse { path, .. } | Self::Serialize { path, .. } => path,
+        }
+    }
+}
+
+/// Logs `error` and forwards it to the UI as a [`SaveErrorEvent`].
+pub fn report_save_error(
+    context: &str,
+    error: &SaveError,
+    events: &mut MessageWriter<SaveErrorEvent>,
+) {
+    error!("{context}: {error}");
+    events.write(SaveErrorEvent {
+        message: error.to_string(),
+    });
+}
+
+/// Writes `data` as pretty-printed JSON, creating the parent directory.
+pub(crate) fn write_json<T: Serialize>(data: &T, path: &Path) -> Result<(), SaveError> {
+    let json = serde_json::to_string_pretty(data).map_err(|source| SaveError::Serialize {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    write_text(&json, path)
+}
+
+/// Writes `text` to `path`, creating the parent directory.
+fn write_text(text: &str, path: &Path) -> Result<(), SaveError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|source| SaveError::Io {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+    }
+    fs::write(path, text).map_err(|source| SaveError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Reads and parses a JSON file; `Ok(None)` when it does not exist.
+pub(crate) fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, SaveError> {
+    let json = match fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => {
+            return Err(SaveError::Io {
+                path: path.to_path_buf(),
+                source,
+            });
+        }
+    };
+    serde_json::from_str(&json)
+        .map(Some)
+        .map_err(|e| SaveError::parse(path, e))
+}
+
+// ---------------------------------------------------------------------------
+// Highscore persistence
+// ---------------------------------------------------------------------------
 
 /// Highscore data structure
 ///
-/// This structure is serialized to JSON and saved to disk.
+/// This structure is serialized to JSON and saved to disk.  Besides the
+/// score it keeps the best combo and the largest fruit ever reached; files
+/// written before those records existed load them as zero / `None`.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct HighscoreData {
     /// The player's all-time highest score
     pub highscore: u32,
+    /// The highest combo count ever reached
+    #[serde(default)]
+    pub best_combo: u32,
+    /// The largest fruit ever reached, `None` before the first merge
+    #[serde(default)]
+    pub highest_fruit: Option<FruitType>,
+}
+
+impl HighscoreData {
+    /// Folds a finished game into the records.
+    ///
+    /// Returns `true` when any of the three records improved.
+    pub fn record_game(
+        &mut self,
+        score: u32,
+        best_combo: u32,
+        largest_fruit: Option<FruitType>,
+    ) -> bool {
+        let mut improved = false;
+        if score > self.highscore {
+            self.highscore = score;
+            improved = true;
+        }
+        if best_combo > self.best_combo {
+            self.best_combo = best_combo;
+            improved = true;
+        }
+        if let Some(fruit) = largest_fruit
+            && self
+                .highest_fruit
+                .is_none_or(|highest| fruit.stage_index() > highest.stage_index())
+        {
+            self.highest_fruit = Some(fruit);
+            improved = true;
+        }
+        improved
+    }
 }
 
 /// Saves the highscore data to a JSON file in the specified directory
@@ -48,7 +259,7 @@ pub struct HighscoreData {
 /// # Returns
 ///
 /// * `Ok(())` if the save was successful
-/// * `Err` if there was an IO error or serialization failed
+/// * `Err(SaveError)` if there was an IO error or serialization failed
 ///
 /// # Examples
 ///
@@ -56,24 +267,11 @@ pub struct HighscoreData {
 /// # use suika_game_core::persistence::{HighscoreData, save_highscore};
 /// # use suika_game_core::constants::storage::SAVE_DIR;
 /// # use std::path::Path;
-/// let data = HighscoreData { highscore: 10000 };
+/// let data = HighscoreData { highscore: 10000, ..Default::default() };
 /// save_highscore(&data, Path::new(SAVE_DIR)).expect("Failed to save highscore");
 /// ```
-pub fn save_highscore(
-    data: &HighscoreData,
-    save_dir: &Path,
-) -> Result<(), Box<dyn std::error::Error>> {
-    // Create save directory if it doesn't exist
-    fs::create_dir_all(save_dir)?;
-
-    // Serialize to pretty-printed JSON
-    let json = serde_json::to_string_pretty(data)?;
-
-    // Write to file
-    let file_path = save_dir.join("highscore.json");
-    fs::write(file_path, json)?;
-
-    Ok(())
+pub fn save_highscore(data: &HighscoreData, save_dir: &Path) -> Result<(), SaveError> {
+    write_json(data, &save_dir.join("highscore.json"))
 }
 
 /// Loads the highscore data from a JSON file in the specified directory
@@ -83,6 +281,8 @@ pub fn save_highscore(
 /// 2. If it exists, read and deserialize the JSON
 /// 3. If it doesn't exist or there's an error, return default (0)
 ///
+/// Use [`try_load_highscore`] to find out why a file was rejected.
+///
 /// # Arguments
 ///
 /// * `save_dir` - The directory where the highscore file is located
@@ -102,21 +302,17 @@ pub fn save_highscore(
 /// println!("Current highscore: {}", data.highscore);
 /// ```
 pub fn load_highscore(save_dir: &Path) -> HighscoreData {
-    let file_path = save_dir.join("highscore.json");
-
-    // Return default if file doesn't exist
-    if !file_path.exists() {
-        return HighscoreData::default();
-    }
+    try_load_highscore(save_dir).unwrap_or_else(|e| {
+        warn!("Ignoring unreadable highscore: {e}");
+        HighscoreData::default()
+    })
+}
 
-    // Try to read and deserialize the file
-    match fs::read_to_string(&file_path) {
-        Ok(json) => {
-            // Deserialize JSON, return default if parsing fails
-            serde_json::from_str(&json).unwrap_or_default()
-        }
-        Err(_) => HighscoreData::default(),
-    }
+/// Loads the highscore data, reporting an unreadable or corrupted file.
+///
+/// A missing file is not an error and yields the default (0).
+pub fn try_load_highscore(save_dir: &Path) -> Result<HighscoreData, SaveError> {
+    read_json(&save_dir.join("highscore.json")).map(Option::unwrap_or_default)
 }
 
 /// Attempts to update the highscore if the new score is higher
@@ -150,10 +346,7 @@ pub fn load_highscore(save_dir: &Path) -> HighscoreData {
 ///     Err(e) => eprintln!("Failed to save: {}", e),
 /// }
 /// ```
-pub fn update_highscore(
-    new_score: u32,
-    save_dir: &Path,
-) -> Result<bool, Box<dyn std::error::Error>> {
+pub fn update_highscore(new_score: u32, save_dir: &Path) -> Result<bool, SaveError> {
     let mut data = load_highscore(save_dir);
 
     if new_score > data.highscore {
@@ -165,17 +358,6 @@ pub fn update_highscore(
     }
 }
 
-/// Bevy startup system: reads the persisted highscore into [`GameState`].
-///
-/// Runs once at [`Startup`] so every screen that shows the best score
-/// (title screen, HUD, game-over screen) always has the correct value
-/// from the very first frame.
-pub fn load_highscore_startup(mut game_state: ResMut<GameState>) {
-    let data = load_highscore(std::path::Path::new(SAVE_DIR));
-    game_state.highscore = data.highscore;
-    info!("Highscore loaded: {}", data.highscore);
-}
-
 // ---------------------------------------------------------------------------
 // Settings persistence
 // ---------------------------------------------------------------------------
@@ -187,15 +369,9 @@ pub fn load_highscore_startup(mut game_state: ResMut<GameState>) {
 /// # Returns
 ///
 /// * `Ok(())` on success
-/// * `Err` if the directory cannot be created or the file cannot be written
-pub fn save_settings(
-    settings: &SettingsResource,
-    save_dir: &Path,
-) -> Result<(), Box<dyn std::error::Error>> {
-    fs::create_dir_all(save_dir)?;
-    let json = serde_json::to_string_pretty(settings)?;
-    fs::write(save_dir.join("settings.json"), json)?;
-    Ok(())
+/// * `Err(SaveError)` if the directory cannot be created or the file cannot be written
+pub fn save_settings(settings: &SettingsResource, save_dir: &Path) -> Result<(), SaveError> {
+    write_json(settings, &save_dir.join("settings.json"))
 }
 
 /// Loads [`SettingsResource`] from `{save_dir}/settings.json`.
@@ -203,27 +379,485 @@ pub fn save_settings(
 /// Returns [`SettingsResource::default`] when the file does not exist or
 /// cannot be parsed, so the game always has a usable value.
 pub fn load_settings(save_dir: &Path) -> SettingsResource {
-    let file_path = save_dir.join("settings.json");
+    try_load_settings(save_dir).unwrap_or_else(|e| {
+        warn!("Ignoring unreadable settings: {e}");
+        SettingsResource::default()
+    })
+}
+
+/// Loads [`SettingsResource`], reporting an unreadable or corrupted file.
+///
+/// A missing file is not an error and yields the defaults.
+pub fn try_load_settings(save_dir: &Path) -> Result<SettingsResource, SaveError> {
+    read_json(&save_dir.join("settings.json")).map(Option::unwrap_or_default)
+}
+
+// ---------------------------------------------------------------------------
+// Lifetime stats persistence
+// ---------------------------------------------------------------------------
+
+/// Saves the lifetime [`StatsResource`] to `{save_dir}/stats.json`.
+///
+/// Creates the save directory if it does not yet exist.
+pub fn save_stats(stats: &StatsResource, save_dir: &Path) -> Result<(), SaveError> {
+    write_json(stats, &save_dir.join("stats.json"))
+}
+
+/// Loads [`StatsResource`] from `{save_dir}/stats.json`.
+///
+/// Returns empty stats when the file does not exist or cannot be parsed.
+pub fn load_stats(save_dir: &Path) -> StatsResource {
+    try_load_stats(save_dir).unwrap_or_else(|e| {
+        warn!("Ignoring unreadable stats: {e}");
+        StatsResource::default()
+    })
+}
 
-    if !file_path.exists() {
-        return SettingsResource::default();
+/// Loads [`StatsResource`], reporting an unreadable or corrupted file.
+///
+/// A missing file is not an error and yields empty stats.
+pub fn try_load_stats(save_dir: &Path) -> Result<StatsResource, SaveError> {
+    read_json(&save_dir.join("stats.json")).map(Option::unwrap_or_default)
+}
+
+// ---------------------------------------------------------------------------
+// Session persistence
+// ---------------------------------------------------------------------------
+
+/// A single fruit on the board, as stored in `save/session.json`.
+pub type SavedFruit = BoardFruit;
+
+/// Snapshot of an unfinished game, serialized to `save/session.json`.
+///
+/// Written when the player leaves a paused game for the title screen and
+/// consumed (then deleted) when they pick "Continue".  Uses the shared
+/// [`BoardSnapshot`] format.
+pub type SessionData = BoardSnapshot;
+
+/// Whether `save/session.json` exists and a "Continue" option should be offered.
+///
+/// Set at [`Startup`] by [`load_save_data_startup`] and kept in sync by the
+/// session save / restore systems in [`crate::systems::session`].
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionAvailable(pub bool);
+
+/// Set to `true` by the title screen's "Continue" button.
+///
+/// Read on the Title → Playing transition to decide whether the saved board
+/// is restored or discarded in favour of a fresh game.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PendingSessionRestore(pub bool);
+
+/// Saves a [`SessionData`] snapshot to `{save_dir}/session.json`.
+///
+/// Creates the save directory if it does not yet exist.
+pub fn save_session(data: &SessionData, save_dir: &Path) -> Result<(), SaveError> {
+    write_json(data, &save_dir.join("session.json"))
+}
+
+/// Loads a [`SessionData`] snapshot from `{save_dir}/session.json`.
+///
+/// Returns `None` when the file does not exist or cannot be parsed.
+pub fn load_session(save_dir: &Path) -> Option<SessionData> {
+    try_load_session(save_dir).unwrap_or_else(|e| {
+        warn!("Ignoring unreadable session: {e}");
+        None
+    })
+}
+
+/// Loads a [`SessionData`] snapshot, reporting an unreadable or corrupted file.
+///
+/// Returns `Ok(None)` when no session was saved.
+pub fn try_load_session(save_dir: &Path) -> Result<Option<SessionData>, SaveError> {
+    read_json(&save_dir.join("session.json"))
+}
+
+/// Returns `true` when `{save_dir}/session.json` exists.
+pub fn session_exists(save_dir: &Path) -> bool {
+    save_dir.join("session.json").exists()
+}
+
+/// Deletes `{save_dir}/session.json`.
+///
+/// A missing file is not an error.
+pub fn delete_session(save_dir: &Path) -> Result<(), SaveError> {
+    let path = save_dir.join("session.json");
+    match fs::remove_file(&path) {
+        Err(source) if source.kind() != io::ErrorKind::NotFound => {
+            Err(SaveError::Io { path, source })
+        }
+        _ => Ok(()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Startup loading
+// ---------------------------------------------------------------------------
+
+/// Bevy startup system: reads every save file of the [`CurrentProfile`]
+/// into its resource.
+///
+/// The single place save data is loaded, so every screen that shows it
+/// (best score, settings, lifetime stats, "Continue") sees the same values
+/// from the very first frame:
+///
+/// - `settings.json`  → [`SettingsResource`]
+/// - `highscore.json` → [`GameState::highscore`]
+/// - `stats.json`     → [`StatsResource`]
+/// - `best_run.json`  → [`BestRun`]
+/// - `session.json`   → [`SessionAvailable`] (existence only)
+///
+/// A corrupted file resets that resource to its defaults and sends a
+/// [`SaveErrorEvent`]; the other files still load.  This also runs again
+/// when the [`CurrentProfile`] changes, so every resource is overwritten
+/// and nothing carries over from the previous profile.  [`SaveDataLoaded`] is
+/// sent once everything was read — also when persistence is disabled — and
+/// the `Loading` state waits for it before showing the title screen.
+#[allow(clippy::too_many_arguments)]
+pub fn load_save_data_startup(
+    mut settings: ResMut<SettingsResource>,
+    mut game_state: ResMut<GameState>,
+    mut stats: ResMut<StatsResource>,
+    mut best_run: ResMut<BestRun>,
+    mut session: ResMut<SessionAvailable>,
+    profile: Res<CurrentProfile>,
+    mut errors: MessageWriter<SaveErrorEvent>,
+    mut loaded: MessageWriter<SaveDataLoaded>,
+) {
+    if let Some(dir) = profile.path() {
+        *settings = try_load_settings(dir).unwrap_or_else(|e| {
+            report_save_error("Failed to load settings", &e, &mut errors);
+            SettingsResource::default()
+        });
+        let highscore = try_load_highscore(dir).unwrap_or_else(|e| {
+            report_save_error("Failed to load highscore", &e, &mut errors);
+            HighscoreData::default()
+        });
+        game_state.highscore = highscore.highscore;
+        game_state.best_combo_record = highscore.best_combo;
+        game_state.highest_fruit_record = highscore.highest_fruit;
+        *stats = try_load_stats(dir).unwrap_or_else(|e| {
+            report_save_error("Failed to load stats", &e, &mut errors);
+            StatsResource::default()
+        });
+        *best_run = try_load_best_run(dir)
+            .unwrap_or_else(|e| {
+                report_save_error("Failed to load best run", &e, &mut errors);
+                None
+            })
+            .unwrap_or_default();
+        session.0 = session_exists(dir);
+        info!(
+            "Save data of profile '{}' loaded: highscore {}, {} games played{}",
+            profile.name,
+            game_state.highscore,
+            stats.games_played,
+            if session.0 {
+                ", saved session found"
+            } else {
+                ""
+            }
+        );
     }
+    loaded.write(SaveDataLoaded {
+        highscore: game_state.highscore,
+        session_available: session.0,
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Best run
+// ---------------------------------------------------------------------------
+
+/// One drop of a recorded game.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DropSample {
+    /// Seconds since the game started
+    pub time: f32,
+    /// World-space X position the fruit was released from (pixels)
+    pub x: f32,
+    /// World-space Y position the fruit was released from (pixels)
+    pub y: f32,
+    /// The dropped fruit
+    pub fruit_type: FruitType,
+}
+
+/// The drops and score timeline of the highscore game, raced by the ghost
+/// run (see [`crate::systems::ghost_run`]).
+///
+/// Loaded by [`load_save_data_startup`] and replaced whenever a game sets a
+/// new highscore; empty until then.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BestRun {
+    /// Final score of the game
+    pub final_score: u32,
+    /// Every drop, in order
+    pub drops: Vec<DropSample>,
+    /// Score after every merge, in order
+    pub score_timeline: Vec<ScoreSample>,
+}
+
+impl BestRun {
+    /// Whether no game has been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.drops.is_empty()
+    }
+
+    /// Score the best run had `time` seconds into the game.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use suika_game_core::persistence::{BestRun, ScoreSample};
+    /// let run = BestRun {
+    ///     score_timeline: vec![ScoreSample { time: 2.0, score: 10 }],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(run.score_at(1.0), 0);
+    /// assert_eq!(run.score_at(2.5), 10);
+    /// ```
+    pub fn score_at(&self, time: f32) -> u32 {
+        self.score_timeline
+            .iter()
+            .take_while(|sample| sample.time <= time)
+            .last()
+            .map_or(0, |sample| sample.score)
+    }
+}
+
+/// Saves a [`BestRun`] to `{save_dir}/best_run.json`.
+pub fn save_best_run(data: &BestRun, save_dir: &Path) -> Result<(), SaveError> {
+    write_json(data, &save_dir.join("best_run.json"))
+}
+
+/// Loads the [`BestRun`], reporting an unreadable or corrupted file.
+///
+/// Returns `Ok(None)` when no game has been recorded yet.
+pub fn try_load_best_run(save_dir: &Path) -> Result<Option<BestRun>, SaveError> {
+    read_json(&save_dir.join("best_run.json"))
+}
+
+// ---------------------------------------------------------------------------
+// Session summary logs
+// ---------------------------------------------------------------------------
+
+/// One point on a game's score timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoreSample {
+    /// Seconds since the game started
+    pub time: f32,
+    /// Total score at that moment
+    pub score: u32,
+}
+
+/// Summary of one finished game, written to `save/sessions/` for analysis.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionSummary {
+    /// Unix timestamp (seconds) at which the game started
+    pub started_at: u64,
+    /// Seed of the fruit spawn sequence
+    pub seed: u64,
+    /// Final score
+    pub final_score: u32,
+    /// Play time in seconds
+    pub elapsed_time: f32,
+    /// Number of fruits dropped
+    pub drops: u32,
+    /// Number of merges
+    pub merges: u32,
+    /// Score after every merge, in order
+    pub score_timeline: Vec<ScoreSample>,
+    /// Build that played the game, e.g. `v0.1.0 (1a2b3c4d5e)`
+    #[serde(default)]
+    pub build: String,
+}
+
+/// Writes a [`SessionSummary`] to `{save_dir}/sessions/session-{started_at}.json`.
+///
+/// Creates the `sessions` directory if needed and returns the path written.
+pub fn save_session_summary(
+    summary: &SessionSummary,
+    save_dir: &Path,
+) -> Result<PathBuf, SaveError> {
+    let path = save_dir
+        .join("sessions")
+        .join(format!("session-{}.json", summary.started_at));
+    write_json(summary, &path)?;
+    Ok(path)
+}
+
+/// Reads every summary in `{save_dir}/sessions/`, oldest first.
+///
+/// A missing directory yields an empty list; unreadable or corrupted files
+/// are logged and skipped so one bad log does not hide the rest.
+pub fn load_session_summaries(save_dir: &Path) -> Result<Vec<SessionSummary>, SaveError> {
+    let dir = save_dir.join("sessions");
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => return Err(SaveError::Io { path: dir, source }),
+    };
+
+    let mut summaries = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        match read_json::<SessionSummary>(&path) {
+            Ok(Some(summary)) => summaries.push(summary),
+            Ok(None) => {}
+            Err(e) => warn!("Skipping session log: {e}"),
+        }
+    }
+    summaries.sort_by_key(|s| s.started_at);
+    Ok(summaries)
+}
 
-    match fs::read_to_string(&file_path) {
-        Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
-        Err(_) => SettingsResource::default(),
+// ---------------------------------------------------------------------------
+// History export
+// ---------------------------------------------------------------------------
+
+/// File format written by [`export_history`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// The full [`HistoryExport`], score timelines included.
+    Json,
+    /// One row per logged game with its leaderboard rank; no timelines.
+    Csv,
+}
+
+impl ExportFormat {
+    /// Both formats, in the order the settings screen writes them.
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Json, ExportFormat::Csv];
+
+    /// File extension without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+        }
     }
 }
 
-/// Bevy startup system: reads the persisted settings into [`SettingsResource`].
+/// One place on the leaderboard of logged games.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// 1 for the best game; ties share a rank
+    pub rank: u32,
+    /// Final score
+    pub final_score: u32,
+    /// Unix timestamp (seconds) at which the game started
+    pub started_at: u64,
+    /// Seed of the fruit spawn sequence
+    pub seed: u64,
+}
+
+/// Highscore and game history, as written by [`export_history`].
 ///
-/// Runs once at [`Startup`], overwriting the default-initialised resource with
-/// the values stored on disk so every screen starts with the player's last
-/// chosen preferences.
-pub fn load_settings_startup(mut settings: ResMut<SettingsResource>) {
-    let loaded = load_settings(std::path::Path::new(SAVE_DIR));
-    *settings = loaded;
-    info!("Settings loaded from disk");
+/// Only games played with `export_session_logs` enabled have a summary, so
+/// [`highscore`](Self::highscore) can be higher than anything in
+/// [`sessions`](Self::sessions).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryExport {
+    /// Unix timestamp (seconds) of the export
+    pub exported_at: u64,
+    /// All-time best score from `highscore.json`
+    pub highscore: u32,
+    /// Logged games, best score first
+    pub leaderboard: Vec<LeaderboardEntry>,
+    /// Logged games, oldest first
+    pub sessions: Vec<SessionSummary>,
+}
+
+impl HistoryExport {
+    /// Collects the highscore and all session logs in `save_dir`.
+    pub fn collect(save_dir: &Path, exported_at: u64) -> Result<Self, SaveError> {
+        let highscore = try_load_highscore(save_dir)?.highscore;
+        let sessions = load_session_summaries(save_dir)?;
+        Ok(Self {
+            exported_at,
+            highscore,
+            leaderboard: leaderboard(&sessions),
+            sessions,
+        })
+    }
+
+    /// CSV table: a header, then one row per logged game, oldest first.
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            String::from("rank,started_at,final_score,elapsed_time,drops,merges,seed,build\n");
+        for summary in &self.sessions {
+            let rank = self
+                .leaderboard
+                .iter()
+                .find(|e| e.started_at == summary.started_at)
+                .map_or(0, |e| e.rank);
+            csv.push_str(&format!(
+                "{rank},{},{},{:.1},{},{},{},{}\n",
+                summary.started_at,
+                summary.final_score,
+                summary.elapsed_time,
+                summary.drops,
+                summary.merges,
+                summary.seed,
+                csv_field(&summary.build)
+            ));
+        }
+        csv
+    }
+}
+
+/// Writes the highscore and session history to
+/// `{save_dir}/exports/history-{exported_at}.{json,csv}` and returns the path.
+pub fn export_history(
+    save_dir: &Path,
+    format: ExportFormat,
+    exported_at: u64,
+) -> Result<PathBuf, SaveError> {
+    let history = HistoryExport::collect(save_dir, exported_at)?;
+    let path = save_dir
+        .join("exports")
+        .join(format!("history-{exported_at}.{}", format.extension()));
+    match format {
+        ExportFormat::Json => write_json(&history, &path)?,
+        ExportFormat::Csv => write_text(&history.to_csv(), &path)?,
+    }
+    Ok(path)
+}
+
+/// Ranks `sessions` by final score, best first; equal scores share a rank.
+fn leaderboard(sessions: &[SessionSummary]) -> Vec<LeaderboardEntry> {
+    let mut sorted: Vec<&SessionSummary> = sessions.iter().collect();
+    sorted.sort_by(|a, b| {
+        b.final_score
+            .cmp(&a.final_score)
+            .then(a.started_at.cmp(&b.started_at))
+    });
+
+    let mut entries: Vec<LeaderboardEntry> = Vec::with_capacity(sorted.len());
+    for (i, summary) in sorted.into_iter().enumerate() {
+        let rank = match entries.last() {
+            Some(prev) if prev.final_score == summary.final_score => prev.rank,
+            _ => i as u32 + 1,
+        };
+        entries.push(LeaderboardEntry {
+            rank,
+            final_score: summary.final_score,
+            started_at: summary.started_at,
+            seed: summary.seed,
+        });
+    }
+    entries
+}
+
+/// Quotes a CSV field when it contains a comma, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
 #[cfg(test)]
@@ -239,7 +873,10 @@ mod tests {
 
     #[test]
     fn test_highscore_data_serde() {
-        let data = HighscoreData { highscore: 12345 };
+        let data = HighscoreData {
+            highscore: 12345,
+            ..Default::default()
+        };
 
         // Test serialization
         let json = serde_json::to_string(&data).unwrap();
@@ -250,12 +887,49 @@ mod tests {
         assert_eq!(deserialized.highscore, 12345);
     }
 
+    #[test]
+    fn test_highscore_data_without_records_loads_defaults() {
+        let data: HighscoreData = serde_json::from_str(r#"{"highscore": 700}"#).unwrap();
+        assert_eq!(data.highscore, 700);
+        assert_eq!(data.best_combo, 0);
+        assert_eq!(data.highest_fruit, None);
+    }
+
+    #[test]
+    fn test_highscore_data_record_game_keeps_each_best() {
+        let mut data = HighscoreData {
+            highscore: 1000,
+            best_combo: 3,
+            highest_fruit: Some(FruitType::Pear),
+        };
+        assert!(!data.record_game(900, 2, Some(FruitType::Apple)));
+        assert!(!data.record_game(1000, 3, None));
+
+        assert!(data.record_game(500, 5, Some(FruitType::Grape)));
+        assert_eq!(
+            data,
+            HighscoreData {
+                highscore: 1000,
+                best_combo: 5,
+                highest_fruit: Some(FruitType::Pear),
+            }
+        );
+
+        assert!(data.record_game(0, 0, Some(FruitType::Melon)));
+        assert_eq!(data.highest_fruit, Some(FruitType::Melon));
+        assert!(data.record_game(1500, 0, None));
+        assert_eq!(data.highscore, 1500);
+    }
+
     #[test]
     fn test_save_and_load_highscore() {
         let temp_dir = TempDir::new().unwrap();
         let save_path = temp_dir.path();
 
-        let data = HighscoreData { highscore: 54321 };
+        let data = HighscoreData {
+            highscore: 54321,
+            ..Default::default()
+        };
 
         // Save using the actual function
         save_highscore(&data, save_path).unwrap();
@@ -292,13 +966,75 @@ mod tests {
         assert_eq!(result.highscore, 0);
     }
 
+    #[test]
+    fn test_try_load_reports_corrupted_file_with_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+        fs::write(
+            save_path.join("settings.json"),
+            "{\n  \"bgm_volume\": 5,\n  \"sfx_volume\": 5,\n  \"effects_enabled\": \"yes\"\n}",
+        )
+        .unwrap();
+
+        let err = try_load_settings(save_path).unwrap_err();
+        match &err {
+            SaveError::Parse { path, line, .. } => {
+                assert_eq!(path, &save_path.join("settings.json"));
+                assert_eq!(*line, 4);
+            }
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+        let message = err.to_string();
+        assert!(message.contains("settings.json line 4: "), "{message}");
+        assert!(!message.contains(" at line "), "{message}");
+
+        // The lenient loader still falls back to defaults
+        let defaults = SettingsResource::default();
+        let loaded = load_settings(save_path);
+        assert_eq!(loaded.bgm_volume, defaults.bgm_volume);
+        assert_eq!(loaded.effects_enabled, defaults.effects_enabled);
+    }
+
+    #[test]
+    fn test_try_load_missing_file_is_default() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(try_load_highscore(temp_dir.path()).unwrap().highscore, 0);
+        assert_eq!(
+            try_load_settings(temp_dir.path()).unwrap().bgm_volume,
+            SettingsResource::default().bgm_volume
+        );
+        assert!(try_load_session(temp_dir.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_save_into_file_path_reports_io_error() {
+        let temp_dir = TempDir::new().unwrap();
+        // A regular file where the save directory should be
+        let blocked = temp_dir.path().join("save");
+        fs::write(&blocked, "").unwrap();
+
+        let err = save_highscore(
+            &HighscoreData {
+                highscore: 1,
+                ..Default::default()
+            },
+            &blocked,
+        )
+        .unwrap_err();
+        assert!(matches!(err, SaveError::Io { .. }), "{err:?}");
+        assert!(err.path().starts_with(&blocked));
+    }
+
     #[test]
     fn test_update_highscore_new_high() {
         let temp_dir = TempDir::new().unwrap();
         let save_path = temp_dir.path();
 
         // Set initial highscore
-        let initial = HighscoreData { highscore: 1000 };
+        let initial = HighscoreData {
+            highscore: 1000,
+            ..Default::default()
+        };
         save_highscore(&initial, save_path).unwrap();
 
         // Update with higher score
@@ -316,7 +1052,10 @@ mod tests {
         let save_path = temp_dir.path();
 
         // Set initial highscore
-        let initial = HighscoreData { highscore: 1000 };
+        let initial = HighscoreData {
+            highscore: 1000,
+            ..Default::default()
+        };
         save_highscore(&initial, save_path).unwrap();
 
         // Update with lower score
@@ -334,7 +1073,10 @@ mod tests {
 
     #[test]
     fn test_json_format() {
-        let data = HighscoreData { highscore: 99999 };
+        let data = HighscoreData {
+            highscore: 99999,
+            ..Default::default()
+        };
         let json = serde_json::to_string_pretty(&data).unwrap();
 
         // Check that JSON is pretty-printed (contains newlines)
@@ -344,4 +1086,345 @@ mod tests {
         assert!(json.contains("highscore"));
         assert!(json.contains("99999"));
     }
+
+    fn sample_session() -> SessionData {
+        SessionData {
+            score: 1234,
+            elapsed_time: 56.5,
+            drops: 21,
+            held_fruit: FruitType::Grape,
+            queue: Vec::new(),
+            fruits: vec![
+                SavedFruit {
+                    fruit_type: FruitType::Cherry,
+                    x: -10.0,
+                    y: -200.0,
+                    rotation: 0.5,
+                    linvel: [3.0, -40.0],
+                    angvel: 0.2,
+                },
+                SavedFruit {
+                    fruit_type: FruitType::Melon,
+                    x: 50.0,
+                    y: -150.0,
+                    rotation: 0.0,
+                    linvel: [0.0, 0.0],
+                    angvel: 0.0,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_save_and_load_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+
+        assert!(!session_exists(save_path));
+        save_session(&sample_session(), save_path).unwrap();
+        assert!(session_exists(save_path));
+
+        let loaded = load_session(save_path).expect("session should load");
+        assert_eq!(loaded, sample_session());
+    }
+
+    #[test]
+    fn test_load_session_missing_or_corrupted() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+        assert!(load_session(save_path).is_none());
+
+        fs::write(save_path.join("session.json"), "{ invalid json }").unwrap();
+        assert!(load_session(save_path).is_none());
+    }
+
+    #[test]
+    fn test_delete_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+
+        // Deleting a missing file is fine
+        delete_session(save_path).unwrap();
+
+        save_session(&sample_session(), save_path).unwrap();
+        delete_session(save_path).unwrap();
+        assert!(!session_exists(save_path));
+    }
+
+    #[test]
+    fn test_save_and_load_stats() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+        assert_eq!(load_stats(save_path), StatsResource::default());
+
+        let mut stats = StatsResource::default();
+        stats.record_merge(FruitType::Grape);
+        stats.record_game(1_500, 120.0);
+        save_stats(&stats, save_path).unwrap();
+        assert_eq!(try_load_stats(save_path).unwrap(), stats);
+
+        fs::write(save_path.join("stats.json"), "{ invalid json }").unwrap();
+        assert!(try_load_stats(save_path).is_err());
+        assert_eq!(load_stats(save_path), StatsResource::default());
+    }
+
+    fn save_data_app(save_dir: Option<&Path>) -> App {
+        let mut app = App::new();
+        app.insert_resource(CurrentProfile {
+            name: "test".to_string(),
+            dir: save_dir.map(Path::to_path_buf),
+        })
+        .init_resource::<SettingsResource>()
+        .init_resource::<GameState>()
+        .init_resource::<StatsResource>()
+        .init_resource::<BestRun>()
+        .init_resource::<SessionAvailable>()
+        .add_message::<SaveErrorEvent>()
+        .add_message::<SaveDataLoaded>()
+        .add_systems(Startup, load_save_data_startup);
+        app
+    }
+
+    fn loaded_events(app: &App) -> Vec<SaveDataLoaded> {
+        let messages = app.world().resource::<Messages<SaveDataLoaded>>();
+        messages.get_cursor().read(messages).copied().collect()
+    }
+
+    #[test]
+    fn test_load_save_data_fills_resources_and_reports_loaded() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+        save_highscore(
+            &HighscoreData {
+                highscore: 4_200,
+                ..Default::default()
+            },
+            save_path,
+        )
+        .unwrap();
+        let settings = SettingsResource {
+            bgm_volume: 3,
+            ..default()
+        };
+        save_settings(&settings, save_path).unwrap();
+        let mut stats = StatsResource::default();
+        stats.record_game(1_000, 60.0);
+        save_stats(&stats, save_path).unwrap();
+        fs::write(save_path.join("session.json"), "{}").unwrap();
+
+        let mut app = save_data_app(Some(save_path));
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.resource::<GameState>().highscore, 4_200);
+        assert_eq!(world.resource::<SettingsResource>().bgm_volume, 3);
+        assert_eq!(world.resource::<StatsResource>(), &stats);
+        assert!(world.resource::<SessionAvailable>().0);
+        assert_eq!(
+            loaded_events(&app),
+            vec![SaveDataLoaded {
+                highscore: 4_200,
+                session_available: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_load_save_data_corrupted_file_still_reports_loaded() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+        save_highscore(
+            &HighscoreData {
+                highscore: 900,
+                ..Default::default()
+            },
+            save_path,
+        )
+        .unwrap();
+        fs::write(save_path.join("settings.json"), "{ invalid json }").unwrap();
+
+        let mut app = save_data_app(Some(save_path));
+        app.update();
+
+        // The broken file keeps its defaults; the others still load
+        assert_eq!(
+            app.world().resource::<SettingsResource>().bgm_volume,
+            SettingsResource::default().bgm_volume
+        );
+        assert_eq!(app.world().resource::<GameState>().highscore, 900);
+        let errors = app.world().resource::<Messages<SaveErrorEvent>>();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(loaded_events(&app).len(), 1);
+    }
+
+    #[test]
+    fn test_load_save_data_corrupted_file_drops_previous_profile_data() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+        fs::write(save_path.join("stats.json"), "{ invalid json }").unwrap();
+
+        // Values left over from the profile that was loaded before
+        let mut app = save_data_app(Some(save_path));
+        app.world_mut()
+            .resource_mut::<StatsResource>()
+            .record_game(5_000, 300.0);
+        app.world_mut().resource_mut::<GameState>().highscore = 5_000;
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<StatsResource>(),
+            &StatsResource::default()
+        );
+        assert_eq!(app.world().resource::<GameState>().highscore, 0);
+        let errors = app.world().resource::<Messages<SaveErrorEvent>>();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_load_save_data_disabled_persistence_reports_loaded() {
+        let mut app = save_data_app(None);
+        app.update();
+        assert_eq!(
+            loaded_events(&app),
+            vec![SaveDataLoaded {
+                highscore: 0,
+                session_available: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_save_dir_default_and_disabled() {
+        assert_eq!(SaveDir::default().path(), Some(Path::new(SAVE_DIR)));
+        assert_eq!(SaveDir(None).path(), None);
+    }
+
+    #[test]
+    fn test_save_and_load_best_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_path = temp_dir.path();
+        assert_eq!(try_load_best_run(save_path).unwrap(), None);
+
+        let run = BestRun {
+            final_score: 320,
+            drops: vec![DropSample {
+                time: 1.5,
+                x: -40.0,
+                y: 300.0,
+                fruit_type: FruitType::Grape,
+            }],
+            score_timeline: vec![ScoreSample {
+                time: 4.0,
+                score: 320,
+            }],
+        };
+        save_best_run(&run, save_path).unwrap();
+        assert_eq!(try_load_best_run(save_path).unwrap(), Some(run));
+    }
+
+    #[test]
+    fn test_save_session_summary() {
+        let temp_dir = TempDir::new().unwrap();
+        let summary = SessionSummary {
+            started_at: 1_700_000_000,
+            seed: 42,
+            final_score: 120,
+            elapsed_time: 33.5,
+            drops: 9,
+            merges: 4,
+            score_timeline: vec![
+                ScoreSample {
+                    time: 3.0,
+                    score: 10,
+                },
+                ScoreSample {
+                    time: 8.5,
+                    score: 120,
+                },
+            ],
+            build: "v0.1.0 (abc1234)".to_string(),
+        };
+
+        let path = save_session_summary(&summary, temp_dir.path()).unwrap();
+        assert_eq!(
+            path,
+            temp_dir.path().join("sessions/session-1700000000.json")
+        );
+
+        let json = fs::read_to_string(path).unwrap();
+        let loaded: SessionSummary = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, summary);
+    }
+
+    fn summary(started_at: u64, final_score: u32) -> SessionSummary {
+        SessionSummary {
+            started_at,
+            seed: started_at * 10,
+            final_score,
+            elapsed_time: 60.0,
+            drops: 20,
+            merges: 10,
+            score_timeline: Vec::new(),
+            build: "v0.1.0 (abc1234)".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_load_session_summaries_sorted_and_skips_bad_files() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(load_session_summaries(temp_dir.path()).unwrap().is_empty());
+
+        save_session_summary(&summary(200, 50), temp_dir.path()).unwrap();
+        save_session_summary(&summary(100, 80), temp_dir.path()).unwrap();
+        fs::write(temp_dir.path().join("sessions/broken.json"), "{").unwrap();
+
+        let loaded = load_session_summaries(temp_dir.path()).unwrap();
+        let starts: Vec<u64> = loaded.iter().map(|s| s.started_at).collect();
+        assert_eq!(starts, [100, 200]);
+    }
+
+    #[test]
+    fn test_leaderboard_ranks_ties_together() {
+        let board = leaderboard(&[summary(1, 50), summary(2, 90), summary(3, 50)]);
+        let ranks: Vec<(u32, u64)> = board.iter().map(|e| (e.rank, e.started_at)).collect();
+        assert_eq!(ranks, [(1, 2), (2, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn test_export_history_json_and_csv() {
+        let temp_dir = TempDir::new().unwrap();
+        save_highscore(
+            &HighscoreData {
+                highscore: 500,
+                ..Default::default()
+            },
+            temp_dir.path(),
+        )
+        .unwrap();
+        save_session_summary(&summary(100, 80), temp_dir.path()).unwrap();
+        save_session_summary(&summary(200, 120), temp_dir.path()).unwrap();
+
+        let json_path = export_history(temp_dir.path(), ExportFormat::Json, 999).unwrap();
+        assert_eq!(json_path, temp_dir.path().join("exports/history-999.json"));
+        let history: HistoryExport =
+            serde_json::from_str(&fs::read_to_string(json_path).unwrap()).unwrap();
+        assert_eq!(history.highscore, 500);
+        assert_eq!(history.sessions.len(), 2);
+        assert_eq!(history.leaderboard[0].started_at, 200);
+
+        let csv_path = export_history(temp_dir.path(), ExportFormat::Csv, 999).unwrap();
+        let csv = fs::read_to_string(csv_path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("rank,started_at"));
+        assert!(lines[1].starts_with("2,100,80,60.0,20,10,1000,"));
+        assert!(lines[2].starts_with("1,200,120,"));
+    }
+
+    #[test]
+    fn test_csv_field_quotes_when_needed() {
+        assert_eq!(csv_field("v0.1.0 (abc)"), "v0.1.0 (abc)");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
 }
@@ -0,0 +1,350 @@
+//! Store-platform integration (achievements, cloud saves)
+//!
+//! Platform SDKs such as Steamworks are kept out of the core crates behind
+//! the [`PlatformServices`] trait.  The binary picks an implementation at
+//! startup (`--features steam` builds one on top of Steamworks) and inserts
+//! it as a [`Platform`] resource; every other build gets [`NullPlatform`]
+//! and behaves exactly as before.
+//!
+//! [`PlatformPlugin`] wires the trait into the game:
+//!
+//! | When                         | Call                                     |
+//! |------------------------------|------------------------------------------|
+//! | `PreStartup`                 | [`PlatformServices::pull_saves`] — before the save files are loaded |
+//! | every frame                  | [`PlatformServices::update`] — pump SDK callbacks |
+//! | game over, quit to title     | [`PlatformServices::push_saves`]         |
+//! | app exit                     | [`PlatformServices::push_saves`]         |
+//! | achievement unlocked         | [`PlatformServices::unlock_achievement`] |
+//! | save data (re)loaded         | [`PlatformServices::unlock_achievement`] for every stored unlock |
+//!
+//! Achievements are tracked in game by
+//! [`track_achievements`](crate::systems::achievements::track_achievements)
+//! and stored with the lifetime stats; the platform only mirrors them, so
+//! unlocks made offline or in streamer-safe mode reach it on the next load.
+
+use std::path::Path;
+
+use bevy::prelude::*;
+use thiserror::Error;
+
+use crate::events::SaveDataLoaded;
+use crate::profiles::CurrentProfile;
+use crate::resources::StatsResource;
+use crate::states::AppState;
+use crate::systems::achievements::AchievementUnlockedEvent;
+use crate::systems::game_over::GameOverSet;
+use crate::systems::safe_mode::online_allowed;
+
+/// Save files mirrored to cloud storage (relative to the save directory).
+///
+/// Per-game session logs stay local; they are for analysis, not progress.
+pub const CLOUD_SAVE_FILES: [&str; 3] = ["highscore.json", "settings.json", "session.json"];
+
+// ---------------------------------------------------------------------------
+// Trait
+// ---------------------------------------------------------------------------
+
+/// An error reported by a platform SDK.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{0}")]
+pub struct PlatformError(pub String);
+
+/// Services a store platform provides to the game.
+///
+/// Every method has a do-nothing default so an implementation only
+/// overrides what its platform supports.
+pub trait PlatformServices: Send + Sync + 'static {
+    /// Name shown in logs.
+    fn name(&self) -> &'static str;
+
+    /// Pumps the SDK's callbacks; called once per frame.
+    fn update(&mut self) {}
+
+    /// Marks achievement `id` as unlocked on the platform.
+    fn unlock_achievement(&mut self, _id: &str) -> Result<(), PlatformError> {
+        Ok(())
+    }
+
+    /// Copies cloud files that are newer than the local ones into `save_dir`.
+    ///
+    /// Returns the number of files written.
+    fn pull_saves(&mut self, _save_dir: &Path) -> Result<usize, PlatformError> {
+        Ok(0)
+    }
+
+    /// Uploads the local save files in `save_dir` to the cloud.
+    ///
+    /// Returns the number of files uploaded.
+    fn push_saves(&mut self, _save_dir: &Path) -> Result<usize, PlatformError> {
+        Ok(0)
+    }
+}
+
+/// Platform for builds without a store SDK; every call is a no-op.
+#[derive(Debug, Default)]
+pub struct NullPlatform;
+
+impl PlatformServices for NullPlatform {
+    fn name(&self) -> &'static str {
+        "none"
+    }
+}
+
+/// The active platform implementation.
+#[derive(Resource)]
+pub struct Platform(pub Box<dyn PlatformServices>);
+
+impl Default for Platform {
+    fn default() -> Self {
+        Self(Box::new(NullPlatform))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Connects the [`Platform`] resource to startup, save points and exit.
+///
+/// Insert a [`Platform`] before adding the plugin to use a real SDK;
+/// otherwise [`NullPlatform`] is used.  Must be added after
+/// [`GameCorePlugin`](crate::GameCorePlugin), which provides [`CurrentProfile`].
+/// Cloud saves hold the files of the current profile and are skipped in
+/// streamer-safe mode
+/// ([`SafeMode`](crate::systems::safe_mode::SafeMode)).
+pub struct PlatformPlugin;
+
+impl Plugin for PlatformPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Platform>();
+        info!("Platform: {}", app.world().resource::<Platform>().0.name());
+
+        app.add_message::<AchievementUnlockedEvent>()
+            .add_message::<SaveDataLoaded>();
+
+        app.add_systems(PreStartup, pull_cloud_saves.run_if(online_allowed))
+            .add_systems(Update, update_platform)
+            .add_systems(Update, mirror_achievements.run_if(online_allowed))
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                push_cloud_saves
+                    .after(GameOverSet::SaveHighscore)
+                    .run_if(online_allowed),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: AppState::Paused,
+                    entered: AppState::Title,
+                },
+                push_cloud_saves
+                    .after(crate::systems::session::save_session_on_quit)
+                    .run_if(online_allowed),
+            )
+            .add_systems(Last, push_cloud_saves_on_exit.run_if(online_allowed));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Pulls newer cloud saves before the startup systems read them.
+pub fn pull_cloud_saves(mut platform: ResMut<Platform>, profile: Res<CurrentProfile>) {
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    match platform.0.pull_saves(dir) {
+        Ok(0) => {}
+        Ok(n) => info!("☁️ Pulled {n} save file(s) from {}", platform.0.name()),
+        Err(e) => warn!("Cloud save pull failed: {e}"),
+    }
+}
+
+/// Uploads the save files after the game wrote them.
+pub fn push_cloud_saves(mut platform: ResMut<Platform>, profile: Res<CurrentProfile>) {
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    match platform.0.push_saves(dir) {
+        Ok(0) => {}
+        Ok(n) => info!("☁️ Pushed {n} save file(s) to {}", platform.0.name()),
+        Err(e) => warn!("Cloud save push failed: {e}"),
+    }
+}
+
+/// Uploads the save files once more when the app is about to exit.
+pub fn push_cloud_saves_on_exit(
+    mut exits: MessageReader<AppExit>,
+    platform: ResMut<Platform>,
+    profile: Res<CurrentProfile>,
+) {
+    if exits.read().count() > 0 {
+        push_cloud_saves(platform, profile);
+    }
+}
+
+/// Reports achievements to the platform.
+///
+/// New unlocks are sent as they happen; whenever the save data is
+/// (re)loaded every stored unlock is sent again, which catches up on
+/// anything unlocked while the platform was unavailable.
+pub fn mirror_achievements(
+    mut platform: ResMut<Platform>,
+    mut unlocked: MessageReader<AchievementUnlockedEvent>,
+    mut loaded: MessageReader<SaveDataLoaded>,
+    stats: Option<Res<StatsResource>>,
+) {
+    let mut ids: Vec<&'static str> = unlocked.read().map(|event| event.0.id()).collect();
+    if loaded.read().count() > 0
+        && let Some(stats) = stats
+    {
+        ids.extend(stats.achievements.iter().map(|a| a.id()));
+    }
+    for id in ids {
+        if let Err(e) = platform.0.unlock_achievement(id) {
+            warn!(
+                "Achievement {id} not reported to {}: {e}",
+                platform.0.name()
+            );
+        }
+    }
+}
+
+/// Pumps the platform SDK.
+pub fn update_platform(mut platform: ResMut<Platform>) {
+    platform.0.update();
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::achievements::Achievement;
+    use crate::systems::safe_mode::SafeMode;
+    use std::sync::{Arc, Mutex};
+
+    /// Records which calls the plugin made.
+    #[derive(Clone, Default)]
+    struct RecordingPlatform(Arc<Mutex<Vec<&'static str>>>);
+
+    impl PlatformServices for RecordingPlatform {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        fn pull_saves(&mut self, _save_dir: &Path) -> Result<usize, PlatformError> {
+            self.0.lock().unwrap().push("pull");
+            Ok(1)
+        }
+
+        fn push_saves(&mut self, _save_dir: &Path) -> Result<usize, PlatformError> {
+            self.0.lock().unwrap().push("push");
+            Ok(1)
+        }
+
+        fn unlock_achievement(&mut self, id: &str) -> Result<(), PlatformError> {
+            let id = Achievement::ALL
+                .into_iter()
+                .map(Achievement::id)
+                .find(|known| *known == id)
+                .ok_or_else(|| PlatformError(format!("unknown achievement '{id}'")))?;
+            self.0.lock().unwrap().push(id);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_null_platform_does_nothing() {
+        let mut platform = NullPlatform;
+        assert_eq!(platform.pull_saves(Path::new("save")), Ok(0));
+        assert_eq!(platform.push_saves(Path::new("save")), Ok(0));
+        assert_eq!(platform.unlock_achievement("FIRST_WATERMELON"), Ok(()));
+    }
+
+    #[test]
+    fn test_pull_at_startup_and_push_on_exit() {
+        let recording = RecordingPlatform::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<AppState>()
+            .insert_resource(CurrentProfile::new("default", Some(Path::new("save"))))
+            .insert_resource(Platform(Box::new(recording.clone())))
+            .add_plugins(PlatformPlugin);
+
+        app.update();
+        assert_eq!(*recording.0.lock().unwrap(), vec!["pull"]);
+
+        app.world_mut().write_message(AppExit::Success);
+        app.update();
+        assert_eq!(*recording.0.lock().unwrap(), vec!["pull", "push"]);
+    }
+
+    #[test]
+    fn test_achievements_are_mirrored() {
+        let recording = RecordingPlatform::default();
+        let mut stats = StatsResource::default();
+        stats.achievements.insert(Achievement::FirstMerge);
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<AppState>()
+            .init_resource::<CurrentProfile>()
+            .insert_resource(stats)
+            .insert_resource(Platform(Box::new(recording.clone())))
+            .add_plugins(PlatformPlugin);
+        app.update();
+
+        // A new unlock is reported as it happens
+        app.world_mut()
+            .write_message(AchievementUnlockedEvent(Achievement::Combo5));
+        app.update();
+        assert_eq!(*recording.0.lock().unwrap(), vec!["COMBO_5"]);
+
+        // Loading the save data reports every stored unlock again
+        app.world_mut().write_message(SaveDataLoaded {
+            highscore: 0,
+            session_available: false,
+        });
+        app.update();
+        assert_eq!(*recording.0.lock().unwrap(), vec!["COMBO_5", "FIRST_MERGE"]);
+    }
+
+    #[test]
+    fn test_disabled_persistence_skips_sync() {
+        let recording = RecordingPlatform::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<AppState>()
+            .init_resource::<CurrentProfile>()
+            .insert_resource(Platform(Box::new(recording.clone())))
+            .add_plugins(PlatformPlugin);
+
+        app.update();
+        app.world_mut().write_message(AppExit::Success);
+        app.update();
+        assert!(recording.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_safe_mode_skips_sync() {
+        let recording = RecordingPlatform::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<AppState>()
+            .insert_resource(CurrentProfile::new("default", Some(Path::new("save"))))
+            .insert_resource(SafeMode { active: true })
+            .insert_resource(Platform(Box::new(recording.clone())))
+            .add_plugins(PlatformPlugin);
+
+        app.update();
+        app.world_mut().write_message(AppExit::Success);
+        app.update();
+        assert!(recording.0.lock().unwrap().is_empty());
+    }
+}
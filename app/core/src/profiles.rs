@@ -0,0 +1,321 @@
+//! Player profiles
+//!
+//! Every profile keeps its own settings, lifetime stats, highscore, saved
+//! session and logs in a directory of its own under the save directory:
+//!
+//! ```text
+//! save/
+//! ├── profiles.json              the selected profile
+//! └── profiles/
+//!     ├── default/
+//!     │   ├── highscore.json
+//!     │   ├── settings.json
+//!     │   └── ...
+//!     └── Player 2/
+//! ```
+//!
+//! All persistence goes through the [`CurrentProfile`] resource, whose
+//! [`path`](CurrentProfile::path) is the directory the `persistence`
+//! functions read and write.  Changing the resource (the title screen's
+//! profile selector) reloads the save data of the new profile.
+//!
+//! Save files written before profiles existed sit directly in `save/`; they
+//! are moved into [`DEFAULT_PROFILE`] the first time the game starts.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::storage::{DEFAULT_PROFILE, PROFILES_DIR};
+use crate::persistence::{SaveError, read_json, write_json};
+
+/// Save files and directories that belong to a profile.
+const PROFILE_ENTRIES: [&str; 6] = [
+    "highscore.json",
+    "settings.json",
+    "stats.json",
+    "session.json",
+    "sessions",
+    "exports",
+];
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// The profile being played.
+///
+/// Inserted by `GameCorePlugin`.  `dir` is `None` when persistence is
+/// disabled, which turns every load into a default and every save into a
+/// no-op.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct CurrentProfile {
+    /// Profile name, also its directory name
+    pub name: String,
+    /// The profile's save directory
+    pub dir: Option<PathBuf>,
+}
+
+impl Default for CurrentProfile {
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_PROFILE.to_string(),
+            dir: None,
+        }
+    }
+}
+
+impl CurrentProfile {
+    /// Profile `name` inside save directory `save_dir`.
+    pub fn new(name: &str, save_dir: Option<&Path>) -> Self {
+        Self {
+            name: name.to_string(),
+            dir: save_dir.map(|dir| profile_dir(dir, name)),
+        }
+    }
+
+    /// The profile selected last time in `save_dir`, after moving legacy
+    /// save files into [`DEFAULT_PROFILE`].
+    ///
+    /// `None` (persistence disabled) is the default profile without a
+    /// directory.
+    pub fn load(save_dir: Option<&Path>) -> Self {
+        let Some(save_dir) = save_dir else {
+            return Self::default();
+        };
+        if let Err(e) = migrate_legacy_saves(save_dir) {
+            warn!("Failed to move old save files into a profile: {e}");
+        }
+        let name = try_load_selection(save_dir)
+            .unwrap_or_else(|e| {
+                warn!("Ignoring unreadable profile selection: {e}");
+                None
+            })
+            .map(|selection| selection.current)
+            .filter(|name| is_valid_name(name))
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+        Self::new(&name, Some(save_dir))
+    }
+
+    /// The profile's save directory, or `None` when persistence is disabled.
+    pub fn path(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Profile directories
+// ---------------------------------------------------------------------------
+
+/// Contents of `save/profiles.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileSelection {
+    /// Name of the selected profile
+    pub current: String,
+}
+
+/// Directory of profile `name`: `{save_dir}/profiles/{name}`.
+pub fn profile_dir(save_dir: &Path, name: &str) -> PathBuf {
+    save_dir.join(PROFILES_DIR).join(name)
+}
+
+/// Names of the profiles in `save_dir`, sorted, [`DEFAULT_PROFILE`] first.
+///
+/// The default profile is always listed, even before anything was saved.
+pub fn list_profiles(save_dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(save_dir.join(PROFILES_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name != DEFAULT_PROFILE && is_valid_name(name))
+        .collect();
+    names.sort();
+    names.insert(0, DEFAULT_PROFILE.to_string());
+    names
+}
+
+/// Creates the next free `Player N` profile and returns its name.
+pub fn create_profile(save_dir: &Path) -> Result<String, SaveError> {
+    let existing = list_profiles(save_dir);
+    let name = (2..)
+        .map(|n| format!("Player {n}"))
+        .find(|name| !existing.contains(name))
+        .expect("unbounded range always has a free name");
+    let dir = profile_dir(save_dir, &name);
+    fs::create_dir_all(&dir).map_err(|source| SaveError::Io { path: dir, source })?;
+    Ok(name)
+}
+
+/// Remembers `name` as the profile to load on the next launch.
+pub fn save_selection(name: &str, save_dir: &Path) -> Result<(), SaveError> {
+    let selection = ProfileSelection {
+        current: name.to_string(),
+    };
+    write_json(&selection, &save_dir.join("profiles.json"))
+}
+
+/// Reads `save/profiles.json`; `Ok(None)` when no profile was selected yet.
+pub fn try_load_selection(save_dir: &Path) -> Result<Option<ProfileSelection>, SaveError> {
+    read_json(&save_dir.join("profiles.json"))
+}
+
+/// Moves save files from the top of `save_dir` into [`DEFAULT_PROFILE`].
+///
+/// Only runs while `save_dir` has no profiles directory yet, so a file
+/// written there later is never picked up.  Returns how many entries were
+/// moved.
+pub fn migrate_legacy_saves(save_dir: &Path) -> Result<usize, SaveError> {
+    if save_dir.join(PROFILES_DIR).exists() {
+        return Ok(0);
+    }
+    let legacy: Vec<PathBuf> = PROFILE_ENTRIES
+        .iter()
+        .map(|entry| save_dir.join(entry))
+        .filter(|path| path.exists())
+        .collect();
+    if legacy.is_empty() {
+        return Ok(0);
+    }
+
+    let target = profile_dir(save_dir, DEFAULT_PROFILE);
+    fs::create_dir_all(&target).map_err(|source| SaveError::Io {
+        path: target.clone(),
+        source,
+    })?;
+    for path in &legacy {
+        let Some(entry) = path.file_name() else {
+            continue;
+        };
+        fs::rename(path, target.join(entry)).map_err(|source| SaveError::Io {
+            path: path.clone(),
+            source,
+        })?;
+    }
+    info!(
+        "Moved {} save file(s) into profile '{DEFAULT_PROFILE}'",
+        legacy.len()
+    );
+    Ok(legacy.len())
+}
+
+/// Whether `name` can be used as a directory name on every platform.
+fn is_valid_name(name: &str) -> bool {
+    !name.trim().is_empty()
+        && name != "."
+        && name != ".."
+        && !name
+            .chars()
+            .any(|c| matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|'))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::{HighscoreData, load_highscore, save_highscore};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_without_save_dir_is_default() {
+        let profile = CurrentProfile::load(None);
+        assert_eq!(profile.name, DEFAULT_PROFILE);
+        assert_eq!(profile.path(), None);
+    }
+
+    #[test]
+    fn test_load_uses_saved_selection() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_dir = temp_dir.path();
+        assert_eq!(
+            CurrentProfile::load(Some(save_dir)).path(),
+            Some(profile_dir(save_dir, DEFAULT_PROFILE).as_path())
+        );
+
+        let name = create_profile(save_dir).unwrap();
+        save_selection(&name, save_dir).unwrap();
+        let profile = CurrentProfile::load(Some(save_dir));
+        assert_eq!(profile.name, "Player 2");
+        assert_eq!(profile.dir, Some(profile_dir(save_dir, "Player 2")));
+    }
+
+    #[test]
+    fn test_load_rejects_path_in_selection() {
+        let temp_dir = TempDir::new().unwrap();
+        save_selection("../elsewhere", temp_dir.path()).unwrap();
+        let profile = CurrentProfile::load(Some(temp_dir.path()));
+        assert_eq!(profile.name, DEFAULT_PROFILE);
+    }
+
+    #[test]
+    fn test_create_and_list_profiles() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_dir = temp_dir.path();
+        assert_eq!(list_profiles(save_dir), [DEFAULT_PROFILE]);
+
+        assert_eq!(create_profile(save_dir).unwrap(), "Player 2");
+        assert_eq!(create_profile(save_dir).unwrap(), "Player 3");
+        assert_eq!(
+            list_profiles(save_dir),
+            [DEFAULT_PROFILE, "Player 2", "Player 3"]
+        );
+    }
+
+    #[test]
+    fn test_profiles_keep_separate_saves() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_dir = temp_dir.path();
+        let first = CurrentProfile::new(DEFAULT_PROFILE, Some(save_dir));
+        let second = CurrentProfile::new("Player 2", Some(save_dir));
+        save_highscore(
+            &HighscoreData {
+                highscore: 500,
+                ..Default::default()
+            },
+            first.path().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(load_highscore(first.path().unwrap()).highscore, 500);
+        assert_eq!(load_highscore(second.path().unwrap()).highscore, 0);
+    }
+
+    #[test]
+    fn test_migrate_moves_legacy_files_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let save_dir = temp_dir.path();
+        save_highscore(
+            &HighscoreData {
+                highscore: 1_200,
+                ..Default::default()
+            },
+            save_dir,
+        )
+        .unwrap();
+        fs::create_dir_all(save_dir.join("sessions")).unwrap();
+
+        assert_eq!(migrate_legacy_saves(save_dir).unwrap(), 2);
+        assert!(!save_dir.join("highscore.json").exists());
+        let default_dir = profile_dir(save_dir, DEFAULT_PROFILE);
+        assert_eq!(load_highscore(&default_dir).highscore, 1_200);
+        assert!(default_dir.join("sessions").is_dir());
+
+        // Once profiles exist, files at the top level are left alone
+        save_highscore(
+            &HighscoreData {
+                highscore: 7,
+                ..Default::default()
+            },
+            save_dir,
+        )
+        .unwrap();
+        assert_eq!(migrate_legacy_saves(save_dir).unwrap(), 0);
+        assert!(save_dir.join("highscore.json").exists());
+    }
+}
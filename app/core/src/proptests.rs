@@ -0,0 +1,252 @@
+//! Property-based tests for fruit evolution and scoring
+//!
+//! Every property runs against randomly generated [`FruitsConfig`] /
+//! [`GameRulesConfig`] values instead of the shipped RON files, so balance
+//! edits cannot silently break the invariants:
+//!
+//! - merging two stage-N fruits always yields exactly one stage-N+1 fruit
+//!   (and two watermelons yield nothing)
+//! - the score never decreases, whatever the merge sequence and combo bonuses
+//! - the combo multiplier is monotone in the combo count when the configured
+//!   bonuses are
+//! - the clamped spawn position always keeps the fruit inside the container
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Collider;
+use proptest::prelude::*;
+
+use crate::components::Fruit;
+use crate::config::{
+    FruitConfigEntry, FruitsConfig, FruitsConfigHandle, GameRulesConfig, GameRulesConfigHandle,
+};
+use crate::events::{ChainMergeEvent, FruitMergeEvent, ScoreEarnedEvent};
+use crate::fruit::FruitType;
+use crate::resources::{CircleTexture, ComboTimer, GameState};
+use crate::systems::input::clamp_spawn_x;
+use crate::systems::merge::handle_fruit_merge;
+use crate::systems::score::{combo_multiplier, update_score_on_merge};
+use crate::systems::spawn::spawn_fruit;
+
+/// Number of fruit stages.
+const STAGES: usize = 11;
+
+/// Cases per property; each case that builds an `App` is comparatively slow.
+const APP_CASES: u32 = 64;
+
+// ---------------------------------------------------------------------------
+// Strategies
+// ---------------------------------------------------------------------------
+
+fn fruit_type() -> impl Strategy<Value = FruitType> {
+    (0..STAGES).prop_map(|stage| {
+        std::iter::successors(Some(FruitType::Cherry), FruitType::next)
+            .nth(stage)
+            .expect("stage is in range")
+    })
+}
+
+/// A full fruits config with growing radii and arbitrary points.
+fn fruits_config() -> impl Strategy<Value = FruitsConfig> {
+    (
+        1.0f32..40.0,
+        prop::collection::vec(1.0f32..30.0, STAGES),
+        prop::collection::vec(0u32..1_000_000, STAGES),
+    )
+        .prop_map(|(first_radius, growth, points)| {
+            let mut radius = first_radius;
+            FruitsConfig {
+                fruits: growth
+                    .iter()
+                    .zip(points)
+                    .enumerate()
+                    .map(|(i, (step, points))| {
+                        let entry = FruitConfigEntry {
+                            name: format!("Fruit{i}"),
+                            radius,
+                            points,
+                            restitution: 0.3,
+                            friction: 0.5,
+                            mass_multiplier: 0.01,
+                            ..Default::default()
+                        };
+                        radius += step;
+                        entry
+                    })
+                    .collect(),
+            }
+        })
+}
+
+/// Game rules with arbitrary (possibly < 1.0) combo bonuses.
+fn rules_config() -> impl Strategy<Value = GameRulesConfig> {
+    (
+        0.1f32..10.0,
+        prop::collection::hash_map(2u32..20, 0.0f32..10.0, 0..6),
+    )
+        .prop_map(|(combo_window, combo_bonuses)| rules_with(combo_window, combo_bonuses))
+}
+
+/// Game rules whose combo bonuses never drop as the combo key grows.
+fn monotone_rules_config() -> impl Strategy<Value = GameRulesConfig> {
+    prop::collection::btree_map(2u32..20, 0.0f32..1.0, 0..6).prop_map(|steps| {
+        let mut bonus = 1.0;
+        let combo_bonuses = steps
+            .into_iter()
+            .map(|(combo, step)| {
+                bonus += step;
+                (combo, bonus)
+            })
+            .collect();
+        rules_with(2.0, combo_bonuses)
+    })
+}
+
+fn rules_with(combo_window: f32, combo_bonuses: HashMap<u32, f32>) -> GameRulesConfig {
+    GameRulesConfig {
+        spawnable_fruit_count: 5,
+        combo_window,
+        combo_max: 10,
+        game_over_timer: 3.0,
+        combo_bonuses,
+        chain_window: 0.25,
+        chain_bonuses: HashMap::new(),
+        overfill_pressure: Default::default(),
+        adaptive_spawn: Default::default(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn app_with_fruits_config(config: FruitsConfig) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_message::<FruitMergeEvent>();
+    app.add_message::<ChainMergeEvent>();
+    app.add_message::<ScoreEarnedEvent>();
+    app.insert_resource(CircleTexture(Handle::default()));
+
+    let mut fruits_assets = Assets::<FruitsConfig>::default();
+    let handle = fruits_assets.add(config);
+    app.insert_resource(fruits_assets);
+    app.insert_resource(FruitsConfigHandle(handle));
+    app
+}
+
+// ---------------------------------------------------------------------------
+// Properties
+// ---------------------------------------------------------------------------
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(APP_CASES))]
+
+    #[test]
+    fn test_merging_stage_n_yields_stage_n_plus_one(
+        config in fruits_config(),
+        fruit in fruit_type(),
+    ) {
+        let mut app = app_with_fruits_config(config.clone());
+        app.add_systems(Update, handle_fruit_merge);
+
+        let mut commands = app.world_mut().commands();
+        let a = spawn_fruit(&mut commands, fruit, Vec2::ZERO, &config, Handle::default(), None);
+        let b = spawn_fruit(&mut commands, fruit, Vec2::X, &config, Handle::default(), None);
+        // Flush the spawns before the merge event is sent
+        app.update();
+        app.world_mut().write_message(FruitMergeEvent {
+            entity1: a,
+            entity2: b,
+            fruit_type: fruit,
+            position: Vec2::ZERO,
+        });
+        app.update();
+
+        let fruits: Vec<(FruitType, f32)> = app
+            .world_mut()
+            .query_filtered::<(&FruitType, &Collider), With<Fruit>>()
+            .iter(app.world())
+            .map(|(t, c)| (*t, c.as_ball().map_or(0.0, |b| b.radius())))
+            .collect();
+
+        match fruit.next() {
+            Some(next) => {
+                prop_assert_eq!(fruits.len(), 1);
+                prop_assert_eq!(fruits[0].0, next);
+                let radius = next.parameters_from_config(&config).radius;
+                prop_assert!((fruits[0].1 - radius).abs() < 1e-3);
+            }
+            None => prop_assert!(fruits.is_empty()),
+        }
+    }
+
+    #[test]
+    fn test_score_never_decreases(
+        config in fruits_config(),
+        rules in rules_config(),
+        merges in prop::collection::vec((fruit_type(), 0.0f32..3.0), 1..20),
+    ) {
+        let mut app = app_with_fruits_config(config);
+        app.init_resource::<GameState>();
+        app.init_resource::<ComboTimer>();
+        let mut rules_assets = Assets::<GameRulesConfig>::default();
+        let rules_handle = rules_assets.add(rules);
+        app.insert_resource(rules_assets);
+        app.insert_resource(GameRulesConfigHandle(rules_handle));
+        app.add_systems(Update, update_score_on_merge);
+
+        let mut last_score = 0;
+        for (fruit, gap) in merges {
+            app.world_mut().resource_mut::<ComboTimer>().tick(gap);
+            app.world_mut().write_message(FruitMergeEvent {
+                entity1: Entity::PLACEHOLDER,
+                entity2: Entity::PLACEHOLDER,
+                fruit_type: fruit,
+                position: Vec2::ZERO,
+            });
+            app.update();
+
+            let score = app.world().resource::<GameState>().score;
+            prop_assert!(score >= last_score, "score dropped from {} to {}", last_score, score);
+            last_score = score;
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_combo_multiplier_is_monotone(rules in monotone_rules_config()) {
+        for combo in 0..25 {
+            let (now, next) = (
+                combo_multiplier(combo, Some(&rules)),
+                combo_multiplier(combo + 1, Some(&rules)),
+            );
+            prop_assert!(now <= next, "combo {}: {} > {}", combo, now, next);
+        }
+    }
+
+    #[test]
+    fn test_default_combo_multiplier_is_monotone(combo in 0u32..1_000) {
+        prop_assert!(combo_multiplier(combo, None) <= combo_multiplier(combo + 1, None));
+    }
+
+    #[test]
+    fn test_spawn_clamp_stays_within_container(
+        config in fruits_config(),
+        fruit in fruit_type(),
+        container_width in 1.0f32..2_000.0,
+        x in -1e6f32..1e6,
+    ) {
+        let radius = fruit.parameters_from_config(&config).radius;
+        let half_width = container_width / 2.0;
+        let clamped = clamp_spawn_x(x, container_width, radius);
+
+        prop_assert!((-half_width..=half_width).contains(&clamped));
+        if radius <= half_width {
+            // The whole fruit fits between the walls
+            prop_assert!(clamped.abs() + radius <= half_width + 1e-3);
+        }
+    }
+}
@@ -62,10 +62,15 @@ impl ComboTimer {
     }
 
     /// Checks if the combo window has expired and resets if needed
-    pub fn check_and_reset(&mut self) {
+    ///
+    /// Returns the combo count that just expired, if any.
+    pub fn check_and_reset(&mut self) -> Option<u32> {
         if self.time_since_last_merge > self.combo_window && self.current_combo > 1 {
+            let expired = self.current_combo;
             self.current_combo = 1;
+            return Some(expired);
         }
+        None
     }
 
     /// Returns true if currently in a combo (2+ merges)
@@ -2,18 +2,27 @@
 
 use bevy::prelude::*;
 
+use crate::fruit::FruitType;
+
 /// Main game state resource
 ///
-/// Tracks the player's current score, all-time high score,
-/// and elapsed time in the current game session.
+/// Tracks the player's current score, all-time records (high score, best
+/// combo, largest fruit), elapsed time and drop count in the current game
+/// session.
 #[derive(Resource, Debug, Clone)]
 pub struct GameState {
     /// Current score in this game session
     pub score: u32,
     /// All-time high score (persisted across sessions)
     pub highscore: u32,
+    /// All-time highest combo count (persisted across sessions)
+    pub best_combo_record: u32,
+    /// All-time largest fruit reached (persisted across sessions)
+    pub highest_fruit_record: Option<FruitType>,
     /// Elapsed time in seconds since game started
     pub elapsed_time: f32,
+    /// Fruits dropped in this game session
+    pub drops: u32,
     /// Set to `true` on `OnEnter(GameOver)` when the current score beats the
     /// previous highscore.  Consumed by the game-over screen to show the
     /// "NEW RECORD!" banner.  Cleared on every game reset.
@@ -25,8 +34,18 @@ impl Default for GameState {
         Self {
             score: 0,
             highscore: 0,
+            best_combo_record: 0,
+            highest_fruit_record: None,
             elapsed_time: 0.0,
+            drops: 0,
             is_new_record: false,
         }
     }
 }
+
+impl GameState {
+    /// Average points earned per dropped fruit, or `None` before the first drop.
+    pub fn points_per_drop(&self) -> Option<f32> {
+        (self.drops > 0).then(|| self.score as f32 / self.drops as f32)
+    }
+}
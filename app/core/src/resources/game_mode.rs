@@ -0,0 +1,63 @@
+//! Game mode resource
+
+use std::fmt;
+use std::str::FromStr;
+
+use bevy::prelude::*;
+
+use super::ScoreStrategy;
+
+/// Rule set the game is played with.
+///
+/// Selected once at launch (`--mode` / `SUIKA_MODE`, see
+/// [`crate::launch::LaunchOptions`]) and fixed for the whole run.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GameMode {
+    /// Standard rules: base points with the combo multiplier.
+    #[default]
+    Classic,
+    /// Base points only; combos never change the score.
+    NoCombo,
+}
+
+impl GameMode {
+    /// All modes, in the order they are listed in `--help` style output.
+    pub const ALL: [GameMode; 2] = [GameMode::Classic, GameMode::NoCombo];
+
+    /// Name used on the command line and in environment variables.
+    pub fn name(self) -> &'static str {
+        match self {
+            GameMode::Classic => "classic",
+            GameMode::NoCombo => "no-combo",
+        }
+    }
+
+    /// Score pipeline used by this mode.
+    pub fn score_strategy(self) -> ScoreStrategy {
+        match self {
+            GameMode::Classic => ScoreStrategy::default(),
+            GameMode::NoCombo => ScoreStrategy::without_combo(),
+        }
+    }
+}
+
+impl fmt::Display for GameMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for GameMode {
+    type Err = String;
+
+    /// Parses a mode name case-insensitively (`classic`, `no-combo`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|m| m.name()).collect();
+                format!("unknown mode '{s}' (expected one of: {})", names.join(", "))
+            })
+    }
+}
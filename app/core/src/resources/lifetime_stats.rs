@@ -0,0 +1,138 @@
+//! Lifetime statistics accumulated over every finished game
+//!
+//! Persisted to `save/stats.json` and shown on the stats screen.
+
+use std::collections::BTreeSet;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::fruit::FruitType;
+use crate::systems::achievements::Achievement;
+
+/// Number of fruit kinds, Cherry through Watermelon.
+pub const FRUIT_KIND_COUNT: usize = FruitType::Watermelon as usize + 1;
+
+/// Width of one score-distribution bucket (points).
+pub const SCORE_BUCKET_SIZE: u32 = 500;
+
+/// Number of score-distribution buckets; the last one is open-ended.
+pub const SCORE_BUCKET_COUNT: usize = 10;
+
+/// Totals across all finished games.
+///
+/// Merges are counted while playing; games, playtime and the score
+/// distribution are added on game over.  Games abandoned from the pause menu
+/// only contribute their merges.
+#[derive(Resource, Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsResource {
+    /// Finished games
+    pub games_played: u32,
+    /// Seconds spent in finished games
+    pub total_playtime: f64,
+    /// Sum of all final scores
+    pub total_score: u64,
+    /// Merges per fruit kind, indexed by [`FruitType::stage_index`] of the
+    /// merging fruits
+    pub merges: [u32; FRUIT_KIND_COUNT],
+    /// Games per score bucket of [`SCORE_BUCKET_SIZE`] points
+    pub score_distribution: [u32; SCORE_BUCKET_COUNT],
+    /// Unlocked achievements
+    pub achievements: BTreeSet<Achievement>,
+}
+
+impl StatsResource {
+    /// Counts a merge of two `fruit_type` fruits.
+    pub fn record_merge(&mut self, fruit_type: FruitType) {
+        self.merges[fruit_type.stage_index()] += 1;
+    }
+
+    /// Adds a finished game with `final_score` that lasted `elapsed_time` seconds.
+    pub fn record_game(&mut self, final_score: u32, elapsed_time: f32) {
+        self.games_played += 1;
+        self.total_playtime += elapsed_time as f64;
+        self.total_score += final_score as u64;
+        self.score_distribution[Self::bucket_of(final_score)] += 1;
+    }
+
+    /// Merges of two `fruit_type` fruits.
+    pub fn merges_of(&self, fruit_type: FruitType) -> u32 {
+        self.merges[fruit_type.stage_index()]
+    }
+
+    /// Merges of every kind.
+    pub fn total_merges(&self) -> u64 {
+        self.merges.iter().map(|&n| n as u64).sum()
+    }
+
+    /// Mean final score, or `None` before the first finished game.
+    pub fn average_score(&self) -> Option<u32> {
+        (self.games_played > 0).then(|| (self.total_score / self.games_played as u64) as u32)
+    }
+
+    /// Distribution bucket that `score` falls into.
+    pub fn bucket_of(score: u32) -> usize {
+        ((score / SCORE_BUCKET_SIZE) as usize).min(SCORE_BUCKET_COUNT - 1)
+    }
+
+    /// Lowest score of `bucket`.
+    pub fn bucket_floor(bucket: usize) -> u32 {
+        bucket as u32 * SCORE_BUCKET_SIZE
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_game_updates_totals_and_distribution() {
+        let mut stats = StatsResource::default();
+        assert_eq!(stats.average_score(), None);
+
+        stats.record_game(400, 60.0);
+        stats.record_game(1_200, 90.0);
+        stats.record_game(99_999, 30.0);
+
+        assert_eq!(stats.games_played, 3);
+        assert_eq!(stats.total_playtime, 180.0);
+        assert_eq!(stats.average_score(), Some(33_866));
+        assert_eq!(stats.score_distribution[0], 1);
+        assert_eq!(stats.score_distribution[2], 1);
+        assert_eq!(stats.score_distribution[SCORE_BUCKET_COUNT - 1], 1);
+    }
+
+    #[test]
+    fn test_record_merge_counts_per_fruit() {
+        let mut stats = StatsResource::default();
+        stats.record_merge(FruitType::Cherry);
+        stats.record_merge(FruitType::Cherry);
+        stats.record_merge(FruitType::Melon);
+
+        assert_eq!(stats.merges_of(FruitType::Cherry), 2);
+        assert_eq!(stats.merges_of(FruitType::Melon), 1);
+        assert_eq!(stats.merges_of(FruitType::Watermelon), 0);
+        assert_eq!(stats.total_merges(), 3);
+    }
+
+    #[test]
+    fn test_bucket_bounds() {
+        assert_eq!(StatsResource::bucket_of(0), 0);
+        assert_eq!(StatsResource::bucket_of(SCORE_BUCKET_SIZE - 1), 0);
+        assert_eq!(StatsResource::bucket_of(SCORE_BUCKET_SIZE), 1);
+        assert_eq!(StatsResource::bucket_of(u32::MAX), SCORE_BUCKET_COUNT - 1);
+        assert_eq!(StatsResource::bucket_floor(3), 3 * SCORE_BUCKET_SIZE);
+    }
+
+    #[test]
+    fn test_missing_fields_deserialize_to_defaults() {
+        let stats: StatsResource = serde_json::from_str(r#"{"games_played": 4}"#).unwrap();
+        assert_eq!(stats.games_played, 4);
+        assert_eq!(stats.total_merges(), 0);
+    }
+}
@@ -2,23 +2,31 @@
 //!
 //! This module defines Bevy resources for managing game state,
 //! including score tracking, combo system, game over detection,
-//! next fruit preview, and user settings.
+//! next fruit preview, lifetime stats, and user settings.
 
 use bevy::prelude::*;
 
 pub mod combo;
 pub mod game;
+pub mod game_mode;
 pub mod game_over;
+pub mod lifetime_stats;
+pub mod score_strategy;
+pub mod session_stats;
 pub mod settings;
 pub mod spawn;
 pub mod sprites;
 
 pub use combo::ComboTimer;
 pub use game::GameState;
+pub use game_mode::GameMode;
 pub use game_over::GameOverTimer;
+pub use lifetime_stats::StatsResource;
+pub use score_strategy::ScoreStrategy;
+pub use session_stats::SessionStats;
 pub use settings::{Language, SettingsResource};
-pub use spawn::NextFruitType;
-pub use sprites::FruitSprites;
+pub use spawn::{FruitQueue, NextFruitType, SpawnRng};
+pub use sprites::{FruitFrame, FruitSprites};
 
 /// Shared white circle texture used as placeholder for fruit sprites.
 ///
@@ -43,6 +51,18 @@ mod tests {
         assert_eq!(state.score, 0);
         assert_eq!(state.highscore, 0);
         assert_eq!(state.elapsed_time, 0.0);
+        assert_eq!(state.drops, 0);
+        assert_eq!(state.points_per_drop(), None);
+    }
+
+    #[test]
+    fn test_game_state_points_per_drop() {
+        let state = GameState {
+            score: 450,
+            drops: 30,
+            ..default()
+        };
+        assert_eq!(state.points_per_drop(), Some(15.0));
     }
 
     #[test]
@@ -89,12 +109,15 @@ mod tests {
 
         timer.current_combo = 5;
         timer.time_since_last_merge = 1.0;
-        timer.check_and_reset();
+        assert_eq!(timer.check_and_reset(), None);
         assert_eq!(timer.current_combo, 5); // Still in window
 
         timer.time_since_last_merge = DEFAULT_COMBO_WINDOW + 1.0;
-        timer.check_and_reset();
+        assert_eq!(timer.check_and_reset(), Some(5));
         assert_eq!(timer.current_combo, 1); // Window expired, reset
+
+        // Already reset: nothing expires again
+        assert_eq!(timer.check_and_reset(), None);
     }
 
     #[test]
@@ -186,6 +209,56 @@ mod tests {
         assert!(spawnable.contains(&next.get()));
     }
 
+    #[test]
+    fn test_session_stats_record_merge() {
+        let mut stats = SessionStats::new(99, 1_000);
+        stats.drops = 3;
+        stats.record_merge(1.5, 10);
+        stats.record_merge(4.0, 30);
+
+        let summary = stats.to_summary(30, 12.0);
+        assert_eq!(summary.seed, 99);
+        assert_eq!(summary.started_at, 1_000);
+        assert_eq!(summary.drops, 3);
+        assert_eq!(summary.merges, 2);
+        assert_eq!(summary.score_timeline.len(), 2);
+        assert_eq!(summary.score_timeline[1].score, 30);
+        assert_eq!(summary.final_score, 30);
+        assert_eq!(
+            summary.build,
+            crate::build_info::BuildInfo::CURRENT.to_string()
+        );
+    }
+
+    #[test]
+    fn test_spawn_rng_same_seed_same_sequence() {
+        let mut a = SpawnRng::from_seed(42);
+        let mut b = SpawnRng::from_seed(42);
+        let seq_a: Vec<usize> = (0..32).map(|_| a.next_index(5)).collect();
+        let seq_b: Vec<usize> = (0..32).map(|_| b.next_index(5)).collect();
+        assert_eq!(seq_a, seq_b);
+    }
+
+    #[test]
+    fn test_spawn_rng_reseed_restarts_sequence() {
+        let mut rng = SpawnRng::from_seed(7);
+        let first: Vec<usize> = (0..8).map(|_| rng.next_index(5)).collect();
+        rng.reseed(7);
+        let again: Vec<usize> = (0..8).map(|_| rng.next_index(5)).collect();
+        assert_eq!(rng.seed(), 7);
+        assert_eq!(first, again);
+    }
+
+    #[test]
+    fn test_next_fruit_type_randomize_with_is_spawnable() {
+        let mut rng = SpawnRng::from_seed(1);
+        let mut next = NextFruitType::default();
+        for _ in 0..20 {
+            next.randomize_with(&mut rng, 3);
+            assert!(FruitType::spawnable_fruits()[..3].contains(&next.get()));
+        }
+    }
+
     #[test]
     fn test_next_fruit_type_randomize_count_limits_range() {
         // With count=1, only Cherry should ever be returned
@@ -210,4 +283,133 @@ mod tests {
             assert!(spawnable.contains(&next.get()));
         }
     }
+
+    #[test]
+    fn test_fruit_queue_keeps_seeded_sequence() {
+        // Without a queue: one draw per held fruit
+        let mut rng = SpawnRng::from_seed(11);
+        let mut next = NextFruitType::default();
+        let direct: Vec<FruitType> = (0..8)
+            .map(|_| {
+                next.randomize_with(&mut rng, 5);
+                next.get()
+            })
+            .collect();
+
+        // With a queue: the same fruits come out of `next` in the same order
+        let mut rng = SpawnRng::from_seed(11);
+        let mut next = NextFruitType::default();
+        let mut queue = FruitQueue::default();
+        next.randomize_with(&mut rng, 5);
+        queue.refill_with(&mut rng, 5);
+        let mut queued = vec![next.get()];
+        for _ in 1..8 {
+            queue.advance_with(&mut next, &mut rng, 5);
+            queued.push(next.get());
+        }
+        assert_eq!(direct, queued);
+    }
+
+    #[test]
+    fn test_fruit_queue_shows_upcoming_fruits() {
+        let mut rng = SpawnRng::from_seed(3);
+        let mut next = NextFruitType::default();
+        let mut queue = FruitQueue::default();
+        queue.refill_with(&mut rng, 5);
+        let upcoming = queue.with_next(&next);
+        assert_eq!(upcoming.len(), FruitQueue::LEN + 1);
+        assert_eq!(upcoming[0], next.get());
+
+        queue.advance_with(&mut next, &mut rng, 5);
+        assert_eq!(next.get(), upcoming[1]);
+        assert_eq!(queue.iter().next(), Some(upcoming[2]));
+        assert_eq!(queue.iter().count(), FruitQueue::LEN);
+    }
+
+    #[test]
+    fn test_fruit_queue_advance_fills_empty_queue() {
+        let mut next = NextFruitType::default();
+        let mut queue = FruitQueue::default();
+        queue.advance(&mut next, 1);
+        assert_eq!(next.get(), FruitType::Cherry);
+        assert_eq!(queue.iter().count(), FruitQueue::LEN);
+    }
+
+    #[test]
+    fn test_spawn_probabilities() {
+        let probs = NextFruitType::spawn_probabilities(3);
+        assert_eq!(probs.len(), FruitType::spawnable_fruits().len());
+        assert_eq!(probs[0], (FruitType::Cherry, 1.0 / 3.0));
+        assert_eq!(probs[2].1, 1.0 / 3.0);
+        assert_eq!(probs[3].1, 0.0);
+
+        let total: f32 = NextFruitType::spawn_probabilities(999)
+            .iter()
+            .map(|(_, p)| p)
+            .sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        assert_eq!(NextFruitType::spawn_probabilities(0)[0].1, 1.0);
+    }
+
+    #[test]
+    fn test_biased_probabilities_favour_small_fruits() {
+        let probs = NextFruitType::biased_probabilities(5, 0.5);
+        let total: f32 = probs.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        assert!(probs.windows(2).all(|w| w[0].1 > w[1].1));
+        // The largest spawnable fruit keeps half its uniform chance
+        assert!((probs[4].1 - 0.1).abs() < 1e-5);
+        // Nothing outside the spawnable count is ever drawn
+        assert_eq!(NextFruitType::biased_probabilities(3, 0.5)[3].1, 0.0);
+        assert_eq!(NextFruitType::biased_probabilities(1, 0.5)[0].1, 1.0);
+    }
+
+    #[test]
+    fn test_fruit_queue_unbiased_advance_keeps_seeded_sequence() {
+        let run = |biased: bool| {
+            let mut rng = SpawnRng::from_seed(5);
+            let mut next = NextFruitType::default();
+            let mut queue = FruitQueue::default();
+            queue.refill_with(&mut rng, 5);
+            (0..8)
+                .map(|_| {
+                    if biased {
+                        queue.advance_biased(&mut next, &mut rng, 5, 0.0);
+                    } else {
+                        queue.advance_with(&mut next, &mut rng, 5);
+                    }
+                    next.get()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(false), run(true));
+    }
+
+    #[test]
+    fn test_randomize_biased_leans_small() {
+        let mut rng = SpawnRng::from_seed(9);
+        let mut next = NextFruitType::default();
+        let spawnable = FruitType::spawnable_fruits();
+        let mut counts = [0u32; 5];
+        for _ in 0..2000 {
+            next.randomize_biased(&mut rng, 5, 0.5);
+            let index = spawnable.iter().position(|&f| f == next.get()).unwrap();
+            counts[index] += 1;
+        }
+        assert!(counts[0] > counts[4] * 2);
+    }
+
+    #[test]
+    fn test_game_mode_parse_is_case_insensitive() {
+        assert_eq!("classic".parse(), Ok(GameMode::Classic));
+        assert_eq!("No-Combo".parse(), Ok(GameMode::NoCombo));
+        assert!("hard".parse::<GameMode>().is_err());
+    }
+
+    #[test]
+    fn test_game_mode_names_round_trip() {
+        for mode in GameMode::ALL {
+            assert_eq!(mode.name().parse(), Ok(mode));
+        }
+    }
 }
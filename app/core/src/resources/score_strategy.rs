@@ -0,0 +1,190 @@
+//! Pluggable scoring rules
+//!
+//! [`ScoreStrategy`] is an ordered list of [`ScoreRule`]s that
+//! `update_score_on_merge` folds over to turn a merge into points.  Swapping
+//! the resource changes how a game mode scores without touching the system:
+//!
+//! ```
+//! # use suika_game_core::resources::score_strategy::{FlatMultiplier, ScoreStrategy};
+//! // Standard scoring: base points × combo multiplier × chain multiplier
+//! let standard = ScoreStrategy::default();
+//!
+//! // Double-points event on top of the standard rules
+//! let weekend = ScoreStrategy::default().with_rule(FlatMultiplier(2.0));
+//!
+//! // Purist mode: base points only, combos are ignored
+//! let purist = ScoreStrategy::without_combo();
+//! # let _ = (standard, weekend, purist);
+//! ```
+
+use bevy::prelude::*;
+
+use crate::config::GameRulesConfig;
+use crate::fruit::FruitType;
+use crate::systems::score::{chain_multiplier, combo_multiplier};
+
+/// Everything a [`ScoreRule`] may look at when scoring a single merge.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreContext<'a> {
+    /// Type of the fruits that merged
+    pub fruit_type: FruitType,
+    /// Base points of `fruit_type` from `fruits.ron`
+    pub base_points: u32,
+    /// Combo count including this merge
+    pub combo: u32,
+    /// Chain depth of this merge (0 when it was not set off by a merge)
+    pub chain: u32,
+    /// Game rules config, when loaded
+    pub rules: Option<&'a GameRulesConfig>,
+}
+
+/// One step of the scoring pipeline.
+///
+/// Receives the points produced by the previous rules (`0.0` for the first
+/// rule) and returns the new running total.
+pub trait ScoreRule: Send + Sync + 'static {
+    /// Applies this rule to the running total.
+    fn apply(&self, ctx: &ScoreContext, points: f32) -> f32;
+}
+
+/// Adds the merged fruit's base points.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BasePoints;
+
+impl ScoreRule for BasePoints {
+    fn apply(&self, ctx: &ScoreContext, points: f32) -> f32 {
+        points + ctx.base_points as f32
+    }
+}
+
+/// Multiplies by the combo bonus from `game_rules.ron` `combo_bonuses`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComboMultiplier;
+
+impl ScoreRule for ComboMultiplier {
+    fn apply(&self, ctx: &ScoreContext, points: f32) -> f32 {
+        points * combo_multiplier(ctx.combo, ctx.rules)
+    }
+}
+
+/// Multiplies by the chain bonus from `game_rules.ron` `chain_bonuses`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChainMultiplier;
+
+impl ScoreRule for ChainMultiplier {
+    fn apply(&self, ctx: &ScoreContext, points: f32) -> f32 {
+        points * chain_multiplier(ctx.chain, ctx.rules)
+    }
+}
+
+/// Multiplies by a fixed factor (double-points events, …).
+#[derive(Debug, Clone, Copy)]
+pub struct FlatMultiplier(pub f32);
+
+impl ScoreRule for FlatMultiplier {
+    fn apply(&self, _ctx: &ScoreContext, points: f32) -> f32 {
+        points * self.0
+    }
+}
+
+/// Ordered set of [`ScoreRule`]s used to score every merge.
+///
+/// The default strategy is [`BasePoints`] followed by [`ComboMultiplier`]
+/// and [`ChainMultiplier`].
+#[derive(Resource)]
+pub struct ScoreStrategy {
+    rules: Vec<Box<dyn ScoreRule>>,
+}
+
+impl Default for ScoreStrategy {
+    fn default() -> Self {
+        Self::empty()
+            .with_rule(BasePoints)
+            .with_rule(ComboMultiplier)
+            .with_rule(ChainMultiplier)
+    }
+}
+
+impl ScoreStrategy {
+    /// A strategy with no rules; every merge scores `0`.
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Base points only — combos and chains do not change the score.
+    pub fn without_combo() -> Self {
+        Self::empty().with_rule(BasePoints)
+    }
+
+    /// Appends `rule` to the end of the pipeline.
+    pub fn with_rule(mut self, rule: impl ScoreRule) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Scores a merge by running every rule in order, rounded to whole points.
+    pub fn score(&self, ctx: &ScoreContext) -> u32 {
+        let points = self
+            .rules
+            .iter()
+            .fold(0.0, |points, rule| rule.apply(ctx, points));
+        points.max(0.0).round() as u32
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx(base_points: u32, combo: u32) -> ScoreContext<'static> {
+        ScoreContext {
+            fruit_type: FruitType::Cherry,
+            base_points,
+            combo,
+            chain: 0,
+            rules: None,
+        }
+    }
+
+    #[test]
+    fn test_default_strategy_applies_combo() {
+        let strategy = ScoreStrategy::default();
+        assert_eq!(strategy.score(&ctx(10, 1)), 10);
+        // 10 × 1.1 (fallback 2-combo bonus)
+        assert_eq!(strategy.score(&ctx(10, 2)), 11);
+    }
+
+    #[test]
+    fn test_default_strategy_applies_chain() {
+        let strategy = ScoreStrategy::default();
+        let chained = ScoreContext {
+            chain: 1,
+            ..ctx(10, 1)
+        };
+        // 10 × 1.5 (fallback depth-1 chain bonus)
+        assert_eq!(strategy.score(&chained), 15);
+        assert_eq!(ScoreStrategy::without_combo().score(&chained), 10);
+    }
+
+    #[test]
+    fn test_without_combo_ignores_combo() {
+        let strategy = ScoreStrategy::without_combo();
+        assert_eq!(strategy.score(&ctx(10, 5)), 10);
+    }
+
+    #[test]
+    fn test_flat_multiplier_stacks_after_combo() {
+        let strategy = ScoreStrategy::default().with_rule(FlatMultiplier(2.0));
+        // 100 × 1.5 (5-combo) × 2.0
+        assert_eq!(strategy.score(&ctx(100, 5)), 300);
+    }
+
+    #[test]
+    fn test_empty_strategy_scores_zero() {
+        assert_eq!(ScoreStrategy::empty().score(&ctx(40, 3)), 0);
+    }
+}
@@ -0,0 +1,98 @@
+//! Per-game statistics collected for the session summary log
+
+use bevy::prelude::*;
+
+use crate::build_info::BuildInfo;
+use crate::fruit::FruitType;
+use crate::persistence::{BestRun, DropSample, ScoreSample, SessionSummary};
+use crate::seed_code::SeedCode;
+
+/// Running statistics for the current game.
+///
+/// Reset at the start of every game and turned into a [`SessionSummary`]
+/// on game over when `SettingsResource::export_session_logs` is enabled.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SessionStats {
+    /// Unix timestamp (seconds) at which the game started
+    pub started_at: u64,
+    /// Seed of the fruit spawn sequence for this game
+    pub seed: u64,
+    /// Shareable code replaying this game's fruit sequence
+    pub seed_code: Option<SeedCode>,
+    /// Fruits dropped so far
+    pub drops: u32,
+    /// Merges so far
+    pub merges: u32,
+    /// Score after every merge
+    pub score_timeline: Vec<ScoreSample>,
+    /// Every drop, for the ghost run
+    pub drop_timeline: Vec<DropSample>,
+    /// Largest fruit created or restored this game
+    pub largest_fruit: Option<FruitType>,
+    /// Highest combo count reached this game
+    pub best_combo: u32,
+}
+
+impl SessionStats {
+    /// Starts a fresh record for a game using `seed`, started at `started_at`.
+    pub fn new(seed: u64, started_at: u64) -> Self {
+        Self {
+            started_at,
+            seed,
+            ..default()
+        }
+    }
+
+    /// Records a merge that brought the total score to `score` at `time`.
+    pub fn record_merge(&mut self, time: f32, score: u32) {
+        self.merges += 1;
+        self.score_timeline.push(ScoreSample { time, score });
+    }
+
+    /// Records a drop of `fruit_type` from `position` at `time`.
+    pub fn record_drop(&mut self, time: f32, position: Vec2, fruit_type: FruitType) {
+        self.drops += 1;
+        self.drop_timeline.push(DropSample {
+            time,
+            x: position.x,
+            y: position.y,
+            fruit_type,
+        });
+    }
+
+    /// Records that `fruit_type` has been reached this game.
+    ///
+    /// Returns `true` when it is larger than every fruit seen before.
+    pub fn record_fruit(&mut self, fruit_type: FruitType) -> bool {
+        let is_record = self
+            .largest_fruit
+            .is_none_or(|largest| fruit_type.stage_index() > largest.stage_index());
+        if is_record {
+            self.largest_fruit = Some(fruit_type);
+        }
+        is_record
+    }
+
+    /// Builds the summary to be written on game over.
+    pub fn to_summary(&self, final_score: u32, elapsed_time: f32) -> SessionSummary {
+        SessionSummary {
+            started_at: self.started_at,
+            seed: self.seed,
+            final_score,
+            elapsed_time,
+            drops: self.drops,
+            merges: self.merges,
+            score_timeline: self.score_timeline.clone(),
+            build: BuildInfo::CURRENT.to_string(),
+        }
+    }
+
+    /// The game as a [`BestRun`] for the ghost run, once it set a highscore.
+    pub fn to_best_run(&self, final_score: u32) -> BestRun {
+        BestRun {
+            final_score,
+            drops: self.drop_timeline.clone(),
+            score_timeline: self.score_timeline.clone(),
+        }
+    }
+}
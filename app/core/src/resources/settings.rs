@@ -15,12 +15,90 @@ pub enum Language {
     English,
 }
 
+impl Language {
+    /// Whether the language is written right to left.  The UI mirrors its
+    /// layout for these; none of the current languages is.
+    pub fn is_rtl(self) -> bool {
+        match self {
+            Language::Japanese | Language::English => false,
+        }
+    }
+}
+
+/// Frame rate caps offered by the settings screen, lowest first.  `0` means
+/// uncapped and comes last, above the highest number.
+pub const FPS_CAP_STEPS: [u16; 6] = [30, 60, 120, 144, 240, 0];
+
+/// The [`FPS_CAP_STEPS`] entry one step above (`up`) or below `current`,
+/// clamped to the ends of the list.  A value not in the list (e.g. edited by
+/// hand in `settings.json`) moves to the nearest step in that direction.
+pub fn step_fps_cap(current: u16, up: bool) -> u16 {
+    // Rank so that uncapped (0) sorts above every number
+    let rank = |cap: u16| if cap == 0 { u32::MAX } else { u32::from(cap) };
+    let found = if up {
+        FPS_CAP_STEPS.iter().find(|&&cap| rank(cap) > rank(current))
+    } else {
+        FPS_CAP_STEPS
+            .iter()
+            .rev()
+            .find(|&&cap| rank(cap) < rank(current))
+    };
+    found.copied().unwrap_or(current)
+}
+
+/// Step of the SFX latency offset on the calibration screen, in milliseconds.
+pub const SFX_LATENCY_STEP_MS: u16 = 10;
+
+/// Largest SFX latency offset the calibration screen allows, in milliseconds.
+pub const SFX_LATENCY_MAX_MS: u16 = 300;
+
+/// `current` moved one [`SFX_LATENCY_STEP_MS`] up (`up`) or down, kept
+/// within `0..=`[`SFX_LATENCY_MAX_MS`].
+pub fn step_sfx_latency(current: u16, up: bool) -> u16 {
+    if up {
+        current
+            .saturating_add(SFX_LATENCY_STEP_MS)
+            .min(SFX_LATENCY_MAX_MS)
+    } else {
+        current
+            .min(SFX_LATENCY_MAX_MS)
+            .saturating_sub(SFX_LATENCY_STEP_MS)
+    }
+}
+
+/// Whether the build targets a touch-first platform, where
+/// [`SettingsResource::touch_controls`] starts switched on.
+pub const TOUCH_PLATFORM: bool = cfg!(any(target_os = "android", target_os = "ios"));
+
+/// Digits in [`SettingsResource::settings_pin`].
+pub const SETTINGS_PIN_LEN: usize = 4;
+
+/// Auto-drop delays offered by the settings screen, in seconds.  `0` turns
+/// auto-drop off and comes first.
+pub const AUTO_DROP_STEPS: [u8; 5] = [0, 3, 5, 8, 12];
+
+/// The [`AUTO_DROP_STEPS`] entry one step above (`up`) or below `current`,
+/// clamped to the ends of the list.  A value not in the list moves to the
+/// nearest step in that direction.
+pub fn step_auto_drop(current: u8, up: bool) -> u8 {
+    let found = if up {
+        AUTO_DROP_STEPS.iter().find(|&&secs| secs > current)
+    } else {
+        AUTO_DROP_STEPS.iter().rev().find(|&&secs| secs < current)
+    };
+    found.copied().unwrap_or(current)
+}
+
 /// User-configurable settings, persisted to `save/settings.json`.
 ///
 /// All fields have sensible defaults so new installations work without a save
 /// file.  Use [`crate::persistence::load_settings`] to populate this resource
 /// from disk at startup.
+///
+/// `#[serde(default)]` lets save files written by older versions load
+/// cleanly: any field missing from the JSON takes its default value.
 #[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SettingsResource {
     /// BGM volume (0 = muted, 10 = full).  Default: 8 (80 %).
     pub bgm_volume: u8,
@@ -28,8 +106,72 @@ pub struct SettingsResource {
     pub sfx_volume: u8,
     /// Whether particle / flash / shake visual effects are active.
     pub effects_enabled: bool,
+    /// Water droplets on merges and landings, while `effects_enabled`.
+    pub droplet_effects: bool,
+    /// Merge and screen flashes, while `effects_enabled`.
+    pub flash_effects: bool,
+    /// Camera shake and zoom punch, while `effects_enabled`.
+    pub shake_effects: bool,
     /// UI and text language.
     pub language: Language,
+    /// Set once the player has finished the first-run onboarding hints, so
+    /// they are never shown again.
+    pub onboarding_completed: bool,
+    /// Write a JSON summary of every finished game to `save/sessions/`.
+    /// Opt-in; off by default.
+    pub export_session_logs: bool,
+    /// Assist: while the held fruit hovers above a fruit of the same kind,
+    /// show a translucent ghost of the fruit they would merge into.
+    pub merge_ghost: bool,
+    /// Assist: show a faint silhouette where the held fruit would first
+    /// touch down on the floor or the pile.
+    pub landing_preview: bool,
+    /// Vibrate gamepads (and phones on the web) on drops, merges and game
+    /// over.
+    pub haptics_enabled: bool,
+    /// Wait for the display's vertical sync before presenting a frame.
+    /// Turning it off lowers input latency on high-refresh monitors at the
+    /// cost of tearing.
+    pub vsync: bool,
+    /// Maximum frames per second; `0` = uncapped.  Useful with vsync off, or
+    /// to spare a weak GPU.  The settings screen steps through
+    /// [`FPS_CAP_STEPS`].
+    pub fps_cap: u16,
+    /// Assist: the held fruit sweeps left and right on its own, so a single
+    /// button (any drop input) is enough to play.
+    pub sweep_assist: bool,
+    /// Assist: seconds a fruit may be held before it drops on its own;
+    /// `0` = off.  The settings screen steps through [`AUTO_DROP_STEPS`].
+    pub auto_drop_secs: u8,
+    /// Read important state changes and focused menu buttons aloud through
+    /// text-to-speech.
+    pub announcements: bool,
+    /// Show scores with full-width digits (`１，２３４`) while the language
+    /// is Japanese.
+    pub full_width_digits: bool,
+    /// Race the highscore game: its drops reappear as fading ghosts and
+    /// the HUD shows the score difference (see
+    /// [`crate::systems::ghost_run`]).
+    pub ghost_run: bool,
+    /// Show bars bouncing with the music next to the score (cosmetic).
+    pub audio_visualizer: bool,
+    /// Show the on-screen control strip (pause, nudge left / right, drop)
+    /// at the bottom of the window.  On by default on [`TOUCH_PLATFORM`]s.
+    pub touch_controls: bool,
+    /// How late sound effects reach the speakers, in milliseconds (e.g. over
+    /// Bluetooth).  Gameplay sounds start this far into their clip so they
+    /// line up with the screen again.  Measured on the calibration screen.
+    pub sfx_latency_ms: u16,
+    /// Names of the user mods switched off on the settings screen.  Mods
+    /// not listed here are enabled (see [`crate::mods`]).
+    pub disabled_mods: Vec<String>,
+    /// Streamer-safe / parental mode: no online features, seed codes and
+    /// the profile name masked on screen (see
+    /// [`crate::systems::safe_mode`]).
+    pub streamer_safe: bool,
+    /// [`SETTINGS_PIN_LEN`] digits asked for before the settings screen
+    /// opens while `streamer_safe` is on; empty = no PIN.
+    pub settings_pin: String,
 }
 
 impl Default for SettingsResource {
@@ -38,7 +180,49 @@ impl Default for SettingsResource {
             bgm_volume: 8,
             sfx_volume: 8,
             effects_enabled: true,
+            droplet_effects: true,
+            flash_effects: true,
+            shake_effects: true,
             language: Language::default(),
+            onboarding_completed: false,
+            export_session_logs: false,
+            merge_ghost: false,
+            landing_preview: false,
+            haptics_enabled: true,
+            vsync: true,
+            fps_cap: 0,
+            sweep_assist: false,
+            auto_drop_secs: 0,
+            announcements: false,
+            full_width_digits: false,
+            ghost_run: false,
+            audio_visualizer: false,
+            touch_controls: TOUCH_PLATFORM,
+            sfx_latency_ms: 0,
+            disabled_mods: Vec::new(),
+            streamer_safe: false,
+            settings_pin: String::new(),
+        }
+    }
+}
+
+impl SettingsResource {
+    /// Whether the mod called `name` should load.
+    pub fn mod_enabled(&self, name: &str) -> bool {
+        !self.disabled_mods.iter().any(|disabled| disabled == name)
+    }
+
+    /// Whether opening the settings screen needs the PIN.
+    pub fn settings_locked(&self) -> bool {
+        self.streamer_safe && !self.settings_pin.is_empty()
+    }
+
+    /// Switches the mod called `name` on or off.
+    pub fn toggle_mod(&mut self, name: &str) {
+        if self.mod_enabled(name) {
+            self.disabled_mods.push(name.to_string());
+        } else {
+            self.disabled_mods.retain(|disabled| disabled != name);
         }
     }
 }
@@ -57,7 +241,97 @@ mod tests {
         assert_eq!(s.bgm_volume, 8);
         assert_eq!(s.sfx_volume, 8);
         assert!(s.effects_enabled);
+        assert!(s.droplet_effects);
+        assert!(s.flash_effects);
+        assert!(s.shake_effects);
         assert_eq!(s.language, Language::Japanese);
+        assert!(!s.onboarding_completed);
+        assert!(!s.export_session_logs);
+        assert!(!s.merge_ghost);
+        assert!(!s.landing_preview);
+        assert!(s.haptics_enabled);
+        assert!(s.vsync);
+        assert_eq!(s.fps_cap, 0);
+        assert!(!s.sweep_assist);
+        assert_eq!(s.auto_drop_secs, 0);
+        assert!(!s.announcements);
+        assert!(!s.full_width_digits);
+        assert!(!s.ghost_run);
+        assert!(!s.audio_visualizer);
+        assert_eq!(s.touch_controls, TOUCH_PLATFORM);
+        assert_eq!(s.sfx_latency_ms, 0);
+        assert!(s.disabled_mods.is_empty());
+        assert!(!s.streamer_safe);
+        assert!(s.settings_pin.is_empty());
+    }
+
+    #[test]
+    fn test_settings_locked_needs_safe_mode_and_pin() {
+        let mut s = SettingsResource::default();
+        s.settings_pin = "1234".to_string();
+        assert!(!s.settings_locked());
+        s.streamer_safe = true;
+        assert!(s.settings_locked());
+        s.settings_pin.clear();
+        assert!(!s.settings_locked());
+    }
+
+    #[test]
+    fn test_toggle_mod() {
+        let mut s = SettingsResource::default();
+        assert!(s.mod_enabled("pastel"));
+        s.toggle_mod("pastel");
+        assert!(!s.mod_enabled("pastel"));
+        assert!(s.mod_enabled("retro"));
+        s.toggle_mod("pastel");
+        assert!(s.mod_enabled("pastel"));
+        assert!(s.disabled_mods.is_empty());
+    }
+
+    #[test]
+    fn test_step_auto_drop() {
+        assert_eq!(step_auto_drop(0, true), 3);
+        assert_eq!(step_auto_drop(3, false), 0);
+        // Clamped at both ends
+        assert_eq!(step_auto_drop(0, false), 0);
+        assert_eq!(step_auto_drop(12, true), 12);
+        // Hand-edited values snap to the next step
+        assert_eq!(step_auto_drop(4, true), 5);
+        assert_eq!(step_auto_drop(4, false), 3);
+    }
+
+    #[test]
+    fn test_step_fps_cap() {
+        assert_eq!(step_fps_cap(60, true), 120);
+        assert_eq!(step_fps_cap(60, false), 30);
+        assert_eq!(step_fps_cap(240, true), 0);
+        assert_eq!(step_fps_cap(0, false), 240);
+        // Clamped at both ends
+        assert_eq!(step_fps_cap(0, true), 0);
+        assert_eq!(step_fps_cap(30, false), 30);
+        // Hand-edited values snap to the next step
+        assert_eq!(step_fps_cap(75, true), 120);
+        assert_eq!(step_fps_cap(75, false), 60);
+    }
+
+    #[test]
+    fn test_step_sfx_latency() {
+        assert_eq!(step_sfx_latency(0, true), 10);
+        assert_eq!(step_sfx_latency(40, false), 30);
+        // Clamped at both ends
+        assert_eq!(step_sfx_latency(0, false), 0);
+        assert_eq!(
+            step_sfx_latency(SFX_LATENCY_MAX_MS, true),
+            SFX_LATENCY_MAX_MS
+        );
+        // Hand-edited values past the maximum come back within range
+        assert_eq!(step_sfx_latency(1000, false), SFX_LATENCY_MAX_MS - 10);
+    }
+
+    #[test]
+    fn test_current_languages_are_left_to_right() {
+        assert!(!Language::Japanese.is_rtl());
+        assert!(!Language::English.is_rtl());
     }
 
     #[test]
@@ -72,12 +346,30 @@ mod tests {
             sfx_volume: 3,
             effects_enabled: false,
             language: Language::English,
+            onboarding_completed: true,
+            export_session_logs: true,
+            vsync: false,
+            fps_cap: 144,
+            ..SettingsResource::default()
         };
         let json = serde_json::to_string(&original).unwrap();
         let deserialized: SettingsResource = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.bgm_volume, 5);
         assert_eq!(deserialized.sfx_volume, 3);
         assert!(!deserialized.effects_enabled);
+        assert!(deserialized.onboarding_completed);
+        assert!(deserialized.export_session_logs);
+        assert!(!deserialized.vsync);
+        assert_eq!(deserialized.fps_cap, 144);
         assert_eq!(deserialized.language, Language::English);
     }
+
+    #[test]
+    fn test_settings_resource_missing_fields_use_defaults() {
+        let json = r#"{"bgm_volume":2,"sfx_volume":4,"effects_enabled":true,"language":"English"}"#;
+        let s: SettingsResource = serde_json::from_str(json).unwrap();
+        assert_eq!(s.bgm_volume, 2);
+        assert_eq!(s.language, Language::English);
+        assert!(!s.onboarding_completed);
+    }
 }
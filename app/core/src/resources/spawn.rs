@@ -1,6 +1,10 @@
-//! Next-fruit-type resource
+//! Next-fruit-type, fruit queue and spawn RNG resources
+
+use std::collections::VecDeque;
 
 use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 
 use crate::fruit::FruitType;
 
@@ -52,4 +56,227 @@ impl NextFruitType {
         let n = spawnable_count.clamp(1, spawnable.len());
         self.0 = spawnable[rand::rng().random_range(0..n)];
     }
+
+    /// Chance of each fruit being picked by [`randomize`](Self::randomize) /
+    /// [`randomize_with`](Self::randomize_with) for `spawnable_count`.
+    ///
+    /// Lists every spawnable fruit in order; fruits beyond the (clamped)
+    /// count get `0.0`.  The eligible ones share the probability equally.
+    pub fn spawn_probabilities(spawnable_count: usize) -> Vec<(FruitType, f32)> {
+        Self::biased_probabilities(spawnable_count, 0.0)
+    }
+
+    /// Same as [`spawn_probabilities`](Self::spawn_probabilities) for a draw
+    /// by [`randomize_biased`](Self::randomize_biased) with `bias`.
+    pub fn biased_probabilities(spawnable_count: usize, bias: f32) -> Vec<(FruitType, f32)> {
+        let spawnable = FruitType::spawnable_fruits();
+        let weights = spawn_weights(spawnable_count, bias);
+        let total: f32 = weights.iter().sum();
+        spawnable
+            .iter()
+            .enumerate()
+            .map(|(i, &fruit)| (fruit, weights.get(i).map_or(0.0, |w| w / total)))
+            .collect()
+    }
+
+    /// Same as [`randomize`](Self::randomize) but draws from a seeded
+    /// [`SpawnRng`], so the fruit sequence is reproducible from its seed.
+    pub fn randomize_with(&mut self, rng: &mut SpawnRng, spawnable_count: usize) {
+        let spawnable = FruitType::spawnable_fruits();
+        let n = spawnable_count.clamp(1, spawnable.len());
+        self.0 = spawnable[rng.next_index(n)];
+    }
+
+    /// Same as [`randomize_with`](Self::randomize_with) but leans toward
+    /// the smaller spawnable fruits by `bias` (see [`spawn_weights`]).
+    ///
+    /// A `bias` of `0.0` or less is an ordinary uniform draw and consumes
+    /// the generator exactly like [`randomize_with`](Self::randomize_with),
+    /// so seeded sequences are unchanged while no bias is applied.
+    pub fn randomize_biased(&mut self, rng: &mut SpawnRng, spawnable_count: usize, bias: f32) {
+        if bias <= 0.0 {
+            self.randomize_with(rng, spawnable_count);
+            return;
+        }
+        let spawnable = FruitType::spawnable_fruits();
+        self.0 = spawnable[rng.next_weighted(&spawn_weights(spawnable_count, bias))];
+    }
+}
+
+/// Relative draw weights of the first `spawnable_count` spawnable fruits.
+///
+/// The weights tilt linearly from `1 + bias` for the smallest fruit to
+/// `1 - bias` for the largest, keeping their mean at `1.0`.  `bias` is
+/// clamped to `0.0..=1.0`; with a single eligible fruit it has no effect.
+pub fn spawn_weights(spawnable_count: usize, bias: f32) -> Vec<f32> {
+    let n = spawnable_count.clamp(1, FruitType::spawnable_fruits().len());
+    let bias = bias.clamp(0.0, 1.0);
+    if n == 1 {
+        return vec![1.0];
+    }
+    (0..n)
+        .map(|i| 1.0 + bias * (1.0 - 2.0 * i as f32 / (n - 1) as f32))
+        .collect()
+}
+
+/// Fruits queued after [`NextFruitType`].
+///
+/// The HUD shows [`NextFruitType`] followed by these, so the player can see
+/// [`FruitQueue::LEN`]` + 1` fruits ahead.  Every draw still comes from the
+/// same [`SpawnRng`] in the same order, so a seed produces the same fruit
+/// sequence with or without the queue.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct FruitQueue {
+    upcoming: VecDeque<FruitType>,
+}
+
+impl FruitQueue {
+    /// Number of fruits queued after the next one.
+    pub const LEN: usize = 2;
+
+    /// The queued fruits, soonest first.
+    pub fn iter(&self) -> impl Iterator<Item = FruitType> + '_ {
+        self.upcoming.iter().copied()
+    }
+
+    /// `next` followed by the queued fruits, soonest first.
+    pub fn with_next(&self, next: &NextFruitType) -> Vec<FruitType> {
+        std::iter::once(next.get()).chain(self.iter()).collect()
+    }
+
+    /// Replaces the queued fruits with `fruits`, soonest first, e.g. when a
+    /// saved board is restored.
+    ///
+    /// Keeps up to [`LEN`](Self::LEN)` + 1` fruits so a queue captured while
+    /// a fruit was held survives the advance that hands it back; missing
+    /// fruits are drawn on the next advance.
+    pub fn restore(&mut self, fruits: impl IntoIterator<Item = FruitType>) {
+        self.upcoming = fruits.into_iter().take(Self::LEN + 1).collect();
+    }
+
+    /// Empties the queue and draws [`LEN`](Self::LEN) fresh fruits from
+    /// `rng`.  Call right after drawing the first [`NextFruitType`] of a game.
+    pub fn refill_with(&mut self, rng: &mut SpawnRng, spawnable_count: usize) {
+        self.upcoming.clear();
+        self.top_up(|| draw(rng, spawnable_count, 0.0));
+    }
+
+    /// Moves the queue forward: the front fruit becomes `next` and a fresh
+    /// one is drawn from `rng` at the back.
+    pub fn advance_with(
+        &mut self,
+        next: &mut NextFruitType,
+        rng: &mut SpawnRng,
+        spawnable_count: usize,
+    ) {
+        self.advance_biased(next, rng, spawnable_count, 0.0);
+    }
+
+    /// Same as [`advance_with`](Self::advance_with) but the fresh fruit is
+    /// drawn with [`NextFruitType::randomize_biased`].
+    ///
+    /// Only the back of the queue is affected, so a change of `bias` shows
+    /// up [`LEN`](Self::LEN) fruits later.
+    pub fn advance_biased(
+        &mut self,
+        next: &mut NextFruitType,
+        rng: &mut SpawnRng,
+        spawnable_count: usize,
+        bias: f32,
+    ) {
+        let mut draw = || draw(rng, spawnable_count, bias);
+        next.set(self.upcoming.pop_front().unwrap_or_else(&mut draw));
+        self.top_up(draw);
+    }
+
+    /// Same as [`advance_with`](Self::advance_with) but draws from the
+    /// thread RNG.
+    pub fn advance(&mut self, next: &mut NextFruitType, spawnable_count: usize) {
+        let mut draw = || {
+            let mut fruit = NextFruitType::default();
+            fruit.randomize(spawnable_count);
+            fruit.get()
+        };
+        next.set(self.upcoming.pop_front().unwrap_or_else(&mut draw));
+        self.top_up(draw);
+    }
+
+    fn top_up(&mut self, mut draw: impl FnMut() -> FruitType) {
+        while self.upcoming.len() < Self::LEN {
+            self.upcoming.push_back(draw());
+        }
+    }
+}
+
+/// One fruit drawn from `rng`, as [`NextFruitType::randomize_biased`] would.
+fn draw(rng: &mut SpawnRng, spawnable_count: usize, bias: f32) -> FruitType {
+    let mut fruit = NextFruitType::default();
+    fruit.randomize_biased(rng, spawnable_count, bias);
+    fruit.get()
+}
+
+/// Seeded random number generator that drives the fruit spawn sequence.
+///
+/// Reseeded with a fresh random seed at the start of every game.  The seed
+/// is recorded in session summaries so a run's fruit order can be traced
+/// back to it.
+#[derive(Resource, Debug, Clone)]
+pub struct SpawnRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl Default for SpawnRng {
+    fn default() -> Self {
+        Self::from_seed(rand::random())
+    }
+}
+
+impl SpawnRng {
+    /// Creates a generator initialised from `seed`.
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// The seed this generator was last initialised with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restarts the sequence from `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::from_seed(seed);
+    }
+
+    /// Returns a uniformly distributed index in `0..n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n == 0`.
+    pub fn next_index(&mut self, n: usize) -> usize {
+        use rand::RngExt;
+        self.rng.random_range(0..n)
+    }
+
+    /// Returns an index into `weights`, each picked with a chance
+    /// proportional to its weight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty or does not sum to a positive value.
+    pub fn next_weighted(&mut self, weights: &[f32]) -> usize {
+        use rand::RngExt;
+        let total: f32 = weights.iter().sum();
+        let mut roll = self.rng.random_range(0.0..total);
+        for (i, &weight) in weights.iter().enumerate() {
+            if roll < weight {
+                return i;
+            }
+            roll -= weight;
+        }
+        weights.len() - 1
+    }
 }
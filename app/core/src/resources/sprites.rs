@@ -4,6 +4,10 @@
 //! `load_fruit_sprites`.  Core systems (`spawn_fruit`, `spawn_held_fruit`)
 //! consult this resource and fall back to the circular placeholder when no
 //! sprite is registered for a given fruit type.
+//!
+//! A sprite may be a sheet of [`FruitFrame`]s side by side; the assets crate
+//! then registers a [`TextureAtlasLayout`] for it and spawning systems show
+//! the [`FruitFrame::Idle`] frame via [`FruitSprites::atlas`].
 
 use std::collections::HashMap;
 
@@ -11,6 +15,35 @@ use bevy::prelude::*;
 
 use crate::fruit::FruitType;
 
+// ---------------------------------------------------------------------------
+// Frames
+// ---------------------------------------------------------------------------
+
+/// Animation frames of a fruit sprite sheet, left to right in the sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FruitFrame {
+    /// Resting pose
+    Idle,
+    /// Flattened on landing
+    Squish,
+    /// Bursting out of a merge
+    MergePop,
+}
+
+impl FruitFrame {
+    /// Number of frames in a sheet.
+    pub const COUNT: usize = 3;
+
+    /// Index of the frame in the sheet's [`TextureAtlasLayout`].
+    pub fn index(self) -> usize {
+        match self {
+            FruitFrame::Idle => 0,
+            FruitFrame::Squish => 1,
+            FruitFrame::MergePop => 2,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Resource
 // ---------------------------------------------------------------------------
@@ -28,6 +61,8 @@ use crate::fruit::FruitType;
 #[derive(Resource, Debug, Default)]
 pub struct FruitSprites {
     handles: HashMap<FruitType, Handle<Image>>,
+    /// Frame layouts of the sprites that are sheets of [`FruitFrame`]s
+    layouts: HashMap<FruitType, Handle<TextureAtlasLayout>>,
 }
 
 impl FruitSprites {
@@ -41,6 +76,24 @@ impl FruitSprites {
         self.handles.get(&fruit_type)
     }
 
+    /// Marks the sprite of `fruit_type` as a sheet of [`FruitFrame`]s cut by
+    /// `layout`.
+    pub fn insert_layout(&mut self, fruit_type: FruitType, layout: Handle<TextureAtlasLayout>) {
+        self.layouts.insert(fruit_type, layout);
+    }
+
+    /// The [`FruitFrame::Idle`] frame of `fruit_type`'s sheet, or `None` when
+    /// its sprite is a single image (or absent).
+    ///
+    /// Goes into `Sprite::texture_atlas` / `ImageNode::texture_atlas` next
+    /// to the image from [`get`](Self::get) or [`resolve`](Self::resolve).
+    pub fn atlas(&self, fruit_type: FruitType) -> Option<TextureAtlas> {
+        self.layouts.get(&fruit_type).map(|layout| TextureAtlas {
+            layout: layout.clone(),
+            index: FruitFrame::Idle.index(),
+        })
+    }
+
     /// Resolves `(image, color)` for spawning.
     ///
     /// Returns the real sprite with [`Color::WHITE`] when one is registered,
@@ -82,6 +135,19 @@ mod tests {
         assert!(sprites.get(FruitType::Cherry).is_some());
     }
 
+    #[test]
+    fn test_atlas_only_for_sheets() {
+        let mut sprites = FruitSprites::default();
+        sprites.insert(FruitType::Cherry, make_handle());
+        assert!(sprites.atlas(FruitType::Cherry).is_none());
+
+        sprites.insert_layout(FruitType::Cherry, Handle::default());
+        let atlas = sprites
+            .atlas(FruitType::Cherry)
+            .expect("sheet has an atlas");
+        assert_eq!(atlas.index, FruitFrame::Idle.index());
+    }
+
     #[test]
     fn test_resolve_returns_fallback_when_absent() {
         let sprites = FruitSprites::default();
@@ -0,0 +1,390 @@
+//! Scriptable rules (`scripting` feature)
+//!
+//! An enabled user mod (see [`crate::mods`]) may ship
+//! `scripts/rules.rhai`, a [Rhai](https://rhai.rs) script that reacts to
+//! the game through hook functions.  Every hook is optional:
+//!
+//! | Hook                             | Called                        |
+//! |----------------------------------|-------------------------------|
+//! | `on_drop(stage, x)`              | when the player drops a fruit |
+//! | `on_merge(stage, points, combo)` | after a merge was scored      |
+//! | `on_tick(dt)`                    | every frame while playing     |
+//!
+//! `stage` is the fruit's evolution stage (`0` = Cherry … `10` =
+//! Watermelon).  Hooks change the game through three functions:
+//!
+//! | Function                   | Effect                                     |
+//! |----------------------------|--------------------------------------------|
+//! | `add_score(points)`        | adds (or, negative, subtracts) points      |
+//! | `spawn_fruit(stage, x, y)` | places a fruit on the board                |
+//! | `set_gravity(y)`           | replaces `physics.ron` gravity for the run |
+//!
+//! ```text
+//! // Watermelons are worth an extra 1000 points, and gravity halves
+//! fn on_merge(stage, points, combo) {
+//!     if stage == 9 { add_score(1000); set_gravity(-490.0); }
+//! }
+//! ```
+//!
+//! Scripts are sandboxed: Rhai has no file, network or process access, and
+//! each hook call is capped at [`MAX_OPERATIONS`].  A script that fails to
+//! compile is skipped; one that errors at run time is switched off for the
+//! rest of the run.  The whole module only exists with the `scripting`
+//! feature, which is off by default.
+
+use std::iter;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{DefaultRapierContext, RapierConfiguration};
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{AST, CallFnOptions, Dynamic, Engine, FuncArgs, Scope};
+
+use crate::components::FruitSpawnState;
+use crate::events::{FruitDroppedEvent, ScoreEarnedEvent};
+use crate::fruit::FruitType;
+use crate::mods::ModList;
+use crate::states::AppState;
+use crate::systems::board::BoardCommands;
+
+/// Script inside a mod directory.
+pub const RULES_SCRIPT: &str = "scripts/rules.rhai";
+
+/// Operations one hook call may run before it is aborted.
+pub const MAX_OPERATIONS: u64 = 100_000;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// A change requested by a script, applied after the hooks of the frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptCommand {
+    /// `add_score(points)`
+    AddScore(i64),
+    /// `spawn_fruit(stage, x, y)`
+    SpawnFruit {
+        /// Evolution stage (`0` = Cherry)
+        stage: i64,
+        /// World position
+        position: Vec2,
+    },
+    /// `set_gravity(y)`
+    SetGravity(f32),
+}
+
+/// A compiled rules script.
+struct RulesScript {
+    /// Mod the script belongs to
+    name: String,
+    ast: AST,
+    /// Switched off after a run-time error
+    failed: bool,
+}
+
+/// The sandboxed engine and the scripts of every enabled mod.
+#[derive(Resource)]
+pub struct ScriptedRules {
+    engine: Engine,
+    scripts: Vec<RulesScript>,
+    commands: Arc<Mutex<Vec<ScriptCommand>>>,
+    /// Gravity set by `set_gravity`, kept for the rest of the run
+    pub gravity: Option<f32>,
+}
+
+impl Default for ScriptedRules {
+    fn default() -> Self {
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        Self {
+            engine: sandboxed_engine(&commands),
+            scripts: Vec::new(),
+            commands,
+            gravity: None,
+        }
+    }
+}
+
+impl ScriptedRules {
+    /// Compiles `source` as the rules script of mod `name`.
+    pub fn add_script(&mut self, name: &str, source: &str) -> Result<(), String> {
+        let ast = self.engine.compile(source).map_err(|e| e.to_string())?;
+        self.scripts.push(RulesScript {
+            name: name.to_string(),
+            ast,
+            failed: false,
+        });
+        Ok(())
+    }
+
+    /// Calls hook `hook` in every script that defines it.
+    pub fn call(&mut self, hook: &str, args: impl FuncArgs) {
+        let mut values: Vec<Dynamic> = Vec::new();
+        args.parse(&mut values);
+        for script in self.scripts.iter_mut().filter(|s| !s.failed) {
+            let defined = script
+                .ast
+                .iter_functions()
+                .any(|f| f.name == hook && f.params.len() == values.len());
+            if !defined {
+                continue;
+            }
+            let result = self.engine.call_fn_with_options::<Dynamic>(
+                CallFnOptions::new().eval_ast(false),
+                &mut Scope::new(),
+                &script.ast,
+                hook,
+                values.clone(),
+            );
+            if let Err(e) = result {
+                warn!("Script of mod '{}' disabled: {hook}: {e}", script.name);
+                script.failed = true;
+            }
+        }
+    }
+
+    /// Takes the commands the hooks have issued so far.
+    pub fn drain_commands(&mut self) -> Vec<ScriptCommand> {
+        std::mem::take(&mut *self.commands.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+/// A Rhai engine without module imports or `eval`, with resource limits and
+/// the script API registered.
+fn sandboxed_engine(commands: &Arc<Mutex<Vec<ScriptCommand>>>) -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_call_levels(32)
+        .set_max_expr_depths(64, 32)
+        .set_max_string_size(4_096)
+        .set_max_array_size(1_024)
+        .set_max_map_size(1_024)
+        .set_max_modules(0)
+        .set_module_resolver(DummyModuleResolver::new())
+        .disable_symbol("eval")
+        .on_print(|text| info!("[script] {text}"))
+        .on_debug(|text, _, _| debug!("[script] {text}"));
+
+    let push = |commands: &Arc<Mutex<Vec<ScriptCommand>>>| {
+        let commands = Arc::clone(commands);
+        move |command| {
+            commands
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(command);
+        }
+    };
+    let add_score = push(commands);
+    engine.register_fn("add_score", move |points: i64| {
+        add_score(ScriptCommand::AddScore(points));
+    });
+    let spawn_fruit = push(commands);
+    engine.register_fn("spawn_fruit", move |stage: i64, x: f64, y: f64| {
+        spawn_fruit(ScriptCommand::SpawnFruit {
+            stage,
+            position: Vec2::new(x as f32, y as f32),
+        });
+    });
+    let set_gravity = push(commands);
+    engine.register_fn("set_gravity", move |y: f64| {
+        set_gravity(ScriptCommand::SetGravity(y as f32));
+    });
+    engine
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Loads the rules scripts of the enabled mods and runs their hooks while
+/// playing.
+///
+/// Add after [`GameCorePlugin`](crate::GameCorePlugin); the [`ModList`] must
+/// already be inserted (see
+/// [`LaunchOptions::register_asset_sources`](crate::launch::LaunchOptions::register_asset_sources)).
+pub struct ScriptingPlugin;
+
+impl Plugin for ScriptingPlugin {
+    fn build(&self, app: &mut App) {
+        let mut rules = ScriptedRules::default();
+        let mods = app.world().get_resource::<ModList>();
+        for info in mods.iter().flat_map(|m| &m.mods) {
+            if !info.enabled || !info.files.contains(RULES_SCRIPT) {
+                continue;
+            }
+            let path = info.path.join(RULES_SCRIPT);
+            let result = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|source| rules.add_script(&info.name, &source));
+            match result {
+                Ok(()) => info!("📜 Rules script loaded from mod '{}'", info.name),
+                Err(e) => warn!("Skipping {}: {e}", path.display()),
+            }
+        }
+
+        app.insert_resource(rules)
+            .add_systems(
+                Update,
+                (
+                    run_drop_hooks,
+                    run_merge_hooks.after(crate::systems::score::update_score_on_merge),
+                    run_tick_hooks,
+                    apply_script_commands,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                Update,
+                apply_script_gravity.after(crate::systems::sandbox::apply_sandbox_gravity),
+            )
+            .add_systems(OnExit(AppState::GameOver), reset_script_gravity)
+            .add_systems(OnEnter(AppState::Title), reset_script_gravity);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Calls `on_drop(stage, x)` for every dropped fruit.
+pub fn run_drop_hooks(
+    mut rules: ResMut<ScriptedRules>,
+    mut dropped: MessageReader<FruitDroppedEvent>,
+) {
+    for event in dropped.read() {
+        let stage = event.fruit_type.stage_index() as i64;
+        rules.call("on_drop", (stage, f64::from(event.position.x)));
+    }
+}
+
+/// Calls `on_merge(stage, points, combo)` for every scored merge.
+pub fn run_merge_hooks(
+    mut rules: ResMut<ScriptedRules>,
+    mut scored: MessageReader<ScoreEarnedEvent>,
+) {
+    for event in scored.read() {
+        rules.call(
+            "on_merge",
+            (
+                event.fruit_type.stage_index() as i64,
+                i64::from(event.earned_points),
+                i64::from(event.combo_count),
+            ),
+        );
+    }
+}
+
+/// Calls `on_tick(dt)` once per frame.
+pub fn run_tick_hooks(mut rules: ResMut<ScriptedRules>, time: Res<Time>) {
+    rules.call("on_tick", (f64::from(time.delta_secs()),));
+}
+
+/// Applies the commands the hooks issued this frame.
+pub fn apply_script_commands(mut rules: ResMut<ScriptedRules>, mut board: BoardCommands) {
+    for command in rules.drain_commands() {
+        match command {
+            ScriptCommand::AddScore(points) => board.add_score(points),
+            ScriptCommand::SpawnFruit { stage, position } => {
+                let fruit = usize::try_from(stage).ok().and_then(|stage| {
+                    iter::successors(Some(FruitType::Cherry), FruitType::next).nth(stage)
+                });
+                match fruit {
+                    Some(fruit) => {
+                        board.spawn_fruit(fruit, position, FruitSpawnState::Landed);
+                    }
+                    None => warn!("Script asked for unknown fruit stage {stage}"),
+                }
+            }
+            ScriptCommand::SetGravity(gravity) => rules.gravity = Some(gravity),
+        }
+    }
+}
+
+/// Overrides Rapier's gravity once a script has called `set_gravity`.
+///
+/// Runs after `apply_sandbox_gravity`, which sets the `physics.ron` value.
+pub fn apply_script_gravity(
+    rules: Res<ScriptedRules>,
+    mut rapier_query: Query<&mut RapierConfiguration, With<DefaultRapierContext>>,
+) {
+    let Some(gravity) = rules.gravity else {
+        return;
+    };
+    let Ok(mut rapier_config) = rapier_query.single_mut() else {
+        return;
+    };
+    let gravity = Vec2::new(0.0, gravity);
+    if rapier_config.gravity != gravity {
+        rapier_config.gravity = gravity;
+    }
+}
+
+/// Drops a script's gravity when the game ends or the player returns to the
+/// title, so the next game starts with the configured value.
+pub fn reset_script_gravity(mut rules: ResMut<ScriptedRules>) {
+    rules.gravity = None;
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(source: &str) -> ScriptedRules {
+        let mut rules = ScriptedRules::default();
+        rules.add_script("test", source).unwrap();
+        rules
+    }
+
+    #[test]
+    fn test_hook_issues_commands() {
+        let mut rules = rules(
+            "fn on_merge(stage, points, combo) {
+                if stage == 9 { add_score(points * 2); set_gravity(-490.0); }
+                spawn_fruit(0, 1.0, 2.0);
+            }",
+        );
+        rules.call("on_merge", (9_i64, 100_i64, 1_i64));
+        assert_eq!(
+            rules.drain_commands(),
+            vec![
+                ScriptCommand::AddScore(200),
+                ScriptCommand::SetGravity(-490.0),
+                ScriptCommand::SpawnFruit {
+                    stage: 0,
+                    position: Vec2::new(1.0, 2.0)
+                },
+            ]
+        );
+        assert!(rules.drain_commands().is_empty());
+    }
+
+    #[test]
+    fn test_missing_hook_is_skipped() {
+        let mut rules = rules("fn on_drop(stage, x) { add_score(1); }");
+        rules.call("on_tick", (0.016_f64,));
+        assert!(rules.drain_commands().is_empty());
+        assert!(!rules.scripts[0].failed);
+    }
+
+    #[test]
+    fn test_runaway_script_is_disabled() {
+        let mut rules = rules("fn on_tick(dt) { loop { } }");
+        rules.call("on_tick", (0.016_f64,));
+        assert!(rules.scripts[0].failed);
+    }
+
+    #[test]
+    fn test_sandbox_rejects_eval_and_imports() {
+        let mut rules = ScriptedRules::default();
+        assert!(rules.add_script("eval", "eval(\"1\")").is_err());
+
+        let mut rules = rules("fn on_tick(dt) { import \"os\" as os; }");
+        rules.call("on_tick", (0.016_f64,));
+        assert!(rules.scripts[0].failed);
+    }
+}
@@ -0,0 +1,273 @@
+//! Shareable seed codes for challenge runs
+//!
+//! A [`SeedCode`] packs everything that decides a run's fruit sequence — the
+//! [`SpawnRng`](crate::resources::SpawnRng) seed, the [`GameMode`] and the
+//! difficulty (number of spawnable fruit kinds) — into a short code players
+//! can paste to each other:
+//!
+//! ```text
+//! 3ZK8Q-0M1XA-7T2PC
+//! ```
+//!
+//! The code is 15 Crockford base32 characters: 14 carry the 70-bit payload
+//! (`seed << 6 | mode << 3 | difficulty`) and the last one is a checksum
+//! that catches most typos.  Decoding ignores case, dashes and spaces, and
+//! reads the look-alikes `I`/`L` as `1` and `O` as `0`.
+//!
+//! While a [`ChallengeSeed`] is set, every new game uses its seed and
+//! difficulty, so everyone who entered the same code drops the same fruits.
+//! A [`RetrySeed`] does the same for just the next game.
+
+use std::fmt;
+use std::str::FromStr;
+
+use bevy::prelude::*;
+use thiserror::Error;
+
+use crate::fruit::FruitType;
+use crate::resources::GameMode;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Characters of the code alphabet (Crockford base32).
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Characters carrying the payload.
+const PAYLOAD_LEN: usize = 14;
+
+/// Total characters in a code, checksum included (dashes not counted).
+pub const SEED_CODE_LEN: usize = PAYLOAD_LEN + 1;
+
+/// Characters between the dashes of a formatted code.
+const GROUP_LEN: usize = 5;
+
+// ---------------------------------------------------------------------------
+// SeedCode
+// ---------------------------------------------------------------------------
+
+/// Seed, mode and difficulty of a shareable run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeedCode {
+    /// [`SpawnRng`](crate::resources::SpawnRng) seed.
+    pub seed: u64,
+    /// Rule set the run is played with.
+    pub mode: GameMode,
+    /// Number of spawnable fruit kinds, `1..=5`; fewer kinds is easier.
+    pub difficulty: u8,
+}
+
+/// Why a seed code could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SeedCodeError {
+    /// Wrong number of characters once dashes and spaces are removed.
+    #[error("expected {SEED_CODE_LEN} characters, got {0}")]
+    Length(usize),
+    /// A character outside the code alphabet.
+    #[error("invalid character '{0}'")]
+    InvalidChar(char),
+    /// The checksum does not match — most likely a typo.
+    #[error("checksum mismatch")]
+    Checksum,
+    /// The mode bits name no known [`GameMode`].
+    #[error("unknown mode {0}")]
+    UnknownMode(u8),
+    /// The difficulty is outside `1..=5`.
+    #[error("difficulty {0} out of range")]
+    Difficulty(u8),
+}
+
+impl SeedCode {
+    /// A code for `seed` with the given mode and difficulty.
+    ///
+    /// `difficulty` is clamped into the spawnable range.
+    pub fn new(seed: u64, mode: GameMode, difficulty: u8) -> Self {
+        let max = FruitType::spawnable_fruits().len() as u8;
+        Self {
+            seed,
+            mode,
+            difficulty: difficulty.clamp(1, max),
+        }
+    }
+
+    /// Encodes to the 15-character code without dashes.
+    pub fn encode(&self) -> String {
+        let mode = GameMode::ALL
+            .iter()
+            .position(|&m| m == self.mode)
+            .unwrap_or(0) as u128;
+        let payload = (self.seed as u128) << 6 | mode << 3 | self.difficulty as u128;
+
+        let mut digits = [0u8; SEED_CODE_LEN];
+        for (i, digit) in digits[..PAYLOAD_LEN].iter_mut().enumerate() {
+            let shift = 5 * (PAYLOAD_LEN - 1 - i);
+            *digit = (payload >> shift & 0x1f) as u8;
+        }
+        digits[PAYLOAD_LEN] = checksum(&digits[..PAYLOAD_LEN]);
+        digits
+            .iter()
+            .map(|&d| ALPHABET[d as usize] as char)
+            .collect()
+    }
+
+    /// Decodes a code as typed by a player.
+    pub fn decode(input: &str) -> Result<Self, SeedCodeError> {
+        let digits = input
+            .chars()
+            .filter(|c| !matches!(c, '-' | ' '))
+            .map(|c| digit_value(c).ok_or(SeedCodeError::InvalidChar(c)))
+            .collect::<Result<Vec<u8>, _>>()?;
+        if digits.len() != SEED_CODE_LEN {
+            return Err(SeedCodeError::Length(digits.len()));
+        }
+        if checksum(&digits[..PAYLOAD_LEN]) != digits[PAYLOAD_LEN] {
+            return Err(SeedCodeError::Checksum);
+        }
+
+        let payload = digits[..PAYLOAD_LEN]
+            .iter()
+            .fold(0u128, |acc, &d| acc << 5 | d as u128);
+        let mode_index = (payload >> 3 & 0b111) as u8;
+        let mode = *GameMode::ALL
+            .get(mode_index as usize)
+            .ok_or(SeedCodeError::UnknownMode(mode_index))?;
+        let difficulty = (payload & 0b111) as u8;
+        if !(1..=FruitType::spawnable_fruits().len() as u8).contains(&difficulty) {
+            return Err(SeedCodeError::Difficulty(difficulty));
+        }
+
+        Ok(Self {
+            seed: (payload >> 6) as u64,
+            mode,
+            difficulty,
+        })
+    }
+}
+
+impl fmt::Display for SeedCode {
+    /// Formats as `XXXXX-XXXXX-XXXXX`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = self.encode();
+        let groups: Vec<&str> = (0..SEED_CODE_LEN)
+            .step_by(GROUP_LEN)
+            .map(|i| &code[i..i + GROUP_LEN])
+            .collect();
+        f.write_str(&groups.join("-"))
+    }
+}
+
+impl FromStr for SeedCode {
+    type Err = SeedCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::decode(s)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Seed code every new game is played with, if any.
+///
+/// Takes precedence over the `--seed` launch option.  Set from the title
+/// screen; the [`GameMode`] and [`ScoreStrategy`](crate::resources::ScoreStrategy)
+/// are switched along with it.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChallengeSeed(pub Option<SeedCode>);
+
+/// Seed code for the next game only, e.g. "Retry (same seed)".
+///
+/// Taken by `begin_session_stats` when the next game starts; wins over
+/// [`ChallengeSeed`] and the `--seed` launch option for that one game.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetrySeed(pub Option<SeedCode>);
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Value of a code character, accepting lowercase and Crockford look-alikes.
+fn digit_value(c: char) -> Option<u8> {
+    let c = match c.to_ascii_uppercase() {
+        'I' | 'L' => '1',
+        'O' => '0',
+        c => c,
+    };
+    ALPHABET
+        .iter()
+        .position(|&a| a as char == c)
+        .map(|i| i as u8)
+}
+
+/// Position-weighted digit sum modulo 31, so swapped or mistyped characters
+/// change the result.
+fn checksum(digits: &[u8]) -> u8 {
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(i, &d)| (i as u32 + 1) * d as u32)
+        .sum();
+    (sum % 31) as u8
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for code in [
+            SeedCode::new(0, GameMode::Classic, 1),
+            SeedCode::new(1234, GameMode::NoCombo, 3),
+            SeedCode::new(u64::MAX, GameMode::Classic, 5),
+        ] {
+            assert_eq!(code.encode().len(), SEED_CODE_LEN);
+            assert_eq!(SeedCode::decode(&code.encode()), Ok(code));
+            assert_eq!(code.to_string().parse(), Ok(code));
+        }
+    }
+
+    #[test]
+    fn test_display_groups_with_dashes() {
+        let text = SeedCode::new(42, GameMode::Classic, 5).to_string();
+        assert_eq!(text.len(), SEED_CODE_LEN + 2);
+        assert_eq!(text.matches('-').count(), 2);
+        assert_eq!(&text[5..6], "-");
+    }
+
+    #[test]
+    fn test_decode_is_forgiving() {
+        let code = SeedCode::new(987_654_321, GameMode::NoCombo, 4);
+        let sloppy = code.to_string().to_lowercase().replace('-', " ");
+        assert_eq!(SeedCode::decode(&sloppy), Ok(code));
+        assert_eq!(digit_value('o'), Some(0));
+        assert_eq!(digit_value('L'), Some(1));
+    }
+
+    #[test]
+    fn test_decode_rejects_typos() {
+        let code = SeedCode::new(555, GameMode::Classic, 5).encode();
+        let mut typo: Vec<char> = code.chars().collect();
+        typo[3] = if typo[3] == '1' { '2' } else { '1' };
+        let typo: String = typo.into_iter().collect();
+
+        assert_eq!(SeedCode::decode(&typo), Err(SeedCodeError::Checksum));
+        assert_eq!(SeedCode::decode("ABC"), Err(SeedCodeError::Length(3)));
+        assert_eq!(
+            SeedCode::decode("ABCDE-FGHJK-MNPQU"),
+            Err(SeedCodeError::InvalidChar('U'))
+        );
+    }
+
+    #[test]
+    fn test_new_clamps_difficulty() {
+        assert_eq!(SeedCode::new(1, GameMode::Classic, 0).difficulty, 1);
+        assert_eq!(SeedCode::new(1, GameMode::Classic, 9).difficulty, 5);
+    }
+}
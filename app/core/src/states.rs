@@ -17,10 +17,17 @@ use bevy::prelude::*;
 /// - `Title` → `Playing`: Player starts a new game
 /// - `Title` → `Settings`: Player opens the settings screen
 /// - `Title` → `HowToPlay`: Player opens the how-to-play screen
-/// - `Settings` → `Title`: Player presses back
+/// - `Title` → `Stats`: Player opens the lifetime stats screen
+/// - `Settings` → `Title` / `Paused`: Player presses back (see [`ReturnTo`])
+/// - `Settings` → `AudioCalibration`: Player opens the SFX delay calibration
+/// - `AudioCalibration` → `Settings`: Player presses back
+/// - `Settings` → `EffectsSettings`: Player opens the per-effect toggles
+/// - `EffectsSettings` → `Settings`: Player presses back
 /// - `HowToPlay` → `Title`: Player presses back
+/// - `Stats` → `Title`: Player presses back
 /// - `Playing` → `Paused`: Player pauses the game
 /// - `Paused` → `Playing`: Player resumes the game
+/// - `Paused` → `Settings`: Player opens the settings from the pause menu
 /// - `Playing` → `GameOver`: Game over condition is met
 /// - `GameOver` → `Title`: Player returns to title screen
 /// - `GameOver` → `Playing`: Player starts a new game
@@ -64,11 +71,29 @@ pub enum AppState {
     /// toggle, and language selection.  Changes are persisted immediately.
     Settings,
 
+    /// Audio calibration screen state
+    ///
+    /// Plays a metronome so the player can match the SFX latency offset to
+    /// their speakers.  Opened from, and returns to, the settings screen.
+    AudioCalibration,
+
+    /// Effects settings screen state
+    ///
+    /// Switches individual visual effects (droplets, flashes, shake) on and
+    /// off.  Opened from, and returns to, the settings screen.
+    EffectsSettings,
+
     /// How-to-play screen state
     ///
     /// Shows a two-column layout explaining the game rules.
     HowToPlay,
 
+    /// Stats screen state
+    ///
+    /// Shows the lifetime stats: merges per fruit, playtime, average score
+    /// and the score distribution.
+    Stats,
+
     /// Active gameplay state
     ///
     /// The main game loop is running. Player can drop fruits
@@ -88,6 +113,33 @@ pub enum AppState {
     GameOver,
 }
 
+/// Screen that [`AppState::Settings`] goes back to.
+///
+/// Set when the settings screen is opened: from the title screen it returns
+/// to [`AppState::Title`], from the pause menu to [`AppState::Paused`] so the
+/// game can be resumed.  Reset to `Title` once the settings screen is left.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReturnTo(pub AppState);
+
+impl Default for ReturnTo {
+    fn default() -> Self {
+        Self(AppState::Title)
+    }
+}
+
+impl ReturnTo {
+    /// Returns the screen to go back to and resets it to `Title`.
+    pub fn take(&mut self) -> AppState {
+        std::mem::take(self).0
+    }
+}
+
+/// Run condition: `true` while the settings screen was opened from the pause
+/// menu, i.e. the game is still paused underneath it.
+pub fn settings_over_pause(return_to: Option<Res<ReturnTo>>) -> bool {
+    return_to.is_some_and(|r| r.0 == AppState::Paused)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,6 +162,14 @@ mod tests {
         assert_ne!(AppState::Paused, AppState::GameOver);
     }
 
+    #[test]
+    fn test_return_to_take_resets_to_title() {
+        let mut return_to = ReturnTo(AppState::Paused);
+        assert_eq!(return_to.take(), AppState::Paused);
+        assert_eq!(return_to, ReturnTo::default());
+        assert_eq!(return_to.take(), AppState::Title);
+    }
+
     #[test]
     fn test_app_state_clone() {
         let state = AppState::Playing;
@@ -136,7 +196,10 @@ mod tests {
             AppState::Loading,
             AppState::Title,
             AppState::Settings,
+            AppState::AudioCalibration,
+            AppState::EffectsSettings,
             AppState::HowToPlay,
+            AppState::Stats,
             AppState::Playing,
             AppState::Paused,
             AppState::GameOver,
@@ -0,0 +1,172 @@
+//! In-game achievements
+//!
+//! [`track_achievements`] watches every scored merge and unlocks the
+//! [`Achievement`]s it reaches.  Unlocked achievements are kept in the
+//! lifetime [`StatsResource`] (and so in `stats.json`), and each new unlock
+//! sends an [`AchievementUnlockedEvent`] that
+//! [`PlatformPlugin`](crate::platform::PlatformPlugin) mirrors to the store
+//! platform.
+//!
+//! | Achievement       | Unlocked when                          |
+//! |-------------------|----------------------------------------|
+//! | `FirstMerge`      | any two fruits merge                   |
+//! | `FirstWatermelon` | two Melons merge into a Watermelon     |
+//! | `Combo5`          | a merge reaches a 5 combo              |
+//! | `Score3000`       | the score reaches [`SCORE_ACHIEVEMENT`] |
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::events::ScoreEarnedEvent;
+use crate::fruit::FruitType;
+use crate::resources::{GameState, StatsResource};
+
+/// Combo count that unlocks [`Achievement::Combo5`].
+pub const COMBO_ACHIEVEMENT: u32 = 5;
+
+/// Score that unlocks [`Achievement::Score3000`].
+pub const SCORE_ACHIEVEMENT: u32 = 3000;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// An achievement the player can unlock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Achievement {
+    /// First merge of any two fruits
+    FirstMerge,
+    /// First Watermelon
+    FirstWatermelon,
+    /// A [`COMBO_ACHIEVEMENT`] combo
+    Combo5,
+    /// A score of [`SCORE_ACHIEVEMENT`]
+    Score3000,
+}
+
+impl Achievement {
+    /// Every achievement, in unlock-screen order.
+    pub const ALL: [Achievement; 4] = [
+        Achievement::FirstMerge,
+        Achievement::FirstWatermelon,
+        Achievement::Combo5,
+        Achievement::Score3000,
+    ];
+
+    /// API name of the achievement on store platforms.
+    pub fn id(self) -> &'static str {
+        match self {
+            Achievement::FirstMerge => "FIRST_MERGE",
+            Achievement::FirstWatermelon => "FIRST_WATERMELON",
+            Achievement::Combo5 => "COMBO_5",
+            Achievement::Score3000 => "SCORE_3000",
+        }
+    }
+
+    /// Whether the merge in `event` reaches this achievement, with the game
+    /// score at `score` after it.
+    pub fn reached_by(self, event: &ScoreEarnedEvent, score: u32) -> bool {
+        match self {
+            Achievement::FirstMerge => true,
+            Achievement::FirstWatermelon => event.fruit_type == FruitType::Melon,
+            Achievement::Combo5 => event.combo_count >= COMBO_ACHIEVEMENT,
+            Achievement::Score3000 => score >= SCORE_ACHIEVEMENT,
+        }
+    }
+}
+
+/// Event emitted by [`track_achievements`] the first time an achievement
+/// is unlocked.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AchievementUnlockedEvent(pub Achievement);
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Unlocks the achievements reached by this frame's merges.
+///
+/// Already unlocked achievements are skipped, so each one is announced once
+/// per profile.  The unlocks are saved with the lifetime stats.
+pub fn track_achievements(
+    mut score_events: MessageReader<ScoreEarnedEvent>,
+    game_state: Res<GameState>,
+    mut stats: ResMut<StatsResource>,
+    mut unlocked: MessageWriter<AchievementUnlockedEvent>,
+) {
+    for event in score_events.read() {
+        for achievement in Achievement::ALL {
+            if !stats.achievements.contains(&achievement)
+                && achievement.reached_by(event, game_state.score)
+            {
+                stats.achievements.insert(achievement);
+                info!("🏆 Achievement unlocked: {}", achievement.id());
+                unlocked.write(AchievementUnlockedEvent(achievement));
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merge(fruit_type: FruitType, combo_count: u32) -> ScoreEarnedEvent {
+        ScoreEarnedEvent {
+            position: Vec2::ZERO,
+            earned_points: 10,
+            combo_count,
+            fruit_type,
+        }
+    }
+
+    #[test]
+    fn test_achievement_conditions() {
+        let cherry = merge(FruitType::Cherry, 1);
+        assert!(Achievement::FirstMerge.reached_by(&cherry, 0));
+        assert!(!Achievement::FirstWatermelon.reached_by(&cherry, 0));
+        assert!(Achievement::FirstWatermelon.reached_by(&merge(FruitType::Melon, 1), 0));
+        assert!(!Achievement::Combo5.reached_by(&merge(FruitType::Cherry, 4), 0));
+        assert!(Achievement::Combo5.reached_by(&merge(FruitType::Cherry, 5), 0));
+        assert!(!Achievement::Score3000.reached_by(&cherry, 2999));
+        assert!(Achievement::Score3000.reached_by(&cherry, 3000));
+    }
+
+    #[test]
+    fn test_track_achievements_unlocks_once() {
+        let mut app = App::new();
+        app.add_message::<ScoreEarnedEvent>()
+            .add_message::<AchievementUnlockedEvent>()
+            .init_resource::<GameState>()
+            .init_resource::<StatsResource>()
+            .add_systems(Update, track_achievements);
+
+        app.world_mut().write_message(merge(FruitType::Melon, 1));
+        app.update();
+        let stats = app.world().resource::<StatsResource>();
+        assert!(stats.achievements.contains(&Achievement::FirstMerge));
+        assert!(stats.achievements.contains(&Achievement::FirstWatermelon));
+        assert!(!stats.achievements.contains(&Achievement::Combo5));
+
+        let unlocked: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<AchievementUnlockedEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(unlocked.len(), 2);
+
+        // A second Watermelon unlocks nothing new
+        app.world_mut().write_message(merge(FruitType::Melon, 1));
+        app.update();
+        let unlocked = app
+            .world_mut()
+            .resource_mut::<Messages<AchievementUnlockedEvent>>()
+            .drain()
+            .count();
+        assert_eq!(unlocked, 0);
+    }
+}
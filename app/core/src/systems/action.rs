@@ -0,0 +1,471 @@
+//! Input actions
+//!
+//! Gameplay reads [`ActionState`] instead of raw device input.  Once per
+//! frame [`collect_input_actions`] maps the keyboard, mouse, gamepads and
+//! touch screen into the same small set of [`InputAction`]s, so rebinding,
+//! replays and bots only have to deal with actions:
+//!
+//! | Action      | Keyboard      | Mouse        | Gamepad                  | Touch       |
+//! |-------------|---------------|--------------|--------------------------|-------------|
+//! | `MoveLeft`  | `←` / `A`     |              | D-pad left / left stick  |             |
+//! | `MoveRight` | `→` / `D`     |              | D-pad right / left stick |             |
+//! | `Drop`      | `Space`       | left button  | South                    | lift finger |
+//! | `Pause`     | `Esc`         |              | Start                    |             |
+//! | `Hold`      | `Shift` / `C` | right button | left shoulder            |             |
+//!
+//! Besides the buttons the pointer aims: [`ActionState::aim_x`] is the world
+//! X under a finger while it touches the screen, otherwise under the mouse
+//! cursor.  [`ActionState::aim_moved`] tells a moving pointer apart from a
+//! cursor that merely rests over the window.
+//!
+//! Mouse buttons and touches that start over a UI element
+//! ([`PointerOverUi`], kept up to date by the UI crate) belong to that
+//! element: they produce no actions until released, so pressing a button
+//! never also drops the fruit.  While they are blocked the pointer does
+//! not aim either.
+//!
+//! On-screen controls (the UI's touch control strip) feed in through
+//! [`OnScreenActions`], which count as one more device.
+
+use std::collections::HashSet;
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::systems::input::LastCursorPosition;
+
+// ---------------------------------------------------------------------------
+// Bindings
+// ---------------------------------------------------------------------------
+
+/// Keyboard bindings.
+const KEY_BINDINGS: &[(KeyCode, InputAction)] = &[
+    (KeyCode::ArrowLeft, InputAction::MoveLeft),
+    (KeyCode::KeyA, InputAction::MoveLeft),
+    (KeyCode::ArrowRight, InputAction::MoveRight),
+    (KeyCode::KeyD, InputAction::MoveRight),
+    (KeyCode::Space, InputAction::Drop),
+    (KeyCode::Escape, InputAction::Pause),
+    (KeyCode::ShiftLeft, InputAction::Hold),
+    (KeyCode::ShiftRight, InputAction::Hold),
+    (KeyCode::KeyC, InputAction::Hold),
+];
+
+/// Mouse button bindings.
+const MOUSE_BINDINGS: &[(MouseButton, InputAction)] = &[
+    (MouseButton::Left, InputAction::Drop),
+    (MouseButton::Right, InputAction::Hold),
+];
+
+/// Gamepad button bindings.
+const GAMEPAD_BINDINGS: &[(GamepadButton, InputAction)] = &[
+    (GamepadButton::DPadLeft, InputAction::MoveLeft),
+    (GamepadButton::DPadRight, InputAction::MoveRight),
+    (GamepadButton::South, InputAction::Drop),
+    (GamepadButton::Start, InputAction::Pause),
+    (GamepadButton::LeftTrigger, InputAction::Hold),
+];
+
+/// Left stick deflection that counts as `MoveLeft` / `MoveRight`.
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// Cursor movement (pixels) below which the mouse is not considered moved.
+const MOUSE_MOVEMENT_THRESHOLD: f32 = 1.0;
+
+// ---------------------------------------------------------------------------
+// Actions
+// ---------------------------------------------------------------------------
+
+/// A gameplay action, independent of the device that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputAction {
+    /// Move the held fruit left
+    MoveLeft,
+    /// Move the held fruit right
+    MoveRight,
+    /// Drop the held fruit
+    Drop,
+    /// Pause or resume the game
+    Pause,
+    /// Swap the held fruit into the hold slot (not used by any rule yet)
+    Hold,
+}
+
+impl InputAction {
+    /// Every action, in declaration order.
+    pub const ALL: [InputAction; 5] = [
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::Drop,
+        InputAction::Pause,
+        InputAction::Hold,
+    ];
+}
+
+/// This frame's actions.
+///
+/// Written by [`collect_input_actions`] in `PreUpdate`; gameplay systems
+/// only read it.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ActionState {
+    buttons: ButtonInput<InputAction>,
+    aim_x: Option<f32>,
+    aim_moved: bool,
+}
+
+impl ActionState {
+    /// Whether `action` is held down.
+    pub fn pressed(&self, action: InputAction) -> bool {
+        self.buttons.pressed(action)
+    }
+
+    /// Whether `action` started this frame.
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.buttons.just_pressed(action)
+    }
+
+    /// World X the pointer aims at, if it is over the window.
+    pub fn aim_x(&self) -> Option<f32> {
+        self.aim_x
+    }
+
+    /// Whether the pointer moved this frame; always `true` while a finger
+    /// touches the screen.
+    pub fn aim_moved(&self) -> bool {
+        self.aim_moved
+    }
+
+    /// Starts a new frame: actions for which `active` is `true` are held,
+    /// all others released.  `aim` is the pointer's world X and whether it
+    /// moved.
+    pub fn set_frame(&mut self, active: impl Fn(InputAction) -> bool, aim: Option<(f32, bool)>) {
+        self.buttons.clear();
+        for action in InputAction::ALL {
+            if active(action) {
+                self.buttons.press(action);
+            } else {
+                self.buttons.release(action);
+            }
+        }
+        self.aim_x = aim.map(|(x, _)| x);
+        self.aim_moved = aim.is_some_and(|(_, moved)| moved);
+    }
+}
+
+/// Whether the mouse cursor or a finger is over an interactive UI element.
+///
+/// Written by the UI crate in `PreUpdate` before [`collect_input_actions`];
+/// stays `false` when no UI is present (headless runs, tests).
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PointerOverUi(pub bool);
+
+/// Actions held through on-screen buttons.
+///
+/// Rewritten every frame by the UI crate in `PreUpdate` before
+/// [`collect_input_actions`], which treats them like any other device.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct OnScreenActions(pub HashSet<InputAction>);
+
+// ---------------------------------------------------------------------------
+// Devices
+// ---------------------------------------------------------------------------
+
+/// Raw button state of every device that feeds [`ActionState`].
+#[derive(SystemParam)]
+pub struct DeviceInput<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+    touches: Res<'w, Touches>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl DeviceInput<'_, '_> {
+    /// Whether any device holds `action` this frame.
+    ///
+    /// A finger drops the fruit on the frame it leaves the screen.  With
+    /// `pointer_blocked` set the mouse buttons and touches are ignored.
+    pub fn active(&self, action: InputAction, pointer_blocked: bool) -> bool {
+        let bound = |&(_, bound): &(_, InputAction)| bound == action;
+        KEY_BINDINGS
+            .iter()
+            .filter(bound)
+            .any(|(key, _)| self.keyboard.pressed(*key))
+            || (!pointer_blocked
+                && MOUSE_BINDINGS
+                    .iter()
+                    .filter(bound)
+                    .any(|(button, _)| self.mouse.pressed(*button)))
+            || self.gamepads.iter().any(|gamepad| {
+                GAMEPAD_BINDINGS
+                    .iter()
+                    .filter(bound)
+                    .any(|(button, _)| gamepad.pressed(*button))
+                    || stick_action(gamepad.left_stick().x) == Some(action)
+            })
+            || (!pointer_blocked && action == InputAction::Drop && self.touches.any_just_released())
+    }
+
+    /// Whether a mouse button or a finger went down this frame.
+    fn pointer_just_pressed(&self) -> bool {
+        MOUSE_BINDINGS
+            .iter()
+            .any(|(button, _)| self.mouse.just_pressed(*button))
+            || self.touches.any_just_pressed()
+    }
+
+    /// Whether any mouse button or finger is down.
+    fn pointer_down(&self) -> bool {
+        MOUSE_BINDINGS
+            .iter()
+            .any(|(button, _)| self.mouse.pressed(*button))
+            || self.touches.iter().next().is_some()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Fills [`ActionState`] from the devices and the pointer.
+///
+/// Registered in `PreUpdate` after Bevy's input systems, in every state.
+/// A press that starts over the UI ([`PointerOverUi`]) blocks the mouse and
+/// touch bindings until every button and finger is released, including the
+/// release frame itself.
+pub fn collect_input_actions(
+    devices: DeviceInput,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    pointer_over_ui: Option<Res<PointerOverUi>>,
+    on_screen: Option<Res<OnScreenActions>>,
+    mut pointer_blocked: Local<bool>,
+    mut last_cursor_pos: ResMut<LastCursorPosition>,
+    mut actions: ResMut<ActionState>,
+) {
+    if devices.pointer_just_pressed() && pointer_over_ui.is_some_and(|over| over.0) {
+        *pointer_blocked = true;
+    }
+
+    let to_world = |viewport: Vec2| {
+        let (camera, camera_transform) = camera_query.single().ok()?;
+        camera.viewport_to_world_2d(camera_transform, viewport).ok()
+    };
+
+    // A finger on the screen aims; otherwise the mouse cursor does
+    let touch_aim = devices
+        .touches
+        .first_pressed_position()
+        .and_then(to_world)
+        .map(|pos| (pos.x, true));
+    let mouse_aim = windows
+        .single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .and_then(to_world)
+        .map(|world_pos| {
+            // First frame: nothing to compare against yet
+            let moved = last_cursor_pos
+                .position
+                .is_some_and(|last| (world_pos - last).length() > MOUSE_MOVEMENT_THRESHOLD);
+            last_cursor_pos.position = Some(world_pos);
+            (world_pos.x, moved)
+        });
+
+    let blocked = *pointer_blocked;
+    let on_screen = |action| {
+        on_screen
+            .as_ref()
+            .is_some_and(|held| held.0.contains(&action))
+    };
+    actions.set_frame(
+        |action| devices.active(action, blocked) || on_screen(action),
+        if blocked {
+            None
+        } else {
+            touch_aim.or(mouse_aim)
+        },
+    );
+    if blocked && !devices.pointer_down() {
+        *pointer_blocked = false;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Movement action for a left stick deflection of `x`.
+fn stick_action(x: f32) -> Option<InputAction> {
+    if x <= -STICK_THRESHOLD {
+        Some(InputAction::MoveLeft)
+    } else if x >= STICK_THRESHOLD {
+        Some(InputAction::MoveRight)
+    } else {
+        None
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Touches>()
+            .init_resource::<LastCursorPosition>()
+            .init_resource::<ActionState>()
+            .add_systems(Update, collect_input_actions);
+        app
+    }
+
+    #[test]
+    fn test_stick_action_threshold() {
+        assert_eq!(stick_action(0.0), None);
+        assert_eq!(stick_action(0.3), None);
+        assert_eq!(stick_action(-0.8), Some(InputAction::MoveLeft));
+        assert_eq!(stick_action(1.0), Some(InputAction::MoveRight));
+    }
+
+    #[test]
+    fn test_every_action_has_a_key() {
+        for action in InputAction::ALL {
+            assert!(
+                KEY_BINDINGS.iter().any(|(_, bound)| *bound == action),
+                "{action:?} has no keyboard binding"
+            );
+        }
+    }
+
+    #[test]
+    fn test_keys_map_to_actions() {
+        let mut app = action_app();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyA);
+        app.update();
+
+        let actions = app.world().resource::<ActionState>();
+        assert!(actions.pressed(InputAction::MoveLeft));
+        assert!(actions.just_pressed(InputAction::MoveLeft));
+        assert!(!actions.pressed(InputAction::MoveRight));
+        assert_eq!(actions.aim_x(), None);
+    }
+
+    #[test]
+    fn test_mouse_and_gamepad_map_to_actions() {
+        let mut app = action_app();
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        let mut gamepad = Gamepad::default();
+        gamepad.digital_mut().press(GamepadButton::Start);
+        app.world_mut().spawn(gamepad);
+        app.update();
+
+        let actions = app.world().resource::<ActionState>();
+        assert!(actions.just_pressed(InputAction::Drop));
+        assert!(actions.just_pressed(InputAction::Pause));
+    }
+
+    #[test]
+    fn test_click_over_ui_does_not_drop() {
+        let mut app = action_app();
+        app.insert_resource(PointerOverUi(true));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        assert!(
+            !app.world()
+                .resource::<ActionState>()
+                .pressed(InputAction::Drop)
+        );
+
+        // Dragging off the button keeps the press with the UI
+        app.insert_resource(PointerOverUi(false));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .clear();
+        app.update();
+        assert!(
+            !app.world()
+                .resource::<ActionState>()
+                .pressed(InputAction::Drop)
+        );
+
+        // The next click, away from the UI, drops
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Left);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<ActionState>()
+                .just_pressed(InputAction::Drop)
+        );
+    }
+
+    #[test]
+    fn test_on_screen_actions_map_to_actions() {
+        let mut app = action_app();
+        app.insert_resource(OnScreenActions(HashSet::from([InputAction::MoveRight])));
+        app.update();
+        let actions = app.world().resource::<ActionState>();
+        assert!(actions.just_pressed(InputAction::MoveRight));
+        assert!(!actions.pressed(InputAction::Drop));
+    }
+
+    #[test]
+    fn test_keyboard_drop_works_over_ui() {
+        let mut app = action_app();
+        app.insert_resource(PointerOverUi(true));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<ActionState>()
+                .just_pressed(InputAction::Drop)
+        );
+    }
+
+    #[test]
+    fn test_held_action_is_just_pressed_once() {
+        let mut app = action_app();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+        app.update();
+
+        let actions = app.world().resource::<ActionState>();
+        assert!(actions.pressed(InputAction::Drop));
+        assert!(!actions.just_pressed(InputAction::Drop));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::Space);
+        app.update();
+        assert!(
+            !app.world()
+                .resource::<ActionState>()
+                .pressed(InputAction::Drop)
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Adaptive spawn
+//!
+//! [`update_container_fill_level`] measures how full the container is every
+//! frame: the top of the highest resting fruit, from the container floor
+//! (`0.0`) up to the boundary line (`1.0`), kept in [`ContainerFillLevel`].
+//!
+//! Optional rule from `game_rules.ron` `adaptive_spawn`: once the fill level
+//! reaches `nearly_full`, [`update_adaptive_spawn`] leans new draws toward
+//! the smaller spawnable fruits, and switches back to normal draws when the
+//! fill level has dropped to `healthy`.  The gap between the two keeps the
+//! nudge from flickering on and off around a single threshold.
+//!
+//! ```text
+//! ─ ─ ─ ─ ─ ─ ─ ─ ─   boundary line (fill 1.0)
+//! ·················   nearly_full  → nudge on
+//!    ●●
+//! ·················   healthy      → nudge off
+//!    ●●●●●●●●●
+//! └───────────────┘   floor (fill 0.0)
+//! ```
+//!
+//! The nudge is bounded so it cannot be farmed for score: it only reweights
+//! the fruits that are already spawnable, its strength is capped at
+//! [`MAX_ADAPTIVE_BIAS`], and challenge seeds always draw uniformly so their
+//! fruit sequence stays the same for every player.  Any other run the nudge
+//! has changed loses its seed code, so it can neither be shared nor retried
+//! as if the seed alone produced it.
+
+use bevy::prelude::*;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::{
+    AdaptiveSpawnConfig, FruitsParams, GameRulesParams, MAX_ADAPTIVE_BIAS, PhysicsParams,
+};
+use crate::fruit::FruitType;
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// How full the container is, from `0.0` (empty) to `1.0` (a resting fruit
+/// touches the boundary line).
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ContainerFillLevel(pub f32);
+
+/// State of the adaptive spawn rule.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct AdaptiveSpawn {
+    /// Whether the nudge is on (between reaching `nearly_full` and dropping
+    /// back to `healthy`)
+    pub active: bool,
+    /// Bias handed to the spawn draw; `0.0` while inactive
+    pub bias: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Updates [`ContainerFillLevel`] from the landed fruits.
+///
+/// Held and falling fruits do not count, so a drop in mid-air never reads
+/// as a full board.  Stays at `0.0` until `physics.ron` is loaded.
+pub fn update_container_fill_level(
+    physics: PhysicsParams,
+    fruits: FruitsParams,
+    fruit_query: Query<(&FruitType, &FruitSpawnState, &Transform), With<Fruit>>,
+    mut fill: ResMut<ContainerFillLevel>,
+) {
+    let Some(physics) = physics.get() else {
+        return;
+    };
+    let config = fruits.get();
+    let tops = fruit_query
+        .iter()
+        .filter(|(_, state, _)| **state == FruitSpawnState::Landed)
+        .map(|(fruit_type, _, transform)| {
+            let radius = config
+                .and_then(|c| fruit_type.try_parameters_from_config(c))
+                .map_or(0.0, |p| p.radius);
+            transform.translation.y + radius
+        });
+    let level = fill_level(
+        tops,
+        -physics.container_height / 2.0,
+        physics.boundary_line_y,
+    );
+    fill.set_if_neq(ContainerFillLevel(level));
+}
+
+/// Switches the nudge on and off from [`ContainerFillLevel`].
+///
+/// Always inactive while the rule is disabled or `game_rules.ron` is not
+/// loaded.
+pub fn update_adaptive_spawn(
+    rules: GameRulesParams,
+    fill: Res<ContainerFillLevel>,
+    mut adaptive: ResMut<AdaptiveSpawn>,
+) {
+    let next = match rules.get().map(|r| r.adaptive_spawn).filter(|c| c.enabled) {
+        Some(config) => next_state(&config, adaptive.active, fill.0),
+        None => AdaptiveSpawn::default(),
+    };
+    adaptive.set_if_neq(next);
+}
+
+/// Clears [`ContainerFillLevel`] and [`AdaptiveSpawn`] so a new game starts
+/// with normal draws.
+pub fn reset_adaptive_spawn(
+    mut fill: ResMut<ContainerFillLevel>,
+    mut adaptive: ResMut<AdaptiveSpawn>,
+) {
+    *fill = ContainerFillLevel::default();
+    *adaptive = AdaptiveSpawn::default();
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Fill level for fruit tops at `tops`, between the container `floor` and
+/// the boundary `line`.
+fn fill_level(tops: impl Iterator<Item = f32>, floor: f32, line: f32) -> f32 {
+    let height = line - floor;
+    if height <= 0.0 {
+        return 0.0;
+    }
+    tops.map(|top| ((top - floor) / height).clamp(0.0, 1.0))
+        .fold(0.0, f32::max)
+}
+
+/// The rule's state after a frame at `fill`, given whether it was `active`.
+///
+/// While active the bias grows from nothing at `healthy` to the (capped)
+/// `max_bias` at a full board, so the nudge eases in instead of snapping.
+fn next_state(config: &AdaptiveSpawnConfig, active: bool, fill: f32) -> AdaptiveSpawn {
+    let active = if active {
+        fill > config.healthy
+    } else {
+        fill >= config.nearly_full
+    };
+    if !active {
+        return AdaptiveSpawn::default();
+    }
+    let ramp = ((fill - config.healthy) / (1.0 - config.healthy).max(f32::EPSILON)).clamp(0.0, 1.0);
+    AdaptiveSpawn {
+        active,
+        bias: config.max_bias.clamp(0.0, MAX_ADAPTIVE_BIAS) * ramp,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> AdaptiveSpawnConfig {
+        AdaptiveSpawnConfig {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fill_level_uses_the_highest_top() {
+        assert_eq!(fill_level(std::iter::empty(), -400.0, 300.0), 0.0);
+        let level = fill_level([-300.0, -50.0, -400.0].into_iter(), -400.0, 300.0);
+        assert!((level - 0.5).abs() < 1e-5);
+        assert_eq!(fill_level([500.0].into_iter(), -400.0, 300.0), 1.0);
+    }
+
+    #[test]
+    fn test_next_state_has_hysteresis() {
+        let config = enabled();
+        // Below nearly_full: stays off
+        assert!(!next_state(&config, false, 0.7).active);
+        // Reaching nearly_full: on
+        let on = next_state(&config, false, 0.8);
+        assert!(on.active);
+        assert!(on.bias > 0.0);
+        // Between healthy and nearly_full: stays on
+        assert!(next_state(&config, true, 0.7).active);
+        // Back to healthy: off with no bias
+        assert_eq!(next_state(&config, true, 0.6), AdaptiveSpawn::default());
+    }
+
+    #[test]
+    fn test_next_state_caps_the_bias() {
+        let config = AdaptiveSpawnConfig {
+            max_bias: 5.0,
+            ..enabled()
+        };
+        let full = next_state(&config, true, 1.0);
+        assert_eq!(full.bias, MAX_ADAPTIVE_BIAS);
+        assert!(next_state(&config, true, 0.7).bias < full.bias);
+    }
+}
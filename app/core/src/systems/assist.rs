@@ -0,0 +1,230 @@
+//! One-button play assists
+//!
+//! Two optional [`SettingsResource`] assists make the game playable with a
+//! single switch or button:
+//!
+//! - **Sweep** (`sweep_assist`): the held fruit glides left and right across
+//!   the container on its own, bouncing off the walls.  Any drop input
+//!   (see [`InputAction::Drop`](crate::systems::action::InputAction::Drop))
+//!   releases it; the move and aim inputs are ignored.
+//! - **Auto-drop** (`auto_drop_secs`): a fruit held for that many seconds
+//!   drops on its own.  Works with or without the sweep.  While the pile is
+//!   still moving ([`BoardSettled`]) the drop waits for it to come to rest,
+//!   for at most [`AUTO_DROP_SETTLE_GRACE_SECS`] more.
+//!
+//! ```text
+//! |  ←── ● ──→  |   sweep: back and forth until the button is pressed
+//! ```
+//!
+//! Both only drive the board while the local player has control.
+
+use bevy::prelude::*;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::{FruitsParams, PhysicsParams};
+use crate::events::FruitDroppedEvent;
+use crate::fruit::FruitType;
+use crate::resources::settings::SettingsResource;
+use crate::systems::input::{SpawnPosition, clamp_spawn_x, release_held_fruit};
+use crate::systems::settle::BoardSettled;
+
+/// Sweep speed (pixels per second); slow enough to time a single press.
+pub const SWEEP_SPEED: f32 = 150.0;
+
+/// Longest an auto-drop waits past `auto_drop_secs` for the pile to settle.
+pub const AUTO_DROP_SETTLE_GRACE_SECS: f32 = 2.0;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Progress of the one-button assists for the current fruit.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AssistState {
+    /// Sweep direction: `1.0` moving right, `-1.0` moving left
+    pub sweep_direction: f32,
+    /// Seconds the current fruit has been held
+    pub held_secs: f32,
+}
+
+impl Default for AssistState {
+    fn default() -> Self {
+        Self {
+            sweep_direction: 1.0,
+            held_secs: 0.0,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` while the sweep assist is switched on.
+pub fn sweep_assist_enabled(settings: Option<Res<SettingsResource>>) -> bool {
+    settings.is_some_and(|s| s.sweep_assist)
+}
+
+/// `true` while auto-drop is switched on.
+pub fn auto_drop_enabled(settings: Option<Res<SettingsResource>>) -> bool {
+    settings.is_some_and(|s| s.auto_drop_secs > 0)
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Sweeps the held fruit between the walls.
+///
+/// Replaces `update_spawn_position` while the sweep assist is on.
+#[allow(clippy::type_complexity)]
+pub fn sweep_held_fruit(
+    time: Res<Time>,
+    mut assist: ResMut<AssistState>,
+    mut spawn_pos: ResMut<SpawnPosition>,
+    mut fruits: Query<(&FruitType, &FruitSpawnState, &mut Transform), With<Fruit>>,
+    fruits_config: FruitsParams,
+    physics_config: PhysicsParams,
+) {
+    let (Some(fruits_config), Some(physics_config)) = (fruits_config.get(), physics_config.get())
+    else {
+        return;
+    };
+    let Some((fruit_type, _, mut transform)) = fruits
+        .iter_mut()
+        .find(|(_, state, _)| **state == FruitSpawnState::Held)
+    else {
+        return;
+    };
+
+    let radius = fruit_type.parameters_from_config(fruits_config).radius;
+    let (x, direction) = sweep_step(
+        spawn_pos.x,
+        assist.sweep_direction,
+        SWEEP_SPEED * time.delta_secs(),
+        physics_config.container_width,
+        radius,
+    );
+    spawn_pos.x = x;
+    assist.sweep_direction = direction;
+    transform.translation.x = x;
+}
+
+/// Drops the held fruit once it has been held for
+/// [`SettingsResource::auto_drop_secs`] and the board has settled.
+#[allow(clippy::too_many_arguments)]
+pub fn auto_drop_held_fruit(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<SettingsResource>,
+    board: Option<Res<BoardSettled>>,
+    mut assist: ResMut<AssistState>,
+    mut fruits: Query<(Entity, &FruitType, &Transform, &mut FruitSpawnState), With<Fruit>>,
+    fruits_config: FruitsParams,
+    physics_config: PhysicsParams,
+    mut dropped_events: MessageWriter<FruitDroppedEvent>,
+) {
+    let (Some(fruits_config), Some(physics_config)) = (fruits_config.get(), physics_config.get())
+    else {
+        return;
+    };
+    let Some((entity, fruit_type, transform, mut state)) = fruits
+        .iter_mut()
+        .find(|(_, _, _, state)| **state == FruitSpawnState::Held)
+    else {
+        assist.held_secs = 0.0;
+        return;
+    };
+
+    assist.held_secs += time.delta_secs();
+    let settled = board.is_none_or(|b| b.settled);
+    if !auto_drop_due(
+        assist.held_secs,
+        f32::from(settings.auto_drop_secs),
+        settled,
+    ) {
+        return;
+    }
+    info!("Auto-drop after {}s", settings.auto_drop_secs);
+    release_held_fruit(
+        &mut commands,
+        entity,
+        *fruit_type,
+        transform.translation.truncate(),
+        &mut state,
+        fruits_config,
+        physics_config,
+        &mut dropped_events,
+    );
+    assist.held_secs = 0.0;
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Moves `x` by `distance` in `direction` and bounces off the container
+/// walls.  Returns the new position and direction.
+fn sweep_step(
+    x: f32,
+    direction: f32,
+    distance: f32,
+    container_width: f32,
+    radius: f32,
+) -> (f32, f32) {
+    let next = x + direction * distance;
+    let clamped = clamp_spawn_x(next, container_width, radius);
+    if clamped != next {
+        (clamped, -direction)
+    } else {
+        (next, direction)
+    }
+}
+
+/// `true` once a fruit held for `held_secs` should auto-drop: after
+/// `delay` on a `settled` board, or after the grace period regardless.
+fn auto_drop_due(held_secs: f32, delay: f32, settled: bool) -> bool {
+    held_secs >= delay && (settled || held_secs >= delay + AUTO_DROP_SETTLE_GRACE_SECS)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep_step_moves_and_bounces() {
+        assert_eq!(sweep_step(0.0, 1.0, 10.0, 600.0, 20.0), (10.0, 1.0));
+        assert_eq!(sweep_step(0.0, -1.0, 10.0, 600.0, 20.0), (-10.0, -1.0));
+        // Right wall at 300 - 20
+        assert_eq!(sweep_step(275.0, 1.0, 10.0, 600.0, 20.0), (280.0, -1.0));
+        assert_eq!(sweep_step(-275.0, -1.0, 10.0, 600.0, 20.0), (-280.0, 1.0));
+    }
+
+    #[test]
+    fn test_auto_drop_waits_for_settle() {
+        assert!(!auto_drop_due(2.0, 3.0, true));
+        assert!(auto_drop_due(3.0, 3.0, true));
+        assert!(!auto_drop_due(3.0, 3.0, false));
+        assert!(auto_drop_due(3.0 + AUTO_DROP_SETTLE_GRACE_SECS, 3.0, false));
+    }
+
+    #[test]
+    fn test_assists_disabled_by_default() {
+        let mut world = World::new();
+        world.init_resource::<SettingsResource>();
+        assert!(!world.run_system_cached(sweep_assist_enabled).unwrap());
+        assert!(!world.run_system_cached(auto_drop_enabled).unwrap());
+
+        {
+            let mut settings = world.resource_mut::<SettingsResource>();
+            settings.sweep_assist = true;
+            settings.auto_drop_secs = 5;
+        }
+        assert!(world.run_system_cached(sweep_assist_enabled).unwrap());
+        assert!(world.run_system_cached(auto_drop_enabled).unwrap());
+    }
+}
@@ -0,0 +1,330 @@
+//! Attract mode
+//!
+//! When the title screen sits idle for [`ATTRACT_IDLE_SECS`], a demo game
+//! starts in which a simple bot drops the fruits.  Any key, mouse button,
+//! mouse movement or gamepad button — or the demo reaching its game over or
+//! [`ATTRACT_MAX_SECS`] — returns to the title screen.
+//!
+//! The bot aims each fruit at the highest landed fruit of the same type so it
+//! merges now and then, and otherwise drops at a random spot.  Like a sandbox
+//! game, a demo records nothing: no highscore, session log, lifetime stats or
+//! saved session (the player's saved session is left untouched).
+//!
+//! The UI hides the HUD and shows a "Press any key" overlay while
+//! [`AttractMode::active`] is set.
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::{FruitsParams, PhysicsParams};
+use crate::events::FruitDroppedEvent;
+use crate::fruit::FruitType;
+use crate::states::AppState;
+use crate::systems::input::{SpawnPosition, clamp_spawn_x, release_held_fruit};
+use crate::transition::ScreenTransition;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Seconds the title screen must sit idle before the demo starts.
+pub const ATTRACT_IDLE_SECS: f32 = 30.0;
+
+/// Longest a demo runs before returning to the title screen (seconds).
+pub const ATTRACT_MAX_SECS: f32 = 90.0;
+
+/// Minimum time the bot holds each fruit before dropping it (seconds), so the
+/// demo reads like a person playing.
+const BOT_AIM_SECS: f32 = 0.6;
+
+/// Distance (px) at which the held fruit counts as on target.
+const BOT_TARGET_TOLERANCE: f32 = 1.0;
+
+/// Bot move speed when `keyboard_move_speed` is not positive (px/s).
+const DEFAULT_BOT_MOVE_SPEED: f32 = 300.0;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Whether the current game is an attract-mode demo, and the bot's state.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AttractMode {
+    /// The current game is a demo played by the bot
+    pub active: bool,
+    /// Seconds the title screen has been idle
+    pub idle_secs: f32,
+    /// Seconds the current demo has been running
+    pub elapsed: f32,
+    /// X the held fruit is moving to; `None` until a target is picked
+    target_x: Option<f32>,
+    /// Seconds the current fruit has been held
+    aim_secs: f32,
+}
+
+/// Any player input this frame: a key, mouse button or gamepad button press,
+/// or mouse movement.
+#[derive(SystemParam)]
+pub struct AnyInput<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+    motion: MessageReader<'w, 's, MouseMotion>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl AnyInput<'_, '_> {
+    /// Returns `true` if the player did anything this frame.
+    pub fn detected(&mut self) -> bool {
+        let moved = self.motion.read().any(|m| m.delta != Vec2::ZERO);
+        moved
+            || self.keyboard.get_just_pressed().next().is_some()
+            || self.mouse.get_just_pressed().next().is_some()
+            || self
+                .gamepads
+                .iter()
+                .any(|gamepad| gamepad.get_just_pressed().next().is_some())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` while a demo is playing.
+pub fn attract_active(attract: Option<Res<AttractMode>>) -> bool {
+    attract.is_some_and(|a| a.active)
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Starts the demo once the title screen has been idle for
+/// [`ATTRACT_IDLE_SECS`].  Runs while on the title screen.
+pub fn track_title_idle(
+    time: Res<Time>,
+    mut input: AnyInput,
+    mut attract: ResMut<AttractMode>,
+    mut transition: ResMut<ScreenTransition>,
+) {
+    if input.detected() {
+        attract.idle_secs = 0.0;
+        return;
+    }
+    attract.idle_secs += time.delta_secs();
+    if attract.idle_secs >= ATTRACT_IDLE_SECS && transition.is_idle() {
+        info!("Title idle for {ATTRACT_IDLE_SECS}s, starting attract mode");
+        *attract = AttractMode {
+            active: true,
+            ..default()
+        };
+        transition.start(AppState::Playing);
+    }
+}
+
+/// Returns to the title screen on any input or once the demo has run for
+/// [`ATTRACT_MAX_SECS`].  Runs while a demo is playing.
+pub fn end_attract_on_input(
+    time: Res<Time>,
+    mut input: AnyInput,
+    mut attract: ResMut<AttractMode>,
+    mut transition: ResMut<ScreenTransition>,
+) {
+    attract.elapsed += time.delta_secs();
+    if input.detected() || attract.elapsed >= ATTRACT_MAX_SECS {
+        transition.start(AppState::Title);
+    }
+}
+
+/// Returns to the title screen when the demo reaches its game over.
+pub fn end_attract_on_game_over(mut transition: ResMut<ScreenTransition>) {
+    transition.start(AppState::Title);
+}
+
+/// Leaves attract mode and restarts the idle timer on entering the title
+/// screen.
+pub fn reset_attract(mut attract: ResMut<AttractMode>) {
+    *attract = AttractMode::default();
+}
+
+/// Plays the demo: moves the held fruit to the bot's target and drops it.
+///
+/// Replaces `update_spawn_position` / `handle_fruit_drop_input`, which are
+/// disabled during a demo.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn drive_attract_bot(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut attract: ResMut<AttractMode>,
+    mut spawn_pos: ResMut<SpawnPosition>,
+    mut fruits: Query<(Entity, &FruitType, &mut Transform, &mut FruitSpawnState), With<Fruit>>,
+    fruits_config: FruitsParams,
+    physics_config: PhysicsParams,
+    mut dropped_events: MessageWriter<FruitDroppedEvent>,
+) {
+    let (Some(fruits_config), Some(physics_config)) = (fruits_config.get(), physics_config.get())
+    else {
+        return;
+    };
+
+    let Some((entity, fruit_type)) = fruits
+        .iter()
+        .find(|(_, _, _, state)| **state == FruitSpawnState::Held)
+        .map(|(entity, fruit_type, _, _)| (entity, *fruit_type))
+    else {
+        attract.target_x = None;
+        return;
+    };
+
+    let radius = fruit_type.parameters_from_config(fruits_config).radius;
+    let width = physics_config.container_width;
+    let target = match attract.target_x {
+        Some(target) => target,
+        None => {
+            let landed = fruits
+                .iter()
+                .filter(|(_, _, _, state)| **state == FruitSpawnState::Landed)
+                .map(|(_, fruit, transform, _)| (*fruit, transform.translation.truncate()));
+            let fallback = rand::rng().random_range(-width / 2.0..=width / 2.0);
+            let target = clamp_spawn_x(bot_target(fruit_type, landed, fallback), width, radius);
+            attract.target_x = Some(target);
+            attract.aim_secs = 0.0;
+            target
+        }
+    };
+
+    let speed = if physics_config.keyboard_move_speed > 0.0 {
+        physics_config.keyboard_move_speed
+    } else {
+        DEFAULT_BOT_MOVE_SPEED
+    };
+    spawn_pos.x = clamp_spawn_x(
+        step_toward(spawn_pos.x, target, speed * time.delta_secs()),
+        width,
+        radius,
+    );
+    attract.aim_secs += time.delta_secs();
+
+    let Ok((_, _, mut transform, mut state)) = fruits.get_mut(entity) else {
+        return;
+    };
+    transform.translation.x = spawn_pos.x;
+
+    if (spawn_pos.x - target).abs() <= BOT_TARGET_TOLERANCE && attract.aim_secs >= BOT_AIM_SECS {
+        release_held_fruit(
+            &mut commands,
+            entity,
+            fruit_type,
+            transform.translation.truncate(),
+            &mut state,
+            fruits_config,
+            physics_config,
+            &mut dropped_events,
+        );
+        attract.target_x = None;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Where the bot drops `fruit`: above the highest landed fruit of the same
+/// type, or `fallback` when there is none.
+fn bot_target(
+    fruit: FruitType,
+    landed: impl Iterator<Item = (FruitType, Vec2)>,
+    fallback: f32,
+) -> f32 {
+    landed
+        .filter(|(other, _)| *other == fruit)
+        .max_by(|(_, a), (_, b)| a.y.total_cmp(&b.y))
+        .map_or(fallback, |(_, position)| position.x)
+}
+
+/// Moves `current` toward `target` by at most `max_step`.
+fn step_toward(current: f32, target: f32, max_step: f32) -> f32 {
+    current + (target - current).clamp(-max_step, max_step)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bot_targets_highest_matching_fruit() {
+        let landed = [
+            (FruitType::Cherry, Vec2::new(-100.0, -200.0)),
+            (FruitType::Grape, Vec2::new(50.0, 0.0)),
+            (FruitType::Cherry, Vec2::new(80.0, -150.0)),
+        ];
+        assert_eq!(bot_target(FruitType::Cherry, landed.into_iter(), 0.0), 80.0);
+        assert_eq!(
+            bot_target(FruitType::Dekopon, landed.into_iter(), -12.0),
+            -12.0
+        );
+    }
+
+    #[test]
+    fn test_step_toward_limits_speed() {
+        assert_eq!(step_toward(0.0, 100.0, 10.0), 10.0);
+        assert_eq!(step_toward(0.0, -100.0, 10.0), -10.0);
+        assert_eq!(step_toward(95.0, 100.0, 10.0), 100.0);
+    }
+
+    #[test]
+    fn test_attract_active_condition() {
+        let mut world = World::new();
+        assert!(!world.run_system_cached(attract_active).unwrap());
+        world.insert_resource(AttractMode {
+            active: true,
+            ..default()
+        });
+        assert!(world.run_system_cached(attract_active).unwrap());
+    }
+
+    fn title_idle_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .add_message::<MouseMotion>()
+            .init_resource::<AttractMode>()
+            .init_resource::<ScreenTransition>()
+            .add_systems(Update, track_title_idle);
+        app
+    }
+
+    #[test]
+    fn test_idle_title_starts_demo() {
+        let mut app = title_idle_app();
+
+        app.world_mut().resource_mut::<AttractMode>().idle_secs = ATTRACT_IDLE_SECS;
+        app.update();
+
+        assert!(app.world().resource::<AttractMode>().active);
+        assert!(!app.world().resource::<ScreenTransition>().is_idle());
+    }
+
+    #[test]
+    fn test_key_press_resets_idle_timer() {
+        let mut app = title_idle_app();
+
+        app.world_mut().resource_mut::<AttractMode>().idle_secs = ATTRACT_IDLE_SECS - 1.0;
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Enter);
+        app.update();
+
+        let attract = app.world().resource::<AttractMode>();
+        assert!(!attract.active);
+        assert_eq!(attract.idle_secs, 0.0);
+    }
+}
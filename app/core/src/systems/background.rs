@@ -0,0 +1,207 @@
+//! Background throttling
+//!
+//! While the primary window is unfocused or minimized (occluded), the game
+//! drops to [`BACKGROUND_FPS`] and presents with vsync (see
+//! [`frame_pacing`](super::frame_pacing)) so a laptop left with the game in
+//! the background does not burn its battery.  The
+//! particle / flash / shake effects pause for the same period; they are
+//! registered with `run_if(not(background_throttled))`.
+//!
+//! Audio is left alone: the BGM keeps playing on the audio thread and the
+//! [`AppState`](crate::states::AppState) does not change, so the track, its
+//! fades and the volume settings are exactly as they were when the window
+//! regains focus.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowOccluded};
+use bevy::winit::{UpdateMode, WinitSettings};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Frame rate cap while the window is in the background.
+pub const BACKGROUND_FPS: f64 = 10.0;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Whether the game is currently throttled because its window is in the
+/// background.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct BackgroundThrottle {
+    /// The window is unfocused or minimized and the frame rate is capped
+    pub active: bool,
+    /// The window is minimized or fully covered
+    occluded: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` while the window is in the background.
+pub fn background_throttled(throttle: Option<Res<BackgroundThrottle>>) -> bool {
+    throttle.is_some_and(|t| t.active)
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Updates [`BackgroundThrottle::active`] from the primary window's focus and
+/// occlusion state.
+///
+/// Without a primary window (headless tests) the game is never throttled.
+pub fn track_window_background(
+    mut occluded_events: MessageReader<WindowOccluded>,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    mut throttle: ResMut<BackgroundThrottle>,
+) {
+    let Ok((primary, window)) = windows.single() else {
+        return;
+    };
+    for event in occluded_events.read() {
+        if event.window == primary {
+            throttle.occluded = event.occluded;
+        }
+    }
+
+    let active = !window.focused || throttle.occluded;
+    if throttle.active != active {
+        throttle.active = active;
+        if active {
+            info!("Window in background, capping frame rate at {BACKGROUND_FPS} FPS");
+        } else {
+            info!("Window in foreground, restoring frame rate");
+        }
+    }
+}
+
+/// Applies the background frame rate cap whenever [`BackgroundThrottle`]
+/// changes.
+///
+/// Throttled: both winit update modes become
+/// [`reactive_low_power`](UpdateMode::reactive_low_power) at
+/// [`BACKGROUND_FPS`], covering a minimized window that still has focus.
+/// Restored: back to [`WinitSettings::game`].
+///
+/// Registered with `run_if(resource_changed::<BackgroundThrottle>)`.
+pub fn apply_background_throttle(
+    throttle: Res<BackgroundThrottle>,
+    winit: Option<ResMut<WinitSettings>>,
+) {
+    if let Some(mut winit) = winit {
+        *winit = winit_settings(throttle.active);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Winit update modes for the foreground or background.
+fn winit_settings(background: bool) -> WinitSettings {
+    if background {
+        let low_power =
+            UpdateMode::reactive_low_power(Duration::from_secs_f64(1.0 / BACKGROUND_FPS));
+        WinitSettings {
+            focused_mode: low_power,
+            unfocused_mode: low_power,
+        }
+    } else {
+        WinitSettings::game()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn background_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<WindowOccluded>()
+            .init_resource::<BackgroundThrottle>()
+            .insert_resource(WinitSettings::game())
+            .add_systems(
+                Update,
+                (
+                    track_window_background,
+                    apply_background_throttle.run_if(resource_changed::<BackgroundThrottle>),
+                )
+                    .chain(),
+            );
+        app
+    }
+
+    fn spawn_window(app: &mut App, focused: bool) -> Entity {
+        app.world_mut()
+            .spawn((
+                Window {
+                    focused,
+                    ..default()
+                },
+                PrimaryWindow,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_unfocused_window_throttles() {
+        let mut app = background_app();
+        spawn_window(&mut app, false);
+        app.update();
+
+        assert!(app.world().resource::<BackgroundThrottle>().active);
+        assert!(matches!(
+            app.world().resource::<WinitSettings>().focused_mode,
+            UpdateMode::Reactive { .. }
+        ));
+    }
+
+    #[test]
+    fn test_refocus_restores_update_mode() {
+        let mut app = background_app();
+        let window = spawn_window(&mut app, false);
+        app.update();
+
+        app.world_mut().get_mut::<Window>(window).unwrap().focused = true;
+        app.update();
+
+        assert!(!app.world().resource::<BackgroundThrottle>().active);
+        assert!(matches!(
+            app.world().resource::<WinitSettings>().focused_mode,
+            UpdateMode::Continuous
+        ));
+    }
+
+    #[test]
+    fn test_minimized_window_throttles_while_focused() {
+        let mut app = background_app();
+        let window = spawn_window(&mut app, true);
+        app.update();
+        assert!(!app.world().resource::<BackgroundThrottle>().active);
+
+        app.world_mut().write_message(WindowOccluded {
+            window,
+            occluded: true,
+        });
+        app.update();
+        assert!(app.world().resource::<BackgroundThrottle>().active);
+    }
+
+    #[test]
+    fn test_no_window_never_throttles() {
+        let mut app = background_app();
+        app.update();
+        assert!(!app.world().resource::<BackgroundThrottle>().active);
+    }
+}
@@ -0,0 +1,642 @@
+//! Programmatic board control
+//!
+//! [`BoardCommands`] is a [`SystemParam`] that wraps the handful of
+//! operations tools, tests, the tutorial and debug tooling need to drive the
+//! board directly, so none of them have to know which component bundle a
+//! fruit needs:
+//!
+//! ```ignore
+//! fn setup_puzzle(mut board: BoardCommands) {
+//!     board.clear();
+//!     board.spawn_fruit(FruitType::Grape, Vec2::new(-40.0, -250.0), FruitSpawnState::Landed);
+//!     board.spawn_fruit(FruitType::Grape, Vec2::new(40.0, -250.0), FruitSpawnState::Landed);
+//!     board.set_score(1_000);
+//! }
+//! ```
+//!
+//! All changes go through [`Commands`] and therefore take effect when the
+//! command buffer is applied, like any other spawn.
+//!
+//! # Snapshots
+//!
+//! [`BoardSnapshot`] is the one serializable format for "the board at a
+//! point in time" — every fruit's type, position and velocity plus score,
+//! play time, the fruit in the player's hand and the fruits queued behind it.  [`capture_board`] builds
+//! one from queries, [`BoardCommands::restore_board`] puts it back.  Session
+//! save / continue and the emergency save use it; undo, replays and netcode
+//! should too instead of inventing their own format.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{ActiveEvents, Velocity};
+use serde::{Deserialize, Serialize};
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::{FruitsConfig, FruitsConfigHandle};
+use crate::events::FruitMergeEvent;
+use crate::fruit::FruitType;
+use crate::resources::{CircleTexture, FruitQueue, FruitSprites, GameState, NextFruitType};
+use crate::systems::spawn::spawn_fruit;
+
+// ---------------------------------------------------------------------------
+// Snapshot
+// ---------------------------------------------------------------------------
+
+/// A single fruit in a [`BoardSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardFruit {
+    /// Evolution stage of the fruit
+    pub fruit_type: FruitType,
+    /// World-space X position (pixels)
+    pub x: f32,
+    /// World-space Y position (pixels)
+    pub y: f32,
+    /// Rotation around the Z axis (radians)
+    pub rotation: f32,
+    /// Linear velocity in pixels per second (absent in older saves)
+    #[serde(default)]
+    pub linvel: [f32; 2],
+    /// Angular velocity in radians per second (absent in older saves)
+    #[serde(default)]
+    pub angvel: f32,
+}
+
+/// Serializable state of the board at a point in time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardSnapshot {
+    /// Score at capture time
+    pub score: u32,
+    /// Elapsed play time in seconds
+    pub elapsed_time: f32,
+    /// Fruits dropped so far (absent in sessions saved by older versions)
+    #[serde(default)]
+    pub drops: u32,
+    /// Head of the fruit queue: the fruit that goes back into the player's
+    /// hand on restore
+    pub held_fruit: FruitType,
+    /// Fruits after `held_fruit`, soonest first (absent in older saves)
+    #[serde(default)]
+    pub queue: Vec<FruitType>,
+    /// Every fruit that was already on the board
+    pub fruits: Vec<BoardFruit>,
+}
+
+/// Builds a [`BoardSnapshot`] from the current board.
+///
+/// `upcoming` is the [`NextFruitType`] followed by the [`FruitQueue`], as
+/// returned by [`FruitQueue::with_next`].  The held fruit (if any) is stored
+/// as `held_fruit` rather than as a board fruit so it goes back into the
+/// player's hand on restore, and all of `upcoming` is queued behind it.
+/// Without a held fruit the head of `upcoming` is used instead.  Fruits
+/// without a [`Velocity`] are captured at rest.
+pub fn capture_board<'a>(
+    game_state: &GameState,
+    upcoming: &[FruitType],
+    fruits: impl IntoIterator<
+        Item = (
+            &'a Transform,
+            &'a FruitType,
+            &'a FruitSpawnState,
+            Option<&'a Velocity>,
+        ),
+    >,
+) -> BoardSnapshot {
+    let mut held = None;
+    let mut captured = Vec::new();
+
+    for (transform, fruit_type, state, velocity) in fruits {
+        if *state == FruitSpawnState::Held {
+            held = Some(*fruit_type);
+            continue;
+        }
+        let velocity = velocity.copied().unwrap_or_else(Velocity::zero);
+        captured.push(BoardFruit {
+            fruit_type: *fruit_type,
+            x: transform.translation.x,
+            y: transform.translation.y,
+            rotation: transform.rotation.to_euler(EulerRot::XYZ).2,
+            linvel: velocity.linvel.to_array(),
+            angvel: velocity.angvel,
+        });
+    }
+
+    let (held_fruit, queue) = match (held, upcoming.split_first()) {
+        (Some(held), _) => (held, upcoming.to_vec()),
+        (None, Some((next, queue))) => (*next, queue.to_vec()),
+        (None, None) => (NextFruitType::default().get(), Vec::new()),
+    };
+
+    BoardSnapshot {
+        score: game_state.score,
+        elapsed_time: game_state.elapsed_time,
+        drops: game_state.drops,
+        held_fruit,
+        queue,
+        fruits: captured,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// BoardCommands
+// ---------------------------------------------------------------------------
+
+/// High-level board operations for tools and tests.
+#[derive(SystemParam)]
+pub struct BoardCommands<'w, 's> {
+    commands: Commands<'w, 's>,
+    fruits: Query<'w, 's, (Entity, &'static FruitType, &'static Transform), With<Fruit>>,
+    spawn_states: Query<'w, 's, (Entity, &'static FruitSpawnState), With<Fruit>>,
+    game_state: ResMut<'w, GameState>,
+    next_fruit: ResMut<'w, NextFruitType>,
+    queue: ResMut<'w, FruitQueue>,
+    fruits_handle: Res<'w, FruitsConfigHandle>,
+    fruits_assets: Res<'w, Assets<FruitsConfig>>,
+    circle_texture: Res<'w, CircleTexture>,
+    fruit_sprites: Option<Res<'w, FruitSprites>>,
+    merge_events: MessageWriter<'w, FruitMergeEvent>,
+}
+
+impl BoardCommands<'_, '_> {
+    /// Spawns a dynamic fruit of `fruit_type` at `position` in `state`.
+    ///
+    /// The fruit gets the full gameplay bundle (physics body, sprite, type,
+    /// spawn state and collision events), exactly like a merge result.
+    /// Returns `None` while the fruits config is still loading.
+    pub fn spawn_fruit(
+        &mut self,
+        fruit_type: FruitType,
+        position: Vec2,
+        state: FruitSpawnState,
+    ) -> Option<Entity> {
+        let config = self.fruits_assets.get(&self.fruits_handle.0)?;
+        let entity = spawn_fruit(
+            &mut self.commands,
+            fruit_type,
+            position,
+            config,
+            self.circle_texture.0.clone(),
+            self.fruit_sprites.as_deref(),
+        );
+        self.commands
+            .entity(entity)
+            .insert((fruit_type, state, ActiveEvents::COLLISION_EVENTS));
+        Some(entity)
+    }
+
+    /// Despawns every fruit on the board, including the held one.
+    ///
+    /// Returns the number of fruits removed.
+    pub fn clear(&mut self) -> usize {
+        let mut count = 0;
+        for (entity, _, _) in self.fruits.iter() {
+            self.commands.entity(entity).despawn();
+            count += 1;
+        }
+        count
+    }
+
+    /// Merges two fruits as if they had collided.
+    ///
+    /// Sends a [`FruitMergeEvent`] at their midpoint, so the regular merge,
+    /// score, effect and audio systems all react.  Returns `false` (and does
+    /// nothing) unless both entities are distinct fruits of the same type.
+    pub fn force_merge(&mut self, a: Entity, b: Entity) -> bool {
+        if a == b {
+            return false;
+        }
+        let (Ok((_, type_a, transform_a)), Ok((_, type_b, transform_b))) =
+            (self.fruits.get(a), self.fruits.get(b))
+        else {
+            return false;
+        };
+        if type_a != type_b {
+            return false;
+        }
+
+        let position =
+            (transform_a.translation.truncate() + transform_b.translation.truncate()) / 2.0;
+        self.merge_events.write(FruitMergeEvent {
+            entity1: a,
+            entity2: b,
+            fruit_type: *type_a,
+            position,
+        });
+        true
+    }
+
+    /// Replaces the board with `snapshot`.
+    ///
+    /// Clears every fruit, respawns the captured ones as
+    /// [`FruitSpawnState::Landed`] with their rotation and velocity, restores
+    /// score, play time and drops, and queues `held_fruit` as the next fruit
+    /// so `spawn_held_fruit` hands it back to the player, with the captured
+    /// [`FruitQueue`] behind it.  Returns `false`
+    /// (and changes nothing) while the fruits config is still loading.
+    pub fn restore_board(&mut self, snapshot: &BoardSnapshot) -> bool {
+        if self.fruits_assets.get(&self.fruits_handle.0).is_none() {
+            return false;
+        }
+        self.clear();
+
+        self.game_state.score = snapshot.score;
+        self.game_state.elapsed_time = snapshot.elapsed_time;
+        self.game_state.drops = snapshot.drops;
+        self.next_fruit.set(snapshot.held_fruit);
+        self.queue.restore(snapshot.queue.iter().copied());
+
+        for fruit in &snapshot.fruits {
+            let position = Vec2::new(fruit.x, fruit.y);
+            let Some(entity) =
+                self.spawn_fruit(fruit.fruit_type, position, FruitSpawnState::Landed)
+            else {
+                continue;
+            };
+            self.commands.entity(entity).insert((
+                Transform::from_translation(position.extend(0.0))
+                    .with_rotation(Quat::from_rotation_z(fruit.rotation)),
+                Velocity {
+                    linvel: Vec2::from_array(fruit.linvel),
+                    angvel: fruit.angvel,
+                },
+            ));
+        }
+        true
+    }
+
+    /// Queues `fruit_type` as the next fruit handed to the player.
+    pub fn set_next_fruit(&mut self, fruit_type: FruitType) {
+        if self.next_fruit.get() != fruit_type {
+            self.next_fruit.set(fruit_type);
+        }
+    }
+
+    /// Swaps the fruit in the player's hand for `fruit_type`.
+    ///
+    /// Despawns the held fruit and queues `fruit_type` as the next fruit, so
+    /// `spawn_held_fruit` hands it out on the following frame.  Returns
+    /// `false` when nothing is held (the last drop is still falling); the
+    /// fruit is queued either way.
+    pub fn replace_held_fruit(&mut self, fruit_type: FruitType) -> bool {
+        self.set_next_fruit(fruit_type);
+        let mut replaced = false;
+        for (entity, state) in self.spawn_states.iter() {
+            if *state == FruitSpawnState::Held {
+                self.commands.entity(entity).despawn();
+                replaced = true;
+            }
+        }
+        replaced
+    }
+
+    /// Overwrites the current score.
+    pub fn set_score(&mut self, score: u32) {
+        self.game_state.score = score;
+    }
+
+    /// Adds `points` to the score; negative points subtract.  The score
+    /// stays within `0..=u32::MAX`.
+    pub fn add_score(&mut self, points: i64) {
+        let score = i64::from(self.game_state.score).saturating_add(points);
+        self.game_state.score = score.clamp(0, i64::from(u32::MAX)) as u32;
+    }
+
+    /// Iterates over every fruit on the board as `(entity, type, position)`.
+    pub fn fruits(&self) -> impl Iterator<Item = (Entity, FruitType, Vec2)> + '_ {
+        self.fruits.iter().map(|(entity, fruit_type, transform)| {
+            (entity, *fruit_type, transform.translation.truncate())
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FruitConfigEntry;
+
+    fn setup_board_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<FruitMergeEvent>();
+        app.init_resource::<GameState>();
+        app.init_resource::<NextFruitType>();
+        app.init_resource::<FruitQueue>();
+        app.insert_resource(CircleTexture(Handle::default()));
+
+        let mut fruits_assets = Assets::<FruitsConfig>::default();
+        let handle = fruits_assets.add(FruitsConfig {
+            fruits: (0..11)
+                .map(|i| FruitConfigEntry {
+                    name: format!("Fruit{i}"),
+                    radius: 20.0 + i as f32 * 10.0,
+                    points: 10,
+                    ..Default::default()
+                })
+                .collect(),
+        });
+        app.insert_resource(fruits_assets);
+        app.insert_resource(FruitsConfigHandle(handle));
+        app
+    }
+
+    fn fruit_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<(), With<Fruit>>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn test_spawn_and_clear() {
+        let mut app = setup_board_app();
+
+        app.add_systems(Update, |mut board: BoardCommands| {
+            board.spawn_fruit(FruitType::Cherry, Vec2::ZERO, FruitSpawnState::Landed);
+            board.spawn_fruit(FruitType::Grape, Vec2::X, FruitSpawnState::Falling);
+            board.set_score(500);
+        });
+        app.update();
+        assert_eq!(fruit_count(&mut app), 2);
+        assert_eq!(app.world().resource::<GameState>().score, 500);
+
+        let mut app = setup_board_app();
+        app.world_mut()
+            .spawn((Fruit, FruitType::Cherry, Transform::default()));
+        app.add_systems(Update, |mut board: BoardCommands| {
+            assert_eq!(board.clear(), 1);
+        });
+        app.update();
+        assert_eq!(fruit_count(&mut app), 0);
+    }
+
+    #[test]
+    fn test_add_score_clamps() {
+        let mut app = setup_board_app();
+        app.add_systems(Update, |mut board: BoardCommands| {
+            board.set_score(100);
+            board.add_score(50);
+            board.add_score(-500);
+        });
+        app.update();
+        assert_eq!(app.world().resource::<GameState>().score, 0);
+    }
+
+    #[test]
+    fn test_replace_held_fruit() {
+        let mut app = setup_board_app();
+        let held = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                FruitType::Cherry,
+                FruitSpawnState::Held,
+                Transform::default(),
+            ))
+            .id();
+        let landed = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                FruitType::Cherry,
+                FruitSpawnState::Landed,
+                Transform::default(),
+            ))
+            .id();
+
+        app.add_systems(Update, |mut board: BoardCommands| {
+            board.replace_held_fruit(FruitType::Peach);
+        });
+        app.update();
+
+        assert!(app.world().get_entity(held).is_err());
+        assert!(app.world().get_entity(landed).is_ok());
+        assert_eq!(
+            app.world().resource::<NextFruitType>().get(),
+            FruitType::Peach
+        );
+    }
+
+    #[test]
+    fn test_force_merge_requires_matching_types() {
+        let mut app = setup_board_app();
+        let a = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                FruitType::Cherry,
+                Transform::from_xyz(-10.0, 0.0, 0.0),
+            ))
+            .id();
+        let b = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                FruitType::Cherry,
+                Transform::from_xyz(10.0, 0.0, 0.0),
+            ))
+            .id();
+        let c = app
+            .world_mut()
+            .spawn((Fruit, FruitType::Grape, Transform::default()))
+            .id();
+
+        app.add_systems(Update, move |mut board: BoardCommands| {
+            assert!(!board.force_merge(a, a));
+            assert!(!board.force_merge(a, c));
+            assert!(board.force_merge(a, b));
+        });
+        app.update();
+
+        let events = app.world().resource::<Messages<FruitMergeEvent>>();
+        let mut cursor = events.get_cursor();
+        let sent: Vec<_> = cursor.read(events).collect();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].fruit_type, FruitType::Cherry);
+        assert_eq!(sent[0].position, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_capture_board_separates_held_fruit() {
+        let game_state = GameState {
+            score: 420,
+            elapsed_time: 12.0,
+            ..default()
+        };
+        let held = (
+            Transform::from_xyz(0.0, 250.0, 0.0),
+            FruitType::Grape,
+            FruitSpawnState::Held,
+        );
+        let landed = (
+            Transform::from_xyz(-30.0, -200.0, 0.0),
+            FruitType::Apple,
+            FruitSpawnState::Landed,
+        );
+        let falling = (
+            Transform::from_xyz(20.0, 100.0, 0.0),
+            FruitType::Cherry,
+            FruitSpawnState::Falling,
+        );
+        let velocity = Velocity {
+            linvel: Vec2::new(5.0, -80.0),
+            angvel: 0.5,
+        };
+
+        let snapshot = capture_board(
+            &game_state,
+            &[FruitType::Strawberry, FruitType::Cherry, FruitType::Dekopon],
+            [
+                (&held.0, &held.1, &held.2, None),
+                (&landed.0, &landed.1, &landed.2, None),
+                (&falling.0, &falling.1, &falling.2, Some(&velocity)),
+            ],
+        );
+
+        assert_eq!(snapshot.score, 420);
+        assert_eq!(snapshot.elapsed_time, 12.0);
+        assert_eq!(snapshot.held_fruit, FruitType::Grape);
+        assert_eq!(
+            snapshot.queue,
+            vec![FruitType::Strawberry, FruitType::Cherry, FruitType::Dekopon]
+        );
+        assert_eq!(snapshot.fruits.len(), 2);
+        assert_eq!(snapshot.fruits[0].fruit_type, FruitType::Apple);
+        assert_eq!(snapshot.fruits[0].x, -30.0);
+        assert_eq!(snapshot.fruits[0].linvel, [0.0, 0.0]);
+        assert_eq!(snapshot.fruits[1].fruit_type, FruitType::Cherry);
+        assert_eq!(snapshot.fruits[1].linvel, [5.0, -80.0]);
+        assert_eq!(snapshot.fruits[1].angvel, 0.5);
+    }
+
+    #[test]
+    fn test_capture_board_without_held_uses_next_fruit() {
+        let snapshot = capture_board(
+            &GameState::default(),
+            &[FruitType::Dekopon, FruitType::Grape, FruitType::Cherry],
+            std::iter::empty(),
+        );
+        assert_eq!(snapshot.held_fruit, FruitType::Dekopon);
+        assert_eq!(snapshot.queue, vec![FruitType::Grape, FruitType::Cherry]);
+        assert!(snapshot.fruits.is_empty());
+    }
+
+    #[test]
+    fn test_capture_board_stores_rotation() {
+        let fruit = (
+            Transform::from_rotation(Quat::from_rotation_z(0.75)),
+            FruitType::Peach,
+            FruitSpawnState::Landed,
+        );
+        let snapshot = capture_board(
+            &GameState::default(),
+            &[FruitType::Cherry],
+            std::iter::once((&fruit.0, &fruit.1, &fruit.2, None)),
+        );
+        assert!((snapshot.fruits[0].rotation - 0.75).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_board_fruit_without_velocity_deserializes() {
+        let json = r#"{ "fruit_type": "Cherry", "x": 1.0, "y": 2.0, "rotation": 0.0 }"#;
+        let fruit: BoardFruit = serde_json::from_str(json).unwrap();
+        assert_eq!(fruit.linvel, [0.0, 0.0]);
+        assert_eq!(fruit.angvel, 0.0);
+    }
+
+    #[test]
+    fn test_restore_board_replaces_fruits_and_state() {
+        let mut app = setup_board_app();
+        app.world_mut()
+            .spawn((Fruit, FruitType::Melon, Transform::default()));
+        let snapshot = BoardSnapshot {
+            score: 900,
+            elapsed_time: 30.0,
+            drops: 12,
+            held_fruit: FruitType::Persimmon,
+            queue: vec![FruitType::Cherry, FruitType::Grape, FruitType::Strawberry],
+            fruits: vec![BoardFruit {
+                fruit_type: FruitType::Grape,
+                x: 10.0,
+                y: -100.0,
+                rotation: 0.25,
+                linvel: [0.0, -50.0],
+                angvel: 1.0,
+            }],
+        };
+
+        app.add_systems(Update, move |mut board: BoardCommands| {
+            assert!(board.restore_board(&snapshot));
+        });
+        app.update();
+
+        let game_state = app.world().resource::<GameState>();
+        assert_eq!((game_state.score, game_state.drops), (900, 12));
+        assert_eq!(
+            app.world().resource::<NextFruitType>().get(),
+            FruitType::Persimmon
+        );
+        assert_eq!(
+            app.world()
+                .resource::<FruitQueue>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![FruitType::Cherry, FruitType::Grape, FruitType::Strawberry]
+        );
+        let fruits: Vec<_> = app
+            .world_mut()
+            .query_filtered::<(&FruitType, &Transform, &Velocity, &FruitSpawnState), With<Fruit>>()
+            .iter(app.world())
+            .map(|(f, t, v, s)| (*f, t.translation.truncate(), v.linvel, *s))
+            .collect();
+        assert_eq!(
+            fruits,
+            vec![(
+                FruitType::Grape,
+                Vec2::new(10.0, -100.0),
+                Vec2::new(0.0, -50.0),
+                FruitSpawnState::Landed
+            )]
+        );
+    }
+
+    #[test]
+    fn test_restore_board_hands_out_the_captured_queue() {
+        let mut app = setup_board_app();
+        let held = (
+            Transform::default(),
+            FruitType::Grape,
+            FruitSpawnState::Held,
+        );
+        let upcoming = [FruitType::Cherry, FruitType::Dekopon, FruitType::Apple];
+        let snapshot = capture_board(
+            &GameState::default(),
+            &upcoming,
+            std::iter::once((&held.0, &held.1, &held.2, None)),
+        );
+
+        app.add_systems(Update, move |mut board: BoardCommands| {
+            assert!(board.restore_board(&snapshot));
+        });
+        app.update();
+
+        // Handing out the held Grape moves the captured sequence forward
+        let world = app.world_mut();
+        let mut next = world.resource::<NextFruitType>().clone();
+        assert_eq!(next.get(), FruitType::Grape);
+        let mut queue = world.resource_mut::<FruitQueue>();
+        queue.advance(&mut next, 5);
+        assert_eq!(next.get(), FruitType::Cherry);
+        let queued: Vec<_> = queue.iter().collect();
+        assert_eq!(queued, vec![FruitType::Dekopon, FruitType::Apple]);
+    }
+
+    #[test]
+    fn test_snapshot_without_queue_deserializes() {
+        let json = r#"{ "score": 10, "elapsed_time": 1.0, "held_fruit": "Cherry", "fruits": [] }"#;
+        let snapshot: BoardSnapshot = serde_json::from_str(json).unwrap();
+        assert!(snapshot.queue.is_empty());
+    }
+}
@@ -3,12 +3,14 @@
 //! This module monitors whether any in-play fruit has risen above the
 //! boundary line.  When the overflow condition persists for the warning
 //! threshold (default 0.5 s) the game transitions to `AppState::GameOver`.
-//! While in warning state the boundary line sprite blinks red.
+//! The UI draws the line itself and escalates its style with
+//! [`GameOverTimer::warning_progress`].
 
 use bevy::prelude::*;
 
-use crate::components::{BoundaryLine, Fruit, FruitSpawnState};
+use crate::components::{Fruit, FruitSpawnState};
 use crate::config::{PhysicsConfig, PhysicsConfigHandle};
+use crate::events::BoundaryWarningEvent;
 use crate::resources::GameOverTimer;
 use crate::states::AppState;
 
@@ -43,12 +45,15 @@ fn boundary_y(
 /// threshold (0.5 s default) filters out the brief window when a newly
 /// dropped fruit passes through the boundary area before settling.
 /// When no overflow is detected the timer resets.
+///
+/// A [`BoundaryWarningEvent`] is sent whenever the warning state flips.
 pub fn check_boundary_overflow(
     fruit_query: Query<(&Transform, &FruitSpawnState), With<Fruit>>,
     mut game_over_timer: ResMut<GameOverTimer>,
     time: Res<Time>,
     physics_handle: Option<Res<PhysicsConfigHandle>>,
     physics_assets: Option<Res<Assets<PhysicsConfig>>>,
+    mut warning_events: MessageWriter<BoundaryWarningEvent>,
 ) {
     let threshold = boundary_y(physics_handle.as_ref(), physics_assets.as_ref());
 
@@ -58,11 +63,23 @@ pub fn check_boundary_overflow(
         .filter(|(_, state)| **state != FruitSpawnState::Held)
         .any(|(t, _)| t.translation.y > threshold);
 
+    let was_warning = game_over_timer.is_warning;
+
     if any_overflow {
         game_over_timer.tick_warning(time.delta_secs());
     } else {
         game_over_timer.reset();
     }
+
+    match (was_warning, game_over_timer.is_warning) {
+        (false, true) => {
+            warning_events.write(BoundaryWarningEvent::Started);
+        }
+        (true, false) => {
+            warning_events.write(BoundaryWarningEvent::Cleared);
+        }
+        _ => {}
+    }
 }
 
 /// Transitions to `AppState::GameOver` when the timer exceeds its threshold.
@@ -83,28 +100,6 @@ pub fn trigger_game_over(
     }
 }
 
-/// Animates the boundary line sprite to blink red while in warning state.
-///
-/// - Warning active: alternates between bright and dark red at ~2 Hz
-/// - Warning inactive: restores the default semi-transparent red color
-pub fn animate_boundary_warning(
-    game_over_timer: Res<GameOverTimer>,
-    mut boundary_query: Query<&mut Sprite, With<BoundaryLine>>,
-    time: Res<Time>,
-) {
-    for mut sprite in boundary_query.iter_mut() {
-        if game_over_timer.is_warning {
-            // Smooth sine-based blink at ~2 Hz
-            let blink = (time.elapsed_secs() * 4.0).sin();
-            let alpha = 0.4 + 0.4 * blink; // range [0.0, 0.8]
-            sprite.color = Color::srgba(1.0, 0.0, 0.0, alpha);
-        } else {
-            // Default: semi-transparent red
-            sprite.color = Color::srgba(1.0, 0.0, 0.0, 0.5);
-        }
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -133,4 +128,36 @@ mod tests {
         assert_eq!(timer.time_over_boundary, 0.0);
         assert!(!timer.is_game_over());
     }
+
+    #[test]
+    fn test_check_boundary_overflow_sends_warning_transitions() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<BoundaryWarningEvent>();
+        app.init_resource::<GameOverTimer>();
+        app.add_systems(Update, check_boundary_overflow);
+
+        let fruit = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                FruitSpawnState::Landed,
+                Transform::from_xyz(0.0, 10_000.0, 0.0),
+            ))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .entity_mut(fruit)
+            .insert(Transform::from_xyz(0.0, -10_000.0, 0.0));
+        app.update();
+
+        let events = app.world().resource::<Messages<BoundaryWarningEvent>>();
+        let mut cursor = events.get_cursor();
+        let sent: Vec<_> = cursor.read(events).copied().collect();
+        assert_eq!(
+            sent,
+            vec![BoundaryWarningEvent::Started, BoundaryWarningEvent::Cleared]
+        );
+    }
 }
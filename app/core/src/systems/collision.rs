@@ -1,7 +1,9 @@
 //! Fruit collision detection system
 //!
-//! This module polls Rapier2D contact pairs every frame to detect when two fruits
-//! of the same type are touching, triggering the merge system via `FruitMergeEvent`.
+//! This module detects, every frame, when two fruits of the same type are
+//! touching and triggers the merge system via `FruitMergeEvent`.  Merges of a
+//! fruit that was itself just born from a merge additionally send a
+//! `ChainMergeEvent` carrying the chain depth.
 //!
 //! # Why polling instead of `CollisionEvent::Started`
 //!
@@ -10,18 +12,42 @@
 //! (e.g. squeezed by a third fruit landing on top) without ever generating a new
 //! `Started` event. The merge would be permanently missed.
 //!
-//! By polling `rapier_context.simulation.contact_pairs()` each frame we catch
-//! all *currently active* contacts, so no merge opportunity is ever skipped.
+//! Polling the *currently active* contacts each frame catches every merge
+//! opportunity.
+//!
+//! # Spatial-bucket pre-filter
+//!
+//! Scanning every Rapier contact pair scales with the total number of
+//! contacts, and on a late-game board almost all of them are fruit–wall or
+//! different-type pairs that can never merge.  [`MergeBuckets`] instead hashes
+//! fruits into a uniform grid per fruit stage, with cells just larger than
+//! that stage's diameter.  Only same-stage fruits in neighbouring cells whose
+//! circles (nearly) overlap become candidates, and only those are checked
+//! against Rapier's contact graph.  Building and querying the buckets is
+//! O(n) for n fruits.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use bevy::prelude::*;
-use bevy_rapier2d::prelude::ReadRapierContext;
+use bevy_rapier2d::prelude::{Collider, ReadRapierContext};
 
-use crate::components::{Fruit, FruitSpawnState, MergeCandidate};
-use crate::events::FruitMergeEvent;
+use crate::components::{ChainLink, Fruit, FruitSpawnState, MergeCandidate};
+use crate::events::{ChainMergeEvent, FruitMergeEvent};
 use crate::fruit::FruitType;
 
+/// Extra distance in pixels tolerated between two fruit surfaces when
+/// picking candidates, so contacts reported with a small gap are not missed.
+const CONTACT_MARGIN: f32 = 2.0;
+
+/// Cell offsets visited from each cell so every neighbouring pair of cells is
+/// examined exactly once (the cell itself plus four of its eight neighbours).
+const HALF_NEIGHBOURHOOD: [IVec2; 4] = [
+    IVec2::new(1, 0),
+    IVec2::new(-1, 1),
+    IVec2::new(0, 1),
+    IVec2::new(1, 1),
+];
+
 /// Resource tracking entity pairs processed this frame to prevent duplicate merge events
 ///
 /// Stores normalized (min, max) entity pairs to ensure each collision is only
@@ -31,17 +57,155 @@ pub struct ProcessedCollisions {
     pub pairs: HashSet<(Entity, Entity)>,
 }
 
+// ---------------------------------------------------------------------------
+// Spatial buckets
+// ---------------------------------------------------------------------------
+
+/// A fruit as seen by the bucket pre-filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BucketedFruit {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub radius: f32,
+}
+
+/// Uniform grid for one fruit stage.
+#[derive(Debug)]
+struct StageGrid {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<BucketedFruit>>,
+}
+
+/// Per-stage spatial hash used to find same-type fruits that may be touching.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use suika_game_core::fruit::FruitType;
+/// # use suika_game_core::systems::collision::{BucketedFruit, MergeBuckets};
+/// let fruit = |bits, x| BucketedFruit {
+///     entity: Entity::from_bits(bits),
+///     position: Vec2::new(x, 0.0),
+///     radius: 10.0,
+/// };
+/// let buckets = MergeBuckets::build([
+///     (FruitType::Cherry, fruit(1, 0.0)),
+///     (FruitType::Cherry, fruit(2, 19.0)),  // touching fruit 1
+///     (FruitType::Cherry, fruit(3, 200.0)), // far away
+///     (FruitType::Grape, fruit(4, 10.0)),   // different stage
+/// ]);
+/// assert_eq!(buckets.candidate_pairs().len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct MergeBuckets {
+    stages: HashMap<FruitType, StageGrid>,
+}
+
+impl MergeBuckets {
+    /// Buckets `fruits` by stage and grid cell.
+    ///
+    /// Each stage's cell size is its largest diameter plus [`CONTACT_MARGIN`],
+    /// so any two overlapping fruits of that stage share a cell or sit in
+    /// adjacent cells.
+    pub fn build(fruits: impl IntoIterator<Item = (FruitType, BucketedFruit)>) -> Self {
+        let mut grouped: HashMap<FruitType, Vec<BucketedFruit>> = HashMap::new();
+        for (fruit_type, fruit) in fruits {
+            grouped.entry(fruit_type).or_default().push(fruit);
+        }
+
+        let stages = grouped
+            .into_iter()
+            // A lone fruit of a stage has nothing to merge with
+            .filter(|(_, fruits)| fruits.len() >= 2)
+            .map(|(fruit_type, fruits)| {
+                let max_radius = fruits.iter().map(|f| f.radius).fold(0.0, f32::max);
+                let cell_size = (2.0 * max_radius + CONTACT_MARGIN).max(1.0);
+                let mut cells: HashMap<IVec2, Vec<BucketedFruit>> = HashMap::new();
+                for fruit in fruits {
+                    cells
+                        .entry(cell_of(fruit.position, cell_size))
+                        .or_default()
+                        .push(fruit);
+                }
+                (fruit_type, StageGrid { cell_size, cells })
+            })
+            .collect();
+
+        Self { stages }
+    }
+
+    /// Returns every same-stage pair whose circles are within
+    /// [`CONTACT_MARGIN`] of each other.
+    ///
+    /// Pairs are normalized (smaller entity first) and sorted so the result
+    /// does not depend on hash-map iteration order.
+    pub fn candidate_pairs(&self) -> Vec<(FruitType, BucketedFruit, BucketedFruit)> {
+        let mut pairs = Vec::new();
+
+        for (&fruit_type, grid) in &self.stages {
+            for (cell, fruits) in &grid.cells {
+                // Pairs inside the same cell
+                for (i, a) in fruits.iter().enumerate() {
+                    for b in &fruits[i + 1..] {
+                        push_if_close(&mut pairs, fruit_type, a, b);
+                    }
+                }
+                // Pairs with the forward half of the neighbourhood
+                for offset in HALF_NEIGHBOURHOOD {
+                    let Some(neighbours) = grid.cells.get(&(*cell + offset)) else {
+                        continue;
+                    };
+                    for a in fruits {
+                        for b in neighbours {
+                            push_if_close(&mut pairs, fruit_type, a, b);
+                        }
+                    }
+                }
+            }
+        }
+
+        pairs.sort_by_key(|(_, a, b)| (a.entity, b.entity));
+        pairs
+    }
+}
+
+/// Grid cell containing `position`.
+fn cell_of(position: Vec2, cell_size: f32) -> IVec2 {
+    (position / cell_size).floor().as_ivec2()
+}
+
+/// Pushes the normalized pair `(a, b)` when the two circles (nearly) touch.
+fn push_if_close(
+    pairs: &mut Vec<(FruitType, BucketedFruit, BucketedFruit)>,
+    fruit_type: FruitType,
+    a: &BucketedFruit,
+    b: &BucketedFruit,
+) {
+    let reach = a.radius + b.radius + CONTACT_MARGIN;
+    if a.position.distance_squared(b.position) > reach * reach {
+        return;
+    }
+    if a.entity < b.entity {
+        pairs.push((fruit_type, *a, *b));
+    } else {
+        pairs.push((fruit_type, *b, *a));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
 /// Detects active contacts between fruits of the same type and fires `FruitMergeEvent`
 ///
-/// Each frame this system polls `rapier_context.simulation.contact_pairs()` and
-/// checks whether both entities are fruits with the same `FruitType`. When a valid
-/// merge is detected, both fruits are marked with `MergeCandidate` and a
+/// Each frame this system buckets every dropped fruit with [`MergeBuckets`]
+/// and asks Rapier whether each candidate pair has an active contact. When a
+/// valid merge is detected, both fruits are marked with `MergeCandidate` and a
 /// `FruitMergeEvent` is sent.
 ///
 /// # Why polling
 ///
 /// Using `CollisionEvent::Started` misses merges that occur when fruits are slowly
-/// pressed together (no new contact-start event fires). Polling active contact pairs
+/// pressed together (no new contact-start event fires). Polling active contacts
 /// every frame ensures every touching same-type pair is eventually merged.
 ///
 /// # Deduplication
@@ -55,24 +219,54 @@ pub struct ProcessedCollisions {
 ///
 /// # Conditions for a merge
 ///
-/// - The contact pair must have at least one active contact point
-/// - Both entities must have the `Fruit` component
+/// - Both entities must have the `Fruit` component and a ball collider
 /// - Neither entity may already be a `MergeCandidate`
 /// - Neither may be in `FruitSpawnState::Held` state (still aimed by the player)
 /// - Both must have the same `FruitType`
+/// - Their contact pair must have at least one active contact point
+///
+/// # Chains
+///
+/// When either fruit still carries a [`ChainLink`] from the merge that
+/// produced it, a [`ChainMergeEvent`] with the resulting depth is sent next
+/// to the `FruitMergeEvent`.
 #[allow(clippy::type_complexity)]
 pub fn detect_fruit_contact(
     mut commands: Commands,
     rapier_context: ReadRapierContext,
-    fruit_query: Query<(&FruitType, &FruitSpawnState), (With<Fruit>, Without<MergeCandidate>)>,
-    transform_query: Query<&Transform>,
+    fruit_query: Query<
+        (Entity, &FruitType, &FruitSpawnState, &Transform, &Collider),
+        (With<Fruit>, Without<MergeCandidate>),
+    >,
+    chain_links: Query<&ChainLink>,
     mut merge_events: MessageWriter<FruitMergeEvent>,
+    mut chain_events: MessageWriter<ChainMergeEvent>,
     mut processed: ResMut<ProcessedCollisions>,
 ) {
     let Ok(ctx) = rapier_context.single() else {
         return;
     };
 
+    let buckets = MergeBuckets::build(fruit_query.iter().filter_map(
+        |(entity, fruit_type, state, transform, collider)| {
+            // Fruits still held by the player (not yet dropped) never merge
+            if *state == FruitSpawnState::Held {
+                return None;
+            }
+            // Colliders follow the transform scale (e.g. the merge pop animation)
+            let scale = transform.scale.x.max(transform.scale.y);
+            let radius = collider.as_ball()?.radius() * scale;
+            Some((
+                *fruit_type,
+                BucketedFruit {
+                    entity,
+                    position: transform.translation.truncate(),
+                    radius,
+                },
+            ))
+        },
+    ));
+
     // Tracks individual entities already claimed for a merge this frame.
     // MergeCandidate is inserted via deferred Commands, so Without<MergeCandidate>
     // won't filter out a just-claimed entity until the next command flush.
@@ -81,26 +275,9 @@ pub fn detect_fruit_contact(
     // otherwise cause double scoring.
     let mut claimed: HashSet<Entity> = HashSet::new();
 
-    for contact_pair in ctx
-        .simulation
-        .contact_pairs(ctx.colliders, ctx.rigidbody_set)
-    {
-        // Only consider pairs with at least one active contact point
-        if !contact_pair.has_any_active_contact() {
-            continue;
-        }
-
-        let (Some(entity1), Some(entity2)) = (contact_pair.collider1(), contact_pair.collider2())
-        else {
-            continue;
-        };
-
-        // Normalize pair to prevent duplicate processing (smaller entity first)
-        let pair = if entity1 < entity2 {
-            (entity1, entity2)
-        } else {
-            (entity2, entity1)
-        };
+    for (fruit_type, a, b) in buckets.candidate_pairs() {
+        let (entity1, entity2) = (a.entity, b.entity);
+        let pair = (entity1, entity2);
 
         if processed.pairs.contains(&pair) {
             continue;
@@ -111,48 +288,17 @@ pub fn detect_fruit_contact(
             continue;
         }
 
-        // Check both entities are fruits not already merging
-        let Ok((type1, state1)) = fruit_query.get(entity1) else {
-            continue;
-        };
-        let Ok((type2, state2)) = fruit_query.get(entity2) else {
-            continue;
-        };
-
-        // Skip fruits still held by the player (not yet dropped)
-        if *state1 == FruitSpawnState::Held || *state2 == FruitSpawnState::Held {
+        // Only merge pairs Rapier reports as actually touching
+        let touching = ctx
+            .simulation
+            .contact_pair(ctx.colliders, ctx.rigidbody_set, entity1, entity2)
+            .is_some_and(|contact_pair| contact_pair.has_any_active_contact());
+        if !touching {
             continue;
         }
 
-        // Only merge fruits of the same type
-        if type1 != type2 {
-            continue;
-        }
-
-        let fruit_type = *type1;
-
-        // Calculate merge position as midpoint between the two fruits
-        let pos1 = match transform_query.get(entity1) {
-            Ok(t) => t.translation.truncate(),
-            Err(_) => {
-                warn!(
-                    "detect_fruit_contact: entity1 {:?} has no Transform",
-                    entity1
-                );
-                Vec2::ZERO
-            }
-        };
-        let pos2 = match transform_query.get(entity2) {
-            Ok(t) => t.translation.truncate(),
-            Err(_) => {
-                warn!(
-                    "detect_fruit_contact: entity2 {:?} has no Transform",
-                    entity2
-                );
-                Vec2::ZERO
-            }
-        };
-        let position = (pos1 + pos2) / 2.0;
+        // Merge position is the midpoint between the two fruits
+        let position = (a.position + b.position) / 2.0;
 
         // Mark both fruits as merge candidates to prevent further collision processing
         commands.entity(entity1).insert(MergeCandidate);
@@ -169,6 +315,19 @@ pub fn detect_fruit_contact(
             position,
         });
 
+        let depth =
+            ChainLink::merge_depth(chain_links.get(entity1).ok(), chain_links.get(entity2).ok());
+        if depth > 0 {
+            chain_events.write(ChainMergeEvent {
+                entity1,
+                entity2,
+                fruit_type,
+                position,
+                depth,
+            });
+            info!("Chain merge x{depth}: {fruit_type:?} at {position:?}");
+        }
+
         info!(
             "Merge detected: {:?} + {:?} = {:?} at {:?}",
             fruit_type,
@@ -234,4 +393,126 @@ mod tests {
         processed.pairs.clear();
         assert!(processed.pairs.is_empty());
     }
+
+    fn bucketed(bits: u64, x: f32, y: f32, radius: f32) -> BucketedFruit {
+        BucketedFruit {
+            entity: Entity::from_bits(bits),
+            position: Vec2::new(x, y),
+            radius,
+        }
+    }
+
+    /// Reference O(n²) scan used to validate (and benchmark) the buckets.
+    fn brute_force_pairs(
+        fruits: &[(FruitType, BucketedFruit)],
+    ) -> Vec<(FruitType, BucketedFruit, BucketedFruit)> {
+        let mut pairs = Vec::new();
+        for (i, (type_a, a)) in fruits.iter().enumerate() {
+            for (type_b, b) in &fruits[i + 1..] {
+                if type_a == type_b {
+                    push_if_close(&mut pairs, *type_a, a, b);
+                }
+            }
+        }
+        pairs.sort_by_key(|(_, a, b)| (a.entity, b.entity));
+        pairs
+    }
+
+    /// Deterministic pseudo-random board of `count` fruits packed into a
+    /// 600 × 800 container.
+    fn packed_board(count: u64) -> Vec<(FruitType, BucketedFruit)> {
+        let types = [
+            FruitType::Cherry,
+            FruitType::Strawberry,
+            FruitType::Grape,
+            FruitType::Dekopon,
+            FruitType::Persimmon,
+        ];
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % 10_000) as f32 / 10_000.0
+        };
+        (0..count)
+            .map(|i| {
+                let fruit_type = types[(i % types.len() as u64) as usize];
+                let radius = 15.0 + 5.0 * fruit_type as u8 as f32;
+                (
+                    fruit_type,
+                    bucketed(
+                        i + 1,
+                        next() * 600.0 - 300.0,
+                        next() * 800.0 - 400.0,
+                        radius,
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_buckets_finds_touching_same_type_only() {
+        let buckets = MergeBuckets::build([
+            (FruitType::Cherry, bucketed(1, 0.0, 0.0, 10.0)),
+            (FruitType::Cherry, bucketed(2, 20.0, 0.0, 10.0)),
+            (FruitType::Cherry, bucketed(3, 100.0, 0.0, 10.0)),
+            (FruitType::Grape, bucketed(4, 10.0, 5.0, 10.0)),
+        ]);
+        let pairs = buckets.candidate_pairs();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].0, FruitType::Cherry);
+        assert_eq!(pairs[0].1.entity, Entity::from_bits(1));
+        assert_eq!(pairs[0].2.entity, Entity::from_bits(2));
+    }
+
+    #[test]
+    fn test_merge_buckets_across_cell_boundaries() {
+        // Straddles cell boundaries in x, y and diagonally (negative coords too)
+        let buckets = MergeBuckets::build([
+            (FruitType::Apple, bucketed(1, -1.0, -1.0, 30.0)),
+            (FruitType::Apple, bucketed(2, 40.0, 40.0, 30.0)),
+        ]);
+        assert_eq!(buckets.candidate_pairs().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_buckets_match_brute_force() {
+        let board = packed_board(400);
+        let expected = brute_force_pairs(&board);
+        let actual = MergeBuckets::build(board.iter().copied()).candidate_pairs();
+        assert!(!expected.is_empty());
+        assert_eq!(actual, expected);
+    }
+
+    /// Timing comparison on a 300+ fruit board.
+    ///
+    /// Run with `cargo test -p suika-game-core --release -- --ignored --nocapture bench_`.
+    #[test]
+    #[ignore]
+    fn bench_merge_buckets_vs_brute_force() {
+        use std::time::Instant;
+
+        const ITERATIONS: u32 = 200;
+
+        for count in [100, 300, 1_000] {
+            let board = packed_board(count);
+
+            let start = Instant::now();
+            for _ in 0..ITERATIONS {
+                std::hint::black_box(brute_force_pairs(std::hint::black_box(&board)));
+            }
+            let brute = start.elapsed() / ITERATIONS;
+
+            let start = Instant::now();
+            for _ in 0..ITERATIONS {
+                let buckets = MergeBuckets::build(std::hint::black_box(&board).iter().copied());
+                std::hint::black_box(buckets.candidate_pairs());
+            }
+            let bucketed = start.elapsed() / ITERATIONS;
+
+            println!("{count:>5} fruits: brute force {brute:?}, buckets {bucketed:?}");
+        }
+    }
 }
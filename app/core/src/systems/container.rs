@@ -144,6 +144,10 @@ mod tests {
             fruit_linear_damping: 0.5,
             fruit_angular_damping: 1.0,
             keyboard_move_speed: 300.0,
+            ccd_speed_threshold: 600.0,
+            max_fruit_speed: 2500.0,
+            sleep_linear_threshold: 0.6,
+            sleep_angular_threshold: 0.8,
         };
         let handle = physics_assets.add(physics_config);
 
@@ -0,0 +1,139 @@
+//! Control source
+//!
+//! [`ControlSource`] says who is playing the board.  The player's aim and
+//! drop input systems only run for [`ControlSource::Local`]; every other
+//! source turns the game into a spectator view that can be watched without
+//! any input reaching the board:
+//!
+//! | Source    | Driven by                                      |
+//! |-----------|------------------------------------------------|
+//! | `Local`   | keyboard / mouse / gamepad of this machine     |
+//! | `Bot`     | the attract-mode bot (`drive_attract_bot`)     |
+//! | `Replay`  | a recorded game being played back              |
+//! | `Network` | a remote player being spectated                |
+//!
+//! Attract mode switches the source to `Bot` for the length of a demo via
+//! [`sync_attract_control`].  The UI offers a free camera while spectating.
+
+use bevy::prelude::*;
+
+use crate::systems::attract::AttractMode;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Who drives the held fruit in the current game.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ControlSource {
+    /// The player at this machine.
+    #[default]
+    Local,
+    /// Playback of a recorded game.
+    Replay,
+    /// A remote player being spectated.
+    Network,
+    /// The built-in bot (attract mode).
+    Bot,
+}
+
+impl ControlSource {
+    /// Whether the game is only being watched: any source but `Local`.
+    pub fn is_spectating(self) -> bool {
+        self != ControlSource::Local
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` while the local player controls the board (also when the resource
+/// is absent).
+pub fn local_control(source: Option<Res<ControlSource>>) -> bool {
+    source.is_none_or(|s| *s == ControlSource::Local)
+}
+
+/// `true` while the bot controls the board.
+pub fn bot_control(source: Option<Res<ControlSource>>) -> bool {
+    source.is_some_and(|s| *s == ControlSource::Bot)
+}
+
+/// `true` while the game is only being watched.
+pub fn spectating(source: Option<Res<ControlSource>>) -> bool {
+    source.is_some_and(|s| s.is_spectating())
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Hands the board to the bot while an attract-mode demo runs and back to
+/// the local player when it ends.
+///
+/// Registered with `run_if(resource_changed::<AttractMode>)`.  Leaves a
+/// `Replay` or `Network` source alone.
+pub fn sync_attract_control(attract: Res<AttractMode>, mut source: ResMut<ControlSource>) {
+    let next = match (*source, attract.active) {
+        (_, true) => ControlSource::Bot,
+        (ControlSource::Bot, false) => ControlSource::Local,
+        (current, false) => current,
+    };
+    source.set_if_neq(next);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_local_is_not_spectating() {
+        assert!(!ControlSource::Local.is_spectating());
+        assert!(ControlSource::Replay.is_spectating());
+        assert!(ControlSource::Network.is_spectating());
+        assert!(ControlSource::Bot.is_spectating());
+    }
+
+    #[test]
+    fn test_run_conditions() {
+        let mut world = World::new();
+        assert!(world.run_system_cached(local_control).unwrap());
+        assert!(!world.run_system_cached(spectating).unwrap());
+
+        world.insert_resource(ControlSource::Replay);
+        assert!(!world.run_system_cached(local_control).unwrap());
+        assert!(!world.run_system_cached(bot_control).unwrap());
+        assert!(world.run_system_cached(spectating).unwrap());
+    }
+
+    #[test]
+    fn test_attract_demo_hands_control_to_bot() {
+        let mut app = App::new();
+        app.init_resource::<AttractMode>()
+            .init_resource::<ControlSource>()
+            .add_systems(Update, sync_attract_control);
+
+        app.world_mut().resource_mut::<AttractMode>().active = true;
+        app.update();
+        assert_eq!(*app.world().resource::<ControlSource>(), ControlSource::Bot);
+
+        app.world_mut().resource_mut::<AttractMode>().active = false;
+        app.update();
+        assert_eq!(
+            *app.world().resource::<ControlSource>(),
+            ControlSource::Local
+        );
+
+        // A replay is not interrupted by the attract-mode bookkeeping
+        app.insert_resource(ControlSource::Replay);
+        app.update();
+        assert_eq!(
+            *app.world().resource::<ControlSource>(),
+            ControlSource::Replay
+        );
+    }
+}
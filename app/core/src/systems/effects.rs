@@ -2,22 +2,44 @@
 //!
 //! This module implements transient visual effects applied to game entities.
 //! Sub-modules provide squash-and-stretch bounce, water droplet particles,
-//! and flash effects for merges and landings.
+//! landing dust puffs, flash effects, camera shake and zoom punch,
+//! fireworks for a new Watermelon, and sprite-sheet frames for merges and
+//! landings.
+//!
+//! Beyond the global `effects_enabled` switch, droplets, flashes and camera
+//! shake can each be switched off in the settings; the run conditions below
+//! gate the systems that start them.
 
 pub mod bounce;
 pub mod droplet;
+pub mod dust;
+pub mod fireworks;
 pub mod flash;
+pub mod frames;
+pub mod punch;
 pub mod shake;
 pub mod watermelon;
 
 use bevy::prelude::*;
 
+use crate::resources::SettingsResource;
+
 /// Scale pop-in animation played on a newly merged fruit
 ///
 /// Added to a fruit entity immediately after it is spawned by the merge system.
-/// The entity starts at scale 0 and grows to its natural size (1.0) over
-/// `duration` seconds using an ease-out quadratic curve, giving a satisfying
-/// "pop" feel without the complexity of a full animation framework.
+/// The entity starts at scale 0, springs past its natural size and settles
+/// at 1.0 over `duration` seconds using an elastic ease-out curve, giving a
+/// satisfying "pop" feel without the complexity of a full animation
+/// framework:
+///
+/// ```text
+/// scale
+///  1.16 ┤     ╭─╮
+///  1.0  ┤    ╱   ╰──╮╭──────
+///       │   ╱        ╰╯
+///  0    ┼──╯
+///       └──────────────────▶ t
+/// ```
 ///
 /// # Lifecycle
 ///
@@ -40,8 +62,14 @@ impl MergeAnimation {
         }
     }
 
-    /// Default animation duration (0.25 seconds)
-    pub const DEFAULT_DURATION: f32 = 0.25;
+    /// Default animation duration (0.4 seconds)
+    pub const DEFAULT_DURATION: f32 = 0.4;
+
+    /// Half-turns of the spring over the whole animation
+    const ELASTIC_HALF_TURNS: f32 = 3.0;
+
+    /// Exponential decay rate of the spring
+    const ELASTIC_DECAY: f32 = 5.0;
 
     /// Returns the normalized progress in `[0.0, 1.0]`
     ///
@@ -54,21 +82,44 @@ impl MergeAnimation {
         (self.elapsed / self.duration).clamp(0.0, 1.0)
     }
 
-    /// Returns the ease-out quadratic scale factor for the current progress
+    /// Returns the elastic ease-out scale factor for the current progress
     ///
-    /// Uses `f(t) = t * (2 - t)` which starts fast and decelerates,
-    /// giving a natural "pop-in" appearance.
+    /// Uses `f(t) = 1 - cos(3πt) × e^(-5t) × (1 - t)`: a damped spring that
+    /// starts at 0, overshoots to about 1.16 a quarter of the way in, dips
+    /// just below 1 and lands exactly on 1 at `t = 1`.
     pub fn scale_factor(&self) -> f32 {
         let t = self.progress();
-        t * (2.0 - t)
+        let spring = (Self::ELASTIC_HALF_TURNS * std::f32::consts::PI * t).cos()
+            * (-Self::ELASTIC_DECAY * t).exp();
+        1.0 - spring * (1.0 - t)
     }
 }
 
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` unless water droplets are switched off in the settings.
+pub fn droplets_enabled(settings: Option<Res<SettingsResource>>) -> bool {
+    settings.is_none_or(|s| s.droplet_effects)
+}
+
+/// `true` unless merge and screen flashes are switched off in the settings.
+pub fn flashes_enabled(settings: Option<Res<SettingsResource>>) -> bool {
+    settings.is_none_or(|s| s.flash_effects)
+}
+
+/// `true` unless camera shake and zoom punch are switched off in the
+/// settings.
+pub fn shake_enabled(settings: Option<Res<SettingsResource>>) -> bool {
+    settings.is_none_or(|s| s.shake_effects)
+}
+
 /// Advances `MergeAnimation` components and applies the resulting scale
 ///
 /// Each frame:
 /// 1. Increments `elapsed` by `delta_secs`
-/// 2. Computes the ease-out scale factor from the current progress
+/// 2. Computes the elastic scale factor from the current progress
 /// 3. Writes the scale to the entity's `Transform`
 /// 4. Removes `MergeAnimation` once the animation has finished
 pub fn animate_merge_scale(
@@ -90,6 +141,41 @@ pub fn animate_merge_scale(
     }
 }
 
+/// Despawns every leftover transient effect entity.
+///
+/// Effect animations only tick while `Playing`, so droplets, dust puffs,
+/// flashes and watermelon bursts that were in flight when the game ended
+/// would otherwise sit frozen on the game-over and title screens, keeping
+/// their entities (and the sprite handles they hold) alive.  Registered on
+/// `OnExit(GameOver)` and `OnExit(Title)` so each new game starts from a
+/// clean slate.
+#[allow(clippy::type_complexity)]
+pub fn cleanup_effect_entities(
+    mut commands: Commands,
+    effect_query: Query<
+        Entity,
+        Or<(
+            With<droplet::WaterDroplet>,
+            With<dust::DustPuff>,
+            With<flash::LocalFlashAnimation>,
+            With<flash::ScreenFlashAnimation>,
+            With<watermelon::WatermelonExplosionRing>,
+            With<watermelon::WatermelonBurstParticle>,
+            With<fireworks::FireworkShell>,
+            With<fireworks::FireworkSpark>,
+        )>,
+    >,
+) {
+    let mut count = 0u32;
+    for entity in effect_query.iter() {
+        commands.entity(entity).despawn();
+        count += 1;
+    }
+    if count > 0 {
+        debug!("Cleaned up {count} leftover effect entities");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,8 +196,8 @@ mod tests {
         let mut anim = MergeAnimation::new(1.0);
         anim.elapsed = 0.5; // 50% through
         assert!((anim.progress() - 0.5).abs() < f32::EPSILON);
-        // ease-out at 0.5: 0.5 * (2 - 0.5) = 0.5 * 1.5 = 0.75
-        assert!((anim.scale_factor() - 0.75).abs() < f32::EPSILON);
+        // cos(1.5π) = 0, so the spring passes through the natural size
+        assert!((anim.scale_factor() - 1.0).abs() < 1e-5);
     }
 
     #[test]
@@ -144,23 +230,33 @@ mod tests {
     }
 
     #[test]
-    fn test_merge_animation_scale_increases_monotonically() {
-        let duration = 1.0_f32;
-        let steps = 10;
-        let mut prev = -1.0_f32;
-        for i in 0..=steps {
-            let mut anim = MergeAnimation::new(duration);
-            anim.elapsed = (i as f32 / steps as f32) * duration;
-            let scale = anim.scale_factor();
-            assert!(
-                scale >= prev,
-                "Scale should be non-decreasing: step {} scale {} < prev {}",
-                i,
-                scale,
-                prev
-            );
-            prev = scale;
-        }
+    fn test_merge_animation_overshoots_then_settles() {
+        let samples: Vec<f32> = (0..=100)
+            .map(|i| {
+                let mut anim = MergeAnimation::new(1.0);
+                anim.elapsed = i as f32 / 100.0;
+                anim.scale_factor()
+            })
+            .collect();
+        let peak = samples.iter().copied().fold(0.0_f32, f32::max);
+        let peak_at = samples.iter().position(|&s| s == peak).unwrap();
+
+        assert!(
+            samples.iter().all(|&s| s >= 0.0),
+            "scale must never go negative"
+        );
+        assert!(
+            peak > 1.05 && peak < 1.25,
+            "should overshoot noticeably but not wildly, peak {peak}"
+        );
+        assert!(
+            samples[..=peak_at].windows(2).all(|w| w[1] >= w[0]),
+            "should grow steadily up to the overshoot"
+        );
+        assert!(
+            samples[70..].iter().all(|&s| (s - 1.0).abs() < 0.02),
+            "should have settled near 1.0 by the end"
+        );
     }
 
     // --- animate_merge_scale system tests ---
@@ -213,4 +309,56 @@ mod tests {
         let transform = app.world().get::<Transform>(entity).unwrap();
         assert_eq!(transform.scale, Vec3::ONE);
     }
+
+    fn droplet_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query::<&droplet::WaterDroplet>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn test_effect_assets_do_not_grow_across_games() {
+        use crate::events::FruitMergeEvent;
+        use crate::fruit::FruitType;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::asset::AssetPlugin::default());
+        app.init_asset::<Image>();
+        app.add_message::<FruitMergeEvent>();
+        app.add_systems(Update, droplet::spawn_merge_droplets);
+
+        let images_before = app.world().resource::<Assets<Image>>().len();
+
+        for _ in 0..100 {
+            // One "game": a merge burst, then the state-exit cleanup
+            app.world_mut().write_message(FruitMergeEvent {
+                entity1: Entity::PLACEHOLDER,
+                entity2: Entity::PLACEHOLDER,
+                fruit_type: FruitType::Watermelon,
+                position: Vec2::ZERO,
+            });
+            app.update();
+            assert!(
+                droplet_count(&mut app) > 0,
+                "the merge burst should spawn droplets"
+            );
+            app.world_mut()
+                .run_system_cached(cleanup_effect_entities)
+                .unwrap();
+            app.world_mut().flush();
+        }
+
+        assert_eq!(
+            droplet_count(&mut app),
+            0,
+            "effect entities must not accumulate"
+        );
+        assert_eq!(
+            app.world().resource::<Assets<Image>>().len(),
+            images_before,
+            "effects must not allocate image assets per particle"
+        );
+    }
 }
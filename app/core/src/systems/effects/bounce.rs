@@ -4,10 +4,14 @@
 //! Two modes are supported:
 //! - `SpawnIn`: merge pop-in (scale 0→1 with bounce overshoot)
 //! - `Impact`: landing impact (scale stays at 1, squashes then recovers)
+//!
+//! Merged fruits pop in with [`MergeAnimation`] instead; a landing squash
+//! waits until that pop has finished so the two never fight over the scale.
 
 use bevy::prelude::*;
 
 use crate::config::BounceConfig;
+use crate::systems::effects::MergeAnimation;
 
 /// The mode of the bounce animation
 #[derive(Debug, Clone, PartialEq)]
@@ -25,7 +29,8 @@ pub enum BounceMode {
 ///
 /// # Lifecycle
 ///
-/// Inserted by `handle_fruit_merge` (SpawnIn) or `spawn_landing_droplets` (Impact).
+/// Inserted by `spawn_landing_droplets` (Impact); `for_merge` (SpawnIn) is
+/// available for a spring-only pop-in.
 /// Removed automatically by `animate_squash_stretch` once the spring settles.
 #[derive(Component, Debug, Clone)]
 pub struct SquashStretchAnimation {
@@ -170,9 +175,14 @@ impl SquashStretchAnimation {
 /// 2. Computes (scale_x, scale_y) for the current state
 /// 3. Writes the scale to the entity's `Transform`
 /// 4. Removes `SquashStretchAnimation` once the spring has settled
+///
+/// Entities still playing a [`MergeAnimation`] are skipped until it ends.
 pub fn animate_squash_stretch(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut SquashStretchAnimation, &mut Transform)>,
+    mut query: Query<
+        (Entity, &mut SquashStretchAnimation, &mut Transform),
+        Without<MergeAnimation>,
+    >,
     time: Res<Time>,
 ) {
     for (entity, mut anim, mut transform) in query.iter_mut() {
@@ -290,6 +300,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_system_waits_for_merge_pop() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, animate_squash_stretch);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                SquashStretchAnimation::for_landing(None),
+                MergeAnimation::new(MergeAnimation::DEFAULT_DURATION),
+                Transform::from_scale(Vec3::splat(0.5)),
+            ))
+            .id();
+        app.update();
+        app.update();
+
+        let anim = app.world().get::<SquashStretchAnimation>(entity).unwrap();
+        assert_eq!(anim.elapsed, 0.0, "landing squash should not advance yet");
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert_eq!(transform.scale, Vec3::splat(0.5), "pop owns the scale");
+    }
+
     #[test]
     fn test_system_removes_component_when_settled() {
         let mut app = App::new();
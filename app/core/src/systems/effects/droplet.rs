@@ -10,7 +10,9 @@ use rand::RngExt;
 use crate::components::{Fruit, FruitSpawnState};
 use crate::config::{BounceParams, DropletColorMode, DropletConfig, DropletParams, PhysicsParams};
 use crate::events::FruitMergeEvent;
+use crate::resources::SettingsResource;
 use crate::systems::effects::bounce::SquashStretchAnimation;
+use crate::systems::effects::frames::FruitFrameAnimation;
 
 // --- Constants ---
 
@@ -177,12 +179,14 @@ pub fn spawn_merge_droplets(
     }
 }
 
-/// Handles fruit landing: spawns water droplets and inserts the impact bounce animation
+/// Handles fruit landing: spawns water droplets and inserts the impact animations
 ///
 /// Uses Bevy's change detection (`Changed<FruitSpawnState>`) to detect the
 /// moment a falling fruit lands. For each newly-landed fruit it:
-/// 1. Spawns a small splash of water droplets
+/// 1. Spawns a small splash of water droplets, unless
+///    [`SettingsResource::droplet_effects`] is off
 /// 2. Inserts `SquashStretchAnimation::for_landing` on the fruit entity
+/// 3. Inserts [`FruitFrameAnimation::squish`] to show the squish frame
 #[allow(clippy::type_complexity)]
 pub fn handle_fruit_landing(
     mut commands: Commands,
@@ -197,7 +201,9 @@ pub fn handle_fruit_landing(
     >,
     droplet: DropletParams<'_>,
     bounce: BounceParams<'_>,
+    settings: Option<Res<SettingsResource>>,
 ) {
+    let droplets = settings.is_none_or(|s| s.droplet_effects);
     let droplet_cfg = droplet.get();
     let bounce_cfg = bounce.get();
     let base_count = droplet_cfg
@@ -209,16 +215,19 @@ pub fn handle_fruit_landing(
             continue;
         }
 
-        let count = scale_count_by_fruit(base_count, *fruit_type);
-        let pos = transform.translation.truncate();
-        let fruit_color = fruit_type.placeholder_color();
-        let color = resolve_droplet_color(droplet_cfg, fruit_color);
-        spawn_droplets(&mut commands, pos, color, count, droplet_cfg);
+        if droplets {
+            let count = scale_count_by_fruit(base_count, *fruit_type);
+            let pos = transform.translation.truncate();
+            let fruit_color = fruit_type.placeholder_color();
+            let color = resolve_droplet_color(droplet_cfg, fruit_color);
+            spawn_droplets(&mut commands, pos, color, count, droplet_cfg);
+        }
 
-        // Add landing bounce (squash-and-stretch) to the fruit
-        commands
-            .entity(entity)
-            .insert(SquashStretchAnimation::for_landing(bounce_cfg));
+        // Add landing bounce (squash-and-stretch) and the squish frame to the fruit
+        commands.entity(entity).insert((
+            SquashStretchAnimation::for_landing(bounce_cfg),
+            FruitFrameAnimation::squish(),
+        ));
     }
 }
 
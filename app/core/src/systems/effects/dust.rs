@@ -0,0 +1,275 @@
+//! Landing dust-puff particle system
+//!
+//! Spawns a few soft puffs at the contact point when a fruit lands on the
+//! floor or the pile.  Unlike water droplets the puffs have no gravity: they
+//! roll out sideways, drift up a little, slow down, grow and fade.
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::{DustConfig, DustParams, FruitsParams};
+use crate::resources::CircleTexture;
+
+// --- Constants ---
+
+/// Fallback: puffs spawned per landing when `dust.ron` is not yet loaded
+pub const DUST_COUNT: u32 = 6;
+/// Fallback: puff radius in pixels when spawned
+pub const DUST_RADIUS: f32 = 4.0;
+/// Fallback: puff radius multiplier at the end of its lifetime
+pub const DUST_GROWTH: f32 = 2.5;
+/// Fallback: sideways speed of the outermost puffs (pixels/second)
+pub const DUST_SPREAD_SPEED: f32 = 90.0;
+/// Fallback: upward drift speed (pixels/second)
+pub const DUST_RISE_SPEED: f32 = 20.0;
+/// Fallback: fraction of the velocity lost per second
+pub const DUST_DRAG: f32 = 0.9;
+/// Fallback: puff lifetime in seconds
+pub const DUST_LIFETIME: f32 = 0.5;
+/// Fallback: puff color
+pub const DUST_COLOR: Color = Color::srgba(0.85, 0.8, 0.7, 0.55);
+
+/// Puff z-layer: just in front of the fruits, behind the droplets.
+const DUST_Z: f32 = 4.0;
+
+// --- Component ---
+
+/// Dust puff particle component
+///
+/// A short-lived sprite spawned by [`spawn_landing_dust`] and advanced (then
+/// despawned) by [`update_dust_puffs`].
+#[derive(Component, Debug)]
+pub struct DustPuff {
+    /// Current velocity in pixels/second
+    pub velocity: Vec2,
+    /// Elapsed lifetime in seconds
+    pub lifetime: f32,
+    /// Total lifetime in seconds before despawn
+    pub max_lifetime: f32,
+    /// Radius in pixels when spawned
+    pub radius: f32,
+    /// Alpha when spawned
+    pub alpha: f32,
+}
+
+// --- Internal helpers ---
+
+/// Values of `config`, or the module constants while it is not loaded.
+fn dust_settings(config: Option<&DustConfig>) -> DustSettings {
+    match config {
+        Some(c) => DustSettings {
+            count: c.count,
+            radius: c.radius,
+            growth: c.growth,
+            color: c.color.into(),
+            spread_speed: c.spread_speed,
+            rise_speed: c.rise_speed,
+            drag: c.drag,
+            lifetime: c.lifetime,
+        },
+        None => DustSettings {
+            count: DUST_COUNT,
+            radius: DUST_RADIUS,
+            growth: DUST_GROWTH,
+            color: DUST_COLOR,
+            spread_speed: DUST_SPREAD_SPEED,
+            rise_speed: DUST_RISE_SPEED,
+            drag: DUST_DRAG,
+            lifetime: DUST_LIFETIME,
+        },
+    }
+}
+
+/// Resolved [`DustConfig`] values.
+struct DustSettings {
+    count: u32,
+    radius: f32,
+    growth: f32,
+    color: Color,
+    spread_speed: f32,
+    rise_speed: f32,
+    drag: f32,
+    lifetime: f32,
+}
+
+/// Sideways direction and speed fraction of puff `i` of `count`.
+///
+/// Puffs alternate left and right, the later ones further out, so even a
+/// small count covers both sides of the contact point.
+fn puff_spread(i: u32, count: u32) -> f32 {
+    let side = if i % 2 == 0 { -1.0 } else { 1.0 };
+    let pairs = count.div_ceil(2).max(1) as f32;
+    side * ((i / 2) as f32 + 1.0) / pairs
+}
+
+// --- Systems ---
+
+/// Spawns dust puffs under every fruit that landed this frame.
+///
+/// Like `handle_fruit_landing`, uses change detection on
+/// [`FruitSpawnState`].  The contact point is the bottom of the fruit's
+/// collider (its centre while the fruit config is not loaded).
+#[allow(clippy::type_complexity)]
+pub fn spawn_landing_dust(
+    mut commands: Commands,
+    changed_fruits: Query<
+        (&FruitSpawnState, &Transform, &crate::fruit::FruitType),
+        (With<Fruit>, Changed<FruitSpawnState>),
+    >,
+    dust: DustParams<'_>,
+    fruits: FruitsParams<'_>,
+    circle_texture: Option<Res<CircleTexture>>,
+) {
+    let settings = dust_settings(dust.get());
+    if settings.count == 0 || settings.lifetime <= 0.0 {
+        return;
+    }
+    let image = circle_texture.map(|t| t.0.clone()).unwrap_or_default();
+    let mut rng = rand::rng();
+
+    for (state, transform, fruit_type) in changed_fruits.iter() {
+        if *state != FruitSpawnState::Landed {
+            continue;
+        }
+        let fruit_radius = fruits
+            .get()
+            .and_then(|cfg| fruit_type.try_parameters_from_config(cfg))
+            .map(|p| p.radius)
+            .unwrap_or(0.0);
+        let contact = transform.translation.truncate() - Vec2::new(0.0, fruit_radius);
+
+        for i in 0..settings.count {
+            let spread = puff_spread(i, settings.count);
+            let velocity = Vec2::new(
+                spread * settings.spread_speed * rng.random_range(0.7_f32..1.0),
+                settings.rise_speed * rng.random_range(0.5_f32..1.0),
+            );
+            let offset = spread * fruit_radius * 0.3;
+            commands.spawn((
+                DustPuff {
+                    velocity,
+                    lifetime: 0.0,
+                    max_lifetime: settings.lifetime,
+                    radius: settings.radius,
+                    alpha: settings.color.alpha(),
+                },
+                Sprite {
+                    image: image.clone(),
+                    color: settings.color,
+                    custom_size: Some(Vec2::splat(settings.radius * 2.0)),
+                    ..default()
+                },
+                Transform::from_translation((contact + Vec2::new(offset, 0.0)).extend(DUST_Z)),
+            ));
+        }
+    }
+}
+
+/// Advances dust puffs: drifts, slows, grows and fades them, then despawns
+/// the finished ones.
+pub fn update_dust_puffs(
+    mut commands: Commands,
+    mut puffs: Query<(Entity, &mut DustPuff, &mut Transform, &mut Sprite)>,
+    time: Res<Time>,
+    dust: DustParams<'_>,
+) {
+    let dt = time.delta_secs();
+    let settings = dust_settings(dust.get());
+    let keep = (1.0 - settings.drag).clamp(0.0, 1.0).powf(dt);
+
+    for (entity, mut puff, mut transform, mut sprite) in puffs.iter_mut() {
+        puff.lifetime += dt;
+        if puff.lifetime >= puff.max_lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let t = puff.lifetime / puff.max_lifetime;
+
+        transform.translation += (puff.velocity * dt).extend(0.0);
+        puff.velocity *= keep;
+
+        let radius = puff.radius * (1.0 + (settings.growth - 1.0) * t);
+        sprite.custom_size = Some(Vec2::splat(radius * 2.0));
+        sprite.color.set_alpha(puff.alpha * (1.0 - t));
+    }
+}
+
+// --- Tests ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fruit::FruitType;
+
+    #[test]
+    fn test_puff_spread_covers_both_sides() {
+        let spreads: Vec<f32> = (0..4).map(|i| puff_spread(i, 4)).collect();
+        assert_eq!(spreads, [-0.5, 0.5, -1.0, 1.0]);
+        assert_eq!(puff_spread(0, 1), -1.0);
+    }
+
+    #[test]
+    fn test_landing_spawns_dust() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, spawn_landing_dust);
+        let fruit = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                FruitType::Apple,
+                FruitSpawnState::Falling,
+                Transform::default(),
+            ))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world_mut()
+                .query::<&DustPuff>()
+                .iter(app.world())
+                .count(),
+            0,
+            "a falling fruit raises no dust"
+        );
+
+        *app.world_mut().get_mut::<FruitSpawnState>(fruit).unwrap() = FruitSpawnState::Landed;
+        app.update();
+        assert_eq!(
+            app.world_mut()
+                .query::<&DustPuff>()
+                .iter(app.world())
+                .count(),
+            DUST_COUNT as usize
+        );
+    }
+
+    #[test]
+    fn test_puffs_grow_fade_and_despawn() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, update_dust_puffs);
+        let puff = app
+            .world_mut()
+            .spawn((
+                DustPuff {
+                    velocity: Vec2::new(10.0, 0.0),
+                    lifetime: DUST_LIFETIME / 2.0,
+                    max_lifetime: DUST_LIFETIME,
+                    radius: DUST_RADIUS,
+                    alpha: 1.0,
+                },
+                Sprite::default(),
+                Transform::default(),
+            ))
+            .id();
+        app.update();
+        let sprite = app.world().get::<Sprite>(puff).unwrap();
+        assert!(sprite.custom_size.unwrap().x > DUST_RADIUS * 2.0);
+        assert!(sprite.color.alpha() < 1.0);
+
+        app.world_mut().get_mut::<DustPuff>(puff).unwrap().lifetime = DUST_LIFETIME;
+        app.update();
+        assert!(app.world().get_entity(puff).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+//! Fireworks celebrating the birth of a Watermelon
+//!
+//! When two Melons merge into a Watermelon, [`spawn_watermelon_fireworks`]
+//! launches [`FIREWORK_BURSTS`] shells above the container.  Each waits its
+//! turn ([`FIREWORK_INTERVAL`] apart) and then explodes into a ring of
+//! coloured sparks that droop under a light gravity and fade out:
+//!
+//! ```text
+//!      *  .  *          .*.
+//!    .  \ | /  .      *  |  *        ← one burst, then the next …
+//!   * --  ●  -- *    .--   --.
+//!    '  / | \  '      *  |  *
+//!  ┌────────────────────────────┐
+//!  │          container         │
+//! ```
+//!
+//! This is separate from the explosion ring of [`super::watermelon`]: the
+//! fireworks only mark a new Watermelon, not a Watermelon pair vanishing.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::config::PhysicsParams;
+use crate::events::FruitMergeEvent;
+use crate::fruit::FruitType;
+use crate::resources::CircleTexture;
+
+// --- Constants ---
+
+/// Shells launched per new Watermelon
+pub const FIREWORK_BURSTS: u32 = 5;
+/// Seconds between two bursts
+pub const FIREWORK_INTERVAL: f32 = 0.22;
+/// Sparks per burst
+pub const FIREWORK_SPARKS: u32 = 28;
+/// Initial spark speed in pixels/second
+pub const FIREWORK_SPEED: f32 = 200.0;
+/// Spark lifetime in seconds
+pub const FIREWORK_LIFETIME: f32 = 1.1;
+/// Spark diameter in pixels
+pub const FIREWORK_SPARK_SIZE: f32 = 5.0;
+/// Gravity applied to sparks (pixels/s², negative = downward)
+const FIREWORK_GRAVITY: f32 = -140.0;
+/// Fraction of the spark velocity lost per second
+const FIREWORK_DRAG: f32 = 0.8;
+/// Height range of the bursts above the container top, in pixels
+const FIREWORK_ALTITUDE: std::ops::Range<f32> = 20.0..110.0;
+
+/// Burst colours, one per shell in turn
+const FIREWORK_COLORS: [Color; 5] = [
+    Color::srgb(1.0, 0.84, 0.25),  // gold
+    Color::srgb(1.0, 0.42, 0.62),  // pink
+    Color::srgb(0.35, 0.85, 1.0),  // sky blue
+    Color::srgb(0.55, 0.95, 0.35), // lime
+    Color::srgb(1.0, 0.6, 0.2),    // orange
+];
+
+/// Spark z-layer: in front of the watermelon burst, behind the screen flash.
+const FIREWORK_Z: f32 = 7.5;
+
+// --- Components ---
+
+/// A firework waiting to burst at its position
+#[derive(Component, Debug)]
+pub struct FireworkShell {
+    /// Seconds left before the burst
+    pub delay: f32,
+    /// Colour of the sparks
+    pub color: Color,
+}
+
+/// One spark of a burst
+#[derive(Component, Debug)]
+pub struct FireworkSpark {
+    /// Current velocity in pixels/second
+    pub velocity: Vec2,
+    /// Elapsed lifetime in seconds
+    pub lifetime: f32,
+    /// Total lifetime in seconds before despawn
+    pub max_lifetime: f32,
+}
+
+// --- Systems ---
+
+/// Launches [`FIREWORK_BURSTS`] [`FireworkShell`]s above the container for
+/// every Melon merge (a Watermelon is born).
+pub fn spawn_watermelon_fireworks(
+    mut commands: Commands,
+    mut merge_events: MessageReader<FruitMergeEvent>,
+    physics: PhysicsParams<'_>,
+) {
+    let (half_w, half_h) = physics
+        .get()
+        .map(|cfg| (cfg.container_width / 2.0, cfg.container_height / 2.0))
+        .unwrap_or((300.0, 400.0));
+    let mut rng = rand::rng();
+
+    for event in merge_events.read() {
+        if event.fruit_type != FruitType::Melon {
+            continue;
+        }
+        for i in 0..FIREWORK_BURSTS {
+            let x = rng.random_range(-half_w * 0.8..half_w * 0.8);
+            let y = half_h + rng.random_range(FIREWORK_ALTITUDE);
+            commands.spawn((
+                FireworkShell {
+                    delay: i as f32 * FIREWORK_INTERVAL,
+                    color: FIREWORK_COLORS[i as usize % FIREWORK_COLORS.len()],
+                },
+                Transform::from_xyz(x, y, FIREWORK_Z),
+            ));
+        }
+    }
+}
+
+/// Counts down the [`FireworkShell`]s and bursts the due ones into a ring
+/// of [`FireworkSpark`]s.
+pub fn burst_firework_shells(
+    mut commands: Commands,
+    mut shells: Query<(Entity, &mut FireworkShell, &Transform)>,
+    time: Res<Time>,
+    circle_texture: Option<Res<CircleTexture>>,
+) {
+    let image = circle_texture.map(|t| t.0.clone()).unwrap_or_default();
+    let mut rng = rand::rng();
+
+    for (entity, mut shell, transform) in shells.iter_mut() {
+        shell.delay -= time.delta_secs();
+        if shell.delay > 0.0 {
+            continue;
+        }
+        commands.entity(entity).despawn();
+
+        for i in 0..FIREWORK_SPARKS {
+            let angle = TAU * i as f32 / FIREWORK_SPARKS as f32 + rng.random_range(-0.1..0.1);
+            let speed = FIREWORK_SPEED * rng.random_range(0.75..1.0);
+            commands.spawn((
+                FireworkSpark {
+                    velocity: Vec2::from_angle(angle) * speed,
+                    lifetime: 0.0,
+                    max_lifetime: FIREWORK_LIFETIME * rng.random_range(0.8..1.0),
+                },
+                Sprite {
+                    image: image.clone(),
+                    color: shell.color,
+                    custom_size: Some(Vec2::splat(FIREWORK_SPARK_SIZE)),
+                    ..default()
+                },
+                *transform,
+            ));
+        }
+    }
+}
+
+/// Advances [`FireworkSpark`]s: gravity and drag, alpha fade, despawn
+pub fn update_firework_sparks(
+    mut commands: Commands,
+    mut sparks: Query<(Entity, &mut FireworkSpark, &mut Transform, &mut Sprite)>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let keep = (1.0 - FIREWORK_DRAG).powf(dt);
+
+    for (entity, mut spark, mut transform, mut sprite) in sparks.iter_mut() {
+        spark.lifetime += dt;
+        if spark.lifetime >= spark.max_lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        spark.velocity.y += FIREWORK_GRAVITY * dt;
+        spark.velocity *= keep;
+        transform.translation += (spark.velocity * dt).extend(0.0);
+
+        let t = spark.lifetime / spark.max_lifetime;
+        sprite.color.set_alpha(1.0 - t * t);
+    }
+}
+
+// --- Tests ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merge(fruit_type: FruitType) -> FruitMergeEvent {
+        FruitMergeEvent {
+            entity1: Entity::PLACEHOLDER,
+            entity2: Entity::PLACEHOLDER,
+            fruit_type,
+            position: Vec2::ZERO,
+        }
+    }
+
+    fn count<C: Component>(app: &mut App) -> usize {
+        app.world_mut().query::<&C>().iter(app.world()).count()
+    }
+
+    #[test]
+    fn test_only_a_new_watermelon_launches_fireworks() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<FruitMergeEvent>();
+        app.add_systems(Update, spawn_watermelon_fireworks);
+
+        app.world_mut().write_message(merge(FruitType::Pineapple));
+        app.world_mut().write_message(merge(FruitType::Watermelon));
+        app.update();
+        assert_eq!(count::<FireworkShell>(&mut app), 0);
+
+        app.world_mut().write_message(merge(FruitType::Melon));
+        app.update();
+        assert_eq!(count::<FireworkShell>(&mut app), FIREWORK_BURSTS as usize);
+    }
+
+    #[test]
+    fn test_shells_burst_in_turn() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, burst_firework_shells);
+        for delay in [0.0, 1.0] {
+            app.world_mut().spawn((
+                FireworkShell {
+                    delay,
+                    color: FIREWORK_COLORS[0],
+                },
+                Transform::default(),
+            ));
+        }
+        app.update();
+        assert_eq!(count::<FireworkShell>(&mut app), 1, "the later shell waits");
+        assert_eq!(count::<FireworkSpark>(&mut app), FIREWORK_SPARKS as usize);
+    }
+}
@@ -0,0 +1,149 @@
+//! Fruit sprite-sheet frame animation
+//!
+//! Fruits whose sprite is a sheet of [`FruitFrame`]s switch frames for a
+//! moment when something happens to them:
+//!
+//! | Trigger                          | Frame      | Held for                 |
+//! |----------------------------------|------------|--------------------------|
+//! | landing (`handle_fruit_landing`) | `Squish`   | [`SQUISH_FRAME_SECS`]    |
+//! | merge (`handle_fruit_merge`)     | `MergePop` | [`MERGE_POP_FRAME_SECS`] |
+//!
+//! and then return to `Idle`.  Fruits drawn from a single image (or the
+//! circle placeholder) carry the component too but show no change.
+
+use bevy::prelude::*;
+
+use crate::resources::FruitFrame;
+
+/// Seconds the squish frame shows after a landing.
+pub const SQUISH_FRAME_SECS: f32 = 0.12;
+
+/// Seconds the pop frame shows on a freshly merged fruit.
+pub const MERGE_POP_FRAME_SECS: f32 = 0.18;
+
+/// Shows `frame` of the fruit's sprite sheet until `remaining` runs out.
+///
+/// # Lifecycle
+///
+/// Inserted by `handle_fruit_merge` (`MergePop`) or `handle_fruit_landing`
+/// (`Squish`).  Removed by [`animate_fruit_frames`] once the frame is over.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct FruitFrameAnimation {
+    /// Frame shown while the animation runs
+    pub frame: FruitFrame,
+    /// Seconds left before returning to [`FruitFrame::Idle`]
+    pub remaining: f32,
+}
+
+impl FruitFrameAnimation {
+    /// Squish frame for a landing.
+    pub fn squish() -> Self {
+        Self {
+            frame: FruitFrame::Squish,
+            remaining: SQUISH_FRAME_SECS,
+        }
+    }
+
+    /// Pop frame for a merge.
+    pub fn merge_pop() -> Self {
+        Self {
+            frame: FruitFrame::MergePop,
+            remaining: MERGE_POP_FRAME_SECS,
+        }
+    }
+}
+
+/// Advances [`FruitFrameAnimation`]s and writes the frame to the sprite's
+/// texture atlas index.
+pub fn animate_fruit_frames(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut FruitFrameAnimation, &mut Sprite)>,
+) {
+    for (entity, mut anim, mut sprite) in query.iter_mut() {
+        let frame = if anim.remaining > 0.0 {
+            anim.frame
+        } else {
+            commands.entity(entity).remove::<FruitFrameAnimation>();
+            FruitFrame::Idle
+        };
+        if let Some(atlas) = sprite.texture_atlas.as_mut() {
+            atlas.index = frame.index();
+        }
+        anim.remaining -= time.delta_secs();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, animate_fruit_frames);
+        let entity = app
+            .world_mut()
+            .spawn((
+                Sprite {
+                    texture_atlas: Some(TextureAtlas::default()),
+                    ..default()
+                },
+                FruitFrameAnimation::squish(),
+            ))
+            .id();
+        (app, entity)
+    }
+
+    fn atlas_index(app: &App, entity: Entity) -> usize {
+        app.world()
+            .get::<Sprite>(entity)
+            .and_then(|s| s.texture_atlas.as_ref())
+            .map(|a| a.index)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_frame_shows_while_running() {
+        let (mut app, entity) = frame_app();
+        app.update();
+        assert_eq!(atlas_index(&app, entity), FruitFrame::Squish.index());
+        assert!(app.world().get::<FruitFrameAnimation>(entity).is_some());
+    }
+
+    #[test]
+    fn test_finished_frame_returns_to_idle() {
+        let (mut app, entity) = frame_app();
+        app.update();
+        app.world_mut()
+            .get_mut::<FruitFrameAnimation>(entity)
+            .unwrap()
+            .remaining = 0.0;
+        app.update();
+        assert_eq!(atlas_index(&app, entity), FruitFrame::Idle.index());
+        assert!(app.world().get::<FruitFrameAnimation>(entity).is_none());
+    }
+
+    #[test]
+    fn test_single_image_sprite_is_left_alone() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, animate_fruit_frames);
+        let entity = app
+            .world_mut()
+            .spawn((Sprite::default(), FruitFrameAnimation::merge_pop()))
+            .id();
+        app.update();
+        assert!(
+            app.world()
+                .get::<Sprite>(entity)
+                .unwrap()
+                .texture_atlas
+                .is_none()
+        );
+    }
+}
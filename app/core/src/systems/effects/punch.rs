@@ -0,0 +1,261 @@
+//! Camera zoom punch effect system
+//!
+//! Big merges briefly push the camera in and let it settle back, on top of
+//! the [`CameraShake`](super::shake::CameraShake) jolt.  The punch is an
+//! orthographic scale dip: `scale = 1 − zoom × envelope(t)`, where the
+//! envelope eases in over the first quarter of the punch and smoothly
+//! settles back to zero:
+//!
+//! ```text
+//! zoom ▲   ╭─╮
+//!      │  ╱   ╲___
+//!      │ ╱        ╲____
+//!    0 ┼──────────────────▶ t
+//!      0  ¼              duration
+//! ```
+//!
+//! Parameters live in the `punch` section of `assets/config/effects/shake.ron`
+//! ([`PunchConfig`]).
+
+use bevy::prelude::*;
+
+use crate::config::{PunchConfig, ShakeParams};
+use crate::events::FruitMergeEvent;
+
+/// Fraction of the punch spent zooming in; the rest settles back.
+const PUNCH_IN_FRACTION: f32 = 0.25;
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Camera zoom punch state component
+///
+/// Attach this to the camera entity next to `CameraShake`.  While `zoom` is
+/// non-zero the camera's orthographic scale follows the punch envelope; once
+/// the punch has settled the scale is put back to `1.0` and the projection
+/// is left alone until the next punch.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct CameraPunch {
+    /// Peak zoom of the running punch (0.05 = 5 % closer), `0.0` when idle
+    pub zoom: f32,
+    /// Seconds since the running punch started
+    pub elapsed: f32,
+    /// Total length of the running punch in seconds
+    pub duration: f32,
+}
+
+impl CameraPunch {
+    /// Starts a punch of `zoom` lasting `duration` seconds.
+    ///
+    /// A punch already running is continued from its current zoom rather
+    /// than from zero, and never to a smaller peak, so chained merges do not
+    /// make the camera jump.
+    pub fn punch(&mut self, zoom: f32, duration: f32) {
+        let current = self.current_zoom();
+        let zoom = zoom.max(current);
+        if zoom <= 0.0 || duration <= 0.0 {
+            return;
+        }
+        // Invert the ease-in so the new punch starts where the camera is
+        let u = 1.0 - (1.0 - current / zoom).sqrt();
+        *self = Self {
+            zoom,
+            elapsed: u * PUNCH_IN_FRACTION * duration,
+            duration,
+        };
+    }
+
+    /// `true` while a punch is running.
+    pub fn is_active(&self) -> bool {
+        self.zoom > 0.0
+    }
+
+    /// Zoom at the current point of the punch.
+    pub fn current_zoom(&self) -> f32 {
+        if !self.is_active() || self.duration <= 0.0 {
+            return 0.0;
+        }
+        self.zoom * punch_envelope(self.elapsed / self.duration)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Starts a camera punch on merges at or above `punch.min_fruit_index`
+///
+/// The zoom grows by `zoom_step` per fruit index above the minimum, capped
+/// at `max_zoom`.  Values are read from `shake.ron` when loaded, falling
+/// back to [`PunchConfig::default`] otherwise.
+pub fn add_camera_punch(
+    mut merge_events: MessageReader<FruitMergeEvent>,
+    mut punch_query: Query<&mut CameraPunch>,
+    shake: ShakeParams<'_>,
+) {
+    let cfg = shake.get().map(|c| c.punch).unwrap_or_default();
+
+    for event in merge_events.read() {
+        let Some(zoom) = punch_zoom(event.fruit_type as usize, &cfg) else {
+            continue;
+        };
+        if let Ok(mut punch) = punch_query.single_mut() {
+            punch.punch(zoom, cfg.duration);
+        }
+    }
+}
+
+/// Advances the camera punch and writes it to the orthographic scale
+///
+/// Runs every frame regardless of game state (like `apply_camera_shake`) so
+/// that a punch started just before a pause or game over still settles.
+pub fn apply_camera_punch(
+    mut query: Query<(&mut Projection, &mut CameraPunch), With<Camera2d>>,
+    time: Res<Time>,
+) {
+    let Ok((mut projection, mut punch)) = query.single_mut() else {
+        return;
+    };
+    if !punch.is_active() {
+        return;
+    }
+
+    punch.elapsed += time.delta_secs();
+    let scale = if punch.elapsed >= punch.duration {
+        *punch = CameraPunch::default();
+        1.0
+    } else {
+        1.0 - punch.current_zoom()
+    };
+    if let Projection::Orthographic(ortho) = projection.as_mut() {
+        ortho.scale = scale;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Punch zoom for a merge into `fruit_index`, `None` below the threshold.
+fn punch_zoom(fruit_index: usize, cfg: &PunchConfig) -> Option<f32> {
+    if fruit_index < cfg.min_fruit_index {
+        return None;
+    }
+    let steps_above_min = (fruit_index - cfg.min_fruit_index + 1) as f32;
+    Some((steps_above_min * cfg.zoom_step).min(cfg.max_zoom))
+}
+
+/// Punch envelope over normalised time `t`: `0` at both ends, `1` at
+/// [`PUNCH_IN_FRACTION`].
+fn punch_envelope(t: f32) -> f32 {
+    if !(0.0..1.0).contains(&t) {
+        return 0.0;
+    }
+    if t < PUNCH_IN_FRACTION {
+        // Ease-out quadratic in
+        let u = t / PUNCH_IN_FRACTION;
+        u * (2.0 - u)
+    } else {
+        // Smoothstep back out
+        let u = (t - PUNCH_IN_FRACTION) / (1.0 - PUNCH_IN_FRACTION);
+        1.0 - u * u * (3.0 - 2.0 * u)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fruit::FruitType;
+
+    #[test]
+    fn test_punch_envelope_starts_and_ends_at_zero() {
+        assert_eq!(punch_envelope(0.0), 0.0);
+        assert_eq!(punch_envelope(PUNCH_IN_FRACTION), 1.0);
+        assert_eq!(punch_envelope(1.0), 0.0);
+        assert!(punch_envelope(0.9) < punch_envelope(0.5));
+    }
+
+    #[test]
+    fn test_punch_zoom_scales_and_caps() {
+        let cfg = PunchConfig::default();
+        assert_eq!(punch_zoom(cfg.min_fruit_index - 1, &cfg), None);
+        assert_eq!(punch_zoom(cfg.min_fruit_index, &cfg), Some(cfg.zoom_step));
+        assert_eq!(punch_zoom(100, &cfg), Some(cfg.max_zoom));
+    }
+
+    #[test]
+    fn test_chained_punch_never_zooms_out() {
+        let mut punch = CameraPunch::default();
+        punch.punch(0.08, 1.0);
+        punch.elapsed = PUNCH_IN_FRACTION;
+        punch.punch(0.02, 1.0);
+        assert_eq!(punch.zoom, 0.08, "smaller punch keeps the current zoom");
+        assert!((punch.current_zoom() - 0.08).abs() < 1e-6);
+
+        punch.elapsed = 0.5;
+        let before = punch.current_zoom();
+        punch.punch(0.1, 1.0);
+        assert_eq!(punch.zoom, 0.1);
+        assert!((punch.current_zoom() - before).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_watermelon_merge_punches_camera() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<FruitMergeEvent>();
+        app.add_systems(Update, add_camera_punch);
+        let camera = app.world_mut().spawn(CameraPunch::default()).id();
+
+        for fruit_type in [FruitType::Cherry, FruitType::Watermelon] {
+            app.world_mut().write_message(FruitMergeEvent {
+                entity1: Entity::PLACEHOLDER,
+                entity2: Entity::PLACEHOLDER,
+                fruit_type,
+                position: Vec2::ZERO,
+            });
+            app.update();
+            let active = app.world().get::<CameraPunch>(camera).unwrap().is_active();
+            assert_eq!(active, fruit_type == FruitType::Watermelon);
+        }
+    }
+
+    #[test]
+    fn test_apply_camera_punch_settles_to_unit_scale() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, apply_camera_punch);
+        let camera = app
+            .world_mut()
+            .spawn((
+                Camera2d,
+                Projection::Orthographic(OrthographicProjection::default_2d()),
+                CameraPunch {
+                    zoom: 0.1,
+                    elapsed: PUNCH_IN_FRACTION,
+                    duration: 1.0,
+                },
+            ))
+            .id();
+        let scale = |app: &App| match app.world().get::<Projection>(camera).unwrap() {
+            Projection::Orthographic(ortho) => ortho.scale,
+            _ => unreachable!(),
+        };
+
+        app.update();
+        assert!(scale(&app) < 1.0, "camera is zoomed in mid-punch");
+
+        app.world_mut()
+            .get_mut::<CameraPunch>(camera)
+            .unwrap()
+            .elapsed = 1.0;
+        app.update();
+        assert_eq!(scale(&app), 1.0);
+        assert!(!app.world().get::<CameraPunch>(camera).unwrap().is_active());
+    }
+}
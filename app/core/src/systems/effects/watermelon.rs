@@ -9,6 +9,7 @@
 //!   (green rind, red flesh, white sparkle) that fly outward with gravity.
 //! - **Extra camera trauma**: directly adds to [`CameraShake`] to guarantee the
 //!   camera shake is at maximum regardless of the regular `add_camera_shake` result.
+//!   Skipped while [`SettingsResource::shake_effects`] is off.
 //!
 //! All parameters are read from `assets/config/effects/watermelon.ron` when
 //! loaded, falling back to the `DEFAULT_*` constants otherwise.
@@ -19,6 +20,7 @@ use rand::RngExt;
 use crate::config::WatermelonParams;
 use crate::events::FruitMergeEvent;
 use crate::fruit::FruitType;
+use crate::resources::SettingsResource;
 use crate::systems::effects::shake::CameraShake;
 
 // ---------------------------------------------------------------------------
@@ -105,8 +107,10 @@ pub fn spawn_watermelon_effects(
     mut merge_events: MessageReader<FruitMergeEvent>,
     mut shake_query: Query<&mut CameraShake>,
     config: WatermelonParams<'_>,
+    settings: Option<Res<SettingsResource>>,
 ) {
     let cfg = config.get();
+    let shake_on = settings.is_none_or(|s| s.shake_effects);
 
     let ring_duration = cfg
         .map(|c| c.ring_duration)
@@ -148,7 +152,7 @@ pub fn spawn_watermelon_effects(
         let pos = event.position;
 
         // Max camera trauma ensures a dramatic shake on every Watermelon merge
-        if let Ok(mut shake) = shake_query.single_mut() {
+        if shake_on && let Ok(mut shake) = shake_query.single_mut() {
             shake.add_trauma(1.0);
         }
 
@@ -444,4 +448,37 @@ mod tests {
             "Ring entity should be despawned when duration is reached"
         );
     }
+
+    #[test]
+    fn test_spawn_watermelon_effects_respects_shake_setting() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<FruitMergeEvent>();
+        app.insert_resource(SettingsResource {
+            shake_effects: false,
+            ..default()
+        });
+        app.add_systems(Update, spawn_watermelon_effects);
+        let camera = app.world_mut().spawn(CameraShake::default()).id();
+
+        app.world_mut().write_message(FruitMergeEvent {
+            entity1: Entity::PLACEHOLDER,
+            entity2: Entity::PLACEHOLDER,
+            fruit_type: FruitType::Melon,
+            position: Vec2::ZERO,
+        });
+        app.update();
+
+        let shake = app.world().get::<CameraShake>(camera).unwrap();
+        assert_eq!(shake.trauma, 0.0, "no trauma while shake is switched off");
+        let ring_count = app
+            .world_mut()
+            .query::<&WatermelonExplosionRing>()
+            .iter(app.world())
+            .count();
+        assert_eq!(
+            ring_count, 1,
+            "the ring does not depend on the shake setting"
+        );
+    }
 }
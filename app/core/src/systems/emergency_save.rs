@@ -0,0 +1,250 @@
+//! Crash-safe emergency save
+//!
+//! A panic deep into a record run would otherwise lose the board, the session
+//! stats and a highscore that is only written on game over.  While playing,
+//! `update_emergency_snapshot` keeps a copy of everything worth saving in a
+//! process-wide slot; the panic hook installed by [`install_panic_hook`]
+//! writes that copy to disk before the default hook prints the panic:
+//!
+//! | Data          | File                               | Condition                      |
+//! |---------------|------------------------------------|--------------------------------|
+//! | Board         | `save/session.json`                | always (offered as "Continue") |
+//! | Session stats | `save/sessions/session-<t>.json`   | `export_session_logs` enabled  |
+//! | Highscore     | `save/highscore.json`              | score beats the stored one     |
+//!
+//! The slot is cleared when the game ends normally (game over, quit to
+//! title), so a later crash on a menu never resurrects a finished game.
+//! Native builds only: a wasm panic cannot run file I/O.
+
+use std::path::PathBuf;
+use std::sync::{Mutex, Once, TryLockError};
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::events::BoardSettledEvent;
+use crate::fruit::FruitType;
+use crate::persistence::{
+    SaveError, SessionData, SessionSummary, save_session, save_session_summary, update_highscore,
+};
+use crate::profiles::CurrentProfile;
+use crate::resources::{FruitQueue, GameState, NextFruitType, SessionStats, SettingsResource};
+use crate::systems::board::capture_board;
+
+/// Seconds between snapshots while the score is unchanged.
+pub const SNAPSHOT_INTERVAL_SECS: f32 = 0.5;
+
+/// Latest state to write if the process panics.
+static SNAPSHOT: Mutex<Option<EmergencySnapshot>> = Mutex::new(None);
+
+// ---------------------------------------------------------------------------
+// Snapshot
+// ---------------------------------------------------------------------------
+
+/// Everything the panic hook writes to disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmergencySnapshot {
+    /// Directory the files are written to.
+    pub save_dir: PathBuf,
+    /// Board, score and held fruit.
+    pub session: SessionData,
+    /// Session stats, when the player opted into session logs.
+    pub summary: Option<SessionSummary>,
+}
+
+impl EmergencySnapshot {
+    /// Writes the snapshot, continuing past individual failures.
+    ///
+    /// Returns every error encountered so the caller can report them.
+    pub fn write(&self) -> Vec<SaveError> {
+        let mut errors = Vec::new();
+        if let Err(e) = save_session(&self.session, &self.save_dir) {
+            errors.push(e);
+        }
+        if let Some(summary) = &self.summary
+            && let Err(e) = save_session_summary(summary, &self.save_dir)
+        {
+            errors.push(e);
+        }
+        if let Err(e) = update_highscore(self.session.score, &self.save_dir) {
+            errors.push(e);
+        }
+        errors
+    }
+}
+
+/// Replaces the snapshot the panic hook will write.
+fn store_snapshot(snapshot: Option<EmergencySnapshot>) {
+    *SNAPSHOT.lock().unwrap_or_else(|e| e.into_inner()) = snapshot;
+}
+
+/// Takes the pending snapshot without blocking.
+///
+/// A panic while another thread holds the lock skips the save rather than
+/// deadlocking inside the hook.
+fn take_snapshot() -> Option<EmergencySnapshot> {
+    match SNAPSHOT.try_lock() {
+        Ok(mut slot) => slot.take(),
+        Err(TryLockError::Poisoned(e)) => e.into_inner().take(),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Panic hook
+// ---------------------------------------------------------------------------
+
+/// Chains the emergency save in front of the current panic hook.
+///
+/// Safe to call more than once; only the first call installs the hook.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if let Some(snapshot) = take_snapshot() {
+                // The logger may be what panicked; write straight to stderr
+                eprintln!(
+                    "Panic during a game: saving score {} to {}",
+                    snapshot.session.score,
+                    snapshot.save_dir.display()
+                );
+                for e in snapshot.write() {
+                    eprintln!("Emergency save failed: {e}");
+                }
+            }
+            previous(info);
+        }));
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Refreshes the emergency snapshot while playing.
+///
+/// Runs every frame in `Playing` but only rebuilds the snapshot when the
+/// score changed, [`SNAPSHOT_INTERVAL_SECS`] have passed, or the board has
+/// just settled ([`BoardSettledEvent`]) — a safe point with no fruit in
+/// flight.
+#[allow(clippy::too_many_arguments)]
+pub fn update_emergency_snapshot(
+    time: Res<Time>,
+    mut settled_events: MessageReader<BoardSettledEvent>,
+    game_state: Res<GameState>,
+    next_fruit: Res<NextFruitType>,
+    queue: Res<FruitQueue>,
+    stats: Res<SessionStats>,
+    settings: Res<SettingsResource>,
+    profile: Res<CurrentProfile>,
+    fruit_query: Query<(&Transform, &FruitType, &FruitSpawnState, Option<&Velocity>), With<Fruit>>,
+    mut since_last: Local<f32>,
+    mut last_score: Local<Option<u32>>,
+) {
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    *since_last += time.delta_secs();
+    let settled = settled_events.read().count() > 0;
+    if !settled && *last_score == Some(game_state.score) && *since_last < SNAPSHOT_INTERVAL_SECS {
+        return;
+    }
+    *since_last = 0.0;
+    *last_score = Some(game_state.score);
+
+    let summary = settings
+        .export_session_logs
+        .then(|| stats.to_summary(game_state.score, game_state.elapsed_time));
+    store_snapshot(Some(EmergencySnapshot {
+        save_dir: dir.to_path_buf(),
+        session: capture_board(
+            &game_state,
+            &queue.with_next(&next_fruit),
+            fruit_query.iter(),
+        ),
+        summary,
+    }));
+}
+
+/// Drops the snapshot once the game has ended through normal means.
+pub fn clear_emergency_snapshot() {
+    store_snapshot(None);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::{HighscoreData, load_highscore, load_session, save_highscore};
+    use tempfile::TempDir;
+
+    fn snapshot(save_dir: PathBuf, score: u32, summary: bool) -> EmergencySnapshot {
+        EmergencySnapshot {
+            save_dir,
+            session: SessionData {
+                score,
+                elapsed_time: 300.0,
+                drops: 40,
+                held_fruit: FruitType::Cherry,
+                queue: Vec::new(),
+                fruits: Vec::new(),
+            },
+            summary: summary.then(|| SessionStats::default().to_summary(score, 300.0)),
+        }
+    }
+
+    #[test]
+    fn test_write_saves_session_and_new_highscore() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().to_path_buf();
+        save_highscore(
+            &HighscoreData {
+                highscore: 100,
+                ..Default::default()
+            },
+            &dir,
+        )
+        .unwrap();
+
+        let errors = snapshot(dir.clone(), 5000, true).write();
+        assert!(errors.is_empty(), "{errors:?}");
+
+        assert_eq!(load_session(&dir).unwrap().score, 5000);
+        assert_eq!(load_highscore(&dir).highscore, 5000);
+        assert!(dir.join("sessions/session-0.json").exists());
+    }
+
+    #[test]
+    fn test_write_keeps_higher_stored_highscore() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().to_path_buf();
+        save_highscore(
+            &HighscoreData {
+                highscore: 9000,
+                ..Default::default()
+            },
+            &dir,
+        )
+        .unwrap();
+
+        snapshot(dir.clone(), 10, false).write();
+
+        assert_eq!(load_highscore(&dir).highscore, 9000);
+        assert!(!dir.join("sessions").exists());
+    }
+
+    #[test]
+    fn test_take_snapshot_empties_the_slot() {
+        let temp_dir = TempDir::new().unwrap();
+        let expected = snapshot(temp_dir.path().to_path_buf(), 42, false);
+
+        store_snapshot(Some(expected.clone()));
+        assert_eq!(take_snapshot(), Some(expected));
+        assert_eq!(take_snapshot(), None);
+    }
+}
@@ -0,0 +1,183 @@
+//! Out-of-container escape recovery
+//!
+//! A fruit that tunnels through a wall or the floor, or is flung far above
+//! the container, falls forever outside the play area: it can never merge,
+//! and the boundary check keeps seeing (or missing) it.  Config hot-reload
+//! already deletes out-of-bounds fruits when the container shrinks;
+//! [`recover_escaped_fruits`] catches the ones that escape during normal
+//! play:
+//!
+//! - the first [`MAX_ESCAPE_RETURNS`] times, the fruit is put back above
+//!   the pile at the nearest x inside the walls, at rest;
+//! - after that it is removed and its merge points are refunded to the
+//!   score, so a fruit the solver keeps losing cannot stall the game.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::{FruitsParams, PhysicsParams};
+use crate::fruit::FruitType;
+use crate::resources::GameState;
+
+/// Times a fruit is put back before the next escape removes it.
+pub const MAX_ESCAPE_RETURNS: u32 = 2;
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// How often a fruit has been put back into the container.
+///
+/// Inserted by [`recover_escaped_fruits`] on the first return.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EscapeCount(pub u32);
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Returns or removes every dropped fruit that has left the container.
+#[allow(clippy::type_complexity)]
+pub fn recover_escaped_fruits(
+    mut commands: Commands,
+    physics: PhysicsParams,
+    fruits_config: FruitsParams,
+    mut game_state: ResMut<GameState>,
+    mut fruits: Query<
+        (
+            Entity,
+            &FruitType,
+            &FruitSpawnState,
+            &mut Transform,
+            Option<&mut Velocity>,
+            Option<&EscapeCount>,
+        ),
+        With<Fruit>,
+    >,
+) {
+    let (Some(physics), Some(fruits_config)) = (physics.get(), fruits_config.get()) else {
+        return;
+    };
+    let half = Vec2::new(physics.container_width, physics.container_height) / 2.0;
+    let radius_of = |fruit_type: &FruitType| {
+        fruit_type
+            .try_parameters_from_config(fruits_config)
+            .map(|p| p.radius)
+    };
+
+    let escaped: Vec<Entity> = fruits
+        .iter()
+        .filter(|(_, fruit_type, state, transform, _, _)| {
+            **state != FruitSpawnState::Held
+                && radius_of(*fruit_type)
+                    .is_some_and(|r| has_escaped(transform.translation.truncate(), r, half))
+        })
+        .map(|(entity, ..)| entity)
+        .collect();
+    if escaped.is_empty() {
+        return;
+    }
+
+    // Top of the pile among the fruits still inside
+    let pile_top = fruits
+        .iter()
+        .filter(|(entity, _, state, _, _, _)| {
+            **state == FruitSpawnState::Landed && !escaped.contains(entity)
+        })
+        .filter_map(|(_, fruit_type, _, transform, _, _)| {
+            Some(transform.translation.y + radius_of(fruit_type)?)
+        })
+        .fold(-half.y, f32::max);
+
+    for entity in escaped {
+        let Ok((_, fruit_type, _, mut transform, velocity, count)) = fruits.get_mut(entity) else {
+            continue;
+        };
+        let Some(params) = fruit_type.try_parameters_from_config(fruits_config) else {
+            continue;
+        };
+        let from = transform.translation.truncate();
+        let returns = count.map_or(0, |c| c.0);
+
+        if returns >= MAX_ESCAPE_RETURNS {
+            commands.entity(entity).despawn();
+            game_state.score = game_state.score.saturating_add(params.points);
+            warn!(
+                "🚪 {:?} escaped the container again at ({:.1}, {:.1}); removed, refunded {} points",
+                fruit_type, from.x, from.y, params.points
+            );
+            continue;
+        }
+
+        let to = return_position(from, params.radius, pile_top, half);
+        transform.translation.x = to.x;
+        transform.translation.y = to.y;
+        if let Some(mut velocity) = velocity {
+            *velocity = Velocity::zero();
+        }
+        commands.entity(entity).insert(EscapeCount(returns + 1));
+        warn!(
+            "🚪 {:?} escaped the container at ({:.1}, {:.1}); returned to ({:.1}, {:.1})",
+            fruit_type, from.x, from.y, to.x, to.y
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// `true` when a fruit at `position` with `radius` is entirely past a wall
+/// or the floor of the container (inner half size `half`), or more than a
+/// container height above its top.
+///
+/// Fruits only partly inside a wall are the stuck-fruit watchdog's job.
+fn has_escaped(position: Vec2, radius: f32, half: Vec2) -> bool {
+    position.x.abs() > half.x + radius || position.y < -half.y - radius || position.y > 3.0 * half.y
+}
+
+/// Where an escaped fruit is put back: its x pulled inside the walls, just
+/// above `pile_top` and no higher than the container top.
+fn return_position(from: Vec2, radius: f32, pile_top: f32, half: Vec2) -> Vec2 {
+    let max_x = (half.x - radius).max(0.0);
+    let y = (pile_top + radius).min(half.y - radius);
+    Vec2::new(from.x.clamp(-max_x, max_x), y)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF: Vec2 = Vec2::new(300.0, 400.0);
+
+    #[test]
+    fn test_has_escaped() {
+        assert!(!has_escaped(Vec2::ZERO, 20.0, HALF));
+        // Half inside the right wall: stuck, not escaped
+        assert!(!has_escaped(Vec2::new(310.0, 0.0), 20.0, HALF));
+        assert!(has_escaped(Vec2::new(321.0, 0.0), 20.0, HALF));
+        assert!(has_escaped(Vec2::new(-321.0, 0.0), 20.0, HALF));
+        assert!(has_escaped(Vec2::new(0.0, -421.0), 20.0, HALF));
+        // Bouncing a little above the open top is fine
+        assert!(!has_escaped(Vec2::new(0.0, 450.0), 20.0, HALF));
+        assert!(has_escaped(Vec2::new(0.0, 1300.0), 20.0, HALF));
+    }
+
+    #[test]
+    fn test_return_position_lands_on_pile_inside_walls() {
+        assert_eq!(
+            return_position(Vec2::new(500.0, -600.0), 20.0, -100.0, HALF),
+            Vec2::new(280.0, -80.0)
+        );
+        // A pile reaching the top never puts the fruit above the container
+        assert_eq!(
+            return_position(Vec2::new(-10.0, -600.0), 20.0, 395.0, HALF),
+            Vec2::new(-10.0, 380.0)
+        );
+    }
+}
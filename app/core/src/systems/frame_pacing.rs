@@ -0,0 +1,139 @@
+//! Frame pacing: vsync and the FPS cap
+//!
+//! Applies [`SettingsResource::vsync`] to the primary window's present mode
+//! and enforces [`SettingsResource::fps_cap`] by sleeping off the rest of
+//! each frame.  While the window is in the background
+//! ([`BackgroundThrottle`]) the window always presents with vsync and the
+//! cap is left to the winit low-power mode.
+
+use bevy::prelude::*;
+use bevy::window::{PresentMode, PrimaryWindow};
+
+use crate::resources::SettingsResource;
+use crate::systems::background::BackgroundThrottle;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Sets the primary window's present mode from the vsync setting.
+///
+/// Registered with `run_if` on changes to [`SettingsResource`] or
+/// [`BackgroundThrottle`]; only writes the window when the mode differs so
+/// the surface is not reconfigured needlessly.
+pub fn apply_present_mode(
+    settings: Res<SettingsResource>,
+    throttle: Option<Res<BackgroundThrottle>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    let background = throttle.is_some_and(|t| t.active);
+    let mode = present_mode(settings.vsync, background);
+    if window.present_mode != mode {
+        info!("Present mode: {mode:?}");
+        window.present_mode = mode;
+    }
+}
+
+/// Sleeps until the frame has taken `1 / fps_cap` seconds.
+///
+/// Runs at the end of [`Last`].  Does nothing while uncapped or throttled in
+/// the background.  `std::thread::sleep` is unavailable on the web, where the
+/// browser paces frames itself.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn limit_frame_rate(
+    settings: Res<SettingsResource>,
+    throttle: Option<Res<BackgroundThrottle>>,
+    mut frame_start: Local<Option<std::time::Instant>>,
+) {
+    let background = throttle.is_some_and(|t| t.active);
+    let remaining = frame_start
+        .filter(|_| !background)
+        .and_then(|start| frame_sleep(settings.fps_cap, start.elapsed()));
+    if let Some(remaining) = remaining {
+        std::thread::sleep(remaining);
+    }
+    *frame_start = Some(std::time::Instant::now());
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Present mode for the vsync setting; the background always uses vsync.
+fn present_mode(vsync: bool, background: bool) -> PresentMode {
+    if vsync || background {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    }
+}
+
+/// How long to sleep after a frame that took `elapsed` to stay under
+/// `fps_cap`; `None` when uncapped or the frame already took long enough.
+fn frame_sleep(fps_cap: u16, elapsed: std::time::Duration) -> Option<std::time::Duration> {
+    if fps_cap == 0 {
+        return None;
+    }
+    let target = std::time::Duration::from_secs_f64(1.0 / f64::from(fps_cap));
+    target.checked_sub(elapsed).filter(|d| !d.is_zero())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_present_mode() {
+        assert_eq!(present_mode(true, false), PresentMode::AutoVsync);
+        assert_eq!(present_mode(false, false), PresentMode::AutoNoVsync);
+        assert_eq!(present_mode(false, true), PresentMode::AutoVsync);
+    }
+
+    #[test]
+    fn test_frame_sleep() {
+        assert_eq!(frame_sleep(0, Duration::ZERO), None);
+        assert_eq!(
+            frame_sleep(50, Duration::from_millis(5)),
+            Some(Duration::from_millis(15))
+        );
+        assert_eq!(frame_sleep(50, Duration::from_millis(25)), None);
+    }
+
+    #[test]
+    fn test_vsync_setting_applies_to_window() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(SettingsResource {
+                vsync: false,
+                ..default()
+            })
+            .add_systems(Update, apply_present_mode);
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Window>(window).unwrap().present_mode,
+            PresentMode::AutoNoVsync
+        );
+
+        app.init_resource::<BackgroundThrottle>();
+        app.world_mut().resource_mut::<BackgroundThrottle>().active = true;
+        app.update();
+        assert_eq!(
+            app.world().get::<Window>(window).unwrap().present_mode,
+            PresentMode::AutoVsync
+        );
+    }
+}
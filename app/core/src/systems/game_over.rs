@@ -5,13 +5,17 @@
 //! - `tick_elapsed_time` — runs every frame during `AppState::Playing`.
 //!   Increments [`GameState::elapsed_time`] so the HUD can display a live timer.
 //!
+//! - `count_drops` — runs every frame during `AppState::Playing`.
+//!   Increments [`GameState::drops`] for every dropped fruit.
+//!
 //! - `save_highscore_on_game_over` — runs on `OnEnter(AppState::GameOver)`
-//!   inside [`GameOverSet::SaveHighscore`].  Compares the current score with
-//!   the stored highscore and writes to disk when a new record is set.
+//!   inside [`GameOverSet::SaveHighscore`].  Compares the current score, best
+//!   combo and largest fruit with the stored records and writes to disk when
+//!   any of them is beaten.
 //!
 //! - `reset_game_state` — runs on `OnEnter(AppState::Playing)`.
 //!   Clears all in-game resources and despawns existing fruits so each new
-//!   game starts from a clean slate.  The highscore is intentionally preserved.
+//!   game starts from a clean slate.  The records are intentionally preserved.
 //!
 //! ## Ordering for downstream crates
 //!
@@ -36,15 +40,16 @@ pub enum GameOverSet {
     /// Contains [`save_highscore_on_game_over`].
     ///
     /// Runs on `OnEnter(AppState::GameOver)`.  After this set completes,
-    /// [`GameState::is_new_record`] and [`GameState::highscore`] are
-    /// up-to-date and safe to read.
+    /// [`GameState::is_new_record`], [`GameState::highscore`] and the other
+    /// records are up-to-date and safe to read.
     SaveHighscore,
 }
 
 use crate::components::Fruit;
-use crate::constants::storage::SAVE_DIR;
-use crate::persistence::{HighscoreData, save_highscore};
-use crate::resources::{ComboTimer, GameOverTimer, GameState};
+use crate::events::{FruitDroppedEvent, SaveErrorEvent};
+use crate::persistence::{HighscoreData, report_save_error, save_highscore};
+use crate::profiles::CurrentProfile;
+use crate::resources::{ComboTimer, GameOverTimer, GameState, SessionStats};
 use crate::systems::input::{InputMode, SpawnPosition};
 
 // ---------------------------------------------------------------------------
@@ -59,34 +64,63 @@ pub fn tick_elapsed_time(mut game_state: ResMut<GameState>, time: Res<Time>) {
     game_state.elapsed_time += time.delta_secs();
 }
 
-/// Saves the highscore to disk when the game ends.
+/// Adds every [`FruitDroppedEvent`] to [`GameState::drops`].
+pub fn count_drops(
+    mut dropped_events: MessageReader<FruitDroppedEvent>,
+    mut game_state: ResMut<GameState>,
+) {
+    let dropped = dropped_events.read().count() as u32;
+    if dropped > 0 {
+        game_state.drops += dropped;
+    }
+}
+
+/// Saves the highscore and the other records to disk when the game ends.
 ///
-/// Only writes to disk when the current score exceeds the stored highscore.
+/// Folds the final score, the game's best combo and its largest fruit (from
+/// [`SessionStats`]) into the records kept on [`GameState`].  Only writes to
+/// disk when one of them was beaten and persistence is enabled
+/// ([`CurrentProfile`] has a directory).  The in-memory records and
+/// `is_new_record` (score only) are updated either way; a failed write is
+/// reported as a [`SaveErrorEvent`].
 /// Runs once on `OnEnter(AppState::GameOver)`.
-pub fn save_highscore_on_game_over(mut game_state: ResMut<GameState>) {
-    if game_state.score > game_state.highscore {
+pub fn save_highscore_on_game_over(
+    mut game_state: ResMut<GameState>,
+    session: Res<SessionStats>,
+    profile: Res<CurrentProfile>,
+    mut errors: MessageWriter<SaveErrorEvent>,
+) {
+    let mut data = HighscoreData {
+        highscore: game_state.highscore,
+        best_combo: game_state.best_combo_record,
+        highest_fruit: game_state.highest_fruit_record,
+    };
+    game_state.is_new_record = game_state.score > game_state.highscore;
+    if game_state.is_new_record {
         info!(
             "New highscore! {} → {}",
             game_state.highscore, game_state.score
         );
-        game_state.is_new_record = true;
-        game_state.highscore = game_state.score;
-
-        let data = HighscoreData {
-            highscore: game_state.highscore,
-        };
-
-        match save_highscore(&data, std::path::Path::new(SAVE_DIR)) {
-            Ok(_) => info!("Highscore saved to {SAVE_DIR}/highscore.json"),
-            Err(e) => error!("Failed to save highscore: {e}"),
-        }
     } else {
-        game_state.is_new_record = false;
         info!(
             "Game over. Score: {} (Highscore: {})",
             game_state.score, game_state.highscore
         );
     }
+
+    if !data.record_game(game_state.score, session.best_combo, session.largest_fruit) {
+        return;
+    }
+    game_state.highscore = data.highscore;
+    game_state.best_combo_record = data.best_combo;
+    game_state.highest_fruit_record = data.highest_fruit;
+
+    if let Some(dir) = profile.path() {
+        match save_highscore(&data, dir) {
+            Ok(_) => info!("Records saved to {}/highscore.json", dir.display()),
+            Err(e) => report_save_error("Failed to save highscore", &e, &mut errors),
+        }
+    }
 }
 
 /// Resets all mutable game state and despawns existing fruits.
@@ -94,7 +128,7 @@ pub fn save_highscore_on_game_over(mut game_state: ResMut<GameState>) {
 /// Runs once on `OnEnter(AppState::Playing)` so that both the initial game
 /// start and any subsequent retries begin from a consistent state.
 ///
-/// The highscore is **not** reset.
+/// The highscore and the other records are **not** reset.
 pub fn reset_game_state(
     mut commands: Commands,
     mut game_state: ResMut<GameState>,
@@ -109,7 +143,10 @@ pub fn reset_game_state(
     *game_state = GameState {
         score: 0,
         highscore,
+        best_combo_record: game_state.best_combo_record,
+        highest_fruit_record: game_state.highest_fruit_record,
         elapsed_time: 0.0,
+        drops: 0,
         is_new_record: false,
     };
     combo_timer.reset_session();
@@ -141,7 +178,10 @@ mod tests {
         let mut state = GameState {
             score: 5000,
             highscore: 8000,
+            best_combo_record: 0,
+            highest_fruit_record: None,
             elapsed_time: 42.0,
+            drops: 30,
             is_new_record: true,
         };
 
@@ -149,13 +189,17 @@ mod tests {
         state = GameState {
             score: 0,
             highscore,
+            best_combo_record: 0,
+            highest_fruit_record: None,
             elapsed_time: 0.0,
+            drops: 0,
             is_new_record: false,
         };
 
         assert_eq!(state.score, 0);
         assert_eq!(state.highscore, 8000);
         assert_eq!(state.elapsed_time, 0.0);
+        assert_eq!(state.drops, 0);
         assert!(!state.is_new_record);
     }
 
@@ -177,4 +221,61 @@ mod tests {
         };
         assert_eq!(new_highscore2, 10000);
     }
+
+    #[test]
+    fn test_save_highscore_on_game_over_keeps_combo_and_fruit_records() {
+        use crate::fruit::FruitType;
+        use crate::persistence::load_highscore;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let profile = CurrentProfile::new("Player", Some(temp_dir.path()));
+        let dir = profile.path().unwrap().to_path_buf();
+
+        let mut app = App::new();
+        app.add_message::<SaveErrorEvent>()
+            .insert_resource(profile)
+            .insert_resource(GameState {
+                score: 300,
+                highscore: 1000,
+                best_combo_record: 2,
+                ..default()
+            })
+            .insert_resource(SessionStats {
+                best_combo: 4,
+                largest_fruit: Some(FruitType::Peach),
+                ..default()
+            })
+            .add_systems(Update, save_highscore_on_game_over);
+        app.update();
+
+        let state = app.world().resource::<GameState>();
+        assert!(!state.is_new_record, "the score record was not beaten");
+        assert_eq!(state.highscore, 1000);
+        assert_eq!(state.best_combo_record, 4);
+        assert_eq!(state.highest_fruit_record, Some(FruitType::Peach));
+
+        let saved = load_highscore(&dir);
+        assert_eq!(saved.highscore, 1000);
+        assert_eq!(saved.best_combo, 4);
+        assert_eq!(saved.highest_fruit, Some(FruitType::Peach));
+    }
+
+    #[test]
+    fn test_count_drops_adds_every_drop() {
+        use crate::fruit::FruitType;
+
+        let mut app = App::new();
+        app.add_message::<FruitDroppedEvent>()
+            .init_resource::<GameState>()
+            .add_systems(Update, count_drops);
+
+        for _ in 0..3 {
+            app.world_mut().write_message(FruitDroppedEvent {
+                fruit_type: FruitType::Cherry,
+                position: Vec2::ZERO,
+            });
+        }
+        app.update();
+        assert_eq!(app.world().resource::<GameState>().drops, 3);
+    }
 }
@@ -0,0 +1,221 @@
+//! Ghost run
+//!
+//! With [`SettingsResource::ghost_run`] on, the player races the highscore
+//! game ([`BestRun`]): whenever the game clock passes the moment the best
+//! run dropped a fruit, a translucent copy of that fruit appears where it
+//! was released and fades out over [`GHOST_MARKER_SECS`].  The UI shows the
+//! score difference at the same moment next to the drop counter.
+//!
+//! The markers are only a pace hint — they do not fall and have no
+//! collider.  Sandbox games and attract-mode demos show no ghost.
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::config::FruitsParams;
+use crate::persistence::{BestRun, DropSample};
+use crate::resources::{CircleTexture, FruitSprites, GameState, SettingsResource};
+use crate::states::AppState;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Opacity of a fresh ghost marker.
+pub const GHOST_MARKER_ALPHA: f32 = 0.35;
+
+/// Seconds a ghost marker takes to fade out.
+pub const GHOST_MARKER_SECS: f32 = 1.2;
+
+/// Marker z-layer: behind the fruits, so the real pile stays readable.
+const GHOST_MARKER_Z: f32 = -0.5;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// A best-run drop shown as a fading fruit.
+///
+/// # Lifecycle
+///
+/// Spawned by [`spawn_ghost_markers`]; despawned by [`fade_ghost_markers`]
+/// once faded, or when leaving [`AppState::Playing`].
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct GhostMarker {
+    /// Seconds left before the marker is gone
+    pub remaining: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` while the ghost run is switched on and there is a run to race.
+pub fn ghost_run_enabled(settings: Res<SettingsResource>, best_run: Option<Res<BestRun>>) -> bool {
+    settings.ghost_run && best_run.is_some_and(|run| !run.is_empty())
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns a [`GhostMarker`] for every best-run drop the game clock passed
+/// this frame.
+///
+/// `last_time` is the clock seen last frame; a clock that went back means a
+/// new game started.
+pub fn spawn_ghost_markers(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    best_run: Res<BestRun>,
+    fruits: FruitsParams,
+    circle_texture: Res<CircleTexture>,
+    fruit_sprites: Option<Res<FruitSprites>>,
+    mut last_time: Local<f32>,
+) {
+    let now = game_state.elapsed_time;
+    if now < *last_time {
+        *last_time = 0.0;
+    }
+    let Some(config) = fruits.get() else {
+        return;
+    };
+
+    for drop in drops_between(&best_run, *last_time, now) {
+        let Some(params) = drop.fruit_type.try_parameters_from_config(config) else {
+            continue;
+        };
+        let (image, color) = fruit_sprites
+            .as_deref()
+            .map(|s| s.resolve(drop.fruit_type, circle_texture.0.clone()))
+            .unwrap_or_else(|| {
+                (
+                    circle_texture.0.clone(),
+                    drop.fruit_type.placeholder_color(),
+                )
+            });
+        commands.spawn((
+            GhostMarker {
+                remaining: GHOST_MARKER_SECS,
+            },
+            Sprite {
+                image,
+                color: color.with_alpha(GHOST_MARKER_ALPHA),
+                custom_size: Some(Vec2::splat(params.radius * 2.0 * params.sprite_scale)),
+                texture_atlas: fruit_sprites
+                    .as_deref()
+                    .and_then(|s| s.atlas(drop.fruit_type)),
+                ..default()
+            },
+            Anchor(Vec2::new(params.sprite_anchor_x, params.sprite_anchor_y)),
+            Transform::from_xyz(drop.x, drop.y, GHOST_MARKER_Z),
+            DespawnOnExit(AppState::Playing),
+        ));
+    }
+    *last_time = now;
+}
+
+/// Fades [`GhostMarker`]s out and despawns the finished ones.
+pub fn fade_ghost_markers(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut GhostMarker, &mut Sprite)>,
+) {
+    for (entity, mut marker, mut sprite) in query.iter_mut() {
+        marker.remaining -= time.delta_secs();
+        if marker.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = GHOST_MARKER_ALPHA * marker.remaining / GHOST_MARKER_SECS;
+        sprite.color.set_alpha(alpha);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Drops of `run` made after `from` and up to `to` seconds into the game.
+fn drops_between(run: &BestRun, from: f32, to: f32) -> impl Iterator<Item = &DropSample> {
+    run.drops
+        .iter()
+        .filter(move |drop| drop.time > from && drop.time <= to)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fruit::FruitType;
+
+    fn run_with_drops(times: &[f32]) -> BestRun {
+        BestRun {
+            drops: times
+                .iter()
+                .map(|&time| DropSample {
+                    time,
+                    x: 0.0,
+                    y: 0.0,
+                    fruit_type: FruitType::Cherry,
+                })
+                .collect(),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_drops_between_is_half_open() {
+        let run = run_with_drops(&[0.5, 1.0, 2.0]);
+        let times: Vec<f32> = drops_between(&run, 0.5, 2.0).map(|d| d.time).collect();
+        assert_eq!(times, [1.0, 2.0]);
+        assert_eq!(drops_between(&run, 2.0, 3.0).count(), 0);
+    }
+
+    #[test]
+    fn test_ghost_run_needs_setting_and_run() {
+        let mut app = App::new();
+        app.init_resource::<SettingsResource>()
+            .init_resource::<BestRun>();
+        let enabled = |app: &mut App| {
+            app.world_mut()
+                .run_system_cached(ghost_run_enabled)
+                .unwrap()
+        };
+        assert!(!enabled(&mut app));
+
+        app.world_mut().resource_mut::<SettingsResource>().ghost_run = true;
+        assert!(!enabled(&mut app));
+
+        app.insert_resource(run_with_drops(&[1.0]));
+        assert!(enabled(&mut app));
+    }
+
+    #[test]
+    fn test_markers_fade_and_despawn() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, fade_ghost_markers);
+        let marker = app
+            .world_mut()
+            .spawn((
+                GhostMarker {
+                    remaining: GHOST_MARKER_SECS,
+                },
+                Sprite::default(),
+            ))
+            .id();
+        app.update();
+        assert!(app.world().get::<GhostMarker>(marker).is_some());
+
+        app.world_mut()
+            .get_mut::<GhostMarker>(marker)
+            .unwrap()
+            .remaining = 0.0;
+        app.update();
+        assert!(app.world().get_entity(marker).is_err());
+    }
+}
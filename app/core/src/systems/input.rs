@@ -2,13 +2,15 @@
 //!
 //! This module handles player input for fruit control, including:
 //! - Spawning a held fruit at the start
-//! - Mouse position and arrow keys (←→ or A/D) for position control
-//! - Space key or mouse click to drop the fruit
+//! - Pointer aim and the `MoveLeft` / `MoveRight` actions for position control
+//! - The `Drop` action to drop the fruit
 //! - Automatic spawning of next fruit after drop
+//!
+//! Device input reaches these systems only through
+//! [`ActionState`](crate::systems::action::ActionState).
 
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
-use bevy::window::PrimaryWindow;
 use bevy_rapier2d::prelude::*;
 
 #[cfg(test)]
@@ -19,15 +21,21 @@ use crate::config::{
     FruitsConfig, FruitsConfigHandle, GameRulesConfig, GameRulesConfigHandle, PhysicsConfig,
     PhysicsConfigHandle,
 };
+use crate::events::FruitDroppedEvent;
 use crate::fruit::FruitType;
-use crate::resources::{CircleTexture, FruitSprites, NextFruitType};
+use crate::resources::{
+    CircleTexture, FruitQueue, FruitSprites, NextFruitType, SessionStats, SpawnRng,
+};
+use crate::seed_code::ChallengeSeed;
+use crate::systems::action::{ActionState, InputAction};
+use crate::systems::adaptive_spawn::AdaptiveSpawn;
 
 // ---------------------------------------------------------------------------
 // Default values for RON-loaded parameters (fallbacks before configs are loaded)
 // ---------------------------------------------------------------------------
 
 /// Default spawnable fruit count — mirrors `game_rules.ron` `spawnable_fruit_count`.
-const DEFAULT_SPAWNABLE_FRUIT_COUNT: usize = 5;
+pub const DEFAULT_SPAWNABLE_FRUIT_COUNT: usize = 5;
 /// Default keyboard move speed (px/s) — mirrors `physics.ron` `keyboard_move_speed`.
 const DEFAULT_KEYBOARD_MOVE_SPEED: f32 = 300.0;
 /// Default container width (px) — mirrors `physics.ron` `container_width`.
@@ -54,24 +62,25 @@ impl Default for SpawnPosition {
 
 /// Input mode for controlling fruit position
 ///
-/// Tracks whether the player is currently using keyboard or mouse input.
-/// The mode automatically switches based on which input device was used most recently.
+/// Tracks whether the player is currently moving the fruit with the
+/// `MoveLeft` / `MoveRight` actions or aiming with the pointer.
+/// The mode automatically switches based on which was used most recently.
 #[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum InputMode {
-    /// Player is using keyboard (arrow keys or A/D)
+    /// Player is using the move actions (arrow keys, A/D, D-pad or stick)
     /// Default so the held fruit starts at the container center (spawn_pos.x = 0)
     /// and only follows the mouse after the user actually moves the cursor.
     #[default]
     Keyboard,
-    /// Player is using mouse cursor
+    /// Player is aiming with the mouse cursor or a finger
     Mouse,
 }
 
 /// Tracks the last known cursor position to detect mouse movement
 ///
-/// Used to distinguish between actual mouse movement and position changes
-/// caused by keyboard input. Only switches to mouse mode when the cursor
-/// itself moves.
+/// Used by `collect_input_actions` to distinguish between actual mouse
+/// movement and a cursor resting over the window. Only switches to mouse
+/// mode when the cursor itself moves.
 #[derive(Resource, Debug, Clone, Default)]
 pub struct LastCursorPosition {
     /// Last known cursor position in world coordinates
@@ -85,7 +94,8 @@ pub struct LastCursorPosition {
 ///
 /// **Important**: Will NOT spawn if there's a falling fruit (waiting for it to land first).
 ///
-/// After spawning the fruit, the next fruit type is randomized for the preview display.
+/// After spawning the fruit, the [`FruitQueue`] moves forward: its front
+/// fruit becomes the next fruit and a fresh one is queued behind it.
 ///
 /// # System Parameters
 ///
@@ -93,10 +103,18 @@ pub struct LastCursorPosition {
 /// - `next_fruit`: The type of fruit to spawn (mutable to randomize after spawn)
 /// - `spawn_pos`: Current spawn position (X coordinate)
 /// - `fruit_states`: Query to check fruit spawn states
+/// - `queue`: Fruits queued after the next one
+/// - `spawn_rng`: Seeded RNG for newly queued fruits; falls back to the
+///   thread RNG when absent
+/// - `adaptive`: Adaptive spawn bias for newly queued fruits; ignored for
+///   challenge seeds so their sequence stays the same for everyone
+/// - `stats`: Loses its [`SessionStats::seed_code`] once a biased fruit is
+///   queued, since the seed no longer reproduces the run
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_held_fruit(
     mut commands: Commands,
     mut next_fruit: ResMut<NextFruitType>,
+    mut queue: ResMut<FruitQueue>,
     mut spawn_pos: ResMut<SpawnPosition>,
     fruit_states: Query<&FruitSpawnState, With<Fruit>>,
     fruits_config_handle: Res<FruitsConfigHandle>,
@@ -107,6 +125,10 @@ pub fn spawn_held_fruit(
     rules_config_assets: Option<Res<Assets<GameRulesConfig>>>,
     circle_texture: Res<CircleTexture>,
     fruit_sprites: Option<Res<FruitSprites>>,
+    mut spawn_rng: Option<ResMut<SpawnRng>>,
+    challenge: Option<Res<ChallengeSeed>>,
+    adaptive: Option<Res<AdaptiveSpawn>>,
+    mut stats: Option<ResMut<SessionStats>>,
 ) {
     // Get the configs, return early if not loaded yet
     let Some(fruits_config) = fruits_config_assets.get(&fruits_config_handle.0) else {
@@ -118,13 +140,22 @@ pub fn spawn_held_fruit(
         return;
     };
 
-    // Spawnable count from game rules (default to 5 if config not yet loaded)
-    let spawnable_count = rules_config_handle
-        .as_ref()
-        .zip(rules_config_assets.as_ref())
-        .and_then(|(h, a)| a.get(&h.0))
-        .map(|r| r.spawnable_fruit_count)
-        .unwrap_or(DEFAULT_SPAWNABLE_FRUIT_COUNT);
+    // Spawnable count from the seed code's difficulty, else from game rules
+    // (default to 5 if config not yet loaded)
+    let code = challenge.and_then(|c| c.0);
+    let spawnable_count = match code {
+        Some(code) => code.difficulty as usize,
+        None => rules_config_handle
+            .as_ref()
+            .zip(rules_config_assets.as_ref())
+            .and_then(|(h, a)| a.get(&h.0))
+            .map(|r| r.spawnable_fruit_count)
+            .unwrap_or(DEFAULT_SPAWNABLE_FRUIT_COUNT),
+    };
+    let bias = match code {
+        Some(_) => 0.0,
+        None => adaptive.map_or(0.0, |a| a.bias),
+    };
 
     // Count fruits by state in a single iteration
     let (held_count, falling_count, landed_count) =
@@ -178,6 +209,9 @@ pub fn spawn_held_fruit(
                     image,
                     color,
                     custom_size: Some(Vec2::splat(params.radius * 2.0 * params.sprite_scale)),
+                    texture_atlas: fruit_sprites
+                        .as_ref()
+                        .and_then(|s| s.atlas(next_fruit.get())),
                     ..default()
                 }
             },
@@ -196,9 +230,21 @@ pub fn spawn_held_fruit(
 
         info!("Spawned held fruit: {:?}", next_fruit.get());
 
-        // Randomize next fruit type for preview display
-        // This ensures the preview shows the NEXT fruit, not the current held fruit
-        next_fruit.randomize(spawnable_count);
+        // Move the queue forward so the HUD shows the fruits AFTER the one
+        // now held
+        match spawn_rng.as_deref_mut() {
+            Some(rng) => queue.advance_biased(&mut next_fruit, rng, spawnable_count, bias),
+            None => queue.advance(&mut next_fruit, spawnable_count),
+        }
+
+        // A nudged draw leaves the seed's sequence, so neither sharing nor
+        // "Retry (same seed)" could replay this run any more
+        if bias > 0.0
+            && let Some(stats) = stats.as_mut()
+            && stats.seed_code.take().is_some()
+        {
+            info!("Adaptive spawn changed the fruit sequence; this run has no seed code");
+        }
     }
 }
 
@@ -264,9 +310,8 @@ pub fn detect_fruit_landing(
 
 /// Handles player input for dropping held fruits
 ///
-/// Drops the currently held fruit when:
-/// - Mouse left button is pressed
-/// - Space key is pressed
+/// Drops the currently held fruit when the [`InputAction::Drop`] action
+/// starts (Space, left click, gamepad South or lifting a finger).
 ///
 /// After dropping, the fruit transitions from Held to Falling state,
 /// becomes a dynamic rigid body, and gets physics properties.
@@ -274,19 +319,19 @@ pub fn detect_fruit_landing(
 /// # System Parameters
 ///
 /// - `commands`: For adding/removing components
-/// - `mouse_button`: Mouse button input state
-/// - `keyboard`: Keyboard input state
+/// - `actions`: This frame's input actions
 /// - `held_fruits`: Query for held fruits to drop
+/// - `dropped_events`: Emits a [`FruitDroppedEvent`] for each dropped fruit
 #[allow(clippy::too_many_arguments)]
 pub fn handle_fruit_drop_input(
     mut commands: Commands,
-    mouse_button: Res<ButtonInput<MouseButton>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut held_fruits: Query<(Entity, &FruitType, &mut FruitSpawnState), With<Fruit>>,
+    actions: Res<ActionState>,
+    mut held_fruits: Query<(Entity, &FruitType, &Transform, &mut FruitSpawnState), With<Fruit>>,
     fruits_config_handle: Res<FruitsConfigHandle>,
     fruits_config_assets: Res<Assets<FruitsConfig>>,
     physics_config_handle: Res<PhysicsConfigHandle>,
     physics_config_assets: Res<Assets<PhysicsConfig>>,
+    mut dropped_events: MessageWriter<FruitDroppedEvent>,
 ) {
     // Get the configs, return early if not loaded yet
     let Some(fruits_config) = fruits_config_assets.get(&fruits_config_handle.0) else {
@@ -296,68 +341,95 @@ pub fn handle_fruit_drop_input(
         return;
     };
 
-    if mouse_button.just_pressed(MouseButton::Left) || keyboard.just_pressed(KeyCode::Space) {
-        for (entity, fruit_type, mut spawn_state) in held_fruits.iter_mut() {
+    if actions.just_pressed(InputAction::Drop) {
+        for (entity, fruit_type, transform, mut spawn_state) in held_fruits.iter_mut() {
             if *spawn_state == FruitSpawnState::Held {
-                // Transition to Falling state
-                *spawn_state = FruitSpawnState::Falling;
-
-                let params = fruit_type.parameters_from_config(fruits_config);
-
-                // Convert to dynamic rigid body with physics properties
-                // Reset velocity to prevent diagonal falling due to kinematic movement
-                commands.entity(entity).insert((
-                    RigidBody::Dynamic,
-                    Velocity::zero(), // Reset velocity to drop straight down
-                    Restitution {
-                        coefficient: params.restitution,
-                        combine_rule: CoefficientCombineRule::Min, // Use minimum restitution in collisions
-                    },
-                    Friction::coefficient(params.friction),
-                    ColliderMassProperties::Mass(params.mass),
-                    Damping {
-                        linear_damping: physics_config.fruit_linear_damping,
-                        angular_damping: physics_config.fruit_angular_damping,
-                    },
-                    GravityScale(1.0),
-                ));
-
-                info!("Dropped fruit: {:?}", fruit_type);
+                release_held_fruit(
+                    &mut commands,
+                    entity,
+                    *fruit_type,
+                    transform.translation.truncate(),
+                    &mut spawn_state,
+                    fruits_config,
+                    physics_config,
+                    &mut dropped_events,
+                );
             }
         }
     }
 }
 
+/// Drops a held fruit: it becomes a falling dynamic body and a
+/// [`FruitDroppedEvent`] is sent.
+///
+/// Shared by the player's drop input and the attract-mode bot.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn release_held_fruit(
+    commands: &mut Commands,
+    entity: Entity,
+    fruit_type: FruitType,
+    position: Vec2,
+    spawn_state: &mut FruitSpawnState,
+    fruits_config: &FruitsConfig,
+    physics_config: &PhysicsConfig,
+    dropped_events: &mut MessageWriter<FruitDroppedEvent>,
+) {
+    // Transition to Falling state
+    *spawn_state = FruitSpawnState::Falling;
+
+    let params = fruit_type.parameters_from_config(fruits_config);
+
+    // Convert to dynamic rigid body with physics properties
+    // Reset velocity to prevent diagonal falling due to kinematic movement
+    commands.entity(entity).insert((
+        RigidBody::Dynamic,
+        Velocity::zero(), // Reset velocity to drop straight down
+        Restitution {
+            coefficient: params.restitution,
+            combine_rule: CoefficientCombineRule::Min, // Use minimum restitution in collisions
+        },
+        Friction::coefficient(params.friction),
+        ColliderMassProperties::Mass(params.mass),
+        Damping {
+            linear_damping: physics_config.fruit_linear_damping,
+            angular_damping: physics_config.fruit_angular_damping,
+        },
+        GravityScale(1.0),
+    ));
+
+    dropped_events.write(FruitDroppedEvent {
+        fruit_type,
+        position,
+    });
+
+    info!("Dropped fruit: {:?}", fruit_type);
+}
+
 /// Updates the spawn position and held fruit position based on player input
 ///
-/// Updates spawn position from multiple input sources:
-/// 1. Arrow keys: Left/Right (←→) or A/D keys move horizontally
-/// 2. Mouse cursor: Position follows the mouse X coordinate
+/// Updates spawn position from two kinds of input:
+/// 1. `MoveLeft` / `MoveRight` actions move horizontally
+/// 2. Pointer aim: Position follows the mouse cursor or finger X coordinate
 ///
-/// The input mode automatically switches based on which input device was used most recently:
-/// - Pressing arrow/AD keys switches to keyboard mode
-/// - Moving the mouse cursor switches to mouse mode
+/// The input mode automatically switches based on which was used most recently:
+/// - A move action switches to keyboard mode
+/// - Moving the pointer switches to mouse mode
 ///
 /// Only fruits in the Held state are moved. Falling and Landed fruits are not affected.
 /// The final position is clamped to stay within container boundaries.
 ///
 /// # System Parameters
 ///
-/// - `keyboard`: Keyboard input state
-/// - `windows`: Query for the primary window (to get cursor position)
-/// - `camera_query`: Query for camera and its transform (for world position conversion)
+/// - `actions`: This frame's input actions and pointer aim
 /// - `spawn_pos`: Mutable spawn position resource to update
 /// - `input_mode`: Current input mode (keyboard or mouse)
 /// - `held_fruits`: Query for held fruits to move (only Held state)
 /// - `time`: Time resource for delta time (smooth movement with keys)
 #[allow(clippy::too_many_arguments)]
 pub fn update_spawn_position(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    windows: Query<&Window, With<PrimaryWindow>>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    actions: Res<ActionState>,
     mut spawn_pos: ResMut<SpawnPosition>,
     mut input_mode: ResMut<InputMode>,
-    mut last_cursor_pos: ResMut<LastCursorPosition>,
     mut held_fruits: Query<(&mut Transform, &FruitSpawnState, &FruitType), With<Fruit>>,
     time: Res<Time>,
     fruits_config_handle: Res<FruitsConfigHandle>,
@@ -368,57 +440,38 @@ pub fn update_spawn_position(
     // Get the configs
     let fruits_config = fruits_config_assets.get(&fruits_config_handle.0);
     let physics_config = physics_config_assets.get(&physics_config_handle.0);
-    // Check for keyboard input and switch mode if detected
-    let keyboard_input = keyboard.pressed(KeyCode::ArrowLeft)
-        || keyboard.pressed(KeyCode::KeyA)
-        || keyboard.pressed(KeyCode::ArrowRight)
-        || keyboard.pressed(KeyCode::KeyD);
+    // Check for a move action and switch mode if detected
+    let move_left = actions.pressed(InputAction::MoveLeft);
+    let move_right = actions.pressed(InputAction::MoveRight);
 
-    if keyboard_input {
+    if move_left || move_right {
         *input_mode = InputMode::Keyboard;
     }
 
-    // Handle keyboard movement (only in keyboard mode)
+    // Handle action movement (only in keyboard mode)
     if *input_mode == InputMode::Keyboard {
         let move_speed = physics_config
             .map(|c| c.keyboard_move_speed)
             .unwrap_or(DEFAULT_KEYBOARD_MOVE_SPEED);
 
-        if keyboard.pressed(KeyCode::ArrowLeft) || keyboard.pressed(KeyCode::KeyA) {
+        if move_left {
             spawn_pos.x -= move_speed * time.delta_secs();
         }
-        if keyboard.pressed(KeyCode::ArrowRight) || keyboard.pressed(KeyCode::KeyD) {
+        if move_right {
             spawn_pos.x += move_speed * time.delta_secs();
         }
     }
 
-    // Check for mouse movement and switch mode if detected
-    if let Ok(window) = windows.single()
-        && let Some(cursor_pos) = window.cursor_position()
-        && let Ok((camera, camera_transform)) = camera_query.single()
-    {
-        // Convert viewport coordinates to world coordinates
-        if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) {
-            // Detect actual mouse movement by comparing with last cursor position
-            const MOUSE_MOVEMENT_THRESHOLD: f32 = 1.0; // pixels
-            let mouse_moved = if let Some(last_pos) = last_cursor_pos.position {
-                (world_pos - last_pos).length() > MOUSE_MOVEMENT_THRESHOLD
-            } else {
-                false // First frame, don't switch to mouse mode yet
-            };
-
-            if mouse_moved {
-                *input_mode = InputMode::Mouse;
-            }
-
-            // Update last cursor position
-            last_cursor_pos.position = Some(world_pos);
+    // Check for pointer movement and switch mode if detected
+    if actions.aim_moved() {
+        *input_mode = InputMode::Mouse;
+    }
 
-            // Handle mouse cursor position (only in mouse mode)
-            if *input_mode == InputMode::Mouse {
-                spawn_pos.x = world_pos.x;
-            }
-        }
+    // Handle pointer position (only in mouse mode)
+    if *input_mode == InputMode::Mouse
+        && let Some(aim_x) = actions.aim_x()
+    {
+        spawn_pos.x = aim_x;
     }
 
     // Get the held fruit's radius for proper clamping
@@ -437,8 +490,7 @@ pub fn update_spawn_position(
     let container_width = physics_config
         .map(|c| c.container_width)
         .unwrap_or(DEFAULT_CONTAINER_WIDTH);
-    let max_x = container_width / 2.0 - held_fruit_radius;
-    spawn_pos.x = spawn_pos.x.clamp(-max_x, max_x);
+    spawn_pos.x = clamp_spawn_x(spawn_pos.x, container_width, held_fruit_radius);
 
     // Update ONLY held fruit position to match spawn position
     // Falling and Landed fruits are not affected
@@ -449,12 +501,23 @@ pub fn update_spawn_position(
     }
 }
 
+/// Clamps a spawn X so a fruit of `radius` stays inside the container.
+///
+/// A fruit wider than the container is centred instead of panicking on an
+/// inverted clamp range.
+pub fn clamp_spawn_x(x: f32, container_width: f32, radius: f32) -> f32 {
+    let max_x = (container_width / 2.0 - radius).max(0.0);
+    x.clamp(-max_x, max_x)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::*;
     use crate::resources::CircleTexture;
+    use crate::systems::action::collect_input_actions;
     use bevy::asset::Assets;
+    use bevy::input::touch::Touches;
 
     /// Helper to setup test app with required resources
     fn setup_test_app() -> App {
@@ -476,7 +539,17 @@ mod tests {
         app.insert_resource(PhysicsConfigHandle(physics_handle));
         app.init_resource::<SpawnPosition>();
         app.init_resource::<NextFruitType>();
+        app.init_resource::<FruitQueue>();
         app.insert_resource(CircleTexture(Handle::default()));
+        app.add_message::<FruitDroppedEvent>();
+
+        // Gameplay reads actions collected from the raw device state
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<ButtonInput<MouseButton>>();
+        app.init_resource::<Touches>();
+        app.init_resource::<LastCursorPosition>();
+        app.init_resource::<ActionState>();
+        app.add_systems(PreUpdate, collect_input_actions);
 
         app
     }
@@ -601,6 +674,10 @@ mod tests {
             fruit_linear_damping: 0.5,
             fruit_angular_damping: 1.0,
             keyboard_move_speed: 300.0,
+            ccd_speed_threshold: 600.0,
+            max_fruit_speed: 2500.0,
+            sleep_linear_threshold: 0.6,
+            sleep_angular_threshold: 0.8,
         }
     }
 
@@ -683,6 +760,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_biased_spawn_drops_the_seed_code() {
+        use crate::resources::GameMode;
+        use crate::seed_code::SeedCode;
+
+        let code = SeedCode::new(42, GameMode::Classic, 5);
+        for (bias, expected) in [(0.0, Some(code)), (0.3, None)] {
+            let mut app = setup_test_app();
+            app.insert_resource(SpawnRng::from_seed(42))
+                .insert_resource(AdaptiveSpawn { active: true, bias })
+                .insert_resource(SessionStats {
+                    seed_code: Some(code),
+                    ..default()
+                })
+                .add_systems(Update, spawn_held_fruit);
+            app.update();
+
+            assert_eq!(app.world().resource::<SessionStats>().seed_code, expected);
+        }
+    }
+
     #[test]
     fn test_spawn_held_fruit_waits_for_falling_fruit() {
         let mut app = setup_test_app();
@@ -0,0 +1,152 @@
+//! Landing preview assist
+//!
+//! With [`SettingsResource::landing_preview`] on, the held fruit's collider
+//! is shape-cast straight down the container and a faint silhouette is drawn
+//! where it first touches something: the floor, a wall or the pile.
+//!
+//! Unlike a straight guide line this accounts for the fruit's width, so a
+//! drop that clips the shoulder of a tall neighbour shows it resting up on
+//! that neighbour.  Like the merge ghost it is only a hint — the bounce and
+//! roll after the first contact are not simulated.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{Collider, QueryFilter, ReadRapierContext, ShapeCastOptions};
+
+use crate::components::{Fruit, FruitSpawnState, LandingPreview};
+use crate::resources::{CircleTexture, SettingsResource};
+use crate::states::AppState;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Opacity of the silhouette.
+pub const LANDING_PREVIEW_ALPHA: f32 = 0.2;
+
+/// Silhouette z-layer: behind the fruits, so the pile it rests on stays
+/// readable.
+const LANDING_PREVIEW_Z: f32 = -0.5;
+
+/// Length of the downward cast (pixels); longer than any container.
+const MAX_CAST_LENGTH: f32 = 10_000.0;
+
+/// Drops shorter than this (pixels) show no silhouette: it would only sit
+/// under the held fruit itself.
+const MIN_PREVIEW_DROP: f32 = 1.0;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Shows, moves or hides the landing silhouette for the current held fruit.
+///
+/// The silhouette sprite is spawned on first use and despawned when leaving
+/// [`AppState::Playing`], so pausing hides it as well.
+#[allow(clippy::type_complexity)]
+pub fn update_landing_preview(
+    mut commands: Commands,
+    settings: Res<SettingsResource>,
+    rapier_context: ReadRapierContext,
+    held_query: Query<(Entity, &FruitSpawnState, &Transform, &Collider), With<Fruit>>,
+    mut preview_query: Query<
+        (&mut Sprite, &mut Transform, &mut Visibility),
+        (With<LandingPreview>, Without<Fruit>),
+    >,
+    circle_texture: Res<CircleTexture>,
+) {
+    let landing = if settings.landing_preview {
+        find_landing(&rapier_context, &held_query)
+    } else {
+        None
+    };
+
+    let Some((position, radius)) = landing else {
+        for (_, _, mut visibility) in preview_query.iter_mut() {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+        return;
+    };
+
+    let size = Some(Vec2::splat(radius * 2.0));
+    let transform = Transform::from_translation(position.extend(LANDING_PREVIEW_Z));
+
+    if let Ok((mut sprite, mut preview_transform, mut visibility)) = preview_query.single_mut() {
+        sprite.custom_size = size;
+        *preview_transform = transform;
+        visibility.set_if_neq(Visibility::Visible);
+    } else {
+        commands.spawn((
+            LandingPreview,
+            Sprite {
+                image: circle_texture.0.clone(),
+                color: Color::WHITE.with_alpha(LANDING_PREVIEW_ALPHA),
+                custom_size: size,
+                ..default()
+            },
+            transform,
+            Visibility::Visible,
+            DespawnOnExit(AppState::Playing),
+        ));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Casts the held fruit's collider down and returns where it would come to
+/// its first contact, with the silhouette radius.
+#[allow(clippy::type_complexity)]
+fn find_landing(
+    rapier_context: &ReadRapierContext,
+    held_query: &Query<(Entity, &FruitSpawnState, &Transform, &Collider), With<Fruit>>,
+) -> Option<(Vec2, f32)> {
+    let ctx = rapier_context.single().ok()?;
+    let (held, _, transform, collider) = held_query
+        .iter()
+        .find(|(_, state, _, _)| **state == FruitSpawnState::Held)?;
+    let radius = collider.as_ball()?.radius();
+
+    let origin = transform.translation.truncate();
+    let (_, hit) = ctx.cast_shape(
+        origin,
+        0.0,
+        Vec2::NEG_Y,
+        collider,
+        ShapeCastOptions::with_max_time_of_impact(MAX_CAST_LENGTH),
+        QueryFilter::default()
+            .exclude_sensors()
+            .exclude_collider(held),
+    )?;
+    let position = landing_point(origin, hit.time_of_impact)?;
+    Some((position, radius))
+}
+
+/// Centre of a fruit cast down from `origin` at unit speed that stops after
+/// `time_of_impact`, or `None` when it would barely move.
+fn landing_point(origin: Vec2, time_of_impact: f32) -> Option<Vec2> {
+    (time_of_impact >= MIN_PREVIEW_DROP).then(|| origin + Vec2::NEG_Y * time_of_impact)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_landing_point_is_below_origin() {
+        assert_eq!(
+            landing_point(Vec2::new(12.0, 300.0), 450.0),
+            Some(Vec2::new(12.0, -150.0))
+        );
+    }
+
+    #[test]
+    fn test_no_landing_point_for_a_touching_drop() {
+        assert_eq!(landing_point(Vec2::new(0.0, 300.0), 0.0), None);
+        assert_eq!(landing_point(Vec2::new(0.0, 300.0), 0.5), None);
+    }
+}
@@ -0,0 +1,263 @@
+//! Imminent-watermelon link
+//!
+//! [`detect_melon_pair`] scans the fruits every frame and keeps the two
+//! closest Melons on the board in [`MelonPair`] — one merge away from a
+//! Watermelon.  While there is a pair, [`update_melon_link`] draws a faint
+//! line of twinkling sparkles drifting from one Melon to the other, and the
+//! UI shows an "imminent watermelon" hint.
+//!
+//! ```text
+//!    ( Melon )  ·  ✦  ·  ✦  ·  ( Melon )
+//!              └── drifting sparkles ──┘
+//! ```
+//!
+//! The held fruit never counts.  The sparkles follow the global effects
+//! switch; the pair (and so the HUD hint) does not.
+
+use bevy::prelude::*;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::FruitsParams;
+use crate::fruit::FruitType;
+use crate::resources::{CircleTexture, SettingsResource};
+use crate::states::AppState;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Sparkles along the link.
+pub const LINK_SPARKLES: usize = 7;
+
+/// Peak opacity of a sparkle; kept low so the link stays a hint.
+pub const LINK_ALPHA: f32 = 0.55;
+
+/// Sparkle diameter in pixels.
+const LINK_SPARKLE_SIZE: f32 = 7.0;
+
+/// Link lengths the sparkles drift per second.
+const LINK_DRIFT_SPEED: f32 = 0.25;
+
+/// Twinkle rate of a single sparkle (radians per second).
+const LINK_TWINKLE_RATE: f32 = 6.0;
+
+/// Sparkle colour: a pale gold.
+const LINK_COLOR: Color = Color::srgb(1.0, 0.95, 0.6);
+
+/// Sparkle z-layer: above the fruits, below the merge ghost.
+const LINK_Z: f32 = 4.5;
+
+// ---------------------------------------------------------------------------
+// Components / resources
+// ---------------------------------------------------------------------------
+
+/// One sparkle of the link; the index orders them along it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MelonLinkSparkle(pub usize);
+
+/// The two closest Melons on the board, `None` while there are fewer than
+/// two.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MelonPair(pub Option<(Entity, Entity)>);
+
+impl MelonPair {
+    /// `true` while a Watermelon is one merge away.
+    pub fn is_imminent(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Updates [`MelonPair`] from the Melons currently on the board.
+pub fn detect_melon_pair(
+    fruit_query: Query<(Entity, &FruitType, &FruitSpawnState, &Transform), With<Fruit>>,
+    mut pair: ResMut<MelonPair>,
+) {
+    let melons = fruit_query
+        .iter()
+        .filter(|(_, fruit_type, state, _)| {
+            **fruit_type == FruitType::Melon && **state != FruitSpawnState::Held
+        })
+        .map(|(entity, _, _, transform)| (entity, transform.translation.truncate()));
+    pair.set_if_neq(MelonPair(closest_pair(melons)));
+}
+
+/// Places the [`MelonLinkSparkle`]s between the Melons of [`MelonPair`], or
+/// hides them when there is no pair or effects are switched off.
+///
+/// The sparkles are spawned on first use and despawned when leaving
+/// [`AppState::Playing`].
+#[allow(clippy::type_complexity)]
+pub fn update_melon_link(
+    mut commands: Commands,
+    time: Res<Time>,
+    pair: Res<MelonPair>,
+    settings: Option<Res<SettingsResource>>,
+    fruits: FruitsParams,
+    circle_texture: Res<CircleTexture>,
+    melon_query: Query<&Transform, (With<Fruit>, Without<MelonLinkSparkle>)>,
+    mut sparkles: Query<(
+        &MelonLinkSparkle,
+        &mut Sprite,
+        &mut Transform,
+        &mut Visibility,
+    )>,
+) {
+    let enabled = settings.is_none_or(|s| s.effects_enabled);
+    let ends = pair
+        .0
+        .filter(|_| enabled)
+        .and_then(|(a, b)| Some((melon_query.get(a).ok()?, melon_query.get(b).ok()?)));
+    let Some((a, b)) = ends else {
+        for (_, _, _, mut visibility) in sparkles.iter_mut() {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+        return;
+    };
+
+    // Run the link from rim to rim so the sparkles sit between the Melons
+    let radius = fruits
+        .get()
+        .and_then(|config| FruitType::Melon.try_parameters_from_config(config))
+        .map_or(0.0, |params| params.radius);
+    let (a, b) = (a.translation.truncate(), b.translation.truncate());
+    let dir = (b - a).normalize_or_zero();
+    let (start, end) = (a + dir * radius, b - dir * radius);
+    if (end - start).dot(dir) <= 0.0 {
+        // Touching: about to merge anyway
+        for (_, _, _, mut visibility) in sparkles.iter_mut() {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    let mut pooled = 0;
+    for (sparkle, mut sprite, mut transform, mut visibility) in sparkles.iter_mut() {
+        pooled += 1;
+        let (position, alpha) = sparkle_at(sparkle.0, start, end, now);
+        sprite.color = LINK_COLOR.with_alpha(alpha);
+        transform.translation = position.extend(LINK_Z);
+        visibility.set_if_neq(Visibility::Visible);
+    }
+    for index in pooled..LINK_SPARKLES {
+        let (position, alpha) = sparkle_at(index, start, end, now);
+        commands.spawn((
+            MelonLinkSparkle(index),
+            Sprite {
+                image: circle_texture.0.clone(),
+                color: LINK_COLOR.with_alpha(alpha),
+                custom_size: Some(Vec2::splat(LINK_SPARKLE_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(position.extend(LINK_Z)),
+            Visibility::Visible,
+            DespawnOnExit(AppState::Playing),
+        ));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// The closest two of `melons`, or `None` when there are fewer than two.
+fn closest_pair(melons: impl Iterator<Item = (Entity, Vec2)>) -> Option<(Entity, Entity)> {
+    let melons: Vec<_> = melons.collect();
+    let mut best: Option<(f32, (Entity, Entity))> = None;
+    for (i, &(a, pa)) in melons.iter().enumerate() {
+        for &(b, pb) in &melons[i + 1..] {
+            let distance = pa.distance_squared(pb);
+            if best.is_none_or(|(d, _)| distance < d) {
+                best = Some((distance, (a, b)));
+            }
+        }
+    }
+    best.map(|(_, pair)| pair)
+}
+
+/// Position and opacity of sparkle `index` on the link `start → end` at
+/// `now` seconds: evenly spaced, drifting along and twinkling out of step.
+fn sparkle_at(index: usize, start: Vec2, end: Vec2, now: f32) -> (Vec2, f32) {
+    let along = ((index as f32 + now * LINK_DRIFT_SPEED * LINK_SPARKLES as f32)
+        / LINK_SPARKLES as f32)
+        .fract();
+    let twinkle = 0.5 + 0.5 * (now * LINK_TWINKLE_RATE + index as f32 * 1.7).sin();
+    // Fade in and out at the rims so sparkles do not pop at the ends
+    let edge = (along * (1.0 - along) * 4.0).min(1.0);
+    (start.lerp(end, along), LINK_ALPHA * twinkle * edge)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn(app: &mut App, fruit_type: FruitType, state: FruitSpawnState, x: f32) -> Entity {
+        app.world_mut()
+            .spawn((Fruit, fruit_type, state, Transform::from_xyz(x, 0.0, 0.0)))
+            .id()
+    }
+
+    #[test]
+    fn test_closest_pair_picks_the_nearest_melons() {
+        let mut world = World::new();
+        let [a, b, c] = [(); 3].map(|_| world.spawn_empty().id());
+        assert_eq!(closest_pair([(a, Vec2::ZERO)].into_iter()), None);
+        let pair = closest_pair(
+            [
+                (a, Vec2::ZERO),
+                (b, Vec2::new(300.0, 0.0)),
+                (c, Vec2::new(100.0, 0.0)),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(pair, Some((a, c)));
+    }
+
+    #[test]
+    fn test_sparkles_stay_on_the_link_and_faint() {
+        let (start, end) = (Vec2::new(-50.0, 0.0), Vec2::new(50.0, 20.0));
+        for index in 0..LINK_SPARKLES {
+            for step in 0..20 {
+                let (position, alpha) = sparkle_at(index, start, end, step as f32 * 0.37);
+                let t = (position - start).length() / (end - start).length();
+                assert!((0.0..=1.0).contains(&t));
+                assert!((0.0..=LINK_ALPHA).contains(&alpha));
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_melon_pair_ignores_held_and_other_fruits() {
+        let mut app = App::new();
+        app.init_resource::<MelonPair>()
+            .add_systems(Update, detect_melon_pair);
+
+        let first = spawn(&mut app, FruitType::Melon, FruitSpawnState::Landed, 0.0);
+        spawn(
+            &mut app,
+            FruitType::Pineapple,
+            FruitSpawnState::Landed,
+            10.0,
+        );
+        spawn(&mut app, FruitType::Melon, FruitSpawnState::Held, 20.0);
+        app.update();
+        assert!(!app.world().resource::<MelonPair>().is_imminent());
+
+        let second = spawn(&mut app, FruitType::Melon, FruitSpawnState::Falling, 200.0);
+        app.update();
+        let (x, y) = app.world().resource::<MelonPair>().0.unwrap();
+        assert!((x, y) == (first, second) || (y, x) == (first, second));
+
+        app.world_mut().entity_mut(second).despawn();
+        app.update();
+        assert!(!app.world().resource::<MelonPair>().is_imminent());
+    }
+}
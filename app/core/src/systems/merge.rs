@@ -4,17 +4,22 @@
 //! It despawns both colliding fruits and spawns the next evolution stage at the
 //! merge midpoint. For Watermelons (the final stage), both fruits are simply
 //! removed without spawning a new one.
+//!
+//! Every fruit born from a merge carries a [`ChainLink`] for
+//! `game_rules.ron` `chain_window` seconds, so a merge it sets off right
+//! away is scored as a chain (see `ChainMergeEvent`).
 
 use std::collections::HashSet;
 
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::ActiveEvents;
 
-use crate::components::FruitSpawnState;
-use crate::config::{BounceConfig, BounceConfigHandle, FruitsConfig, FruitsConfigHandle};
+use crate::components::{ChainLink, FruitSpawnState};
+use crate::config::{DEFAULT_CHAIN_WINDOW, FruitsConfig, FruitsConfigHandle, GameRulesParams};
 use crate::events::FruitMergeEvent;
 use crate::resources::{CircleTexture, FruitSprites};
-use crate::systems::effects::bounce::SquashStretchAnimation;
+use crate::systems::effects::MergeAnimation;
+use crate::systems::effects::frames::FruitFrameAnimation;
 use crate::systems::spawn::spawn_fruit;
 
 /// Processes `FruitMergeEvent` and performs the actual fruit merge
@@ -22,8 +27,14 @@ use crate::systems::spawn::spawn_fruit;
 /// For each merge event:
 /// 1. Despawns both source fruit entities
 /// 2. If the fruit type has a next evolution stage, spawns it at the midpoint
+///    with a [`MergeAnimation`] so it pops in from nothing instead of
+///    appearing at full size
 /// 3. If the fruit is Watermelon (final stage), both fruits disappear
 ///
+/// The new fruit gets a [`ChainLink`] with the depth of the merge that
+/// produced it, computed from the source fruits' links before they are
+/// despawned.
+///
 /// # Duplicate despawn prevention
 ///
 /// A local `HashSet` tracks entities already despawned within the current frame.
@@ -40,10 +51,10 @@ pub fn handle_fruit_merge(
     mut merge_events: MessageReader<FruitMergeEvent>,
     fruits_handle: Res<FruitsConfigHandle>,
     fruits_assets: Res<Assets<FruitsConfig>>,
-    bounce_handle: Option<Res<BounceConfigHandle>>,
-    bounce_assets: Option<Res<Assets<BounceConfig>>>,
     circle_texture: Res<CircleTexture>,
     fruit_sprites: Option<Res<FruitSprites>>,
+    rules: GameRulesParams,
+    chain_links: Query<&ChainLink>,
 ) {
     let Some(fruits_config) = fruits_assets.get(&fruits_handle.0) else {
         // Drain events to prevent stale buffering
@@ -51,10 +62,7 @@ pub fn handle_fruit_merge(
         return;
     };
 
-    let bounce_config = bounce_handle
-        .as_ref()
-        .zip(bounce_assets.as_ref())
-        .and_then(|(h, a)| a.get(&h.0));
+    let chain_window = rules.get().map_or(DEFAULT_CHAIN_WINDOW, |r| r.chain_window);
 
     let mut despawned: HashSet<Entity> = HashSet::new();
 
@@ -68,6 +76,11 @@ pub fn handle_fruit_merge(
             continue;
         }
 
+        let depth = ChainLink::merge_depth(
+            chain_links.get(event.entity1).ok(),
+            chain_links.get(event.entity2).ok(),
+        );
+
         // Despawn both source fruits
         commands.entity(event.entity1).despawn();
         commands.entity(event.entity2).despawn();
@@ -90,7 +103,12 @@ pub fn handle_fruit_merge(
                 next_type,
                 FruitSpawnState::Falling,
                 ActiveEvents::COLLISION_EVENTS,
-                SquashStretchAnimation::for_merge(bounce_config),
+                MergeAnimation::new(MergeAnimation::DEFAULT_DURATION),
+                FruitFrameAnimation::merge_pop(),
+                ChainLink {
+                    depth,
+                    remaining: chain_window,
+                },
             ));
 
             info!(
@@ -107,6 +125,24 @@ pub fn handle_fruit_merge(
     }
 }
 
+/// Counts down every [`ChainLink`] and removes it once its window is over.
+///
+/// Registered to run only while [`AppState::Playing`](crate::states::AppState)
+/// so the window does not run out while physics is paused.
+pub fn tick_chain_links(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut links: Query<(Entity, &mut ChainLink)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut link) in links.iter_mut() {
+        link.remaining -= dt;
+        if link.remaining <= 0.0 {
+            commands.entity(entity).remove::<ChainLink>();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,4 +345,51 @@ mod tests {
 
         assert_eq!(falling_count, 1, "Merged fruit should be in Falling state");
     }
+
+    #[test]
+    fn test_merged_fruit_extends_chain() {
+        let mut app = setup_merge_app();
+
+        let e1 = spawn_test_fruit(&mut app, FruitType::Cherry);
+        let e2 = spawn_test_fruit(&mut app, FruitType::Cherry);
+        app.world_mut().entity_mut(e1).insert(ChainLink {
+            depth: 1,
+            remaining: 0.1,
+        });
+
+        app.world_mut().write_message(FruitMergeEvent {
+            entity1: e1,
+            entity2: e2,
+            fruit_type: FruitType::Cherry,
+            position: Vec2::ZERO,
+        });
+        app.update();
+
+        let links: Vec<ChainLink> = app
+            .world_mut()
+            .query::<&ChainLink>()
+            .iter(app.world())
+            .copied()
+            .collect();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].depth, 2);
+        assert_eq!(links[0].remaining, DEFAULT_CHAIN_WINDOW);
+    }
+
+    #[test]
+    fn test_chain_link_expires() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, tick_chain_links);
+        let fruit = app
+            .world_mut()
+            .spawn(ChainLink {
+                depth: 0,
+                remaining: 0.0,
+            })
+            .id();
+
+        app.update();
+        assert!(app.world().get::<ChainLink>(fruit).is_none());
+    }
 }
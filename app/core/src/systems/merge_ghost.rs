@@ -0,0 +1,189 @@
+//! Merge ghost assist
+//!
+//! With [`SettingsResource::merge_ghost`] on, a ray is cast straight down
+//! from the held fruit.  When the first thing it hits is a fruit of the same
+//! kind, a translucent ghost of the next-stage fruit is drawn where the two
+//! would merge: the midpoint between the target and the held fruit at the
+//! moment they touch.
+//!
+//! The ghost is only a hint — it ignores the bounce and roll of the real
+//! drop, and never appears for Watermelons, which have no next stage.
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy_rapier2d::prelude::{QueryFilter, ReadRapierContext};
+
+use crate::components::{Fruit, FruitSpawnState, MergeGhost};
+use crate::config::FruitsParams;
+use crate::fruit::FruitType;
+use crate::resources::{CircleTexture, FruitSprites, SettingsResource};
+use crate::states::AppState;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Opacity of the ghost sprite.
+pub const MERGE_GHOST_ALPHA: f32 = 0.4;
+
+/// Ghost z-layer: above the fruits, below the UI overlays.
+const MERGE_GHOST_Z: f32 = 5.0;
+
+/// Length of the downward ray (pixels); longer than any container.
+const MAX_RAY_LENGTH: f32 = 10_000.0;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Shows, moves or hides the merge ghost for the current held fruit.
+///
+/// The ghost sprite is spawned on first use and despawned when leaving
+/// [`AppState::Playing`], so pausing hides it as well.
+#[allow(clippy::too_many_arguments)]
+pub fn update_merge_ghost(
+    mut commands: Commands,
+    settings: Res<SettingsResource>,
+    rapier_context: ReadRapierContext,
+    fruit_query: Query<(Entity, &FruitType, &FruitSpawnState, &Transform), With<Fruit>>,
+    mut ghost_query: Query<
+        (&mut Sprite, &mut Anchor, &mut Transform, &mut Visibility),
+        (With<MergeGhost>, Without<Fruit>),
+    >,
+    fruits: FruitsParams,
+    circle_texture: Res<CircleTexture>,
+    fruit_sprites: Option<Res<FruitSprites>>,
+) {
+    let target = if settings.merge_ghost {
+        find_merge_target(&rapier_context, &fruit_query, &fruits)
+    } else {
+        None
+    };
+
+    let Some((fruit_type, position)) = target else {
+        for (_, _, _, mut visibility) in ghost_query.iter_mut() {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+        return;
+    };
+    let Some(params) = fruits
+        .get()
+        .and_then(|config| fruit_type.try_parameters_from_config(config))
+    else {
+        return;
+    };
+
+    let (image, color) = fruit_sprites
+        .as_deref()
+        .map(|s| s.resolve(fruit_type, circle_texture.0.clone()))
+        .unwrap_or_else(|| (circle_texture.0.clone(), fruit_type.placeholder_color()));
+    let sprite = Sprite {
+        image,
+        color: color.with_alpha(MERGE_GHOST_ALPHA),
+        custom_size: Some(Vec2::splat(params.radius * 2.0 * params.sprite_scale)),
+        texture_atlas: fruit_sprites.as_deref().and_then(|s| s.atlas(fruit_type)),
+        ..default()
+    };
+    let anchor = Anchor(Vec2::new(params.sprite_anchor_x, params.sprite_anchor_y));
+    let transform = Transform::from_translation(position.extend(MERGE_GHOST_Z));
+
+    if let Ok((mut ghost_sprite, mut ghost_anchor, mut ghost_transform, mut visibility)) =
+        ghost_query.single_mut()
+    {
+        *ghost_sprite = sprite;
+        *ghost_anchor = anchor;
+        *ghost_transform = transform;
+        visibility.set_if_neq(Visibility::Visible);
+    } else {
+        commands.spawn((
+            MergeGhost,
+            sprite,
+            anchor,
+            transform,
+            Visibility::Visible,
+            DespawnOnExit(AppState::Playing),
+        ));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Casts down from the held fruit and returns the merge result and its
+/// predicted position, if the first fruit below is of the same kind.
+fn find_merge_target(
+    rapier_context: &ReadRapierContext,
+    fruit_query: &Query<(Entity, &FruitType, &FruitSpawnState, &Transform), With<Fruit>>,
+    fruits: &FruitsParams,
+) -> Option<(FruitType, Vec2)> {
+    let config = fruits.get()?;
+    let ctx = rapier_context.single().ok()?;
+    let (held, &held_type, _, held_transform) = fruit_query
+        .iter()
+        .find(|(_, _, state, _)| **state == FruitSpawnState::Held)?;
+    let result = held_type.next()?;
+
+    let origin = held_transform.translation.truncate();
+    let (hit, _) = ctx.cast_ray(
+        origin,
+        Vec2::NEG_Y,
+        MAX_RAY_LENGTH,
+        true,
+        QueryFilter::default()
+            .exclude_sensors()
+            .exclude_collider(held),
+    )?;
+    let (_, &hit_type, _, hit_transform) = fruit_query.get(hit).ok()?;
+    if hit_type != held_type {
+        return None;
+    }
+
+    let radius = held_type.try_parameters_from_config(config)?.radius;
+    let position = predicted_merge_point(origin, hit_transform.translation.truncate(), radius);
+    Some((result, position))
+}
+
+/// Midpoint of two touching fruits of `radius`, with the held one dropped
+/// straight down from `held` onto the one at `target`.
+///
+/// When the held fruit is not above the target at all, the two are treated
+/// as side by side at the target's height.
+fn predicted_merge_point(held: Vec2, target: Vec2, radius: f32) -> Vec2 {
+    let contact_distance = radius * 2.0;
+    let dx = held.x - target.x;
+    let dy = (contact_distance * contact_distance - dx * dx)
+        .max(0.0)
+        .sqrt();
+    let landed = Vec2::new(held.x, target.y + dy);
+    (landed + target) / 2.0
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predicted_merge_point_straight_above() {
+        let point = predicted_merge_point(Vec2::new(10.0, 300.0), Vec2::new(10.0, -100.0), 20.0);
+        // Touching centres are 40 px apart, so the merge is 20 px above the target
+        assert_eq!(point, Vec2::new(10.0, -80.0));
+    }
+
+    #[test]
+    fn test_predicted_merge_point_off_centre() {
+        let point = predicted_merge_point(Vec2::new(30.0, 300.0), Vec2::ZERO, 25.0);
+        // dx = 30, contact distance = 50 → landed at (30, 40)
+        assert_eq!(point, Vec2::new(15.0, 20.0));
+    }
+
+    #[test]
+    fn test_predicted_merge_point_beside_target() {
+        let point = predicted_merge_point(Vec2::new(100.0, 300.0), Vec2::ZERO, 20.0);
+        assert_eq!(point, Vec2::new(50.0, 0.0));
+    }
+}
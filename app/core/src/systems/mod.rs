@@ -3,14 +3,38 @@
 //! This module contains the core game systems that implement game logic,
 //! physics, and gameplay mechanics using Bevy's ECS (Entity-Component-System).
 
+pub mod achievements;
+pub mod action;
+pub mod adaptive_spawn;
+pub mod assist;
+pub mod attract;
+pub mod background;
+pub mod board;
 pub mod boundary;
 pub mod collision;
 pub mod container;
+pub mod control;
 pub mod effects;
+pub mod emergency_save;
+pub mod escape;
+pub mod frame_pacing;
 pub mod game_over;
+pub mod ghost_run;
 pub mod input;
+pub mod landing_preview;
+pub mod melon_link;
 pub mod merge;
+pub mod merge_ghost;
 pub mod pause;
-pub mod preview;
+pub mod pressure;
+pub mod recap;
+pub mod safe_mode;
+pub mod sandbox;
 pub mod score;
+pub mod session;
+pub mod session_log;
+pub mod settle;
+pub mod sleep;
 pub mod spawn;
+pub mod velocity;
+pub mod watchdog;
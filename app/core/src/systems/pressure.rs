@@ -0,0 +1,233 @@
+//! Overfill pressure
+//!
+//! Optional rule from `game_rules.ron` `overfill_pressure`: a fruit that
+//! rests above the soft line — `soft_line_offset` pixels below the boundary
+//! line — for longer than `delay` seconds starts being pushed gently
+//! downward.  The push ramps up over `ramp_time` seconds so the pile
+//! compresses instead of jolting, which gives a nearly full board a chance
+//! to settle before the hard game-over timer runs out.
+//!
+//! ```text
+//! ─ ─ ─ ─ ─ ─ ─ ─ ─   boundary line (game-over timer)
+//!      ●  ↓
+//! ·················   soft line (pressure after `delay`)
+//!    ●●●●●●●●●
+//! ```
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{Sleeping, Velocity};
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::{GameRulesParams, OverfillPressureConfig, PhysicsParams};
+
+/// Boundary line height used until `physics.ron` is available (pixels).
+const DEFAULT_BOUNDARY_Y: f32 = 300.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// How long a fruit has been resting above the soft line.
+///
+/// Inserted when a fruit first rises above the line and removed as soon as
+/// it drops below it again.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct OverfillTime(pub f32);
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Tracks fruits above the soft line and pushes those past the delay down.
+///
+/// `Held` fruits are ignored.  Does nothing while the rule is disabled or
+/// `game_rules.ron` is not loaded, apart from clearing leftover timers.
+#[allow(clippy::type_complexity)]
+pub fn apply_overfill_pressure(
+    mut commands: Commands,
+    time: Res<Time>,
+    rules: GameRulesParams,
+    physics: PhysicsParams,
+    mut fruit_query: Query<
+        (
+            Entity,
+            &Transform,
+            &FruitSpawnState,
+            &mut Velocity,
+            Option<&mut Sleeping>,
+            Option<&mut OverfillTime>,
+        ),
+        With<Fruit>,
+    >,
+) {
+    let config = rules
+        .get()
+        .map(|r| r.overfill_pressure)
+        .filter(|p| p.enabled);
+    let soft_line = physics
+        .get()
+        .map_or(DEFAULT_BOUNDARY_Y, |p| p.boundary_line_y)
+        - config.map_or(0.0, |p| p.soft_line_offset);
+    let dt = time.delta_secs();
+
+    for (entity, transform, state, mut velocity, sleeping, overfill) in fruit_query.iter_mut() {
+        let above = *state != FruitSpawnState::Held && transform.translation.y > soft_line;
+        let Some(config) = config.filter(|_| above) else {
+            if overfill.is_some() {
+                commands.entity(entity).remove::<OverfillTime>();
+            }
+            continue;
+        };
+
+        let Some(mut overfill) = overfill else {
+            commands.entity(entity).insert(OverfillTime(dt));
+            continue;
+        };
+        overfill.0 += dt;
+
+        let accel = pressure_acceleration(&config, overfill.0);
+        if accel > 0.0 {
+            velocity.linvel.y -= accel * dt;
+            if let Some(mut sleeping) = sleeping.filter(|s| s.sleeping) {
+                sleeping.sleeping = false;
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Downward acceleration (pixels/s²) for a fruit that has been above the soft
+/// line for `time_above` seconds.
+///
+/// Zero until `delay`, then a linear ramp to `acceleration` over `ramp_time`.
+fn pressure_acceleration(config: &OverfillPressureConfig, time_above: f32) -> f32 {
+    let pressing = time_above - config.delay;
+    if pressing <= 0.0 {
+        return 0.0;
+    }
+    let ramp = if config.ramp_time > 0.0 {
+        (pressing / config.ramp_time).min(1.0)
+    } else {
+        1.0
+    };
+    config.acceleration.max(0.0) * ramp
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GameRulesConfig, GameRulesConfigHandle};
+
+    fn enabled() -> OverfillPressureConfig {
+        OverfillPressureConfig {
+            enabled: true,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_pressure_waits_for_delay_then_ramps() {
+        let config = enabled();
+        assert_eq!(pressure_acceleration(&config, config.delay), 0.0);
+        let half = pressure_acceleration(&config, config.delay + config.ramp_time / 2.0);
+        assert!((half - config.acceleration / 2.0).abs() < 1e-3);
+        assert_eq!(
+            pressure_acceleration(&config, config.delay + config.ramp_time * 3.0),
+            config.acceleration
+        );
+    }
+
+    #[test]
+    fn test_pressure_without_ramp_is_immediate() {
+        let config = OverfillPressureConfig {
+            ramp_time: 0.0,
+            ..enabled()
+        };
+        assert_eq!(
+            pressure_acceleration(&config, config.delay + 0.01),
+            config.acceleration
+        );
+    }
+
+    fn pressure_app(config: OverfillPressureConfig) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, apply_overfill_pressure);
+        let mut assets = Assets::<GameRulesConfig>::default();
+        let handle = assets.add(GameRulesConfig {
+            spawnable_fruit_count: 5,
+            combo_window: 5.0,
+            combo_max: 10,
+            game_over_timer: 3.0,
+            combo_bonuses: Default::default(),
+            chain_window: 0.25,
+            chain_bonuses: Default::default(),
+            overfill_pressure: config,
+            adaptive_spawn: Default::default(),
+        });
+        app.insert_resource(assets)
+            .insert_resource(GameRulesConfigHandle(handle));
+        app
+    }
+
+    fn spawn_fruit_at(app: &mut App, y: f32, state: FruitSpawnState) -> Entity {
+        app.world_mut()
+            .spawn((
+                Fruit,
+                state,
+                Transform::from_xyz(0.0, y, 0.0),
+                Velocity::zero(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_tracks_only_fruits_above_soft_line() {
+        let mut app = pressure_app(enabled());
+        let above = spawn_fruit_at(&mut app, 280.0, FruitSpawnState::Landed);
+        let below = spawn_fruit_at(&mut app, 100.0, FruitSpawnState::Landed);
+        let held = spawn_fruit_at(&mut app, 350.0, FruitSpawnState::Held);
+        app.update();
+
+        assert!(app.world().get::<OverfillTime>(above).is_some());
+        assert!(app.world().get::<OverfillTime>(below).is_none());
+        assert!(app.world().get::<OverfillTime>(held).is_none());
+    }
+
+    #[test]
+    fn test_pushes_fruit_down_after_delay() {
+        let mut app = pressure_app(OverfillPressureConfig {
+            delay: 0.0,
+            ramp_time: 0.0,
+            ..enabled()
+        });
+        let fruit = spawn_fruit_at(&mut app, 280.0, FruitSpawnState::Landed);
+        app.world_mut().entity_mut(fruit).insert(OverfillTime(1.0));
+        app.update();
+        app.update();
+
+        let velocity = app.world().get::<Velocity>(fruit).unwrap();
+        assert!(velocity.linvel.y < 0.0);
+    }
+
+    #[test]
+    fn test_disabled_rule_clears_timers() {
+        let mut app = pressure_app(OverfillPressureConfig::default());
+        let fruit = spawn_fruit_at(&mut app, 280.0, FruitSpawnState::Landed);
+        app.world_mut().entity_mut(fruit).insert(OverfillTime(5.0));
+        app.update();
+
+        assert!(app.world().get::<OverfillTime>(fruit).is_none());
+        assert_eq!(
+            app.world().get::<Velocity>(fruit).unwrap().linvel,
+            Vec2::ZERO
+        );
+    }
+}
@@ -0,0 +1,310 @@
+//! Game-over recap
+//!
+//! While playing, [`record_recap_frames`] keeps the last
+//! [`RECAP_WINDOW_SECS`] of the board as [`BoardSnapshot`]s in a ring
+//! buffer ([`RecapBuffer`]).  On game over [`start_recap`] hides the real
+//! fruits and [`play_recap`] replays the buffer at [`RECAP_SPEED`]× with
+//! plain sprites behind the game-over panel — a quick "how it went wrong" —
+//! then shows the final board again.
+//!
+//! ```text
+//! Playing:   … ─ snapshot ─ snapshot ─ … ─ snapshot ─▶ game over
+//!                └──────── last RECAP_WINDOW_SECS ────────┘
+//! GameOver:  replay at RECAP_SPEED× ─▶ final board
+//! ```
+//!
+//! Snapshots are taken on the game clock, so pauses do not eat into the
+//! window.  Recap sprites have no physics and are gone when leaving
+//! [`AppState::GameOver`].
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::FruitsParams;
+use crate::fruit::FruitType;
+use crate::resources::{CircleTexture, FruitQueue, FruitSprites, GameState, NextFruitType};
+use crate::states::AppState;
+use crate::systems::board::{BoardSnapshot, capture_board};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Seconds of play kept for the recap.
+pub const RECAP_WINDOW_SECS: f32 = 10.0;
+
+/// Game-clock seconds between two snapshots; at [`RECAP_SPEED`] this
+/// replays at 60 frames per second.
+pub const RECAP_SAMPLE_SECS: f32 = 1.0 / 30.0;
+
+/// Playback speed of the recap.
+pub const RECAP_SPEED: f32 = 2.0;
+
+// ---------------------------------------------------------------------------
+// Components / resources
+// ---------------------------------------------------------------------------
+
+/// A sprite standing in for one fruit of the recap frame being shown.
+///
+/// Pooled by [`play_recap`]: the n-th sprite shows the n-th fruit of each
+/// frame, so they are only spawned when a frame has more fruits than any
+/// before it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecapFruit(pub usize);
+
+/// The last [`RECAP_WINDOW_SECS`] of the board, oldest first.
+#[derive(Resource, Debug, Default)]
+pub struct RecapBuffer {
+    frames: VecDeque<BoardSnapshot>,
+}
+
+impl RecapBuffer {
+    /// Appends `snapshot` and drops the frames that fell out of the window.
+    pub fn push(&mut self, snapshot: BoardSnapshot) {
+        let newest = snapshot.elapsed_time;
+        self.frames.push_back(snapshot);
+        while self
+            .frames
+            .front()
+            .is_some_and(|oldest| newest - oldest.elapsed_time > RECAP_WINDOW_SECS)
+        {
+            self.frames.pop_front();
+        }
+    }
+
+    /// Game-clock time of the newest frame, if any.
+    pub fn latest_time(&self) -> Option<f32> {
+        self.frames.back().map(|frame| frame.elapsed_time)
+    }
+
+    /// Seconds of play the buffer covers.
+    pub fn duration(&self) -> f32 {
+        match (self.frames.front(), self.frames.back()) {
+            (Some(first), Some(last)) => last.elapsed_time - first.elapsed_time,
+            _ => 0.0,
+        }
+    }
+
+    /// The frame shown `offset` seconds of play after the oldest one.
+    pub fn frame_at(&self, offset: f32) -> Option<&BoardSnapshot> {
+        let start = self.frames.front()?.elapsed_time;
+        let index = self
+            .frames
+            .partition_point(|frame| frame.elapsed_time - start <= offset);
+        self.frames.get(index.saturating_sub(1))
+    }
+
+    /// Forgets every frame.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+}
+
+/// Progress of the recap on the game-over screen.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct RecapPlayback {
+    /// `true` while the recap is on screen
+    pub active: bool,
+    /// Seconds of play replayed so far
+    pub offset: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Adds a snapshot to [`RecapBuffer`] every [`RECAP_SAMPLE_SECS`] of play.
+pub fn record_recap_frames(
+    game_state: Res<GameState>,
+    next_fruit: Res<NextFruitType>,
+    queue: Res<FruitQueue>,
+    fruit_query: Query<(&Transform, &FruitType, &FruitSpawnState, Option<&Velocity>), With<Fruit>>,
+    mut buffer: ResMut<RecapBuffer>,
+) {
+    let due = buffer
+        .latest_time()
+        .is_none_or(|last| game_state.elapsed_time - last >= RECAP_SAMPLE_SECS);
+    if due {
+        buffer.push(capture_board(
+            &game_state,
+            &queue.with_next(&next_fruit),
+            fruit_query.iter(),
+        ));
+    }
+}
+
+/// Starts the recap on entering [`AppState::GameOver`]: the real fruits are
+/// hidden while the recap sprites stand in for them.
+///
+/// Does nothing when less than a frame's worth of play was recorded.
+pub fn start_recap(
+    buffer: Res<RecapBuffer>,
+    mut playback: ResMut<RecapPlayback>,
+    mut fruits: Query<&mut Visibility, With<Fruit>>,
+) {
+    if buffer.duration() < RECAP_SAMPLE_SECS {
+        return;
+    }
+    *playback = RecapPlayback {
+        active: true,
+        offset: 0.0,
+    };
+    for mut visibility in fruits.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+/// Advances the recap and shows the current frame with [`RecapFruit`]
+/// sprites; at the end the sprites go and the real fruits come back.
+#[allow(clippy::too_many_arguments)]
+pub fn play_recap(
+    mut commands: Commands,
+    time: Res<Time>,
+    buffer: Res<RecapBuffer>,
+    mut playback: ResMut<RecapPlayback>,
+    fruits_config: FruitsParams,
+    circle_texture: Res<CircleTexture>,
+    fruit_sprites: Option<Res<FruitSprites>>,
+    mut recap_sprites: Query<(
+        Entity,
+        &RecapFruit,
+        &mut Sprite,
+        &mut Anchor,
+        &mut Transform,
+        &mut Visibility,
+    )>,
+    mut fruits: Query<&mut Visibility, (With<Fruit>, Without<RecapFruit>)>,
+) {
+    if !playback.active {
+        return;
+    }
+    playback.offset += time.delta_secs() * RECAP_SPEED;
+    if playback.offset > buffer.duration() {
+        playback.active = false;
+        for (entity, ..) in recap_sprites.iter() {
+            commands.entity(entity).despawn();
+        }
+        for mut visibility in fruits.iter_mut() {
+            *visibility = Visibility::Inherited;
+        }
+        return;
+    }
+    let frame = buffer.frame_at(playback.offset);
+    let (Some(frame), Some(config)) = (frame, fruits_config.get()) else {
+        return;
+    };
+
+    let look = |fruit_type: FruitType| {
+        let params = fruit_type.try_parameters_from_config(config)?;
+        let (image, color) = fruit_sprites
+            .as_deref()
+            .map(|s| s.resolve(fruit_type, circle_texture.0.clone()))
+            .unwrap_or_else(|| (circle_texture.0.clone(), fruit_type.placeholder_color()));
+        Some((
+            Sprite {
+                image,
+                color,
+                custom_size: Some(Vec2::splat(params.radius * 2.0 * params.sprite_scale)),
+                texture_atlas: fruit_sprites.as_deref().and_then(|s| s.atlas(fruit_type)),
+                ..default()
+            },
+            Anchor(Vec2::new(params.sprite_anchor_x, params.sprite_anchor_y)),
+        ))
+    };
+    let transform_of = |x: f32, y: f32, rotation: f32| {
+        Transform::from_xyz(x, y, 0.0).with_rotation(Quat::from_rotation_z(rotation))
+    };
+
+    let mut pooled = 0;
+    for (_, slot, mut sprite, mut anchor, mut transform, mut visibility) in recap_sprites.iter_mut()
+    {
+        pooled = pooled.max(slot.0 + 1);
+        let shown = frame
+            .fruits
+            .get(slot.0)
+            .and_then(|fruit| Some((fruit, look(fruit.fruit_type)?)));
+        let Some((fruit, (new_sprite, new_anchor))) = shown else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        *sprite = new_sprite;
+        *anchor = new_anchor;
+        *transform = transform_of(fruit.x, fruit.y, fruit.rotation);
+        visibility.set_if_neq(Visibility::Visible);
+    }
+    for (index, fruit) in frame.fruits.iter().enumerate().skip(pooled) {
+        let Some((sprite, anchor)) = look(fruit.fruit_type) else {
+            continue;
+        };
+        commands.spawn((
+            RecapFruit(index),
+            sprite,
+            anchor,
+            transform_of(fruit.x, fruit.y, fruit.rotation),
+            Visibility::Visible,
+            DespawnOnExit(AppState::GameOver),
+        ));
+    }
+}
+
+/// Forgets the recorded play and stops the recap, so the next game starts
+/// with an empty buffer and its fruits visible.
+pub fn reset_recap(
+    mut buffer: ResMut<RecapBuffer>,
+    mut playback: ResMut<RecapPlayback>,
+    mut fruits: Query<&mut Visibility, With<Fruit>>,
+) {
+    buffer.clear();
+    if playback.active {
+        *playback = RecapPlayback::default();
+        for mut visibility in fruits.iter_mut() {
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(elapsed_time: f32) -> BoardSnapshot {
+        BoardSnapshot {
+            score: 0,
+            elapsed_time,
+            drops: 0,
+            held_fruit: FruitType::Cherry,
+            queue: Vec::new(),
+            fruits: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_buffer_keeps_only_the_window() {
+        let mut buffer = RecapBuffer::default();
+        for i in 0..=150 {
+            buffer.push(snapshot(i as f32 * 0.1));
+        }
+        assert!((buffer.duration() - RECAP_WINDOW_SECS).abs() < 1e-3);
+        assert!((buffer.latest_time().unwrap() - 15.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_frame_at_picks_the_frame_shown_at_that_time() {
+        let mut buffer = RecapBuffer::default();
+        for t in [20.0, 20.5, 21.0] {
+            buffer.push(snapshot(t));
+        }
+        assert_eq!(buffer.frame_at(0.0).unwrap().elapsed_time, 20.0);
+        assert_eq!(buffer.frame_at(0.7).unwrap().elapsed_time, 20.5);
+        assert_eq!(buffer.frame_at(5.0).unwrap().elapsed_time, 21.0);
+        assert!(RecapBuffer::default().frame_at(0.0).is_none());
+    }
+}
@@ -0,0 +1,118 @@
+//! Streamer-safe / parental mode
+//!
+//! With [`SettingsResource::streamer_safe`] on, the game stays offline and
+//! keeps private details off the screen:
+//!
+//! | Feature                          | In safe mode                          |
+//! |----------------------------------|---------------------------------------|
+//! | update check (`update-check`)    | not started                           |
+//! | cloud saves ([`crate::platform`])| neither pulled nor pushed             |
+//! | seed codes, profile name         | masked on screen                      |
+//! | settings screen                  | asks for [`SettingsResource::settings_pin`], if set |
+//!
+//! Network code asks the [`SafeMode`] resource (usually through the
+//! [`online_allowed`] run condition) rather than reading the settings.  It
+//! is filled from the profile's settings file while `GameCorePlugin` is
+//! built, so it is already right for the `PreStartup` cloud pull, and
+//! follows the setting from then on.
+
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use crate::persistence::load_settings;
+use crate::resources::SettingsResource;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Whether streamer-safe mode is on.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SafeMode {
+    /// Online features are off and personal details are hidden
+    pub active: bool,
+}
+
+impl SafeMode {
+    /// Safe mode as saved in the settings of profile directory `dir`; off
+    /// when persistence is disabled.
+    pub fn load(dir: Option<&Path>) -> Self {
+        Self {
+            active: dir.is_some_and(|dir| load_settings(dir).streamer_safe),
+        }
+    }
+
+    /// Whether network features may run.
+    pub fn online_allowed(&self) -> bool {
+        !self.active
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` unless safe mode is on.  Without a [`SafeMode`] resource (tests
+/// that skip `GameCorePlugin`) online features are allowed.
+pub fn online_allowed(safe_mode: Option<Res<SafeMode>>) -> bool {
+    safe_mode.is_none_or(|s| s.online_allowed())
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Copies [`SettingsResource::streamer_safe`] into [`SafeMode`].
+///
+/// Registered with `run_if(resource_changed::<SettingsResource>)`.
+pub fn sync_safe_mode(settings: Res<SettingsResource>, mut safe_mode: ResMut<SafeMode>) {
+    if safe_mode.active != settings.streamer_safe {
+        safe_mode.active = settings.streamer_safe;
+        info!(
+            "Streamer-safe mode {}",
+            if safe_mode.active { "on" } else { "off" }
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::save_settings;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_reads_profile_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(!SafeMode::load(Some(temp_dir.path())).active);
+
+        let settings = SettingsResource {
+            streamer_safe: true,
+            ..default()
+        };
+        save_settings(&settings, temp_dir.path()).unwrap();
+        assert!(SafeMode::load(Some(temp_dir.path())).active);
+        assert!(!SafeMode::load(None).active);
+    }
+
+    #[test]
+    fn test_sync_follows_setting() {
+        let mut app = App::new();
+        app.init_resource::<SettingsResource>()
+            .init_resource::<SafeMode>()
+            .add_systems(Update, sync_safe_mode);
+        app.update();
+        assert!(app.world().resource::<SafeMode>().online_allowed());
+
+        app.world_mut()
+            .resource_mut::<SettingsResource>()
+            .streamer_safe = true;
+        app.update();
+        assert!(!app.world().resource::<SafeMode>().online_allowed());
+    }
+}
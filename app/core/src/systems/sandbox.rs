@@ -0,0 +1,330 @@
+//! Sandbox mode
+//!
+//! A playground built on [`BoardCommands`]: while [`SandboxMode::active`] is
+//! set the player picks the fruit to drop with the number keys, and can turn
+//! gravity and the boundary line off.  Nothing is recorded — no highscore,
+//! session log, lifetime stats or saved session.
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `1`–`9` | Drop Cherry … Pineapple from now on |
+//! | [`SANDBOX_GRAVITY_KEY`] | Toggle gravity |
+//! | [`SANDBOX_BOUNDARY_KEY`] | Toggle the boundary line (game over) |
+//!
+//! With gravity off a dropped fruit stays where it was released and counts
+//! as landed at once, so the next fruit is handed out right away.
+//!
+//! The mode is started from the title screen, survives "Retry", and ends on
+//! returning to the title.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{DefaultRapierContext, RapierConfiguration};
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::PhysicsParams;
+use crate::fruit::FruitType;
+use crate::resources::GameOverTimer;
+use crate::systems::board::BoardCommands;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Key that toggles gravity.
+pub const SANDBOX_GRAVITY_KEY: KeyCode = KeyCode::KeyG;
+
+/// Key that toggles the boundary line.
+pub const SANDBOX_BOUNDARY_KEY: KeyCode = KeyCode::KeyB;
+
+/// Number keys selecting Cherry (`1`) through Pineapple (`9`).
+const FRUIT_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Whether the current game is a sandbox, and its toggles.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SandboxMode {
+    /// The current game is a sandbox
+    pub active: bool,
+    /// Gravity pulls dropped fruits down
+    pub gravity: bool,
+    /// Crossing the boundary line ends the game
+    pub boundary: bool,
+    /// Fruit picked with the number keys; `None` keeps the random queue
+    pub selected: Option<FruitType>,
+}
+
+impl Default for SandboxMode {
+    fn default() -> Self {
+        Self {
+            active: false,
+            gravity: true,
+            boundary: true,
+            selected: None,
+        }
+    }
+}
+
+impl SandboxMode {
+    /// A fresh sandbox with gravity and boundary on.
+    pub fn started() -> Self {
+        Self {
+            active: true,
+            ..default()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` while playing a sandbox.
+pub fn sandbox_active(sandbox: Option<Res<SandboxMode>>) -> bool {
+    sandbox.is_some_and(|s| s.active)
+}
+
+/// `true` unless the sandbox has turned the boundary line off.
+pub fn boundary_enabled(sandbox: Option<Res<SandboxMode>>) -> bool {
+    sandbox.is_none_or(|s| !s.active || s.boundary)
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Handles the sandbox keys and keeps the selected fruit queued.
+///
+/// Runs after `spawn_held_fruit`, which queues a random fruit after every
+/// spawn, so the selection wins.
+pub fn handle_sandbox_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut sandbox: ResMut<SandboxMode>,
+    mut board: BoardCommands,
+    mut game_over_timer: ResMut<GameOverTimer>,
+) {
+    if !sandbox.active {
+        return;
+    }
+
+    if let Some(fruit) = FRUIT_KEYS
+        .iter()
+        .position(|&key| keyboard.just_pressed(key))
+        .and_then(fruit_for_key)
+    {
+        sandbox.selected = Some(fruit);
+        board.replace_held_fruit(fruit);
+    }
+    if keyboard.just_pressed(SANDBOX_GRAVITY_KEY) {
+        sandbox.gravity = !sandbox.gravity;
+    }
+    if keyboard.just_pressed(SANDBOX_BOUNDARY_KEY) {
+        sandbox.boundary = !sandbox.boundary;
+        if !sandbox.boundary {
+            // Drop any running warning; the overflow check no longer runs
+            game_over_timer.reset();
+        }
+    }
+
+    if let Some(fruit) = sandbox.selected {
+        board.set_next_fruit(fruit);
+    }
+}
+
+/// Sets Rapier's gravity from `physics.ron`, or to zero while the sandbox
+/// has turned gravity off.
+pub fn apply_sandbox_gravity(
+    sandbox: Res<SandboxMode>,
+    physics: PhysicsParams,
+    mut rapier_query: Query<&mut RapierConfiguration, With<DefaultRapierContext>>,
+) {
+    let Some(config) = physics.get() else {
+        return;
+    };
+    let Ok(mut rapier_config) = rapier_query.single_mut() else {
+        return;
+    };
+    let gravity = if sandbox.active && !sandbox.gravity {
+        Vec2::ZERO
+    } else {
+        Vec2::new(0.0, config.gravity)
+    };
+    if rapier_config.gravity != gravity {
+        rapier_config.gravity = gravity;
+    }
+}
+
+/// Lands falling fruits at once while gravity is off, so they stay put and
+/// the next fruit is handed out.
+pub fn settle_weightless_fruits(
+    sandbox: Res<SandboxMode>,
+    mut fruit_query: Query<&mut FruitSpawnState, With<Fruit>>,
+) {
+    if !sandbox.active || sandbox.gravity {
+        return;
+    }
+    for mut state in fruit_query.iter_mut() {
+        if *state == FruitSpawnState::Falling {
+            *state = FruitSpawnState::Landed;
+        }
+    }
+}
+
+/// Leaves the sandbox when returning to the title screen.
+pub fn end_sandbox(mut sandbox: ResMut<SandboxMode>) {
+    *sandbox = SandboxMode::default();
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Fruit selected by the number key at `index` (`0` is key `1`).
+fn fruit_for_key(index: usize) -> Option<FruitType> {
+    std::iter::successors(Some(FruitType::Cherry), FruitType::next).nth(index)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{FruitConfigEntry, FruitsConfig, FruitsConfigHandle};
+    use crate::events::FruitMergeEvent;
+    use crate::resources::{CircleTexture, FruitQueue, GameState, NextFruitType};
+
+    fn setup_sandbox_app(sandbox: SandboxMode) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<FruitMergeEvent>()
+            .init_resource::<GameState>()
+            .init_resource::<NextFruitType>()
+            .init_resource::<FruitQueue>()
+            .init_resource::<GameOverTimer>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(CircleTexture(Handle::default()))
+            .insert_resource(sandbox)
+            .add_systems(Update, (handle_sandbox_input, settle_weightless_fruits));
+
+        let mut fruits_assets = Assets::<FruitsConfig>::default();
+        let handle = fruits_assets.add(FruitsConfig {
+            fruits: vec![FruitConfigEntry::default(); 11],
+        });
+        app.insert_resource(fruits_assets)
+            .insert_resource(FruitsConfigHandle(handle));
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release_all();
+        keyboard.clear();
+        keyboard.press(key);
+    }
+
+    #[test]
+    fn test_fruit_for_key() {
+        assert_eq!(fruit_for_key(0), Some(FruitType::Cherry));
+        assert_eq!(fruit_for_key(8), Some(FruitType::Pineapple));
+    }
+
+    #[test]
+    fn test_run_conditions() {
+        let mut world = World::new();
+        assert!(!world.run_system_cached(sandbox_active).unwrap());
+        assert!(world.run_system_cached(boundary_enabled).unwrap());
+
+        world.insert_resource(SandboxMode {
+            boundary: false,
+            ..SandboxMode::started()
+        });
+        assert!(world.run_system_cached(sandbox_active).unwrap());
+        assert!(!world.run_system_cached(boundary_enabled).unwrap());
+
+        // The boundary toggle only applies inside a sandbox
+        world.resource_mut::<SandboxMode>().active = false;
+        assert!(world.run_system_cached(boundary_enabled).unwrap());
+    }
+
+    #[test]
+    fn test_number_key_selects_fruit_and_keeps_it_queued() {
+        let mut app = setup_sandbox_app(SandboxMode::started());
+        let held = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                FruitType::Cherry,
+                FruitSpawnState::Held,
+                Transform::default(),
+            ))
+            .id();
+
+        press(&mut app, KeyCode::Digit7);
+        app.update();
+        assert_eq!(
+            app.world().resource::<SandboxMode>().selected,
+            Some(FruitType::Pear)
+        );
+        assert!(app.world().get_entity(held).is_err());
+
+        // spawn_held_fruit re-rolls the queue; the selection puts it back
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.world_mut()
+            .resource_mut::<NextFruitType>()
+            .set(FruitType::Cherry);
+        app.update();
+        assert_eq!(
+            app.world().resource::<NextFruitType>().get(),
+            FruitType::Pear
+        );
+    }
+
+    #[test]
+    fn test_toggles_and_weightless_landing() {
+        let mut app = setup_sandbox_app(SandboxMode::started());
+        let falling = app
+            .world_mut()
+            .spawn((Fruit, FruitType::Grape, FruitSpawnState::Falling))
+            .id();
+
+        press(&mut app, SANDBOX_GRAVITY_KEY);
+        app.update();
+        press(&mut app, SANDBOX_BOUNDARY_KEY);
+        app.update();
+
+        let sandbox = *app.world().resource::<SandboxMode>();
+        assert!(!sandbox.gravity);
+        assert!(!sandbox.boundary);
+        assert_eq!(
+            app.world().get::<FruitSpawnState>(falling),
+            Some(&FruitSpawnState::Landed)
+        );
+    }
+
+    #[test]
+    fn test_inactive_sandbox_ignores_keys() {
+        let mut app = setup_sandbox_app(SandboxMode::default());
+        press(&mut app, SANDBOX_GRAVITY_KEY);
+        app.update();
+        assert_eq!(
+            *app.world().resource::<SandboxMode>(),
+            SandboxMode::default()
+        );
+    }
+}
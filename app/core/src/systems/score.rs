@@ -22,11 +22,35 @@
 //! | 3           | 1.2× (+20%)|
 //! | 4           | 1.3× (+30%)|
 //! | 5+          | 1.5× (+50%)|
+//!
+//! # Chain multipliers
+//!
+//! Chains are distinct from combos: a combo counts merges within
+//! `combo_window` seconds of each other, while a chain is a merge set off
+//! directly by the previous one — the freshly merged fruit lands on a twin
+//! and merges again.  The chain depth comes from `ChainMergeEvent`; the
+//! multipliers from `game_rules.ron` `chain_bonuses`, with these fallbacks:
+//!
+//! | Chain depth | Multiplier |
+//! |-------------|-----------|
+//! | 0 (no chain)| 1.0×      |
+//! | 1           | 1.5×      |
+//! | 2           | 2.0×      |
+//! | 3+          | 3.0×      |
+//!
+//! # Scoring strategy
+//!
+//! Base points, the combo multiplier and the chain multiplier are the rules
+//! of the default [`ScoreStrategy`].  Insert a different strategy resource to change how
+//! merges are scored for a game mode.
+
+use std::collections::HashMap;
 
 use bevy::prelude::*;
 
 use crate::config::{FruitsConfig, FruitsConfigHandle, GameRulesConfig, GameRulesConfigHandle};
-use crate::events::{FruitMergeEvent, ScoreEarnedEvent};
+use crate::events::{ChainMergeEvent, ComboBrokenEvent, FruitMergeEvent, ScoreEarnedEvent};
+use crate::resources::score_strategy::{ScoreContext, ScoreStrategy};
 use crate::resources::{ComboTimer, GameState};
 
 // ---------------------------------------------------------------------------
@@ -42,6 +66,13 @@ const DEFAULT_COMBO_BONUS_4: f32 = 1.3;
 /// Fallback multiplier for a 5×+ combo — mirrors `game_rules.ron` `combo_bonuses` key 5.
 const DEFAULT_COMBO_BONUS_5_PLUS: f32 = 1.5;
 
+/// Fallback multiplier for a depth-1 chain — mirrors `game_rules.ron` `chain_bonuses` key 1.
+const DEFAULT_CHAIN_BONUS_1: f32 = 1.5;
+/// Fallback multiplier for a depth-2 chain — mirrors `game_rules.ron` `chain_bonuses` key 2.
+const DEFAULT_CHAIN_BONUS_2: f32 = 2.0;
+/// Fallback multiplier for a depth-3+ chain — mirrors `game_rules.ron` `chain_bonuses` key 3.
+const DEFAULT_CHAIN_BONUS_3_PLUS: f32 = 3.0;
+
 /// Returns the combo score multiplier for the given combo count.
 ///
 /// When `rules` is `Some`, the multiplier is looked up from
@@ -66,14 +97,7 @@ const DEFAULT_COMBO_BONUS_5_PLUS: f32 = 1.5;
 /// ```
 pub fn combo_multiplier(combo: u32, rules: Option<&GameRulesConfig>) -> f32 {
     if let Some(rules) = rules {
-        // Step-function lookup: find the largest key ≤ combo.
-        rules
-            .combo_bonuses
-            .iter()
-            .filter(|&(&k, _)| k <= combo)
-            .max_by_key(|&(&k, _)| k)
-            .map(|(_, &v)| v)
-            .unwrap_or(1.0)
+        step_bonus(&rules.combo_bonuses, combo)
     } else {
         // Hardcoded fallback — mirrors the default game_rules.ron values.
         match combo {
@@ -86,19 +110,62 @@ pub fn combo_multiplier(combo: u32, rules: Option<&GameRulesConfig>) -> f32 {
     }
 }
 
+/// Returns the chain score multiplier for the given chain depth.
+///
+/// Looked up from `GameRulesConfig::chain_bonuses` the same way as
+/// [`combo_multiplier`]; falls back to the defaults matching the shipped
+/// `game_rules.ron` when `rules` is `None`.
+///
+/// # Examples
+///
+/// ```
+/// # use suika_game_core::systems::score::chain_multiplier;
+/// assert_eq!(chain_multiplier(0, None), 1.0);
+/// assert_eq!(chain_multiplier(1, None), 1.5);
+/// assert_eq!(chain_multiplier(2, None), 2.0);
+/// assert_eq!(chain_multiplier(7, None), 3.0);
+/// ```
+pub fn chain_multiplier(depth: u32, rules: Option<&GameRulesConfig>) -> f32 {
+    if let Some(rules) = rules {
+        step_bonus(&rules.chain_bonuses, depth)
+    } else {
+        match depth {
+            0 => 1.0,
+            1 => DEFAULT_CHAIN_BONUS_1,
+            2 => DEFAULT_CHAIN_BONUS_2,
+            _ => DEFAULT_CHAIN_BONUS_3_PLUS,
+        }
+    }
+}
+
+/// Step-function lookup: the bonus of the largest key ≤ `count`, or `1.0`.
+fn step_bonus(bonuses: &HashMap<u32, f32>, count: u32) -> f32 {
+    bonuses
+        .iter()
+        .filter(|&(&k, _)| k <= count)
+        .max_by_key(|&(&k, _)| k)
+        .map(|(_, &v)| v)
+        .unwrap_or(1.0)
+}
+
 /// Updates score and combo state in response to `FruitMergeEvent`.
 ///
 /// For each merge event:
 /// 1. Registers the merge with `ComboTimer` (updates combo count and window)
-/// 2. Calculates base points from the merged fruit's config entry
-/// 3. Applies the combo multiplier from `GameRulesConfig::combo_bonuses`
+/// 2. Looks up base points from the merged fruit's config entry
+/// 3. Runs the [`ScoreStrategy`] rules (by default: base points × combo
+///    multiplier from `GameRulesConfig::combo_bonuses` × chain multiplier
+///    from `GameRulesConfig::chain_bonuses`, using the depth of the matching
+///    [`ChainMergeEvent`])
 /// 4. Adds the result to `GameState.score`
 /// 5. Emits a `ScoreEarnedEvent` with the authoritative per-merge data
 ///
 /// If the fruits config is not yet loaded, events are drained silently.
+/// Without a [`ScoreStrategy`] resource the default strategy is used.
 #[allow(clippy::too_many_arguments)]
 pub fn update_score_on_merge(
     mut merge_events: MessageReader<FruitMergeEvent>,
+    mut chain_events: MessageReader<ChainMergeEvent>,
     mut score_events: MessageWriter<ScoreEarnedEvent>,
     mut game_state: ResMut<GameState>,
     mut combo_timer: ResMut<ComboTimer>,
@@ -106,7 +173,14 @@ pub fn update_score_on_merge(
     fruits_assets: Res<Assets<FruitsConfig>>,
     rules_handle: Option<Res<GameRulesConfigHandle>>,
     rules_assets: Option<Res<Assets<GameRulesConfig>>>,
+    strategy: Option<Res<ScoreStrategy>>,
 ) {
+    // Chain depth of every chained merge this frame, by entity pair
+    let chain_depths: HashMap<(Entity, Entity), u32> = chain_events
+        .read()
+        .map(|e| ((e.entity1, e.entity2), e.depth))
+        .collect();
+
     let Some(config) = fruits_assets.get(&fruits_handle.0) else {
         for _ in merge_events.read() {}
         return;
@@ -117,10 +191,12 @@ pub fn update_score_on_merge(
         .zip(rules_assets.as_ref())
         .and_then(|(h, a)| a.get(&h.0));
 
+    let default_strategy = ScoreStrategy::default();
+    let strategy = strategy.as_deref().unwrap_or(&default_strategy);
+
     for event in merge_events.read() {
         // Update the combo timer first so the multiplier reflects this merge
         combo_timer.register_merge();
-        let multiplier = combo_multiplier(combo_timer.current_combo, rules);
 
         // Base points from the merged fruit type (not the resulting fruit)
         let base_points = event
@@ -129,13 +205,22 @@ pub fn update_score_on_merge(
             .map(|p| p.points)
             .unwrap_or(0);
 
-        let earned = (base_points as f32 * multiplier).round() as u32;
+        let earned = strategy.score(&ScoreContext {
+            fruit_type: event.fruit_type,
+            base_points,
+            combo: combo_timer.current_combo,
+            chain: chain_depths
+                .get(&(event.entity1, event.entity2))
+                .copied()
+                .unwrap_or(0),
+            rules,
+        });
         game_state.score = game_state.score.saturating_add(earned);
 
         if combo_timer.is_combo() {
             info!(
-                "Merge scored {} pts ({}× combo {}): {:?} → total {}",
-                earned, multiplier, combo_timer.current_combo, event.fruit_type, game_state.score
+                "Merge scored {} pts (combo {}): {:?} → total {}",
+                earned, combo_timer.current_combo, event.fruit_type, game_state.score
             );
         } else {
             info!(
@@ -155,12 +240,25 @@ pub fn update_score_on_merge(
     }
 }
 
+/// Smallest expired combo that sends a [`ComboBrokenEvent`].
+pub const COMBO_BREAK_MIN: u32 = 3;
+
 /// Ticks `ComboTimer` every frame and resets it when the combo window expires
 ///
-/// Must run every frame to keep `time_since_last_merge` up to date.
-pub fn tick_combo_timer(mut combo_timer: ResMut<ComboTimer>, time: Res<Time>) {
+/// Must run every frame to keep `time_since_last_merge` up to date.  Sends a
+/// [`ComboBrokenEvent`] when a combo of [`COMBO_BREAK_MIN`] or more expires.
+pub fn tick_combo_timer(
+    mut combo_timer: ResMut<ComboTimer>,
+    time: Res<Time>,
+    mut broken_events: MessageWriter<ComboBrokenEvent>,
+) {
     combo_timer.tick(time.delta_secs());
-    combo_timer.check_and_reset();
+    if let Some(combo) = combo_timer.check_and_reset()
+        && combo >= COMBO_BREAK_MIN
+    {
+        info!("Combo x{combo} broken");
+        broken_events.write(ComboBrokenEvent { combo });
+    }
 }
 
 #[cfg(test)]
@@ -192,6 +290,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.add_message::<FruitMergeEvent>();
+        app.add_message::<ChainMergeEvent>();
         app.add_message::<ScoreEarnedEvent>();
         app.add_systems(Update, update_score_on_merge);
         app.init_resource::<GameState>();
@@ -231,9 +330,10 @@ mod tests {
             combo_max: 10,
             game_over_timer: 3.0,
             combo_bonuses: HashMap::from([(2, 2.0), (3, 3.0), (5, 5.0)]),
-            preview_x_offset: 0.0,
-            preview_y_offset: 0.0,
-            preview_scale: 1.0,
+            chain_window: 0.25,
+            chain_bonuses: HashMap::from([(1, 4.0)]),
+            overfill_pressure: Default::default(),
+            adaptive_spawn: Default::default(),
         };
         // combo=1 → no key ≤ 1 in map → 1.0
         assert!((combo_multiplier(1, Some(&rules)) - 1.0).abs() < f32::EPSILON);
@@ -243,10 +343,37 @@ mod tests {
         assert!((combo_multiplier(4, Some(&rules)) - 3.0).abs() < f32::EPSILON);
         // combo=10 → largest key ≤ 10 is 5 → 5.0
         assert!((combo_multiplier(10, Some(&rules)) - 5.0).abs() < f32::EPSILON);
+        // chain multipliers use their own table
+        assert!((chain_multiplier(0, Some(&rules)) - 1.0).abs() < f32::EPSILON);
+        assert!((chain_multiplier(3, Some(&rules)) - 4.0).abs() < f32::EPSILON);
     }
 
     // --- update_score_on_merge system tests ---
 
+    #[test]
+    fn test_chain_merge_applies_chain_bonus() {
+        let mut app = setup_score_app();
+        let (e1, e2) = (Entity::from_bits(1), Entity::from_bits(2));
+
+        app.world_mut().write_message(FruitMergeEvent {
+            entity1: e1,
+            entity2: e2,
+            fruit_type: FruitType::Cherry, // Cherry.points = 10
+            position: Vec2::ZERO,
+        });
+        app.world_mut().write_message(ChainMergeEvent {
+            entity1: e1,
+            entity2: e2,
+            fruit_type: FruitType::Cherry,
+            position: Vec2::ZERO,
+            depth: 2,
+        });
+        app.update();
+
+        // No combo on the first merge; depth-2 chain fallback = 2.0
+        assert_eq!(app.world().resource::<GameState>().score, 20);
+    }
+
     #[test]
     fn test_single_merge_adds_score() {
         let mut app = setup_score_app();
@@ -295,6 +422,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_custom_strategy_resource_is_used() {
+        use crate::resources::score_strategy::FlatMultiplier;
+
+        let mut app = setup_score_app();
+        app.insert_resource(ScoreStrategy::without_combo().with_rule(FlatMultiplier(2.0)));
+
+        for i in 0..2 {
+            app.world_mut().write_message(FruitMergeEvent {
+                entity1: Entity::from_bits(1 + i * 2),
+                entity2: Entity::from_bits(2 + i * 2),
+                fruit_type: FruitType::Cherry, // 10 pts × 2.0, combo ignored
+                position: Vec2::ZERO,
+            });
+            app.update();
+        }
+
+        let score = app.world().resource::<GameState>().score;
+        assert_eq!(score, 40, "Custom strategy should replace combo scoring");
+    }
+
     #[test]
     fn test_score_uses_fruit_type_points() {
         let mut app = setup_score_app();
@@ -373,4 +521,38 @@ mod tests {
             assert_eq!(timer.current_combo, 2);
         }
     }
+
+    /// Runs `tick_combo_timer` once on a combo of `combo` whose window has
+    /// expired and returns the sent events.
+    fn break_combo(combo: u32) -> Vec<ComboBrokenEvent> {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<ComboBrokenEvent>()
+            .insert_resource(ComboTimer {
+                current_combo: combo,
+                time_since_last_merge: 100.0,
+                ..default()
+            })
+            .add_systems(Update, tick_combo_timer);
+        app.update();
+
+        let messages = app.world().resource::<Messages<ComboBrokenEvent>>();
+        messages.iter_current_update_messages().copied().collect()
+    }
+
+    #[test]
+    fn test_long_combo_break_sends_event() {
+        assert_eq!(break_combo(4), vec![ComboBrokenEvent { combo: 4 }]);
+        assert_eq!(
+            break_combo(COMBO_BREAK_MIN),
+            vec![ComboBrokenEvent {
+                combo: COMBO_BREAK_MIN
+            }]
+        );
+    }
+
+    #[test]
+    fn test_short_combo_break_is_silent() {
+        assert!(break_combo(COMBO_BREAK_MIN - 1).is_empty());
+    }
 }
@@ -0,0 +1,121 @@
+//! Unfinished-session save and restore
+//!
+//! Lets the player leave a paused game for the title screen and pick it up
+//! again later with the title screen's "Continue" button.
+//!
+//! - `save_session_on_quit` — runs on the `Paused → Title` transition.
+//!   Snapshots every fruit on the board plus score / time into
+//!   `save/session.json` and sets [`SessionAvailable`].
+//!
+//! - `restore_or_discard_session` — runs on the `Title → Playing` transition,
+//!   after `reset_game_state` has cleared the previous board.  Respawns the
+//!   saved fruits when [`PendingSessionRestore`] is set; otherwise the player
+//!   chose "New Game" and the stale session file is deleted.
+//!
+//! Either way the session file is removed once a game starts, so a session
+//! can only be resumed once.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::events::SaveErrorEvent;
+use crate::fruit::FruitType;
+use crate::persistence::{
+    PendingSessionRestore, SessionAvailable, delete_session, report_save_error, save_session,
+    try_load_session,
+};
+use crate::profiles::CurrentProfile;
+use crate::resources::{FruitQueue, GameState, NextFruitType, SessionStats};
+use crate::systems::board::{BoardCommands, capture_board};
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Saves the current board to `save/session.json` when quitting to title.
+///
+/// Registered on `OnTransition { exited: Paused, entered: Title }`, which runs
+/// before the title screen is built so the "Continue" button appears
+/// immediately.
+pub fn save_session_on_quit(
+    game_state: Res<GameState>,
+    next_fruit: Res<NextFruitType>,
+    queue: Res<FruitQueue>,
+    fruit_query: Query<(&Transform, &FruitType, &FruitSpawnState, Option<&Velocity>), With<Fruit>>,
+    mut available: ResMut<SessionAvailable>,
+    profile: Res<CurrentProfile>,
+    mut errors: MessageWriter<SaveErrorEvent>,
+) {
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    let data = capture_board(
+        &game_state,
+        &queue.with_next(&next_fruit),
+        fruit_query.iter(),
+    );
+
+    match save_session(&data, dir) {
+        Ok(_) => {
+            available.0 = true;
+            info!(
+                "Session saved ({} fruits, score {})",
+                data.fruits.len(),
+                data.score
+            );
+        }
+        Err(e) => report_save_error("Failed to save session", &e, &mut errors),
+    }
+}
+
+/// Restores the saved board on "Continue", or discards it on "New Game".
+///
+/// Registered on `OnTransition { exited: Title, entered: Playing }` so it runs
+/// after `reset_game_state` (`OnExit(Title)`) and before the first gameplay
+/// frame.  The board is put back with [`BoardCommands::restore_board`];
+/// `spawn_held_fruit` then hands the player the saved held fruit.  A restored
+/// game has no shareable [`SessionStats::seed_code`].
+pub fn restore_or_discard_session(
+    mut pending: ResMut<PendingSessionRestore>,
+    mut available: ResMut<SessionAvailable>,
+    mut board: BoardCommands,
+    mut stats: ResMut<SessionStats>,
+    profile: Res<CurrentProfile>,
+    mut errors: MessageWriter<SaveErrorEvent>,
+) {
+    let restore = std::mem::take(&mut pending.0);
+    let Some(save_dir) = profile.path() else {
+        return;
+    };
+
+    if restore {
+        let session = try_load_session(save_dir).unwrap_or_else(|e| {
+            report_save_error("Failed to read saved session", &e, &mut errors);
+            None
+        });
+        match session {
+            Some(data) if board.restore_board(&data) => {
+                for saved in &data.fruits {
+                    stats.record_fruit(saved.fruit_type);
+                }
+                // The restored board did not come from this game's seed
+                stats.seed_code = None;
+                info!(
+                    "Session restored ({} fruits, score {})",
+                    data.fruits.len(),
+                    data.score
+                );
+            }
+            Some(_) => warn!("Fruits config not loaded; starting a new game"),
+            None => warn!("Saved session could not be read; starting a new game"),
+        }
+    }
+
+    if available.0 || restore {
+        if let Err(e) = delete_session(save_dir) {
+            report_save_error("Failed to delete session file", &e, &mut errors);
+        }
+        available.0 = false;
+    }
+}
@@ -0,0 +1,371 @@
+//! Per-game session summary logging
+//!
+//! Collects drops, merges and the score timeline for the current game in
+//! [`SessionStats`], and — when the player has opted in via
+//! `SettingsResource::export_session_logs` — writes them to
+//! `save/sessions/session-<timestamp>.json` on game over.
+//!
+//! - `begin_session_stats` — reseeds [`SpawnRng`] (with the entered seed
+//!   code or the `--seed` launch option when given), draws the first
+//!   [`NextFruitType`] and [`FruitQueue`] from it and clears the stats at
+//!   the start of every game (same schedules as `reset_game_state`).
+//! - `record_session_drops` / `record_session_merges` — tally gameplay events.
+//! - `export_session_summary` — writes the summary on `OnEnter(GameOver)`.
+//! - `keep_best_run` — keeps a highscore game's drops as the [`BestRun`]
+//!   raced by the ghost run.
+//! - `record_lifetime_merges` / `save_lifetime_stats` — feed the lifetime
+//!   [`StatsResource`] and write it to `save/stats.json` on game over.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use crate::config::GameRulesParams;
+use crate::events::{FruitDroppedEvent, SaveErrorEvent, ScoreEarnedEvent};
+use crate::launch::LaunchOptions;
+use crate::persistence::{
+    BestRun, report_save_error, save_best_run, save_session_summary, save_stats,
+};
+use crate::profiles::CurrentProfile;
+use crate::resources::{
+    FruitQueue, GameMode, GameState, NextFruitType, SessionStats, SettingsResource, SpawnRng,
+    StatsResource,
+};
+use crate::seed_code::{ChallengeSeed, RetrySeed, SeedCode};
+use crate::systems::input::DEFAULT_SPAWNABLE_FRUIT_COUNT;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Starts a new [`SessionStats`] record and reseeds [`SpawnRng`].
+///
+/// A pending [`RetrySeed`] replays one game's fruit sequence; a
+/// [`ChallengeSeed`] or a fixed [`LaunchOptions::seed`] (in that order)
+/// makes every game replay the same one; otherwise each game gets a fresh
+/// random seed.  The next fruit and the [`FruitQueue`] behind it are drawn
+/// from the new seed too, so the sequence does not depend on the previous
+/// game.
+///
+/// The game's [`SeedCode`] is recorded in
+/// [`SessionStats::seed_code`] for sharing; `spawn_held_fruit` drops it
+/// again if adaptive spawn changes the fruit sequence.
+#[allow(clippy::too_many_arguments)]
+pub fn begin_session_stats(
+    mut stats: ResMut<SessionStats>,
+    mut spawn_rng: ResMut<SpawnRng>,
+    mut next_fruit: ResMut<NextFruitType>,
+    mut queue: ResMut<FruitQueue>,
+    mut retry: Option<ResMut<RetrySeed>>,
+    challenge: Option<Res<ChallengeSeed>>,
+    launch: Option<Res<LaunchOptions>>,
+    mode: Option<Res<GameMode>>,
+    rules: GameRulesParams,
+) {
+    let code = retry
+        .as_mut()
+        .and_then(|r| r.0.take())
+        .or_else(|| challenge.and_then(|c| c.0));
+    let seed = code
+        .map(|c| c.seed)
+        .or_else(|| launch.and_then(|l| l.seed))
+        .unwrap_or_else(rand::random);
+    spawn_rng.reseed(seed);
+
+    let spawnable_count = code.map(|c| c.difficulty as usize).unwrap_or_else(|| {
+        rules
+            .get()
+            .map(|r| r.spawnable_fruit_count)
+            .unwrap_or(DEFAULT_SPAWNABLE_FRUIT_COUNT)
+    });
+    next_fruit.randomize_with(&mut spawn_rng, spawnable_count);
+    queue.refill_with(&mut spawn_rng, spawnable_count);
+
+    let started_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    *stats = SessionStats::new(spawn_rng.seed(), started_at);
+    stats.seed_code = Some(SeedCode::new(
+        seed,
+        code.map(|c| c.mode)
+            .or_else(|| mode.map(|m| *m))
+            .unwrap_or_default(),
+        spawnable_count as u8,
+    ));
+}
+
+/// Counts and logs every [`FruitDroppedEvent`].
+pub fn record_session_drops(
+    mut dropped_events: MessageReader<FruitDroppedEvent>,
+    mut stats: ResMut<SessionStats>,
+    game_state: Res<GameState>,
+) {
+    for event in dropped_events.read() {
+        stats.record_drop(game_state.elapsed_time, event.position, event.fruit_type);
+    }
+}
+
+/// Records a merge, a score-timeline sample, the resulting fruit and the
+/// best combo for every [`ScoreEarnedEvent`].
+pub fn record_session_merges(
+    mut score_events: MessageReader<ScoreEarnedEvent>,
+    mut stats: ResMut<SessionStats>,
+    game_state: Res<GameState>,
+) {
+    for event in score_events.read() {
+        stats.record_merge(game_state.elapsed_time, game_state.score);
+        stats.best_combo = stats.best_combo.max(event.combo_count);
+        // Two watermelons vanish; the run still reached a watermelon
+        stats.record_fruit(event.fruit_type.next().unwrap_or(event.fruit_type));
+    }
+}
+
+/// Writes the session summary to `save/sessions/` when enabled in settings.
+pub fn export_session_summary(
+    stats: Res<SessionStats>,
+    game_state: Res<GameState>,
+    settings: Res<SettingsResource>,
+    profile: Res<CurrentProfile>,
+    mut errors: MessageWriter<SaveErrorEvent>,
+) {
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    if !settings.export_session_logs {
+        return;
+    }
+
+    let summary = stats.to_summary(game_state.score, game_state.elapsed_time);
+    match save_session_summary(&summary, dir) {
+        Ok(path) => info!("Session summary written to {}", path.display()),
+        Err(e) => report_save_error("Failed to write session summary", &e, &mut errors),
+    }
+}
+
+/// Makes a game that set a new highscore the [`BestRun`] and saves it.
+///
+/// A game continued from a saved session only logged the drops since it
+/// was restored (it has no seed code), so it is not kept.
+pub fn keep_best_run(
+    stats: Res<SessionStats>,
+    game_state: Res<GameState>,
+    profile: Res<CurrentProfile>,
+    mut best_run: ResMut<BestRun>,
+    mut errors: MessageWriter<SaveErrorEvent>,
+) {
+    if !game_state.is_new_record || stats.seed_code.is_none() {
+        return;
+    }
+    *best_run = stats.to_best_run(game_state.score);
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    if let Err(e) = save_best_run(&best_run, dir) {
+        report_save_error("Failed to save best run", &e, &mut errors);
+    }
+}
+
+/// Counts every merge in the lifetime [`StatsResource`].
+pub fn record_lifetime_merges(
+    mut score_events: MessageReader<ScoreEarnedEvent>,
+    mut stats: ResMut<StatsResource>,
+) {
+    for event in score_events.read() {
+        stats.record_merge(event.fruit_type);
+    }
+}
+
+/// Adds the finished game to the lifetime [`StatsResource`] and saves it.
+pub fn save_lifetime_stats(
+    mut stats: ResMut<StatsResource>,
+    game_state: Res<GameState>,
+    profile: Res<CurrentProfile>,
+    mut errors: MessageWriter<SaveErrorEvent>,
+) {
+    stats.record_game(game_state.score, game_state.elapsed_time);
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    if let Err(e) = save_stats(&stats, dir) {
+        report_save_error("Failed to save stats", &e, &mut errors);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fruit::FruitType;
+
+    #[test]
+    fn test_record_session_events() {
+        let mut app = App::new();
+        app.add_message::<FruitDroppedEvent>()
+            .add_message::<ScoreEarnedEvent>()
+            .init_resource::<SessionStats>()
+            .insert_resource(GameState {
+                score: 50,
+                elapsed_time: 7.0,
+                ..default()
+            })
+            .add_systems(Update, (record_session_drops, record_session_merges));
+
+        app.world_mut().write_message(FruitDroppedEvent {
+            fruit_type: FruitType::Cherry,
+            position: Vec2::ZERO,
+        });
+        app.world_mut().write_message(ScoreEarnedEvent {
+            position: Vec2::ZERO,
+            earned_points: 50,
+            combo_count: 1,
+            fruit_type: FruitType::Cherry,
+        });
+        app.update();
+
+        let stats = app.world().resource::<SessionStats>();
+        assert_eq!(stats.drops, 1);
+        assert_eq!(stats.drop_timeline[0].time, 7.0);
+        assert_eq!(stats.merges, 1);
+        assert_eq!(stats.score_timeline[0].score, 50);
+        assert_eq!(stats.score_timeline[0].time, 7.0);
+        assert_eq!(stats.largest_fruit, Some(FruitType::Strawberry));
+        assert_eq!(stats.best_combo, 1);
+    }
+
+    #[test]
+    fn test_lifetime_stats_count_merges_and_games() {
+        let mut app = App::new();
+        app.add_message::<ScoreEarnedEvent>()
+            .add_message::<SaveErrorEvent>()
+            .init_resource::<StatsResource>()
+            .init_resource::<CurrentProfile>()
+            .insert_resource(GameState {
+                score: 1_200,
+                elapsed_time: 90.0,
+                ..default()
+            })
+            .add_systems(Update, record_lifetime_merges);
+
+        app.world_mut().write_message(ScoreEarnedEvent {
+            position: Vec2::ZERO,
+            earned_points: 10,
+            combo_count: 1,
+            fruit_type: FruitType::Grape,
+        });
+        app.update();
+        app.world_mut()
+            .run_system_cached(save_lifetime_stats)
+            .unwrap();
+
+        let stats = app.world().resource::<StatsResource>();
+        assert_eq!(stats.merges_of(FruitType::Grape), 1);
+        assert_eq!(stats.games_played, 1);
+        assert_eq!(stats.total_playtime, 90.0);
+        assert_eq!(stats.average_score(), Some(1_200));
+    }
+
+    #[test]
+    fn test_keep_best_run_keeps_new_records_only() {
+        let mut app = App::new();
+        app.add_message::<SaveErrorEvent>()
+            .init_resource::<CurrentProfile>()
+            .init_resource::<BestRun>()
+            .insert_resource(GameState {
+                score: 800,
+                ..default()
+            });
+        let mut stats = SessionStats::new(7, 0);
+        stats.seed_code = Some(SeedCode::new(7, GameMode::Classic, 5));
+        stats.record_drop(1.0, Vec2::new(10.0, 300.0), FruitType::Cherry);
+        app.insert_resource(stats);
+
+        app.world_mut().run_system_cached(keep_best_run).unwrap();
+        assert!(app.world().resource::<BestRun>().is_empty());
+
+        app.world_mut().resource_mut::<GameState>().is_new_record = true;
+        app.world_mut().run_system_cached(keep_best_run).unwrap();
+        let best_run = app.world().resource::<BestRun>();
+        assert_eq!(best_run.final_score, 800);
+        assert_eq!(best_run.drops.len(), 1);
+    }
+
+    #[test]
+    fn test_record_fruit_only_counts_larger_fruits() {
+        let mut stats = SessionStats::default();
+        assert!(stats.record_fruit(FruitType::Grape));
+        assert!(!stats.record_fruit(FruitType::Cherry));
+        assert!(!stats.record_fruit(FruitType::Grape));
+        assert!(stats.record_fruit(FruitType::Watermelon));
+        assert_eq!(stats.largest_fruit, Some(FruitType::Watermelon));
+    }
+
+    #[test]
+    fn test_begin_session_stats_uses_launch_seed() {
+        let mut app = App::new();
+        app.init_resource::<SessionStats>()
+            .init_resource::<SpawnRng>()
+            .init_resource::<NextFruitType>()
+            .init_resource::<FruitQueue>()
+            .insert_resource(LaunchOptions {
+                seed: Some(1234),
+                ..default()
+            })
+            .add_systems(Update, begin_session_stats);
+
+        app.update();
+        assert_eq!(app.world().resource::<SpawnRng>().seed(), 1234);
+        assert_eq!(app.world().resource::<SessionStats>().seed, 1234);
+    }
+
+    #[test]
+    fn test_begin_session_stats_prefers_challenge_seed() {
+        let mut app = App::new();
+        app.init_resource::<SessionStats>()
+            .init_resource::<SpawnRng>()
+            .init_resource::<NextFruitType>()
+            .init_resource::<FruitQueue>()
+            .insert_resource(LaunchOptions {
+                seed: Some(1234),
+                ..default()
+            })
+            .insert_resource(ChallengeSeed(Some(SeedCode::new(99, GameMode::Classic, 1))))
+            .add_systems(Update, begin_session_stats);
+
+        app.update();
+        assert_eq!(app.world().resource::<SpawnRng>().seed(), 99);
+        // Difficulty 1 only ever spawns the smallest fruit
+        assert_eq!(
+            app.world().resource::<NextFruitType>().get(),
+            FruitType::Cherry
+        );
+        assert_eq!(
+            app.world().resource::<SessionStats>().seed_code,
+            Some(SeedCode::new(99, GameMode::Classic, 1))
+        );
+    }
+
+    #[test]
+    fn test_begin_session_stats_replays_retry_seed_once() {
+        let mut app = App::new();
+        app.init_resource::<SessionStats>()
+            .init_resource::<SpawnRng>()
+            .init_resource::<NextFruitType>()
+            .init_resource::<FruitQueue>()
+            .init_resource::<RetrySeed>()
+            .add_systems(Update, begin_session_stats);
+
+        app.update();
+        let first = app.world().resource::<SessionStats>().seed_code.unwrap();
+        let first_fruit = app.world().resource::<NextFruitType>().get();
+
+        app.world_mut().resource_mut::<RetrySeed>().0 = Some(first);
+        app.update();
+        assert_eq!(app.world().resource::<SpawnRng>().seed(), first.seed);
+        assert_eq!(app.world().resource::<NextFruitType>().get(), first_fruit);
+        assert_eq!(app.world().resource::<RetrySeed>().0, None);
+    }
+}
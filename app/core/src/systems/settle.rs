@@ -0,0 +1,195 @@
+//! Pile settle detection
+//!
+//! [`detect_board_settled`] watches the fruits on the board and sends a
+//! [`BoardSettledEvent`] the moment everything has come to rest: no fruit is
+//! falling and every landed fruit has moved slower than
+//! [`SETTLE_LINEAR_THRESHOLD`] / [`SETTLE_ANGULAR_THRESHOLD`] for
+//! [`SETTLE_HOLD_SECS`].  The current state is kept in [`BoardSettled`] for
+//! systems that would rather poll.
+//!
+//! ```text
+//! drop ──▶ falling / rolling ──▶ quiet for SETTLE_HOLD_SECS ──▶ BoardSettledEvent
+//!   ▲                                                                │
+//!   └──────────────── next drop or merge starts it moving ◀──────────┘
+//! ```
+//!
+//! Listeners: the auto-drop assist waits for a quiet board before dropping,
+//! and the emergency save refreshes its snapshot at every settle, when the
+//! board is in a safe state to restore.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::events::BoardSettledEvent;
+
+/// Fastest a fruit may move and still count as at rest (pixels/second).
+pub const SETTLE_LINEAR_THRESHOLD: f32 = 5.0;
+
+/// Fastest a fruit may spin and still count as at rest (radians/second).
+pub const SETTLE_ANGULAR_THRESHOLD: f32 = 0.2;
+
+/// Seconds the board must stay quiet before it counts as settled, so a
+/// fruit passing through zero speed mid-bounce does not trigger it.
+pub const SETTLE_HOLD_SECS: f32 = 0.25;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Whether the pile is at rest, updated by [`detect_board_settled`].
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct BoardSettled {
+    /// `true` from the [`BoardSettledEvent`] until something moves again
+    pub settled: bool,
+    /// Seconds the board has been quiet so far
+    pub quiet_secs: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Updates [`BoardSettled`] and sends [`BoardSettledEvent`] when the board
+/// comes to rest.
+///
+/// The held fruit is ignored; fruits without a [`Velocity`] (not yet handed
+/// to the physics engine) count as at rest.
+pub fn detect_board_settled(
+    time: Res<Time>,
+    fruits: Query<(&FruitSpawnState, Option<&Velocity>), With<Fruit>>,
+    mut board: ResMut<BoardSettled>,
+    mut settled_events: MessageWriter<BoardSettledEvent>,
+) {
+    let quiet = fruits
+        .iter()
+        .all(|(state, velocity)| fruit_at_rest(*state, velocity));
+
+    if !quiet {
+        if board.settled || board.quiet_secs > 0.0 {
+            *board = BoardSettled::default();
+        }
+        return;
+    }
+    if board.settled {
+        return;
+    }
+    board.quiet_secs += time.delta_secs();
+    if board.quiet_secs >= SETTLE_HOLD_SECS {
+        board.settled = true;
+        debug!("Board settled after {:.2}s quiet", board.quiet_secs);
+        settled_events.write(BoardSettledEvent {
+            fruit_count: fruits
+                .iter()
+                .filter(|(state, _)| **state == FruitSpawnState::Landed)
+                .count(),
+        });
+    }
+}
+
+/// Clears [`BoardSettled`] so a new game starts unsettled.
+pub fn reset_board_settled(mut board: ResMut<BoardSettled>) {
+    *board = BoardSettled::default();
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// `true` when a fruit in `state` moving at `velocity` does not keep the
+/// board from settling.
+fn fruit_at_rest(state: FruitSpawnState, velocity: Option<&Velocity>) -> bool {
+    match state {
+        FruitSpawnState::Held => true,
+        FruitSpawnState::Falling => false,
+        FruitSpawnState::Landed => velocity.is_none_or(|v| {
+            v.linvel.length() < SETTLE_LINEAR_THRESHOLD && v.angvel.abs() < SETTLE_ANGULAR_THRESHOLD
+        }),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn settle_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<BoardSettled>()
+            .add_message::<BoardSettledEvent>()
+            .add_systems(Update, detect_board_settled);
+        app
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.update();
+    }
+
+    fn settled_events(app: &mut App) -> usize {
+        app.world_mut()
+            .resource_mut::<Messages<BoardSettledEvent>>()
+            .drain()
+            .count()
+    }
+
+    #[test]
+    fn test_fruit_at_rest() {
+        let slow = Velocity::linear(Vec2::new(1.0, 0.0));
+        let fast = Velocity::linear(Vec2::new(0.0, -80.0));
+        let spinning = Velocity::angular(1.0);
+        assert!(fruit_at_rest(FruitSpawnState::Landed, Some(&slow)));
+        assert!(!fruit_at_rest(FruitSpawnState::Landed, Some(&fast)));
+        assert!(!fruit_at_rest(FruitSpawnState::Landed, Some(&spinning)));
+        assert!(!fruit_at_rest(FruitSpawnState::Falling, Some(&slow)));
+        assert!(fruit_at_rest(FruitSpawnState::Held, Some(&fast)));
+    }
+
+    #[test]
+    fn test_settles_once_after_hold() {
+        let mut app = settle_app();
+        let fruit = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                FruitSpawnState::Landed,
+                Velocity::linear(Vec2::new(0.0, -80.0)),
+            ))
+            .id();
+        advance(&mut app, SETTLE_HOLD_SECS * 2.0);
+        assert!(!app.world().resource::<BoardSettled>().settled);
+        assert_eq!(settled_events(&mut app), 0);
+
+        *app.world_mut().get_mut::<Velocity>(fruit).unwrap() = Velocity::zero();
+        advance(&mut app, SETTLE_HOLD_SECS / 2.0);
+        assert_eq!(settled_events(&mut app), 0, "not quiet for long enough");
+        advance(&mut app, SETTLE_HOLD_SECS);
+        assert!(app.world().resource::<BoardSettled>().settled);
+        assert_eq!(settled_events(&mut app), 1);
+
+        advance(&mut app, SETTLE_HOLD_SECS);
+        assert_eq!(settled_events(&mut app), 0, "sent once per settle");
+    }
+
+    #[test]
+    fn test_movement_unsettles_the_board() {
+        let mut app = settle_app();
+        app.insert_resource(BoardSettled {
+            settled: true,
+            quiet_secs: 1.0,
+        });
+        app.world_mut().spawn((Fruit, FruitSpawnState::Falling));
+        advance(&mut app, 0.1);
+        assert_eq!(
+            *app.world().resource::<BoardSettled>(),
+            BoardSettled::default()
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Rapier sleep tuning
+//!
+//! A settled pile of fruit should cost next to nothing to simulate.  Rapier
+//! already puts slow bodies to sleep; [`apply_fruit_sleep_thresholds`] makes
+//! the thresholds configurable from `physics.ron` by writing them to every
+//! newly spawned fruit, and to all fruits when `physics.ron` is (re)loaded.
+//! Container walls are fixed bodies and never simulate, so only fruits need
+//! tuning.
+//!
+//! How many bodies are still awake is reported by the
+//! [`AWAKE_BODIES`](crate::diagnostics::AWAKE_BODIES) diagnostic.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Sleeping;
+
+use crate::components::Fruit;
+use crate::config::{
+    DEFAULT_SLEEP_ANGULAR_THRESHOLD, DEFAULT_SLEEP_LINEAR_THRESHOLD, PhysicsConfig, PhysicsParams,
+};
+
+/// Applies the `physics.ron` sleep thresholds to fruits.
+///
+/// New fruits are updated on the frame they appear; every fruit is updated
+/// when the physics config is loaded or hot-reloaded.
+pub fn apply_fruit_sleep_thresholds(
+    mut events: MessageReader<AssetEvent<PhysicsConfig>>,
+    physics: PhysicsParams,
+    mut fruit_query: Query<(Ref<Fruit>, &mut Sleeping)>,
+) {
+    let config_changed = events
+        .read()
+        .any(|e| matches!(e, AssetEvent::Added { .. } | AssetEvent::Modified { .. }));
+
+    let (linear, angular) = physics
+        .get()
+        .map(|cfg| (cfg.sleep_linear_threshold, cfg.sleep_angular_threshold))
+        .unwrap_or((
+            DEFAULT_SLEEP_LINEAR_THRESHOLD,
+            DEFAULT_SLEEP_ANGULAR_THRESHOLD,
+        ));
+
+    for (fruit, mut sleeping) in fruit_query.iter_mut() {
+        if config_changed || fruit.is_added() {
+            sleeping.normalized_linear_threshold = linear;
+            sleeping.angular_threshold = angular;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_fruits_get_default_thresholds() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<AssetEvent<PhysicsConfig>>();
+        app.add_systems(Update, apply_fruit_sleep_thresholds);
+
+        let fruit = app.world_mut().spawn((Fruit, Sleeping::default())).id();
+        app.update();
+
+        let sleeping = app.world().get::<Sleeping>(fruit).unwrap();
+        assert_eq!(
+            sleeping.normalized_linear_threshold,
+            DEFAULT_SLEEP_LINEAR_THRESHOLD
+        );
+        assert_eq!(sleeping.angular_threshold, DEFAULT_SLEEP_ANGULAR_THRESHOLD);
+    }
+}
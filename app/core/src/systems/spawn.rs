@@ -132,6 +132,8 @@ pub fn spawn_fruit(
                 image,
                 color,
                 custom_size: Some(Vec2::splat(params.radius * 2.0 * params.sprite_scale)),
+                // Idle frame when the sprite is a sheet
+                texture_atlas: sprites.and_then(|s| s.atlas(fruit_type)),
                 ..default()
             },
             // Sprite anchor offset (horizontal + vertical) for fine-tuned alignment.
@@ -155,6 +157,11 @@ pub fn spawn_fruit(
             },
             // Gravity scale: 1.0 = normal gravity, 0.0 = no gravity
             GravityScale(1.0),
+            // Velocity readback and CCD toggle used by `regulate_fruit_velocity`
+            Velocity::zero(),
+            Ccd::disabled(),
+            // Sleep state; thresholds are set by `apply_fruit_sleep_thresholds`
+            Sleeping::default(),
         ))
         .id()
 }
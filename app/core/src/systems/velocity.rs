@@ -0,0 +1,104 @@
+//! Anti-tunneling for fast fruits
+//!
+//! Small fruits dropped from the top can reach speeds where a single physics
+//! step carries them further than a wall is thick, especially on low-FPS
+//! frames.  [`regulate_fruit_velocity`] guards against that in two ways:
+//!
+//! - **CCD** — continuous collision detection is switched on only for fruits
+//!   moving faster than `ccd_speed_threshold`, so the cost is paid just while
+//!   it matters.
+//! - **Speed cap** — linear velocity is clamped to `max_fruit_speed`.
+//!
+//! Both values come from `physics.ron`.
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{Ccd, Velocity};
+
+use crate::components::Fruit;
+use crate::config::{DEFAULT_CCD_SPEED_THRESHOLD, DEFAULT_MAX_FRUIT_SPEED, PhysicsParams};
+
+/// Toggles CCD and clamps the linear velocity of every fruit.
+///
+/// Components are only written when the value actually changes so Rapier
+/// does not resync untouched bodies every frame.
+pub fn regulate_fruit_velocity(
+    physics: PhysicsParams,
+    mut fruit_query: Query<(&mut Velocity, &mut Ccd), With<Fruit>>,
+) {
+    let (ccd_threshold, max_speed) = physics
+        .get()
+        .map(|cfg| (cfg.ccd_speed_threshold, cfg.max_fruit_speed))
+        .unwrap_or((DEFAULT_CCD_SPEED_THRESHOLD, DEFAULT_MAX_FRUIT_SPEED));
+
+    for (mut velocity, mut ccd) in fruit_query.iter_mut() {
+        let speed = velocity.linvel.length();
+
+        let needs_ccd = speed > ccd_threshold;
+        if ccd.enabled != needs_ccd {
+            ccd.enabled = needs_ccd;
+        }
+
+        if speed > max_speed {
+            velocity.linvel = clamp_speed(velocity.linvel, max_speed);
+        }
+    }
+}
+
+/// Scales `linvel` down so its length does not exceed `max_speed`.
+fn clamp_speed(linvel: Vec2, max_speed: f32) -> Vec2 {
+    linvel.clamp_length_max(max_speed.max(0.0))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_speed_keeps_direction() {
+        let clamped = clamp_speed(Vec2::new(0.0, -5000.0), 2500.0);
+        assert_eq!(clamped, Vec2::new(0.0, -2500.0));
+    }
+
+    #[test]
+    fn test_clamp_speed_leaves_slow_fruit_alone() {
+        let linvel = Vec2::new(30.0, -40.0);
+        assert_eq!(clamp_speed(linvel, 2500.0), linvel);
+    }
+
+    #[test]
+    fn test_regulate_fruit_velocity_uses_defaults() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, regulate_fruit_velocity);
+
+        let slow = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                Velocity::linear(Vec2::new(0.0, -100.0)),
+                Ccd::disabled(),
+            ))
+            .id();
+        let fast = app
+            .world_mut()
+            .spawn((
+                Fruit,
+                Velocity::linear(Vec2::new(0.0, -10_000.0)),
+                Ccd::disabled(),
+            ))
+            .id();
+        app.update();
+
+        let world = app.world();
+        assert!(!world.get::<Ccd>(slow).unwrap().enabled);
+        assert!(world.get::<Ccd>(fast).unwrap().enabled);
+        assert_eq!(
+            world.get::<Velocity>(fast).unwrap().linvel,
+            Vec2::new(0.0, -DEFAULT_MAX_FRUIT_SPEED)
+        );
+    }
+}
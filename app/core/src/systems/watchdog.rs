@@ -0,0 +1,271 @@
+//! Stuck-fruit watchdog
+//!
+//! Over a long session the physics solver occasionally leaves a fruit
+//! pushed deep into a wall or another fruit, where it jitters forever or
+//! pins the pile.  [`watch_stuck_fruits`] looks for landed fruits that stay
+//! embedded — overlapping by more than [`EMBED_TOLERANCE`] of their radius —
+//! for [`EMBED_FRAMES`] frames in a row, and recovers them:
+//!
+//! 1. **Separate** — the fruit is moved out of the overlap and stopped.
+//! 2. **Respawn** — a fruit that got stuck again after
+//!    [`RESPAWN_AFTER_NUDGES`] separations is lifted to the top of the
+//!    container and dropped back onto the pile.
+//!
+//! Every recovery is logged with the fruit, its position and what it was
+//! stuck in, and counted in [`StuckFruitWatchdog::recovered`].
+//!
+//! The check is geometric (circles against the container walls and each
+//! other), so it needs no extra Rapier queries and runs on plain data.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::components::{Fruit, FruitSpawnState};
+use crate::config::{FruitsParams, PhysicsParams};
+use crate::fruit::FruitType;
+
+/// Overlap, as a fraction of the fruit's radius, beyond which it counts as
+/// embedded.  Ordinary resting contacts overlap by far less.
+pub const EMBED_TOLERANCE: f32 = 0.25;
+
+/// Frames a fruit must stay embedded before the watchdog steps in.
+pub const EMBED_FRAMES: u32 = 30;
+
+/// Separations after which a fruit that is stuck again gets respawned.
+pub const RESPAWN_AFTER_NUDGES: u32 = 3;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Watchdog bookkeeping: how long each fruit has been embedded and how
+/// often it was separated.
+#[derive(Resource, Debug, Default)]
+pub struct StuckFruitWatchdog {
+    /// Consecutive embedded frames per fruit
+    embedded_frames: HashMap<Entity, u32>,
+    /// Separations performed per fruit
+    nudges: HashMap<Entity, u32>,
+    /// Fruits recovered (separated or respawned) since startup
+    pub recovered: u32,
+}
+
+/// What an embedded fruit is stuck in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmbedContact {
+    /// One of the container walls or the floor
+    Wall,
+    /// Another fruit
+    Fruit(Entity),
+}
+
+/// An embedded fruit and the move that would free it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Embed {
+    /// The embedded fruit
+    pub entity: Entity,
+    /// Translation that separates it from the deepest overlap
+    pub push: Vec2,
+    /// What the deepest overlap is with
+    pub contact: EmbedContact,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Separates or respawns fruits that have stayed embedded for
+/// [`EMBED_FRAMES`] frames.
+#[allow(clippy::type_complexity)]
+pub fn watch_stuck_fruits(
+    mut watchdog: ResMut<StuckFruitWatchdog>,
+    physics: PhysicsParams,
+    fruits_config: FruitsParams,
+    mut fruits: Query<
+        (
+            Entity,
+            &FruitType,
+            &FruitSpawnState,
+            &mut Transform,
+            Option<&mut Velocity>,
+        ),
+        With<Fruit>,
+    >,
+) {
+    let (Some(physics), Some(fruits_config)) = (physics.get(), fruits_config.get()) else {
+        return;
+    };
+    let half = Vec2::new(physics.container_width, physics.container_height) / 2.0;
+
+    let bodies: Vec<(Entity, Vec2, f32)> = fruits
+        .iter()
+        .filter(|(_, _, state, _, _)| **state == FruitSpawnState::Landed)
+        .filter_map(|(entity, fruit_type, _, transform, _)| {
+            let radius = fruit_type.try_parameters_from_config(fruits_config)?.radius;
+            Some((entity, transform.translation.truncate(), radius))
+        })
+        .collect();
+    let embeds = find_embeds(&bodies, half);
+
+    // Forget fruits that came free or no longer exist
+    let watchdog = &mut *watchdog;
+    watchdog
+        .embedded_frames
+        .retain(|entity, _| embeds.iter().any(|e| e.entity == *entity));
+    watchdog
+        .nudges
+        .retain(|entity, _| bodies.iter().any(|(e, _, _)| e == entity));
+
+    for embed in embeds {
+        let frames = watchdog.embedded_frames.entry(embed.entity).or_default();
+        *frames += 1;
+        if *frames < EMBED_FRAMES {
+            continue;
+        }
+        *frames = 0;
+        let Ok((_, fruit_type, _, mut transform, velocity)) = fruits.get_mut(embed.entity) else {
+            continue;
+        };
+        let nudges = watchdog.nudges.entry(embed.entity).or_default();
+        *nudges += 1;
+        let from = transform.translation.truncate();
+
+        if *nudges > RESPAWN_AFTER_NUDGES {
+            let radius = bodies
+                .iter()
+                .find(|(e, _, _)| *e == embed.entity)
+                .map_or(0.0, |(_, _, r)| *r);
+            let x = from.x.clamp(-half.x + radius, half.x - radius);
+            transform.translation.x = x;
+            transform.translation.y = half.y - radius;
+            *nudges = 0;
+            warn!(
+                "🩹 Watchdog: respawned {:?} stuck in {:?} at ({:.1}, {:.1})",
+                fruit_type, embed.contact, from.x, from.y
+            );
+        } else {
+            transform.translation += embed.push.extend(0.0);
+            warn!(
+                "🩹 Watchdog: separated {:?} from {:?} at ({:.1}, {:.1}), overlap {:.1}px",
+                fruit_type,
+                embed.contact,
+                from.x,
+                from.y,
+                embed.push.length()
+            );
+        }
+        if let Some(mut velocity) = velocity {
+            *velocity = Velocity::zero();
+        }
+        watchdog.recovered += 1;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Finds every fruit in `bodies` (entity, centre, radius) embedded deeper
+/// than [`EMBED_TOLERANCE`] in a wall of the container (inner half size
+/// `half`, centred on the origin) or another fruit.
+///
+/// Each embedded fruit is reported once, with the push out of its deepest
+/// overlap; in a fruit pair each side is pushed half the overlap.
+pub fn find_embeds(bodies: &[(Entity, Vec2, f32)], half: Vec2) -> Vec<Embed> {
+    let mut deepest: HashMap<Entity, (f32, Embed)> = HashMap::new();
+    let mut record = |entity: Entity, depth: f32, radius: f32, push: Vec2, contact| {
+        if depth <= radius * EMBED_TOLERANCE {
+            return;
+        }
+        let embed = Embed {
+            entity,
+            push,
+            contact,
+        };
+        let entry = deepest.entry(entity).or_insert((depth, embed));
+        if depth > entry.0 {
+            *entry = (depth, embed);
+        }
+    };
+
+    for &(entity, pos, radius) in bodies {
+        let walls = [
+            (radius - (pos.x + half.x), Vec2::X),
+            (radius - (half.x - pos.x), Vec2::NEG_X),
+            (radius - (pos.y + half.y), Vec2::Y),
+        ];
+        for (depth, normal) in walls {
+            record(entity, depth, radius, normal * depth, EmbedContact::Wall);
+        }
+    }
+
+    for (i, &(a, pos_a, radius_a)) in bodies.iter().enumerate() {
+        for &(b, pos_b, radius_b) in &bodies[i + 1..] {
+            let offset = pos_a - pos_b;
+            let depth = radius_a + radius_b - offset.length();
+            if depth <= 0.0 {
+                continue;
+            }
+            // Coincident centres: pick any direction
+            let normal = offset.try_normalize().unwrap_or(Vec2::Y);
+            let push = normal * depth / 2.0;
+            let smaller = radius_a.min(radius_b);
+            record(a, depth, smaller, push, EmbedContact::Fruit(b));
+            record(b, depth, smaller, -push, EmbedContact::Fruit(a));
+        }
+    }
+
+    let mut embeds: Vec<Embed> = deepest.into_values().map(|(_, embed)| embed).collect();
+    embeds.sort_by_key(|e| e.entity);
+    embeds
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF: Vec2 = Vec2::new(300.0, 400.0);
+
+    fn entity(bits: u64) -> Entity {
+        Entity::from_bits(bits)
+    }
+
+    #[test]
+    fn test_resting_contacts_are_not_embedded() {
+        let bodies = [
+            // Resting on the floor, slightly overlapping
+            (entity(1), Vec2::new(0.0, -HALF.y + 19.0), 20.0),
+            // Touching its neighbour
+            (entity(2), Vec2::new(39.0, -HALF.y + 20.0), 20.0),
+        ];
+        assert!(find_embeds(&bodies, HALF).is_empty());
+    }
+
+    #[test]
+    fn test_fruit_in_wall_is_pushed_inwards() {
+        let bodies = [(entity(1), Vec2::new(HALF.x - 5.0, 0.0), 20.0)];
+        let embeds = find_embeds(&bodies, HALF);
+        assert_eq!(embeds.len(), 1);
+        assert_eq!(embeds[0].contact, EmbedContact::Wall);
+        assert_eq!(embeds[0].push, Vec2::new(-15.0, 0.0));
+    }
+
+    #[test]
+    fn test_overlapping_fruits_are_pushed_apart() {
+        let bodies = [
+            (entity(1), Vec2::new(0.0, 0.0), 20.0),
+            (entity(2), Vec2::new(20.0, 0.0), 20.0),
+        ];
+        let embeds = find_embeds(&bodies, HALF);
+        assert_eq!(embeds.len(), 2);
+        assert_eq!(embeds[0].push, Vec2::new(-10.0, 0.0));
+        assert_eq!(embeds[0].contact, EmbedContact::Fruit(entity(2)));
+        assert_eq!(embeds[1].push, Vec2::new(10.0, 0.0));
+    }
+}
@@ -0,0 +1,218 @@
+//! Screen transitions between [`AppState`]s.
+//!
+//! Instead of setting [`NextState`] directly, menus call
+//! [`ScreenTransition::start`].  The transition then runs in two halves:
+//!
+//! 1. **Out** — the screen is covered over [`ScreenTransition::out_secs`].
+//! 2. The state switches while the screen is fully covered, so `OnExit` /
+//!    `OnEnter` work (despawning one screen, spawning the next, resetting
+//!    the board) never shows.
+//! 3. **In** — the cover is removed over [`ScreenTransition::in_secs`].
+//!
+//! The UI draws the cover from [`ScreenTransition::coverage`]; gameplay input
+//! is gated on [`screen_transition_idle`] so play only starts once the new
+//! screen is fully visible.
+//!
+//! Instant state changes (pause / resume, the game-over trigger, tests that
+//! set [`NextState`] themselves) bypass the transition entirely.
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const DEFAULT_OUT_SECS: f32 = 0.25;
+const DEFAULT_IN_SECS: f32 = 0.25;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// How the cover is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TransitionStyle {
+    /// The whole screen fades to [`ScreenTransition::color`] and back.
+    #[default]
+    Fade,
+    /// A solid band sweeps across from left to right.
+    Wipe,
+}
+
+/// Which half of a transition is running.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TransitionPhase {
+    /// No transition.
+    Idle,
+    /// Covering the screen; switches to `target` when done.
+    Out { target: AppState, elapsed: f32 },
+    /// Uncovering the new screen.
+    In { elapsed: f32 },
+}
+
+/// The running screen transition and how transitions look.
+#[derive(Resource, Debug, Clone)]
+pub struct ScreenTransition {
+    /// How the cover is drawn.
+    pub style: TransitionStyle,
+    /// Colour of the cover.
+    pub color: Color,
+    /// Seconds to cover the old screen.
+    pub out_secs: f32,
+    /// Seconds to reveal the new screen.
+    pub in_secs: f32,
+    phase: TransitionPhase,
+}
+
+impl Default for ScreenTransition {
+    fn default() -> Self {
+        Self {
+            style: TransitionStyle::default(),
+            color: Color::BLACK,
+            out_secs: DEFAULT_OUT_SECS,
+            in_secs: DEFAULT_IN_SECS,
+            phase: TransitionPhase::Idle,
+        }
+    }
+}
+
+impl ScreenTransition {
+    /// Starts a transition to `target`.
+    ///
+    /// Ignored while another transition is running, so a double click cannot
+    /// queue two state changes.
+    pub fn start(&mut self, target: AppState) {
+        if self.is_idle() {
+            self.phase = TransitionPhase::Out {
+                target,
+                elapsed: 0.0,
+            };
+        }
+    }
+
+    /// Returns `true` if no transition is running.
+    pub fn is_idle(&self) -> bool {
+        self.phase == TransitionPhase::Idle
+    }
+
+    /// Returns `true` while the new screen is being revealed.
+    pub fn is_revealing(&self) -> bool {
+        matches!(self.phase, TransitionPhase::In { .. })
+    }
+
+    /// How much of the screen the cover hides, from `0.0` (nothing) to `1.0`
+    /// (everything).
+    pub fn coverage(&self) -> f32 {
+        match self.phase {
+            TransitionPhase::Idle => 0.0,
+            TransitionPhase::Out { elapsed, .. } => ratio(elapsed, self.out_secs),
+            TransitionPhase::In { elapsed } => 1.0 - ratio(elapsed, self.in_secs),
+        }
+    }
+
+    /// Advances the transition by `delta` seconds.
+    ///
+    /// Returns the state to switch to on the frame the screen becomes fully
+    /// covered.
+    fn advance(&mut self, delta: f32) -> Option<AppState> {
+        match &mut self.phase {
+            TransitionPhase::Idle => None,
+            TransitionPhase::Out { target, elapsed } => {
+                *elapsed += delta;
+                if *elapsed < self.out_secs {
+                    return None;
+                }
+                let target = *target;
+                self.phase = TransitionPhase::In { elapsed: 0.0 };
+                Some(target)
+            }
+            TransitionPhase::In { elapsed } => {
+                *elapsed += delta;
+                if *elapsed >= self.in_secs {
+                    self.phase = TransitionPhase::Idle;
+                }
+                None
+            }
+        }
+    }
+}
+
+/// `elapsed / duration` clamped to `0..=1`; `1.0` for a zero duration.
+fn ratio(elapsed: f32, duration: f32) -> f32 {
+    if duration <= 0.0 {
+        1.0
+    } else {
+        (elapsed / duration).clamp(0.0, 1.0)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems / run conditions
+// ---------------------------------------------------------------------------
+
+/// Advances the [`ScreenTransition`] and switches [`AppState`] once the
+/// screen is covered.
+pub fn advance_screen_transition(
+    time: Res<Time>,
+    mut transition: ResMut<ScreenTransition>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if let Some(target) = transition.advance(time.delta_secs()) {
+        next_state.set(target);
+    }
+}
+
+/// Run condition: `true` while no [`ScreenTransition`] is running.
+pub fn screen_transition_idle(transition: Option<Res<ScreenTransition>>) -> bool {
+    transition.is_none_or(|t| t.is_idle())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transition_switches_state_when_covered() {
+        let mut transition = ScreenTransition::default();
+        transition.start(AppState::Playing);
+        assert!(!transition.is_idle());
+
+        assert_eq!(transition.advance(0.1), None);
+        assert!(transition.coverage() > 0.0 && transition.coverage() < 1.0);
+
+        assert_eq!(transition.advance(0.2), Some(AppState::Playing));
+        assert!(transition.is_revealing());
+        assert_eq!(transition.coverage(), 1.0);
+
+        assert_eq!(transition.advance(0.3), None);
+        assert!(transition.is_idle());
+        assert_eq!(transition.coverage(), 0.0);
+    }
+
+    #[test]
+    fn test_start_is_ignored_while_running() {
+        let mut transition = ScreenTransition::default();
+        transition.start(AppState::Playing);
+        transition.start(AppState::Settings);
+        assert_eq!(transition.advance(1.0), Some(AppState::Playing));
+    }
+
+    #[test]
+    fn test_zero_duration_switches_immediately() {
+        let mut transition = ScreenTransition {
+            out_secs: 0.0,
+            in_secs: 0.0,
+            ..default()
+        };
+        transition.start(AppState::Title);
+        assert_eq!(transition.advance(0.0), Some(AppState::Title));
+        transition.advance(0.0);
+        assert!(transition.is_idle());
+    }
+}
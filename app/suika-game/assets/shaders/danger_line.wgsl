@@ -0,0 +1,28 @@
+// Animated dashed danger line drawn over the container's boundary line.
+//
+// The mesh is a unit quad scaled to the line size, so `uv.x * size.x` is the
+// distance along the line in pixels.  Dash edges are softened by one pixel
+// to avoid shimmering while the dashes scroll.
+
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+struct DangerLine {
+    color: vec4<f32>,
+    size: vec2<f32>,
+    dash_length: f32,
+    gap_length: f32,
+    dash_offset: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> line: DangerLine;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let period = max(line.dash_length + line.gap_length, 1.0);
+    let x = in.uv.x * line.size.x + line.dash_offset;
+    let along = x - floor(x / period) * period;
+
+    // 1 inside a dash, 0 in a gap, with a 1px ramp at both ends
+    let dash = smoothstep(0.0, 1.0, along) * (1.0 - smoothstep(line.dash_length - 1.0, line.dash_length, along));
+    return vec4<f32>(line.color.rgb, line.color.a * dash);
+}
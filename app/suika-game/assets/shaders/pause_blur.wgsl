@@ -0,0 +1,44 @@
+// Blur / darken post-process drawn under menus (pause, game over).
+//
+// Gaussian-weighted 9x9 kernel stretched over `radius` pixels, then a
+// partial desaturate and darken.  Everything scales with `strength` so the
+// effect can fade in and out.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct PauseBlur {
+    strength: f32,
+    radius: f32,
+    darken: f32,
+    desaturate: f32,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: PauseBlur;
+
+const TAPS: i32 = 4;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let texel = 1.0 / vec2<f32>(textureDimensions(screen_texture));
+    let step = settings.radius * settings.strength / f32(TAPS) * texel;
+    let original = textureSample(screen_texture, texture_sampler, in.uv);
+
+    var sum = vec3<f32>(0.0);
+    var weight_sum = 0.0;
+    for (var x = -TAPS; x <= TAPS; x++) {
+        for (var y = -TAPS; y <= TAPS; y++) {
+            let offset = vec2<f32>(f32(x), f32(y));
+            let weight = exp(-dot(offset, offset) / f32(TAPS * TAPS));
+            sum += textureSample(screen_texture, texture_sampler, in.uv + offset * step).rgb * weight;
+            weight_sum += weight;
+        }
+    }
+
+    var color = sum / weight_sum;
+    let luma = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+    color = mix(color, vec3<f32>(luma), settings.desaturate * settings.strength);
+    color *= 1.0 - settings.darken * settings.strength;
+    return vec4<f32>(color, original.a);
+}
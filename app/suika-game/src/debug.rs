@@ -28,6 +28,13 @@ use bevy::prelude::*;
 /// - Inspect entities and their components
 /// - Modify resource values at runtime
 /// - View game state in real-time
+/// - Show the performance overlay via the `PerfOverlayVisible` resource
+///   (also toggled with F2 in any build)
+/// - Show the spawn-probability overlay via the `SpawnOverlayVisible`
+///   resource (also toggled with F4 in any build)
+/// - Show the merge heatmap over the container with F5 (debug builds)
+/// - Hover a fruit to see its type, velocity, sleep state and entity id
+///   (debug builds)
 pub struct DebugPlugin;
 
 impl Plugin for DebugPlugin {
@@ -1,4 +1,6 @@
 mod debug;
+#[cfg(feature = "steam")]
+mod steam;
 
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
@@ -10,7 +12,22 @@ use suika_game_core::prelude::*;
 use suika_game_ui::GameUIPlugin;
 
 fn main() {
-    App::new()
+    let launch = match LaunchOptions::from_env() {
+        Ok(launch) => launch,
+        Err(e) => {
+            eprintln!("suika-game: {e}");
+            eprintln!(
+                "usage: suika-game [--config-dir DIR] [--save-dir DIR] [--mods-dir DIR] [--seed N] [--mode classic|no-combo]"
+            );
+            std::process::exit(2);
+        }
+    };
+
+    let mut app = App::new();
+    // Asset sources must exist before AssetPlugin (in DefaultPlugins) is built
+    launch.register_asset_sources(&mut app);
+
+    app.insert_resource(launch)
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "スイカゲーム".to_string(),
@@ -18,13 +35,92 @@ fn main() {
                 ..default()
             }),
             ..default()
-        }))
-        .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0))
+        }));
+    add_game_plugins(&mut app);
+    app.add_plugins(DebugPlugin).run();
+}
+
+/// Adds the game's plugins on top of the engine plugins already in `app`.
+///
+/// Every gameplay system is registered by these plugins; `main` adds
+/// nothing else but the window and the debug tools.  The registration test
+/// builds its app through this function so it sees what ships.
+fn add_game_plugins(app: &mut App) {
+    app.add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0))
         .add_plugins(GameAssetsPlugin)
         .add_plugins(GameConfigPlugin)
-        .add_plugins(GameCorePlugin)
+        .add_plugins(GameCorePlugin::default());
+
+    // Store integration; without the `steam` feature this is a no-op platform
+    #[cfg(feature = "steam")]
+    app.insert_resource(steam::platform());
+
+    // Rules scripts shipped by user mods
+    #[cfg(feature = "scripting")]
+    app.add_plugins(suika_game_core::scripting::ScriptingPlugin);
+
+    app.add_plugins(PlatformPlugin)
+        .add_plugins(GameDiagnosticsPlugin::default())
         .add_plugins(GameUIPlugin)
-        .add_plugins(GameAudioPlugin)
-        .add_plugins(DebugPlugin)
-        .run();
+        .add_plugins(GameAudioPlugin);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::render::RenderPlugin;
+    use bevy::render::settings::WgpuSettings;
+    use bevy::winit::WinitPlugin;
+
+    use super::*;
+
+    #[test]
+    fn test_input_systems_registered_once() {
+        // Same plugins as the game, without a window or GPU
+        let mut app = App::new();
+        app.add_plugins(
+            DefaultPlugins
+                .build()
+                .disable::<WinitPlugin>()
+                .set(WindowPlugin {
+                    primary_window: None,
+                    ..default()
+                })
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }
+                    .into(),
+                    ..default()
+                }),
+        );
+        add_game_plugins(&mut app);
+
+        let mut update = app
+            .world_mut()
+            .resource_mut::<Schedules>()
+            .remove(Update)
+            .expect("Update schedule should exist");
+        update
+            .initialize(app.world_mut())
+            .expect("Update schedule should build");
+        let names: Vec<String> = update
+            .systems()
+            .expect("schedule is initialized")
+            .map(|(_, system)| system.name().to_string())
+            .collect();
+
+        for system in [
+            "update_spawn_position",
+            "handle_fruit_drop_input",
+            "detect_fruit_landing",
+            "spawn_held_fruit",
+        ] {
+            let count = names
+                .iter()
+                .filter(|name| name.ends_with(&format!("::{system}")))
+                .count();
+            assert_eq!(count, 1, "{system} should be registered exactly once");
+        }
+    }
 }
@@ -0,0 +1,127 @@
+//! Steamworks implementation of [`PlatformServices`]
+//!
+//! Only compiled with `--features steam`.  Steam must be running and the
+//! app id must be available (`steam_appid.txt` next to the executable
+//! during development); otherwise the game logs a warning and runs with
+//! [`NullPlatform`].
+//!
+//! - Achievements are set by API name and stored immediately.
+//! - The files in [`CLOUD_SAVE_FILES`] are mirrored to Steam Cloud: pulled at
+//!   startup when the cloud copy is newer than the local file, pushed at the
+//!   save points chosen by [`PlatformPlugin`](suika_game_core::platform::PlatformPlugin).
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use bevy::prelude::*;
+use steamworks::Client;
+use suika_game_core::platform::{
+    CLOUD_SAVE_FILES, NullPlatform, Platform, PlatformError, PlatformServices,
+};
+
+/// Steam-backed platform services.
+pub struct SteamPlatform {
+    client: Client,
+}
+
+impl SteamPlatform {
+    /// Connects to the running Steam client.
+    pub fn init() -> Result<Self, PlatformError> {
+        Client::init()
+            .map(|client| Self { client })
+            .map_err(|e| PlatformError(format!("Steam init failed: {e}")))
+    }
+
+    fn cloud_enabled(&self) -> bool {
+        let storage = self.client.remote_storage();
+        storage.is_cloud_enabled_for_account() && storage.is_cloud_enabled_for_app()
+    }
+}
+
+impl PlatformServices for SteamPlatform {
+    fn name(&self) -> &'static str {
+        "steam"
+    }
+
+    fn update(&mut self) {
+        self.client.run_callbacks();
+    }
+
+    fn unlock_achievement(&mut self, id: &str) -> Result<(), PlatformError> {
+        let stats = self.client.user_stats();
+        stats
+            .achievement(id)
+            .set()
+            .map_err(|_| PlatformError(format!("unknown achievement '{id}'")))?;
+        stats
+            .store_stats()
+            .map_err(|_| PlatformError("storing stats failed".to_string()))
+    }
+
+    fn pull_saves(&mut self, save_dir: &Path) -> Result<usize, PlatformError> {
+        if !self.cloud_enabled() {
+            return Ok(0);
+        }
+        let storage = self.client.remote_storage();
+        let mut pulled = 0;
+        for name in CLOUD_SAVE_FILES {
+            let file = storage.file(name);
+            if !file.exists() {
+                continue;
+            }
+            let local = save_dir.join(name);
+            if local_timestamp(&local).is_some_and(|t| t >= file.timestamp()) {
+                continue;
+            }
+            let mut bytes = Vec::new();
+            file.read()
+                .read_to_end(&mut bytes)
+                .map_err(|e| PlatformError(format!("reading cloud {name}: {e}")))?;
+            fs::create_dir_all(save_dir)
+                .and_then(|_| fs::write(&local, bytes))
+                .map_err(|e| PlatformError(format!("{}: {e}", local.display())))?;
+            pulled += 1;
+        }
+        Ok(pulled)
+    }
+
+    fn push_saves(&mut self, save_dir: &Path) -> Result<usize, PlatformError> {
+        if !self.cloud_enabled() {
+            return Ok(0);
+        }
+        let storage = self.client.remote_storage();
+        let mut pushed = 0;
+        for name in CLOUD_SAVE_FILES {
+            let Ok(bytes) = fs::read(save_dir.join(name)) else {
+                continue;
+            };
+            storage
+                .file(name)
+                .write()
+                .write_all(&bytes)
+                .map_err(|e| PlatformError(format!("writing cloud {name}: {e}")))?;
+            pushed += 1;
+        }
+        Ok(pushed)
+    }
+}
+
+/// Modification time of a local file in Unix seconds.
+fn local_timestamp(path: &Path) -> Option<i64> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    let secs = modified.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    i64::try_from(secs).ok()
+}
+
+/// Steam when available, [`NullPlatform`] otherwise.
+pub fn platform() -> Platform {
+    match SteamPlatform::init() {
+        Ok(steam) => Platform(Box::new(steam)),
+        Err(e) => {
+            warn!("{e}; achievements and cloud saves are disabled");
+            Platform(Box::new(NullPlatform))
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Spoken announcements for screen-reader and text-to-speech users.
+//!
+//! Important state changes are written as [`Announce`] messages and read
+//! aloud while [`SettingsResource::announcements`] is on:
+//!
+//! | Trigger                                 | Spoken text             |
+//! |-----------------------------------------|-------------------------|
+//! | score passes a multiple of [`SCORE_MILESTONE`] | "Score 3000"     |
+//! | the game-over warning starts            | "Warning! …"            |
+//! | game over                               | "Game over. Score 4210" |
+//! | a menu button gains keyboard focus      | the button's label      |
+//!
+//! The web build speaks through the browser's Web Speech API
+//! (`speechSynthesis`).  Native builds use the `tts` crate (SAPI / WinRT,
+//! AVFoundation, speech-dispatcher) when built with the `tts` feature;
+//! without it announcements are only logged.  A new announcement cuts off
+//! the one still being spoken, so the player always hears the latest state.
+
+use bevy::prelude::*;
+use suika_game_core::prelude::{GameOverTimer, GameState};
+use suika_game_core::resources::settings::{Language, SettingsResource};
+
+use crate::components::MenuButton;
+use crate::i18n::t;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Score step between milestone announcements.
+pub const SCORE_MILESTONE: u32 = 1_000;
+
+// ---------------------------------------------------------------------------
+// Messages
+// ---------------------------------------------------------------------------
+
+/// Text to read aloud.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct Announce(pub String);
+
+// ---------------------------------------------------------------------------
+// Systems: sources
+// ---------------------------------------------------------------------------
+
+/// Announces the score each time it passes a multiple of
+/// [`SCORE_MILESTONE`].  Runs while playing.
+pub fn announce_score_milestones(
+    game_state: Res<GameState>,
+    settings: Res<SettingsResource>,
+    mut last_milestone: Local<u32>,
+    mut announcements: MessageWriter<Announce>,
+) {
+    let milestone = game_state.score / SCORE_MILESTONE;
+    // A new game starts again from zero
+    if milestone < *last_milestone {
+        *last_milestone = milestone;
+    }
+    if milestone > *last_milestone {
+        *last_milestone = milestone;
+        announcements.write(Announce(score_text(
+            milestone * SCORE_MILESTONE,
+            settings.language,
+        )));
+    }
+}
+
+/// Announces the game-over warning when it starts.  Runs while playing.
+pub fn announce_warning(
+    game_over_timer: Res<GameOverTimer>,
+    settings: Res<SettingsResource>,
+    mut was_warning: Local<bool>,
+    mut announcements: MessageWriter<Announce>,
+) {
+    if game_over_timer.is_warning && !*was_warning {
+        announcements.write(Announce(
+            t("announce_warning", settings.language).to_string(),
+        ));
+    }
+    *was_warning = game_over_timer.is_warning;
+}
+
+/// Announces the game over with the final score.
+pub fn announce_game_over(
+    game_state: Res<GameState>,
+    settings: Res<SettingsResource>,
+    mut announcements: MessageWriter<Announce>,
+) {
+    let lang = settings.language;
+    announcements.write(Announce(format!(
+        "{}. {}",
+        t("announce_game_over", lang),
+        score_text(game_state.score, lang)
+    )));
+}
+
+/// Reads out the label of a menu button when it gains the focus ring.
+pub fn announce_menu_focus(
+    focused: Query<&Children, (With<MenuButton>, Added<Outline>)>,
+    texts: Query<&Text>,
+    mut announcements: MessageWriter<Announce>,
+) {
+    for children in focused.iter() {
+        if let Some(label) = children.iter().find_map(|child| texts.get(child).ok()) {
+            announcements.write(Announce(label.0.clone()));
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems: output
+// ---------------------------------------------------------------------------
+
+/// Speaks the latest [`Announce`] of the frame, if announcements are on.
+///
+/// Earlier ones in the same frame are skipped: they would be cut off at once.
+pub fn speak_announcements(
+    mut announcements: MessageReader<Announce>,
+    settings: Res<SettingsResource>,
+    mut speaker: NonSendMut<Speaker>,
+) {
+    let Some(latest) = announcements.read().last() else {
+        return;
+    };
+    if settings.announcements {
+        speaker.speak(&latest.0, settings.language);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// "Score 3000" in `lang`.
+fn score_text(score: u32, lang: Language) -> String {
+    format!("{} {score}", t("score", lang))
+}
+
+// ---------------------------------------------------------------------------
+// Platform output
+// ---------------------------------------------------------------------------
+
+pub use platform::Speaker;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "tts"))]
+mod platform {
+    use bevy::log::{info, warn};
+    use suika_game_core::resources::settings::Language;
+
+    /// Native text-to-speech; `None` when no engine could be started.
+    ///
+    /// Non-send: some engines must stay on the thread that created them.
+    pub struct Speaker(Option<tts::Tts>);
+
+    impl Default for Speaker {
+        fn default() -> Self {
+            match tts::Tts::default() {
+                Ok(engine) => Self(Some(engine)),
+                Err(e) => {
+                    warn!("Text-to-speech unavailable: {e}");
+                    Self(None)
+                }
+            }
+        }
+    }
+
+    impl Speaker {
+        pub fn speak(&mut self, text: &str, _lang: Language) {
+            info!("Announce: {text}");
+            if let Some(engine) = self.0.as_mut()
+                && let Err(e) = engine.speak(text, true)
+            {
+                warn!("Text-to-speech failed: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "tts")))]
+mod platform {
+    use bevy::log::info;
+    use suika_game_core::resources::settings::Language;
+
+    /// Built without the `tts` feature: announcements are only logged.
+    #[derive(Default)]
+    pub struct Speaker;
+
+    impl Speaker {
+        pub fn speak(&mut self, text: &str, _lang: Language) {
+            info!("Announce: {text}");
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    use suika_game_core::resources::settings::Language;
+
+    /// The browser's `speechSynthesis`.
+    #[derive(Default)]
+    pub struct Speaker;
+
+    impl Speaker {
+        pub fn speak(&mut self, text: &str, lang: Language) {
+            let Some(synth) = web_sys::window().and_then(|w| w.speech_synthesis().ok()) else {
+                return;
+            };
+            let Ok(utterance) = web_sys::SpeechSynthesisUtterance::new_with_text(text) else {
+                return;
+            };
+            utterance.set_lang(match lang {
+                Language::Japanese => "ja-JP",
+                Language::English => "en-US",
+            });
+            synth.cancel();
+            synth.speak(&utterance);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn milestone_app(score: u32) -> App {
+        let mut app = App::new();
+        app.add_message::<Announce>()
+            .init_resource::<SettingsResource>()
+            .insert_resource(GameState { score, ..default() })
+            .add_systems(Update, announce_score_milestones);
+        app
+    }
+
+    fn spoken(app: &App) -> Vec<String> {
+        let messages = app.world().resource::<Messages<Announce>>();
+        messages
+            .iter_current_update_messages()
+            .map(|a| a.0.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_score_milestone_announced_once() {
+        let mut app = milestone_app(999);
+        app.update();
+        assert!(spoken(&app).is_empty());
+
+        app.world_mut().resource_mut::<GameState>().score = 2_150;
+        app.update();
+        assert_eq!(spoken(&app), vec!["スコア 2000".to_string()]);
+
+        app.update();
+        assert!(spoken(&app).is_empty());
+    }
+
+    #[test]
+    fn test_new_game_resets_milestones() {
+        let mut app = milestone_app(3_000);
+        app.update();
+        app.world_mut().resource_mut::<GameState>().score = 0;
+        app.update();
+        app.world_mut().resource_mut::<GameState>().score = 1_000;
+        app.update();
+        assert_eq!(spoken(&app), vec!["スコア 1000".to_string()]);
+    }
+}
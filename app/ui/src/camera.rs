@@ -3,9 +3,17 @@
 //! Spawns the single orthographic [`Camera2d`] used to render the game world.
 //! Registered by [`crate::GameUIPlugin`] at [`Startup`] so the camera is
 //! available from the very first frame, before any state transitions occur.
+//!
+//! Also keeps [`ContainerScreenRect`] — where the container appears on screen
+//! in UI coordinates — up to date, so HUD widgets can line up with the
+//! container walls instead of guessing pixel offsets, and enforces the
+//! smallest window the board supports ([`apply_min_window_size`]).
 
 use bevy::prelude::*;
-use suika_game_core::prelude::CameraShake;
+use bevy::window::PrimaryWindow;
+use suika_game_core::prelude::{CameraPunch, CameraShake, PhysicsParams};
+
+use crate::pause_blur::PauseBlur;
 
 /// Spawns the orthographic camera used to render the game world.
 ///
@@ -14,7 +22,9 @@ use suika_game_core::prelude::CameraShake;
 /// one world unit equals one logical pixel.
 ///
 /// A [`CameraShake`] component is attached so that the core shake system can
-/// apply trauma-based offsets to this camera when fruits merge.
+/// apply trauma-based offsets to this camera when fruits merge, a
+/// [`CameraPunch`] for the zoom punch of big merges, and a [`PauseBlur`] so
+/// menus can blur the board behind them.
 pub fn setup_camera(mut commands: Commands) {
     commands.spawn((
         Camera2d,
@@ -24,11 +34,112 @@ pub fn setup_camera(mut commands: Commands) {
             ..OrthographicProjection::default_2d()
         }),
         CameraShake::default(),
+        CameraPunch::default(),
+        PauseBlur::default(),
     ));
 
     info!("Camera initialized");
 }
 
+// ---------------------------------------------------------------------------
+// Container screen rect
+// ---------------------------------------------------------------------------
+
+/// The container's inner play area projected to UI coordinates.
+///
+/// Coordinates are logical UI pixels from the window's top-left corner (the
+/// same space as [`Node::left`] / [`Node::top`] on a full-screen root), so a
+/// widget can be placed next to a container wall directly.  Updated every
+/// frame by [`update_container_screen_rect`]; `rect` is `None` until both
+/// the camera viewport and the physics config are available.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ContainerScreenRect {
+    /// Inner play area (between the walls, bottom to container top).
+    pub rect: Option<Rect>,
+    /// Size of the UI viewport (logical UI pixels).
+    pub viewport: Vec2,
+}
+
+/// Projects the container from [`PhysicsConfig`](suika_game_core::prelude::PhysicsConfig)
+/// through the game camera into [`ContainerScreenRect`].
+///
+/// Uses the camera's rest position rather than its shaken one, so widgets
+/// aligned to the container do not jitter during camera shake.
+pub fn update_container_screen_rect(
+    camera_query: Query<(&Camera, &Transform), With<Camera2d>>,
+    physics: PhysicsParams,
+    ui_scale: Res<UiScale>,
+    mut screen_rect: ResMut<ContainerScreenRect>,
+) {
+    let Some(cfg) = physics.get() else {
+        return;
+    };
+    let Ok((camera, transform)) = camera_query.single() else {
+        return;
+    };
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return;
+    };
+
+    let rest = GlobalTransform::from(Transform {
+        translation: Vec3::new(0.0, 0.0, transform.translation.z),
+        ..*transform
+    });
+    let half = Vec2::new(cfg.container_width, cfg.container_height) / 2.0;
+    let (Ok(top_left), Ok(bottom_right)) = (
+        camera.world_to_viewport(&rest, half.with_x(-half.x).extend(0.0)),
+        camera.world_to_viewport(&rest, half.with_y(-half.y).extend(0.0)),
+    ) else {
+        return;
+    };
+
+    let scale = ui_scale.0.max(f32::EPSILON);
+    screen_rect.set_if_neq(ContainerScreenRect {
+        rect: Some(Rect::from_corners(top_left / scale, bottom_right / scale)),
+        viewport: viewport / scale,
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Minimum window size
+// ---------------------------------------------------------------------------
+
+/// Smallest supported window height (logical pixels).  Shorter windows
+/// would crop the container top, where the held fruit and the danger line
+/// sit.
+pub const MIN_WINDOW_HEIGHT: f32 = 480.0;
+
+/// Smallest window that still shows the whole container width, walls
+/// included, for a container `container_width` wide with walls
+/// `wall_thickness` thick.
+pub fn min_window_size(container_width: f32, wall_thickness: f32) -> Vec2 {
+    Vec2::new(container_width + 2.0 * wall_thickness, MIN_WINDOW_HEIGHT)
+}
+
+/// Keeps the primary window's minimum size at [`min_window_size`] for the
+/// current container, so it cannot be resized narrower than the board.
+///
+/// Below the width where the HUD fits beside the container the HUD switches
+/// to its compact layout instead ([`HudCompact`](crate::screens::hud::HudCompact)).
+pub fn apply_min_window_size(
+    physics: PhysicsParams,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Some(cfg) = physics.get() else {
+        return;
+    };
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    let min = min_window_size(cfg.container_width, cfg.wall_thickness);
+    let constraints = &window.resize_constraints;
+    if constraints.min_width != min.x || constraints.min_height != min.y {
+        let constraints = &mut window.resize_constraints;
+        constraints.min_width = min.x;
+        constraints.min_height = min.y;
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -37,6 +148,14 @@ pub fn setup_camera(mut commands: Commands) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_min_window_size_fits_container_and_walls() {
+        assert_eq!(
+            min_window_size(600.0, 20.0),
+            Vec2::new(640.0, MIN_WINDOW_HEIGHT)
+        );
+    }
+
     #[test]
     fn test_camera_setup() {
         let mut app = App::new();
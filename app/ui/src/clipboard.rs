@@ -0,0 +1,45 @@
+//! System clipboard access.
+//!
+//! Native builds use [`arboard`]; the web build hands the text to the
+//! browser's async clipboard API (`navigator.clipboard.writeText`), which
+//! only works in a secure context and in response to a user gesture — a
+//! button press qualifies.
+
+/// Puts `text` on the system clipboard.
+///
+/// On the web the write finishes asynchronously, so `Ok` only means the
+/// request was made.
+pub fn copy_text(text: &str) -> Result<(), String> {
+    platform::copy_text(text)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod platform {
+    use std::sync::Mutex;
+
+    /// Kept alive after copying: on X11 / Wayland the clipboard contents are
+    /// served by this process and vanish when the handle is dropped.
+    static CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+
+    pub fn copy_text(text: &str) -> Result<(), String> {
+        let mut clipboard = CLIPBOARD.lock().map_err(|e| e.to_string())?;
+        if clipboard.is_none() {
+            *clipboard = Some(arboard::Clipboard::new().map_err(|e| e.to_string())?);
+        }
+        clipboard
+            .as_mut()
+            .expect("clipboard initialised above")
+            .set_text(text)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    pub fn copy_text(text: &str) -> Result<(), String> {
+        let window = web_sys::window().ok_or("no browser window")?;
+        // The returned promise is not awaited; failures show up in the console
+        let _ = window.navigator().clipboard().write_text(text);
+        Ok(())
+    }
+}
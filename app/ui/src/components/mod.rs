@@ -0,0 +1,937 @@
+//! Reusable UI components and spawn helpers.
+//!
+//! Provides the [`MenuButton`] component, the [`ButtonAction`] enum, and
+//! helper functions for spawning styled buttons and text nodes so that every
+//! screen can build its layout from the same building blocks.  Dialogs are
+//! built with [`Modal`] (see [`modal`]).
+
+use bevy::app::AppExit;
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use suika_game_core::events::SaveErrorEvent;
+use suika_game_core::mods::ModList;
+use suika_game_core::persistence::{
+    ExportFormat, PendingSessionRestore, SaveDir, export_history, report_save_error, save_settings,
+};
+use suika_game_core::prelude::{
+    AppState, GameState, PointerOverUi, RetrySeed, ReturnTo, SandboxMode, ScreenTransition,
+    SessionStats,
+};
+use suika_game_core::profiles::{CurrentProfile, create_profile, list_profiles, save_selection};
+use suika_game_core::resources::settings::{
+    Language, SettingsResource, step_auto_drop, step_fps_cap, step_sfx_latency,
+};
+use suika_game_core::systems::safe_mode::SafeMode;
+
+use crate::clipboard::copy_text;
+use crate::focus::{ActiveFocusGroup, FocusCell, FocusGrid, FocusGroup, MenuInput};
+use crate::i18n::t;
+use crate::safe_mode::{PinPurpose, open_pin_prompt};
+use crate::screens::game_over::{BoardViewActive, result_snippet};
+use crate::styles::{
+    BUTTON_HOVER, BUTTON_NORMAL, BUTTON_PRESSED, FONT_JP, FONT_SIZE_MEDIUM, TEXT_COLOR,
+};
+use crate::toast::ShowToast;
+
+pub mod modal;
+
+pub use modal::{Modal, ModalDialog, close_modals};
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marker component attached to every interactive menu button.
+///
+/// Stores the [`ButtonAction`] that should be executed when the button is
+/// clicked so that a single interaction system can handle all button presses.
+#[derive(Component, Debug, Clone)]
+pub struct MenuButton {
+    /// The action triggered when this button is activated.
+    pub action: ButtonAction,
+}
+
+/// Sequential index used for keyboard navigation between [`MenuButton`]s.
+///
+/// Assign incrementing values (0, 1, 2, …) to buttons in top-to-bottom order.
+/// [`handle_keyboard_menu_navigation`] stores the focused button's index in
+/// [`KeyboardFocusIndex`]; buttons placed side by side also get a
+/// [`FocusCell`] so focus can move along rows.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ButtonIndex(pub usize);
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// Tracks which [`MenuButton`] currently has keyboard focus.
+///
+/// The value corresponds to the [`ButtonIndex`] of the focused button.
+/// Defaults to `0` (first button).
+#[derive(Resource, Debug, Default)]
+pub struct KeyboardFocusIndex(pub usize);
+
+/// Set while a text field is taking keyboard input.
+///
+/// [`handle_keyboard_menu_navigation`] ignores the keyboard meanwhile, so
+/// typing W / S or pressing Enter edits the field instead of the menu.
+#[derive(Resource, Debug, Default)]
+pub struct TextInputActive(pub bool);
+
+// ---------------------------------------------------------------------------
+// System params
+// ---------------------------------------------------------------------------
+
+/// Everything a [`ButtonAction`] may need to mutate when it fires.
+///
+/// Bundled into one [`SystemParam`] so the mouse and keyboard interaction
+/// systems stay within clippy's argument limit as new actions are added.
+#[derive(SystemParam)]
+pub struct ButtonActionContext<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    modals: Query<'w, 's, Entity, With<ModalDialog>>,
+    state: Res<'w, State<AppState>>,
+    next_state: ResMut<'w, NextState<AppState>>,
+    transition: ResMut<'w, ScreenTransition>,
+    return_to: ResMut<'w, ReturnTo>,
+    settings: ResMut<'w, SettingsResource>,
+    mods: Option<Res<'w, ModList>>,
+    pending_restore: ResMut<'w, PendingSessionRestore>,
+    board_view: ResMut<'w, BoardViewActive>,
+    retry_seed: ResMut<'w, RetrySeed>,
+    sandbox: ResMut<'w, SandboxMode>,
+    stats: Res<'w, SessionStats>,
+    game_state: Res<'w, GameState>,
+    save_dir: Res<'w, SaveDir>,
+    profile: ResMut<'w, CurrentProfile>,
+    safe_mode: Res<'w, SafeMode>,
+    save_errors: MessageWriter<'w, SaveErrorEvent>,
+    toasts: MessageWriter<'w, ShowToast>,
+    app_exit: MessageWriter<'w, AppExit>,
+}
+
+// ---------------------------------------------------------------------------
+// Enums
+// ---------------------------------------------------------------------------
+
+/// Identifies the intended behavior of a [`MenuButton`].
+///
+/// A single button-interaction system matches on this enum to perform the
+/// correct state transition or game action without coupling individual screens
+/// to the input handling logic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonAction {
+    /// Transition from Title to Playing — starts a fresh game.
+    StartGame,
+    /// Transition from Title to Playing — restores the saved unfinished session.
+    ContinueGame,
+    /// Transition from Title to Playing — starts a sandbox game.
+    StartSandbox,
+    /// Transition from GameOver back to Playing — restarts the game.
+    RetryGame,
+    /// Like [`RetryGame`](Self::RetryGame), replaying the finished game's
+    /// seed and fruit queue.
+    RetrySameSeed,
+    /// Transition from GameOver or Paused back to Title.
+    GoToTitle,
+    /// Transition from Paused back to Playing — resumes the current game.
+    ResumeGame,
+    /// Open the settings screen (Title / Paused → Settings).
+    OpenSettings,
+    /// Open the how-to-play screen (Title → HowToPlay).
+    OpenHowToPlay,
+    /// Switch to the previous player profile (Title screen).
+    PreviousProfile,
+    /// Switch to the next player profile (Title screen).
+    NextProfile,
+    /// Create a new player profile and switch to it (Title screen).
+    NewProfile,
+    /// Open the lifetime stats screen (Title → Stats).
+    OpenStats,
+    /// Return to the Title screen (HowToPlay / Stats → Title).
+    BackToTitle,
+    /// Leave the settings screen for the screen it was opened from
+    /// ([`ReturnTo`]).
+    CloseSettings,
+    /// Decrease BGM volume by 1 step (Settings screen).
+    BgmVolumeDown,
+    /// Increase BGM volume by 1 step (Settings screen).
+    BgmVolumeUp,
+    /// Decrease SFX volume by 1 step (Settings screen).
+    SfxVolumeDown,
+    /// Increase SFX volume by 1 step (Settings screen).
+    SfxVolumeUp,
+    /// Toggle visual effects on / off (Settings screen).
+    ToggleEffects,
+    /// Toggle the merge ghost assist on / off (Settings screen).
+    ToggleMergeGhost,
+    /// Toggle the landing preview assist on / off (Settings screen).
+    ToggleLandingPreview,
+    /// Toggle haptic feedback on / off (Settings screen).
+    ToggleHaptics,
+    /// Toggle vsync on / off (Settings screen).
+    ToggleVsync,
+    /// Lower the FPS cap by one step (Settings screen).
+    FpsCapDown,
+    /// Raise the FPS cap by one step, up to uncapped (Settings screen).
+    FpsCapUp,
+    /// Toggle the one-button sweep assist on / off (Settings screen).
+    ToggleSweepAssist,
+    /// Shorten the auto-drop delay by one step, down to off (Settings screen).
+    AutoDropDown,
+    /// Lengthen the auto-drop delay by one step (Settings screen).
+    AutoDropUp,
+    /// Toggle spoken announcements on / off (Settings screen).
+    ToggleAnnouncements,
+    /// Toggle full-width score digits on / off (Settings screen).
+    ToggleFullWidthDigits,
+    /// Toggle the ghost run against the highscore game (Settings screen).
+    ToggleGhostRun,
+    /// Toggle the BGM visualizer next to the score (Settings screen).
+    ToggleAudioVisualizer,
+    /// Toggle the on-screen touch control strip (Settings screen).
+    ToggleTouchControls,
+    /// Open the SFX delay calibration screen (Settings → AudioCalibration).
+    OpenAudioCalibration,
+    /// Decrease the SFX delay by one step (AudioCalibration screen).
+    SfxLatencyDown,
+    /// Increase the SFX delay by one step (AudioCalibration screen).
+    SfxLatencyUp,
+    /// Open the per-effect toggles (Settings → EffectsSettings).
+    OpenEffectsSettings,
+    /// Toggle water droplets on / off (EffectsSettings screen).
+    ToggleDropletEffects,
+    /// Toggle merge and screen flashes on / off (EffectsSettings screen).
+    ToggleFlashEffects,
+    /// Toggle camera shake and zoom punch on / off (EffectsSettings screen).
+    ToggleShakeEffects,
+    /// Return to the settings screen (AudioCalibration / EffectsSettings →
+    /// Settings).
+    BackToSettings,
+    /// Toggle streamer-safe mode (Settings screen).
+    ToggleStreamerSafe,
+    /// Choose a settings PIN, or remove the one set (Settings screen).
+    SettingsPin,
+    /// Switch the user mod at this index of [`ModList`] on / off for the next
+    /// launch (Settings screen).
+    ToggleMod(usize),
+    /// Toggle UI language between Japanese and English (Settings screen).
+    ToggleLanguage,
+    /// Write the highscore and game history to the profile's `exports/` as
+    /// JSON and CSV (Settings screen).
+    ExportHistory,
+    /// Copy a shareable result summary to the clipboard (GameOver screen).
+    CopyResult,
+    /// Hide the game-over panel to reveal the final board (GameOver screen).
+    ViewBoard,
+    /// Ask whether to exit the application (Title screen).
+    QuitGame,
+    /// Gracefully exit the application (quit confirmation dialog).
+    ConfirmQuit,
+    /// Close the open [`Modal`] dialog.
+    CloseModal,
+}
+
+// ---------------------------------------------------------------------------
+// Spawn helpers
+// ---------------------------------------------------------------------------
+
+/// Spawns a styled [`MenuButton`] as a child of `parent`.
+///
+/// The button is a Bevy [`Button`] node with a centered text label. The button
+/// at `index == 0` starts with [`BUTTON_HOVER`] color to indicate initial
+/// keyboard focus; all others start with [`BUTTON_NORMAL`].
+///
+/// # Arguments
+///
+/// * `parent`    – the [`ChildBuilder`] to attach the new node to
+/// * `text`      – label displayed inside the button
+/// * `action`    – [`ButtonAction`] fired when the button is clicked
+/// * `index`     – keyboard-navigation order (0 = first / top button)
+/// * `font_size` – text size in logical pixels (use `FONT_SIZE_*` constants)
+/// * `width`     – button width in logical pixels (use `BUTTON_*_WIDTH` constants)
+/// * `height`    – button height in logical pixels (use `BUTTON_*_HEIGHT` constants)
+/// * `font`      – font asset handle; pass `Handle::default()` to use Bevy's built-in font
+///
+/// Returns the button's [`EntityCommands`] so callers can add components such
+/// as a [`FocusCell`].
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_button<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    text: &str,
+    action: ButtonAction,
+    index: usize,
+    font_size: f32,
+    width: f32,
+    height: f32,
+    font: Handle<Font>,
+) -> EntityCommands<'a> {
+    let initial_color = if index == 0 {
+        BUTTON_HOVER
+    } else {
+        BUTTON_NORMAL
+    };
+
+    let mut button = parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(width),
+            height: Val::Px(height),
+            margin: UiRect::all(Val::Px(10.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(initial_color),
+        MenuButton { action },
+        ButtonIndex(index),
+    ));
+    button.with_children(|btn: &mut ChildSpawnerCommands| {
+        btn.spawn((
+            Text::new(text),
+            TextFont {
+                font: font.clone(),
+                font_size,
+                ..default()
+            },
+            TextColor(TEXT_COLOR),
+        ));
+    });
+    button
+}
+
+/// Spawns a plain text node as a child of `parent`.
+///
+/// Use this for labels, score readouts, and any other non-interactive text.
+///
+/// # Arguments
+///
+/// * `parent`    – the [`ChildBuilder`] to attach the new node to
+/// * `text`      – string to display
+/// * `font_size` – text size in logical pixels (use `FONT_SIZE_*` constants)
+/// * `color`     – [`Color`] applied to the text (use palette constants from [`crate::styles`])
+/// * `font`      – font asset handle; pass `Handle::default()` to use Bevy's built-in font
+pub fn spawn_text(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    font_size: f32,
+    color: Color,
+    font: Handle<Font>,
+) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font,
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+/// Spawns a default-sized [`MenuButton`] using [`FONT_SIZE_MEDIUM`] and the
+/// large button dimensions from [`crate::styles`].
+///
+/// Convenience wrapper around [`spawn_button`] for the most common case.
+pub fn spawn_menu_button(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    action: ButtonAction,
+    index: usize,
+    font: Handle<Font>,
+) {
+    use crate::styles::{BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH};
+    spawn_button(
+        parent,
+        text,
+        action,
+        index,
+        FONT_SIZE_MEDIUM,
+        BUTTON_LARGE_WIDTH,
+        BUTTON_LARGE_HEIGHT,
+        font,
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Handles mouse/touch interaction with [`MenuButton`] entities.
+///
+/// Changes the button background color on hover/press and triggers the
+/// appropriate [`AppState`] transition or settings mutation when a button is
+/// clicked.
+///
+/// When the mouse leaves a button (`Interaction::None`), the keyboard-focus
+/// highlight is preserved if that button is currently focused by
+/// [`KeyboardFocusIndex`].
+#[allow(clippy::type_complexity)]
+pub fn handle_button_interaction(
+    mut interaction_query: Query<
+        (
+            &Interaction,
+            &MenuButton,
+            &ButtonIndex,
+            Option<&FocusGroup>,
+            &mut BackgroundColor,
+        ),
+        Changed<Interaction>,
+    >,
+    focus: Res<KeyboardFocusIndex>,
+    active_group: Res<ActiveFocusGroup>,
+    mut ctx: ButtonActionContext,
+) {
+    for (interaction, button, idx, group, mut bg) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg = BackgroundColor(BUTTON_PRESSED);
+                apply_button_action(button.action, &mut ctx);
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(BUTTON_HOVER);
+            }
+            Interaction::None => {
+                // Preserve keyboard-focus highlight when the mouse leaves.
+                *bg = BackgroundColor(if has_focus(*idx, group, &focus, &active_group) {
+                    BUTTON_HOVER
+                } else {
+                    BUTTON_NORMAL
+                });
+            }
+        }
+    }
+}
+
+/// Keeps core's [`PointerOverUi`] in step with the UI: `true` while the
+/// mouse or a finger is over (or pressing) any interactive node.
+///
+/// Runs in `PreUpdate` between Bevy's UI focus pass and
+/// `collect_input_actions`, so a click on a button is known to belong to
+/// the UI before it could count as a drop.
+pub fn update_pointer_over_ui(
+    interactions: Query<&Interaction>,
+    mut pointer_over_ui: ResMut<PointerOverUi>,
+) {
+    let over = interactions
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+    pointer_over_ui.set_if_neq(PointerOverUi(over));
+}
+
+/// Moves keyboard focus between the [`MenuButton`]s of the
+/// [`ActiveFocusGroup`] and confirms the focused button with Enter or a
+/// gamepad's south button.
+///
+/// W / S / Up / Down move between rows and A / D / Left / Right within a row,
+/// wrapping around at the ends; the gamepad D-pad and left stick do the same.
+/// See [`crate::focus`] for how buttons are laid out on the grid.
+///
+/// Updates [`KeyboardFocusIndex`] and reflects the change immediately by
+/// recoloring the group's buttons: the focused one gets [`BUTTON_HOVER`], the
+/// rest get [`BUTTON_NORMAL`].
+///
+/// Does nothing while [`TextInputActive`] is set.
+#[allow(clippy::type_complexity)]
+pub fn handle_keyboard_menu_navigation(
+    mut input: MenuInput,
+    text_input: Res<TextInputActive>,
+    active_group: Res<ActiveFocusGroup>,
+    mut focus: ResMut<KeyboardFocusIndex>,
+    mut button_query: Query<(
+        &ButtonIndex,
+        &MenuButton,
+        Option<&FocusCell>,
+        Option<&FocusGroup>,
+        &mut BackgroundColor,
+    )>,
+    mut ctx: ButtonActionContext,
+) {
+    let in_group =
+        |group: Option<&FocusGroup>| group.copied().unwrap_or_default() == active_group.0;
+    let grid = FocusGrid::new(
+        button_query
+            .iter()
+            .filter(|(_, _, _, group, _)| in_group(*group))
+            .map(|(idx, _, cell, _, _)| (idx.0, cell.copied())),
+    );
+    let direction = input.direction();
+    if grid.is_empty() || text_input.0 {
+        return;
+    }
+
+    if let Some(direction) = direction {
+        let next = grid.navigate(focus.0, direction);
+        if next != focus.0 {
+            focus.0 = next;
+            for (idx, _, _, group, mut bg) in button_query.iter_mut() {
+                if in_group(group) {
+                    *bg = BackgroundColor(if idx.0 == focus.0 {
+                        BUTTON_HOVER
+                    } else {
+                        BUTTON_NORMAL
+                    });
+                }
+            }
+        }
+    }
+
+    if input.confirm()
+        && let Some((_, button, _, _, _)) = button_query
+            .iter()
+            .find(|(idx, _, _, group, _)| idx.0 == focus.0 && in_group(*group))
+    {
+        let action = button.action;
+        apply_button_action(action, &mut ctx);
+    }
+}
+
+/// Returns `true` if the button with `idx` in `group` has keyboard focus.
+fn has_focus(
+    idx: ButtonIndex,
+    group: Option<&FocusGroup>,
+    focus: &KeyboardFocusIndex,
+    active_group: &ActiveFocusGroup,
+) -> bool {
+    idx.0 == focus.0 && group.copied().unwrap_or_default() == active_group.0
+}
+
+// ---------------------------------------------------------------------------
+// Shared helper
+// ---------------------------------------------------------------------------
+
+/// Applies the effect of a [`ButtonAction`].
+///
+/// Extracted so that both the mouse-click and keyboard-Enter paths share the
+/// same logic without duplication.
+///
+/// Screen changes play a [`ScreenTransition`]; only resuming from the pause
+/// overlay switches state instantly.  Actions are ignored while a transition
+/// is running so a second press cannot start another one.
+fn apply_button_action(action: ButtonAction, ctx: &mut ButtonActionContext) {
+    let ButtonActionContext {
+        commands,
+        asset_server,
+        modals,
+        state,
+        next_state,
+        transition,
+        return_to,
+        settings,
+        mods,
+        pending_restore,
+        board_view,
+        retry_seed,
+        sandbox,
+        stats,
+        game_state,
+        save_dir,
+        profile,
+        safe_mode,
+        save_errors,
+        toasts,
+        app_exit,
+    } = ctx;
+
+    if !transition.is_idle() {
+        return;
+    }
+
+    match action {
+        ButtonAction::StartGame | ButtonAction::RetryGame => {
+            pending_restore.0 = false;
+            transition.start(AppState::Playing);
+        }
+        ButtonAction::StartSandbox => {
+            pending_restore.0 = false;
+            **sandbox = SandboxMode::started();
+            transition.start(AppState::Playing);
+        }
+        ButtonAction::RetrySameSeed => {
+            pending_restore.0 = false;
+            retry_seed.0 = stats.seed_code;
+            transition.start(AppState::Playing);
+        }
+        ButtonAction::ContinueGame => {
+            pending_restore.0 = true;
+            transition.start(AppState::Playing);
+        }
+        ButtonAction::GoToTitle | ButtonAction::BackToTitle => {
+            transition.start(AppState::Title);
+        }
+        ButtonAction::ResumeGame => {
+            next_state.set(AppState::Playing);
+        }
+        ButtonAction::OpenSettings if settings.settings_locked() => {
+            open_pin_prompt(
+                commands,
+                PinPurpose::Unlock,
+                settings.language,
+                asset_server.load(FONT_JP),
+            );
+        }
+        ButtonAction::OpenSettings => {
+            return_to.0 = *state.get();
+            transition.start(AppState::Settings);
+        }
+        ButtonAction::CloseSettings => {
+            transition.start(return_to.take());
+        }
+        ButtonAction::OpenHowToPlay => {
+            transition.start(AppState::HowToPlay);
+        }
+        ButtonAction::OpenStats => {
+            transition.start(AppState::Stats);
+        }
+        ButtonAction::PreviousProfile | ButtonAction::NextProfile => {
+            let Some(root) = save_dir.path() else {
+                return;
+            };
+            let names = list_profiles(root);
+            let current = names.iter().position(|n| *n == profile.name).unwrap_or(0);
+            let next = if action == ButtonAction::NextProfile {
+                (current + 1) % names.len()
+            } else {
+                (current + names.len() - 1) % names.len()
+            };
+            select_profile(&names[next], root, profile, transition, save_errors);
+        }
+        ButtonAction::NewProfile => {
+            let Some(root) = save_dir.path() else {
+                return;
+            };
+            match create_profile(root) {
+                Ok(name) => select_profile(&name, root, profile, transition, save_errors),
+                Err(e) => report_save_error("Failed to create profile", &e, save_errors),
+            }
+        }
+        ButtonAction::BgmVolumeDown => {
+            settings.bgm_volume = settings.bgm_volume.saturating_sub(1);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::BgmVolumeUp => {
+            settings.bgm_volume = (settings.bgm_volume + 1).min(10);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::SfxVolumeDown => {
+            settings.sfx_volume = settings.sfx_volume.saturating_sub(1);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::SfxVolumeUp => {
+            settings.sfx_volume = (settings.sfx_volume + 1).min(10);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleEffects => {
+            settings.effects_enabled = !settings.effects_enabled;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleMergeGhost => {
+            settings.merge_ghost = !settings.merge_ghost;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleLandingPreview => {
+            settings.landing_preview = !settings.landing_preview;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleHaptics => {
+            settings.haptics_enabled = !settings.haptics_enabled;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleVsync => {
+            settings.vsync = !settings.vsync;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::FpsCapDown => {
+            settings.fps_cap = step_fps_cap(settings.fps_cap, false);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::FpsCapUp => {
+            settings.fps_cap = step_fps_cap(settings.fps_cap, true);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleSweepAssist => {
+            settings.sweep_assist = !settings.sweep_assist;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::AutoDropDown => {
+            settings.auto_drop_secs = step_auto_drop(settings.auto_drop_secs, false);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::AutoDropUp => {
+            settings.auto_drop_secs = step_auto_drop(settings.auto_drop_secs, true);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleAnnouncements => {
+            settings.announcements = !settings.announcements;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleFullWidthDigits => {
+            settings.full_width_digits = !settings.full_width_digits;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleGhostRun => {
+            settings.ghost_run = !settings.ghost_run;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleAudioVisualizer => {
+            settings.audio_visualizer = !settings.audio_visualizer;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleTouchControls => {
+            settings.touch_controls = !settings.touch_controls;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::OpenAudioCalibration => {
+            transition.start(AppState::AudioCalibration);
+        }
+        ButtonAction::SfxLatencyDown => {
+            settings.sfx_latency_ms = step_sfx_latency(settings.sfx_latency_ms, false);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::SfxLatencyUp => {
+            settings.sfx_latency_ms = step_sfx_latency(settings.sfx_latency_ms, true);
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::OpenEffectsSettings => {
+            transition.start(AppState::EffectsSettings);
+        }
+        ButtonAction::ToggleDropletEffects => {
+            settings.droplet_effects = !settings.droplet_effects;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleFlashEffects => {
+            settings.flash_effects = !settings.flash_effects;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleShakeEffects => {
+            settings.shake_effects = !settings.shake_effects;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::BackToSettings => {
+            transition.start(AppState::Settings);
+        }
+        ButtonAction::ToggleStreamerSafe => {
+            settings.streamer_safe = !settings.streamer_safe;
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::SettingsPin if settings.settings_pin.is_empty() => {
+            open_pin_prompt(
+                commands,
+                PinPurpose::Set,
+                settings.language,
+                asset_server.load(FONT_JP),
+            );
+        }
+        ButtonAction::SettingsPin => {
+            settings.settings_pin.clear();
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ToggleMod(index) => {
+            if let Some(info) = mods.as_ref().and_then(|m| m.mods.get(index)) {
+                settings.toggle_mod(&info.name);
+                persist_settings(settings, profile, save_errors);
+            }
+        }
+        ButtonAction::ToggleLanguage => {
+            settings.language = match settings.language {
+                Language::Japanese => Language::English,
+                Language::English => Language::Japanese,
+            };
+            persist_settings(settings, profile, save_errors);
+        }
+        ButtonAction::ExportHistory => {
+            export_history_files(
+                profile,
+                safe_mode.active,
+                settings.language,
+                save_errors,
+                toasts,
+            );
+        }
+        ButtonAction::CopyResult => {
+            let lang = settings.language;
+            match copy_text(&result_snippet(game_state, stats, safe_mode, lang)) {
+                Ok(()) => {
+                    toasts.write(ShowToast::info(t("result_copied", lang)));
+                }
+                Err(e) => {
+                    warn!("Failed to copy result: {e}");
+                    toasts.write(ShowToast::error(format!(
+                        "{}: {e}",
+                        t("result_copy_failed", lang)
+                    )));
+                }
+            }
+        }
+        ButtonAction::ViewBoard => {
+            board_view.0 = true;
+        }
+        ButtonAction::QuitGame => {
+            let lang = settings.language;
+            Modal::new(t("quit_confirm_title", lang))
+                .message(t("quit_confirm_message", lang))
+                .button(t("btn_cancel", lang), ButtonAction::CloseModal)
+                .button(t("btn_quit", lang), ButtonAction::ConfirmQuit)
+                .spawn(commands, asset_server.load(FONT_JP));
+        }
+        ButtonAction::ConfirmQuit => {
+            app_exit.write(AppExit::Success);
+        }
+        ButtonAction::CloseModal => {
+            close_modals(commands, modals);
+        }
+    }
+}
+
+/// Makes `name` the [`CurrentProfile`] and remembers it for the next launch.
+///
+/// Core reloads the profile's save data when the resource changes; the
+/// title screen is entered again so it shows the new profile's settings,
+/// best score and "Continue" option.
+fn select_profile(
+    name: &str,
+    root: &Path,
+    profile: &mut CurrentProfile,
+    transition: &mut ScreenTransition,
+    errors: &mut MessageWriter<SaveErrorEvent>,
+) {
+    if profile.name == name {
+        return;
+    }
+    *profile = CurrentProfile::new(name, Some(root));
+    if let Err(e) = save_selection(name, root) {
+        report_save_error("Failed to save profile selection", &e, errors);
+    }
+    info!("Switched to profile '{name}'");
+    transition.start(AppState::Title);
+}
+
+/// Saves the current settings to the profile's directory, reporting a
+/// failure as a [`SaveErrorEvent`].
+///
+/// Does nothing when persistence is disabled ([`CurrentProfile`] has no
+/// directory).
+pub(crate) fn persist_settings(
+    settings: &SettingsResource,
+    profile: &CurrentProfile,
+    errors: &mut MessageWriter<SaveErrorEvent>,
+) {
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    if let Err(e) = save_settings(settings, dir) {
+        report_save_error("Failed to save settings", &e, errors);
+    }
+}
+
+/// Exports the history in every [`ExportFormat`] and toasts where it went.
+///
+/// `hide_path` (streamer-safe mode) leaves the directory, which may
+/// contain the user name, out of the toast.  Failures are reported like any
+/// other save error.
+fn export_history_files(
+    profile: &CurrentProfile,
+    hide_path: bool,
+    lang: Language,
+    errors: &mut MessageWriter<SaveErrorEvent>,
+    toasts: &mut MessageWriter<ShowToast>,
+) {
+    let Some(dir) = profile.path() else {
+        return;
+    };
+    let exported_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    for format in ExportFormat::ALL {
+        if let Err(e) = export_history(dir, format, exported_at) {
+            report_save_error("Failed to export history", &e, errors);
+            return;
+        }
+    }
+    let exports = dir.join("exports");
+    info!("History exported to {}", exports.display());
+    let message = if hide_path {
+        t("history_exported_private", lang).to_string()
+    } else {
+        format!("{} {}", t("history_exported", lang), exports.display())
+    };
+    toasts.write(ShowToast::info(message));
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_button_action_equality() {
+        assert_eq!(ButtonAction::StartGame, ButtonAction::StartGame);
+        assert_ne!(ButtonAction::StartGame, ButtonAction::RetryGame);
+        assert_ne!(ButtonAction::RetryGame, ButtonAction::RetrySameSeed);
+        assert_ne!(ButtonAction::StartGame, ButtonAction::ContinueGame);
+        assert_ne!(ButtonAction::StartGame, ButtonAction::StartSandbox);
+        assert_ne!(ButtonAction::GoToTitle, ButtonAction::ResumeGame);
+        assert_ne!(ButtonAction::OpenSettings, ButtonAction::StartGame);
+        assert_ne!(ButtonAction::OpenHowToPlay, ButtonAction::OpenSettings);
+        assert_ne!(ButtonAction::OpenStats, ButtonAction::OpenHowToPlay);
+        assert_ne!(ButtonAction::BgmVolumeDown, ButtonAction::BgmVolumeUp);
+        assert_ne!(ButtonAction::SfxVolumeDown, ButtonAction::SfxVolumeUp);
+        assert_ne!(ButtonAction::ToggleEffects, ButtonAction::ToggleLanguage);
+        assert_ne!(ButtonAction::PreviousProfile, ButtonAction::NextProfile);
+        assert_ne!(ButtonAction::ToggleStreamerSafe, ButtonAction::SettingsPin);
+        assert_ne!(
+            ButtonAction::ToggleGhostRun,
+            ButtonAction::ToggleStreamerSafe
+        );
+    }
+
+    #[test]
+    fn test_button_action_all_variants_copy() {
+        // ButtonAction is Copy — verify copying produces an equal value.
+        let a = ButtonAction::RetryGame;
+        let b = a;
+        assert_eq!(a, b);
+
+        let c = ButtonAction::BgmVolumeDown;
+        let d = c;
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn test_menu_button_stores_action() {
+        let btn = MenuButton {
+            action: ButtonAction::StartGame,
+        };
+        assert_eq!(btn.action, ButtonAction::StartGame);
+    }
+
+    #[test]
+    fn test_menu_button_clone() {
+        let original = MenuButton {
+            action: ButtonAction::GoToTitle,
+        };
+        let cloned = original.clone();
+        assert_eq!(original.action, cloned.action);
+    }
+
+    #[test]
+    fn test_button_index_copy() {
+        let a = ButtonIndex(2);
+        let b = a;
+        assert_eq!(a.0, b.0);
+    }
+
+    #[test]
+    fn test_keyboard_focus_index_default() {
+        let focus = KeyboardFocusIndex::default();
+        assert_eq!(focus.0, 0);
+    }
+}
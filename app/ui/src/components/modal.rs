@@ -0,0 +1,335 @@
+//! Modal dialog widget.
+//!
+//! A [`Modal`] is a centred panel with a title, an optional message and a
+//! row of [`MenuButton`](super::MenuButton)s, drawn over a full-screen overlay that swallows
+//! mouse input for everything behind it.  While a dialog is open keyboard
+//! and gamepad focus stay inside its buttons ([`MODAL_FOCUS_GROUP`]); the
+//! focus the screen had is restored when the dialog closes.
+//!
+//! ```text
+//! ┌──────────────────────────────── overlay ┐
+//! │        ┌───────────────────────┐        │
+//! │        │      Quit game?       │        │
+//! │        │  Unsaved progress …   │        │
+//! │        │  [ Cancel ] [ Quit ]  │        │
+//! │        └───────────────────────┘        │
+//! └─────────────────────────────────────────┘
+//! ```
+//!
+//! # Usage
+//!
+//! ```ignore
+//! Modal::new(t("quit_confirm_title", lang))
+//!     .message(t("quit_confirm_message", lang))
+//!     .button(t("btn_cancel", lang), ButtonAction::CloseModal)
+//!     .button(t("btn_quit", lang), ButtonAction::ConfirmQuit)
+//!     .spawn(&mut commands, font);
+//! ```
+//!
+//! [`ButtonAction::CloseModal`] closes the dialog; any other action runs as
+//! usual and the dialog goes away with the screen it was opened on.  Only one
+//! dialog is shown at a time: opening a new one replaces the old one.
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use super::{ButtonAction, KeyboardFocusIndex, spawn_button};
+use crate::focus::{ActiveFocusGroup, FocusCell, FocusGroup};
+use crate::styles::{
+    BG_COLOR, BUTTON_MEDIUM_HEIGHT, BUTTON_MEDIUM_WIDTH, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM,
+    FONT_SIZE_SMALL, PRIMARY_COLOR, TEXT_COLOR,
+};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Focus group of the buttons inside a dialog.
+pub const MODAL_FOCUS_GROUP: FocusGroup = FocusGroup(1);
+
+/// Dims the screen behind a dialog.
+const OVERLAY_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.55);
+
+/// Widest a dialog panel grows before its message wraps (logical pixels).
+const PANEL_MAX_WIDTH: f32 = 640.0;
+
+// ---------------------------------------------------------------------------
+// Components / resources
+// ---------------------------------------------------------------------------
+
+/// Marks the overlay entity of an open dialog.
+#[derive(Component, Debug)]
+pub struct ModalDialog;
+
+/// Screen focus saved while a dialog is open.
+#[derive(Resource, Debug, Default)]
+pub struct ModalFocus {
+    /// [`KeyboardFocusIndex`] of the screen when the dialog opened, `None`
+    /// while no dialog is open.
+    saved: Option<usize>,
+}
+
+// ---------------------------------------------------------------------------
+// Builder
+// ---------------------------------------------------------------------------
+
+/// Builder for a modal dialog.
+#[derive(Debug, Clone, Default)]
+pub struct Modal {
+    title: String,
+    message: Option<String>,
+    buttons: Vec<(String, ButtonAction)>,
+}
+
+impl Modal {
+    /// Dialog with `title` and no buttons yet.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..default()
+        }
+    }
+
+    /// Sets the text shown under the title.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Adds a button after the existing ones; the first button gets the
+    /// initial focus.
+    pub fn button(mut self, label: impl Into<String>, action: ButtonAction) -> Self {
+        self.buttons.push((label.into(), action));
+        self
+    }
+
+    /// Spawns the dialog, replacing any dialog that is already open, and
+    /// returns its overlay entity.
+    pub fn spawn(self, commands: &mut Commands, font: Handle<Font>) -> Entity {
+        commands.queue(|world: &mut World| {
+            let open: Vec<Entity> = world
+                .query_filtered::<Entity, With<ModalDialog>>()
+                .iter(world)
+                .collect();
+            if open.is_empty() {
+                return;
+            }
+            for entity in open {
+                world.despawn(entity);
+            }
+            // Focus the new dialog's first button.
+            if let Some(mut focus) = world.get_resource_mut::<KeyboardFocusIndex>() {
+                focus.0 = 0;
+            }
+        });
+
+        commands
+            .spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(OVERLAY_COLOR),
+                FocusPolicy::Block,
+                GlobalZIndex(i32::MAX - 2),
+                ModalDialog,
+            ))
+            .with_children(|overlay| {
+                overlay
+                    .spawn((
+                        Node {
+                            max_width: Val::Px(PANEL_MAX_WIDTH),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(24.0)),
+                            row_gap: Val::Px(12.0),
+                            ..default()
+                        },
+                        BackgroundColor(BG_COLOR),
+                        BorderRadius::all(Val::Px(12.0)),
+                    ))
+                    .with_children(|panel| {
+                        panel.spawn((
+                            Text::new(self.title),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: FONT_SIZE_LARGE,
+                                ..default()
+                            },
+                            TextColor(PRIMARY_COLOR),
+                        ));
+                        if let Some(message) = self.message {
+                            panel.spawn((
+                                Text::new(message),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: FONT_SIZE_SMALL,
+                                    ..default()
+                                },
+                                TextColor(TEXT_COLOR),
+                                TextLayout::new_with_justify(Justify::Center),
+                            ));
+                        }
+                        panel
+                            .spawn(Node {
+                                flex_direction: FlexDirection::Row,
+                                ..default()
+                            })
+                            .with_children(|row| {
+                                for (col, (label, action)) in self.buttons.into_iter().enumerate() {
+                                    spawn_button(
+                                        row,
+                                        &label,
+                                        action,
+                                        col,
+                                        FONT_SIZE_MEDIUM,
+                                        BUTTON_MEDIUM_WIDTH,
+                                        BUTTON_MEDIUM_HEIGHT,
+                                        font.clone(),
+                                    )
+                                    .insert((MODAL_FOCUS_GROUP, FocusCell::new(0, col)));
+                                }
+                            });
+                    });
+            })
+            .id()
+    }
+}
+
+/// Despawns every open dialog.
+pub fn close_modals(commands: &mut Commands, modals: &Query<Entity, With<ModalDialog>>) {
+    for modal in modals.iter() {
+        commands.entity(modal).despawn();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Moves keyboard focus into a dialog when one opens and back to the screen
+/// once no dialog is left.
+///
+/// Runs before [`handle_keyboard_menu_navigation`] so the dialog's buttons
+/// respond from the first frame they are visible.
+///
+/// [`handle_keyboard_menu_navigation`]: super::handle_keyboard_menu_navigation
+pub fn sync_modal_focus(
+    modals: Query<(), With<ModalDialog>>,
+    mut modal_focus: ResMut<ModalFocus>,
+    mut active_group: ResMut<ActiveFocusGroup>,
+    mut focus: ResMut<KeyboardFocusIndex>,
+) {
+    let open = !modals.is_empty();
+    match (open, modal_focus.saved) {
+        (true, None) => {
+            modal_focus.saved = Some(focus.0);
+            active_group.0 = MODAL_FOCUS_GROUP;
+            focus.0 = 0;
+        }
+        (false, Some(saved)) => {
+            modal_focus.saved = None;
+            active_group.0 = FocusGroup::SCREEN;
+            focus.0 = saved;
+        }
+        _ => {}
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{ButtonIndex, MenuButton};
+
+    fn modal_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<KeyboardFocusIndex>()
+            .init_resource::<ActiveFocusGroup>()
+            .init_resource::<ModalFocus>()
+            .add_systems(Update, sync_modal_focus);
+        app
+    }
+
+    fn spawn_modal(app: &mut App) -> Entity {
+        let modal = {
+            let mut commands = app.world_mut().commands();
+            Modal::new("Quit?")
+                .message("Really?")
+                .button("Cancel", ButtonAction::CloseModal)
+                .button("Quit", ButtonAction::ConfirmQuit)
+                .spawn(&mut commands, Handle::default())
+        };
+        app.world_mut().flush();
+        modal
+    }
+
+    #[test]
+    fn test_modal_buttons_join_modal_group() {
+        let mut app = modal_app();
+        spawn_modal(&mut app);
+
+        let mut buttons: Vec<(usize, ButtonAction, FocusGroup, FocusCell)> = app
+            .world_mut()
+            .query::<(&ButtonIndex, &MenuButton, &FocusGroup, &FocusCell)>()
+            .iter(app.world())
+            .map(|(idx, button, group, cell)| (idx.0, button.action, *group, *cell))
+            .collect();
+        buttons.sort_by_key(|(idx, ..)| *idx);
+        assert_eq!(
+            buttons,
+            vec![
+                (
+                    0,
+                    ButtonAction::CloseModal,
+                    MODAL_FOCUS_GROUP,
+                    FocusCell::new(0, 0)
+                ),
+                (
+                    1,
+                    ButtonAction::ConfirmQuit,
+                    MODAL_FOCUS_GROUP,
+                    FocusCell::new(0, 1)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_focus_moves_into_modal_and_back() {
+        let mut app = modal_app();
+        app.world_mut().resource_mut::<KeyboardFocusIndex>().0 = 3;
+
+        let modal = spawn_modal(&mut app);
+        app.update();
+        assert_eq!(
+            app.world().resource::<ActiveFocusGroup>().0,
+            MODAL_FOCUS_GROUP
+        );
+        assert_eq!(app.world().resource::<KeyboardFocusIndex>().0, 0);
+
+        app.world_mut().entity_mut(modal).despawn();
+        app.update();
+        assert_eq!(
+            app.world().resource::<ActiveFocusGroup>().0,
+            FocusGroup::SCREEN
+        );
+        assert_eq!(app.world().resource::<KeyboardFocusIndex>().0, 3);
+    }
+
+    #[test]
+    fn test_new_modal_replaces_open_one() {
+        let mut app = modal_app();
+        let first = spawn_modal(&mut app);
+        let second = spawn_modal(&mut app);
+        assert!(app.world().get_entity(first).is_err());
+        assert!(app.world().get_entity(second).is_ok());
+    }
+}
@@ -7,7 +7,7 @@
 //! | `config/ui/hud/layout.ron`     | [`HudLayoutConfig`]     | Widget anchor positions         |
 //! | `config/ui/hud/score.ron`      | [`ScoreHudConfig`]      | Score panel padding/gap         |
 //! | `config/ui/hud/best_score.ron` | [`BestScoreHudConfig`]  | Best-score panel padding        |
-//! | `config/ui/hud/next.ron`       | [`NextHudConfig`]       | Next-fruit preview size         |
+//! | `config/ui/hud/next.ron`       | [`NextHudConfig`]       | Next-fruit queue slot sizes     |
 //! | `config/ui/hud/score_popup.ron`| [`ScorePopupConfig`]    | Floating score popup visuals    |
 //!
 //! All files are watched by Bevy's asset server, so edits take effect while
@@ -17,6 +17,14 @@ use bevy::asset::io::Reader;
 use bevy::asset::{Asset, AssetLoader, LoadContext};
 use bevy::prelude::*;
 use serde::Deserialize;
+use suika_game_core::config::RonColor;
+use suika_game_core::config::{
+    ConfigError, read_ron_asset, report_config_load_errors, report_config_reloads,
+};
+use suika_game_core::events::{ConfigErrorEvent, ConfigReloadedEvent};
+use suika_game_core::resources::settings::{Language, SettingsResource};
+
+use crate::camera::ContainerScreenRect;
 
 // ---------------------------------------------------------------------------
 // Macro — reusable RON loader (mirrors the pattern in app/core/src/config.rs)
@@ -30,18 +38,15 @@ macro_rules! ron_asset_loader {
         impl AssetLoader for $loader {
             type Asset = $asset;
             type Settings = ();
-            type Error = std::io::Error;
+            type Error = ConfigError;
 
             async fn load(
                 &self,
                 reader: &mut dyn Reader,
                 _settings: &Self::Settings,
-                _load_context: &mut LoadContext<'_>,
+                load_context: &mut LoadContext<'_>,
             ) -> Result<Self::Asset, Self::Error> {
-                let mut bytes = Vec::new();
-                reader.read_to_end(&mut bytes).await?;
-                ron::de::from_bytes(&bytes)
-                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+                read_ron_asset(reader, load_context.path()).await
             }
 
             fn extensions(&self) -> &[&str] {
@@ -55,30 +60,336 @@ macro_rules! ron_asset_loader {
 // HudLayoutConfig — widget anchor positions on screen
 // ---------------------------------------------------------------------------
 
+/// A distance in a [`HudPosition`]: fixed pixels or a percentage of the
+/// window size along the same axis.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum HudLength {
+    /// Logical pixels.
+    Px(f32),
+    /// Percent of the window width (horizontal) or height (vertical).
+    Percent(f32),
+}
+
+impl HudLength {
+    /// The equivalent UI [`Val`].
+    pub fn val(self) -> Val {
+        match self {
+            Self::Px(px) => Val::Px(px),
+            Self::Percent(pct) => Val::Percent(pct),
+        }
+    }
+
+    /// The distance in pixels, with percentages taken of `extent`.
+    pub fn resolve(self, extent: f32) -> f32 {
+        match self {
+            Self::Px(px) => px,
+            Self::Percent(pct) => extent * pct / 100.0,
+        }
+    }
+
+    /// The same distance in the opposite direction.
+    fn negated(self) -> Self {
+        match self {
+            Self::Px(px) => Self::Px(-px),
+            Self::Percent(pct) => Self::Percent(-pct),
+        }
+    }
+}
+
+/// The window point a HUD widget is positioned relative to.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HudAnchor {
+    #[default]
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+    /// Outside the container's left wall, level with the container top
+    /// ([`ContainerScreenRect`]).  Falls back to `TopLeft` until the
+    /// container position is known.
+    ContainerLeft,
+    /// Outside the container's right wall, level with the container top
+    /// ([`ContainerScreenRect`]).  Falls back to `TopRight` until the
+    /// container position is known.
+    ContainerRight,
+}
+
+impl HudAnchor {
+    /// The anchor on the opposite side of the window: left and right swap,
+    /// centred anchors stay.
+    pub fn mirrored(self) -> Self {
+        use HudAnchor::*;
+
+        match self {
+            TopLeft => TopRight,
+            TopRight => TopLeft,
+            CenterLeft => CenterRight,
+            CenterRight => CenterLeft,
+            BottomLeft => BottomRight,
+            BottomRight => BottomLeft,
+            ContainerLeft => ContainerRight,
+            ContainerRight => ContainerLeft,
+            TopCenter | Center | BottomCenter => self,
+        }
+    }
+}
+
+/// Where a HUD widget sits: an [`HudAnchor`] plus an offset from it.
+///
+/// For edge anchors the offset is the distance *inwards* from that edge, so
+/// `TopRight` with `x: Px(16.0)` keeps the widget 16 px from the right edge
+/// at any window width.  On a centred axis the offset moves the widget
+/// right / down from the centre line (negative values go left / up).  For
+/// container anchors `x` is the gap to the wall and `y` the distance down
+/// from the container top.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct HudPosition {
+    /// Window point the widget is attached to.
+    pub anchor: HudAnchor,
+    /// Horizontal offset from the anchor.
+    pub x: HudLength,
+    /// Vertical offset from the anchor.
+    pub y: HudLength,
+}
+
+impl Default for HudPosition {
+    fn default() -> Self {
+        Self::px(HudAnchor::TopLeft, 0.0, 0.0)
+    }
+}
+
+impl HudPosition {
+    /// Position `x` / `y` pixels from `anchor`.
+    pub const fn px(anchor: HudAnchor, x: f32, y: f32) -> Self {
+        Self {
+            anchor,
+            x: HudLength::Px(x),
+            y: HudLength::Px(y),
+        }
+    }
+
+    /// Positions an absolute anchor `node` at this position, overwriting its
+    /// insets and alignment.
+    ///
+    /// A centred axis stretches the node across the window (with the offset
+    /// applied to both insets) and centres the widget inside it, so the
+    /// widget stays centred without knowing its own size.
+    pub fn apply(&self, node: &mut Node, container: &ContainerScreenRect) {
+        use HudAnchor::*;
+
+        node.position_type = PositionType::Absolute;
+        let anchor = match (self.anchor, container.rect) {
+            (ContainerLeft, Some(rect)) => {
+                let gap = self.x.resolve(container.viewport.x);
+                let top = rect.min.y + self.y.resolve(container.viewport.y);
+                node.left = Val::Auto;
+                node.right = Val::Px(container.viewport.x - rect.min.x + gap);
+                node.top = Val::Px(top);
+                node.bottom = Val::Auto;
+                node.justify_content = JustifyContent::End;
+                node.align_items = AlignItems::Start;
+                return;
+            }
+            (ContainerRight, Some(rect)) => {
+                let gap = self.x.resolve(container.viewport.x);
+                let top = rect.min.y + self.y.resolve(container.viewport.y);
+                node.left = Val::Px(rect.max.x + gap);
+                node.right = Val::Auto;
+                node.top = Val::Px(top);
+                node.bottom = Val::Auto;
+                node.justify_content = JustifyContent::Start;
+                node.align_items = AlignItems::Start;
+                return;
+            }
+            (ContainerLeft, None) => TopLeft,
+            (ContainerRight, None) => TopRight,
+            (anchor, _) => anchor,
+        };
+
+        let (left, right, justify) = match anchor {
+            TopLeft | CenterLeft | BottomLeft => (self.x.val(), Val::Auto, JustifyContent::Start),
+            TopRight | CenterRight | BottomRight => (Val::Auto, self.x.val(), JustifyContent::End),
+            _ => (self.x.val(), self.x.negated().val(), JustifyContent::Center),
+        };
+        let (top, bottom, align) = match anchor {
+            TopLeft | TopCenter | TopRight => (self.y.val(), Val::Auto, AlignItems::Start),
+            BottomLeft | BottomCenter | BottomRight => (Val::Auto, self.y.val(), AlignItems::End),
+            _ => (self.y.val(), self.y.negated().val(), AlignItems::Center),
+        };
+        node.left = left;
+        node.right = right;
+        node.top = top;
+        node.bottom = bottom;
+        node.justify_content = justify;
+        node.align_items = align;
+    }
+
+    /// A fresh absolute anchor [`Node`] at this position.
+    pub fn node(&self, container: &ContainerScreenRect) -> Node {
+        let mut node = Node::default();
+        self.apply(&mut node, container);
+        node
+    }
+
+    /// The position mirrored left to right, for right-to-left languages.
+    ///
+    /// Edge and container offsets are measured inwards, so they carry over
+    /// unchanged; an offset from a centre line changes sign.
+    pub fn mirrored(self) -> Self {
+        let x = match self.anchor {
+            HudAnchor::TopCenter | HudAnchor::Center | HudAnchor::BottomCenter => self.x.negated(),
+            _ => self.x,
+        };
+        Self {
+            anchor: self.anchor.mirrored(),
+            x,
+            y: self.y,
+        }
+    }
+
+    /// Returns `true` if the position follows the container rather than
+    /// the window.
+    pub fn is_container_relative(&self) -> bool {
+        matches!(
+            self.anchor,
+            HudAnchor::ContainerLeft | HudAnchor::ContainerRight
+        )
+    }
+}
+
 /// HUD anchor position configuration loaded from `config/ui/hud/layout.ron`.
 ///
-/// Controls where each widget group is placed on screen.  Adjust these values
-/// to reposition widgets without touching widget-level code.
+/// Controls where each widget group is placed on screen.  Every widget has
+/// its own [`HudPosition`], so it can be pinned to any corner, edge or the
+/// centre of the window and offset in pixels or percent; positions follow
+/// window resizes and wide aspect ratios.
+///
+/// Positions are written for left-to-right languages;
+/// [`for_language`](Self::for_language) mirrors them for right-to-left ones.
+/// When the window leaves too little room beside the container, the
+/// [`compact`](Self::compact) positions replace the left-hand column.
 #[derive(Asset, TypePath, Deserialize, Debug, Clone)]
 #[serde(default)]
 pub struct HudLayoutConfig {
-    /// Margin from the screen edge for the score panels (pixels).
-    pub edge_margin: f32,
-    /// Horizontal distance between the best-score and score panel anchors (pixels).
-    pub score_panel_offset: f32,
-    /// Distance from the top of the screen for the next-fruit anchor (pixels).
-    pub next_top: f32,
-    /// Distance from the right edge of the screen for the next-fruit anchor (pixels).
-    pub next_right: f32,
+    /// Best-score panel.
+    pub best_score: HudPosition,
+    /// Current-score panel.
+    pub score: HudPosition,
+    /// Next-fruit preview.
+    pub next: HudPosition,
+    /// Largest-fruit badge.
+    pub largest_fruit: HudPosition,
+    /// Drop counter / efficiency.
+    pub drops: HudPosition,
+    /// Combo counter.
+    pub combo: HudPosition,
+    /// First-run onboarding hints.
+    pub onboarding: HudPosition,
+    /// Sandbox controls (sandbox games only).
+    pub sandbox: HudPosition,
+    /// On-screen touch control strip (when switched on).
+    pub touch_controls: HudPosition,
+    /// Imminent-watermelon hint (while two Melons are on the board).
+    pub melon_hint: HudPosition,
+    /// Single-column fallback for narrow windows.
+    pub compact: CompactHudLayout,
+}
+
+/// Compact HUD variant: the panels beside the container stacked in one
+/// narrow column, used while the free width beside the container is below
+/// [`below_margin`](Self::below_margin) (see
+/// [`HudCompact`](crate::screens::hud::HudCompact)).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CompactHudLayout {
+    /// Free width (pixels) on the narrower side of the container below
+    /// which the compact positions are used.
+    pub below_margin: f32,
+    /// Best-score panel.
+    pub best_score: HudPosition,
+    /// Current-score panel.
+    pub score: HudPosition,
+    /// Largest-fruit badge.
+    pub largest_fruit: HudPosition,
+    /// Drop counter / efficiency.
+    pub drops: HudPosition,
+    /// Combo counter.
+    pub combo: HudPosition,
+}
+
+impl Default for CompactHudLayout {
+    fn default() -> Self {
+        Self {
+            below_margin: 336.0,
+            best_score: HudPosition::px(HudAnchor::TopLeft, 8.0, 8.0),
+            score: HudPosition::px(HudAnchor::TopLeft, 8.0, 98.0),
+            largest_fruit: HudPosition::px(HudAnchor::TopLeft, 8.0, 188.0),
+            drops: HudPosition::px(HudAnchor::TopLeft, 8.0, 278.0),
+            combo: HudPosition::px(HudAnchor::TopLeft, 8.0, 368.0),
+        }
+    }
 }
 
 impl Default for HudLayoutConfig {
     fn default() -> Self {
         Self {
-            edge_margin: 16.0,
-            score_panel_offset: 160.0,
-            next_top: 40.0,
-            next_right: 300.0,
+            best_score: HudPosition::px(HudAnchor::TopLeft, 16.0, 16.0),
+            score: HudPosition::px(HudAnchor::TopLeft, 176.0, 16.0),
+            next: HudPosition::px(HudAnchor::ContainerRight, 24.0, 0.0),
+            largest_fruit: HudPosition::px(HudAnchor::TopLeft, 16.0, 110.0),
+            drops: HudPosition::px(HudAnchor::TopLeft, 176.0, 110.0),
+            combo: HudPosition::px(HudAnchor::TopLeft, 16.0, 200.0),
+            onboarding: HudPosition::px(HudAnchor::BottomCenter, 0.0, 16.0),
+            sandbox: HudPosition::px(HudAnchor::BottomLeft, 16.0, 16.0),
+            touch_controls: HudPosition::px(HudAnchor::BottomRight, 16.0, 16.0),
+            melon_hint: HudPosition::px(HudAnchor::TopCenter, 0.0, 16.0),
+            compact: CompactHudLayout::default(),
+        }
+    }
+}
+
+impl HudLayoutConfig {
+    /// The layout to place the HUD with: the [`compact`](Self::compact)
+    /// column swapped in when `compact` is set, then adjusted for `lang`.
+    pub fn resolve(&self, lang: Language, compact: bool) -> Self {
+        if !compact {
+            return self.for_language(lang);
+        }
+        Self {
+            best_score: self.compact.best_score,
+            score: self.compact.score,
+            largest_fruit: self.compact.largest_fruit,
+            drops: self.compact.drops,
+            combo: self.compact.combo,
+            ..self.clone()
+        }
+        .for_language(lang)
+    }
+
+    /// The layout to use for `lang`: unchanged, or every position
+    /// [mirrored](HudPosition::mirrored) for a right-to-left language.
+    pub fn for_language(&self, lang: Language) -> Self {
+        if !lang.is_rtl() {
+            return self.clone();
+        }
+        Self {
+            best_score: self.best_score.mirrored(),
+            score: self.score.mirrored(),
+            next: self.next.mirrored(),
+            largest_fruit: self.largest_fruit.mirrored(),
+            drops: self.drops.mirrored(),
+            combo: self.combo.mirrored(),
+            onboarding: self.onboarding.mirrored(),
+            sandbox: self.sandbox.mirrored(),
+            touch_controls: self.touch_controls.mirrored(),
+            melon_hint: self.melon_hint.mirrored(),
+            compact: self.compact.clone(),
         }
     }
 }
@@ -171,13 +482,18 @@ ron_asset_loader!(BestScoreHudConfigLoader, BestScoreHudConfig);
 #[derive(Asset, TypePath, Deserialize, Debug, Clone)]
 #[serde(default)]
 pub struct NextHudConfig {
-    /// Diameter of the next-fruit preview circle (pixels).
+    /// Size of the next fruit's slot (pixels).
     pub preview_size: f32,
+    /// Size of the queued fruits' slots relative to `preview_size`.
+    pub queue_scale: f32,
 }
 
 impl Default for NextHudConfig {
     fn default() -> Self {
-        Self { preview_size: 80.0 }
+        Self {
+            preview_size: 80.0,
+            queue_scale: 0.6,
+        }
     }
 }
 
@@ -198,6 +514,53 @@ const DEFAULT_POPUP_FONT_SIZE_PER_RADIUS: f32 = 0.8;
 const DEFAULT_POPUP_FADE_START_FRACTION: f32 = 0.5;
 const DEFAULT_POPUP_RAINBOW_HUE_SPEED: f32 = 180.0;
 const DEFAULT_POPUP_Z_LAYER: f32 = 8.0;
+const DEFAULT_POPUP_RAINBOW_COMBO: u32 = 4;
+
+/// One step of popup escalation in `score_popup.ron`.
+///
+/// A tier applies from `min` upward (combo count for `combo_tiers`,
+/// resulting fruit stage for `stage_tiers`); the highest matching tier wins.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct PopupTier {
+    /// Lowest combo count / fruit stage the tier applies to.
+    pub min: u32,
+    /// Text color.
+    pub color: RonColor,
+    /// Font size multiplier.
+    pub scale: f32,
+    /// Outline (drop-shadow) thickness in pixels; `0.0` draws none.
+    pub outline: f32,
+}
+
+impl PopupTier {
+    const fn new(min: u32, color: RonColor, scale: f32, outline: f32) -> Self {
+        Self {
+            min,
+            color,
+            scale,
+            outline,
+        }
+    }
+}
+
+const SILVER: RonColor = RonColor {
+    r: 0.75,
+    g: 0.75,
+    b: 0.82,
+    a: 1.0,
+};
+const GOLD: RonColor = RonColor {
+    r: 1.0,
+    g: 0.84,
+    b: 0.0,
+    a: 1.0,
+};
+const OUTLINE: RonColor = RonColor {
+    r: 0.15,
+    g: 0.1,
+    b: 0.05,
+    a: 0.9,
+};
 
 /// Floating score popup configuration loaded from `config/ui/hud/score_popup.ron`.
 #[derive(Asset, TypePath, Deserialize, Debug, Clone)]
@@ -215,6 +578,15 @@ pub struct ScorePopupConfig {
     pub rainbow_hue_speed: f32,
     /// Z depth for the popup text entity — renders above game objects.
     pub z_layer: f32,
+    /// Color / size / outline steps by combo count.
+    pub combo_tiers: Vec<PopupTier>,
+    /// Color / size / outline steps by resulting fruit stage (0 = Cherry,
+    /// 10 = Watermelon); a matching stage tier's color wins over the combo's.
+    pub stage_tiers: Vec<PopupTier>,
+    /// Combo count from which popups cycle through the rainbow ("fever").
+    pub rainbow_combo: u32,
+    /// Color of the outline drawn behind tiered popups.
+    pub outline_color: RonColor,
 }
 
 impl Default for ScorePopupConfig {
@@ -226,6 +598,13 @@ impl Default for ScorePopupConfig {
             fade_start_fraction: DEFAULT_POPUP_FADE_START_FRACTION,
             rainbow_hue_speed: DEFAULT_POPUP_RAINBOW_HUE_SPEED,
             z_layer: DEFAULT_POPUP_Z_LAYER,
+            combo_tiers: vec![
+                PopupTier::new(2, SILVER, 1.1, 1.0),
+                PopupTier::new(3, GOLD, 1.25, 2.0),
+            ],
+            stage_tiers: vec![PopupTier::new(10, GOLD, 1.5, 3.0)],
+            rainbow_combo: DEFAULT_POPUP_RAINBOW_COMBO,
+            outline_color: OUTLINE,
         }
     }
 }
@@ -240,56 +619,119 @@ ron_asset_loader!(ScorePopupConfigLoader, ScorePopupConfig);
 // Hot-reload systems
 // ---------------------------------------------------------------------------
 
-/// Updates HUD anchor node positions when `config/ui/hud/layout.ron` changes.
-#[allow(clippy::type_complexity)]
+/// Updates HUD anchor node positions when `config/ui/hud/layout.ron` changes
+/// or the HUD switches to or from its [compact](CompactHudLayout) variant.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn hot_reload_hud_layout(
     mut events: MessageReader<AssetEvent<HudLayoutConfig>>,
     config_assets: Res<Assets<HudLayoutConfig>>,
     config_handle: Option<Res<HudLayoutConfigHandle>>,
+    container: Res<ContainerScreenRect>,
+    settings: Res<SettingsResource>,
+    compact: Res<crate::screens::hud::HudCompact>,
     mut best_score_q: Query<
-        &mut Node,
+        (&mut Node, &mut crate::screens::hud::HudPlacement),
         (
             With<crate::screens::hud::HudBestScoreAnchor>,
             Without<crate::screens::hud::HudScoreAnchor>,
             Without<crate::screens::hud::HudNextAnchor>,
+            Without<crate::screens::hud::HudLargestFruitAnchor>,
+            Without<crate::screens::hud::HudDropsAnchor>,
+            Without<crate::screens::hud::HudComboAnchor>,
         ),
     >,
     mut score_q: Query<
-        &mut Node,
+        (&mut Node, &mut crate::screens::hud::HudPlacement),
         (
             With<crate::screens::hud::HudScoreAnchor>,
             Without<crate::screens::hud::HudBestScoreAnchor>,
             Without<crate::screens::hud::HudNextAnchor>,
+            Without<crate::screens::hud::HudLargestFruitAnchor>,
+            Without<crate::screens::hud::HudDropsAnchor>,
+            Without<crate::screens::hud::HudComboAnchor>,
         ),
     >,
     mut next_q: Query<
-        &mut Node,
+        (&mut Node, &mut crate::screens::hud::HudPlacement),
         (
             With<crate::screens::hud::HudNextAnchor>,
             Without<crate::screens::hud::HudBestScoreAnchor>,
             Without<crate::screens::hud::HudScoreAnchor>,
+            Without<crate::screens::hud::HudLargestFruitAnchor>,
+            Without<crate::screens::hud::HudDropsAnchor>,
+            Without<crate::screens::hud::HudComboAnchor>,
+        ),
+    >,
+    mut largest_fruit_q: Query<
+        (&mut Node, &mut crate::screens::hud::HudPlacement),
+        (
+            With<crate::screens::hud::HudLargestFruitAnchor>,
+            Without<crate::screens::hud::HudBestScoreAnchor>,
+            Without<crate::screens::hud::HudScoreAnchor>,
+            Without<crate::screens::hud::HudNextAnchor>,
+            Without<crate::screens::hud::HudDropsAnchor>,
+            Without<crate::screens::hud::HudComboAnchor>,
+        ),
+    >,
+    mut drops_q: Query<
+        (&mut Node, &mut crate::screens::hud::HudPlacement),
+        (
+            With<crate::screens::hud::HudDropsAnchor>,
+            Without<crate::screens::hud::HudBestScoreAnchor>,
+            Without<crate::screens::hud::HudScoreAnchor>,
+            Without<crate::screens::hud::HudNextAnchor>,
+            Without<crate::screens::hud::HudLargestFruitAnchor>,
+            Without<crate::screens::hud::HudComboAnchor>,
+        ),
+    >,
+    mut combo_q: Query<
+        (&mut Node, &mut crate::screens::hud::HudPlacement),
+        (
+            With<crate::screens::hud::HudComboAnchor>,
+            Without<crate::screens::hud::HudBestScoreAnchor>,
+            Without<crate::screens::hud::HudScoreAnchor>,
+            Without<crate::screens::hud::HudNextAnchor>,
+            Without<crate::screens::hud::HudLargestFruitAnchor>,
+            Without<crate::screens::hud::HudDropsAnchor>,
         ),
     >,
 ) {
     let Some(config_handle) = config_handle else {
         return;
     };
-    for event in events.read() {
-        if let AssetEvent::Modified { .. } = event
-            && let Some(cfg) = config_assets.get(&config_handle.0)
-        {
-            if let Ok(mut node) = best_score_q.single_mut() {
-                node.top = Val::Px(cfg.edge_margin);
-                node.left = Val::Px(cfg.edge_margin);
-            }
-            if let Ok(mut node) = score_q.single_mut() {
-                node.top = Val::Px(cfg.edge_margin);
-                node.left = Val::Px(cfg.edge_margin + cfg.score_panel_offset);
-            }
-            if let Ok(mut node) = next_q.single_mut() {
-                node.top = Val::Px(cfg.next_top);
-                node.right = Val::Px(cfg.next_right);
-            }
+    let modified = events
+        .read()
+        .any(|event| matches!(event, AssetEvent::Modified { .. }));
+    if !modified && !compact.is_changed() {
+        return;
+    }
+    if let Some(cfg) = config_assets.get(&config_handle.0) {
+        let cfg = cfg.resolve(settings.language, compact.0);
+        if let Ok((mut node, mut placement)) = best_score_q.single_mut() {
+            placement.0 = cfg.best_score;
+            cfg.best_score.apply(&mut node, &container);
+        }
+        if let Ok((mut node, mut placement)) = score_q.single_mut() {
+            placement.0 = cfg.score;
+            cfg.score.apply(&mut node, &container);
+        }
+        if let Ok((mut node, mut placement)) = next_q.single_mut() {
+            placement.0 = cfg.next;
+            cfg.next.apply(&mut node, &container);
+        }
+        if let Ok((mut node, mut placement)) = largest_fruit_q.single_mut() {
+            placement.0 = cfg.largest_fruit;
+            cfg.largest_fruit.apply(&mut node, &container);
+        }
+        if let Ok((mut node, mut placement)) = drops_q.single_mut() {
+            placement.0 = cfg.drops;
+            cfg.drops.apply(&mut node, &container);
+        }
+        if let Ok((mut node, mut placement)) = combo_q.single_mut() {
+            placement.0 = cfg.combo;
+            cfg.combo.apply(&mut node, &container);
+        }
+        if modified {
             info!("🔥 HUD layout config hot-reloaded");
         }
     }
@@ -341,23 +783,24 @@ pub fn hot_reload_best_score_hud(
     }
 }
 
-/// Updates the next-fruit preview circle size when `config/ui/hud/next.ron` changes.
+/// Redraws the next-fruit slots at their new size when
+/// `config/ui/hud/next.ron` changes.
 pub fn hot_reload_next_hud(
     mut events: MessageReader<AssetEvent<NextHudConfig>>,
     config_assets: Res<Assets<NextHudConfig>>,
     config_handle: Option<Res<NextHudConfigHandle>>,
-    mut preview_q: Query<&mut Node, With<crate::screens::hud::next::HudNextPreview>>,
+    mut slot_q: Query<&mut crate::screens::hud::next::HudNextSlot>,
 ) {
     let Some(config_handle) = config_handle else {
         return;
     };
     for event in events.read() {
         if let AssetEvent::Modified { .. } = event
-            && let Some(cfg) = config_assets.get(&config_handle.0)
+            && config_assets.get(&config_handle.0).is_some()
         {
-            if let Ok(mut node) = preview_q.single_mut() {
-                node.width = Val::Px(cfg.preview_size);
-                node.height = Val::Px(cfg.preview_size);
+            // `update_next` resizes the slots on its next run.
+            for mut slot in slot_q.iter_mut() {
+                slot.invalidate();
             }
             info!("🔥 Next-fruit HUD config hot-reloaded");
         }
@@ -432,6 +875,30 @@ impl Plugin for UiConfigPlugin {
             ),
         );
 
+        // Report broken files (initial load and hot-reload) to the player
+        app.add_message::<ConfigErrorEvent>().add_systems(
+            Update,
+            (
+                report_config_load_errors::<HudLayoutConfig>,
+                report_config_load_errors::<ScoreHudConfig>,
+                report_config_load_errors::<BestScoreHudConfig>,
+                report_config_load_errors::<NextHudConfig>,
+                report_config_load_errors::<ScorePopupConfig>,
+            ),
+        );
+
+        // Confirm successful hot-reloads (shown as toasts in debug builds)
+        app.add_message::<ConfigReloadedEvent>().add_systems(
+            Update,
+            (
+                report_config_reloads::<HudLayoutConfig>,
+                report_config_reloads::<ScoreHudConfig>,
+                report_config_reloads::<BestScoreHudConfig>,
+                report_config_reloads::<NextHudConfig>,
+                report_config_reloads::<ScorePopupConfig>,
+            ),
+        );
+
         info!("✅ UiConfigPlugin initialized");
     }
 }
@@ -447,21 +914,137 @@ mod tests {
     #[test]
     fn test_hud_layout_config_defaults() {
         let cfg = HudLayoutConfig::default();
-        assert!(cfg.edge_margin > 0.0);
-        assert!(cfg.score_panel_offset > 0.0);
-        assert!(cfg.next_top >= 0.0);
-        assert!(cfg.next_right > 0.0);
+        assert_eq!(cfg.best_score.anchor, HudAnchor::TopLeft);
+        assert_eq!(cfg.next.anchor, HudAnchor::ContainerRight);
+        assert_eq!(cfg.onboarding.anchor, HudAnchor::BottomCenter);
+        assert_eq!(cfg.melon_hint.anchor, HudAnchor::TopCenter);
     }
 
     #[test]
     fn test_hud_layout_config_ron_partial_fields_use_defaults() {
-        let ron_str = r#"HudLayoutConfig(edge_margin: 32.0)"#;
+        let ron_str = r#"HudLayoutConfig(next: (anchor: TopCenter, x: Percent(10.0)))"#;
         let cfg: HudLayoutConfig = ron::de::from_str(ron_str).expect("RON parse must succeed");
-        assert_eq!(cfg.edge_margin, 32.0);
+        assert_eq!(cfg.next.anchor, HudAnchor::TopCenter);
+        assert_eq!(cfg.next.x, HudLength::Percent(10.0));
+        assert_eq!(cfg.next.y, HudLength::Px(0.0));
+        assert_eq!(cfg.score, HudLayoutConfig::default().score);
+    }
+
+    #[test]
+    fn test_hud_position_edge_anchors() {
+        let node =
+            HudPosition::px(HudAnchor::TopRight, 20.0, 10.0).node(&ContainerScreenRect::default());
+        assert_eq!(node.position_type, PositionType::Absolute);
+        assert_eq!((node.right, node.left), (Val::Px(20.0), Val::Auto));
+        assert_eq!((node.top, node.bottom), (Val::Px(10.0), Val::Auto));
+
+        let node = HudPosition {
+            anchor: HudAnchor::BottomLeft,
+            x: HudLength::Percent(5.0),
+            y: HudLength::Percent(2.0),
+        }
+        .node(&ContainerScreenRect::default());
+        assert_eq!((node.left, node.right), (Val::Percent(5.0), Val::Auto));
+        assert_eq!((node.bottom, node.top), (Val::Percent(2.0), Val::Auto));
+    }
+
+    #[test]
+    fn test_hud_position_center_offsets_both_insets() {
+        let node =
+            HudPosition::px(HudAnchor::Center, 40.0, -10.0).node(&ContainerScreenRect::default());
+        assert_eq!((node.left, node.right), (Val::Px(40.0), Val::Px(-40.0)));
+        assert_eq!((node.top, node.bottom), (Val::Px(-10.0), Val::Px(10.0)));
+        assert_eq!(node.justify_content, JustifyContent::Center);
+        assert_eq!(node.align_items, AlignItems::Center);
+    }
+
+    #[test]
+    fn test_hud_position_apply_clears_previous_anchor() {
+        let container = ContainerScreenRect::default();
+        let mut node = HudPosition::px(HudAnchor::TopLeft, 16.0, 16.0).node(&container);
+        HudPosition::px(HudAnchor::BottomRight, 8.0, 8.0).apply(&mut node, &container);
+        assert_eq!((node.left, node.top), (Val::Auto, Val::Auto));
+        assert_eq!((node.right, node.bottom), (Val::Px(8.0), Val::Px(8.0)));
+    }
+
+    #[test]
+    fn test_hud_position_mirrored() {
+        let mirrored = HudPosition::px(HudAnchor::TopLeft, 16.0, 8.0).mirrored();
+        assert_eq!(mirrored, HudPosition::px(HudAnchor::TopRight, 16.0, 8.0));
+
+        let mirrored = HudPosition::px(HudAnchor::ContainerRight, 24.0, 0.0).mirrored();
+        assert_eq!(mirrored.anchor, HudAnchor::ContainerLeft);
+
+        let mirrored = HudPosition::px(HudAnchor::BottomCenter, 40.0, 16.0).mirrored();
         assert_eq!(
-            cfg.score_panel_offset,
-            HudLayoutConfig::default().score_panel_offset
+            mirrored,
+            HudPosition::px(HudAnchor::BottomCenter, -40.0, 16.0)
         );
+
+        for anchor in [
+            HudAnchor::CenterLeft,
+            HudAnchor::Center,
+            HudAnchor::BottomRight,
+        ] {
+            assert_eq!(anchor.mirrored().mirrored(), anchor);
+        }
+    }
+
+    #[test]
+    fn test_hud_layout_for_left_to_right_language_is_unchanged() {
+        let cfg = HudLayoutConfig::default();
+        let layout = cfg.for_language(Language::English);
+        assert_eq!(layout.score, cfg.score);
+        assert_eq!(layout.next, cfg.next);
+    }
+
+    #[test]
+    fn test_hud_layout_resolve_compact_swaps_left_column() {
+        let cfg = HudLayoutConfig::default();
+        let wide = cfg.resolve(Language::English, false);
+        assert_eq!(wide.drops, cfg.drops);
+        let compact = cfg.resolve(Language::English, true);
+        assert_eq!(compact.score, cfg.compact.score);
+        assert_eq!(compact.drops, cfg.compact.drops);
+        // Widgets without a compact position keep their place
+        assert_eq!(compact.next, cfg.next);
+        assert_eq!(compact.onboarding, cfg.onboarding);
+    }
+
+    #[test]
+    fn test_hud_layout_compact_ron_partial_fields_use_defaults() {
+        let ron_str = r#"HudLayoutConfig(compact: (below_margin: 200.0))"#;
+        let cfg: HudLayoutConfig = ron::de::from_str(ron_str).expect("RON parse must succeed");
+        assert_eq!(cfg.compact.below_margin, 200.0);
+        assert_eq!(cfg.compact.combo, CompactHudLayout::default().combo);
+    }
+
+    #[test]
+    fn test_hud_position_container_anchors() {
+        let container = ContainerScreenRect {
+            rect: Some(Rect::new(100.0, 50.0, 700.0, 850.0)),
+            viewport: Vec2::new(800.0, 900.0),
+        };
+        let right = HudPosition::px(HudAnchor::ContainerRight, 24.0, 10.0).node(&container);
+        assert_eq!((right.left, right.right), (Val::Px(724.0), Val::Auto));
+        assert_eq!(right.top, Val::Px(60.0));
+
+        let left = HudPosition {
+            anchor: HudAnchor::ContainerLeft,
+            x: HudLength::Percent(1.0),
+            y: HudLength::Px(0.0),
+        }
+        .node(&container);
+        assert_eq!((left.left, left.right), (Val::Auto, Val::Px(708.0)));
+        assert_eq!(left.top, Val::Px(50.0));
+    }
+
+    #[test]
+    fn test_hud_position_container_anchor_falls_back_to_window() {
+        let node = HudPosition::px(HudAnchor::ContainerRight, 24.0, 10.0)
+            .node(&ContainerScreenRect::default());
+        assert_eq!((node.left, node.right), (Val::Auto, Val::Px(24.0)));
+        assert_eq!(node.top, Val::Px(10.0));
     }
 
     #[test]
@@ -515,4 +1098,23 @@ mod tests {
         assert_eq!(cfg.rise_distance, DEFAULT_POPUP_RISE_DISTANCE);
         assert_eq!(cfg.rainbow_hue_speed, DEFAULT_POPUP_RAINBOW_HUE_SPEED);
     }
+
+    #[test]
+    fn test_score_popup_config_ron_tiers() {
+        let ron_str = r#"ScorePopupConfig(
+            combo_tiers: [(min: 5, color: (r: 1.0, g: 0.0, b: 0.0, a: 1.0), scale: 2.0, outline: 4.0)],
+            stage_tiers: [],
+            rainbow_combo: 8,
+        )"#;
+        let cfg: ScorePopupConfig = ron::de::from_str(ron_str).expect("RON parse must succeed");
+        assert_eq!(cfg.combo_tiers.len(), 1);
+        assert_eq!(cfg.combo_tiers[0].min, 5);
+        assert_eq!(cfg.combo_tiers[0].scale, 2.0);
+        assert!(cfg.stage_tiers.is_empty());
+        assert_eq!(cfg.rainbow_combo, 8);
+        assert_eq!(
+            cfg.outline_color.a,
+            ScorePopupConfig::default().outline_color.a
+        );
+    }
 }
@@ -0,0 +1,286 @@
+//! Animated danger line.
+//!
+//! Replaces the plain sprite core spawns for the [`BoundaryLine`] with a
+//! dashed line drawn by [`DANGER_LINE_SHADER_PATH`].  The dashes drift
+//! sideways and the line's alpha pulses; colour, pulse rate and drift speed
+//! escalate from the `idle_*` to the `danger_*` values of
+//! [`BoundaryConfig`] (`effects/boundary.ron`, hot-reloadable) as the
+//! [`GameOverTimer`] warning progresses.
+//!
+//! ```text
+//! idle    ─ ─ ─ ─ ─ ─ ─ ─   pale, slow breathing
+//! warning ━ ━ ━ ━ ━ ━ ━ ━   deep red, fast pulse, faster drift
+//! ```
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderType};
+use bevy::shader::ShaderRef;
+use bevy::sprite_render::{AlphaMode2d, Material2d, Material2dPlugin};
+use suika_game_core::prelude::{
+    AppState, BoundaryConfig, BoundaryLine, BoundaryParams, GameOverTimer, PhysicsParams,
+};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Asset path of the dashed-line fragment shader.
+pub const DANGER_LINE_SHADER_PATH: &str = "shaders/danger_line.wgsl";
+
+/// Line length used until `physics.ron` is available (pixels).
+const DEFAULT_LINE_LENGTH: f32 = 600.0;
+
+/// Style used until `boundary.ron` is available.
+const DEFAULT_STYLE: BoundaryStyle = BoundaryStyle {
+    thickness: 3.0,
+    dash_length: 14.0,
+    gap_length: 8.0,
+    scroll_speed: 12.0,
+    idle_color: Color::srgba(1.0, 0.35, 0.35, 0.5),
+    danger_color: Color::srgba(1.0, 0.05, 0.05, 0.95),
+    idle_pulse_hz: 0.4,
+    danger_pulse_hz: 4.0,
+    pulse_depth: 0.5,
+};
+
+// ---------------------------------------------------------------------------
+// Material
+// ---------------------------------------------------------------------------
+
+/// Uniform block of [`DangerLineMaterial`]; mirrors `DangerLine` in the
+/// shader.
+#[derive(Debug, Clone, Copy, Default, ShaderType)]
+pub struct DangerLineUniform {
+    /// Current colour including the pulse
+    pub color: LinearRgba,
+    /// Line length and thickness in pixels
+    pub size: Vec2,
+    pub dash_length: f32,
+    pub gap_length: f32,
+    /// Distance the dash pattern has drifted (pixels)
+    pub dash_offset: f32,
+}
+
+/// Dashed-line material used by the danger line.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub struct DangerLineMaterial {
+    #[uniform(0)]
+    pub line: DangerLineUniform,
+}
+
+impl Material2d for DangerLineMaterial {
+    fn fragment_shader() -> ShaderRef {
+        DANGER_LINE_SHADER_PATH.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Animation state of a danger line, kept on the [`BoundaryLine`] entity.
+#[derive(Component, Debug, Default)]
+pub struct DangerLineAnimation {
+    /// Pulse phase in cycles; advanced at the current pulse rate so a rate
+    /// change never makes the pulse jump
+    pub pulse_phase: f32,
+    /// Distance the dashes have drifted (pixels)
+    pub dash_offset: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Registers the danger-line material and its systems.
+pub struct DangerLinePlugin;
+
+impl Plugin for DangerLinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<DangerLineMaterial>::default())
+            .add_systems(Update, (attach_danger_line, animate_danger_line).chain());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Swaps the plain boundary sprite for the dashed-line material.
+///
+/// The mesh is a unit quad; [`animate_danger_line`] scales it to the
+/// container width and configured thickness every frame.
+pub fn attach_danger_line(
+    mut commands: Commands,
+    lines: Query<Entity, (With<BoundaryLine>, Without<DangerLineAnimation>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<DangerLineMaterial>>,
+) {
+    for entity in lines.iter() {
+        commands.entity(entity).remove::<Sprite>().insert((
+            Mesh2d(meshes.add(Rectangle::new(1.0, 1.0))),
+            MeshMaterial2d(materials.add(DangerLineMaterial::default())),
+            DangerLineAnimation::default(),
+        ));
+    }
+}
+
+/// Advances the pulse and dash drift and uploads the current style.
+///
+/// The warning only escalates while [`AppState::Playing`]; in other states
+/// the line keeps animating at its idle style.
+#[allow(clippy::type_complexity)]
+pub fn animate_danger_line(
+    time: Res<Time>,
+    state: Res<State<AppState>>,
+    game_over_timer: Res<GameOverTimer>,
+    boundary: BoundaryParams,
+    physics: PhysicsParams,
+    mut lines: Query<
+        (
+            &mut Transform,
+            &mut DangerLineAnimation,
+            &MeshMaterial2d<DangerLineMaterial>,
+        ),
+        With<BoundaryLine>,
+    >,
+    mut materials: ResMut<Assets<DangerLineMaterial>>,
+) {
+    let style = boundary
+        .get()
+        .map(BoundaryStyle::from)
+        .unwrap_or(DEFAULT_STYLE);
+    let length = physics
+        .get()
+        .map_or(DEFAULT_LINE_LENGTH, |p| p.container_width);
+    let progress = if *state.get() == AppState::Playing && game_over_timer.is_warning {
+        game_over_timer.warning_progress()
+    } else {
+        0.0
+    };
+    let dt = time.delta_secs();
+
+    for (mut transform, mut anim, material) in lines.iter_mut() {
+        anim.pulse_phase = (anim.pulse_phase + style.pulse_hz(progress) * dt).fract();
+        anim.dash_offset += style.scroll_speed(progress) * dt;
+        let period = (style.dash_length + style.gap_length).max(1.0);
+        anim.dash_offset %= period;
+
+        let size = Vec2::new(length, style.thickness);
+        transform.scale = size.extend(1.0);
+
+        let Some(material) = materials.get_mut(&material.0) else {
+            continue;
+        };
+        material.line = DangerLineUniform {
+            color: style.color(progress, anim.pulse_phase).into(),
+            size,
+            dash_length: style.dash_length,
+            gap_length: style.gap_length,
+            dash_offset: anim.dash_offset,
+        };
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// [`BoundaryConfig`] with its colours converted, plus the interpolation
+/// between idle and danger.
+#[derive(Debug, Clone, Copy)]
+struct BoundaryStyle {
+    thickness: f32,
+    dash_length: f32,
+    gap_length: f32,
+    scroll_speed: f32,
+    idle_color: Color,
+    danger_color: Color,
+    idle_pulse_hz: f32,
+    danger_pulse_hz: f32,
+    pulse_depth: f32,
+}
+
+impl From<&BoundaryConfig> for BoundaryStyle {
+    fn from(cfg: &BoundaryConfig) -> Self {
+        Self {
+            thickness: cfg.thickness,
+            dash_length: cfg.dash_length,
+            gap_length: cfg.gap_length,
+            scroll_speed: cfg.scroll_speed,
+            idle_color: cfg.idle_color.into(),
+            danger_color: cfg.danger_color.into(),
+            idle_pulse_hz: cfg.idle_pulse_hz,
+            danger_pulse_hz: cfg.danger_pulse_hz,
+            pulse_depth: cfg.pulse_depth,
+        }
+    }
+}
+
+impl BoundaryStyle {
+    /// Pulses per second at warning `progress` (0 = idle, 1 = game over).
+    fn pulse_hz(&self, progress: f32) -> f32 {
+        self.idle_pulse_hz + (self.danger_pulse_hz - self.idle_pulse_hz) * progress
+    }
+
+    /// Dash drift speed at warning `progress`; doubles at full warning.
+    fn scroll_speed(&self, progress: f32) -> f32 {
+        self.scroll_speed * (1.0 + progress)
+    }
+
+    /// Line colour at warning `progress` and pulse `phase` (cycles, 0–1).
+    ///
+    /// The alpha dips by `pulse_depth` at half a cycle and is full at the
+    /// start of each cycle.
+    fn color(&self, progress: f32, phase: f32) -> Color {
+        let base = self
+            .idle_color
+            .mix(&self.danger_color, progress.clamp(0.0, 1.0));
+        let dip = 0.5 - 0.5 * (phase * TAU).cos();
+        let alpha = base.alpha() * (1.0 - self.pulse_depth.clamp(0.0, 1.0) * dip);
+        base.with_alpha(alpha)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pulse_rate_escalates_with_progress() {
+        let style = DEFAULT_STYLE;
+        assert_eq!(style.pulse_hz(0.0), style.idle_pulse_hz);
+        assert_eq!(style.pulse_hz(1.0), style.danger_pulse_hz);
+        assert!(style.pulse_hz(0.5) > style.pulse_hz(0.25));
+        assert_eq!(style.scroll_speed(1.0), style.scroll_speed * 2.0);
+    }
+
+    #[test]
+    fn test_color_moves_to_danger() {
+        let style = DEFAULT_STYLE;
+        assert_eq!(style.color(0.0, 0.0), style.idle_color);
+        let danger = style.color(1.0, 0.0).to_srgba();
+        let expected = style.danger_color.to_srgba();
+        assert!((danger.red - expected.red).abs() < 1e-5);
+        assert!((danger.green - expected.green).abs() < 1e-5);
+        assert!((danger.alpha - expected.alpha).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_pulse_dips_alpha_at_half_cycle() {
+        let style = DEFAULT_STYLE;
+        let full = style.color(0.0, 0.0).alpha();
+        let low = style.color(0.0, 0.5).alpha();
+        assert!((low - full * (1.0 - style.pulse_depth)).abs() < 1e-5);
+    }
+}
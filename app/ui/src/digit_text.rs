@@ -0,0 +1,351 @@
+//! Digit-atlas number display.
+//!
+//! A [`Text`] node that shows a rapidly changing number is re-shaped and
+//! re-laid out every time its string changes.  [`DigitText`] avoids that:
+//! it is a row of fixed-width slots, one per digit or thousands separator
+//! of the largest `u32`, and every slot holds each glyph it can show as its
+//! own pre-shaped text node.  Showing a new value only flips
+//! [`Visibility`] on those glyphs; text is re-shaped only when the glyph set
+//! changes (language or full-width digits), and the row is re-laid out only
+//! when the number of digits changes.
+//!
+//! ```text
+//! slot:   12 11 10  9  8  7  6  5  4  3  2  1  0
+//! glyph:   4  ,  2  9  4  ,  9  6  7  ,  2  9  5
+//!                               └── slots past the value's length: Display::None
+//! ```
+//!
+//! Digits and separators follow [`number_glyphs`], so the row reads the
+//! same as [`format_score`](crate::number_format::format_score).
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::resources::settings::{Language, SettingsResource};
+
+use crate::number_format::number_glyphs;
+
+/// Slots needed for `u32::MAX` (`4,294,967,295`): ten digits and three
+/// separators.
+pub const DIGIT_SLOTS: usize = 13;
+
+/// Width of a half-width digit slot, as a fraction of the font size.
+const DIGIT_ADVANCE: f32 = 0.6;
+
+/// Width of a half-width separator slot, as a fraction of the font size.
+const SEPARATOR_ADVANCE: f32 = 0.35;
+
+/// Width of any full-width slot, as a fraction of the font size.
+const FULL_WIDTH_ADVANCE: f32 = 1.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// A number shown through pre-shaped glyphs.
+///
+/// Write [`value`](Self::value) to change the number; [`update_digit_text`]
+/// shows the matching glyphs.
+#[derive(Component, Debug)]
+pub struct DigitText {
+    /// The number shown
+    pub value: u32,
+    /// Font size the slots are sized for
+    font_size: f32,
+    /// Glyph set the slots currently hold, `None` until first shown
+    glyphs: Option<(Language, bool)>,
+}
+
+/// One position of a [`DigitText`] row, counted from the right.
+#[derive(Component, Debug)]
+pub struct DigitSlot(pub usize);
+
+/// One pre-shaped glyph inside a [`DigitSlot`].
+#[derive(Component, Debug)]
+pub struct DigitGlyph(pub Glyph);
+
+/// What a [`DigitGlyph`] shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Glyph {
+    /// A digit `0`–`9`
+    Digit(u8),
+    /// The thousands separator
+    Separator,
+}
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns a [`DigitText`] row showing `value` as a child of `parent` and
+/// returns its entity commands, so callers can add their own marker.
+///
+/// Glyph characters are filled in by [`update_digit_text`] on its first
+/// run, once the language is known.
+pub fn spawn_digit_text<'a>(
+    parent: &'a mut ChildSpawnerCommands<'_>,
+    value: u32,
+    font: &Handle<Font>,
+    font_size: f32,
+    color: Color,
+) -> EntityCommands<'a> {
+    let mut row = parent.spawn((
+        DigitText {
+            value,
+            font_size,
+            glyphs: None,
+        },
+        Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        UiTransform::default(),
+    ));
+    row.with_children(|row| {
+        for slot in (0..DIGIT_SLOTS).rev() {
+            row.spawn((
+                DigitSlot(slot),
+                Node {
+                    height: Val::Px(font_size * 1.2),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            ))
+            .with_children(|cell| {
+                for glyph in slot_glyphs(slot) {
+                    cell.spawn((
+                        DigitGlyph(glyph),
+                        Text::new(""),
+                        TextFont {
+                            font: font.clone(),
+                            font_size,
+                            ..default()
+                        },
+                        TextColor(color),
+                        Node {
+                            position_type: PositionType::Absolute,
+                            ..default()
+                        },
+                        Visibility::Hidden,
+                    ));
+                }
+            });
+        }
+    });
+    row
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Shows the glyphs for each changed [`DigitText`] and refreshes the glyph
+/// set when the language or full-width digit setting changes.
+///
+/// Everything is written through change-guarded assignments, so an
+/// unchanged number costs no layout or text work.
+#[allow(clippy::type_complexity)]
+pub fn update_digit_text(
+    settings: Res<SettingsResource>,
+    mut rows: Query<(&mut DigitText, &Children)>,
+    mut slots: Query<(&DigitSlot, &mut Node, &Children)>,
+    mut glyphs: Query<(&DigitGlyph, &mut Text, &mut Visibility)>,
+) {
+    let glyph_set = (settings.language, settings.full_width_digits);
+    let (digit_chars, separator) = number_glyphs(glyph_set.0, glyph_set.1);
+    // Full-width only applies to some languages; the glyphs tell
+    let full_width = digit_chars[0] != '0';
+
+    for (mut digits, children) in rows.iter_mut() {
+        let restyle = digits.glyphs != Some(glyph_set);
+        if !restyle && !digits.is_changed() {
+            continue;
+        }
+        if restyle {
+            digits.glyphs = Some(glyph_set);
+        }
+        let used = slots_used(digits.value);
+
+        for &child in children {
+            let Ok((slot, mut node, cells)) = slots.get_mut(child) else {
+                continue;
+            };
+            let display = if slot.0 < used {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            if node.display != display {
+                node.display = display;
+            }
+            if restyle {
+                let width = Val::Px(slot_advance(slot.0, full_width) * digits.font_size);
+                if node.width != width {
+                    node.width = width;
+                }
+            }
+
+            let shown = glyph_at(digits.value, slot.0);
+            for &cell in cells {
+                let Ok((glyph, mut text, mut visibility)) = glyphs.get_mut(cell) else {
+                    continue;
+                };
+                if restyle {
+                    let c = match glyph.0 {
+                        Glyph::Digit(d) => digit_chars[usize::from(d)],
+                        Glyph::Separator => separator,
+                    };
+                    text.0 = c.to_string();
+                }
+                visibility.set_if_neq(if shown == Some(glyph.0) {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                });
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// `true` for the slots that hold a thousands separator.
+fn is_separator_slot(slot: usize) -> bool {
+    slot % 4 == 3
+}
+
+/// Glyphs a slot can show.
+fn slot_glyphs(slot: usize) -> Vec<Glyph> {
+    if is_separator_slot(slot) {
+        vec![Glyph::Separator]
+    } else {
+        (0..10).map(Glyph::Digit).collect()
+    }
+}
+
+/// Width of `slot` as a fraction of the font size.
+fn slot_advance(slot: usize, full_width: bool) -> f32 {
+    if full_width {
+        FULL_WIDTH_ADVANCE
+    } else if is_separator_slot(slot) {
+        SEPARATOR_ADVANCE
+    } else {
+        DIGIT_ADVANCE
+    }
+}
+
+/// Slots `value` occupies, digits and separators together.
+fn slots_used(value: u32) -> usize {
+    let digits = value.checked_ilog10().unwrap_or(0) as usize + 1;
+    digits + (digits - 1) / 3
+}
+
+/// Glyph slot `slot` (counted from the right) shows for `value`, or `None`
+/// past the value's length.
+fn glyph_at(value: u32, slot: usize) -> Option<Glyph> {
+    if slot >= slots_used(value) {
+        return None;
+    }
+    if is_separator_slot(slot) {
+        return Some(Glyph::Separator);
+    }
+    let place = (slot - slot / 4) as u32;
+    Some(Glyph::Digit((value / 10_u32.pow(place) % 10) as u8))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number_format::format_number;
+
+    /// The row as a string, leftmost slot first.
+    fn render(value: u32) -> String {
+        let (digits, separator) = number_glyphs(Language::English, false);
+        (0..DIGIT_SLOTS)
+            .rev()
+            .filter_map(|slot| glyph_at(value, slot))
+            .map(|glyph| match glyph {
+                Glyph::Digit(d) => digits[usize::from(d)],
+                Glyph::Separator => separator,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_slots_used() {
+        assert_eq!(slots_used(0), 1);
+        assert_eq!(slots_used(999), 3);
+        assert_eq!(slots_used(1_000), 5);
+        assert_eq!(slots_used(u32::MAX), DIGIT_SLOTS);
+    }
+
+    #[test]
+    fn test_glyphs_match_format_number() {
+        for value in [0, 7, 42, 999, 1_000, 65_536, 1_234_567, u32::MAX] {
+            assert_eq!(
+                render(value),
+                format_number(value, Language::English, false)
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_digit_text_shows_one_glyph_per_used_slot() {
+        let mut app = App::new();
+        app.init_resource::<SettingsResource>()
+            .add_systems(Update, update_digit_text);
+        let row = app
+            .world_mut()
+            .spawn((
+                DigitText {
+                    value: 1_280,
+                    font_size: 20.0,
+                    glyphs: None,
+                },
+                Node::default(),
+            ))
+            .with_children(|row| {
+                for slot in (0..DIGIT_SLOTS).rev() {
+                    row.spawn((DigitSlot(slot), Node::default()))
+                        .with_children(|cell| {
+                            for glyph in slot_glyphs(slot) {
+                                cell.spawn((DigitGlyph(glyph), Text::new(""), Visibility::Hidden));
+                            }
+                        });
+                }
+            })
+            .id();
+        app.update();
+
+        let shown = |app: &mut App| -> String {
+            let mut visible: Vec<(usize, String)> = Vec::new();
+            let world = app.world_mut();
+            let mut slots = world.query::<(&DigitSlot, &Children)>();
+            let slots: Vec<(usize, Vec<Entity>)> = slots
+                .iter(world)
+                .map(|(slot, children)| (slot.0, children.to_vec()))
+                .collect();
+            for (slot, cells) in slots {
+                for cell in cells {
+                    if world.get::<Visibility>(cell) == Some(&Visibility::Inherited) {
+                        visible.push((slot, world.get::<Text>(cell).unwrap().0.clone()));
+                    }
+                }
+            }
+            visible.sort_by_key(|(slot, _)| std::cmp::Reverse(*slot));
+            visible.into_iter().map(|(_, c)| c).collect()
+        };
+        assert_eq!(shown(&mut app), "1,280");
+
+        app.world_mut().get_mut::<DigitText>(row).unwrap().value = 35;
+        app.update();
+        assert_eq!(shown(&mut app), "35");
+    }
+}
@@ -0,0 +1,436 @@
+//! Keyboard focus management shared by every menu.
+//!
+//! Menu buttons are laid out on a grid of [`FocusCell`]s.  Buttons without a
+//! cell form a single column ordered by their [`ButtonIndex`], which covers
+//! the plain top-to-bottom menus (title, pause, game over, …); screens with
+//! buttons side by side, like the settings rows with ◀ / ▶ arrows, give each
+//! button an explicit cell.
+//!
+//! [`FocusGrid`] moves focus between cells:
+//!
+//! - **Up / Down** (W / S / ↑ / ↓) move to the previous / next row, landing on
+//!   the button whose column is closest to the current one.
+//! - **Left / Right** (A / D / ← / →) move within the current row.
+//! - Both axes wrap around at the ends.
+//!
+//! Gamepads drive the same grid through [`MenuInput`]: the D-pad and left
+//! stick move focus and the south button (A on Xbox, ✕ on PlayStation)
+//! confirms like Enter.  The focused button carries a focus ring
+//! ([`update_focus_ring`]) so it stays visible from the couch.
+//!
+//! Buttons also belong to a [`FocusGroup`] (the screen group when the
+//! component is absent).  Only the group in [`ActiveFocusGroup`] takes part
+//! in navigation, so a dialog can keep focus inside its own buttons while the
+//! screen behind it stays put.
+//!
+//! [`ButtonIndex`]: crate::components::ButtonIndex
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::components::{ButtonIndex, KeyboardFocusIndex, MenuButton};
+use crate::styles::FOCUS_RING_COLOR;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Left-stick deflection that counts as a push in one direction.
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// Thickness of the focus ring (logical pixels).
+const FOCUS_RING_WIDTH: f32 = 4.0;
+
+/// Gap between a button's edge and its focus ring (logical pixels).
+const FOCUS_RING_OFFSET: f32 = 2.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Grid position of a menu button for keyboard navigation.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusCell {
+    /// Row, counted from the top.
+    pub row: usize,
+    /// Column within the row, counted from the left.
+    pub col: usize,
+}
+
+impl FocusCell {
+    /// Cell at `row`, `col`.
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Cell used for a button without an explicit [`FocusCell`]: one row per
+    /// button index, all in the first column.
+    pub const fn column(index: usize) -> Self {
+        Self::new(index, 0)
+    }
+}
+
+/// Focus group a menu button belongs to.
+///
+/// Buttons without this component are in [`FocusGroup::SCREEN`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FocusGroup(pub u32);
+
+impl FocusGroup {
+    /// Group of the buttons that make up the current screen.
+    pub const SCREEN: Self = Self(0);
+}
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// The [`FocusGroup`] keyboard navigation currently works on.
+///
+/// Defaults to [`FocusGroup::SCREEN`].  A dialog sets its own group while it
+/// is open and restores the screen group when it closes.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveFocusGroup(pub FocusGroup);
+
+// ---------------------------------------------------------------------------
+// Navigation
+// ---------------------------------------------------------------------------
+
+/// Direction of a focus move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavDirection {
+    /// Previous row.
+    Up,
+    /// Next row.
+    Down,
+    /// Previous button in the row.
+    Left,
+    /// Next button in the row.
+    Right,
+}
+
+impl NavDirection {
+    /// Direction pressed this frame, if any (W / A / S / D or arrow keys).
+    ///
+    /// Vertical moves win when keys on both axes are pressed together.
+    pub fn from_keyboard(keyboard: &ButtonInput<KeyCode>) -> Option<Self> {
+        let pressed = |keys: [KeyCode; 2]| keyboard.any_just_pressed(keys);
+        if pressed([KeyCode::KeyW, KeyCode::ArrowUp]) {
+            Some(Self::Up)
+        } else if pressed([KeyCode::KeyS, KeyCode::ArrowDown]) {
+            Some(Self::Down)
+        } else if pressed([KeyCode::KeyA, KeyCode::ArrowLeft]) {
+            Some(Self::Left)
+        } else if pressed([KeyCode::KeyD, KeyCode::ArrowRight]) {
+            Some(Self::Right)
+        } else {
+            None
+        }
+    }
+
+    /// Direction pressed on a gamepad's D-pad this frame, if any.
+    pub fn from_dpad(gamepad: &Gamepad) -> Option<Self> {
+        if gamepad.just_pressed(GamepadButton::DPadUp) {
+            Some(Self::Up)
+        } else if gamepad.just_pressed(GamepadButton::DPadDown) {
+            Some(Self::Down)
+        } else if gamepad.just_pressed(GamepadButton::DPadLeft) {
+            Some(Self::Left)
+        } else if gamepad.just_pressed(GamepadButton::DPadRight) {
+            Some(Self::Right)
+        } else {
+            None
+        }
+    }
+
+    /// Direction an analog stick points in, if it is pushed past
+    /// [`STICK_THRESHOLD`]; the dominant axis wins.
+    pub fn from_stick(stick: Vec2) -> Option<Self> {
+        if stick.x.abs().max(stick.y.abs()) < STICK_THRESHOLD {
+            None
+        } else if stick.y.abs() >= stick.x.abs() {
+            Some(if stick.y > 0.0 { Self::Up } else { Self::Down })
+        } else {
+            Some(if stick.x > 0.0 {
+                Self::Right
+            } else {
+                Self::Left
+            })
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Input
+// ---------------------------------------------------------------------------
+
+/// Returns `true` if Enter or any gamepad's south button was pressed this
+/// frame.
+pub fn confirm_pressed<'a>(
+    keyboard: &ButtonInput<KeyCode>,
+    gamepads: impl IntoIterator<Item = &'a Gamepad>,
+) -> bool {
+    keyboard.just_pressed(KeyCode::Enter)
+        || gamepads
+            .into_iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::South))
+}
+
+/// Keyboard and gamepad input for menu navigation.
+#[derive(SystemParam)]
+pub struct MenuInput<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+    /// Direction the left stick was held in last frame, so a held stick
+    /// moves focus once rather than every frame.
+    stick_held: Local<'s, Option<NavDirection>>,
+}
+
+impl MenuInput<'_, '_> {
+    /// Direction to move focus this frame, if any.
+    ///
+    /// Call once per frame; the left stick only reports a direction on the
+    /// frame it is first pushed that way.
+    pub fn direction(&mut self) -> Option<NavDirection> {
+        let stick = self
+            .gamepads
+            .iter()
+            .find_map(|gamepad| NavDirection::from_stick(gamepad.left_stick()));
+        let stick_pushed = stick.filter(|_| *self.stick_held != stick);
+        *self.stick_held = stick;
+
+        NavDirection::from_keyboard(&self.keyboard)
+            .or_else(|| self.gamepads.iter().find_map(NavDirection::from_dpad))
+            .or(stick_pushed)
+    }
+
+    /// Returns `true` if the focused button should activate this frame.
+    pub fn confirm(&self) -> bool {
+        confirm_pressed(&self.keyboard, self.gamepads.iter())
+    }
+}
+
+/// The focusable buttons of one [`FocusGroup`], keyed by button index.
+#[derive(Debug, Default, Clone)]
+pub struct FocusGrid {
+    cells: Vec<(usize, FocusCell)>,
+}
+
+impl FocusGrid {
+    /// Builds a grid from `(button index, cell)` pairs; buttons without a
+    /// cell get [`FocusCell::column`].
+    pub fn new(buttons: impl IntoIterator<Item = (usize, Option<FocusCell>)>) -> Self {
+        Self {
+            cells: buttons
+                .into_iter()
+                .map(|(index, cell)| (index, cell.unwrap_or(FocusCell::column(index))))
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if the grid has no buttons.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Button index focused after moving from `current` in `direction`.
+    ///
+    /// A `current` index that is not in the grid (e.g. the focused button
+    /// was despawned) moves to the top-left button.  Returns `current`
+    /// unchanged for an empty grid.
+    pub fn navigate(&self, current: usize, direction: NavDirection) -> usize {
+        let Some(&(_, from)) = self.cells.iter().find(|(index, _)| *index == current) else {
+            return self.first().unwrap_or(current);
+        };
+
+        let target = match direction {
+            NavDirection::Up | NavDirection::Down => {
+                let mut rows: Vec<usize> = self.cells.iter().map(|(_, c)| c.row).collect();
+                rows.sort_unstable();
+                rows.dedup();
+                let row = step(&rows, from.row, direction == NavDirection::Down);
+                self.cells
+                    .iter()
+                    .filter(|(_, c)| c.row == row)
+                    .min_by_key(|(_, c)| (c.col.abs_diff(from.col), c.col))
+            }
+            NavDirection::Left | NavDirection::Right => {
+                let mut cols: Vec<usize> = self
+                    .cells
+                    .iter()
+                    .filter(|(_, c)| c.row == from.row)
+                    .map(|(_, c)| c.col)
+                    .collect();
+                cols.sort_unstable();
+                cols.dedup();
+                let col = step(&cols, from.col, direction == NavDirection::Right);
+                self.cells
+                    .iter()
+                    .find(|(_, c)| c.row == from.row && c.col == col)
+            }
+        };
+
+        target.map_or(current, |&(index, _)| index)
+    }
+
+    /// Button index of the top-left cell.
+    fn first(&self) -> Option<usize> {
+        self.cells
+            .iter()
+            .min_by_key(|(_, c)| (c.row, c.col))
+            .map(|&(index, _)| index)
+    }
+}
+
+/// The entry after (`forward`) or before `value` in the sorted, deduplicated
+/// `values`, wrapping around at both ends.
+fn step(values: &[usize], value: usize, forward: bool) -> usize {
+    let Some(pos) = values.iter().position(|&v| v == value) else {
+        return value;
+    };
+    let len = values.len();
+    let next = if forward {
+        (pos + 1) % len
+    } else {
+        (pos + len - 1) % len
+    };
+    values[next]
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Draws a focus ring around the focused button of the
+/// [`ActiveFocusGroup`] and removes it from every other button.
+pub fn update_focus_ring(
+    mut commands: Commands,
+    focus: Res<KeyboardFocusIndex>,
+    active_group: Res<ActiveFocusGroup>,
+    buttons: Query<(Entity, &ButtonIndex, Option<&FocusGroup>, Has<Outline>), With<MenuButton>>,
+) {
+    for (entity, idx, group, has_ring) in buttons.iter() {
+        let focused = idx.0 == focus.0 && group.copied().unwrap_or_default() == active_group.0;
+        if focused && !has_ring {
+            commands.entity(entity).insert(Outline::new(
+                Val::Px(FOCUS_RING_WIDTH),
+                Val::Px(FOCUS_RING_OFFSET),
+                FOCUS_RING_COLOR,
+            ));
+        } else if !focused && has_ring {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The settings screen: two arrow rows, a toggle, the language arrows,
+    /// three more toggles and Back.
+    fn settings_grid() -> FocusGrid {
+        FocusGrid::new([
+            (0, Some(FocusCell::new(0, 0))),
+            (1, Some(FocusCell::new(0, 1))),
+            (2, Some(FocusCell::new(1, 0))),
+            (3, Some(FocusCell::new(1, 1))),
+            (4, Some(FocusCell::new(2, 0))),
+            (5, Some(FocusCell::new(3, 0))),
+            (6, Some(FocusCell::new(3, 1))),
+            (7, Some(FocusCell::new(4, 0))),
+            (8, Some(FocusCell::new(5, 0))),
+            (9, Some(FocusCell::new(6, 0))),
+            (10, Some(FocusCell::new(7, 0))),
+        ])
+    }
+
+    #[test]
+    fn test_stick_direction_uses_dominant_axis() {
+        assert_eq!(
+            NavDirection::from_stick(Vec2::new(0.1, 0.9)),
+            Some(NavDirection::Up)
+        );
+        assert_eq!(
+            NavDirection::from_stick(Vec2::new(-0.8, 0.3)),
+            Some(NavDirection::Left)
+        );
+        assert_eq!(NavDirection::from_stick(Vec2::new(0.2, -0.2)), None);
+    }
+
+    #[test]
+    fn test_focus_ring_follows_focus() {
+        let mut app = App::new();
+        app.init_resource::<KeyboardFocusIndex>()
+            .init_resource::<ActiveFocusGroup>()
+            .add_systems(Update, update_focus_ring);
+        let button = |index| {
+            (
+                MenuButton {
+                    action: crate::components::ButtonAction::StartGame,
+                },
+                ButtonIndex(index),
+            )
+        };
+        let first = app.world_mut().spawn(button(0)).id();
+        let second = app.world_mut().spawn(button(1)).id();
+        let dialog = app.world_mut().spawn((button(1), FocusGroup(1))).id();
+
+        app.update();
+        assert!(app.world().entity(first).contains::<Outline>());
+        assert!(!app.world().entity(second).contains::<Outline>());
+
+        app.world_mut().resource_mut::<KeyboardFocusIndex>().0 = 1;
+        app.update();
+        assert!(!app.world().entity(first).contains::<Outline>());
+        assert!(app.world().entity(second).contains::<Outline>());
+        assert!(!app.world().entity(dialog).contains::<Outline>());
+    }
+
+    #[test]
+    fn test_column_menu_wraps_vertically() {
+        let grid = FocusGrid::new((0..4).map(|i| (i, None)));
+        assert_eq!(grid.navigate(1, NavDirection::Down), 2);
+        assert_eq!(grid.navigate(3, NavDirection::Down), 0);
+        assert_eq!(grid.navigate(0, NavDirection::Up), 3);
+    }
+
+    #[test]
+    fn test_column_menu_ignores_horizontal_moves() {
+        let grid = FocusGrid::new((0..3).map(|i| (i, None)));
+        assert_eq!(grid.navigate(1, NavDirection::Left), 1);
+        assert_eq!(grid.navigate(1, NavDirection::Right), 1);
+    }
+
+    #[test]
+    fn test_row_navigation_wraps_within_row() {
+        let grid = settings_grid();
+        assert_eq!(grid.navigate(2, NavDirection::Right), 3);
+        assert_eq!(grid.navigate(3, NavDirection::Right), 2);
+        assert_eq!(grid.navigate(5, NavDirection::Left), 6);
+    }
+
+    #[test]
+    fn test_vertical_move_keeps_nearest_column() {
+        let grid = settings_grid();
+        // ▶ of BGM → ▶ of SFX
+        assert_eq!(grid.navigate(1, NavDirection::Down), 3);
+        // ▶ of SFX → the single Effects toggle
+        assert_eq!(grid.navigate(3, NavDirection::Down), 4);
+        // Back → wraps to BGM ◀
+        assert_eq!(grid.navigate(10, NavDirection::Down), 0);
+        // BGM ◀ → wraps to Back
+        assert_eq!(grid.navigate(0, NavDirection::Up), 10);
+    }
+
+    #[test]
+    fn test_unknown_focus_moves_to_first_button() {
+        let grid = settings_grid();
+        assert_eq!(grid.navigate(42, NavDirection::Down), 0);
+        assert_eq!(FocusGrid::default().navigate(3, NavDirection::Up), 3);
+    }
+}
@@ -0,0 +1,246 @@
+//! Haptic feedback.
+//!
+//! Drops, merges and game over vibrate every connected gamepad through
+//! gilrs ([`GamepadRumbleRequest`]); the web build also vibrates the device
+//! itself through the Vibration API (`navigator.vibrate`), which is what
+//! phones and tablets support.  Merges grow stronger and longer with the
+//! stage of the merging fruits.
+//!
+//! Nothing vibrates while [`SettingsResource::haptics_enabled`] is off.
+
+use std::time::Duration;
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+use suika_game_core::prelude::{FruitDroppedEvent, FruitMergeEvent, FruitType, SettingsResource};
+
+// ---------------------------------------------------------------------------
+// Pulses
+// ---------------------------------------------------------------------------
+
+/// One vibration: motor strength (0.0–1.0) and length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HapticPulse {
+    /// Motor strength, 0.0 (off) to 1.0 (full)
+    pub intensity: f32,
+    /// How long the vibration lasts
+    pub duration: Duration,
+}
+
+/// Short tick when a fruit is released.
+pub const DROP_PULSE: HapticPulse = HapticPulse {
+    intensity: 0.2,
+    duration: Duration::from_millis(30),
+};
+
+/// Long, full-strength buzz when the game ends.
+pub const GAME_OVER_PULSE: HapticPulse = HapticPulse {
+    intensity: 1.0,
+    duration: Duration::from_millis(600),
+};
+
+/// Merge pulse of the smallest fruit (Cherry).
+const MERGE_PULSE_MIN: HapticPulse = HapticPulse {
+    intensity: 0.3,
+    duration: Duration::from_millis(50),
+};
+
+/// Merge pulse of the largest fruit (Watermelon).
+const MERGE_PULSE_MAX: HapticPulse = HapticPulse {
+    intensity: 1.0,
+    duration: Duration::from_millis(250),
+};
+
+/// Pulse for a merge of two `fruit_type` fruits, interpolated by stage
+/// between [`MERGE_PULSE_MIN`] and [`MERGE_PULSE_MAX`].
+pub fn merge_pulse(fruit_type: FruitType) -> HapticPulse {
+    let t = fruit_type.stage_index() as f32 / FruitType::Watermelon.stage_index() as f32;
+    let min_ms = MERGE_PULSE_MIN.duration.as_millis() as f32;
+    let max_ms = MERGE_PULSE_MAX.duration.as_millis() as f32;
+    HapticPulse {
+        intensity: MERGE_PULSE_MIN.intensity.lerp(MERGE_PULSE_MAX.intensity, t),
+        duration: Duration::from_millis(min_ms.lerp(max_ms, t).round() as u64),
+    }
+}
+
+/// The stronger of two pulses.
+fn stronger(a: HapticPulse, b: HapticPulse) -> HapticPulse {
+    if b.intensity > a.intensity { b } else { a }
+}
+
+// ---------------------------------------------------------------------------
+// SystemParam
+// ---------------------------------------------------------------------------
+
+/// Plays [`HapticPulse`]s on every output the platform offers.
+#[derive(SystemParam)]
+pub struct Haptics<'w, 's> {
+    settings: Res<'w, SettingsResource>,
+    gamepads: Query<'w, 's, Entity, With<Gamepad>>,
+    rumble: MessageWriter<'w, GamepadRumbleRequest>,
+}
+
+impl Haptics<'_, '_> {
+    /// Vibrates with `pulse`, unless haptics are turned off in the settings.
+    pub fn play(&mut self, pulse: HapticPulse) {
+        if !self.settings.haptics_enabled {
+            return;
+        }
+        let intensity = GamepadRumbleIntensity {
+            strong_motor: pulse.intensity,
+            weak_motor: pulse.intensity,
+        };
+        for gamepad in self.gamepads.iter() {
+            self.rumble.write(GamepadRumbleRequest::Add {
+                duration: pulse.duration,
+                intensity,
+                gamepad,
+            });
+        }
+        platform::vibrate(pulse.duration);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Vibrates on drops and merges.
+///
+/// Several events in one frame play a single pulse — the strongest — since
+/// gamepad rumbles add up rather than replace each other.
+pub fn haptics_on_drop_and_merge(
+    mut dropped_events: MessageReader<FruitDroppedEvent>,
+    mut merge_events: MessageReader<FruitMergeEvent>,
+    mut haptics: Haptics,
+) {
+    let drop = (dropped_events.read().count() > 0).then_some(DROP_PULSE);
+    let pulse = merge_events
+        .read()
+        .map(|event| merge_pulse(event.fruit_type))
+        .chain(drop)
+        .reduce(stronger);
+    if let Some(pulse) = pulse {
+        haptics.play(pulse);
+    }
+}
+
+/// Vibrates when the game ends.
+pub fn haptics_on_game_over(mut haptics: Haptics) {
+    haptics.play(GAME_OVER_PULSE);
+}
+
+// ---------------------------------------------------------------------------
+// Platform output
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+mod platform {
+    use std::time::Duration;
+
+    /// Desktop builds have no device vibration; gamepads are enough.
+    pub fn vibrate(_duration: Duration) {}
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    use std::time::Duration;
+
+    pub fn vibrate(duration: Duration) {
+        if let Some(window) = web_sys::window() {
+            // `false` just means the browser or device cannot vibrate
+            let _ = window
+                .navigator()
+                .vibrate_with_duration(duration.as_millis() as u32);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_haptics_app(haptics_enabled: bool) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<FruitDroppedEvent>()
+            .add_message::<FruitMergeEvent>()
+            .add_message::<GamepadRumbleRequest>()
+            .insert_resource(SettingsResource {
+                haptics_enabled,
+                ..default()
+            })
+            .add_systems(Update, haptics_on_drop_and_merge);
+        app.world_mut().spawn(Gamepad::default());
+        app
+    }
+
+    fn rumbles(app: &App) -> Vec<(f32, Duration)> {
+        let messages = app.world().resource::<Messages<GamepadRumbleRequest>>();
+        messages
+            .iter_current_update_messages()
+            .filter_map(|request| match request {
+                GamepadRumbleRequest::Add {
+                    duration,
+                    intensity,
+                    ..
+                } => Some((intensity.strong_motor, *duration)),
+                GamepadRumbleRequest::Stop { .. } => None,
+            })
+            .collect()
+    }
+
+    fn merge(fruit_type: FruitType) -> FruitMergeEvent {
+        FruitMergeEvent {
+            entity1: Entity::PLACEHOLDER,
+            entity2: Entity::PLACEHOLDER,
+            fruit_type,
+            position: Vec2::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_merge_pulse_scales_with_stage() {
+        let cherry = merge_pulse(FruitType::Cherry);
+        let watermelon = merge_pulse(FruitType::Watermelon);
+        assert!((cherry.intensity - MERGE_PULSE_MIN.intensity).abs() < 1e-6);
+        assert!((watermelon.intensity - MERGE_PULSE_MAX.intensity).abs() < 1e-6);
+        assert_eq!(cherry.duration, MERGE_PULSE_MIN.duration);
+        assert_eq!(watermelon.duration, MERGE_PULSE_MAX.duration);
+
+        let mid = merge_pulse(FruitType::Peach);
+        assert!(mid.intensity > MERGE_PULSE_MIN.intensity);
+        assert!(mid.intensity < MERGE_PULSE_MAX.intensity);
+        assert!(mid.duration > MERGE_PULSE_MIN.duration);
+        assert!(mid.duration < MERGE_PULSE_MAX.duration);
+    }
+
+    #[test]
+    fn test_one_frame_plays_the_strongest_pulse() {
+        let mut app = setup_haptics_app(true);
+        app.world_mut().write_message(FruitDroppedEvent {
+            fruit_type: FruitType::Cherry,
+            position: Vec2::ZERO,
+        });
+        app.world_mut().write_message(merge(FruitType::Grape));
+        app.world_mut().write_message(merge(FruitType::Melon));
+        app.update();
+
+        let expected = merge_pulse(FruitType::Melon);
+        assert_eq!(rumbles(&app), vec![(expected.intensity, expected.duration)]);
+    }
+
+    #[test]
+    fn test_disabled_setting_plays_nothing() {
+        let mut app = setup_haptics_app(false);
+        app.world_mut().write_message(merge(FruitType::Melon));
+        app.update();
+
+        assert!(rumbles(&app).is_empty());
+    }
+}
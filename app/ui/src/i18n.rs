@@ -3,9 +3,43 @@
 //! All user-visible strings should pass through [`t`] so that switching
 //! [`Language`] at runtime immediately changes the UI text on the next
 //! screen rebuild.
+//!
+//! Layouts that depend on the reading direction use [`row_direction`], so a
+//! right-to-left language only needs [`Language::is_rtl`] to mirror them.
 
+use bevy::ui::FlexDirection;
+use suika_game_core::prelude::FruitType;
 use suika_game_core::resources::settings::Language;
 
+/// Direction for a row that starts with a label, such as a settings row:
+/// reversed for right-to-left languages so the label stays at the reading
+/// start.
+pub fn row_direction(lang: Language) -> FlexDirection {
+    if lang.is_rtl() {
+        FlexDirection::RowReverse
+    } else {
+        FlexDirection::Row
+    }
+}
+
+/// Returns the localised name of `fruit`.
+pub fn fruit_name(fruit: FruitType, lang: Language) -> &'static str {
+    let key = match fruit {
+        FruitType::Cherry => "fruit_cherry",
+        FruitType::Strawberry => "fruit_strawberry",
+        FruitType::Grape => "fruit_grape",
+        FruitType::Dekopon => "fruit_dekopon",
+        FruitType::Persimmon => "fruit_persimmon",
+        FruitType::Apple => "fruit_apple",
+        FruitType::Pear => "fruit_pear",
+        FruitType::Peach => "fruit_peach",
+        FruitType::Pineapple => "fruit_pineapple",
+        FruitType::Melon => "fruit_melon",
+        FruitType::Watermelon => "fruit_watermelon",
+    };
+    t(key, lang)
+}
+
 /// Returns the localised string for the given key and language.
 ///
 /// Falls back to `key` itself when a translation is missing, so new keys
@@ -18,14 +52,60 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         // ── Title screen ──────────────────────────────────────────────────
         ("game_title", Language::Japanese) => "スイカゲーム",
         ("game_title", Language::English) => "Suika Game",
+        ("loading", Language::Japanese) => "読み込み中…",
+        ("loading", Language::English) => "Loading…",
         ("btn_start", Language::Japanese) => "スタート",
         ("btn_start", Language::English) => "Start",
+        ("btn_continue", Language::Japanese) => "つづきから",
+        ("btn_continue", Language::English) => "Continue",
+        ("btn_new_game", Language::Japanese) => "はじめから",
+        ("btn_new_game", Language::English) => "New Game",
         ("btn_settings", Language::Japanese) => "設定",
         ("btn_settings", Language::English) => "Settings",
         ("btn_how_to_play", Language::Japanese) => "遊び方",
         ("btn_how_to_play", Language::English) => "Guide",
+        ("btn_sandbox", Language::Japanese) => "サンドボックス",
+        ("btn_sandbox", Language::English) => "Sandbox",
+        ("btn_stats", Language::Japanese) => "記録",
+        ("btn_stats", Language::English) => "Stats",
+        ("config_fallback_warning", Language::Japanese) => {
+            "設定ファイルを読み込めなかったため、標準設定で起動しました"
+        }
+        ("config_fallback_warning", Language::English) => {
+            "Some config files failed to load; using built-in defaults"
+        }
+        ("asset_load_warning", Language::Japanese) => "読み込めなかったファイルがあります",
+        ("asset_load_warning", Language::English) => "Some files failed to load",
         ("highscore", Language::Japanese) => "ハイスコア",
         ("highscore", Language::English) => "Best Score",
+        ("update_available", Language::Japanese) => "新しいバージョンがあります:",
+        ("update_available", Language::English) => "New version available:",
+        ("seed_code_placeholder", Language::Japanese) => "シードコードを入力",
+        ("seed_code_placeholder", Language::English) => "Enter a seed code",
+        ("seed_code_hint", Language::Japanese) => {
+            "クリックまたはTabで入力、Enterで決定 — 友達と同じフルーツの順番で遊べます"
+        }
+        ("seed_code_hint", Language::English) => {
+            "Click or press Tab, then Enter — play a friend's exact fruit sequence"
+        }
+        ("seed_code_applied", Language::Japanese) => "シードコード適用:",
+        ("seed_code_applied", Language::English) => "Seed code active:",
+        ("seed_code_difficulty", Language::Japanese) => "難易度",
+        ("seed_code_difficulty", Language::English) => "difficulty",
+        ("seed_code_cleared", Language::Japanese) => "シードコードを解除しました",
+        ("seed_code_cleared", Language::English) => "Seed code cleared",
+        ("seed_code_invalid", Language::Japanese) => "無効なシードコード",
+        ("seed_code_invalid", Language::English) => "Invalid seed code",
+        ("profile", Language::Japanese) => "プロフィール",
+        ("profile", Language::English) => "Profile",
+        ("profile_default", Language::Japanese) => "プレイヤー1",
+        ("profile_default", Language::English) => "Player 1",
+        ("btn_new_profile", Language::Japanese) => "新規",
+        ("btn_new_profile", Language::English) => "New",
+        ("attract_demo", Language::Japanese) => "デモプレイ",
+        ("attract_demo", Language::English) => "Demo",
+        ("attract_press_any_key", Language::Japanese) => "なにかキーを押してください",
+        ("attract_press_any_key", Language::English) => "Press any key",
 
         // ── Settings screen ───────────────────────────────────────────────
         ("settings_title", Language::Japanese) => "設定",
@@ -36,12 +116,66 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         ("label_sfx", Language::English) => "SFX Volume",
         ("label_effects", Language::Japanese) => "エフェクト",
         ("label_effects", Language::English) => "Effects",
+        ("label_merge_ghost", Language::Japanese) => "合体ガイド",
+        ("label_merge_ghost", Language::English) => "Merge Guide",
+        ("label_landing_preview", Language::Japanese) => "落下地点",
+        ("label_landing_preview", Language::English) => "Landing Preview",
+        ("label_haptics", Language::Japanese) => "振動",
+        ("label_haptics", Language::English) => "Vibration",
+        ("label_vsync", Language::Japanese) => "垂直同期",
+        ("label_vsync", Language::English) => "VSync",
+        ("label_fps_cap", Language::Japanese) => "FPS上限",
+        ("label_fps_cap", Language::English) => "FPS cap",
+        ("value_unlimited", Language::Japanese) => "無制限",
+        ("value_unlimited", Language::English) => "Unlimited",
+        ("label_sweep_assist", Language::Japanese) => "片手モード",
+        ("label_sweep_assist", Language::English) => "One-Button",
+        ("label_auto_drop", Language::Japanese) => "自動落下",
+        ("label_auto_drop", Language::English) => "Auto Drop",
+        ("unit_seconds", Language::Japanese) => "秒",
+        ("unit_seconds", Language::English) => " s",
+        ("label_announcements", Language::Japanese) => "読み上げ",
+        ("label_announcements", Language::English) => "Announcements",
+        ("label_full_width_digits", Language::Japanese) => "全角数字",
+        ("label_full_width_digits", Language::English) => "Full-width Digits",
+        ("label_ghost_run", Language::Japanese) => "ゴースト",
+        ("label_ghost_run", Language::English) => "Ghost Run",
+        ("label_audio_visualizer", Language::Japanese) => "BGMバー",
+        ("label_audio_visualizer", Language::English) => "Music Bars",
+        ("label_touch_controls", Language::Japanese) => "タッチ操作",
+        ("label_touch_controls", Language::English) => "Touch Controls",
+        ("label_sfx_latency", Language::Japanese) => "SE遅延",
+        ("label_sfx_latency", Language::English) => "SFX Delay",
+        ("label_effect_details", Language::Japanese) => "演出の詳細",
+        ("label_effect_details", Language::English) => "Effect Details",
+        ("btn_adjust", Language::Japanese) => "調整",
+        ("btn_adjust", Language::English) => "Adjust",
+        ("label_streamer_safe", Language::Japanese) => "配信モード",
+        ("label_streamer_safe", Language::English) => "Streamer Mode",
+        ("label_settings_pin", Language::Japanese) => "設定PIN",
+        ("label_settings_pin", Language::English) => "Settings PIN",
+        ("pin_enter_title", Language::Japanese) => "PINを入力してください",
+        ("pin_enter_title", Language::English) => "Enter PIN",
+        ("pin_set_title", Language::Japanese) => "新しいPIN（数字4桁）",
+        ("pin_set_title", Language::English) => "New PIN (4 digits)",
+        ("pin_wrong", Language::Japanese) => "PINが違います",
+        ("pin_wrong", Language::English) => "Wrong PIN",
         ("label_language", Language::Japanese) => "言語",
         ("label_language", Language::English) => "Language",
         ("value_on", Language::Japanese) => "ON",
         ("value_on", Language::English) => "ON",
         ("value_off", Language::Japanese) => "OFF",
         ("value_off", Language::English) => "OFF",
+        ("label_history", Language::Japanese) => "プレイ履歴",
+        ("label_history", Language::English) => "History",
+        ("btn_export", Language::Japanese) => "書き出す",
+        ("btn_export", Language::English) => "Export",
+        ("history_exported", Language::Japanese) => "プレイ履歴を書き出しました:",
+        ("history_exported", Language::English) => "History exported to",
+        ("history_exported_private", Language::Japanese) => "プレイ履歴を書き出しました",
+        ("history_exported_private", Language::English) => "History exported",
+        ("mods_restart_hint", Language::Japanese) => "MODの切り替えは次回起動時に反映されます",
+        ("mods_restart_hint", Language::English) => "Mod changes apply on the next launch",
         ("lang_japanese", Language::Japanese) => "日本語",
         ("lang_japanese", Language::English) => "Japanese",
         ("lang_english", Language::Japanese) => "English",
@@ -50,6 +184,18 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         ("btn_back", Language::English) => "Back",
         ("btn_quit", Language::Japanese) => "終了",
         ("btn_quit", Language::English) => "Quit",
+        ("quit_confirm_title", Language::Japanese) => "ゲームを終了しますか？",
+        ("quit_confirm_title", Language::English) => "Quit the game?",
+        ("quit_confirm_message", Language::Japanese) => "設定と記録は保存されています。",
+        ("quit_confirm_message", Language::English) => "Your settings and records are saved.",
+
+        // ── Dialogs ───────────────────────────────────────────────────────
+        ("btn_cancel", Language::Japanese) => "キャンセル",
+        ("btn_cancel", Language::English) => "Cancel",
+        ("btn_ok", Language::Japanese) => "OK",
+        ("btn_ok", Language::English) => "OK",
+        ("save_error_title", Language::Japanese) => "保存できませんでした",
+        ("save_error_title", Language::English) => "Could not save",
 
         // ── How to play screen ────────────────────────────────────────────
         ("how_to_play_title", Language::Japanese) => "遊び方",
@@ -77,6 +223,34 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         ("htp_gameover_body", Language::Japanese) => "フルーツが境界ラインを超えたら終了",
         ("htp_gameover_body", Language::English) => "Game ends when fruits exceed the boundary",
 
+        // ── Stats screen ──────────────────────────────────────────────────
+        ("effects_settings_title", Language::Japanese) => "演出の設定",
+        ("effects_settings_title", Language::English) => "Effect Settings",
+        ("label_droplet_effects", Language::Japanese) => "水しぶき",
+        ("label_droplet_effects", Language::English) => "Droplets",
+        ("label_flash_effects", Language::Japanese) => "フラッシュ",
+        ("label_flash_effects", Language::English) => "Flashes",
+        ("label_shake_effects", Language::Japanese) => "画面の揺れ",
+        ("label_shake_effects", Language::English) => "Screen Shake",
+        ("effects_off_hint", Language::Japanese) => "エフェクトがOFFのため表示されません",
+        ("effects_off_hint", Language::English) => "Effects are OFF, so none of these show",
+        ("calibration_title", Language::Japanese) => "音ズレ調整",
+        ("calibration_title", Language::English) => "Audio Calibration",
+        ("calibration_hint", Language::Japanese) => "音と光が同時になるように調整してください",
+        ("calibration_hint", Language::English) => "Adjust until the click and the flash line up",
+        ("stats_title", Language::Japanese) => "これまでの記録",
+        ("stats_title", Language::English) => "Lifetime Stats",
+        ("stats_games_played", Language::Japanese) => "プレイ回数",
+        ("stats_games_played", Language::English) => "Games Played",
+        ("stats_total_playtime", Language::Japanese) => "総プレイ時間",
+        ("stats_total_playtime", Language::English) => "Total Play Time",
+        ("stats_average_score", Language::Japanese) => "平均スコア",
+        ("stats_average_score", Language::English) => "Average Score",
+        ("stats_merges", Language::Japanese) => "フルーツ別の合体数",
+        ("stats_merges", Language::English) => "Merges per Fruit",
+        ("stats_distribution", Language::Japanese) => "スコア分布",
+        ("stats_distribution", Language::English) => "Score Distribution",
+
         // ── Pause menu ────────────────────────────────────────────────────
         ("pause_title", Language::Japanese) => "ポーズ",
         ("pause_title", Language::English) => "PAUSED",
@@ -94,8 +268,30 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         ("new_record", Language::English) => "NEW RECORD!",
         ("elapsed_time", Language::Japanese) => "プレイ時間",
         ("elapsed_time", Language::English) => "Play Time",
+        ("drops", Language::Japanese) => "ドロップ数",
+        ("drops", Language::English) => "Drops",
+        ("points_per_drop", Language::Japanese) => "1ドロップあたりの得点",
+        ("points_per_drop", Language::English) => "Points per Drop",
+        ("btn_retry_same_seed", Language::Japanese) => "同じ順番で",
+        ("btn_retry_same_seed", Language::English) => "Same Seed",
+        ("btn_copy_result", Language::Japanese) => "結果をコピー",
+        ("btn_copy_result", Language::English) => "Copy Result",
+        ("best_combo", Language::Japanese) => "最大コンボ",
+        ("best_combo", Language::English) => "Best Combo",
+        ("result_copied", Language::Japanese) => "結果をクリップボードにコピーしました",
+        ("result_copied", Language::English) => "Result copied to clipboard",
+        ("result_copy_failed", Language::Japanese) => "コピーできませんでした",
+        ("result_copy_failed", Language::English) => "Could not copy result",
+        ("seed_code", Language::Japanese) => "シードコード",
+        ("seed_code", Language::English) => "Seed Code",
+        ("score_graph", Language::Japanese) => "スコア推移",
+        ("score_graph", Language::English) => "Score Over Time",
         ("btn_retry", Language::Japanese) => "もう一度",
         ("btn_retry", Language::English) => "Retry",
+        ("btn_view_board", Language::Japanese) => "盤面を見る",
+        ("btn_view_board", Language::English) => "View Board",
+        ("board_view_hint", Language::Japanese) => "何かキーを押すと戻ります",
+        ("board_view_hint", Language::English) => "Press any key to return",
 
         // ── HUD (in-game overlay) ─────────────────────────────────────────
         ("hud_best_score", Language::Japanese) => "ベストスコア",
@@ -104,6 +300,64 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         ("hud_score", Language::English) => "Score",
         ("hud_next", Language::Japanese) => "ネクスト",
         ("hud_next", Language::English) => "Next",
+        ("hud_largest_fruit", Language::Japanese) => "最大フルーツ",
+        ("hud_largest_fruit", Language::English) => "Best Fruit",
+        ("hud_combo", Language::Japanese) => "コンボ",
+        ("hud_combo", Language::English) => "Combo",
+        ("hud_melon_hint", Language::Japanese) => "スイカまであと一歩！",
+        ("hud_melon_hint", Language::English) => "Watermelon within reach!",
+        ("hud_drops", Language::Japanese) => "ドロップ",
+        ("hud_drops", Language::English) => "Drops",
+        ("hud_efficiency", Language::Japanese) => "効率",
+        ("hud_efficiency", Language::English) => "Pts/Drop",
+        ("hud_vs_best", Language::Japanese) => "ベスト比",
+        ("hud_vs_best", Language::English) => "vs Best",
+        ("onboarding_move", Language::Japanese) => "← → / A D / マウス：移動",
+        ("onboarding_move", Language::English) => "← → / A D / Mouse: Move",
+        ("onboarding_drop", Language::Japanese) => "Space / クリック：落とす",
+        ("onboarding_drop", Language::English) => "Space / Click: Drop",
+        ("onboarding_boundary", Language::Japanese) => "赤い線より上に積むとゲームオーバー",
+        ("onboarding_boundary", Language::English) => "Stack above the red line and it's game over",
+        ("onboarding_dismiss", Language::Japanese) => "H：ヒントを閉じる",
+        ("onboarding_dismiss", Language::English) => "H: Hide hints",
+        ("sandbox_title", Language::Japanese) => "サンドボックス",
+        ("sandbox_title", Language::English) => "SANDBOX",
+        ("sandbox_fruit_keys", Language::Japanese) => "1〜9：フルーツを選ぶ",
+        ("sandbox_fruit_keys", Language::English) => "1–9: Choose fruit",
+        ("sandbox_gravity", Language::Japanese) => "G：重力",
+        ("sandbox_gravity", Language::English) => "G: Gravity",
+        ("sandbox_boundary", Language::Japanese) => "B：境界ライン",
+        ("sandbox_boundary", Language::English) => "B: Boundary",
+
+        // ── Fruit names ───────────────────────────────────────────────────
+        ("fruit_cherry", Language::Japanese) => "さくらんぼ",
+        ("fruit_cherry", Language::English) => "Cherry",
+        ("fruit_strawberry", Language::Japanese) => "いちご",
+        ("fruit_strawberry", Language::English) => "Strawberry",
+        ("fruit_grape", Language::Japanese) => "ぶどう",
+        ("fruit_grape", Language::English) => "Grape",
+        ("fruit_dekopon", Language::Japanese) => "デコポン",
+        ("fruit_dekopon", Language::English) => "Dekopon",
+        ("fruit_persimmon", Language::Japanese) => "かき",
+        ("fruit_persimmon", Language::English) => "Persimmon",
+        ("fruit_apple", Language::Japanese) => "りんご",
+        ("fruit_apple", Language::English) => "Apple",
+        ("fruit_pear", Language::Japanese) => "なし",
+        ("fruit_pear", Language::English) => "Pear",
+        ("fruit_peach", Language::Japanese) => "もも",
+        ("fruit_peach", Language::English) => "Peach",
+        ("fruit_pineapple", Language::Japanese) => "パイナップル",
+        ("fruit_pineapple", Language::English) => "Pineapple",
+        ("fruit_melon", Language::Japanese) => "メロン",
+        ("fruit_melon", Language::English) => "Melon",
+        ("fruit_watermelon", Language::Japanese) => "スイカ",
+        ("fruit_watermelon", Language::English) => "Watermelon",
+
+        // ── Spoken announcements ──────────────────────────────────────────
+        ("announce_warning", Language::Japanese) => "危険！フルーツが線を越えています",
+        ("announce_warning", Language::English) => "Warning! Fruit above the line",
+        ("announce_game_over", Language::Japanese) => "ゲームオーバー",
+        ("announce_game_over", Language::English) => "Game over",
 
         // ── Fallback ──────────────────────────────────────────────────────
         _ => key,
@@ -140,21 +394,73 @@ mod tests {
         let keys = [
             // Title
             "game_title",
+            "loading",
             "btn_start",
+            "btn_continue",
+            "btn_new_game",
             "btn_settings",
             "btn_how_to_play",
+            "btn_sandbox",
+            "btn_stats",
+            "config_fallback_warning",
+            "asset_load_warning",
             "highscore",
+            "update_available",
+            "seed_code_placeholder",
+            "seed_code_hint",
+            "seed_code_applied",
+            "seed_code_difficulty",
+            "seed_code_cleared",
+            "seed_code_invalid",
+            "profile",
+            "profile_default",
+            "btn_new_profile",
+            "attract_demo",
+            "attract_press_any_key",
             "btn_quit",
+            "quit_confirm_title",
+            "quit_confirm_message",
+            // Dialogs
+            "btn_cancel",
+            "btn_ok",
+            "save_error_title",
             // Settings
             "settings_title",
             "label_bgm",
             "label_sfx",
             "label_effects",
+            "label_merge_ghost",
+            "label_landing_preview",
+            "label_haptics",
+            "label_vsync",
+            "label_fps_cap",
+            "value_unlimited",
+            "label_sweep_assist",
+            "label_auto_drop",
+            "unit_seconds",
+            "label_announcements",
+            "label_full_width_digits",
+            "label_ghost_run",
+            "label_audio_visualizer",
+            "label_touch_controls",
+            "label_sfx_latency",
+            "label_effect_details",
+            "btn_adjust",
+            "label_streamer_safe",
+            "label_settings_pin",
+            "pin_enter_title",
+            "pin_set_title",
+            "pin_wrong",
             "label_language",
             "value_on",
             "value_off",
             "lang_japanese",
             "lang_english",
+            "label_history",
+            "btn_export",
+            "history_exported",
+            "history_exported_private",
+            "mods_restart_hint",
             "btn_back",
             // How to play
             "how_to_play_title",
@@ -166,6 +472,22 @@ mod tests {
             "htp_evolve_body",
             "htp_gameover_title",
             "htp_gameover_body",
+            // Effect settings
+            "effects_settings_title",
+            "label_droplet_effects",
+            "label_flash_effects",
+            "label_shake_effects",
+            "effects_off_hint",
+            // Audio calibration
+            "calibration_title",
+            "calibration_hint",
+            // Stats
+            "stats_title",
+            "stats_games_played",
+            "stats_total_playtime",
+            "stats_average_score",
+            "stats_merges",
+            "stats_distribution",
             // Pause
             "pause_title",
             "btn_resume",
@@ -176,11 +498,39 @@ mod tests {
             "new_record",
             "highscore",
             "elapsed_time",
+            "drops",
+            "points_per_drop",
+            "seed_code",
+            "score_graph",
             "btn_retry",
+            "btn_retry_same_seed",
+            "btn_copy_result",
+            "best_combo",
+            "result_copied",
+            "result_copy_failed",
+            "btn_view_board",
+            "board_view_hint",
             // HUD
             "hud_best_score",
             "hud_score",
             "hud_next",
+            "hud_largest_fruit",
+            "hud_combo",
+            "hud_melon_hint",
+            "hud_drops",
+            "hud_efficiency",
+            "hud_vs_best",
+            "onboarding_move",
+            "onboarding_drop",
+            "onboarding_boundary",
+            "onboarding_dismiss",
+            "sandbox_title",
+            "sandbox_fruit_keys",
+            "sandbox_gravity",
+            "sandbox_boundary",
+            // Announcements
+            "announce_warning",
+            "announce_game_over",
         ];
         for key in &keys {
             assert!(
@@ -191,6 +541,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_every_fruit_has_a_name() {
+        for fruit in std::iter::successors(Some(FruitType::Cherry), FruitType::next) {
+            for lang in [Language::Japanese, Language::English] {
+                let name = fruit_name(fruit, lang);
+                assert!(
+                    !name.starts_with("fruit_"),
+                    "{fruit:?} has no {lang:?} name"
+                );
+            }
+        }
+        assert_eq!(
+            fruit_name(FruitType::Watermelon, Language::Japanese),
+            "スイカ"
+        );
+    }
+
     #[test]
     fn test_languages_differ_for_distinguishable_keys() {
         assert_ne!(
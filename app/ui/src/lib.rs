@@ -3,14 +3,31 @@
 //! ユーザーインターフェース：画面実装、UIコンポーネント、スタイル
 
 use bevy::prelude::*;
-use suika_game_core::prelude::{AppState, GameOverSet};
+use suika_game_core::prelude::{AppState, GameOverSet, screen_transition_idle};
+use suika_game_core::systems::attract::attract_active;
 
+pub mod announcements;
 pub mod camera;
+pub mod clipboard;
 pub mod components;
 pub mod config;
+pub mod danger_line;
+pub mod digit_text;
+pub mod focus;
+pub mod haptics;
 pub mod i18n;
+pub mod number_format;
+pub mod pause_blur;
+pub mod perf_overlay;
+pub mod safe_mode;
 pub mod screens;
+pub mod spawn_overlay;
+pub mod spectator;
 pub mod styles;
+pub mod toast;
+pub mod transition;
+#[cfg(feature = "update-check")]
+pub mod update_check;
 
 /// UIプラグイン
 pub struct GameUIPlugin;
@@ -25,10 +42,197 @@ impl Plugin for GameUIPlugin {
         // Background color comes from the UI style palette
         app.insert_resource(ClearColor(styles::BG_COLOR));
 
+        // Blurred, darkened board behind the pause and game-over menus
+        app.add_plugins(pause_blur::PauseBlurPlugin);
+
+        // Animated dashed danger line in place of the plain boundary sprite
+        app.add_plugins(danger_line::DangerLinePlugin);
+
+        // Performance overlay (FPS / frame-time graph / entity count)
+        perf_overlay::add_required_diagnostics(app);
+        app.init_resource::<perf_overlay::PerfOverlayVisible>()
+            .register_type::<perf_overlay::PerfOverlayVisible>()
+            .add_systems(Startup, perf_overlay::spawn_perf_overlay)
+            .add_systems(
+                Update,
+                (
+                    perf_overlay::toggle_perf_overlay,
+                    perf_overlay::update_perf_overlay,
+                )
+                    .chain(),
+            );
+
+        // Spawn-probability overlay (configured weights vs. observed drops)
+        app.init_resource::<spawn_overlay::SpawnOverlayVisible>()
+            .init_resource::<spawn_overlay::SpawnTally>()
+            .register_type::<spawn_overlay::SpawnOverlayVisible>()
+            .add_systems(Startup, spawn_overlay::spawn_spawn_overlay)
+            .add_systems(
+                Update,
+                (
+                    spawn_overlay::tally_spawns,
+                    spawn_overlay::toggle_spawn_overlay,
+                    spawn_overlay::update_spawn_overlay,
+                )
+                    .chain(),
+            );
+
+        // Toasts; config / asset errors from core show up as error toasts,
+        // save errors as a dialog (or a toast during play)
+        app.add_message::<toast::ShowToast>()
+            .add_message::<suika_game_core::prelude::SaveErrorEvent>()
+            .add_message::<suika_game_core::prelude::ConfigErrorEvent>()
+            .add_message::<suika_game_core::prelude::AssetErrorEvent>()
+            .add_systems(Startup, toast::spawn_toast_stack)
+            .add_systems(
+                Update,
+                (
+                    toast::toast_persistence_errors,
+                    toast::report_save_errors,
+                    toast::spawn_toasts,
+                    toast::update_toasts,
+                )
+                    .chain(),
+            );
+
+        // Confirm config hot-reloads on screen while developing
+        #[cfg(debug_assertions)]
+        app.add_message::<suika_game_core::prelude::ConfigReloadedEvent>()
+            .add_systems(
+                Update,
+                toast::toast_config_reloads.before(toast::spawn_toasts),
+            );
+
+        // Optional startup check for a newer release (toast only), skipped
+        // in streamer-safe mode
+        #[cfg(feature = "update-check")]
+        app.init_resource::<update_check::UpdateCheck>()
+            .add_systems(
+                Startup,
+                update_check::start_update_check
+                    .run_if(suika_game_core::systems::safe_mode::online_allowed),
+            )
+            .add_systems(
+                Update,
+                update_check::poll_update_check.before(toast::spawn_toasts),
+            );
+
+        // Cover drawn while a screen transition runs
+        app.add_systems(Startup, transition::spawn_transition_cover)
+            .add_systems(Update, transition::update_transition_cover);
+
+        // Vibration on drops, merges and game over (gamepads / web devices);
+        // the attract demo stays still
+        app.add_systems(
+            Update,
+            haptics::haptics_on_drop_and_merge
+                .run_if(in_state(AppState::Playing))
+                .run_if(not(attract_active)),
+        )
+        .add_systems(
+            OnEnter(AppState::GameOver),
+            haptics::haptics_on_game_over.run_if(not(attract_active)),
+        );
+
+        // Spoken announcements (SettingsResource::announcements)
+        app.add_message::<announcements::Announce>()
+            .init_non_send_resource::<announcements::Speaker>()
+            .add_systems(
+                Update,
+                (
+                    announcements::announce_score_milestones,
+                    announcements::announce_warning,
+                )
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                announcements::announce_game_over,
+            )
+            .add_systems(
+                Update,
+                (
+                    announcements::announce_menu_focus.after(focus::update_focus_ring),
+                    announcements::speak_announcements,
+                )
+                    .chain(),
+            );
+
+        // Free camera while spectating a replay or network game
+        app.init_resource::<spectator::SpectatorCamera>()
+            .add_systems(
+                Update,
+                (
+                    spectator::toggle_free_camera.run_if(not(attract_active)),
+                    spectator::drive_free_camera,
+                    spectator::apply_spectator_camera,
+                )
+                    .chain()
+                    .after(suika_game_core::systems::effects::shake::apply_camera_shake)
+                    .after(suika_game_core::systems::effects::punch::apply_camera_punch),
+            );
+
         app.add_systems(Startup, camera::setup_camera)
+            // Where the container sits on screen, for HUD alignment
+            .init_resource::<camera::ContainerScreenRect>()
+            .add_systems(Update, camera::update_container_screen_rect)
+            // Never let the window shrink below the container
+            .add_systems(Update, camera::apply_min_window_size)
+            // Single-column HUD when there's no room beside the container
+            .init_resource::<screens::hud::HudCompact>()
+            .add_systems(
+                Update,
+                screens::hud::update_hud_compact.after(camera::update_container_screen_rect),
+            )
             .init_resource::<components::KeyboardFocusIndex>()
+            .init_resource::<focus::ActiveFocusGroup>()
+            .init_resource::<components::modal::ModalFocus>()
+            .init_resource::<components::TextInputActive>()
+            // Clicks that land on the UI never reach gameplay input; the
+            // touch strip's buttons feed it instead
+            .add_systems(
+                PreUpdate,
+                (
+                    components::update_pointer_over_ui,
+                    screens::hud::touch_controls::press_touch_controls,
+                )
+                    .after(bevy::ui::UiSystems::Focus)
+                    .before(suika_game_core::systems::action::collect_input_actions),
+            )
+            // Filled by the audio crate's BGM meter
+            .init_resource::<screens::hud::visualizer::BgmLevels>()
+            // Finished score popups kept for reuse
+            .init_resource::<screens::hud::score_popup::ScorePopupPool>()
+            // Loading screen, then a fade into the title screen
+            .add_systems(
+                OnEnter(AppState::Loading),
+                screens::loading::setup_loading_screen,
+            )
+            .add_systems(
+                OnExit(AppState::Loading),
+                screens::loading::start_loading_fade,
+            )
+            .add_systems(
+                Update,
+                (
+                    screens::loading::update_loading_screen.run_if(in_state(AppState::Loading)),
+                    screens::loading::update_loading_fade,
+                ),
+            )
             // Title screen
             .add_systems(OnEnter(AppState::Title), screens::title::setup_title_screen)
+            .add_systems(OnExit(AppState::Title), screens::title::end_seed_code_input)
+            // Seed code field — consumes its Enter press before keyboard
+            // navigation can treat it as a menu confirm.
+            .add_systems(
+                Update,
+                (
+                    screens::title::edit_seed_code
+                        .before(components::handle_keyboard_menu_navigation),
+                    screens::title::update_seed_code_field.after(screens::title::edit_seed_code),
+                )
+                    .run_if(in_state(AppState::Title)),
+            )
             // Settings screen
             .add_systems(
                 OnEnter(AppState::Settings),
@@ -38,15 +242,52 @@ impl Plugin for GameUIPlugin {
                 Update,
                 (
                     screens::settings::update_settings_display,
+                    screens::settings::update_mod_display,
+                    screens::settings::update_hud_option_display,
+                    screens::settings::update_landing_preview_display,
+                    screens::settings::update_touch_controls_display,
+                    screens::settings::update_safe_mode_display,
                     screens::settings::update_translatable_texts,
+                    screens::settings::scroll_settings_with_wheel,
+                    screens::settings::scroll_settings_to_focus
+                        .after(components::handle_keyboard_menu_navigation),
                 )
                     .run_if(in_state(AppState::Settings)),
             )
+            // Effects settings page
+            .add_systems(
+                OnEnter(AppState::EffectsSettings),
+                screens::effects_settings::setup_effects_settings_screen,
+            )
+            .add_systems(
+                Update,
+                screens::effects_settings::update_effects_settings_display
+                    .run_if(in_state(AppState::EffectsSettings)),
+            )
+            // Audio calibration screen
+            .init_resource::<screens::calibration::Metronome>()
+            .add_message::<screens::calibration::MetronomeBeat>()
+            .add_systems(
+                OnEnter(AppState::AudioCalibration),
+                screens::calibration::setup_calibration_screen,
+            )
+            .add_systems(
+                Update,
+                (
+                    screens::calibration::tick_metronome,
+                    screens::calibration::update_beat_indicator
+                        .after(screens::calibration::tick_metronome),
+                    screens::calibration::update_latency_display,
+                )
+                    .run_if(in_state(AppState::AudioCalibration)),
+            )
             // How-to-play screen
             .add_systems(
                 OnEnter(AppState::HowToPlay),
                 screens::how_to_play::setup_how_to_play_screen,
             )
+            // Stats screen
+            .add_systems(OnEnter(AppState::Stats), screens::stats::setup_stats_screen)
             // HUD: spawn layout on enter Playing, run widget updates each frame
             .add_systems(OnEnter(AppState::Playing), screens::hud::setup_hud)
             .add_systems(
@@ -54,9 +295,26 @@ impl Plugin for GameUIPlugin {
                 (
                     screens::hud::best_score::update_best_score,
                     screens::hud::score::update_score,
+                    digit_text::update_digit_text.after(screens::hud::score::update_score),
                     screens::hud::score::animate_score_pulse
                         .after(screens::hud::score::update_score),
                     screens::hud::next::update_next,
+                    screens::hud::largest_fruit::update_largest_fruit,
+                    screens::hud::drops::update_drops,
+                    screens::hud::drops::update_pace,
+                    screens::hud::visualizer::update_visualizer,
+                    screens::hud::combo::update_combo
+                        .after(suika_game_core::systems::score::tick_combo_timer),
+                    screens::hud::largest_fruit::animate_largest_fruit_pulse
+                        .after(screens::hud::largest_fruit::update_largest_fruit),
+                    screens::hud::onboarding::update_onboarding_hints,
+                    screens::hud::melon_hint::update_melon_hint
+                        .after(suika_game_core::systems::melon_link::detect_melon_pair),
+                    screens::hud::sandbox::update_sandbox_widget,
+                    screens::hud::danger_vignette::update_danger_vignette
+                        .after(camera::update_container_screen_rect),
+                    screens::hud::align_hud_to_container
+                        .after(camera::update_container_screen_rect),
                     screens::hud::score_popup::spawn_score_popups,
                     screens::hud::score_popup::update_score_popups,
                 )
@@ -66,18 +324,48 @@ impl Plugin for GameUIPlugin {
             // that GameState::is_new_record and highscore are up-to-date.
             .add_systems(
                 OnEnter(AppState::GameOver),
-                screens::game_over::setup_game_over_screen.after(GameOverSet::SaveHighscore),
+                screens::game_over::setup_game_over_screen
+                    .after(GameOverSet::SaveHighscore)
+                    .run_if(not(attract_active)),
+            )
+            // View-board toggle — consumes the restoring key press before
+            // keyboard navigation can treat it as a menu confirm.
+            .init_resource::<screens::game_over::BoardViewActive>()
+            .add_systems(
+                Update,
+                screens::game_over::toggle_board_view
+                    .before(components::handle_keyboard_menu_navigation)
+                    .run_if(in_state(AppState::GameOver)),
             )
             // Pause menu
             .add_systems(OnEnter(AppState::Paused), screens::pause::setup_pause_menu)
-            // ESC toggles Playing ↔ Paused (runs every frame, ignores other states)
-            .add_systems(Update, screens::pause::toggle_pause)
+            // ESC toggles Playing ↔ Paused (runs every frame, ignores other
+            // states, screen transitions and attract-mode demos)
+            .add_systems(
+                Update,
+                screens::pause::toggle_pause
+                    .run_if(screen_transition_idle)
+                    .run_if(not(attract_active)),
+            )
+            // Attract-mode overlay over the bot-played demo
+            .add_systems(
+                OnEnter(AppState::Playing),
+                screens::attract::setup_attract_overlay.run_if(attract_active),
+            )
+            .add_systems(
+                Update,
+                screens::attract::blink_attract_prompt.run_if(in_state(AppState::Playing)),
+            )
             // Button interaction (all states)
             .add_systems(
                 Update,
                 (
                     components::handle_button_interaction,
+                    components::modal::sync_modal_focus
+                        .before(components::handle_keyboard_menu_navigation),
                     components::handle_keyboard_menu_navigation,
+                    safe_mode::handle_pin_input,
+                    focus::update_focus_ring.after(components::handle_keyboard_menu_navigation),
                 ),
             );
     }
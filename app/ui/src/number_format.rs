@@ -0,0 +1,153 @@
+//! Language-aware number formatting for scores and counts.
+//!
+//! Every score the UI shows (HUD, score popups, title, game over, stats)
+//! goes through [`format_score`], so digits and separators follow the
+//! player's [`SettingsResource`]:
+//!
+//! | Language | Default     | Full-width digits |
+//! |----------|-------------|-------------------|
+//! | Japanese | `1,234,567` | `１，２３４，５６７` |
+//! | English  | `1,234,567` | `1,234,567`       |
+//!
+//! Full-width digits only apply to Japanese; English always uses ASCII.
+
+use suika_game_core::resources::settings::{Language, SettingsResource};
+
+/// Formats a score with the player's language and digit settings.
+pub fn format_score(n: u32, settings: &SettingsResource) -> String {
+    format_number(n, settings.language, settings.full_width_digits)
+}
+
+/// Formats `n` with the thousands separator of `lang`.
+///
+/// `full_width` switches Japanese to full-width digits and separator; it
+/// has no effect on other languages.
+///
+/// # Examples
+///
+/// ```
+/// # use suika_game_core::resources::settings::Language;
+/// # use suika_game_ui::number_format::format_number;
+/// assert_eq!(format_number(0,       Language::English, false), "0");
+/// assert_eq!(format_number(1000,    Language::English, false), "1,000");
+/// assert_eq!(format_number(1234567, Language::Japanese, false), "1,234,567");
+/// assert_eq!(format_number(1234,    Language::Japanese, true), "１，２３４");
+/// ```
+pub fn format_number(n: u32, lang: Language, full_width: bool) -> String {
+    let (glyphs, separator) = number_glyphs(lang, full_width);
+
+    let digits = n.to_string();
+    let mut result = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            result.push(separator);
+        }
+        result.push(c.to_digit(10).map_or(c, |d| glyphs[d as usize]));
+    }
+    result
+}
+
+/// The ten digit characters (`0`–`9`) and the thousands separator that
+/// [`format_number`] uses for `lang`.
+///
+/// Shared with [`digit_text`](crate::digit_text), which pre-shapes these
+/// glyphs once instead of formatting a string.
+pub fn number_glyphs(lang: Language, full_width: bool) -> ([char; 10], char) {
+    let full_width = full_width && lang == Language::Japanese;
+    let separator = if full_width {
+        '，'
+    } else {
+        group_separator(lang)
+    };
+    let digits = std::array::from_fn(|d| {
+        let c = char::from(b'0' + d as u8);
+        if full_width { full_width_digit(c) } else { c }
+    });
+    (digits, separator)
+}
+
+/// Thousands separator of `lang`.
+fn group_separator(lang: Language) -> char {
+    match lang {
+        Language::Japanese | Language::English => ',',
+    }
+}
+
+/// Full-width form (`０`–`９`) of an ASCII digit.
+fn full_width_digit(c: char) -> char {
+    c.to_digit(10)
+        .and_then(|d| char::from_u32('０' as u32 + d))
+        .unwrap_or(c)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ascii(n: u32) -> String {
+        format_number(n, Language::English, false)
+    }
+
+    #[test]
+    fn test_format_number_zero() {
+        assert_eq!(ascii(0), "0");
+    }
+
+    #[test]
+    fn test_format_number_below_thousand() {
+        assert_eq!(ascii(999), "999");
+    }
+
+    #[test]
+    fn test_format_number_thousands() {
+        assert_eq!(ascii(1_000), "1,000");
+        assert_eq!(ascii(10_000), "10,000");
+        assert_eq!(ascii(100_000), "100,000");
+    }
+
+    #[test]
+    fn test_format_number_millions() {
+        assert_eq!(ascii(1_000_000), "1,000,000");
+        assert_eq!(ascii(1_234_567), "1,234,567");
+    }
+
+    #[test]
+    fn test_format_number_u32_max() {
+        // u32::MAX = 4_294_967_295
+        assert_eq!(ascii(u32::MAX), "4,294,967,295");
+    }
+
+    #[test]
+    fn test_full_width_digits_japanese_only() {
+        assert_eq!(
+            format_number(1_234_567, Language::Japanese, true),
+            "１，２３４，５６７"
+        );
+        assert_eq!(format_number(0, Language::Japanese, true), "０");
+        assert_eq!(format_number(1_234, Language::English, true), "1,234");
+    }
+
+    #[test]
+    fn test_number_glyphs() {
+        let (digits, separator) = number_glyphs(Language::English, true);
+        assert_eq!(digits[0], '0');
+        assert_eq!(digits[9], '9');
+        assert_eq!(separator, ',');
+        let (digits, separator) = number_glyphs(Language::Japanese, true);
+        assert_eq!(digits[7], '７');
+        assert_eq!(separator, '，');
+    }
+
+    #[test]
+    fn test_format_score_follows_settings() {
+        let mut settings = SettingsResource::default();
+        assert_eq!(format_score(4_210, &settings), "4,210");
+        settings.language = Language::Japanese;
+        settings.full_width_digits = true;
+        assert_eq!(format_score(4_210, &settings), "４，２１０");
+    }
+}
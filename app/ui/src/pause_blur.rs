@@ -0,0 +1,352 @@
+//! Blur / darken post-process behind menus.
+//!
+//! While the game is [`AppState::Paused`] or [`AppState::GameOver`] the world
+//! camera's image is blurred, slightly desaturated and darkened, so menu text
+//! stays readable over a busy board without hiding the board completely.
+//! The effect fades in and out over [`FADE_SECS`], and clears while the
+//! game-over "view board" mode ([`BoardViewActive`]) shows the final board.
+//!
+//! The pass runs in the 2D render graph between tonemapping and the end of
+//! main-pass post-processing, i.e. before UI is drawn, so only the world is
+//! blurred.  The fragment shader lives at [`PAUSE_BLUR_SHADER_PATH`].
+//!
+//! ```text
+//! … → Tonemapping → PauseBlurLabel → EndMainPassPostProcessing → UI → …
+//! ```
+
+use bevy::core_pipeline::FullscreenShader;
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+use bevy::ecs::query::QueryItem;
+use bevy::image::BevyDefault;
+use bevy::prelude::*;
+use bevy::render::extract_component::{
+    ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+    UniformComponentPlugin,
+};
+use bevy::render::render_graph::{
+    NodeRunError, RenderGraphContext, RenderGraphExt, RenderLabel, ViewNode, ViewNodeRunner,
+};
+use bevy::render::render_resource::binding_types::{sampler, texture_2d, uniform_buffer};
+use bevy::render::render_resource::{
+    BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedRenderPipelineId,
+    ColorTargetState, ColorWrites, FragmentState, Operations, PipelineCache,
+    RenderPassColorAttachment, RenderPassDescriptor, RenderPipelineDescriptor, Sampler,
+    SamplerBindingType, SamplerDescriptor, ShaderStages, ShaderType, TextureFormat,
+    TextureSampleType,
+};
+use bevy::render::renderer::{RenderContext, RenderDevice};
+use bevy::render::view::ViewTarget;
+use bevy::render::{RenderApp, RenderStartup};
+use suika_game_core::prelude::AppState;
+
+use crate::screens::game_over::BoardViewActive;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Asset path of the blur fragment shader.
+pub const PAUSE_BLUR_SHADER_PATH: &str = "shaders/pause_blur.wgsl";
+
+/// Seconds for the blur to fade fully in or out.
+pub const FADE_SECS: f32 = 0.25;
+
+/// Blur radius at full strength (pixels).
+const DEFAULT_RADIUS: f32 = 8.0;
+
+/// Brightness removed at full strength (0 = none, 1 = black).
+const DEFAULT_DARKEN: f32 = 0.3;
+
+/// Saturation removed at full strength (0 = none, 1 = greyscale).
+const DEFAULT_DESATURATE: f32 = 0.4;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Blur settings for a camera; the pass is skipped while `strength` is 0.
+///
+/// Uploaded as-is to the shader's `PauseBlur` uniform.
+#[derive(Component, Debug, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct PauseBlur {
+    /// Current effect amount in `[0, 1]`, animated by [`fade_pause_blur`]
+    pub strength: f32,
+    /// Blur radius at full strength, in pixels
+    pub radius: f32,
+    /// Brightness removed at full strength
+    pub darken: f32,
+    /// Saturation removed at full strength
+    pub desaturate: f32,
+}
+
+impl Default for PauseBlur {
+    fn default() -> Self {
+        Self {
+            strength: 0.0,
+            radius: DEFAULT_RADIUS,
+            darken: DEFAULT_DARKEN,
+            desaturate: DEFAULT_DESATURATE,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Registers the blur pass in the 2D render graph.
+///
+/// Without a render app (headless tests) only the main-world fade system is
+/// added.
+pub struct PauseBlurPlugin;
+
+impl Plugin for PauseBlurPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ExtractComponentPlugin::<PauseBlur>::default(),
+            UniformComponentPlugin::<PauseBlur>::default(),
+        ))
+        .add_systems(Update, fade_pause_blur);
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .add_systems(RenderStartup, init_pause_blur_pipeline)
+            .add_render_graph_node::<ViewNodeRunner<PauseBlurNode>>(Core2d, PauseBlurLabel)
+            .add_render_graph_edges(
+                Core2d,
+                (
+                    Node2d::Tonemapping,
+                    PauseBlurLabel,
+                    Node2d::EndMainPassPostProcessing,
+                ),
+            );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Whether menus in `state` sit over a blurred board.
+pub fn blurs_background(state: &AppState) -> bool {
+    matches!(state, AppState::Paused | AppState::GameOver)
+}
+
+/// Moves every [`PauseBlur::strength`] towards 1 in blurred states and
+/// towards 0 otherwise, taking [`FADE_SECS`] for a full fade.
+pub fn fade_pause_blur(
+    state: Option<Res<State<AppState>>>,
+    board_view: Option<Res<BoardViewActive>>,
+    time: Res<Time>,
+    mut query: Query<&mut PauseBlur>,
+) {
+    let viewing_board = board_view.is_some_and(|v| v.0);
+    let target = match state {
+        Some(state) if blurs_background(state.get()) && !viewing_board => 1.0,
+        _ => 0.0,
+    };
+    let step = time.delta_secs() / FADE_SECS;
+    for mut blur in query.iter_mut() {
+        let strength = approach(blur.strength, target, step);
+        if blur.strength != strength {
+            blur.strength = strength;
+        }
+    }
+}
+
+/// Moves `current` towards `target` by at most `step`.
+fn approach(current: f32, target: f32, step: f32) -> f32 {
+    if current < target {
+        (current + step).min(target)
+    } else {
+        (current - step).max(target)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Render world
+// ---------------------------------------------------------------------------
+
+/// Render-graph label of the blur pass.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct PauseBlurLabel;
+
+/// Bind group layout, sampler and pipeline shared by every view.
+#[derive(Resource)]
+struct PauseBlurPipeline {
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+fn init_pause_blur_pipeline(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    asset_server: Res<AssetServer>,
+    fullscreen_shader: Res<FullscreenShader>,
+    pipeline_cache: Res<PipelineCache>,
+) {
+    let layout = render_device.create_bind_group_layout(
+        "pause_blur_bind_group_layout",
+        &BindGroupLayoutEntries::sequential(
+            ShaderStages::FRAGMENT,
+            (
+                texture_2d(TextureSampleType::Float { filterable: true }),
+                sampler(SamplerBindingType::Filtering),
+                uniform_buffer::<PauseBlur>(true),
+            ),
+        ),
+    );
+    let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+    let pipeline_id = pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+        label: Some("pause_blur_pipeline".into()),
+        layout: vec![layout.clone()],
+        vertex: fullscreen_shader.to_vertex_state(),
+        fragment: Some(FragmentState {
+            shader: asset_server.load(PAUSE_BLUR_SHADER_PATH),
+            // The game camera is not HDR, so the view target uses the default format
+            targets: vec![Some(ColorTargetState {
+                format: TextureFormat::bevy_default(),
+                blend: None,
+                write_mask: ColorWrites::ALL,
+            })],
+            ..default()
+        }),
+        ..default()
+    });
+
+    commands.insert_resource(PauseBlurPipeline {
+        layout,
+        sampler,
+        pipeline_id,
+    });
+}
+
+/// Draws the blurred view into the post-process destination texture.
+#[derive(Default)]
+struct PauseBlurNode;
+
+impl ViewNode for PauseBlurNode {
+    type ViewQuery = (
+        &'static ViewTarget,
+        &'static PauseBlur,
+        &'static DynamicUniformIndex<PauseBlur>,
+    );
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        (view_target, blur, uniform_index): QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        if blur.strength <= 0.0 {
+            return Ok(());
+        }
+        let blur_pipeline = world.resource::<PauseBlurPipeline>();
+        let Some(pipeline) = world
+            .resource::<PipelineCache>()
+            .get_render_pipeline(blur_pipeline.pipeline_id)
+        else {
+            // Shader still loading
+            return Ok(());
+        };
+        let Some(settings) = world
+            .resource::<ComponentUniforms<PauseBlur>>()
+            .uniforms()
+            .binding()
+        else {
+            return Ok(());
+        };
+
+        let post_process = view_target.post_process_write();
+        let bind_group = render_context.render_device().create_bind_group(
+            "pause_blur_bind_group",
+            &blur_pipeline.layout,
+            &BindGroupEntries::sequential((
+                post_process.source,
+                &blur_pipeline.sampler,
+                settings.clone(),
+            )),
+        );
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("pause_blur_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                depth_slice: None,
+                resolve_target: None,
+                ops: Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[uniform_index.index()]);
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_blurs_background_only_behind_menus() {
+        assert!(blurs_background(&AppState::Paused));
+        assert!(blurs_background(&AppState::GameOver));
+        assert!(!blurs_background(&AppState::Playing));
+        assert!(!blurs_background(&AppState::Title));
+    }
+
+    #[test]
+    fn test_approach_clamps_at_target() {
+        assert_eq!(approach(0.0, 1.0, 0.4), 0.4);
+        assert_eq!(approach(0.8, 1.0, 0.4), 1.0);
+        assert_eq!(approach(0.3, 0.0, 0.4), 0.0);
+        assert_eq!(approach(1.0, 1.0, 0.4), 1.0);
+    }
+
+    #[test]
+    fn test_fade_follows_state() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                FADE_SECS / 2.0,
+            )))
+            .init_state::<AppState>()
+            .add_systems(Update, fade_pause_blur);
+        let camera = app.world_mut().spawn(PauseBlur::default()).id();
+        let strength = |app: &App| app.world().get::<PauseBlur>(camera).unwrap().strength;
+
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Paused);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(strength(&app), 1.0);
+
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Playing);
+        app.update();
+        assert!(strength(&app) < 1.0);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(strength(&app), 0.0);
+    }
+}
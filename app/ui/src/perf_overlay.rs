@@ -0,0 +1,264 @@
+//! Performance overlay (FPS, frame-time graph, entity count).
+//!
+//! A small panel in the top-right corner, hidden by default.  It is toggled
+//! with [`PERF_OVERLAY_KEY`] or by flipping [`PerfOverlayVisible`] from the
+//! debug inspector (the resource is reflected so it shows up there).
+//!
+//! All values come from Bevy's [`DiagnosticsStore`]:
+//! [`FrameTimeDiagnosticsPlugin`] and [`EntityCountDiagnosticsPlugin`] are
+//! added by [`crate::GameUIPlugin`] when nothing else registered them.
+//!
+//! ```text
+//! ┌──────────────────────────┐
+//! │ FPS 60   16.7 ms   412 e │
+//! │ ▁▂▁▁▃▁▁▂▁▁▁▇▁▁▁▂▁▁▁▁▁▁▁▁ │  ← last GRAPH_SAMPLES frame times
+//! └──────────────────────────┘
+//! ```
+
+use bevy::diagnostic::{
+    DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Key that shows / hides the overlay.
+pub const PERF_OVERLAY_KEY: KeyCode = KeyCode::F2;
+
+/// Number of frame-time samples shown in the graph.
+const GRAPH_SAMPLES: usize = 60;
+
+/// Graph height in pixels.
+const GRAPH_HEIGHT: f32 = 40.0;
+
+/// Width of one graph bar in pixels.
+const BAR_WIDTH: f32 = 3.0;
+
+/// Frame time (ms) that fills the full graph height.
+const GRAPH_MAX_MS: f64 = 50.0;
+
+/// Frame time budget for 60 FPS in ms; bars under it are green.
+const BUDGET_60_MS: f64 = 1000.0 / 60.0;
+
+/// Frame time budget for 30 FPS in ms; bars under it are yellow, above red.
+const BUDGET_30_MS: f64 = 1000.0 / 30.0;
+
+const PANEL_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+const GOOD_COLOR: Color = Color::srgb(0.3, 0.9, 0.3);
+const SLOW_COLOR: Color = Color::srgb(0.95, 0.8, 0.2);
+const BAD_COLOR: Color = Color::srgb(0.95, 0.25, 0.2);
+
+// ---------------------------------------------------------------------------
+// Resources / components
+// ---------------------------------------------------------------------------
+
+/// Whether the performance overlay is shown.
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct PerfOverlayVisible(pub bool);
+
+/// Marks the overlay panel.
+#[derive(Component, Debug)]
+pub struct PerfOverlay;
+
+/// Marks the overlay's summary text.
+#[derive(Component, Debug)]
+pub struct PerfOverlayText;
+
+/// One bar of the frame-time graph; `0` is the oldest sample.
+#[derive(Component, Debug)]
+pub struct PerfGraphBar(pub usize);
+
+// ---------------------------------------------------------------------------
+// Plugin wiring
+// ---------------------------------------------------------------------------
+
+/// Adds the diagnostics plugins the overlay reads from, unless already present.
+pub fn add_required_diagnostics(app: &mut App) {
+    if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+        app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+    }
+    if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+        app.add_plugins(EntityCountDiagnosticsPlugin::default());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the hidden overlay panel.
+pub fn spawn_perf_overlay(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(4.0),
+                right: Val::Px(4.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(6.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            GlobalZIndex(i32::MAX),
+            Visibility::Hidden,
+            PerfOverlay,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                PerfOverlayText,
+            ));
+            panel
+                .spawn(Node {
+                    height: Val::Px(GRAPH_HEIGHT),
+                    align_items: AlignItems::FlexEnd,
+                    column_gap: Val::Px(1.0),
+                    ..default()
+                })
+                .with_children(|graph| {
+                    for i in 0..GRAPH_SAMPLES {
+                        graph.spawn((
+                            Node {
+                                width: Val::Px(BAR_WIDTH),
+                                height: Val::Px(0.0),
+                                ..default()
+                            },
+                            BackgroundColor(GOOD_COLOR),
+                            PerfGraphBar(i),
+                        ));
+                    }
+                });
+        });
+}
+
+/// Flips [`PerfOverlayVisible`] on [`PERF_OVERLAY_KEY`].
+pub fn toggle_perf_overlay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut visible: ResMut<PerfOverlayVisible>,
+) {
+    if keyboard.just_pressed(PERF_OVERLAY_KEY) {
+        visible.0 = !visible.0;
+    }
+}
+
+/// Shows / hides the overlay and refreshes text and graph while visible.
+pub fn update_perf_overlay(
+    visible: Res<PerfOverlayVisible>,
+    store: Res<DiagnosticsStore>,
+    mut panel_query: Query<&mut Visibility, With<PerfOverlay>>,
+    mut text_query: Query<&mut Text, With<PerfOverlayText>>,
+    mut bar_query: Query<(&PerfGraphBar, &mut Node, &mut BackgroundColor)>,
+) {
+    let Ok(mut visibility) = panel_query.single_mut() else {
+        return;
+    };
+    visibility.set_if_neq(if visible.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    });
+    if !visible.0 {
+        return;
+    }
+
+    let fps = store
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|d| d.smoothed());
+    let frame_time = store.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME);
+    let entities = store
+        .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+        .and_then(|d| d.value());
+
+    if let Ok(mut text) = text_query.single_mut() {
+        text.0 = format_summary(fps, frame_time.and_then(|d| d.smoothed()), entities);
+    }
+
+    let samples: Vec<f64> = frame_time
+        .map(|d| {
+            let values: Vec<f64> = d.values().copied().collect();
+            values[values.len().saturating_sub(GRAPH_SAMPLES)..].to_vec()
+        })
+        .unwrap_or_default();
+    // Right-align so the newest sample is always the last bar
+    let offset = GRAPH_SAMPLES - samples.len();
+
+    for (bar, mut node, mut color) in bar_query.iter_mut() {
+        let ms = bar
+            .0
+            .checked_sub(offset)
+            .and_then(|i| samples.get(i))
+            .copied()
+            .unwrap_or(0.0);
+        node.height = Val::Px(bar_height(ms));
+        color.0 = bar_color(ms);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// `FPS 60   16.7 ms   412 e`, with `-` for values not measured yet.
+fn format_summary(fps: Option<f64>, frame_ms: Option<f64>, entities: Option<f64>) -> String {
+    let fps = fps.map_or("-".to_string(), |v| format!("{v:.0}"));
+    let frame_ms = frame_ms.map_or("-".to_string(), |v| format!("{v:.1}"));
+    let entities = entities.map_or("-".to_string(), |v| format!("{v:.0}"));
+    format!("FPS {fps}   {frame_ms} ms   {entities} e")
+}
+
+/// Bar height in pixels for a frame time in ms, capped at [`GRAPH_HEIGHT`].
+fn bar_height(ms: f64) -> f32 {
+    ((ms / GRAPH_MAX_MS).clamp(0.0, 1.0) as f32) * GRAPH_HEIGHT
+}
+
+/// Green within the 60 FPS budget, yellow within 30 FPS, red beyond.
+fn bar_color(ms: f64) -> Color {
+    if ms <= BUDGET_60_MS {
+        GOOD_COLOR
+    } else if ms <= BUDGET_30_MS {
+        SLOW_COLOR
+    } else {
+        BAD_COLOR
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_summary() {
+        assert_eq!(
+            format_summary(Some(59.6), Some(16.74), Some(412.0)),
+            "FPS 60   16.7 ms   412 e"
+        );
+        assert_eq!(format_summary(None, None, None), "FPS -   - ms   - e");
+    }
+
+    #[test]
+    fn test_bar_height_is_capped() {
+        assert_eq!(bar_height(0.0), 0.0);
+        assert_eq!(bar_height(GRAPH_MAX_MS / 2.0), GRAPH_HEIGHT / 2.0);
+        assert_eq!(bar_height(GRAPH_MAX_MS * 4.0), GRAPH_HEIGHT);
+    }
+
+    #[test]
+    fn test_bar_color_thresholds() {
+        assert_eq!(bar_color(10.0), GOOD_COLOR);
+        assert_eq!(bar_color(25.0), SLOW_COLOR);
+        assert_eq!(bar_color(60.0), BAD_COLOR);
+    }
+}
@@ -0,0 +1,288 @@
+//! Streamer-safe mode on screen.
+//!
+//! Core decides what safe mode turns off
+//! ([`suika_game_core::systems::safe_mode`]); this module covers what the
+//! player sees:
+//!
+//! - [`mask`] hides seed codes and profile names on the title screen
+//! - the settings PIN prompt, a [`Modal`] that takes
+//!   [`SETTINGS_PIN_LEN`] digits from the keyboard:
+//!
+//! ```text
+//! ┌───────────────────────┐
+//! │      Enter PIN        │
+//! │      ● ● ○ ○          │
+//! │     [ Cancel ]        │
+//! └───────────────────────┘
+//! ```
+//!
+//! [`ButtonAction::OpenSettings`] opens the prompt instead of the settings
+//! screen while [`SettingsResource::settings_locked`]; the right PIN opens
+//! the screen.  The settings screen's PIN row uses the same prompt to choose
+//! a new PIN.  Digits are read from [`KeyboardInput`], so the dialog's Cancel
+//! button keeps working with Enter and the gamepad.
+
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use suika_game_core::events::SaveErrorEvent;
+use suika_game_core::prelude::{AppState, ReturnTo, ScreenTransition};
+use suika_game_core::profiles::CurrentProfile;
+use suika_game_core::resources::settings::{Language, SETTINGS_PIN_LEN, SettingsResource};
+
+use crate::components::{ButtonAction, Modal, persist_settings};
+use crate::i18n::t;
+use crate::styles::FONT_JP;
+
+// ---------------------------------------------------------------------------
+// Components / resources
+// ---------------------------------------------------------------------------
+
+/// What a finished PIN prompt does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinPurpose {
+    /// Open the settings screen if the digits match the saved PIN
+    Unlock,
+    /// Save the digits as the new PIN
+    Set,
+}
+
+/// The PIN prompt being answered.
+///
+/// # Lifecycle
+///
+/// Inserted by [`open_pin_prompt`]; removed by [`handle_pin_input`] once the
+/// prompt is finished or its dialog was closed.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct PinPrompt {
+    /// What happens once all digits are in
+    pub purpose: PinPurpose,
+    /// Digits typed so far
+    pub digits: String,
+    /// The last attempt did not match the saved PIN
+    pub wrong: bool,
+}
+
+impl PinPrompt {
+    /// Empty prompt for `purpose`.
+    pub fn new(purpose: PinPurpose) -> Self {
+        Self {
+            purpose,
+            digits: String::new(),
+            wrong: false,
+        }
+    }
+
+    /// Applies one key press; returns `true` once [`SETTINGS_PIN_LEN`]
+    /// digits are in.
+    pub fn press(&mut self, key: &Key) -> bool {
+        match key {
+            Key::Backspace => {
+                self.digits.pop();
+            }
+            Key::Character(chars) => {
+                for c in chars.chars().filter(char::is_ascii_digit) {
+                    if self.digits.len() < SETTINGS_PIN_LEN {
+                        self.digits.push(c);
+                        self.wrong = false;
+                    }
+                }
+            }
+            _ => {}
+        }
+        self.digits.len() == SETTINGS_PIN_LEN
+    }
+}
+
+/// Marks the dialog of the [`PinPrompt`].
+#[derive(Component, Debug)]
+pub struct PinDialog;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// `text` with every character but spaces replaced by `•`.
+///
+/// # Examples
+///
+/// ```
+/// # use suika_game_ui::safe_mode::mask;
+/// assert_eq!(mask("AB-12"), "•••••");
+/// ```
+pub fn mask(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_whitespace() { c } else { '•' })
+        .collect()
+}
+
+/// Starts a PIN prompt for `purpose` and opens its dialog.
+pub fn open_pin_prompt(
+    commands: &mut Commands,
+    purpose: PinPurpose,
+    lang: Language,
+    font: Handle<Font>,
+) {
+    let prompt = PinPrompt::new(purpose);
+    show_pin_dialog(commands, &prompt, lang, font);
+    commands.insert_resource(prompt);
+}
+
+/// Opens the dialog for `prompt`, replacing the one already shown.
+fn show_pin_dialog(
+    commands: &mut Commands,
+    prompt: &PinPrompt,
+    lang: Language,
+    font: Handle<Font>,
+) {
+    let title = match prompt.purpose {
+        PinPurpose::Unlock => t("pin_enter_title", lang),
+        PinPurpose::Set => t("pin_set_title", lang),
+    };
+    let mut message = pin_dots(prompt.digits.len());
+    if prompt.wrong {
+        message = format!("{message}\n{}", t("pin_wrong", lang));
+    }
+    let dialog = Modal::new(title)
+        .message(message)
+        .button(t("btn_cancel", lang), ButtonAction::CloseModal)
+        .spawn(commands, font);
+    commands.entity(dialog).insert(PinDialog);
+}
+
+/// `●` for each typed digit and `○` for each one still missing.
+fn pin_dots(typed: usize) -> String {
+    (0..SETTINGS_PIN_LEN)
+        .map(|i| if i < typed { "●" } else { "○" })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Types digits into the open [`PinPrompt`] and acts on a complete PIN.
+///
+/// A matching unlock PIN opens the settings screen, remembering the screen
+/// to return to; a wrong one clears the digits and says so.  A new PIN is
+/// saved with the settings.  Closing the dialog (Cancel) drops the prompt.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_pin_input(
+    mut commands: Commands,
+    mut key_events: MessageReader<KeyboardInput>,
+    prompt: Option<ResMut<PinPrompt>>,
+    dialogs: Query<Entity, With<PinDialog>>,
+    mut settings: ResMut<SettingsResource>,
+    profile: Res<CurrentProfile>,
+    state: Res<State<AppState>>,
+    mut return_to: ResMut<ReturnTo>,
+    mut transition: ResMut<ScreenTransition>,
+    mut save_errors: MessageWriter<SaveErrorEvent>,
+    asset_server: Res<AssetServer>,
+) {
+    let Some(mut prompt) = prompt else {
+        key_events.clear();
+        return;
+    };
+    if dialogs.is_empty() {
+        key_events.clear();
+        commands.remove_resource::<PinPrompt>();
+        return;
+    }
+
+    let before = prompt.clone();
+    let mut complete = false;
+    for event in key_events.read() {
+        if event.state == ButtonState::Pressed && !complete {
+            complete = prompt.press(&event.logical_key);
+        }
+    }
+
+    if complete {
+        let pin = std::mem::take(&mut prompt.digits);
+        match prompt.purpose {
+            PinPurpose::Unlock if pin != settings.settings_pin => {
+                prompt.wrong = true;
+            }
+            PinPurpose::Unlock => {
+                return_to.0 = *state.get();
+                transition.start(AppState::Settings);
+                close_pin_prompt(&mut commands, &dialogs);
+                return;
+            }
+            PinPurpose::Set => {
+                settings.settings_pin = pin;
+                persist_settings(&settings, &profile, &mut save_errors);
+                close_pin_prompt(&mut commands, &dialogs);
+                return;
+            }
+        }
+    }
+
+    if *prompt != before {
+        show_pin_dialog(
+            &mut commands,
+            &prompt,
+            settings.language,
+            asset_server.load(FONT_JP),
+        );
+    }
+}
+
+/// Closes the PIN dialog and drops the prompt.
+fn close_pin_prompt(commands: &mut Commands, dialogs: &Query<Entity, With<PinDialog>>) {
+    for dialog in dialogs.iter() {
+        commands.entity(dialog).despawn();
+    }
+    commands.remove_resource::<PinPrompt>();
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit(c: &str) -> Key {
+        Key::Character(c.into())
+    }
+
+    #[test]
+    fn test_prompt_takes_digits_only() {
+        let mut prompt = PinPrompt::new(PinPurpose::Set);
+        assert!(!prompt.press(&digit("1")));
+        assert!(!prompt.press(&digit("a")));
+        assert!(!prompt.press(&digit("2")));
+        assert!(!prompt.press(&Key::Backspace));
+        assert_eq!(prompt.digits, "1");
+        assert!(!prompt.press(&digit("2")));
+        assert!(!prompt.press(&digit("3")));
+        assert!(prompt.press(&digit("45")));
+        assert_eq!(prompt.digits, "1234");
+    }
+
+    #[test]
+    fn test_typing_clears_wrong_flag() {
+        let mut prompt = PinPrompt::new(PinPurpose::Unlock);
+        prompt.wrong = true;
+        prompt.press(&Key::Enter);
+        assert!(prompt.wrong);
+        prompt.press(&digit("7"));
+        assert!(!prompt.wrong);
+    }
+
+    #[test]
+    fn test_pin_dots() {
+        assert_eq!(pin_dots(0), "○ ○ ○ ○");
+        assert_eq!(pin_dots(3), "● ● ● ○");
+    }
+
+    #[test]
+    fn test_mask_keeps_spaces() {
+        assert_eq!(mask("Player 2"), "•••••• •");
+        assert_eq!(mask(""), "");
+    }
+}
@@ -0,0 +1,154 @@
+//! Attract-mode overlay — shown over the bot-played demo that starts when
+//! the title screen sits idle (see
+//! [`attract`](suika_game_core::systems::attract)).
+//!
+//! ```text
+//! ┌─────────────────────────────────┐
+//! │            デモプレイ             │  ← label
+//! │                                 │
+//! │          (demo board)           │
+//! │                                 │
+//! │      なにかキーを押してください      │  ← blinking prompt
+//! └─────────────────────────────────┘
+//! ```
+//!
+//! The HUD is hidden during the demo, so only the board and this overlay are
+//! visible.  All entities are tagged with
+//! [`DespawnOnExit`]`(`[`AppState::Playing`]`)`.
+
+use bevy::prelude::*;
+use suika_game_core::prelude::{AppState, SettingsResource};
+
+use crate::i18n::t;
+use crate::styles::{FONT_JP, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Full blink cycle of the prompt (seconds).
+const BLINK_PERIOD: f32 = 1.2;
+
+/// Text colour of the overlay.
+const OVERLAY_TEXT_COLOR: Color = Color::WHITE;
+
+/// Shadow strip behind each line so the text stays readable over fruits.
+const STRIP_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.45);
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the blinking "Press any key" prompt.
+#[derive(Component, Debug)]
+pub struct AttractPrompt;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the overlay when a demo enters [`AppState::Playing`].
+pub fn setup_attract_overlay(
+    mut commands: Commands,
+    settings: Res<SettingsResource>,
+    asset_server: Res<AssetServer>,
+) {
+    let font: Handle<Font> = asset_server.load(FONT_JP);
+    let lang = settings.language;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                padding: UiRect::vertical(Val::Px(24.0)),
+                ..default()
+            },
+            DespawnOnExit(AppState::Playing),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(STRIP_COLOR),
+                ))
+                .with_child((
+                    Text::new(t("attract_demo", lang)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: FONT_SIZE_MEDIUM,
+                        ..default()
+                    },
+                    TextColor(OVERLAY_TEXT_COLOR),
+                ));
+
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(STRIP_COLOR),
+                    AttractPrompt,
+                ))
+                .with_child((
+                    Text::new(t("attract_press_any_key", lang)),
+                    TextFont {
+                        font,
+                        font_size: FONT_SIZE_SMALL,
+                        ..default()
+                    },
+                    TextColor(OVERLAY_TEXT_COLOR),
+                ));
+        });
+}
+
+/// Blinks the "Press any key" prompt.
+pub fn blink_attract_prompt(
+    time: Res<Time>,
+    mut prompt_query: Query<&mut Visibility, With<AttractPrompt>>,
+) {
+    let desired = if prompt_shown(time.elapsed_secs()) {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut visibility in prompt_query.iter_mut() {
+        if *visibility != desired {
+            *visibility = desired;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Whether the prompt is visible `elapsed` seconds in: shown for the first
+/// two thirds of each [`BLINK_PERIOD`].
+fn prompt_shown(elapsed: f32) -> bool {
+    elapsed.rem_euclid(BLINK_PERIOD) < BLINK_PERIOD * 2.0 / 3.0
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_blinks() {
+        assert!(prompt_shown(0.0));
+        assert!(!prompt_shown(BLINK_PERIOD * 0.9));
+        assert!(prompt_shown(BLINK_PERIOD * 1.1));
+    }
+}
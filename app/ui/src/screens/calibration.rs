@@ -0,0 +1,303 @@
+//! Audio calibration screen — opened from the SFX delay row of the settings
+//! screen.
+//!
+//! A metronome clicks every [`BEAT_INTERVAL_SECS`] and a circle flashes
+//! [`SettingsResource::sfx_latency_ms`] after each click is started.  The
+//! player steps the delay with ◀ / ▶ until click and flash line up; the
+//! value is then how late their speakers (e.g. Bluetooth headphones) play
+//! sound, and the audio crate makes up for it on gameplay sounds.
+//!
+//! ```text
+//!          音ズレ調整 / Audio Calibration
+//!
+//!                  ( ● )        ← flashes on the beat
+//!
+//!            ◀    120 ms    ▶
+//!
+//!   音と光が同時になるように調整してください
+//!
+//!               [ もどる ]
+//! ```
+//!
+//! [`tick_metronome`] writes a [`MetronomeBeat`] for the audio crate to play
+//! the click.  Every change is persisted at once.  Back returns to the
+//! settings screen, which still returns to wherever it was opened from.
+//!
+//! All entities are tagged with
+//! [`DespawnOnExit`]`(`[`AppState::AudioCalibration`]`)` so Bevy cleans
+//! them up automatically on state exit.
+
+use bevy::prelude::*;
+use suika_game_core::prelude::AppState;
+use suika_game_core::resources::settings::SettingsResource;
+
+use crate::components::{ButtonAction, KeyboardFocusIndex, spawn_button};
+use crate::focus::FocusCell;
+use crate::i18n::t;
+use crate::styles::{
+    BG_COLOR, BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH, BUTTON_NORMAL, FONT_JP, FONT_SIZE_LARGE,
+    FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, FONT_SYMBOL, HIGHLIGHT_COLOR, PRIMARY_COLOR, TEXT_COLOR,
+};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Seconds between two metronome clicks (80 BPM).
+pub const BEAT_INTERVAL_SECS: f32 = 0.75;
+
+/// Seconds the beat indicator takes to fade back after a flash.
+const FLASH_SECS: f32 = 0.15;
+
+const INDICATOR_SIZE: f32 = 120.0;
+const ARROW_BTN_SIZE: f32 = 64.0;
+const VALUE_WIDTH: f32 = 160.0;
+
+// ---------------------------------------------------------------------------
+// Components / resources / messages
+// ---------------------------------------------------------------------------
+
+/// Marks the circle that flashes on every beat.
+#[derive(Component)]
+pub struct BeatIndicator;
+
+/// Marks the text node that shows the current SFX delay.
+#[derive(Component)]
+pub struct LatencyValueText;
+
+/// Clock of the calibration metronome.
+#[derive(Resource, Debug)]
+pub struct Metronome {
+    timer: Timer,
+    /// Seconds since the last click was started; `None` before the first.
+    since_beat: Option<f32>,
+}
+
+impl Default for Metronome {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(BEAT_INTERVAL_SECS, TimerMode::Repeating),
+            since_beat: None,
+        }
+    }
+}
+
+/// Written on every metronome beat; the audio crate plays the click.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetronomeBeat;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// The SFX delay as shown on screen, e.g. `"120 ms"`.
+pub fn latency_label(ms: u16) -> String {
+    format!("{ms} ms")
+}
+
+/// Brightness (0–1) of the beat indicator `since_beat` seconds after a
+/// click was started, with the flash held back by `latency_secs`.
+fn flash_strength(since_beat: f32, latency_secs: f32) -> f32 {
+    let t = since_beat - latency_secs;
+    if (0.0..FLASH_SECS).contains(&t) {
+        1.0 - t / FLASH_SECS
+    } else {
+        0.0
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the calibration screen UI and restarts the metronome when
+/// entering [`AppState::AudioCalibration`].
+pub fn setup_calibration_screen(
+    mut commands: Commands,
+    settings: Res<SettingsResource>,
+    asset_server: Res<AssetServer>,
+    mut keyboard_focus: ResMut<KeyboardFocusIndex>,
+    mut metronome: ResMut<Metronome>,
+) {
+    keyboard_focus.0 = 0;
+    *metronome = Metronome::default();
+
+    let font: Handle<Font> = asset_server.load(FONT_JP);
+    let symbol_font: Handle<Font> = asset_server.load(FONT_SYMBOL);
+    let lang = settings.language;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            DespawnOnExit(AppState::AudioCalibration),
+        ))
+        .with_children(|root| {
+            // Title
+            root.spawn((
+                Text::new(t("calibration_title", lang)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_LARGE,
+                    ..default()
+                },
+                TextColor(PRIMARY_COLOR),
+            ));
+
+            // Beat indicator
+            root.spawn((
+                BeatIndicator,
+                Node {
+                    width: Val::Px(INDICATOR_SIZE),
+                    height: Val::Px(INDICATOR_SIZE),
+                    ..default()
+                },
+                BackgroundColor(BUTTON_NORMAL),
+                BorderRadius::all(Val::Percent(50.0)),
+            ));
+
+            // ◀ delay ▶ (indices 0 and 1, focus row 0)
+            root.spawn(Node {
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_button(
+                    row,
+                    "◀",
+                    ButtonAction::SfxLatencyDown,
+                    0,
+                    FONT_SIZE_MEDIUM,
+                    ARROW_BTN_SIZE,
+                    ARROW_BTN_SIZE,
+                    symbol_font.clone(),
+                )
+                .insert(FocusCell::new(0, 0));
+                row.spawn((
+                    Text::new(latency_label(settings.sfx_latency_ms)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: FONT_SIZE_MEDIUM,
+                        ..default()
+                    },
+                    TextColor(TEXT_COLOR),
+                    TextLayout::new_with_justify(Justify::Center),
+                    Node {
+                        width: Val::Px(VALUE_WIDTH),
+                        ..default()
+                    },
+                    LatencyValueText,
+                ));
+                spawn_button(
+                    row,
+                    "▶",
+                    ButtonAction::SfxLatencyUp,
+                    1,
+                    FONT_SIZE_MEDIUM,
+                    ARROW_BTN_SIZE,
+                    ARROW_BTN_SIZE,
+                    symbol_font.clone(),
+                )
+                .insert(FocusCell::new(0, 1));
+            });
+
+            // Hint
+            root.spawn((
+                Text::new(t("calibration_hint", lang)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+            ));
+
+            // Back button (index 2, focus row 1)
+            spawn_button(
+                root,
+                t("btn_back", lang),
+                ButtonAction::BackToSettings,
+                2,
+                FONT_SIZE_MEDIUM,
+                BUTTON_LARGE_WIDTH,
+                BUTTON_LARGE_HEIGHT,
+                font.clone(),
+            )
+            .insert(FocusCell::new(1, 0));
+        });
+}
+
+/// Advances the metronome and writes a [`MetronomeBeat`] on every click.
+pub fn tick_metronome(
+    time: Res<Time>,
+    mut metronome: ResMut<Metronome>,
+    mut beats: MessageWriter<MetronomeBeat>,
+) {
+    let delta = time.delta_secs();
+    metronome.since_beat = metronome.since_beat.map(|since| since + delta);
+    if metronome.timer.tick(time.delta()).just_finished() {
+        metronome.since_beat = Some(metronome.timer.elapsed_secs());
+        beats.write(MetronomeBeat);
+    }
+}
+
+/// Flashes the [`BeatIndicator`] [`SettingsResource::sfx_latency_ms`] after
+/// each click.
+pub fn update_beat_indicator(
+    metronome: Res<Metronome>,
+    settings: Res<SettingsResource>,
+    mut indicators: Query<&mut BackgroundColor, With<BeatIndicator>>,
+) {
+    let strength = metronome.since_beat.map_or(0.0, |since| {
+        flash_strength(since, f32::from(settings.sfx_latency_ms) / 1000.0)
+    });
+    let color = BUTTON_NORMAL.mix(&HIGHLIGHT_COLOR, strength);
+    for mut bg in indicators.iter_mut() {
+        bg.set_if_neq(BackgroundColor(color));
+    }
+}
+
+/// Updates the delay text whenever [`SettingsResource`] changes.
+pub fn update_latency_display(
+    settings: Res<SettingsResource>,
+    mut query: Query<&mut Text, With<LatencyValueText>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in query.iter_mut() {
+        text.0 = latency_label(settings.sfx_latency_ms);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flash_is_held_back_by_the_latency() {
+        assert_eq!(flash_strength(0.0, 0.0), 1.0);
+        assert_eq!(flash_strength(0.05, 0.1), 0.0);
+        assert_eq!(flash_strength(0.1, 0.1), 1.0);
+        assert!((flash_strength(0.1 + FLASH_SECS / 2.0, 0.1) - 0.5).abs() < 1e-5);
+        assert_eq!(flash_strength(0.1 + FLASH_SECS, 0.1), 0.0);
+    }
+
+    #[test]
+    fn test_latency_label() {
+        assert_eq!(latency_label(0), "0 ms");
+        assert_eq!(latency_label(120), "120 ms");
+    }
+}
@@ -0,0 +1,181 @@
+//! Effects settings page — opened from the effect details row of the
+//! settings screen.
+//!
+//! Switches single visual effects on and off, for players who like the
+//! effects in general but not one of them:
+//!
+//! ```text
+//!          演出の設定 / Effect Settings
+//!
+//!  水しぶき    [         ON        ]
+//!  フラッシュ  [         ON        ]
+//!  画面の揺れ  [        OFF        ]
+//!
+//!           [ もどる ]
+//! ```
+//!
+//! The toggles only matter while the global effects row of the settings
+//! screen is ON; a hint says so when it is OFF.  Every press mutates
+//! [`SettingsResource`] and is persisted at once.  Back returns to the
+//! settings screen.
+//!
+//! All entities are tagged with
+//! [`DespawnOnExit`]`(`[`AppState::EffectsSettings`]`)` so Bevy cleans them
+//! up automatically on state exit.
+
+use bevy::prelude::*;
+use suika_game_core::prelude::AppState;
+use suika_game_core::resources::settings::SettingsResource;
+
+use crate::components::{ButtonAction, KeyboardFocusIndex, spawn_button};
+use crate::focus::FocusCell;
+use crate::i18n::t;
+use crate::screens::settings::{on_off, spawn_toggle_row};
+use crate::styles::{
+    BG_COLOR, BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH, FONT_JP, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM,
+    FONT_SIZE_SMALL, PRIMARY_COLOR, SECONDARY_COLOR,
+};
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the text node that shows whether one effect is switched on.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectValueText {
+    Droplets,
+    Flash,
+    Shake,
+}
+
+impl EffectValueText {
+    /// Whether the effect is switched on in `settings`.
+    fn value(self, settings: &SettingsResource) -> bool {
+        match self {
+            Self::Droplets => settings.droplet_effects,
+            Self::Flash => settings.flash_effects,
+            Self::Shake => settings.shake_effects,
+        }
+    }
+}
+
+/// One row per effect: label key, value marker and the action toggling it.
+const ROWS: [(&str, EffectValueText, ButtonAction); 3] = [
+    (
+        "label_droplet_effects",
+        EffectValueText::Droplets,
+        ButtonAction::ToggleDropletEffects,
+    ),
+    (
+        "label_flash_effects",
+        EffectValueText::Flash,
+        ButtonAction::ToggleFlashEffects,
+    ),
+    (
+        "label_shake_effects",
+        EffectValueText::Shake,
+        ButtonAction::ToggleShakeEffects,
+    ),
+];
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the effects settings page when entering
+/// [`AppState::EffectsSettings`].
+pub fn setup_effects_settings_screen(
+    mut commands: Commands,
+    settings: Res<SettingsResource>,
+    asset_server: Res<AssetServer>,
+    mut keyboard_focus: ResMut<KeyboardFocusIndex>,
+) {
+    keyboard_focus.0 = 0;
+
+    let font: Handle<Font> = asset_server.load(FONT_JP);
+    let lang = settings.language;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            DespawnOnExit(AppState::EffectsSettings),
+        ))
+        .with_children(|root| {
+            // Title
+            root.spawn((
+                Text::new(t("effects_settings_title", lang)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_LARGE,
+                    ..default()
+                },
+                TextColor(PRIMARY_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(32.0)),
+                    ..default()
+                },
+            ));
+
+            // One toggle row per effect (indices and focus rows 0..3)
+            for (i, (key, marker, action)) in ROWS.into_iter().enumerate() {
+                spawn_toggle_row(
+                    root,
+                    t(key, lang),
+                    Some(key),
+                    on_off(marker.value(&settings), lang),
+                    marker,
+                    action,
+                    i,
+                    i,
+                    lang,
+                    font.clone(),
+                );
+            }
+
+            if !settings.effects_enabled {
+                root.spawn((
+                    Text::new(t("effects_off_hint", lang)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: FONT_SIZE_SMALL,
+                        ..default()
+                    },
+                    TextColor(SECONDARY_COLOR),
+                ));
+            }
+
+            // Back button (index 3, below the rows)
+            spawn_button(
+                root,
+                t("btn_back", lang),
+                ButtonAction::BackToSettings,
+                ROWS.len(),
+                FONT_SIZE_MEDIUM,
+                BUTTON_LARGE_WIDTH,
+                BUTTON_LARGE_HEIGHT,
+                font.clone(),
+            )
+            .insert(FocusCell::new(ROWS.len(), 0));
+        });
+}
+
+/// Updates the ON / OFF texts whenever [`SettingsResource`] changes.
+pub fn update_effects_settings_display(
+    settings: Res<SettingsResource>,
+    mut query: Query<(&mut Text, &EffectValueText)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (mut text, marker) in query.iter_mut() {
+        text.0 = on_off(marker.value(&settings), settings.language).to_string();
+    }
+}
@@ -5,23 +5,41 @@
 //! - The **final score** in large text
 //! - A **NEW RECORD!** banner when a new highscore was achieved
 //! - The **all-time highscore**
+//! - The all-time **best combo** and **largest fruit** records
 //! - The **elapsed time** for this run in `M:SS` format
+//! - The run's **seed code**, for challenging friends to the same fruits
+//!   (hidden in streamer-safe mode)
+//! - A **score graph** of the run's score over time (see
+//!   [`score_graph`](crate::screens::score_graph))
 //! - A **Retry** button (→ [`AppState::Playing`])
+//! - A **Retry (same seed)** button that replays this run's fruit sequence
 //! - A **Title** button (→ [`AppState::Title`])
+//! - A **Copy Result** button that puts a shareable summary (score, largest
+//!   fruit, best combo, seed code) on the clipboard
+//! - A **View Board** button that hides the panel so the final fruit pile can
+//!   be inspected; any key or mouse press brings the panel back
+//!
+//! The panel root carries [`GameOverPanel`] so its visibility can be toggled
+//! by [`toggle_board_view`] instead of despawning and rebuilding it.
 //!
 //! All entities are tagged with [`DespawnOnExit`]`(`[`AppState::GameOver`]`)` so
 //! Bevy automatically despawns them when the state transitions away from
 //! `GameOver`.
 
 use bevy::prelude::*;
-use suika_game_core::prelude::{AppState, GameState, SettingsResource};
+use suika_game_core::prelude::{AppState, GameState, ScoreSample, SessionStats, SettingsResource};
+use suika_game_core::resources::settings::Language;
+use suika_game_core::systems::safe_mode::SafeMode;
 
 use crate::components::{ButtonAction, KeyboardFocusIndex, spawn_button};
-use crate::i18n::t;
+use crate::i18n::{fruit_name, t};
+use crate::number_format::{format_number, format_score};
+use crate::screens::hud::drops::format_efficiency;
 use crate::screens::hud::format_elapsed;
+use crate::screens::score_graph::spawn_score_graph;
 use crate::styles::{
-    BG_COLOR, BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH, BUTTON_MEDIUM_HEIGHT, BUTTON_MEDIUM_WIDTH,
-    FONT_JP, FONT_SIZE_HUGE, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, HIGHLIGHT_COLOR,
+    BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH, BUTTON_MEDIUM_HEIGHT, BUTTON_MEDIUM_WIDTH, FONT_JP,
+    FONT_SIZE_HUGE, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, HIGHLIGHT_COLOR,
     PRIMARY_COLOR, TEXT_COLOR,
 };
 
@@ -32,28 +50,60 @@ use crate::styles::{
 /// Red tone used for the "GAME OVER" heading.
 const GAME_OVER_COLOR: Color = Color::srgb(0.8, 0.2, 0.2);
 
+/// Panel background — [`BG_COLOR`](crate::styles::BG_COLOR) at 80% so the
+/// blurred board shows through.
+const PANEL_COLOR: Color = Color::srgba(0.95, 0.95, 0.90, 0.80);
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marker for the root node of the game-over panel.
+#[derive(Component, Debug)]
+pub struct GameOverPanel;
+
+/// Marker for the "press any key" hint shown while the board is in view.
+#[derive(Component, Debug)]
+pub struct BoardViewHint;
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// `true` while the game-over panel is hidden to show the final board.
+///
+/// Set by [`ButtonAction::ViewBoard`] and cleared by [`toggle_board_view`]
+/// on the next key or mouse press.
+#[derive(Resource, Debug, Default)]
+pub struct BoardViewActive(pub bool);
+
 // ---------------------------------------------------------------------------
 // Systems
 // ---------------------------------------------------------------------------
 
 /// Spawns the game-over screen UI when entering [`AppState::GameOver`].
 ///
-/// Reads [`GameState`] to display the final score, the all-time highscore, and
-/// whether this run set a new record.
+/// Reads [`GameState`] to display the final score, the all-time records, and
+/// whether this run set a new highscore.
 ///
 /// This system is registered with `.after(`[`GameOverSet::SaveHighscore`]`)` in
 /// [`GameUIPlugin`] so it is guaranteed to run after `save_highscore_on_game_over`
 /// has written [`GameState::is_new_record`] and updated [`GameState::highscore`].
 ///
 /// Resets [`KeyboardFocusIndex`] to `0` so the Retry button always has focus.
+#[allow(clippy::too_many_arguments)]
 pub fn setup_game_over_screen(
     mut commands: Commands,
     game_state: Res<GameState>,
+    stats: Res<SessionStats>,
     settings: Res<SettingsResource>,
+    safe_mode: Res<SafeMode>,
     asset_server: Res<AssetServer>,
     mut keyboard_focus: ResMut<KeyboardFocusIndex>,
+    mut board_view: ResMut<BoardViewActive>,
 ) {
     keyboard_focus.0 = 0;
+    board_view.0 = false;
 
     let font: Handle<Font> = asset_server.load(FONT_JP);
     let lang = settings.language;
@@ -69,7 +119,8 @@ pub fn setup_game_over_screen(
                 align_items: AlignItems::Center,
                 ..default()
             },
-            BackgroundColor(BG_COLOR),
+            BackgroundColor(PANEL_COLOR),
+            GameOverPanel,
             DespawnOnExit(AppState::GameOver),
         ))
         .with_children(|parent| {
@@ -93,7 +144,7 @@ pub fn setup_game_over_screen(
                 Text::new(format!(
                     "{}: {}",
                     t("score", lang),
-                    format_score(game_state.score)
+                    format_score(game_state.score, &settings)
                 )),
                 TextFont {
                     font: font.clone(),
@@ -129,7 +180,7 @@ pub fn setup_game_over_screen(
                 Text::new(format!(
                     "{}: {}",
                     t("highscore", lang),
-                    format_score(game_state.highscore)
+                    format_score(game_state.highscore, &settings)
                 )),
                 TextFont {
                     font: font.clone(),
@@ -143,6 +194,21 @@ pub fn setup_game_over_screen(
                 },
             ));
 
+            // All-time best combo and largest fruit
+            parent.spawn((
+                Text::new(records_line(&game_state, lang)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
             // Elapsed time for this run
             parent.spawn((
                 Text::new(format!(
@@ -157,11 +223,68 @@ pub fn setup_game_over_screen(
                 },
                 TextColor(TEXT_COLOR),
                 Node {
-                    margin: UiRect::bottom(Val::Px(40.0)),
+                    margin: UiRect::bottom(Val::Px(10.0)),
                     ..default()
                 },
             ));
 
+            // Drops and points per drop for this run
+            parent.spawn((
+                Text::new(format!(
+                    "{}: {}  /  {}: {}",
+                    t("drops", lang),
+                    game_state.drops,
+                    t("points_per_drop", lang),
+                    format_efficiency(game_state.points_per_drop())
+                )),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            // Seed code of this run (absent for games restored from a save
+            // or changed by adaptive spawn, hidden in streamer-safe mode)
+            if let Some(code) = stats.seed_code.filter(|_| !safe_mode.active) {
+                parent.spawn((
+                    Text::new(format!("{}: {code}", t("seed_code", lang))),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: FONT_SIZE_SMALL,
+                        ..default()
+                    },
+                    TextColor(TEXT_COLOR),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(10.0)),
+                        ..default()
+                    },
+                ));
+            }
+
+            // Score over time (skipped when nothing was merged)
+            spawn_score_graph(
+                parent,
+                t("score_graph", lang),
+                &stats.score_timeline,
+                ScoreSample {
+                    time: game_state.elapsed_time,
+                    score: game_state.score,
+                },
+                font.clone(),
+            );
+
+            // Spacer between the stats and the buttons
+            parent.spawn(Node {
+                height: Val::Px(20.0),
+                ..default()
+            });
+
             // Retry button (index 0 — initial keyboard focus)
             spawn_button(
                 parent,
@@ -174,45 +297,152 @@ pub fn setup_game_over_screen(
                 font.clone(),
             );
 
-            // Go-to-title button (index 1)
-            spawn_button(
-                parent,
-                t("btn_title", lang),
-                ButtonAction::GoToTitle,
-                1,
-                FONT_SIZE_MEDIUM,
-                BUTTON_MEDIUM_WIDTH,
-                BUTTON_MEDIUM_HEIGHT,
-                font.clone(),
-            );
+            // Secondary buttons; same-seed retry only when the run has a seed
+            // code (restored games do not)
+            let same_seed = stats
+                .seed_code
+                .is_some()
+                .then_some(("btn_retry_same_seed", ButtonAction::RetrySameSeed));
+            let secondary = same_seed.into_iter().chain([
+                ("btn_copy_result", ButtonAction::CopyResult),
+                ("btn_title", ButtonAction::GoToTitle),
+                ("btn_view_board", ButtonAction::ViewBoard),
+            ]);
+            for (index, (key, action)) in secondary.enumerate() {
+                spawn_button(
+                    parent,
+                    t(key, lang),
+                    action,
+                    index + 1,
+                    FONT_SIZE_MEDIUM,
+                    BUTTON_MEDIUM_WIDTH,
+                    BUTTON_MEDIUM_HEIGHT,
+                    font.clone(),
+                );
+            }
         });
+
+    // "Press any key" hint, hidden until the board is in view
+    commands.spawn((
+        Text::new(t("board_view_hint", lang)),
+        TextFont {
+            font,
+            font_size: FONT_SIZE_SMALL,
+            ..default()
+        },
+        TextColor(TEXT_COLOR),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(16.0),
+            left: Val::Px(16.0),
+            ..default()
+        },
+        BoardViewHint,
+        Visibility::Hidden,
+        DespawnOnExit(AppState::GameOver),
+    ));
+}
+
+/// Hides the game-over panel while [`BoardViewActive`] is set and restores it
+/// on the next key, mouse or gamepad button press.
+///
+/// The press that restores the panel is consumed (the input buffers are
+/// cleared) so it does not also activate the focused menu button.  Must run
+/// before [`handle_keyboard_menu_navigation`] for that to take effect.
+///
+/// The restore check is skipped on the frame the view is entered, so the
+/// click or Enter press that triggered [`ButtonAction::ViewBoard`] does not
+/// immediately undo it.
+///
+/// [`handle_keyboard_menu_navigation`]: crate::components::handle_keyboard_menu_navigation
+pub fn toggle_board_view(
+    mut board_view: ResMut<BoardViewActive>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut gamepads: Query<&mut Gamepad>,
+    mut panel_query: Query<&mut Visibility, (With<GameOverPanel>, Without<BoardViewHint>)>,
+    mut hint_query: Query<&mut Visibility, (With<BoardViewHint>, Without<GameOverPanel>)>,
+    mut was_active: Local<bool>,
+) {
+    let any_input = keyboard.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.get_just_pressed().next().is_some());
+
+    if board_view.0 && *was_active && any_input {
+        board_view.0 = false;
+        keyboard.clear();
+        mouse.clear();
+        for mut gamepad in gamepads.iter_mut() {
+            gamepad.digital_mut().clear();
+        }
+    }
+    *was_active = board_view.0;
+
+    let (panel, hint) = if board_view.0 {
+        (Visibility::Hidden, Visibility::Inherited)
+    } else {
+        (Visibility::Inherited, Visibility::Hidden)
+    };
+    for mut visibility in panel_query.iter_mut() {
+        visibility.set_if_neq(panel);
+    }
+    for mut visibility in hint_query.iter_mut() {
+        visibility.set_if_neq(hint);
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
-/// Formats an integer score with comma separators every three digits.
-///
-/// # Examples
+/// The all-time best combo and largest fruit, e.g.
+/// `Best Combo: ×6  /  Best Fruit: Melon`; `-` stands in for a record not
+/// set yet.
+pub fn records_line(game_state: &GameState, lang: Language) -> String {
+    let combo = match game_state.best_combo_record {
+        0 => "-".to_string(),
+        combo => format!("×{combo}"),
+    };
+    let fruit = game_state
+        .highest_fruit_record
+        .map_or("-", |fruit| fruit_name(fruit, lang));
+    format!(
+        "{}: {combo}  /  {}: {fruit}",
+        t("best_combo", lang),
+        t("hud_largest_fruit", lang)
+    )
+}
+
+/// Shareable plain-text summary of the finished run for "Copy Result".
 ///
-/// ```
-/// # use suika_game_ui::screens::game_over::format_score;
-/// assert_eq!(format_score(0),       "0");
-/// assert_eq!(format_score(1000),    "1,000");
-/// assert_eq!(format_score(123456),  "123,456");
-/// assert_eq!(format_score(1234567), "1,234,567");
-/// ```
-pub fn format_score(n: u32) -> String {
-    let s = n.to_string();
-    let mut result = String::new();
-    for (i, c) in s.chars().rev().enumerate() {
-        if i > 0 && i % 3 == 0 {
-            result.push(',');
-        }
-        result.push(c);
+/// Lines without data (no merge yet, restored game without a seed code) are
+/// left out, and so is the seed code in streamer-safe mode, like on the
+/// screen itself.
+pub fn result_snippet(
+    game_state: &GameState,
+    stats: &SessionStats,
+    safe_mode: &SafeMode,
+    lang: Language,
+) -> String {
+    let mut lines = vec![format!(
+        "{} — {}: {}",
+        t("game_title", lang),
+        t("score", lang),
+        // Shared text keeps ASCII digits whatever the display setting
+        format_number(game_state.score, lang, false)
+    )];
+    if let Some(fruit) = stats.largest_fruit {
+        lines.push(format!("{}: {fruit:?}", t("hud_largest_fruit", lang)));
     }
-    result.chars().rev().collect()
+    if stats.best_combo > 1 {
+        lines.push(format!("{}: x{}", t("best_combo", lang), stats.best_combo));
+    }
+    if let Some(code) = stats.seed_code.filter(|_| !safe_mode.active) {
+        lines.push(format!("{}: {code}", t("seed_code", lang)));
+    }
+    lines.join("\n")
 }
 
 // ---------------------------------------------------------------------------
@@ -224,31 +454,64 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_format_score_zero() {
-        assert_eq!(format_score(0), "0");
-    }
+    fn test_records_line() {
+        use suika_game_core::prelude::FruitType;
 
-    #[test]
-    fn test_format_score_below_thousand() {
-        assert_eq!(format_score(999), "999");
-    }
+        let fresh = records_line(&GameState::default(), Language::English);
+        assert_eq!(fresh, "Best Combo: -  /  Best Fruit: -");
 
-    #[test]
-    fn test_format_score_thousands() {
-        assert_eq!(format_score(1_000), "1,000");
-        assert_eq!(format_score(10_000), "10,000");
-        assert_eq!(format_score(100_000), "100,000");
+        let game_state = GameState {
+            best_combo_record: 6,
+            highest_fruit_record: Some(FruitType::Melon),
+            ..default()
+        };
+        assert_eq!(
+            records_line(&game_state, Language::English),
+            "Best Combo: ×6  /  Best Fruit: Melon"
+        );
+        assert!(records_line(&game_state, Language::Japanese).ends_with("メロン"));
     }
 
     #[test]
-    fn test_format_score_millions() {
-        assert_eq!(format_score(1_000_000), "1,000,000");
-        assert_eq!(format_score(1_234_567), "1,234,567");
-    }
+    fn test_result_snippet() {
+        use suika_game_core::prelude::{FruitType, GameMode, SeedCode};
 
-    #[test]
-    fn test_format_score_u32_max() {
-        // u32::MAX = 4,294,967,295
-        assert_eq!(format_score(u32::MAX), "4,294,967,295");
+        let game_state = GameState {
+            score: 12_345,
+            ..default()
+        };
+        let code = SeedCode::new(7, GameMode::Classic, 5);
+        let stats = SessionStats {
+            largest_fruit: Some(FruitType::Melon),
+            best_combo: 4,
+            seed_code: Some(code),
+            ..default()
+        };
+        let snippet = result_snippet(&game_state, &stats, &SafeMode::default(), Language::English);
+        let lines: Vec<&str> = snippet.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].ends_with("12,345"));
+        assert!(lines[1].ends_with("Melon"));
+        assert!(lines[2].ends_with("x4"));
+        assert!(lines[3].ends_with(&code.to_string()));
+
+        let bare = result_snippet(
+            &game_state,
+            &SessionStats::default(),
+            &SafeMode::default(),
+            Language::English,
+        );
+        assert_eq!(bare.lines().count(), 1);
+
+        // Streamer-safe mode leaves the seed code out
+        let safe = result_snippet(
+            &game_state,
+            &stats,
+            &SafeMode { active: true },
+            Language::English,
+        );
+        assert_eq!(safe.lines().count(), 3);
+        assert!(!safe.contains(&code.to_string()));
     }
 }
@@ -14,11 +14,11 @@
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
 use suika_game_core::prelude::GameState;
-use suika_game_core::resources::settings::Language;
+use suika_game_core::resources::settings::{Language, SettingsResource};
 
 use crate::config::BestScoreHudConfig;
 use crate::i18n::t;
-use crate::screens::title::format_score;
+use crate::number_format::format_score;
 use crate::styles::{BG_COLOR, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, PRIMARY_COLOR, TEXT_COLOR};
 
 // ---------------------------------------------------------------------------
@@ -99,10 +99,11 @@ pub fn spawn_best_score_widget(
 /// Updates the best-score text node from [`GameState`].
 pub fn update_best_score(
     game_state: Res<GameState>,
+    settings: Res<SettingsResource>,
     mut best_score_q: Query<&mut Text, With<HudBestScore>>,
 ) {
     if let Ok(mut text) = best_score_q.single_mut() {
-        text.0 = format_score(game_state.highscore);
+        text.0 = format_score(game_state.highscore, &settings);
     }
 }
 
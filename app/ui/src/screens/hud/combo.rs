@@ -0,0 +1,266 @@
+//! Combo counter.
+//!
+//! Shows the running combo ([`ComboTimer::current_combo`]) while a chain of
+//! merges is in progress.  When a combo of three or more runs out, core sends
+//! a [`ComboBrokenEvent`] and the counter fades and shrinks away instead of
+//! vanishing, so the player sees that the chain window closed.  Shorter
+//! combos simply disappear.
+//!
+//! ```text
+//! ┌──────────────┐
+//! │  コンボ ×4    │  ← HudCombo (hidden while there is no combo)
+//! └──────────────┘
+//! ```
+//!
+//! # Usage
+//!
+//! ```ignore
+//! parent_anchor.with_children(|p| combo::spawn_combo_widget(p, &font));
+//! app.add_systems(
+//!     Update,
+//!     combo::update_combo
+//!         .after(tick_combo_timer)
+//!         .run_if(in_state(AppState::Playing)),
+//! );
+//! ```
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::{ComboBrokenEvent, ComboTimer, SettingsResource};
+
+use crate::i18n::t;
+use crate::styles::{BG_COLOR, FONT_SIZE_MEDIUM, PRIMARY_COLOR};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Duration of the combo-break fade (seconds).
+const FADE_DURATION: f32 = 0.6;
+
+/// Scale of the counter at the end of the fade.
+const FADE_END_SCALE: f32 = 0.7;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the combo counter panel and tracks what it shows.
+#[derive(Component, Debug, Default)]
+pub struct HudCombo {
+    /// Combo count last displayed (`0` before the first combo).
+    pub shown: u32,
+    /// Seconds into the combo-break fade, `None` while not fading.
+    pub fade: Option<f32>,
+}
+
+/// Marks the text node inside the combo counter panel.
+#[derive(Component, Debug)]
+pub struct HudComboText;
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the (initially hidden) combo counter as a child of `parent`.
+pub fn spawn_combo_widget(parent: &mut ChildSpawnerCommands, font: &Handle<Font>) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            BorderRadius::all(Val::Px(8.0)),
+            Visibility::Hidden,
+            HudCombo::default(),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_MEDIUM,
+                    ..default()
+                },
+                TextColor(PRIMARY_COLOR),
+                HudComboText,
+            ));
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Shows the running combo and plays the fade when a [`ComboBrokenEvent`]
+/// arrives.
+#[allow(clippy::type_complexity)]
+pub fn update_combo(
+    time: Res<Time>,
+    combo_timer: Res<ComboTimer>,
+    settings: Res<SettingsResource>,
+    mut broken_events: MessageReader<ComboBrokenEvent>,
+    mut panel_q: Query<(
+        &mut HudCombo,
+        &mut Visibility,
+        &mut UiTransform,
+        &mut BackgroundColor,
+    )>,
+    mut text_q: Query<(&mut Text, &mut TextColor), With<HudComboText>>,
+) {
+    let broken = broken_events.read().last().is_some();
+    let Ok((mut combo, mut visibility, mut transform, mut bg)) = panel_q.single_mut() else {
+        return;
+    };
+    let Ok((mut text, mut color)) = text_q.single_mut() else {
+        return;
+    };
+
+    if combo_timer.is_combo() {
+        combo.fade = None;
+        if combo.shown != combo_timer.current_combo {
+            combo.shown = combo_timer.current_combo;
+            text.0 = combo_label(combo.shown, t("hud_combo", settings.language));
+        }
+        *visibility = Visibility::Inherited;
+        apply_fade(1.0, &mut transform, &mut bg, &mut color);
+        return;
+    }
+
+    if broken && combo.shown > 0 {
+        combo.fade = Some(0.0);
+    }
+    let Some(elapsed) = combo.fade.map(|e| e + time.delta_secs()) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    if elapsed >= FADE_DURATION {
+        combo.fade = None;
+        *visibility = Visibility::Hidden;
+        apply_fade(1.0, &mut transform, &mut bg, &mut color);
+    } else {
+        combo.fade = Some(elapsed);
+        *visibility = Visibility::Inherited;
+        apply_fade(
+            1.0 - elapsed / FADE_DURATION,
+            &mut transform,
+            &mut bg,
+            &mut color,
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Counter text, e.g. `Combo ×4`.
+fn combo_label(combo: u32, label: &str) -> String {
+    format!("{label} ×{combo}")
+}
+
+/// Sets opacity and scale for the remaining fade fraction `remaining`
+/// (`1` = fully shown, `0` = gone).
+fn apply_fade(
+    remaining: f32,
+    transform: &mut UiTransform,
+    bg: &mut BackgroundColor,
+    color: &mut TextColor,
+) {
+    let remaining = remaining.clamp(0.0, 1.0);
+    transform.scale = Vec2::splat(fade_scale(remaining));
+    bg.0 = BG_COLOR.with_alpha(BG_COLOR.alpha() * remaining);
+    color.0 = PRIMARY_COLOR.with_alpha(remaining);
+}
+
+/// Counter scale for the remaining fade fraction `remaining`.
+fn fade_scale(remaining: f32) -> f32 {
+    FADE_END_SCALE + (1.0 - FADE_END_SCALE) * remaining
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    /// Fixed frame time; the fade takes three frames.
+    const FRAME: Duration = Duration::from_millis(200);
+
+    #[test]
+    fn test_combo_label() {
+        assert_eq!(combo_label(4, "Combo"), "Combo ×4");
+    }
+
+    #[test]
+    fn test_fade_scale_endpoints() {
+        assert_eq!(fade_scale(1.0), 1.0);
+        assert_eq!(fade_scale(0.0), FADE_END_SCALE);
+    }
+
+    /// Builds an app with a bare combo counter.
+    fn combo_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .add_message::<ComboBrokenEvent>()
+            .init_resource::<ComboTimer>()
+            .init_resource::<SettingsResource>()
+            .add_systems(Update, update_combo);
+        app.world_mut().spawn((
+            HudCombo::default(),
+            Visibility::Hidden,
+            UiTransform::default(),
+            BackgroundColor(BG_COLOR),
+        ));
+        app.world_mut()
+            .spawn((Text::new(""), TextColor(PRIMARY_COLOR), HudComboText));
+        app
+    }
+
+    fn combo_state(app: &mut App) -> (Option<f32>, Visibility) {
+        let (combo, visibility) = app
+            .world_mut()
+            .query::<(&HudCombo, &Visibility)>()
+            .single(app.world())
+            .unwrap();
+        (combo.fade, *visibility)
+    }
+
+    #[test]
+    fn test_break_fades_instead_of_hiding() {
+        let mut app = combo_app();
+        app.world_mut().resource_mut::<ComboTimer>().current_combo = 4;
+        app.update();
+        assert_eq!(combo_state(&mut app).1, Visibility::Inherited);
+
+        app.world_mut().resource_mut::<ComboTimer>().current_combo = 1;
+        app.world_mut().write_message(ComboBrokenEvent { combo: 4 });
+        app.update();
+        let (fade, visibility) = combo_state(&mut app);
+        assert!(fade.is_some(), "break should start the fade");
+        assert_eq!(visibility, Visibility::Inherited);
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(combo_state(&mut app), (None, Visibility::Hidden));
+    }
+
+    #[test]
+    fn test_short_combo_hides_without_fade() {
+        let mut app = combo_app();
+        app.world_mut().resource_mut::<ComboTimer>().current_combo = 2;
+        app.update();
+
+        app.world_mut().resource_mut::<ComboTimer>().current_combo = 1;
+        app.update();
+        assert_eq!(combo_state(&mut app), (None, Visibility::Hidden));
+    }
+}
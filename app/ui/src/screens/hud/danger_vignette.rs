@@ -0,0 +1,189 @@
+//! Danger vignette widget.
+//!
+//! A full-screen red radial gradient that darkens the screen edges while a
+//! fruit is above the boundary line.  It is switched on and off by
+//! [`BoundaryWarningEvent`]s and its strength follows
+//! [`GameOverTimer::warning_progress`], so the closer the game is to ending
+//! the redder the edges get.  Complements the boundary-line blink in core.
+//! The clear centre of the gradient sits on the container
+//! ([`ContainerScreenRect`]), so the red closes in around the board even when
+//! the window is wider than it.
+//!
+//! # Usage
+//!
+//! ```ignore
+//! root.with_children(|p| danger_vignette::spawn_danger_vignette(p, timer.is_warning));
+//! app.add_systems(Update, danger_vignette::update_danger_vignette.run_if(in_state(AppState::Playing)));
+//! ```
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::{BoundaryWarningEvent, GameOverTimer};
+
+use crate::camera::ContainerScreenRect;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Vignette color at full strength (alpha is applied separately).
+const VIGNETTE_COLOR: Color = Color::srgb(0.85, 0.05, 0.05);
+
+/// Edge alpha as soon as the warning starts.
+const MIN_ALPHA: f32 = 0.15;
+
+/// Edge alpha when the warning is about to end the game.
+const MAX_ALPHA: f32 = 0.6;
+
+/// How fast the displayed alpha chases its target (alpha units per second).
+const FADE_SPEED: f32 = 3.0;
+
+/// Distance from the centre (in % of the farthest corner) where the red starts.
+const INNER_STOP_PERCENT: f32 = 55.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the vignette node and stores its animated state.
+#[derive(Component, Debug, Default)]
+pub struct DangerVignette {
+    /// `true` between a `Started` and the following `Cleared` event.
+    pub active: bool,
+    /// Alpha currently displayed at the screen edges.
+    pub alpha: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the (initially invisible) vignette as a full-screen child of `parent`.
+///
+/// Spawn it before the other HUD widgets so it is drawn underneath them.
+/// Pass `active = GameOverTimer::is_warning` so a warning that started before
+/// the HUD was rebuilt (e.g. across a pause) is picked up again.
+pub fn spawn_danger_vignette(parent: &mut ChildSpawnerCommands, active: bool) {
+    parent.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        vignette_gradient(0.0, UiPosition::CENTER),
+        DangerVignette { active, alpha: 0.0 },
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Fades the vignette in and out following the boundary warning and keeps
+/// it centred on the container.
+pub fn update_danger_vignette(
+    mut warning_events: MessageReader<BoundaryWarningEvent>,
+    game_over_timer: Res<GameOverTimer>,
+    time: Res<Time>,
+    container: Res<ContainerScreenRect>,
+    mut vignette_query: Query<(&mut DangerVignette, &mut BackgroundGradient)>,
+) {
+    let last_event = warning_events.read().last().copied();
+
+    for (mut vignette, mut gradient) in vignette_query.iter_mut() {
+        match last_event {
+            Some(BoundaryWarningEvent::Started) => vignette.active = true,
+            Some(BoundaryWarningEvent::Cleared) => vignette.active = false,
+            None => {}
+        }
+
+        let target = if vignette.active {
+            target_alpha(game_over_timer.warning_progress())
+        } else {
+            0.0
+        };
+        let step = FADE_SPEED * time.delta_secs();
+        let alpha = vignette.alpha + (target - vignette.alpha).clamp(-step, step);
+
+        if alpha != vignette.alpha || container.is_changed() {
+            vignette.alpha = alpha;
+            *gradient = vignette_gradient(alpha, vignette_center(&container));
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Edge alpha for a warning `progress` in `0.0..=1.0`.
+fn target_alpha(progress: f32) -> f32 {
+    MIN_ALPHA + (MAX_ALPHA - MIN_ALPHA) * progress.clamp(0.0, 1.0)
+}
+
+/// Centre of the gradient: the container centre, or the screen centre while
+/// the container position is unknown.
+fn vignette_center(container: &ContainerScreenRect) -> UiPosition {
+    match container.rect {
+        Some(rect) => {
+            let center = rect.center();
+            UiPosition::top_left(Val::Px(center.x), Val::Px(center.y))
+        }
+        None => UiPosition::CENTER,
+    }
+}
+
+/// Builds the radial gradient: transparent `center`, red edges at `alpha`.
+fn vignette_gradient(alpha: f32, center: UiPosition) -> BackgroundGradient {
+    BackgroundGradient::from(RadialGradient::new(
+        center,
+        RadialGradientShape::FarthestCorner,
+        vec![
+            ColorStop::percent(Color::NONE, INNER_STOP_PERCENT),
+            ColorStop::percent(VIGNETTE_COLOR.with_alpha(alpha), 100.0),
+        ],
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_alpha_range() {
+        assert_eq!(target_alpha(0.0), MIN_ALPHA);
+        assert_eq!(target_alpha(1.0), MAX_ALPHA);
+    }
+
+    #[test]
+    fn test_target_alpha_clamps_progress() {
+        assert_eq!(target_alpha(-1.0), MIN_ALPHA);
+        assert_eq!(target_alpha(2.0), MAX_ALPHA);
+    }
+
+    #[test]
+    fn test_vignette_center_follows_container() {
+        assert_eq!(
+            vignette_center(&ContainerScreenRect::default()),
+            UiPosition::CENTER
+        );
+        let container = ContainerScreenRect {
+            rect: Some(Rect::new(100.0, 0.0, 700.0, 800.0)),
+            viewport: Vec2::new(1200.0, 800.0),
+        };
+        assert_eq!(
+            vignette_center(&container),
+            UiPosition::top_left(Val::Px(400.0), Val::Px(400.0))
+        );
+    }
+
+    #[test]
+    fn test_target_alpha_is_monotonic() {
+        assert!(target_alpha(0.25) < target_alpha(0.75));
+    }
+}
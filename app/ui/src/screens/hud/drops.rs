@@ -0,0 +1,234 @@
+//! Drop counter and efficiency widget.
+//!
+//! Shows how many fruits have been dropped this run ([`GameState::drops`])
+//! and the average points earned per drop ([`GameState::points_per_drop`]),
+//! for players who want to squeeze more score out of every fruit.
+//! While racing the ghost run
+//! ([`suika_game_core::systems::ghost_run`]) a third row shows how far the
+//! score is ahead of or behind the best run at the same moment.
+//! Positioning is left to the caller — typically [`super::setup_hud`] wraps
+//! this widget in an absolute-positioned anchor node.
+//!
+//! ```text
+//! ┌─────────────────┐
+//! │ ドロップ   42    │  ← HudDrops
+//! │ 効率     18.5   │  ← HudEfficiency
+//! │ ベスト比  +120   │  ← HudPace (ghost run only)
+//! └─────────────────┘
+//! ```
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::{BestRun, GameState};
+use suika_game_core::resources::settings::Language;
+
+use crate::i18n::t;
+use crate::styles::{BG_COLOR, FONT_SIZE_SMALL, PRIMARY_COLOR, TEXT_COLOR};
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the [`Text`] node that displays the drop count.
+#[derive(Component, Debug)]
+pub struct HudDrops;
+
+/// Marks the [`Text`] node that displays the points-per-drop value.
+#[derive(Component, Debug)]
+pub struct HudEfficiency;
+
+/// Marks the [`Text`] node that displays the score difference to the best
+/// run.
+#[derive(Component, Debug)]
+pub struct HudPace;
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the drops / efficiency panel as a child of `parent`, with the
+/// best-run row when `show_pace` is set.
+pub fn spawn_drops_widget(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    lang: Language,
+    show_pace: bool,
+) {
+    parent
+        .spawn((
+            Node {
+                display: Display::Grid,
+                grid_template_columns: vec![GridTrack::auto(), GridTrack::auto()],
+                column_gap: Val::Px(12.0),
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            BorderRadius::all(Val::Px(8.0)),
+        ))
+        .with_children(|panel| {
+            spawn_stat_row(panel, font, t("hud_drops", lang), "0", HudDrops);
+            spawn_stat_row(panel, font, t("hud_efficiency", lang), "-", HudEfficiency);
+            if show_pace {
+                spawn_stat_row(
+                    panel,
+                    font,
+                    t("hud_vs_best", lang),
+                    &format_pace(0),
+                    HudPace,
+                );
+            }
+        });
+}
+
+/// Spawns one label / value pair into the two-column grid.
+fn spawn_stat_row(
+    panel: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    label: &str,
+    value: &str,
+    marker: impl Component,
+) {
+    panel.spawn((
+        Text::new(label),
+        TextFont {
+            font: font.clone(),
+            font_size: FONT_SIZE_SMALL,
+            ..default()
+        },
+        TextColor(TEXT_COLOR),
+    ));
+    panel.spawn((
+        Text::new(value),
+        TextFont {
+            font: font.clone(),
+            font_size: FONT_SIZE_SMALL,
+            ..default()
+        },
+        TextColor(PRIMARY_COLOR),
+        Node {
+            justify_self: JustifySelf::End,
+            ..default()
+        },
+        marker,
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Updates the drop count and efficiency text from [`GameState`].
+pub fn update_drops(
+    game_state: Res<GameState>,
+    mut drops_q: Query<&mut Text, (With<HudDrops>, Without<HudEfficiency>)>,
+    mut efficiency_q: Query<&mut Text, (With<HudEfficiency>, Without<HudDrops>)>,
+) {
+    if let Ok(mut text) = drops_q.single_mut() {
+        text.0 = game_state.drops.to_string();
+    }
+    if let Ok(mut text) = efficiency_q.single_mut() {
+        text.0 = format_efficiency(game_state.points_per_drop());
+    }
+}
+
+/// Updates the best-run row with the score difference to [`BestRun`] at
+/// the current game time.
+pub fn update_pace(
+    game_state: Res<GameState>,
+    best_run: Res<BestRun>,
+    mut query: Query<&mut Text, With<HudPace>>,
+) {
+    if let Ok(mut text) = query.single_mut() {
+        let best = best_run.score_at(game_state.elapsed_time);
+        text.0 = format_pace(i64::from(game_state.score) - i64::from(best));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Formats points per drop with one decimal, or `-` before the first drop.
+///
+/// # Examples
+///
+/// ```
+/// # use suika_game_ui::screens::hud::drops::format_efficiency;
+/// assert_eq!(format_efficiency(None), "-");
+/// assert_eq!(format_efficiency(Some(18.46)), "18.5");
+/// ```
+pub fn format_efficiency(points_per_drop: Option<f32>) -> String {
+    match points_per_drop {
+        Some(value) => format!("{value:.1}"),
+        None => "-".to_string(),
+    }
+}
+
+/// Formats a score difference with its sign, `±0` when level.
+///
+/// # Examples
+///
+/// ```
+/// # use suika_game_ui::screens::hud::drops::format_pace;
+/// assert_eq!(format_pace(120), "+120");
+/// assert_eq!(format_pace(-80), "-80");
+/// ```
+pub fn format_pace(diff: i64) -> String {
+    match diff {
+        0 => "±0".to_string(),
+        d if d > 0 => format!("+{d}"),
+        d => d.to_string(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_efficiency() {
+        assert_eq!(format_efficiency(None), "-");
+        assert_eq!(format_efficiency(Some(0.0)), "0.0");
+        assert_eq!(format_efficiency(Some(125.0 / 3.0)), "41.7");
+    }
+
+    #[test]
+    fn test_format_pace() {
+        assert_eq!(format_pace(0), "±0");
+        assert_eq!(format_pace(5), "+5");
+        assert_eq!(format_pace(-1_200), "-1200");
+    }
+
+    #[test]
+    fn test_update_drops_shows_count_and_efficiency() {
+        let mut app = App::new();
+        app.insert_resource(GameState {
+            score: 300,
+            drops: 8,
+            ..default()
+        })
+        .add_systems(Update, update_drops);
+        app.world_mut().spawn((Text::new("0"), HudDrops));
+        app.world_mut().spawn((Text::new("-"), HudEfficiency));
+        app.update();
+
+        let drops = app
+            .world_mut()
+            .query_filtered::<&Text, With<HudDrops>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(drops.0, "8");
+        let efficiency = app
+            .world_mut()
+            .query_filtered::<&Text, With<HudEfficiency>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(efficiency.0, "37.5");
+    }
+}
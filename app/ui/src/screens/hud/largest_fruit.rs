@@ -0,0 +1,341 @@
+//! Largest-fruit badge.
+//!
+//! Shows the largest fruit reached in the current run — an icon of the fruit
+//! and its stage out of 11 — taken from [`SessionStats::largest_fruit`], which
+//! core updates from every merge.  Each time the record grows the icon plays
+//! a short pop so the player notices the new milestone.
+//!
+//! ```text
+//! ┌──────────────┐
+//! │ 最大フルーツ  │  ← FONT_SIZE_SMALL, TEXT_COLOR
+//! │   (●) 7/11   │  ← icon (HudLargestFruitIcon) + stage (HudLargestFruitStage)
+//! └──────────────┘
+//! ```
+//!
+//! # Usage
+//!
+//! ```ignore
+//! parent_anchor.with_children(|p| largest_fruit::spawn_largest_fruit_widget(p, &font, lang));
+//! app.add_systems(Update, largest_fruit::update_largest_fruit.run_if(in_state(AppState::Playing)));
+//! app.add_systems(Update, largest_fruit::animate_largest_fruit_pulse.after(largest_fruit::update_largest_fruit));
+//! ```
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::{FruitSprites, FruitType, SessionStats};
+use suika_game_core::resources::settings::Language;
+
+use crate::i18n::t;
+use crate::styles::{BG_COLOR, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, PRIMARY_COLOR, TEXT_COLOR};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Resting edge length of the fruit icon (pixels).
+const ICON_SIZE: f32 = 32.0;
+
+/// Duration of the new-record pop (seconds).
+const PULSE_DURATION: f32 = 0.45;
+
+/// Icon scale at the peak of the pop.
+const PULSE_PEAK_SCALE: f32 = 1.6;
+
+/// Number of fruit stages, shown as the denominator of the stage text.
+const STAGE_COUNT: usize = FruitType::Watermelon as usize + 1;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the badge panel; remembers which fruit it currently shows.
+#[derive(Component, Debug, Default)]
+pub struct HudLargestFruit {
+    /// Fruit currently displayed (`None` until the first merge).
+    pub shown: Option<FruitType>,
+    /// Set once the badge has caught up with the record after spawning.
+    pub synced: bool,
+}
+
+/// Marks the fruit icon node inside the badge.
+#[derive(Component, Debug)]
+pub struct HudLargestFruitIcon;
+
+/// Marks the stage text node inside the badge.
+#[derive(Component, Debug)]
+pub struct HudLargestFruitStage;
+
+/// Drives the new-record pop on the icon.
+///
+/// Follows the same `1 + (peak − 1) × sin(π t)` envelope as the score pulse
+/// and is removed once `elapsed` reaches [`PULSE_DURATION`].
+#[derive(Component, Debug, Clone, Default)]
+pub struct LargestFruitPulse {
+    /// Elapsed time since the pop started, in seconds
+    pub elapsed: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the largest-fruit badge as a child of `parent`.
+///
+/// The icon stays hidden until the first merge of the run.
+pub fn spawn_largest_fruit_widget(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    lang: Language,
+) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            BorderRadius::all(Val::Px(8.0)),
+            HudLargestFruit::default(),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(t("hud_largest_fruit", lang)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+            ));
+            panel
+                .spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    // Reserve the popped size so the pulse does not shift the text
+                    min_height: Val::Px(ICON_SIZE * PULSE_PEAK_SCALE),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Node {
+                            width: Val::Px(ICON_SIZE),
+                            height: Val::Px(ICON_SIZE),
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                        BorderRadius::all(Val::Percent(50.0)),
+                        ImageNode::default(),
+                        Visibility::Hidden,
+                        HudLargestFruitIcon,
+                    ));
+                    row.spawn((
+                        Text::new("-"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: FONT_SIZE_MEDIUM,
+                            ..default()
+                        },
+                        TextColor(PRIMARY_COLOR),
+                        HudLargestFruitStage,
+                    ));
+                });
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Refreshes the badge when [`SessionStats::largest_fruit`] changes.
+///
+/// The pop only plays when the record grows while the badge is on screen;
+/// a freshly spawned badge (new HUD after resuming from pause or continuing
+/// a saved game) just shows the current record.
+#[allow(clippy::type_complexity)]
+pub fn update_largest_fruit(
+    mut commands: Commands,
+    stats: Res<SessionStats>,
+    fruit_sprites: Option<Res<FruitSprites>>,
+    mut badge_q: Query<&mut HudLargestFruit>,
+    mut icon_q: Query<
+        (
+            Entity,
+            &mut ImageNode,
+            &mut BackgroundColor,
+            &mut BorderRadius,
+            &mut Visibility,
+        ),
+        With<HudLargestFruitIcon>,
+    >,
+    mut stage_q: Query<&mut Text, With<HudLargestFruitStage>>,
+) {
+    let Ok(mut badge) = badge_q.single_mut() else {
+        return;
+    };
+    let celebrate = badge.synced;
+    badge.synced = true;
+    if badge.shown == stats.largest_fruit {
+        return;
+    }
+    let Some(fruit) = stats.largest_fruit else {
+        return;
+    };
+    badge.shown = Some(fruit);
+
+    if let Ok(mut text) = stage_q.single_mut() {
+        text.0 = stage_label(fruit);
+    }
+    let Ok((entity, mut image, mut bg, mut radius, mut visibility)) = icon_q.single_mut() else {
+        return;
+    };
+    *visibility = Visibility::Inherited;
+    if let Some(handle) = fruit_sprites.as_deref().and_then(|s| s.get(fruit)) {
+        image.image = handle.clone();
+        image.texture_atlas = fruit_sprites.as_deref().and_then(|s| s.atlas(fruit));
+        image.color = Color::WHITE;
+        bg.0 = Color::NONE;
+        *radius = BorderRadius::ZERO;
+    } else {
+        image.image = Handle::default();
+        image.texture_atlas = None;
+        image.color = Color::NONE;
+        bg.0 = fruit.placeholder_color();
+        *radius = BorderRadius::all(Val::Percent(50.0));
+    }
+    if celebrate {
+        commands.entity(entity).insert(LargestFruitPulse::default());
+    }
+}
+
+/// Advances the [`LargestFruitPulse`] by resizing the icon node.
+pub fn animate_largest_fruit_pulse(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut LargestFruitPulse, &mut Node)>,
+    time: Res<Time>,
+) {
+    for (entity, mut pulse, mut node) in query.iter_mut() {
+        pulse.elapsed += time.delta_secs();
+
+        let scale = if pulse.elapsed >= PULSE_DURATION {
+            commands.entity(entity).remove::<LargestFruitPulse>();
+            1.0
+        } else {
+            pulse_scale(pulse.elapsed / PULSE_DURATION)
+        };
+        node.width = Val::Px(ICON_SIZE * scale);
+        node.height = Val::Px(ICON_SIZE * scale);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Stage text for `fruit`, 1-based: Cherry is `1/11`, Watermelon `11/11`.
+fn stage_label(fruit: FruitType) -> String {
+    format!("{}/{STAGE_COUNT}", fruit.stage_index() + 1)
+}
+
+/// Icon scale at pulse progress `t` in `[0, 1]`.
+fn pulse_scale(t: f32) -> f32 {
+    (1.0 + (PULSE_PEAK_SCALE - 1.0) * (std::f32::consts::PI * t).sin()).max(1.0)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds an app with a bare badge and `stats` as the run's record.
+    fn badge_app(stats: SessionStats) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(stats)
+            .add_systems(Update, update_largest_fruit);
+        let icon = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                ImageNode::default(),
+                BackgroundColor(Color::NONE),
+                BorderRadius::ZERO,
+                Visibility::Hidden,
+                HudLargestFruitIcon,
+            ))
+            .id();
+        app.world_mut()
+            .spawn((Text::new("-"), HudLargestFruitStage));
+        app.world_mut().spawn(HudLargestFruit::default());
+        app.insert_resource(TestIcon(icon));
+        app
+    }
+
+    #[derive(Resource)]
+    struct TestIcon(Entity);
+
+    fn set_largest(app: &mut App, fruit: FruitType) {
+        app.world_mut()
+            .resource_mut::<SessionStats>()
+            .record_fruit(fruit);
+        app.update();
+    }
+
+    fn has_pulse(app: &App) -> bool {
+        let icon = app.world().resource::<TestIcon>().0;
+        app.world().get::<LargestFruitPulse>(icon).is_some()
+    }
+
+    #[test]
+    fn test_stage_label() {
+        assert_eq!(stage_label(FruitType::Cherry), "1/11");
+        assert_eq!(stage_label(FruitType::Watermelon), "11/11");
+    }
+
+    #[test]
+    fn test_pulse_scale_envelope() {
+        assert!((pulse_scale(0.0) - 1.0).abs() < 1e-5);
+        assert!((pulse_scale(0.5) - PULSE_PEAK_SCALE).abs() < 1e-5);
+        assert!((pulse_scale(1.0) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_new_record_pulses_icon() {
+        let mut app = badge_app(SessionStats::default());
+        app.update();
+        assert!(!has_pulse(&app));
+
+        set_largest(&mut app, FruitType::Grape);
+        assert!(has_pulse(&app));
+        let text = app
+            .world_mut()
+            .query_filtered::<&Text, With<HudLargestFruitStage>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(text.0, "3/11");
+    }
+
+    #[test]
+    fn test_respawned_badge_shows_record_without_pulse() {
+        let mut app = badge_app(SessionStats {
+            largest_fruit: Some(FruitType::Melon),
+            ..default()
+        });
+        app.update();
+
+        assert!(!has_pulse(&app));
+        let icon = app.world().resource::<TestIcon>().0;
+        assert_eq!(
+            app.world().get::<BackgroundColor>(icon).unwrap().0,
+            FruitType::Melon.placeholder_color()
+        );
+
+        set_largest(&mut app, FruitType::Watermelon);
+        assert!(has_pulse(&app));
+    }
+}
@@ -0,0 +1,156 @@
+//! Imminent-watermelon hint.
+//!
+//! Shown while two Melons are on the board ([`MelonPair::is_imminent`]),
+//! alongside the sparkle link core draws between them.  The panel breathes
+//! gently so it catches the eye without competing with the score.
+//!
+//! ```text
+//! ┌──────────────────────┐
+//! │  スイカまであと一歩！  │  ← HudMelonHint (hidden without a Melon pair)
+//! └──────────────────────┘
+//! ```
+//!
+//! # Usage
+//!
+//! ```ignore
+//! parent_anchor.with_children(|p| melon_hint::spawn_melon_hint_widget(p, &font, lang));
+//! app.add_systems(
+//!     Update,
+//!     melon_hint::update_melon_hint
+//!         .after(detect_melon_pair)
+//!         .run_if(in_state(AppState::Playing)),
+//! );
+//! ```
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::MelonPair;
+use suika_game_core::resources::settings::Language;
+
+use crate::i18n::t;
+use crate::styles::{BG_COLOR, FONT_SIZE_SMALL, HIGHLIGHT_COLOR};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Breathing rate of the panel (radians per second).
+const BREATH_RATE: f32 = 4.0;
+
+/// Scale change at the top of a breath.
+const BREATH_DEPTH: f32 = 0.05;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the hint panel.
+#[derive(Component, Debug)]
+pub struct HudMelonHint;
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the (initially hidden) hint as a child of `parent`.
+pub fn spawn_melon_hint_widget(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    lang: Language,
+) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            BorderRadius::all(Val::Px(8.0)),
+            Visibility::Hidden,
+            HudMelonHint,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(t("hud_melon_hint", lang)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(HIGHLIGHT_COLOR),
+            ));
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Shows the hint while a Watermelon is one merge away and lets it breathe.
+pub fn update_melon_hint(
+    time: Res<Time>,
+    pair: Res<MelonPair>,
+    mut panel_q: Query<(&mut Visibility, &mut UiTransform), With<HudMelonHint>>,
+) {
+    let Ok((mut visibility, mut transform)) = panel_q.single_mut() else {
+        return;
+    };
+    if !pair.is_imminent() {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    }
+    visibility.set_if_neq(Visibility::Inherited);
+    transform.scale = Vec2::splat(breath_scale(time.elapsed_secs()));
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Panel scale `now` seconds into the game.
+fn breath_scale(now: f32) -> f32 {
+    1.0 + BREATH_DEPTH * (now * BREATH_RATE).sin()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breath_stays_subtle() {
+        for step in 0..50 {
+            let scale = breath_scale(step as f32 * 0.1);
+            assert!((1.0 - BREATH_DEPTH..=1.0 + BREATH_DEPTH).contains(&scale));
+        }
+    }
+
+    #[test]
+    fn test_hint_follows_the_melon_pair() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<MelonPair>()
+            .add_systems(Update, update_melon_hint);
+        let panel = app
+            .world_mut()
+            .spawn((HudMelonHint, Visibility::Hidden, UiTransform::default()))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(panel),
+            Some(&Visibility::Hidden)
+        );
+
+        let melon = app.world_mut().spawn_empty().id();
+        app.world_mut().resource_mut::<MelonPair>().0 = Some((melon, melon));
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(panel),
+            Some(&Visibility::Inherited)
+        );
+    }
+}
@@ -9,17 +9,33 @@
 //! | [`best_score`]| ベストスコアパネル   |
 //! | [`score`]     | スコアパネル        |
 //! | [`next`]      | ネクストラベル      |
+//! | [`largest_fruit`] | 最大フルーツバッジ |
+//! | [`drops`]     | ドロップ数・効率     |
+//! | [`combo`]     | コンボ数            |
+//! | [`melon_hint`]| スイカ間近のヒント   |
+//! | [`onboarding`]| 初回プレイのヒント   |
+//! | [`danger_vignette`] | 危険時の赤いビネット |
+//! | [`sandbox`]   | サンドボックス操作パネル |
+//! | [`touch_controls`] | タッチ操作バー |
+//! | [`visualizer`]| BGMビジュアライザー  |
 //!
 //! # Layout
 //!
 //! ```text
 //! ┌──────────────────────────────────────────────────────────┐
-//! │  [ベストスコア]  [スコア]              [ネクスト]         │
-//! │                                                          │
+//! │  [ベストスコア]  [スコア][▂▅█▃]  [スイカ間近]  [ネクスト]      │
+//! │  [最大フルーツ]  [ドロップ / 効率]                        │
+//! │  [コンボ]                                                 │
 //! │                  [game container]                        │
+//! │  [sandbox panel]  [onboarding hints]   [|| ◀ ▼ ▶]        │
 //! └──────────────────────────────────────────────────────────┘
 //! ```
 //!
+//! When the window is too narrow for that grid beside the container,
+//! [`HudCompact`] switches the left-hand panels to the single-column
+//! [`CompactHudLayout`](crate::config::CompactHudLayout) so they stay clear
+//! of the play area.
+//!
 //! # Adding a new widget
 //!
 //! 1. Create `hud/<widget>.rs` with a `spawn_<widget>` function and optional
@@ -31,18 +47,30 @@
 //! 4. Register `update_<widget>` in [`crate::GameUIPlugin`].
 
 pub mod best_score;
+pub mod combo;
+pub mod danger_vignette;
+pub mod drops;
+pub mod largest_fruit;
+pub mod melon_hint;
 pub mod next;
+pub mod onboarding;
+pub mod sandbox;
 pub mod score;
 pub mod score_popup;
+pub mod touch_controls;
+pub mod visualizer;
 
 use bevy::prelude::*;
-use suika_game_core::prelude::{AppState, SettingsResource};
+use suika_game_core::prelude::{
+    AppState, AttractMode, BestRun, GameOverTimer, SandboxMode, SettingsResource,
+};
 
+use crate::camera::ContainerScreenRect;
 use crate::config::{
     BestScoreHudConfig, BestScoreHudConfigHandle, HudLayoutConfig, HudLayoutConfigHandle,
-    NextHudConfig, NextHudConfigHandle, ScoreHudConfig, ScoreHudConfigHandle,
+    HudPosition, NextHudConfig, NextHudConfigHandle, ScoreHudConfig, ScoreHudConfigHandle,
 };
-use crate::styles::FONT_JP;
+use crate::styles::{FONT_JP, FONT_SYMBOL};
 
 // ---------------------------------------------------------------------------
 // Anchor marker components (used by hot-reload systems in config.rs)
@@ -60,6 +88,33 @@ pub struct HudScoreAnchor;
 #[derive(Component)]
 pub struct HudNextAnchor;
 
+/// Marks the absolute-positioned anchor node that holds the largest-fruit badge.
+#[derive(Component)]
+pub struct HudLargestFruitAnchor;
+
+/// Marks the absolute-positioned anchor node that holds the drops widget.
+#[derive(Component)]
+pub struct HudDropsAnchor;
+
+/// Marks the absolute-positioned anchor node that holds the combo counter.
+#[derive(Component)]
+pub struct HudComboAnchor;
+
+/// The [`HudPosition`] an anchor node was placed at, kept so
+/// [`align_hud_to_container`] can move it when the container moves on screen.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HudPlacement(pub HudPosition);
+
+/// Whether the HUD uses its compact layout, updated by
+/// [`update_hud_compact`].
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HudCompact(pub bool);
+
+/// Anchor node bundle for a widget at `position`.
+fn anchor_node(position: HudPosition, container: &ContainerScreenRect) -> (Node, HudPlacement) {
+    (position.node(container), HudPlacement(position))
+}
+
 // ---------------------------------------------------------------------------
 // Systems
 // ---------------------------------------------------------------------------
@@ -69,12 +124,20 @@ pub struct HudNextAnchor;
 /// Reads layout values from the per-widget RON configs when available,
 /// falling back to built-in defaults otherwise.
 /// Creates a transparent full-screen root node and positions each widget
-/// inside absolute-positioned anchor containers.  Add new widgets here.
+/// inside an absolute anchor container placed by its
+/// [`HudPosition`](crate::config::HudPosition), so widgets keep their place
+/// relative to the window edges when it is resized.  Add new widgets here.
+///
+/// During an attract-mode demo the root is hidden, leaving only the board and
+/// the attract overlay on screen.
 #[allow(clippy::too_many_arguments)]
 pub fn setup_hud(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     settings: Res<SettingsResource>,
+    game_over_timer: Res<GameOverTimer>,
+    sandbox: Res<SandboxMode>,
+    container: Res<ContainerScreenRect>,
     layout_handle: Res<HudLayoutConfigHandle>,
     layout_assets: Res<Assets<HudLayoutConfig>>,
     score_handle: Res<ScoreHudConfigHandle>,
@@ -83,6 +146,9 @@ pub fn setup_hud(
     best_score_assets: Res<Assets<BestScoreHudConfig>>,
     next_handle: Res<NextHudConfigHandle>,
     next_assets: Res<Assets<NextHudConfig>>,
+    attract: Res<AttractMode>,
+    best_run: Res<BestRun>,
+    compact: Res<HudCompact>,
 ) {
     let font: Handle<Font> = asset_server.load(FONT_JP);
     let lang = settings.language;
@@ -94,7 +160,8 @@ pub fn setup_hud(
 
     let layout = layout_assets
         .get(&layout_handle.0)
-        .unwrap_or(&default_layout);
+        .unwrap_or(&default_layout)
+        .resolve(lang, compact.0);
     let score_cfg = score_assets.get(&score_handle.0).unwrap_or(&default_score);
     let best_score_cfg = best_score_assets
         .get(&best_score_handle.0)
@@ -110,19 +177,24 @@ pub fn setup_hud(
                 ..default()
             },
             BackgroundColor(Color::NONE),
+            if attract.active {
+                Visibility::Hidden
+            } else {
+                Visibility::Inherited
+            },
             DespawnOnExit(AppState::Playing),
         ))
         .with_children(|root| {
+            // ------------------------------------------------------------------
+            // Full screen: danger vignette (spawned first so it sits underneath)
+            // ------------------------------------------------------------------
+            danger_vignette::spawn_danger_vignette(root, game_over_timer.is_warning);
+
             // ------------------------------------------------------------------
             // Top-left: best score widget
             // ------------------------------------------------------------------
             root.spawn((
-                Node {
-                    position_type: PositionType::Absolute,
-                    top: Val::Px(layout.edge_margin),
-                    left: Val::Px(layout.edge_margin),
-                    ..default()
-                },
+                anchor_node(layout.best_score, &container),
                 HudBestScoreAnchor,
             ))
             .with_children(|anchor| {
@@ -130,39 +202,141 @@ pub fn setup_hud(
             });
 
             // ------------------------------------------------------------------
-            // Top-left (second): current score widget
+            // Top-left (second): current score widget, with the BGM
+            // visualizer beside it when switched on
+            // ------------------------------------------------------------------
+            root.spawn((anchor_node(layout.score, &container), HudScoreAnchor))
+                .with_children(|anchor| {
+                    score::spawn_score_widget(anchor, &font, score_cfg, lang);
+                    if settings.audio_visualizer {
+                        visualizer::spawn_visualizer_widget(anchor);
+                    }
+                });
+
+            // ------------------------------------------------------------------
+            // Left, below the best score: largest-fruit badge
             // ------------------------------------------------------------------
             root.spawn((
-                Node {
-                    position_type: PositionType::Absolute,
-                    top: Val::Px(layout.edge_margin),
-                    left: Val::Px(layout.edge_margin + layout.score_panel_offset),
-                    ..default()
-                },
-                HudScoreAnchor,
+                anchor_node(layout.largest_fruit, &container),
+                HudLargestFruitAnchor,
             ))
             .with_children(|anchor| {
-                score::spawn_score_widget(anchor, &font, score_cfg, lang);
+                largest_fruit::spawn_largest_fruit_widget(anchor, &font, lang);
             });
 
+            // ------------------------------------------------------------------
+            // Left, below the score: drop counter / efficiency, plus the
+            // score difference to the best run while racing its ghost
+            // ------------------------------------------------------------------
+            let show_pace = settings.ghost_run && !best_run.is_empty() && !sandbox.active;
+            root.spawn((anchor_node(layout.drops, &container), HudDropsAnchor))
+                .with_children(|anchor| {
+                    drops::spawn_drops_widget(anchor, &font, lang, show_pace);
+                });
+
+            // ------------------------------------------------------------------
+            // Left, third row: combo counter (hidden while there is no combo)
+            // ------------------------------------------------------------------
+            root.spawn((anchor_node(layout.combo, &container), HudComboAnchor))
+                .with_children(|anchor| {
+                    combo::spawn_combo_widget(anchor, &font);
+                });
+
+            // ------------------------------------------------------------------
+            // Top centre: imminent-watermelon hint (hidden without two Melons)
+            // ------------------------------------------------------------------
+            root.spawn(anchor_node(layout.melon_hint, &container))
+                .with_children(|anchor| {
+                    melon_hint::spawn_melon_hint_widget(anchor, &font, lang);
+                });
+
             // ------------------------------------------------------------------
             // Right side: next-fruit widget
             // ------------------------------------------------------------------
-            root.spawn((
-                Node {
-                    position_type: PositionType::Absolute,
-                    top: Val::Px(layout.next_top),
-                    right: Val::Px(layout.next_right),
-                    ..default()
-                },
-                HudNextAnchor,
-            ))
-            .with_children(|anchor| {
-                next::spawn_next_widget(anchor, &font, next_cfg, lang);
-            });
+            root.spawn((anchor_node(layout.next, &container), HudNextAnchor))
+                .with_children(|anchor| {
+                    next::spawn_next_widget(anchor, &font, next_cfg, lang);
+                });
+
+            // ------------------------------------------------------------------
+            // Bottom centre: first-run onboarding hints (until completed)
+            // ------------------------------------------------------------------
+            if !settings.onboarding_completed {
+                root.spawn(anchor_node(layout.onboarding, &container))
+                    .with_children(|anchor| {
+                        onboarding::spawn_onboarding_widget(anchor, &font, lang);
+                    });
+            }
+
+            // ------------------------------------------------------------------
+            // Bottom-left: sandbox controls (sandbox games only)
+            // ------------------------------------------------------------------
+            if sandbox.active {
+                root.spawn(anchor_node(layout.sandbox, &container))
+                    .with_children(|anchor| {
+                        sandbox::spawn_sandbox_widget(anchor, &font, &sandbox, lang);
+                    });
+            }
+
+            // ------------------------------------------------------------------
+            // Bottom-right: touch control strip (when switched on)
+            // ------------------------------------------------------------------
+            if settings.touch_controls {
+                let symbol_font: Handle<Font> = asset_server.load(FONT_SYMBOL);
+                root.spawn(anchor_node(layout.touch_controls, &container))
+                    .with_children(|anchor| {
+                        touch_controls::spawn_touch_controls(anchor, &symbol_font);
+                    });
+            }
         });
 }
 
+/// Re-places container-relative HUD anchors whenever [`ContainerScreenRect`]
+/// changes (window resize, camera zoom, physics config reload).
+pub fn align_hud_to_container(
+    container: Res<ContainerScreenRect>,
+    mut anchor_query: Query<(&HudPlacement, &mut Node)>,
+) {
+    if !container.is_changed() {
+        return;
+    }
+    for (placement, mut node) in anchor_query.iter_mut() {
+        if placement.0.is_container_relative() {
+            placement.0.apply(&mut node, &container);
+        }
+    }
+}
+
+/// Switches [`HudCompact`] on while the free width beside the container is
+/// below the layout's [`below_margin`](crate::config::CompactHudLayout::below_margin).
+///
+/// [`hot_reload_hud_layout`](crate::config::hot_reload_hud_layout) re-places
+/// the anchors when the flag flips.
+pub fn update_hud_compact(
+    container: Res<ContainerScreenRect>,
+    layout_handle: Option<Res<HudLayoutConfigHandle>>,
+    layout_assets: Res<Assets<HudLayoutConfig>>,
+    mut compact: ResMut<HudCompact>,
+) {
+    let Some(rect) = container.rect else {
+        return;
+    };
+    let below_margin = layout_handle
+        .and_then(|handle| layout_assets.get(&handle.0).map(|l| l.compact.below_margin))
+        .unwrap_or_else(|| HudLayoutConfig::default().compact.below_margin);
+    compact.set_if_neq(HudCompact(needs_compact(
+        rect,
+        container.viewport,
+        below_margin,
+    )));
+}
+
+/// `true` when the narrower gap between the container (`rect`) and the
+/// window edge (`viewport`) is below `below_margin`.
+fn needs_compact(rect: Rect, viewport: Vec2, below_margin: f32) -> bool {
+    rect.min.x.min(viewport.x - rect.max.x) < below_margin
+}
+
 // ---------------------------------------------------------------------------
 // Helper — elapsed time formatter (available to timer widget when added)
 // ---------------------------------------------------------------------------
@@ -189,6 +363,18 @@ pub fn format_elapsed(total_secs: u32) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_needs_compact() {
+        let viewport = Vec2::new(1280.0, 800.0);
+        // 600 px container centred in 1280 px: 340 px each side
+        let rect = Rect::new(340.0, 0.0, 940.0, 800.0);
+        assert!(!needs_compact(rect, viewport, 336.0));
+        assert!(needs_compact(rect, viewport, 341.0));
+        // Centred in the 800 px default window: 100 px each side
+        let rect = Rect::new(100.0, 0.0, 700.0, 600.0);
+        assert!(needs_compact(rect, Vec2::new(800.0, 600.0), 336.0));
+    }
+
     #[test]
     fn test_format_elapsed_zero() {
         assert_eq!(format_elapsed(0), "0:00");
@@ -1,11 +1,21 @@
 //! Next-fruit widget.
 //!
-//! Renders a "ネクスト" label with a coloured circle beneath it that mirrors
-//! the [`NextFruitType`] resource.  Both the label and the preview live inside
-//! a single UI column, so they always stay together regardless of layout
-//! changes in [`super::setup_hud`].
+//! Renders a "ネクスト" label above a row of mini fruits: the
+//! [`NextFruitType`] first, followed by the fruits waiting in the
+//! [`FruitQueue`].  Each mini fruit is sized from its radius in
+//! [`FruitsConfig`], so the player can tell at a glance which of the upcoming
+//! fruits are the big ones.  Label and row live inside a single UI column, so
+//! they always stay together regardless of layout changes in
+//! [`super::setup_hud`].
 //!
-//! The preview circle is hidden while no active (held or falling) fruit exists,
+//! ```text
+//!      ネクスト
+//! ┌──────┐ ┌───┐ ┌──┐
+//! │ next │ │ 2 │ │3 │   ← HudNextSlot(0), (1), (2)
+//! └──────┘ └───┘ └──┘
+//! ```
+//!
+//! The slots are hidden while no active (held or falling) fruit exists,
 //! matching the original game's behaviour.
 //!
 //! # Usage
@@ -17,20 +27,49 @@
 
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
-use suika_game_core::prelude::{Fruit, FruitSpawnState, FruitSprites, NextFruitType};
+use suika_game_core::prelude::{
+    Fruit, FruitQueue, FruitSpawnState, FruitSprites, FruitType, FruitsConfig, FruitsParams,
+    NextFruitType,
+};
 use suika_game_core::resources::settings::Language;
 
-use crate::config::NextHudConfig;
+use crate::config::{NextHudConfig, NextHudConfigHandle};
 use crate::i18n::t;
 use crate::styles::{FONT_SIZE_SMALL, TEXT_COLOR};
 
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Number of slots: the next fruit plus the queue behind it.
+pub const SLOT_COUNT: usize = FruitQueue::LEN + 1;
+
+/// Smallest mini fruit relative to its slot's full size, so a cherry next to
+/// a persimmon stays recognisable.
+const MIN_SIZE_RATIO: f32 = 0.35;
+
+/// Horizontal gap between the mini fruits (pixels).
+const SLOT_GAP: f32 = 8.0;
+
 // ---------------------------------------------------------------------------
 // Marker component
 // ---------------------------------------------------------------------------
 
-/// Marks the UI node used as the next-fruit preview circle.
+/// Marks one mini fruit in the next-fruit row.
 #[derive(Component, Debug)]
-pub struct HudNextPreview;
+pub struct HudNextSlot {
+    /// Position in the row; `0` is the next fruit.
+    pub index: usize,
+    /// Fruit currently drawn, `None` until the first refresh.
+    pub shown: Option<FruitType>,
+}
+
+impl HudNextSlot {
+    /// Forgets the drawn fruit so the next [`update_next`] redraws the slot.
+    pub fn invalidate(&mut self) {
+        self.shown = None;
+    }
+}
 
 // ---------------------------------------------------------------------------
 // Spawn helper
@@ -38,16 +77,9 @@ pub struct HudNextPreview;
 
 /// Spawns the next-fruit widget as children of `parent`.
 ///
-/// The preview circle diameter comes from `cfg.preview_size`.
-///
-/// Layout (column, center-aligned):
-///
-/// ```text
-/// ネクスト              ← FONT_SIZE_SMALL, TEXT_COLOR
-/// ┌──────────┐
-/// │  [color] │         ← preview_size × preview_size circle, HudNextPreview
-/// └──────────┘
-/// ```
+/// The slots start at their full size (`cfg.preview_size`, scaled by
+/// `cfg.queue_scale` after the first) and are resized per fruit by
+/// [`update_next`].
 pub fn spawn_next_widget(
     parent: &mut ChildSpawnerCommands,
     font: &Handle<Font>,
@@ -73,19 +105,30 @@ pub fn spawn_next_widget(
                 TextColor(TEXT_COLOR),
             ));
 
-            // Preview circle / sprite
-            col.spawn((
-                Node {
-                    width: Val::Px(cfg.preview_size),
-                    height: Val::Px(cfg.preview_size),
-                    ..default()
-                },
-                BackgroundColor(Color::WHITE),
-                BorderRadius::all(Val::Percent(50.0)),
-                ImageNode::default(),
-                Visibility::Hidden,
-                HudNextPreview,
-            ));
+            // Mini fruits, bottom-aligned like fruits resting on a shelf
+            col.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::End,
+                column_gap: Val::Px(SLOT_GAP),
+                ..default()
+            })
+            .with_children(|row| {
+                for index in 0..SLOT_COUNT {
+                    let size = slot_max_size(index, cfg);
+                    row.spawn((
+                        Node {
+                            width: Val::Px(size),
+                            height: Val::Px(size),
+                            ..default()
+                        },
+                        BackgroundColor(Color::WHITE),
+                        BorderRadius::all(Val::Percent(50.0)),
+                        ImageNode::default(),
+                        Visibility::Hidden,
+                        HudNextSlot { index, shown: None },
+                    ));
+                }
+            });
         });
 }
 
@@ -93,29 +136,44 @@ pub fn spawn_next_widget(
 // Systems
 // ---------------------------------------------------------------------------
 
-/// Updates the next-fruit preview circle every frame.
+/// Updates the mini fruits every frame.
 ///
-/// - **Sprite / colour**: refreshed whenever [`NextFruitType`] or [`FruitSprites`] changes.
-///   Uses the real sprite image when available; falls back to a tinted placeholder circle.
+/// - **Fruit / size / sprite**: a slot is redrawn whenever its fruit changes
+///   or [`FruitSprites`] changes.  Uses the real sprite image when available;
+///   falls back to a tinted placeholder circle.
 /// - **Visibility**: shown while a held or falling fruit exists; hidden otherwise.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn update_next(
     next_fruit: Res<NextFruitType>,
+    queue: Res<FruitQueue>,
     fruit_states: Query<&FruitSpawnState, With<Fruit>>,
-    mut preview_q: Query<
-        (&mut BackgroundColor, &mut Visibility, &mut ImageNode, &mut BorderRadius),
-        With<HudNextPreview>,
-    >,
+    mut slot_q: Query<(
+        &mut HudNextSlot,
+        &mut Node,
+        &mut BackgroundColor,
+        &mut Visibility,
+        &mut ImageNode,
+        &mut BorderRadius,
+    )>,
     fruit_sprites: Option<Res<FruitSprites>>,
+    fruits: FruitsParams,
+    next_handle: Res<NextHudConfigHandle>,
+    next_assets: Res<Assets<NextHudConfig>>,
 ) {
     let has_active = fruit_states
         .iter()
         .any(|s| *s == FruitSpawnState::Held || *s == FruitSpawnState::Falling);
+    let sprites_changed = fruit_sprites.as_ref().is_some_and(|s| s.is_changed());
+    let upcoming = queue.with_next(&next_fruit);
 
-    let sprites_changed = fruit_sprites.as_ref().map(|s| s.is_changed()).unwrap_or(false);
-    let should_update_sprite = next_fruit.is_changed() || sprites_changed;
+    let default_cfg = NextHudConfig::default();
+    let cfg = next_assets.get(&next_handle.0).unwrap_or(&default_cfg);
 
-    for (mut bg, mut vis, mut image_node, mut border_radius) in preview_q.iter_mut() {
-        let desired = if has_active {
+    for (mut slot, mut node, mut bg, mut vis, mut image_node, mut border_radius) in
+        slot_q.iter_mut()
+    {
+        let fruit = upcoming.get(slot.index).copied();
+        let desired = if has_active && fruit.is_some() {
             Visibility::Visible
         } else {
             Visibility::Hidden
@@ -124,26 +182,81 @@ pub fn update_next(
             *vis = desired;
         }
 
-        // Always refresh so newly-spawned HUD widgets get the correct state.
-        if should_update_sprite || image_node.image == Handle::default() {
-            if let Some(handle) = fruit_sprites.as_deref().and_then(|s| s.get(next_fruit.get())) {
-                // Real sprite available — show it directly, no circle clipping.
-                image_node.image = handle.clone();
-                image_node.color = Color::WHITE;
-                *bg = BackgroundColor(Color::NONE);
-                *border_radius = BorderRadius::ZERO;
-            } else {
-                // Fallback: tinted placeholder circle.
-                // Set image transparent so BackgroundColor shows through.
-                image_node.image = Handle::default();
-                image_node.color = Color::NONE;
-                *bg = BackgroundColor(next_fruit.get().placeholder_color());
-                *border_radius = BorderRadius::all(Val::Percent(50.0));
-            }
+        let Some(fruit) = fruit else {
+            continue;
+        };
+        if slot.shown == Some(fruit) && !sprites_changed {
+            continue;
+        }
+        slot.shown = Some(fruit);
+
+        let size = Val::Px(slot_size(fruit, slot.index, fruits.get(), cfg));
+        node.width = size;
+        node.height = size;
+
+        if let Some(handle) = fruit_sprites.as_deref().and_then(|s| s.get(fruit)) {
+            // Real sprite available — show it directly, no circle clipping.
+            image_node.image = handle.clone();
+            image_node.texture_atlas = fruit_sprites.as_deref().and_then(|s| s.atlas(fruit));
+            image_node.color = Color::WHITE;
+            *bg = BackgroundColor(Color::NONE);
+            *border_radius = BorderRadius::ZERO;
+        } else {
+            // Fallback: tinted placeholder circle.
+            // Set image transparent so BackgroundColor shows through.
+            image_node.image = Handle::default();
+            image_node.texture_atlas = None;
+            image_node.color = Color::NONE;
+            *bg = BackgroundColor(fruit.placeholder_color());
+            *border_radius = BorderRadius::all(Val::Percent(50.0));
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Full size of slot `index` (pixels): the next fruit gets
+/// `cfg.preview_size`, queued fruits `cfg.queue_scale` of that.
+fn slot_max_size(index: usize, cfg: &NextHudConfig) -> f32 {
+    if index == 0 {
+        cfg.preview_size
+    } else {
+        cfg.preview_size * cfg.queue_scale
+    }
+}
+
+/// Size of `fruit` in slot `index` (pixels).
+///
+/// Scales the slot's full size by the fruit's drawn radius relative to the
+/// largest spawnable fruit, so the biggest possible drop fills its slot.
+/// Without a fruits config every fruit fills its slot.
+fn slot_size(
+    fruit: FruitType,
+    index: usize,
+    fruits: Option<&FruitsConfig>,
+    cfg: &NextHudConfig,
+) -> f32 {
+    let max = slot_max_size(index, cfg);
+    let Some(fruits) = fruits else {
+        return max;
+    };
+    let drawn_radius = |fruit: FruitType| {
+        fruit
+            .try_parameters_from_config(fruits)
+            .map(|p| p.radius * p.sprite_scale)
+    };
+    let largest = FruitType::spawnable_fruits()
+        .into_iter()
+        .filter_map(drawn_radius)
+        .fold(0.0, f32::max);
+    match drawn_radius(fruit) {
+        Some(radius) if largest > 0.0 => max * (radius / largest).clamp(MIN_SIZE_RATIO, 1.0),
+        _ => max,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -151,14 +264,57 @@ pub fn update_next(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use suika_game_core::prelude::FruitConfigEntry;
 
-    #[test]
-    fn test_hud_next_preview_marker_exists() {
-        let _n = HudNextPreview;
+    fn fruits_config(radii: &[f32]) -> FruitsConfig {
+        FruitsConfig {
+            fruits: radii
+                .iter()
+                .map(|&radius| FruitConfigEntry {
+                    radius,
+                    ..default()
+                })
+                .collect(),
+        }
     }
 
     #[test]
     fn test_default_preview_size_is_positive() {
-        assert!(crate::config::NextHudConfig::default().preview_size > 0.0);
+        assert!(NextHudConfig::default().preview_size > 0.0);
+    }
+
+    #[test]
+    fn test_queued_slots_are_smaller() {
+        let cfg = NextHudConfig::default();
+        assert!(slot_max_size(1, &cfg) < slot_max_size(0, &cfg));
+        assert_eq!(slot_max_size(1, &cfg), slot_max_size(2, &cfg));
+    }
+
+    #[test]
+    fn test_slot_size_follows_fruit_radius() {
+        let cfg = NextHudConfig::default();
+        let fruits = fruits_config(&[10.0, 20.0, 30.0, 40.0, 50.0]);
+        assert_eq!(
+            slot_size(FruitType::Persimmon, 0, Some(&fruits), &cfg),
+            cfg.preview_size
+        );
+        assert_eq!(
+            slot_size(FruitType::Grape, 0, Some(&fruits), &cfg),
+            cfg.preview_size * 0.6
+        );
+        // Tiny fruits are clamped so they stay visible
+        assert_eq!(
+            slot_size(FruitType::Cherry, 0, Some(&fruits), &cfg),
+            cfg.preview_size * MIN_SIZE_RATIO
+        );
+    }
+
+    #[test]
+    fn test_slot_size_without_config_fills_slot() {
+        let cfg = NextHudConfig::default();
+        assert_eq!(
+            slot_size(FruitType::Cherry, 2, None, &cfg),
+            slot_max_size(2, &cfg)
+        );
     }
 }
@@ -0,0 +1,207 @@
+//! First-run onboarding hints widget.
+//!
+//! Shown only while [`SettingsResource::onboarding_completed`] is `false`.
+//! Lists the basic controls and explains the boundary line, then fades out
+//! once the player has dropped [`ONBOARDING_DROP_COUNT`] fruits or pressed
+//! [`ONBOARDING_DISMISS_KEY`].  Either way the completion flag is saved so the
+//! hints never appear again.
+//!
+//! # Usage
+//!
+//! ```ignore
+//! if !settings.onboarding_completed {
+//!     parent_anchor.with_children(|p| onboarding::spawn_onboarding_widget(p, &font, lang));
+//! }
+//! app.add_systems(Update, onboarding::update_onboarding_hints.run_if(in_state(AppState::Playing)));
+//! ```
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::{
+    CurrentProfile, FruitDroppedEvent, SaveErrorEvent, SettingsResource,
+};
+use suika_game_core::resources::settings::Language;
+
+use crate::components::persist_settings;
+use crate::i18n::t;
+use crate::styles::{FONT_SIZE_SMALL, TEXT_COLOR};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Number of drops after which the hints fade out on their own.
+pub const ONBOARDING_DROP_COUNT: u32 = 3;
+
+/// Key that dismisses the hints immediately.
+pub const ONBOARDING_DISMISS_KEY: KeyCode = KeyCode::KeyH;
+
+/// Fade-out duration in seconds.
+const FADE_DURATION: f32 = 1.0;
+
+/// Panel background — translucent white so the board stays visible.
+const PANEL_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.8);
+
+/// Font size for the dismiss hint line.
+const DISMISS_FONT_SIZE: f32 = 16.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the onboarding panel and tracks its progress.
+#[derive(Component, Debug, Default)]
+pub struct OnboardingHints {
+    /// Fruits dropped since the panel appeared.
+    pub drops: u32,
+    /// Seconds spent fading out; `None` while still fully shown.
+    pub fade_elapsed: Option<f32>,
+}
+
+/// Marks every [`Text`] node inside the onboarding panel so the fade can
+/// reach them.
+#[derive(Component, Debug)]
+pub struct OnboardingHintText;
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the onboarding panel as a child of `parent`.
+///
+/// ```text
+/// ┌──────────────────────────────────────┐
+/// │  ← → / A D / マウス：移動              │
+/// │  Space / クリック：落とす              │
+/// │  赤い線より上に積むとゲームオーバー     │
+/// │  H：ヒントを閉じる                     │  ← smaller
+/// └──────────────────────────────────────┘
+/// ```
+pub fn spawn_onboarding_widget(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    lang: Language,
+) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(12.0)),
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            BorderRadius::all(Val::Px(8.0)),
+            OnboardingHints::default(),
+        ))
+        .with_children(|panel| {
+            for (key, size) in [
+                ("onboarding_move", FONT_SIZE_SMALL),
+                ("onboarding_drop", FONT_SIZE_SMALL),
+                ("onboarding_boundary", FONT_SIZE_SMALL),
+                ("onboarding_dismiss", DISMISS_FONT_SIZE),
+            ] {
+                panel.spawn((
+                    Text::new(t(key, lang)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: size,
+                        ..default()
+                    },
+                    TextColor(TEXT_COLOR),
+                    OnboardingHintText,
+                ));
+            }
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Counts drops, starts the fade when onboarding is complete, and despawns
+/// the panel once it has faded out.
+///
+/// The completion flag is written to [`SettingsResource`] and saved to disk
+/// as soon as the fade starts, so quitting mid-fade still counts.
+pub fn update_onboarding_hints(
+    mut commands: Commands,
+    mut dropped_events: MessageReader<FruitDroppedEvent>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut settings: ResMut<SettingsResource>,
+    profile: Res<CurrentProfile>,
+    mut save_errors: MessageWriter<SaveErrorEvent>,
+    mut panel_query: Query<(Entity, &mut OnboardingHints, &mut BackgroundColor)>,
+    mut text_query: Query<&mut TextColor, With<OnboardingHintText>>,
+) {
+    let drops = dropped_events.read().count() as u32;
+
+    let Ok((entity, mut hints, mut bg)) = panel_query.single_mut() else {
+        return;
+    };
+
+    hints.drops += drops;
+
+    if hints.fade_elapsed.is_none()
+        && (hints.drops >= ONBOARDING_DROP_COUNT || keyboard.just_pressed(ONBOARDING_DISMISS_KEY))
+    {
+        hints.fade_elapsed = Some(0.0);
+        if !settings.onboarding_completed {
+            settings.onboarding_completed = true;
+            persist_settings(&settings, &profile, &mut save_errors);
+            info!("Onboarding completed");
+        }
+    }
+
+    let Some(elapsed) = hints.fade_elapsed.as_mut() else {
+        return;
+    };
+    *elapsed += time.delta_secs();
+
+    let alpha = fade_alpha(*elapsed);
+    if alpha <= 0.0 {
+        commands.entity(entity).despawn();
+        return;
+    }
+
+    bg.0 = PANEL_COLOR.with_alpha(PANEL_COLOR.alpha() * alpha);
+    for mut color in text_query.iter_mut() {
+        color.0 = TEXT_COLOR.with_alpha(alpha);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Opacity multiplier after `elapsed` seconds of fading: `1.0 → 0.0` linearly
+/// over [`FADE_DURATION`].
+fn fade_alpha(elapsed: f32) -> f32 {
+    (1.0 - elapsed / FADE_DURATION).clamp(0.0, 1.0)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fade_alpha_starts_opaque() {
+        assert_eq!(fade_alpha(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_fade_alpha_halfway() {
+        assert!((fade_alpha(FADE_DURATION / 2.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fade_alpha_clamped_after_duration() {
+        assert_eq!(fade_alpha(FADE_DURATION * 2.0), 0.0);
+    }
+}
@@ -0,0 +1,168 @@
+//! Sandbox controls panel.
+//!
+//! Shown only while [`SandboxMode::active`] is set.  Lists the sandbox keys
+//! with the current gravity / boundary state:
+//!
+//! ```text
+//! ┌──────────────────────┐
+//! │  SANDBOX             │
+//! │  1–9：フルーツを選ぶ   │
+//! │  G：重力 ON           │
+//! │  B：境界ライン OFF     │
+//! └──────────────────────┘
+//! ```
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::{SandboxMode, SettingsResource};
+use suika_game_core::resources::settings::Language;
+
+use crate::i18n::t;
+use crate::styles::{PRIMARY_COLOR, SECONDARY_COLOR, TEXT_COLOR};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Panel background — translucent white so the board stays visible.
+const PANEL_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.8);
+
+const TITLE_FONT_SIZE: f32 = 20.0;
+const LINE_FONT_SIZE: f32 = 16.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Which sandbox toggle a panel line shows.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxToggleText {
+    /// Shows the gravity toggle
+    Gravity,
+    /// Shows the boundary toggle
+    Boundary,
+}
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the sandbox panel as a child of `parent`.
+pub fn spawn_sandbox_widget(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    sandbox: &SandboxMode,
+    lang: Language,
+) {
+    let text_font = |size: f32| TextFont {
+        font: font.clone(),
+        font_size: size,
+        ..default()
+    };
+
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            BorderRadius::all(Val::Px(8.0)),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(t("sandbox_title", lang)),
+                text_font(TITLE_FONT_SIZE),
+                TextColor(PRIMARY_COLOR),
+            ));
+            panel.spawn((
+                Text::new(t("sandbox_fruit_keys", lang)),
+                text_font(LINE_FONT_SIZE),
+                TextColor(TEXT_COLOR),
+            ));
+            for toggle in [SandboxToggleText::Gravity, SandboxToggleText::Boundary] {
+                panel.spawn((
+                    Text::new(toggle_line(toggle, sandbox, lang)),
+                    text_font(LINE_FONT_SIZE),
+                    TextColor(toggle_color(toggle, sandbox)),
+                    toggle,
+                ));
+            }
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Refreshes the toggle lines when [`SandboxMode`] changes.
+pub fn update_sandbox_widget(
+    sandbox: Res<SandboxMode>,
+    settings: Res<SettingsResource>,
+    mut query: Query<(&mut Text, &mut TextColor, &SandboxToggleText)>,
+) {
+    if !sandbox.is_changed() {
+        return;
+    }
+    for (mut text, mut color, &toggle) in query.iter_mut() {
+        text.0 = toggle_line(toggle, &sandbox, settings.language);
+        color.0 = toggle_color(toggle, &sandbox);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Whether `toggle` is currently on.
+fn toggle_state(toggle: SandboxToggleText, sandbox: &SandboxMode) -> bool {
+    match toggle {
+        SandboxToggleText::Gravity => sandbox.gravity,
+        SandboxToggleText::Boundary => sandbox.boundary,
+    }
+}
+
+/// Text colour for `toggle`: highlighted while switched off.
+fn toggle_color(toggle: SandboxToggleText, sandbox: &SandboxMode) -> Color {
+    if toggle_state(toggle, sandbox) {
+        TEXT_COLOR
+    } else {
+        SECONDARY_COLOR
+    }
+}
+
+/// Panel line for `toggle`, e.g. `G: Gravity ON`.
+fn toggle_line(toggle: SandboxToggleText, sandbox: &SandboxMode, lang: Language) -> String {
+    let key = match toggle {
+        SandboxToggleText::Gravity => "sandbox_gravity",
+        SandboxToggleText::Boundary => "sandbox_boundary",
+    };
+    let value = if toggle_state(toggle, sandbox) {
+        "value_on"
+    } else {
+        "value_off"
+    };
+    format!("{} {}", t(key, lang), t(value, lang))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_line_follows_state() {
+        let mut sandbox = SandboxMode::started();
+        sandbox.boundary = false;
+
+        let gravity = toggle_line(SandboxToggleText::Gravity, &sandbox, Language::English);
+        let boundary = toggle_line(SandboxToggleText::Boundary, &sandbox, Language::English);
+        assert!(gravity.ends_with(t("value_on", Language::English)));
+        assert!(boundary.ends_with(t("value_off", Language::English)));
+    }
+}
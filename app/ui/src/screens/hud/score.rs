@@ -4,11 +4,16 @@
 //! session.  Positioning is left to the caller — typically [`super::setup_hud`]
 //! wraps this widget in an absolute-positioned anchor node.
 //!
+//! The score changes many times a second during a combo chain, so the value
+//! is a [`DigitText`] row of pre-shaped glyphs rather than a [`Text`] node
+//! re-shaped on every change.
+//!
 //! # Usage
 //!
 //! ```ignore
 //! parent_anchor.with_children(|p| score::spawn_score_widget(p, &font, &cfg));
 //! app.add_systems(Update, score::update_score.run_if(in_state(AppState::Playing)));
+//! app.add_systems(Update, digit_text::update_digit_text.after(score::update_score));
 //! app.add_systems(Update, score::animate_score_pulse.after(score::update_score));
 //! ```
 
@@ -18,26 +23,26 @@ use suika_game_core::prelude::GameState;
 use suika_game_core::resources::settings::Language;
 
 use crate::config::{ScoreHudConfig, ScoreHudConfigHandle};
+use crate::digit_text::{DigitText, spawn_digit_text};
 use crate::i18n::t;
-use crate::screens::title::format_score;
 use crate::styles::{BG_COLOR, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, PRIMARY_COLOR, TEXT_COLOR};
 
 // ---------------------------------------------------------------------------
 // Components
 // ---------------------------------------------------------------------------
 
-/// Marks the [`Text`] node that displays the current score value.
+/// Marks the [`DigitText`] row that displays the current score value.
 #[derive(Component, Debug)]
 pub struct HudScore;
 
-/// Drives a font-size pulse animation on the score text node.
+/// Drives a scale pulse animation on the score row.
 ///
 /// Inserted onto the [`HudScore`] entity the first time the current score
 /// exceeds [`GameState::highscore`] in a session.
 /// Removed automatically by [`animate_score_pulse`] when the animation
-/// completes and the font size is snapped back to `base_font_size`.
+/// completes and the scale is snapped back to `1.0`.
 ///
-/// The size multiplier follows `1.0 + (peak_scale − 1.0) × sin(π × t)` where
+/// The scale multiplier follows `1.0 + (peak_scale − 1.0) × sin(π × t)` where
 /// `t = elapsed / duration`, giving a smooth rise-and-fall envelope.
 #[derive(Component, Debug, Clone)]
 pub struct ScorePulse {
@@ -45,8 +50,6 @@ pub struct ScorePulse {
     pub elapsed: f32,
     /// Total duration of the pulse in seconds (loaded from `score.ron`)
     pub duration: f32,
-    /// The resting font size to return to when the pulse ends
-    pub base_font_size: f32,
     /// Peak scale factor at the midpoint of the pulse (loaded from `score.ron`)
     pub peak_scale: f32,
 }
@@ -101,16 +104,7 @@ pub fn spawn_score_widget(
                 },
                 TextColor(TEXT_COLOR),
             ));
-            panel.spawn((
-                Text::new("0"),
-                TextFont {
-                    font: font.clone(),
-                    font_size: FONT_SIZE_MEDIUM,
-                    ..default()
-                },
-                TextColor(PRIMARY_COLOR),
-                HudScore,
-            ));
+            spawn_digit_text(panel, 0, font, FONT_SIZE_MEDIUM, PRIMARY_COLOR).insert(HudScore);
         });
 }
 
@@ -118,7 +112,11 @@ pub fn spawn_score_widget(
 // Systems
 // ---------------------------------------------------------------------------
 
-/// Updates the score text node from [`GameState`].
+/// Updates the score row from [`GameState`].
+///
+/// The [`DigitText`] is only written when the score actually changed, so
+/// [`update_digit_text`](crate::digit_text::update_digit_text) does no work
+/// on quiet frames.
 ///
 /// Triggers a [`ScorePulse`] on the [`HudScore`] entity the first time the
 /// current score exceeds the all-time highscore in a session.
@@ -127,15 +125,17 @@ pub fn spawn_score_widget(
 pub fn update_score(
     mut commands: Commands,
     game_state: Res<GameState>,
-    mut score_q: Query<(Entity, &mut Text), With<HudScore>>,
+    mut score_q: Query<(Entity, &mut DigitText), With<HudScore>>,
     mut was_beating: Local<bool>,
     cfg_handle: Option<Res<ScoreHudConfigHandle>>,
     cfg_assets: Res<Assets<ScoreHudConfig>>,
 ) {
-    let Ok((entity, mut text)) = score_q.single_mut() else {
+    let Ok((entity, mut digits)) = score_q.single_mut() else {
         return;
     };
-    text.0 = format_score(game_state.score);
+    if digits.value != game_state.score {
+        digits.value = game_state.score;
+    }
 
     let default_cfg = ScoreHudConfig::default();
     let cfg = cfg_handle
@@ -148,31 +148,30 @@ pub fn update_score(
         commands.entity(entity).insert(ScorePulse {
             elapsed: 0.0,
             duration: cfg.pulse_duration,
-            base_font_size: FONT_SIZE_MEDIUM,
             peak_scale: cfg.pulse_peak_scale,
         });
     }
     *was_beating = now_beating;
 }
 
-/// Advances the [`ScorePulse`] animation on the score text node.
+/// Advances the [`ScorePulse`] animation on the score row.
 ///
-/// Each frame the font size is set to `base × (1.0 + (peak − 1.0) × sin(π × t))`,
+/// Each frame the [`UiTransform`] scale is set to `1.0 + (peak − 1.0) × sin(π × t)`,
 /// producing a smooth rise-and-fall envelope.  When `elapsed ≥ duration`
-/// the component is removed and the font size is snapped back to `base_font_size`.
+/// the component is removed and the scale is snapped back to `1.0`.
 ///
-/// `TextFont::font_size` is used instead of `Transform::scale` because Bevy's
-/// UI layout pipeline ignores world-space transforms for text rendering.
+/// Scaling the row leaves its pre-shaped glyphs alone, where animating the
+/// font size would re-shape every one of them each frame.
 pub fn animate_score_pulse(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut ScorePulse, &mut TextFont)>,
+    mut query: Query<(Entity, &mut ScorePulse, &mut UiTransform)>,
     time: Res<Time>,
 ) {
-    for (entity, mut pulse, mut text_font) in query.iter_mut() {
+    for (entity, mut pulse, mut transform) in query.iter_mut() {
         pulse.elapsed += time.delta_secs();
 
         if pulse.elapsed >= pulse.duration {
-            text_font.font_size = pulse.base_font_size;
+            transform.scale = Vec2::ONE;
             commands.entity(entity).remove::<ScorePulse>();
             continue;
         }
@@ -182,7 +181,7 @@ pub fn animate_score_pulse(
         // Clamped to avoid floating-point noise pushing the multiplier below 1.0.
         let multiplier =
             (1.0 + (pulse.peak_scale - 1.0) * (std::f32::consts::PI * t).sin()).max(1.0);
-        text_font.font_size = pulse.base_font_size * multiplier;
+        transform.scale = Vec2::splat(multiplier);
     }
 }
 
@@ -260,11 +259,10 @@ mod tests {
                 ScorePulse {
                     elapsed: cfg.pulse_duration,
                     duration: cfg.pulse_duration,
-                    base_font_size: FONT_SIZE_MEDIUM,
                     peak_scale: cfg.pulse_peak_scale,
                 },
-                TextFont {
-                    font_size: FONT_SIZE_MEDIUM,
+                UiTransform {
+                    scale: Vec2::splat(1.2),
                     ..default()
                 },
             ))
@@ -276,10 +274,11 @@ mod tests {
             app.world().get::<ScorePulse>(entity).is_none(),
             "ScorePulse component should be removed when duration is reached"
         );
-        let text_font = app.world().get::<TextFont>(entity).unwrap();
+        let transform = app.world().get::<UiTransform>(entity).unwrap();
         assert_eq!(
-            text_font.font_size, FONT_SIZE_MEDIUM,
-            "Font size should snap back to FONT_SIZE_MEDIUM when pulse ends"
+            transform.scale,
+            Vec2::ONE,
+            "Scale should snap back to 1.0 when pulse ends"
         );
     }
 
@@ -295,11 +294,10 @@ mod tests {
                 ScorePulse {
                     elapsed: 0.0,
                     duration: 10.0, // long duration so it doesn't finish
-                    base_font_size: FONT_SIZE_MEDIUM,
                     peak_scale: ScoreHudConfig::default().pulse_peak_scale,
                 },
-                TextFont {
-                    font_size: FONT_SIZE_MEDIUM,
+                UiTransform {
+                    scale: Vec2::splat(1.2),
                     ..default()
                 },
             ))
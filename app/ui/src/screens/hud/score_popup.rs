@@ -1,8 +1,17 @@
 //! Floating score popup widget.
 //!
-//! Spawns a `Text2d` entity at the merge position when a fruit merge occurs.
+//! Shows a `Text2d` entity at the merge position when a fruit merge occurs.
 //! The text rises upward and fades out over a configurable duration.
 //!
+//! # Pooling
+//!
+//! Long combo chains score several merges per second, and spawning a fresh
+//! text entity for each (then despawning it) churns the text pipeline.  A
+//! finished popup is hidden and parked in [`ScorePopupPool`] instead, and
+//! the next merge reuses it; only popups beyond [`POPUP_POOL_CAPACITY`] are
+//! despawned.  Popups are world-space `Text2d`, so moving one is a plain
+//! [`Transform`] write with no world-to-screen projection per popup.
+//!
 //! # Text format
 //!
 //! | Combo | Text        |
@@ -10,19 +19,30 @@
 //! | 1     | `+10`       |
 //! | 2+    | `+10 ×2`    |
 //!
-//! # Colors
+//! Points are formatted with [`format_score`] (`+1,280`, or `+１，２８０`
+//! with full-width digits).
+//!
+//! # Styling tiers
 //!
-//! | Combo | Color                        |
-//! |-------|------------------------------|
-//! | 1     | White                        |
-//! | 2     | Silver `srgb(0.75, 0.75, 0.82)` |
-//! | 3     | Gold `srgb(1.0, 0.84, 0.0)`  |
-//! | 4+    | Rainbow (hue rotation)       |
+//! Color, size and outline escalate with the combo count and the stage of
+//! the resulting fruit, using the tiers in `score_popup.ron`
+//! ([`popup_style`]).  With the shipped tiers:
+//!
+//! | Trigger           | Color                           | Size  | Outline |
+//! |-------------------|---------------------------------|-------|---------|
+//! | combo 1           | White                           | ×1.0  | —       |
+//! | combo 2           | Silver `srgb(0.75, 0.75, 0.82)` | ×1.1  | 1 px    |
+//! | combo 3           | Gold `srgb(1.0, 0.84, 0.0)`     | ×1.25 | 2 px    |
+//! | combo 4+ (fever)  | Rainbow (hue rotation)          | ×1.25 | 2 px    |
+//! | watermelon merge  | Gold                            | ×1.5  | 3 px    |
 
 use bevy::prelude::*;
+use bevy::sprite::Text2dShadow;
 use suika_game_core::prelude::{FruitsConfig, FruitsConfigHandle, ScoreEarnedEvent};
+use suika_game_core::resources::settings::SettingsResource;
 
-use crate::config::{ScorePopupConfig, ScorePopupConfigHandle};
+use crate::config::{PopupTier, ScorePopupConfig, ScorePopupConfigHandle};
+use crate::number_format::format_score;
 use crate::styles::FONT_JP;
 
 // ---------------------------------------------------------------------------
@@ -32,6 +52,9 @@ use crate::styles::FONT_JP;
 /// Default fruit radius fallback (px) — mirrors the Cherry entry radius in `fruits.ron`.
 const DEFAULT_FRUIT_RADIUS: f32 = 20.0;
 
+/// Finished popups kept hidden for reuse; more than this are despawned.
+pub const POPUP_POOL_CAPACITY: usize = 32;
+
 // ---------------------------------------------------------------------------
 // Component
 // ---------------------------------------------------------------------------
@@ -49,19 +72,33 @@ pub struct ScorePopup {
     pub start_y: f32,
     /// Time at which the alpha fade-out begins (seconds).
     pub fade_start: f32,
-    /// Combo count at the time of the merge — determines color behavior.
+    /// Combo count at the time of the merge.
     pub combo: u32,
+    /// Cycle through the rainbow instead of using `initial_color`.
+    pub rainbow: bool,
+    /// Outline color at full opacity (only used when the popup has a
+    /// [`Text2dShadow`]).
+    pub outline_color: Color,
     /// Angular speed of the hue rotation for rainbow mode (degrees/second).
     pub rainbow_hue_speed: f32,
     /// Base color used for non-rainbow combos (alpha is overridden each frame).
     pub initial_color: Color,
 }
 
+/// Finished, hidden popups waiting to be reused by [`spawn_score_popups`].
+#[derive(Resource, Debug, Default)]
+pub struct ScorePopupPool {
+    /// Pooled popup entities, most recently finished last
+    pub free: Vec<Entity>,
+}
+
 // ---------------------------------------------------------------------------
 // Color helper
 // ---------------------------------------------------------------------------
 
-/// Returns the initial color for a given combo count.
+/// Returns the classic color for a given combo count.
+///
+/// Used by [`popup_style`] when `score_popup.ron` defines no combo tiers.
 ///
 /// ```
 /// # use suika_game_ui::screens::hud::score_popup::color_for_combo;
@@ -81,21 +118,78 @@ pub fn color_for_combo(combo: u32) -> Color {
     }
 }
 
+/// Resolved look of one popup.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PopupStyle {
+    /// Text color (ignored while `rainbow` is set).
+    pub color: Color,
+    /// Font size multiplier.
+    pub scale: f32,
+    /// Outline thickness in pixels; `0.0` draws none.
+    pub outline: f32,
+    /// Whether the popup cycles through the rainbow.
+    pub rainbow: bool,
+}
+
+/// Highest tier in `tiers` whose `min` is at most `value`.
+fn matching_tier(tiers: &[PopupTier], value: u32) -> Option<&PopupTier> {
+    tiers
+        .iter()
+        .filter(|tier| tier.min <= value)
+        .max_by_key(|tier| tier.min)
+}
+
+/// Picks color, size and outline for a merge scored at `combo` that produced
+/// a fruit of `stage` (0 = Cherry).
+///
+/// The combo tier sets the baseline; a matching stage tier overrides the
+/// color and raises scale / outline to at least its own.  Combos of
+/// `rainbow_combo` and above cycle through the rainbow.
+pub fn popup_style(combo: u32, stage: u32, cfg: &ScorePopupConfig) -> PopupStyle {
+    let mut style = match matching_tier(&cfg.combo_tiers, combo) {
+        Some(tier) => PopupStyle {
+            color: tier.color.into(),
+            scale: tier.scale,
+            outline: tier.outline,
+            rainbow: false,
+        },
+        None => PopupStyle {
+            color: if cfg.combo_tiers.is_empty() {
+                color_for_combo(combo)
+            } else {
+                Color::WHITE
+            },
+            scale: 1.0,
+            outline: 0.0,
+            rainbow: false,
+        },
+    };
+    if let Some(tier) = matching_tier(&cfg.stage_tiers, stage) {
+        style.color = tier.color.into();
+        style.scale = style.scale.max(tier.scale);
+        style.outline = style.outline.max(tier.outline);
+    }
+    style.rainbow = combo >= cfg.rainbow_combo;
+    style
+}
+
 // ---------------------------------------------------------------------------
 // Systems
 // ---------------------------------------------------------------------------
 
-/// Spawns floating score popups when fruit merges are scored.
+/// Shows floating score popups when fruit merges are scored.
 ///
 /// Reads [`ScoreEarnedEvent`] each frame. For each event, calculates the
-/// font size from the resulting fruit's radius and spawns a [`Text2d`]
-/// entity with the [`ScorePopup`] component.
+/// font size from the resulting fruit's radius and reuses a pooled popup
+/// from [`ScorePopupPool`], or spawns a new [`Text2d`] entity with the
+/// [`ScorePopup`] component when the pool is empty.
 ///
 /// Each event carries the authoritative `earned_points` (after multiplier)
 /// and `combo_count` for that specific merge, so all popups in a frame
 /// correctly reflect their individual combo state.
 ///
 /// Ordering: must run **after** `update_score_on_merge` which emits the events.
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_score_popups(
     mut commands: Commands,
     mut score_events: MessageReader<ScoreEarnedEvent>,
@@ -104,6 +198,9 @@ pub fn spawn_score_popups(
     popup_handle: Option<Res<ScorePopupConfigHandle>>,
     popup_assets: Res<Assets<ScorePopupConfig>>,
     asset_server: Res<AssetServer>,
+    settings: Res<SettingsResource>,
+    mut pool: ResMut<ScorePopupPool>,
+    popups: Query<(), With<ScorePopup>>,
 ) {
     let Some(fruits_cfg) = fruits_assets.get(&fruits_handle.0) else {
         for _ in score_events.read() {}
@@ -126,18 +223,20 @@ pub fn spawn_score_popups(
             .try_parameters_from_config(fruits_cfg)
             .map(|p| p.radius)
             .unwrap_or(DEFAULT_FRUIT_RADIUS);
-        let font_size = (radius * popup_cfg.font_size_per_radius).max(8.0);
-
         let combo = event.combo_count;
+        let style = popup_style(combo, result_type.stage_index() as u32, popup_cfg);
+        let font_size = (radius * popup_cfg.font_size_per_radius * style.scale).max(8.0);
+
+        let points = format_score(event.earned_points, &settings);
         let text = if combo <= 1 {
-            format!("+{}", event.earned_points)
+            format!("+{points}")
         } else {
-            format!("+{} ×{}", event.earned_points, combo)
+            format!("+{points} ×{combo}")
         };
 
-        let initial_color = color_for_combo(combo);
+        let initial_color = style.color;
 
-        commands.spawn((
+        let bundle = (
             Text2d::new(text),
             TextFont {
                 font: font.clone(),
@@ -146,6 +245,7 @@ pub fn spawn_score_popups(
             },
             TextColor(initial_color),
             Transform::from_translation(event.position.extend(popup_cfg.z_layer)),
+            Visibility::Inherited,
             ScorePopup {
                 elapsed: 0.0,
                 duration: popup_cfg.duration,
@@ -153,10 +253,30 @@ pub fn spawn_score_popups(
                 start_y: event.position.y,
                 fade_start,
                 combo,
+                rainbow: style.rainbow,
+                outline_color: popup_cfg.outline_color.into(),
                 rainbow_hue_speed: popup_cfg.rainbow_hue_speed,
                 initial_color,
             },
-        ));
+        );
+
+        // Reuse a pooled popup if one is still around
+        let mut popup = match take_pooled(&mut pool, &popups) {
+            Some(entity) => {
+                let mut popup = commands.entity(entity);
+                popup.insert(bundle);
+                popup
+            }
+            None => commands.spawn(bundle),
+        };
+        if style.outline > 0.0 {
+            popup.insert(Text2dShadow {
+                offset: Vec2::new(style.outline, -style.outline),
+                color: popup_cfg.outline_color.into(),
+            });
+        } else {
+            popup.remove::<Text2dShadow>();
+        }
     }
 }
 
@@ -164,20 +284,42 @@ pub fn spawn_score_popups(
 ///
 /// - Moves the entity upward proportionally to elapsed time.
 /// - Fades alpha out linearly after `fade_start` seconds.
-/// - For combo ≥ 4: rotates the hue to create a rainbow effect.
-/// - Despawns the entity once `elapsed ≥ duration`.
+/// - For rainbow (fever) popups: rotates the hue.
+/// - Once `elapsed ≥ duration`, hides the entity and returns it to
+///   [`ScorePopupPool`], or despawns it when the pool is full.
+///
+/// Hidden popups are pooled and skipped.
+#[allow(clippy::type_complexity)]
 pub fn update_score_popups(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut ScorePopup, &mut Transform, &mut TextColor)>,
+    mut pool: ResMut<ScorePopupPool>,
+    mut query: Query<(
+        Entity,
+        &mut ScorePopup,
+        &mut Transform,
+        &mut TextColor,
+        &mut Visibility,
+        Option<&mut Text2dShadow>,
+    )>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
 
-    for (entity, mut popup, mut transform, mut text_color) in query.iter_mut() {
+    for (entity, mut popup, mut transform, mut text_color, mut visibility, shadow) in
+        query.iter_mut()
+    {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
         popup.elapsed += dt;
 
         if popup.elapsed >= popup.duration {
-            commands.entity(entity).despawn();
+            if pool.free.len() < POPUP_POOL_CAPACITY {
+                *visibility = Visibility::Hidden;
+                pool.free.push(entity);
+            } else {
+                commands.entity(entity).despawn();
+            }
             continue;
         }
 
@@ -195,16 +337,35 @@ pub fn update_score_popups(
             (1.0 - fade_progress).max(0.0)
         };
 
-        // Color: rainbow for combo ≥ 4, otherwise tint initial_color with alpha
-        text_color.0 = if popup.combo >= 4 {
+        // Color: rainbow during fever, otherwise tint initial_color with alpha
+        text_color.0 = if popup.rainbow {
             let hue = (popup.elapsed * popup.rainbow_hue_speed).rem_euclid(360.0);
             Color::hsla(hue, 1.0, 0.65, alpha)
         } else {
             popup.initial_color.with_alpha(alpha)
         };
+        if let Some(mut shadow) = shadow {
+            shadow.color = popup
+                .outline_color
+                .with_alpha(popup.outline_color.alpha() * alpha);
+        }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Pops the most recently pooled popup that still exists.
+fn take_pooled(pool: &mut ScorePopupPool, popups: &Query<(), With<ScorePopup>>) -> Option<Entity> {
+    while let Some(entity) = pool.free.pop() {
+        if popups.contains(entity) {
+            return Some(entity);
+        }
+    }
+    None
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -282,6 +443,47 @@ mod tests {
         );
     }
 
+    // --- popup_style ---
+
+    #[test]
+    fn test_popup_style_escalates_with_combo() {
+        let cfg = ScorePopupConfig::default();
+        let single = popup_style(1, 0, &cfg);
+        let double = popup_style(2, 0, &cfg);
+        let triple = popup_style(3, 0, &cfg);
+
+        assert_eq!(single.color, Color::WHITE);
+        assert_eq!(single.outline, 0.0);
+        assert!(single.scale < double.scale && double.scale < triple.scale);
+        assert!(single.outline < double.outline && double.outline < triple.outline);
+        assert!(!triple.rainbow);
+        assert!(popup_style(cfg.rainbow_combo, 0, &cfg).rainbow);
+    }
+
+    #[test]
+    fn test_popup_style_watermelon_is_gold_and_largest() {
+        let cfg = ScorePopupConfig::default();
+        let style = popup_style(1, 10, &cfg);
+        let gold = color_for_combo(3).to_srgba();
+        let c = style.color.to_srgba();
+        assert!((c.red - gold.red).abs() < 1e-4 && (c.green - gold.green).abs() < 1e-4);
+        assert!(style.scale >= popup_style(3, 0, &cfg).scale);
+
+        // Stage tiers do not apply below their minimum
+        assert_eq!(popup_style(1, 9, &cfg).color, Color::WHITE);
+    }
+
+    #[test]
+    fn test_popup_style_without_tiers_uses_classic_colors() {
+        let cfg = ScorePopupConfig {
+            combo_tiers: Vec::new(),
+            stage_tiers: Vec::new(),
+            ..default()
+        };
+        assert_eq!(popup_style(2, 0, &cfg).color, color_for_combo(2));
+        assert_eq!(popup_style(2, 0, &cfg).scale, 1.0);
+    }
+
     // --- fade calculation ---
 
     #[test]
@@ -321,29 +523,70 @@ mod tests {
         );
     }
 
-    // --- despawn ---
+    // --- pooling ---
+
+    fn finished_popup() -> ScorePopup {
+        ScorePopup {
+            elapsed: 1.0,
+            duration: 1.0,
+            rise_distance: 80.0,
+            start_y: 0.0,
+            fade_start: 0.5,
+            combo: 1,
+            rainbow: false,
+            outline_color: Color::NONE,
+            rainbow_hue_speed: 180.0,
+            initial_color: Color::WHITE,
+        }
+    }
 
-    #[test]
-    fn test_update_score_popups_despawns_when_done() {
+    fn popup_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
+        app.init_resource::<ScorePopupPool>();
         app.add_systems(Update, update_score_popups);
+        app
+    }
 
+    #[test]
+    fn test_update_score_popups_pools_when_done() {
+        let mut app = popup_app();
         let entity = app
             .world_mut()
             .spawn((
-                ScorePopup {
-                    elapsed: 1.0,
-                    duration: 1.0,
-                    rise_distance: 80.0,
-                    start_y: 0.0,
-                    fade_start: 0.5,
-                    combo: 1,
-                    rainbow_hue_speed: 180.0,
-                    initial_color: Color::WHITE,
-                },
+                finished_popup(),
+                Transform::from_xyz(0.0, 0.0, 8.0),
+                TextColor(Color::WHITE),
+                Visibility::Inherited,
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Visibility>(entity),
+            Some(&Visibility::Hidden),
+            "a finished popup should be hidden, not despawned"
+        );
+        assert_eq!(app.world().resource::<ScorePopupPool>().free, [entity]);
+
+        // Pooled popups are skipped, so it is not pooled twice
+        app.update();
+        assert_eq!(app.world().resource::<ScorePopupPool>().free, [entity]);
+    }
+
+    #[test]
+    fn test_update_score_popups_despawns_when_pool_full() {
+        let mut app = popup_app();
+        app.world_mut().resource_mut::<ScorePopupPool>().free =
+            vec![Entity::PLACEHOLDER; POPUP_POOL_CAPACITY];
+        let entity = app
+            .world_mut()
+            .spawn((
+                finished_popup(),
                 Transform::from_xyz(0.0, 0.0, 8.0),
                 TextColor(Color::WHITE),
+                Visibility::Inherited,
             ))
             .id();
 
@@ -351,7 +594,7 @@ mod tests {
 
         assert!(
             app.world().get_entity(entity).is_err(),
-            "ScorePopup entity should be despawned when elapsed >= duration"
+            "ScorePopup entity should be despawned when the pool is full"
         );
     }
 
@@ -368,6 +611,7 @@ mod tests {
         app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
             16,
         )));
+        app.init_resource::<ScorePopupPool>();
         app.add_systems(Update, update_score_popups);
 
         let entity = app
@@ -380,11 +624,14 @@ mod tests {
                     start_y: 0.0,
                     fade_start: 5.0,
                     combo: 1,
+                    rainbow: false,
+                    outline_color: Color::NONE,
                     rainbow_hue_speed: 180.0,
                     initial_color: Color::WHITE,
                 },
                 Transform::from_xyz(0.0, 0.0, 8.0),
                 TextColor(Color::WHITE),
+                Visibility::Inherited,
             ))
             .id();
 
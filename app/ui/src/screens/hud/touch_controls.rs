@@ -0,0 +1,167 @@
+//! On-screen touch control strip.
+//!
+//! Shown while [`SettingsResource::touch_controls`] is on (by default on
+//! touch platforms).  Each button holds one [`InputAction`] while pressed:
+//!
+//! ```text
+//! ┌──────────────────────────┐
+//! │  [ || ] [ ◀ ] [ ▼ ] [ ▶ ] │
+//! └──────────────────────────┘
+//!   Pause   Left  Drop  Right
+//! ```
+//!
+//! [`press_touch_controls`] writes the pressed buttons into core's
+//! [`OnScreenActions`] before the input actions are collected, so the strip
+//! behaves like any other device.  A press on the strip belongs to the UI
+//! ([`PointerOverUi`](suika_game_core::prelude::PointerOverUi)): the finger
+//! neither aims the fruit nor drops it when lifted.
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::{InputAction, OnScreenActions};
+
+use crate::styles::{BUTTON_HOVER, BUTTON_NORMAL, BUTTON_PRESSED, TEXT_COLOR};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Buttons of the strip, left to right: glyph and the action it holds.
+const STRIP_BUTTONS: [(&str, InputAction); 4] = [
+    ("||", InputAction::Pause),
+    ("◀", InputAction::MoveLeft),
+    ("▼", InputAction::Drop),
+    ("▶", InputAction::MoveRight),
+];
+
+/// Side of a square strip button, in pixels — comfortably finger-sized.
+const BUTTON_SIZE: f32 = 64.0;
+
+/// Gap between the buttons, in pixels.
+const BUTTON_GAP: f32 = 12.0;
+
+const GLYPH_FONT_SIZE: f32 = 28.0;
+
+/// Button opacity, so the board shows through the strip.
+const BUTTON_ALPHA: f32 = 0.8;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// A strip button holding this action while pressed.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TouchControlButton(pub InputAction);
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the touch control strip as a child of `parent`.
+///
+/// `symbol_font` must cover the arrow glyphs (see
+/// [`FONT_SYMBOL`](crate::styles::FONT_SYMBOL)).
+pub fn spawn_touch_controls(parent: &mut ChildSpawnerCommands, symbol_font: &Handle<Font>) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(BUTTON_GAP),
+            ..default()
+        })
+        .with_children(|strip| {
+            for (glyph, action) in STRIP_BUTTONS {
+                strip
+                    .spawn((
+                        Button,
+                        TouchControlButton(action),
+                        Node {
+                            width: Val::Px(BUTTON_SIZE),
+                            height: Val::Px(BUTTON_SIZE),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(BUTTON_NORMAL.with_alpha(BUTTON_ALPHA)),
+                        BorderRadius::all(Val::Px(12.0)),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(glyph),
+                            TextFont {
+                                font: symbol_font.clone(),
+                                font_size: GLYPH_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(TEXT_COLOR),
+                        ));
+                    });
+            }
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Writes the actions of the pressed strip buttons into [`OnScreenActions`]
+/// and colours the buttons by their [`Interaction`].
+///
+/// Runs in `PreUpdate` between Bevy's UI focus pass and
+/// `collect_input_actions`, in every state, so the held actions are
+/// released as soon as the strip is gone.
+pub fn press_touch_controls(
+    mut buttons: Query<(&Interaction, &TouchControlButton, &mut BackgroundColor)>,
+    mut on_screen: ResMut<OnScreenActions>,
+) {
+    let mut held = OnScreenActions::default();
+    for (interaction, button, mut bg) in buttons.iter_mut() {
+        let color = match interaction {
+            Interaction::Pressed => {
+                held.0.insert(button.0);
+                BUTTON_PRESSED
+            }
+            Interaction::Hovered => BUTTON_HOVER,
+            Interaction::None => BUTTON_NORMAL,
+        };
+        bg.set_if_neq(BackgroundColor(color.with_alpha(BUTTON_ALPHA)));
+    }
+    on_screen.set_if_neq(held);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pressed_buttons_hold_their_actions() {
+        let mut app = App::new();
+        app.init_resource::<OnScreenActions>()
+            .add_systems(Update, press_touch_controls);
+        let drop = app
+            .world_mut()
+            .spawn((
+                TouchControlButton(InputAction::Drop),
+                Interaction::Pressed,
+                BackgroundColor(BUTTON_NORMAL),
+            ))
+            .id();
+        app.world_mut().spawn((
+            TouchControlButton(InputAction::MoveLeft),
+            Interaction::Hovered,
+            BackgroundColor(BUTTON_NORMAL),
+        ));
+        app.update();
+        assert_eq!(
+            app.world().resource::<OnScreenActions>().0,
+            [InputAction::Drop].into()
+        );
+
+        app.world_mut().entity_mut(drop).insert(Interaction::None);
+        app.update();
+        assert!(app.world().resource::<OnScreenActions>().0.is_empty());
+    }
+}
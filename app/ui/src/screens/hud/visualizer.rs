@@ -0,0 +1,165 @@
+//! BGM visualizer widget.
+//!
+//! A row of thin bars next to the score that bounce with the music, shown
+//! while [`SettingsResource::audio_visualizer`] is on.  Purely cosmetic.
+//!
+//! ```text
+//! ┌──────────┐
+//! │  スコア   │   ▂▅█▆▃▂▁▁   ← VisualizerBar(0..VISUALIZER_BARS)
+//! │   1200    │
+//! └──────────┘
+//! ```
+//!
+//! The bar heights come from [`BgmLevels`], which the audio crate fills
+//! from the BGM channel every frame; without audio the bars stay flat.
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::resources::settings::SettingsResource;
+
+use crate::styles::{BG_COLOR, PRIMARY_COLOR};
+
+/// Number of bars (frequency bands) in the visualizer.
+pub const VISUALIZER_BARS: usize = 8;
+
+/// Height of a bar at level `0.0`, in pixels.
+const BAR_MIN_HEIGHT: f32 = 2.0;
+
+/// Height of a bar at level `1.0`, in pixels.
+const BAR_MAX_HEIGHT: f32 = 32.0;
+
+/// Width of one bar, in pixels.
+const BAR_WIDTH: f32 = 4.0;
+
+/// Padding around the bars, in pixels.
+const PANEL_PADDING: f32 = 4.0;
+
+// ---------------------------------------------------------------------------
+// Components / resources
+// ---------------------------------------------------------------------------
+
+/// Level of each visualizer band, `0.0` (silent) to `1.0` (loud), lowest
+/// frequency first.
+///
+/// Written by the audio crate's BGM meter; all zero while no music plays.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct BgmLevels {
+    /// One level per bar
+    pub bars: [f32; VISUALIZER_BARS],
+}
+
+/// Marks the bar [`Node`] showing this index of [`BgmLevels::bars`].
+#[derive(Component, Debug)]
+pub struct VisualizerBar(pub usize);
+
+// ---------------------------------------------------------------------------
+// Run conditions
+// ---------------------------------------------------------------------------
+
+/// `true` while the visualizer is switched on.
+pub fn visualizer_enabled(settings: Res<SettingsResource>) -> bool {
+    settings.audio_visualizer
+}
+
+// ---------------------------------------------------------------------------
+// Spawn helper
+// ---------------------------------------------------------------------------
+
+/// Spawns the visualizer bars as a child of `parent`.
+pub fn spawn_visualizer_widget(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn((
+            Node {
+                align_items: AlignItems::End,
+                column_gap: Val::Px(2.0),
+                // Fixed height so the panel does not resize with the bars
+                height: Val::Px(BAR_MAX_HEIGHT + 2.0 * PANEL_PADDING),
+                margin: UiRect::left(Val::Px(8.0)),
+                padding: UiRect::all(Val::Px(PANEL_PADDING)),
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            BorderRadius::all(Val::Px(4.0)),
+        ))
+        .with_children(|panel| {
+            for band in 0..VISUALIZER_BARS {
+                panel.spawn((
+                    Node {
+                        width: Val::Px(BAR_WIDTH),
+                        height: Val::Px(bar_height(0.0)),
+                        ..default()
+                    },
+                    BackgroundColor(PRIMARY_COLOR),
+                    VisualizerBar(band),
+                ));
+            }
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Sets every [`VisualizerBar`]'s height from [`BgmLevels`].
+pub fn update_visualizer(levels: Res<BgmLevels>, mut query: Query<(&VisualizerBar, &mut Node)>) {
+    if !levels.is_changed() {
+        return;
+    }
+    for (bar, mut node) in query.iter_mut() {
+        let level = levels.bars.get(bar.0).copied().unwrap_or(0.0);
+        node.height = Val::Px(bar_height(level));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Bar height in pixels for `level`, clamped to `0.0..=1.0`.
+fn bar_height(level: f32) -> f32 {
+    BAR_MIN_HEIGHT + level.clamp(0.0, 1.0) * (BAR_MAX_HEIGHT - BAR_MIN_HEIGHT)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_height_is_clamped() {
+        assert_eq!(bar_height(0.0), BAR_MIN_HEIGHT);
+        assert_eq!(bar_height(1.0), BAR_MAX_HEIGHT);
+        assert_eq!(bar_height(2.0), BAR_MAX_HEIGHT);
+        assert_eq!(bar_height(-1.0), BAR_MIN_HEIGHT);
+    }
+
+    #[test]
+    fn test_update_visualizer_follows_levels() {
+        let mut app = App::new();
+        let mut levels = BgmLevels::default();
+        levels.bars[2] = 1.0;
+        app.insert_resource(levels)
+            .add_systems(Update, update_visualizer);
+        let loud = app
+            .world_mut()
+            .spawn((VisualizerBar(2), Node::default()))
+            .id();
+        let quiet = app
+            .world_mut()
+            .spawn((VisualizerBar(0), Node::default()))
+            .id();
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Node>(loud).unwrap().height,
+            Val::Px(BAR_MAX_HEIGHT)
+        );
+        assert_eq!(
+            app.world().get::<Node>(quiet).unwrap().height,
+            Val::Px(BAR_MIN_HEIGHT)
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Loading screen — shown while [`AppState::Loading`] waits for the configs
+//! and tracked assets.
+//!
+//! ```text
+//! ┌─────────────────────────────────┐
+//! │                                 │
+//! │           スイカゲーム            │  ← logo
+//! │               ◠                 │  ← spinner
+//! │     ▓▓▓▓▓▓▓▓▓▓░░░░░░░░░░░       │  ← progress bar (LoadingProgress)
+//! │            読み込み中…            │
+//! │                                 │
+//! └─────────────────────────────────┘
+//! ```
+//!
+//! The screen is tagged with [`DespawnOnExit`]`(`[`AppState::Loading`]`)`.
+//! On exit a plain [`LoadingFade`] curtain in the background colour takes
+//! its place and fades out over [`FADE_SECS`], so the title screen fades in
+//! instead of popping up.
+
+use bevy::prelude::*;
+use suika_game_core::prelude::{AppState, LoadingProgress, SettingsResource};
+
+use crate::i18n::t;
+use crate::styles::{
+    BG_COLOR, FONT_JP, FONT_SIZE_HUGE, FONT_SIZE_SMALL, PRIMARY_COLOR, TEXT_COLOR,
+};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Duration of the fade into the title screen (seconds).
+pub const FADE_SECS: f32 = 0.4;
+
+/// Spinner rotation speed (radians per second).
+const SPINNER_SPEED: f32 = std::f32::consts::TAU;
+
+const SPINNER_SIZE: f32 = 48.0;
+const SPINNER_THICKNESS: f32 = 6.0;
+const BAR_WIDTH: f32 = 360.0;
+const BAR_HEIGHT: f32 = 16.0;
+
+/// Colour of the empty part of the progress bar and the spinner track.
+const TRACK_COLOR: Color = Color::srgba(0.3, 0.6, 0.3, 0.2);
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the rotating spinner.
+#[derive(Component, Debug)]
+pub struct LoadingSpinner;
+
+/// Marks the filled part of the progress bar.
+#[derive(Component, Debug)]
+pub struct LoadingBarFill;
+
+/// Curtain that fades out over the title screen after loading.
+#[derive(Component, Debug, Default)]
+pub struct LoadingFade {
+    /// Seconds since the fade started.
+    pub elapsed: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the loading screen when entering [`AppState::Loading`].
+pub fn setup_loading_screen(
+    mut commands: Commands,
+    settings: Res<SettingsResource>,
+    asset_server: Res<AssetServer>,
+) {
+    let font: Handle<Font> = asset_server.load(FONT_JP);
+    let lang = settings.language;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            DespawnOnExit(AppState::Loading),
+        ))
+        .with_children(|parent| {
+            // Logo
+            parent.spawn((
+                Text::new(t("game_title", lang)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_HUGE,
+                    ..default()
+                },
+                TextColor(PRIMARY_COLOR),
+            ));
+
+            // Spinner — a ring with one highlighted side, rotated every frame
+            parent.spawn((
+                Node {
+                    width: Val::Px(SPINNER_SIZE),
+                    height: Val::Px(SPINNER_SIZE),
+                    border: UiRect::all(Val::Px(SPINNER_THICKNESS)),
+                    ..default()
+                },
+                BorderColor {
+                    top: PRIMARY_COLOR,
+                    ..BorderColor::all(TRACK_COLOR)
+                },
+                BorderRadius::MAX,
+                LoadingSpinner,
+            ));
+
+            // Progress bar
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(BAR_WIDTH),
+                        height: Val::Px(BAR_HEIGHT),
+                        ..default()
+                    },
+                    BackgroundColor(TRACK_COLOR),
+                    BorderRadius::all(Val::Px(BAR_HEIGHT / 2.0)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(PRIMARY_COLOR),
+                        BorderRadius::all(Val::Px(BAR_HEIGHT / 2.0)),
+                        LoadingBarFill,
+                    ));
+                });
+
+            parent.spawn((
+                Text::new(t("loading", lang)),
+                TextFont {
+                    font,
+                    font_size: FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+            ));
+        });
+}
+
+/// Turns the spinner and fills the progress bar from [`LoadingProgress`].
+pub fn update_loading_screen(
+    time: Res<Time>,
+    progress: Res<LoadingProgress>,
+    mut spinner_query: Query<&mut UiTransform, With<LoadingSpinner>>,
+    mut fill_query: Query<&mut Node, With<LoadingBarFill>>,
+) {
+    let step = Rot2::radians(SPINNER_SPEED * time.delta_secs());
+    for mut transform in spinner_query.iter_mut() {
+        transform.rotation = transform.rotation * step;
+    }
+    let width = Val::Percent(bar_percent(&progress));
+    for mut node in fill_query.iter_mut() {
+        if node.width != width {
+            node.width = width;
+        }
+    }
+}
+
+/// Covers the screen with the [`LoadingFade`] curtain when leaving
+/// [`AppState::Loading`].
+pub fn start_loading_fade(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(BG_COLOR),
+        GlobalZIndex(i32::MAX - 3),
+        LoadingFade::default(),
+    ));
+}
+
+/// Fades the [`LoadingFade`] curtain out and despawns it when done.
+pub fn update_loading_fade(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut fade_query: Query<(Entity, &mut LoadingFade, &mut BackgroundColor)>,
+) {
+    for (entity, mut fade, mut bg) in fade_query.iter_mut() {
+        fade.elapsed += time.delta_secs();
+        let alpha = fade_alpha(fade.elapsed);
+        if alpha <= 0.0 {
+            commands.entity(entity).despawn();
+        } else {
+            bg.0 = BG_COLOR.with_alpha(alpha);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Width of the filled progress bar in percent.
+fn bar_percent(progress: &LoadingProgress) -> f32 {
+    progress.fraction() * 100.0
+}
+
+/// Curtain opacity `elapsed` seconds into the fade.
+fn fade_alpha(elapsed: f32) -> f32 {
+    (1.0 - elapsed / FADE_SECS).clamp(0.0, 1.0)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use suika_game_core::prelude::LoadProgress;
+
+    #[test]
+    fn test_bar_percent_follows_progress() {
+        let progress = LoadingProgress {
+            configs_loaded: 8,
+            configs_total: 8,
+            assets: LoadProgress {
+                total: 8,
+                loaded: 0,
+                failed: Vec::new(),
+            },
+        };
+        assert_eq!(bar_percent(&progress), 50.0);
+    }
+
+    #[test]
+    fn test_fade_alpha() {
+        assert_eq!(fade_alpha(0.0), 1.0);
+        assert_eq!(fade_alpha(FADE_SECS / 2.0), 0.5);
+        assert_eq!(fade_alpha(FADE_SECS * 2.0), 0.0);
+    }
+}
@@ -1,8 +1,14 @@
 //! Screen implementations for each application state.
 
+pub mod attract;
+pub mod calibration;
+pub mod effects_settings;
 pub mod game_over;
 pub mod how_to_play;
 pub mod hud;
+pub mod loading;
 pub mod pause;
+pub mod score_graph;
 pub mod settings;
+pub mod stats;
 pub mod title;
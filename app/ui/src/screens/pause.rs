@@ -4,6 +4,7 @@
 //! Spawns a full-screen overlay containing:
 //! - A **PAUSED** heading
 //! - A **Resume** button (→ [`AppState::Playing`])
+//! - A **Settings** button (→ [`AppState::Settings`], which returns here)
 //! - A **Back to Title** button (→ [`AppState::Title`])
 //!
 //! All entities are tagged with [`DespawnOnExit`]`(`[`AppState::Paused`]`)` so
@@ -12,12 +13,12 @@
 //!
 //! ## Pause toggle
 //!
-//! [`toggle_pause`] listens for the ESC key in both [`AppState::Playing`] and
+//! [`toggle_pause`] listens for the `Pause` input action in both [`AppState::Playing`] and
 //! [`AppState::Paused`] and toggles between them.  It is registered uncondi-
 //! tionally in [`GameUIPlugin`] so the same system handles both directions.
 
 use bevy::prelude::*;
-use suika_game_core::prelude::{AppState, SettingsResource};
+use suika_game_core::prelude::{ActionState, AppState, InputAction, SettingsResource};
 
 use crate::components::{ButtonAction, KeyboardFocusIndex, spawn_button};
 use crate::i18n::t;
@@ -30,8 +31,9 @@ use crate::styles::{
 // Color constants (local to this screen)
 // ---------------------------------------------------------------------------
 
-/// Semi-transparent dark overlay — dims the game scene while paused.
-const OVERLAY_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.70);
+/// Semi-transparent dark overlay — dims the game scene while paused, on top
+/// of the darkening already applied by the pause blur.
+const OVERLAY_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.40);
 
 /// White text used for the "PAUSED" heading.
 const PAUSED_TEXT_COLOR: Color = Color::WHITE;
@@ -43,7 +45,7 @@ const PAUSED_TEXT_COLOR: Color = Color::WHITE;
 /// Spawns the pause menu overlay when entering [`AppState::Paused`].
 ///
 /// Creates an absolute-positioned, full-screen semi-transparent panel with
-/// a "PAUSED" heading and two buttons.  Resets [`KeyboardFocusIndex`] to `0`
+/// a "PAUSED" heading and three buttons.  Resets [`KeyboardFocusIndex`] to `0`
 /// so the Resume button always receives initial keyboard focus.
 pub fn setup_pause_menu(
     mut commands: Commands,
@@ -98,12 +100,24 @@ pub fn setup_pause_menu(
                 font.clone(),
             );
 
-            // Back-to-title button (index 1)
+            // Settings button (index 1) — returns here when closed
+            spawn_button(
+                parent,
+                t("btn_settings", lang),
+                ButtonAction::OpenSettings,
+                1,
+                FONT_SIZE_MEDIUM,
+                BUTTON_MEDIUM_WIDTH,
+                BUTTON_MEDIUM_HEIGHT,
+                font.clone(),
+            );
+
+            // Back-to-title button (index 2)
             spawn_button(
                 parent,
                 t("btn_title", lang),
                 ButtonAction::GoToTitle,
-                1,
+                2,
                 FONT_SIZE_MEDIUM,
                 BUTTON_MEDIUM_WIDTH,
                 BUTTON_MEDIUM_HEIGHT,
@@ -112,17 +126,18 @@ pub fn setup_pause_menu(
         });
 }
 
-/// Toggles between [`AppState::Playing`] and [`AppState::Paused`] on ESC.
+/// Toggles between [`AppState::Playing`] and [`AppState::Paused`] on the
+/// [`InputAction::Pause`] action (ESC or a gamepad's Start button).
 ///
 /// Runs every frame regardless of the current state (registered without a
 /// `run_if` filter).  Only acts in the two states where the toggle makes
 /// sense; all other states are silently ignored.
 pub fn toggle_pause(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ActionState>,
     current_state: Res<State<AppState>>,
     mut next_state: ResMut<NextState<AppState>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
+    if actions.just_pressed(InputAction::Pause) {
         match current_state.get() {
             AppState::Playing => {
                 next_state.set(AppState::Paused);
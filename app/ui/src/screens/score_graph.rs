@@ -0,0 +1,249 @@
+//! Score timeline graph — a small line graph of the run's score over time,
+//! shown on the game-over screen.
+//!
+//! ```text
+//! スコア推移
+//! ┌────────────────────────────┐
+//! │                      ╱‾‾‾‾ │
+//! │              ╱‾‾‾‾‾‾╱      │
+//! │      ___╱‾‾‾╱              │
+//! │ ____╱                      │
+//! └────────────────────────────┘
+//! ```
+//!
+//! Built entirely from UI nodes: every segment of the polyline is a thin
+//! absolutely-positioned node rotated with [`UiTransform`].  The points come
+//! from [`SessionStats::score_timeline`](suika_game_core::prelude::SessionStats),
+//! which gains a sample on every merge; long runs are thinned to
+//! [`MAX_POINTS`] so the node count stays small.
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+use suika_game_core::prelude::ScoreSample;
+
+use crate::styles::{FONT_SIZE_SMALL, PRIMARY_COLOR, TEXT_COLOR};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Plot area size in pixels.
+const GRAPH_SIZE: Vec2 = Vec2::new(320.0, 90.0);
+
+/// Thickness of the score line in pixels.
+const LINE_THICKNESS: f32 = 2.0;
+
+/// Most points drawn; longer timelines are thinned to this many.
+const MAX_POINTS: usize = 48;
+
+/// Plot area background.
+const GRAPH_BG_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.5);
+
+/// Axis colour.
+const AXIS_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.3);
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marker for the root node of the score graph.
+#[derive(Component, Debug)]
+pub struct ScoreGraph;
+
+// ---------------------------------------------------------------------------
+// Spawning
+// ---------------------------------------------------------------------------
+
+/// Spawns the titled graph of `timeline` as a child of `parent`.
+///
+/// `end` is the final `(time, score)` of the run, so the line reaches the
+/// right edge even when the last merge was long before game over.  Nothing
+/// is spawned for a run without any merges.
+pub fn spawn_score_graph(
+    parent: &mut ChildSpawnerCommands,
+    title: &str,
+    timeline: &[ScoreSample],
+    end: ScoreSample,
+    font: Handle<Font>,
+) {
+    if timeline.is_empty() {
+        return;
+    }
+    let points = graph_points(timeline, end, GRAPH_SIZE);
+
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                margin: UiRect::bottom(Val::Px(10.0)),
+                ..default()
+            },
+            ScoreGraph,
+        ))
+        .with_children(|graph| {
+            graph.spawn((
+                Text::new(title),
+                TextFont {
+                    font,
+                    font_size: FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+            ));
+
+            graph
+                .spawn((
+                    Node {
+                        width: Val::Px(GRAPH_SIZE.x),
+                        height: Val::Px(GRAPH_SIZE.y),
+                        border: UiRect {
+                            left: Val::Px(1.0),
+                            bottom: Val::Px(1.0),
+                            ..default()
+                        },
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BackgroundColor(GRAPH_BG_COLOR),
+                    BorderColor::all(AXIS_COLOR),
+                ))
+                .with_children(|plot| {
+                    for pair in points.windows(2) {
+                        plot.spawn(segment_bundle(pair[0], pair[1]));
+                    }
+                });
+        });
+}
+
+/// Node drawing the line from `a` to `b` (plot pixels, y down).
+///
+/// The node is laid out horizontally, centred on the segment's midpoint,
+/// and rotated clockwise by the segment's angle.
+fn segment_bundle(a: Vec2, b: Vec2) -> impl Bundle {
+    let delta = b - a;
+    let center = (a + b) / 2.0;
+    // Overlap neighbours slightly so joints have no gaps
+    let length = delta.length() + LINE_THICKNESS / 2.0;
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(center.x - length / 2.0),
+            top: Val::Px(center.y - LINE_THICKNESS / 2.0),
+            width: Val::Px(length),
+            height: Val::Px(LINE_THICKNESS),
+            ..default()
+        },
+        UiTransform::from_rotation(Rot2::radians(delta.y.atan2(delta.x))),
+        BackgroundColor(PRIMARY_COLOR),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Polyline for `timeline` in a plot of `size`, y pointing down.
+///
+/// Starts at the origin (time 0, score 0) and ends at `end`.  When the
+/// timeline has more than [`MAX_POINTS`] samples it is split into equal
+/// time buckets and only the last sample of each is kept, which preserves
+/// the shape of a monotonically rising score.
+fn graph_points(timeline: &[ScoreSample], end: ScoreSample, size: Vec2) -> Vec<Vec2> {
+    let duration = timeline
+        .iter()
+        .map(|s| s.time)
+        .fold(end.time, f32::max)
+        .max(f32::EPSILON);
+    let top_score = timeline
+        .iter()
+        .map(|s| s.score)
+        .fold(end.score, u32::max)
+        .max(1) as f32;
+
+    let thinned: Vec<ScoreSample> = if timeline.len() <= MAX_POINTS {
+        timeline.to_vec()
+    } else {
+        let bucket = |s: &ScoreSample| {
+            ((s.time / duration * MAX_POINTS as f32) as usize).min(MAX_POINTS - 1)
+        };
+        timeline
+            .iter()
+            .enumerate()
+            .filter(|(i, s)| {
+                timeline
+                    .get(i + 1)
+                    .is_none_or(|next| bucket(next) != bucket(s))
+            })
+            .map(|(_, s)| *s)
+            .collect()
+    };
+
+    let to_plot = |s: ScoreSample| {
+        Vec2::new(
+            s.time / duration * size.x,
+            size.y - s.score as f32 / top_score * size.y,
+        )
+    };
+    std::iter::once(ScoreSample {
+        time: 0.0,
+        score: 0,
+    })
+    .chain(thinned)
+    .chain(std::iter::once(end))
+    .map(to_plot)
+    .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(time: f32, score: u32) -> ScoreSample {
+        ScoreSample { time, score }
+    }
+
+    #[test]
+    fn test_graph_points_span_plot() {
+        let size = Vec2::new(100.0, 50.0);
+        let timeline = [sample(5.0, 10), sample(10.0, 40)];
+        let points = graph_points(&timeline, sample(20.0, 40), size);
+
+        assert_eq!(
+            points,
+            vec![
+                Vec2::new(0.0, 50.0),
+                Vec2::new(25.0, 37.5),
+                Vec2::new(50.0, 0.0),
+                Vec2::new(100.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_graph_points_thins_long_timeline() {
+        let timeline: Vec<ScoreSample> = (1..=500).map(|i| sample(i as f32, i * 10)).collect();
+        let end = sample(500.0, 5_000);
+        let points = graph_points(&timeline, end, GRAPH_SIZE);
+
+        // Origin + one per bucket + end
+        assert!(points.len() <= MAX_POINTS + 2);
+        assert_eq!(*points.last().unwrap(), Vec2::new(GRAPH_SIZE.x, 0.0));
+        assert!(
+            points
+                .windows(2)
+                .all(|p| p[1].x >= p[0].x && p[1].y <= p[0].y)
+        );
+    }
+
+    #[test]
+    fn test_graph_points_zero_score_stays_on_axis() {
+        let size = Vec2::new(100.0, 50.0);
+        let points = graph_points(&[sample(0.0, 0)], sample(0.0, 0), size);
+        assert!(points.iter().all(|p| p.is_finite() && p.y == size.y));
+    }
+}
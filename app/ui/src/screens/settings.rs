@@ -1,6 +1,11 @@
 //! Settings screen — shown when the player taps the 設定 / Settings button.
 //!
-//! Displays four configurable rows:
+//! Opened from the title screen or the pause menu; the Back button returns
+//! to whichever one opened it ([`ReturnTo`](suika_game_core::prelude::ReturnTo)),
+//! so a paused game can be resumed afterwards.
+//!
+//! Displays twenty configurable rows, a history export button and the
+//! user mods:
 //!
 //! ```text
 //!          設定 / Settings
@@ -9,28 +14,75 @@
 //!  SE音量     ◀  ■■■■■■■■□□  ▶   80%
 //!  エフェクト  [         ON        ]
 //!  言語        ◀  [ 日本語 ]   ▶
+//!  合体ガイド  [        OFF        ]
+//!  落下地点    [        OFF        ]
+//!  振動        [         ON        ]
+//!  垂直同期    [         ON        ]
+//!  FPS上限     ◀  [ 無制限 ]   ▶
+//!  片手モード  [        OFF        ]
+//!  自動落下    ◀  [   OFF   ]   ▶
+//!  読み上げ    [        OFF        ]
+//!  全角数字    [        OFF        ]
+//!  ゴースト    [        OFF        ]
+//!  BGMバー     [        OFF        ]
+//!  タッチ操作  [        OFF        ]
+//!  配信モード  [        OFF        ]
+//!  設定PIN     [        OFF        ]
+//!  SE遅延      [       0 ms        ]
+//!  演出の詳細  [       調整        ]
+//!  プレイ履歴  [     書き出す      ]
+//!  pastel      [         ON        ]   ← one row per user mod
 //!
 //!           [ もどる ]
 //! ```
 //!
 //! Volume rows use ◀ / ▶ arrow buttons to step the value up or down.
-//! The effects row uses a single wide toggle button that cycles ON ↔ OFF.
+//! The effects, merge guide, landing preview, vibration, vsync, one-button, announcement,
+//! full-width digit, ghost run, visualizer, touch control and streamer-safe rows use a single
+//! wide toggle button that cycles ON ↔ OFF.  Full-width digits only change how scores look in Japanese
+//! (see [`number_format`](crate::number_format)).
+//! The FPS cap row uses ◀ / ▶ to step through
+//! [`FPS_CAP_STEPS`](suika_game_core::resources::settings::FPS_CAP_STEPS),
+//! the auto-drop row through
+//! [`AUTO_DROP_STEPS`](suika_game_core::resources::settings::AUTO_DROP_STEPS).
 //! The language row uses ◀ / ▶ to cycle through available languages.
+//! For a right-to-left language every row is mirrored when the screen is
+//! built: the label moves to the right and the arrows swap sides.
+//! The SFX delay row shows the current offset and opens the
+//! [`calibration`](crate::screens::calibration) screen.
+//! The effect details row opens the
+//! [`effects_settings`](crate::screens::effects_settings) page, which switches
+//! single effects off while the effects row is ON.
+//! The PIN row shows whether a settings PIN is set; pressing it asks for a
+//! new PIN, or removes the one set (see [`safe_mode`](crate::safe_mode)).
+//! The history row exports the highscore and game logs to `save/exports/`
+//! as JSON and CSV (see [`export_history`](suika_game_core::persistence::export_history)).
+//! Below it each user mod found at startup ([`ModList`]) gets an ON / OFF
+//! toggle; a change is saved at once but only takes effect on the next
+//! launch, which a hint under the rows points out.
 //!
 //! Every button press immediately mutates [`SettingsResource`] and persists the
 //! change to `save/settings.json`.  [`update_settings_display`] runs every
 //! frame while in this state and updates the value text nodes whenever the
 //! resource is marked changed.
 //!
+//! The rows do not fit the window, so the column scrolls ([`SettingsScroll`]):
+//! with the mouse wheel, and on its own to keep the keyboard-focused button
+//! in view ([`scroll_settings_to_focus`]).
+//!
 //! All entities are tagged with [`DespawnOnExit`]`(`[`AppState::Settings`]`)`
 //! so Bevy cleans them up automatically on state exit.
 
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
+use suika_game_core::mods::ModList;
 use suika_game_core::prelude::AppState;
 use suika_game_core::resources::settings::{Language, SettingsResource};
 
 use crate::components::{ButtonAction, ButtonIndex, KeyboardFocusIndex, MenuButton, spawn_button};
-use crate::i18n::t;
+use crate::focus::FocusCell;
+use crate::i18n::{row_direction, t};
+use crate::screens::calibration::latency_label;
 use crate::styles::{
     BG_COLOR, BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH, BUTTON_NORMAL, FONT_JP, FONT_SIZE_LARGE,
     FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, FONT_SYMBOL, PRIMARY_COLOR, TEXT_COLOR,
@@ -59,6 +111,11 @@ const ROW_WIDTH: f32 = LABEL_WIDTH
     + GAUGE_WIDTH
     + COL_GAP
     + (SMALL_BTN_SIZE + BTN_MARGIN * 2.0);
+/// Distance scrolled per mouse-wheel line (logical pixels).
+const SCROLL_LINE_HEIGHT: f32 = 48.0;
+/// Space kept between the focused button and the edge of the view when
+/// scrolling it into sight (logical pixels).
+const SCROLL_FOCUS_MARGIN: f32 = 24.0;
 /// Width of a boolean toggle button so it occupies the same horizontal space
 /// as the ◀ + value-text + ▶ triplet in a setting row.
 const TOGGLE_BTN_WIDTH: f32 = ROW_WIDTH - LABEL_WIDTH - COL_GAP - BTN_MARGIN * 2.0;
@@ -83,6 +140,75 @@ pub struct SfxGaugeText;
 #[derive(Component)]
 pub struct EffectsValueText;
 
+/// Marks the text node that shows the current merge ghost on/off value.
+#[derive(Component)]
+pub struct MergeGhostValueText;
+
+/// Marks the text node that shows the current landing preview on/off value.
+#[derive(Component)]
+pub struct LandingPreviewValueText;
+
+/// Marks the text node that shows the current haptics on/off value.
+#[derive(Component)]
+pub struct HapticsValueText;
+
+/// Marks the text node that shows the current vsync on/off value.
+#[derive(Component)]
+pub struct VsyncValueText;
+
+/// Marks the text node that shows the current FPS cap.
+#[derive(Component)]
+pub struct FpsCapValueText;
+
+/// Marks the text node that shows the current one-button sweep on/off value.
+#[derive(Component)]
+pub struct SweepAssistValueText;
+
+/// Marks the text node that shows the current auto-drop delay.
+#[derive(Component)]
+pub struct AutoDropValueText;
+
+/// Marks the text node that shows the current announcements on/off value.
+#[derive(Component)]
+pub struct AnnouncementsValueText;
+
+/// Marks the text node that shows the current full-width digits on/off value.
+#[derive(Component)]
+pub struct FullWidthDigitsValueText;
+
+/// Marks the text node that shows the current ghost run on/off value.
+#[derive(Component)]
+pub struct GhostRunValueText;
+
+/// Marks the text node that shows the current BGM visualizer on/off value.
+#[derive(Component)]
+pub struct VisualizerValueText;
+
+/// Marks the text node that shows the current touch controls on/off value.
+#[derive(Component)]
+pub struct TouchControlsValueText;
+
+/// Marks the text node that shows the current SFX delay.
+#[derive(Component)]
+pub struct SfxLatencyValueText;
+
+/// Marks the text node that shows the current streamer-safe on/off value.
+#[derive(Component)]
+pub struct StreamerSafeValueText;
+
+/// Marks the text node that shows whether a settings PIN is set.
+#[derive(Component)]
+pub struct SettingsPinValueText;
+
+/// Marks the text node that shows whether the mod at this index of
+/// [`ModList`] is switched on.
+#[derive(Component)]
+pub struct ModValueText(pub usize);
+
+/// Marks the scrolling column that holds every settings row.
+#[derive(Component)]
+pub struct SettingsScroll;
+
 /// Marks the text node that shows the current language selection.
 #[derive(Component)]
 pub struct LanguageValueText;
@@ -100,6 +226,21 @@ pub struct TranslatableText(pub &'static str);
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// How far to scroll (down is positive) so `item` sits inside `view`,
+/// `margin` away from its edges.  Both are `(top, bottom)` in the same
+/// units, growing downward.  An item taller than the view is aligned to
+/// its top.
+fn scroll_delta(view: (f32, f32), item: (f32, f32), margin: f32) -> f32 {
+    let (view_top, view_bottom) = (view.0 + margin, view.1 - margin);
+    if item.0 < view_top {
+        item.0 - view_top
+    } else if item.1 > view_bottom {
+        (item.1 - view_bottom).min(item.0 - view_top)
+    } else {
+        0.0
+    }
+}
+
 /// Builds the gauge string: filled blocks + empty blocks + percentage.
 ///
 /// Example for `vol = 8`: `"■■■■■■■■□□  80%"`.
@@ -114,12 +255,51 @@ fn gauge_string(vol: u8) -> String {
     )
 }
 
-/// Spawns a small ◀ or ▶ button as a child of `parent`.
+/// Localised `ON` / `OFF` label for a boolean setting.
+pub(crate) fn on_off(value: bool, lang: Language) -> &'static str {
+    if value {
+        t("value_on", lang)
+    } else {
+        t("value_off", lang)
+    }
+}
+
+/// Localised FPS cap label: the number, or "unlimited" for `0`.
+fn fps_cap_label(fps_cap: u16, lang: Language) -> String {
+    if fps_cap == 0 {
+        t("value_unlimited", lang).to_string()
+    } else {
+        fps_cap.to_string()
+    }
+}
+
+/// Localised auto-drop label: the delay in seconds, or "OFF" for `0`.
+fn auto_drop_label(secs: u8, lang: Language) -> String {
+    if secs == 0 {
+        t("value_off", lang).to_string()
+    } else {
+        format!("{secs}{}", t("unit_seconds", lang))
+    }
+}
+
+/// Glyph and focus column of the decrease and increase arrows of a
+/// [`spawn_setting_row`].  A right-to-left row is mirrored, so its decrease
+/// arrow sits on the right and points right.
+fn arrow_layout(lang: Language) -> [(&'static str, usize); 2] {
+    if lang.is_rtl() {
+        [("▶", 1), ("◀", 0)]
+    } else {
+        [("◀", 0), ("▶", 1)]
+    }
+}
+
+/// Spawns a small ◀ or ▶ button at `cell` as a child of `parent`.
 fn spawn_arrow_button(
     parent: &mut bevy::ecs::hierarchy::ChildSpawnerCommands,
     label: &str,
     action: ButtonAction,
     index: usize,
+    cell: FocusCell,
     font: Handle<Font>,
 ) {
     spawn_button(
@@ -131,7 +311,8 @@ fn spawn_arrow_button(
         SMALL_BTN_SIZE,
         SMALL_BTN_SIZE,
         font,
-    );
+    )
+    .insert(cell);
 }
 
 /// Spawns a single settings row (label + ◀ + value text + ▶).
@@ -139,6 +320,9 @@ fn spawn_arrow_button(
 /// `font` is used for the label and value text; `symbol_font` is used for the
 /// ◀ / ▶ arrow buttons so that a font with broader Unicode coverage can be
 /// used independently of the main pixel font.
+///
+/// The arrows occupy columns 0 and 1 of keyboard-focus row `focus_row`.
+/// For a right-to-left `lang` the row is mirrored (see [`arrow_layout`]).
 #[allow(clippy::too_many_arguments)]
 fn spawn_setting_row<M: Component>(
     parent: &mut bevy::ecs::hierarchy::ChildSpawnerCommands,
@@ -150,12 +334,15 @@ fn spawn_setting_row<M: Component>(
     right_action: ButtonAction,
     left_index: usize,
     right_index: usize,
+    focus_row: usize,
+    lang: Language,
     font: Handle<Font>,
     symbol_font: Handle<Font>,
 ) {
+    let [(left_glyph, left_column), (right_glyph, right_column)] = arrow_layout(lang);
     parent
         .spawn(Node {
-            flex_direction: FlexDirection::Row,
+            flex_direction: row_direction(lang),
             align_items: AlignItems::Center,
             width: Val::Px(ROW_WIDTH),
             margin: UiRect::vertical(Val::Px(ROW_GAP / 2.0)),
@@ -179,8 +366,15 @@ fn spawn_setting_row<M: Component>(
                 TranslatableText(label_key),
             ));
 
-            // ◀ button — uses symbol_font for the triangle glyph.
-            spawn_arrow_button(row, "◀", left_action, left_index, symbol_font.clone());
+            // Decrease arrow — uses symbol_font for the triangle glyph.
+            spawn_arrow_button(
+                row,
+                left_glyph,
+                left_action,
+                left_index,
+                FocusCell::new(focus_row, left_column),
+                symbol_font.clone(),
+            );
 
             // Value / gauge text
             row.spawn((
@@ -199,8 +393,15 @@ fn spawn_setting_row<M: Component>(
                 marker,
             ));
 
-            // ▶ button — uses symbol_font for the triangle glyph.
-            spawn_arrow_button(row, "▶", right_action, right_index, symbol_font.clone());
+            // Increase arrow — uses symbol_font for the triangle glyph.
+            spawn_arrow_button(
+                row,
+                right_glyph,
+                right_action,
+                right_index,
+                FocusCell::new(focus_row, right_column),
+                symbol_font.clone(),
+            );
         });
 }
 
@@ -209,21 +410,26 @@ fn spawn_setting_row<M: Component>(
 /// The toggle button spans the same horizontal area as the ◀ + value + ▶ triplet
 /// used by [`spawn_setting_row`], so all rows stay visually aligned.
 /// The button text entity receives `value_marker` so [`update_settings_display`]
-/// can update it when the value changes.
+/// can update it when the value changes.  The button is the only one in
+/// keyboard-focus row `focus_row`.  The row is mirrored for a right-to-left
+/// `lang`.  The label follows language changes through `label_key`; pass
+/// `None` for a label that is never translated, such as a mod name.
 #[allow(clippy::too_many_arguments)]
-fn spawn_toggle_row<M: Component>(
+pub(crate) fn spawn_toggle_row<M: Component>(
     parent: &mut bevy::ecs::hierarchy::ChildSpawnerCommands,
     label: &str,
-    label_key: &'static str,
+    label_key: Option<&'static str>,
     value: &str,
     value_marker: M,
     action: ButtonAction,
     index: usize,
+    focus_row: usize,
+    lang: Language,
     font: Handle<Font>,
 ) {
     parent
         .spawn(Node {
-            flex_direction: FlexDirection::Row,
+            flex_direction: row_direction(lang),
             align_items: AlignItems::Center,
             width: Val::Px(ROW_WIDTH),
             margin: UiRect::vertical(Val::Px(ROW_GAP / 2.0)),
@@ -232,7 +438,7 @@ fn spawn_toggle_row<M: Component>(
         })
         .with_children(|row| {
             // Label
-            row.spawn((
+            let mut label = row.spawn((
                 Text::new(label),
                 TextFont {
                     font: font.clone(),
@@ -244,8 +450,10 @@ fn spawn_toggle_row<M: Component>(
                     width: Val::Px(LABEL_WIDTH),
                     ..default()
                 },
-                TranslatableText(label_key),
             ));
+            if let Some(key) = label_key {
+                label.insert(TranslatableText(key));
+            }
 
             // Single toggle button — wide enough to fill ◀ + value + ▶ space.
             row.spawn((
@@ -261,6 +469,7 @@ fn spawn_toggle_row<M: Component>(
                 BackgroundColor(BUTTON_NORMAL),
                 MenuButton { action },
                 ButtonIndex(index),
+                FocusCell::new(focus_row, 0),
             ))
             .with_children(|btn| {
                 btn.spawn((
@@ -285,6 +494,7 @@ fn spawn_toggle_row<M: Component>(
 pub fn setup_settings_screen(
     mut commands: Commands,
     settings: Res<SettingsResource>,
+    mods: Option<Res<ModList>>,
     asset_server: Res<AssetServer>,
     mut keyboard_focus: ResMut<KeyboardFocusIndex>,
 ) {
@@ -300,11 +510,15 @@ pub fn setup_settings_screen(
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
                 flex_direction: FlexDirection::Column,
-                justify_content: JustifyContent::Center,
+                justify_content: JustifyContent::FlexStart,
                 align_items: AlignItems::Center,
+                padding: UiRect::vertical(Val::Px(32.0)),
+                overflow: Overflow::scroll_y(),
                 ..default()
             },
             BackgroundColor(BG_COLOR),
+            ScrollPosition::default(),
+            SettingsScroll,
             DespawnOnExit(AppState::Settings),
         ))
         .with_children(|parent| {
@@ -324,7 +538,7 @@ pub fn setup_settings_screen(
                 TranslatableText("settings_title"),
             ));
 
-            // BGM Volume row (arrow buttons: index 0 ◀, index 1 ▶)
+            // BGM Volume row (arrow buttons: index 0 ◀, index 1 ▶; focus row 0)
             spawn_setting_row(
                 parent,
                 t("label_bgm", lang),
@@ -335,11 +549,13 @@ pub fn setup_settings_screen(
                 ButtonAction::BgmVolumeUp,
                 0,
                 1,
+                0,
+                lang,
                 font.clone(),
                 symbol_font.clone(),
             );
 
-            // SFX Volume row (arrow buttons: index 2 ◀, index 3 ▶)
+            // SFX Volume row (arrow buttons: index 2 ◀, index 3 ▶; focus row 1)
             spawn_setting_row(
                 parent,
                 t("label_sfx", lang),
@@ -350,28 +566,27 @@ pub fn setup_settings_screen(
                 ButtonAction::SfxVolumeUp,
                 2,
                 3,
+                1,
+                lang,
                 font.clone(),
                 symbol_font.clone(),
             );
 
             // Effects row — single toggle button (index 4); bool needs no arrows.
-            let effects_val = if settings.effects_enabled {
-                t("value_on", lang)
-            } else {
-                t("value_off", lang)
-            };
             spawn_toggle_row(
                 parent,
                 t("label_effects", lang),
-                "label_effects",
-                effects_val,
+                Some("label_effects"),
+                on_off(settings.effects_enabled, lang),
                 EffectsValueText,
                 ButtonAction::ToggleEffects,
                 4,
+                2,
+                lang,
                 font.clone(),
             );
 
-            // Language row (arrow buttons: index 5 ◀, index 6 ▶)
+            // Language row (arrow buttons: index 5 ◀, index 6 ▶; focus row 3)
             // TODO: Both arrows use ToggleLanguage (symmetric toggle) because only
             // two languages exist. If a third language is added, split into
             // ButtonAction::ToggleLanguagePrev / ToggleLanguageNext with proper cycling.
@@ -389,11 +604,286 @@ pub fn setup_settings_screen(
                 ButtonAction::ToggleLanguage,
                 5,
                 6,
+                3,
+                lang,
                 font.clone(),
                 symbol_font.clone(),
             );
 
-            // Back button (index 7) — inlined to tag the text with TranslatableText.
+            // Merge ghost assist row — single toggle button (index 7).
+            spawn_toggle_row(
+                parent,
+                t("label_merge_ghost", lang),
+                Some("label_merge_ghost"),
+                on_off(settings.merge_ghost, lang),
+                MergeGhostValueText,
+                ButtonAction::ToggleMergeGhost,
+                7,
+                4,
+                lang,
+                font.clone(),
+            );
+
+            // Landing preview assist row — single toggle button (index 8).
+            spawn_toggle_row(
+                parent,
+                t("label_landing_preview", lang),
+                Some("label_landing_preview"),
+                on_off(settings.landing_preview, lang),
+                LandingPreviewValueText,
+                ButtonAction::ToggleLandingPreview,
+                8,
+                5,
+                lang,
+                font.clone(),
+            );
+
+            // Haptics row — single toggle button (index 9).
+            spawn_toggle_row(
+                parent,
+                t("label_haptics", lang),
+                Some("label_haptics"),
+                on_off(settings.haptics_enabled, lang),
+                HapticsValueText,
+                ButtonAction::ToggleHaptics,
+                9,
+                6,
+                lang,
+                font.clone(),
+            );
+
+            // Vsync row — single toggle button (index 10).
+            spawn_toggle_row(
+                parent,
+                t("label_vsync", lang),
+                Some("label_vsync"),
+                on_off(settings.vsync, lang),
+                VsyncValueText,
+                ButtonAction::ToggleVsync,
+                10,
+                7,
+                lang,
+                font.clone(),
+            );
+
+            // FPS cap row (arrow buttons: index 11 ◀, index 12 ▶; focus row 8)
+            spawn_setting_row(
+                parent,
+                t("label_fps_cap", lang),
+                "label_fps_cap",
+                &fps_cap_label(settings.fps_cap, lang),
+                FpsCapValueText,
+                ButtonAction::FpsCapDown,
+                ButtonAction::FpsCapUp,
+                11,
+                12,
+                8,
+                lang,
+                font.clone(),
+                symbol_font.clone(),
+            );
+
+            // One-button sweep assist row — single toggle button (index 13).
+            spawn_toggle_row(
+                parent,
+                t("label_sweep_assist", lang),
+                Some("label_sweep_assist"),
+                on_off(settings.sweep_assist, lang),
+                SweepAssistValueText,
+                ButtonAction::ToggleSweepAssist,
+                13,
+                9,
+                lang,
+                font.clone(),
+            );
+
+            // Auto-drop row (arrow buttons: index 14 ◀, index 15 ▶; focus row 10)
+            spawn_setting_row(
+                parent,
+                t("label_auto_drop", lang),
+                "label_auto_drop",
+                &auto_drop_label(settings.auto_drop_secs, lang),
+                AutoDropValueText,
+                ButtonAction::AutoDropDown,
+                ButtonAction::AutoDropUp,
+                14,
+                15,
+                10,
+                lang,
+                font.clone(),
+                symbol_font.clone(),
+            );
+
+            // Spoken announcements row — single toggle button (index 16).
+            spawn_toggle_row(
+                parent,
+                t("label_announcements", lang),
+                Some("label_announcements"),
+                on_off(settings.announcements, lang),
+                AnnouncementsValueText,
+                ButtonAction::ToggleAnnouncements,
+                16,
+                11,
+                lang,
+                font.clone(),
+            );
+
+            // Full-width digits row — single toggle button (index 17).
+            spawn_toggle_row(
+                parent,
+                t("label_full_width_digits", lang),
+                Some("label_full_width_digits"),
+                on_off(settings.full_width_digits, lang),
+                FullWidthDigitsValueText,
+                ButtonAction::ToggleFullWidthDigits,
+                17,
+                12,
+                lang,
+                font.clone(),
+            );
+
+            // Ghost run row — single toggle button (index 18).
+            spawn_toggle_row(
+                parent,
+                t("label_ghost_run", lang),
+                Some("label_ghost_run"),
+                on_off(settings.ghost_run, lang),
+                GhostRunValueText,
+                ButtonAction::ToggleGhostRun,
+                18,
+                13,
+                lang,
+                font.clone(),
+            );
+
+            // BGM visualizer row — single toggle button (index 19).
+            spawn_toggle_row(
+                parent,
+                t("label_audio_visualizer", lang),
+                Some("label_audio_visualizer"),
+                on_off(settings.audio_visualizer, lang),
+                VisualizerValueText,
+                ButtonAction::ToggleAudioVisualizer,
+                19,
+                14,
+                lang,
+                font.clone(),
+            );
+
+            // Touch controls row — single toggle button (index 20).
+            spawn_toggle_row(
+                parent,
+                t("label_touch_controls", lang),
+                Some("label_touch_controls"),
+                on_off(settings.touch_controls, lang),
+                TouchControlsValueText,
+                ButtonAction::ToggleTouchControls,
+                20,
+                15,
+                lang,
+                font.clone(),
+            );
+
+            // Streamer-safe row — single toggle button (index 21).
+            spawn_toggle_row(
+                parent,
+                t("label_streamer_safe", lang),
+                Some("label_streamer_safe"),
+                on_off(settings.streamer_safe, lang),
+                StreamerSafeValueText,
+                ButtonAction::ToggleStreamerSafe,
+                21,
+                16,
+                lang,
+                font.clone(),
+            );
+
+            // Settings PIN row — one button (index 22) setting or clearing it.
+            spawn_toggle_row(
+                parent,
+                t("label_settings_pin", lang),
+                Some("label_settings_pin"),
+                on_off(!settings.settings_pin.is_empty(), lang),
+                SettingsPinValueText,
+                ButtonAction::SettingsPin,
+                22,
+                17,
+                lang,
+                font.clone(),
+            );
+
+            // SFX delay row — one button (index 23) opening the calibration screen.
+            spawn_toggle_row(
+                parent,
+                t("label_sfx_latency", lang),
+                Some("label_sfx_latency"),
+                &latency_label(settings.sfx_latency_ms),
+                SfxLatencyValueText,
+                ButtonAction::OpenAudioCalibration,
+                23,
+                18,
+                lang,
+                font.clone(),
+            );
+
+            // Effect details row — one button (index 24) opening the effects page.
+            spawn_toggle_row(
+                parent,
+                t("label_effect_details", lang),
+                Some("label_effect_details"),
+                t("btn_adjust", lang),
+                TranslatableText("btn_adjust"),
+                ButtonAction::OpenEffectsSettings,
+                24,
+                19,
+                lang,
+                font.clone(),
+            );
+
+            // History export row — one button (index 25) writing JSON + CSV.
+            spawn_toggle_row(
+                parent,
+                t("label_history", lang),
+                Some("label_history"),
+                t("btn_export", lang),
+                TranslatableText("btn_export"),
+                ButtonAction::ExportHistory,
+                25,
+                20,
+                lang,
+                font.clone(),
+            );
+
+            // One toggle row per user mod (indices 26.., focus rows 21..).
+            let mods = mods.as_deref().map_or(&[][..], |m| m.mods.as_slice());
+            for (i, info) in mods.iter().enumerate() {
+                spawn_toggle_row(
+                    parent,
+                    &info.name,
+                    None,
+                    on_off(settings.mod_enabled(&info.name), lang),
+                    ModValueText(i),
+                    ButtonAction::ToggleMod(i),
+                    26 + i,
+                    21 + i,
+                    lang,
+                    font.clone(),
+                );
+            }
+            if !mods.is_empty() {
+                parent.spawn((
+                    Text::new(t("mods_restart_hint", lang)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: FONT_SIZE_SMALL,
+                        ..default()
+                    },
+                    TextColor(TEXT_COLOR),
+                    TranslatableText("mods_restart_hint"),
+                ));
+            }
+
+            // Back button (index 26 + mods) — inlined to tag the text with TranslatableText.
             parent
                 .spawn((
                     Button,
@@ -407,9 +897,10 @@ pub fn setup_settings_screen(
                     },
                     BackgroundColor(BUTTON_NORMAL),
                     MenuButton {
-                        action: ButtonAction::BackToTitle,
+                        action: ButtonAction::CloseSettings,
                     },
-                    ButtonIndex(7),
+                    ButtonIndex(26 + mods.len()),
+                    FocusCell::new(21 + mods.len(), 0),
                 ))
                 .with_children(|btn| {
                     btn.spawn((
@@ -426,22 +917,193 @@ pub fn setup_settings_screen(
         });
 }
 
+/// Scrolls the settings column with the mouse wheel.
+pub fn scroll_settings_with_wheel(
+    mut wheel: MessageReader<MouseWheel>,
+    mut scroll_q: Query<&mut ScrollPosition, With<SettingsScroll>>,
+) {
+    let pixels: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    if pixels == 0.0 {
+        return;
+    }
+    // Wheel up (positive) moves the view up; the layout clamps the offset
+    for mut scroll in scroll_q.iter_mut() {
+        scroll.y = (scroll.y - pixels).max(0.0);
+    }
+}
+
+/// Scrolls the settings column so the keyboard-focused button is visible.
+///
+/// Only acts when [`KeyboardFocusIndex`] changes, so the wheel stays free to
+/// move the view elsewhere.
+#[allow(clippy::type_complexity)]
+pub fn scroll_settings_to_focus(
+    focus: Res<KeyboardFocusIndex>,
+    buttons: Query<(&ButtonIndex, &ComputedNode, &UiGlobalTransform)>,
+    mut scroll_q: Query<
+        (&mut ScrollPosition, &ComputedNode, &UiGlobalTransform),
+        With<SettingsScroll>,
+    >,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+    let Some((_, button, button_at)) = buttons.iter().find(|(index, _, _)| index.0 == focus.0)
+    else {
+        return;
+    };
+    let Ok((mut scroll, view, view_at)) = scroll_q.single_mut() else {
+        return;
+    };
+    // Node positions are centres in physical pixels
+    let half_view = view.size().y / 2.0;
+    let half_button = button.size().y / 2.0;
+    let delta = scroll_delta(
+        (
+            view_at.translation.y - half_view,
+            view_at.translation.y + half_view,
+        ),
+        (
+            button_at.translation.y - half_button,
+            button_at.translation.y + half_button,
+        ),
+        SCROLL_FOCUS_MARGIN / view.inverse_scale_factor(),
+    );
+    if delta != 0.0 {
+        scroll.y = (scroll.y + delta * view.inverse_scale_factor()).max(0.0);
+    }
+}
+
 /// Updates the value text nodes whenever [`SettingsResource`] changes.
 ///
 /// Runs every frame while in [`AppState::Settings`], but only performs work
 /// on frames where the resource was actually modified (via `is_changed()`).
-#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn update_settings_display(
     settings: Res<SettingsResource>,
-    mut bgm_q: Query<&mut Text, (With<BgmGaugeText>, Without<SfxGaugeText>)>,
-    mut sfx_q: Query<&mut Text, (With<SfxGaugeText>, Without<BgmGaugeText>)>,
+    mut bgm_q: Query<
+        &mut Text,
+        (
+            With<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut sfx_q: Query<
+        &mut Text,
+        (
+            With<SfxGaugeText>,
+            Without<BgmGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
     mut effects_q: Query<
         &mut Text,
         (
             With<EffectsValueText>,
             Without<BgmGaugeText>,
             Without<SfxGaugeText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut merge_ghost_q: Query<
+        &mut Text,
+        (
+            With<MergeGhostValueText>,
+            Without<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut haptics_q: Query<
+        &mut Text,
+        (
+            With<HapticsValueText>,
+            Without<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut vsync_q: Query<
+        &mut Text,
+        (
+            With<VsyncValueText>,
+            Without<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
             Without<LanguageValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut fps_cap_q: Query<
+        &mut Text,
+        (
+            With<FpsCapValueText>,
+            Without<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
         ),
     >,
     mut lang_q: Query<
@@ -451,6 +1113,82 @@ pub fn update_settings_display(
             Without<BgmGaugeText>,
             Without<SfxGaugeText>,
             Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut sweep_assist_q: Query<
+        &mut Text,
+        (
+            With<SweepAssistValueText>,
+            Without<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut auto_drop_q: Query<
+        &mut Text,
+        (
+            With<AutoDropValueText>,
+            Without<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AnnouncementsValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut announcements_q: Query<
+        &mut Text,
+        (
+            With<AnnouncementsValueText>,
+            Without<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<FullWidthDigitsValueText>,
+        ),
+    >,
+    mut full_width_digits_q: Query<
+        &mut Text,
+        (
+            With<FullWidthDigitsValueText>,
+            Without<BgmGaugeText>,
+            Without<SfxGaugeText>,
+            Without<EffectsValueText>,
+            Without<MergeGhostValueText>,
+            Without<HapticsValueText>,
+            Without<LanguageValueText>,
+            Without<VsyncValueText>,
+            Without<FpsCapValueText>,
+            Without<SweepAssistValueText>,
+            Without<AutoDropValueText>,
+            Without<AnnouncementsValueText>,
         ),
     >,
 ) {
@@ -467,11 +1205,31 @@ pub fn update_settings_display(
         text.0 = gauge_string(settings.sfx_volume);
     }
     for mut text in effects_q.iter_mut() {
-        text.0 = if settings.effects_enabled {
-            t("value_on", lang).to_string()
-        } else {
-            t("value_off", lang).to_string()
-        };
+        text.0 = on_off(settings.effects_enabled, lang).to_string();
+    }
+    for mut text in merge_ghost_q.iter_mut() {
+        text.0 = on_off(settings.merge_ghost, lang).to_string();
+    }
+    for mut text in haptics_q.iter_mut() {
+        text.0 = on_off(settings.haptics_enabled, lang).to_string();
+    }
+    for mut text in vsync_q.iter_mut() {
+        text.0 = on_off(settings.vsync, lang).to_string();
+    }
+    for mut text in fps_cap_q.iter_mut() {
+        text.0 = fps_cap_label(settings.fps_cap, lang);
+    }
+    for mut text in sweep_assist_q.iter_mut() {
+        text.0 = on_off(settings.sweep_assist, lang).to_string();
+    }
+    for mut text in auto_drop_q.iter_mut() {
+        text.0 = auto_drop_label(settings.auto_drop_secs, lang);
+    }
+    for mut text in announcements_q.iter_mut() {
+        text.0 = on_off(settings.announcements, lang).to_string();
+    }
+    for mut text in full_width_digits_q.iter_mut() {
+        text.0 = on_off(settings.full_width_digits, lang).to_string();
     }
     for mut text in lang_q.iter_mut() {
         text.0 = match settings.language {
@@ -481,6 +1239,109 @@ pub fn update_settings_display(
     }
 }
 
+/// Updates the mod rows' on/off text whenever [`SettingsResource`] changes.
+///
+/// Kept apart from [`update_settings_display`], whose queries are all fixed
+/// markers, because there is one [`ModValueText`] per installed mod.
+pub fn update_mod_display(
+    settings: Res<SettingsResource>,
+    mods: Option<Res<ModList>>,
+    mut query: Query<(&mut Text, &ModValueText)>,
+) {
+    let Some(mods) = mods else {
+        return;
+    };
+    if !settings.is_changed() {
+        return;
+    }
+    for (mut text, ModValueText(index)) in query.iter_mut() {
+        if let Some(info) = mods.mods.get(*index) {
+            text.0 = on_off(settings.mod_enabled(&info.name), settings.language).to_string();
+        }
+    }
+}
+
+/// Updates the ghost run and BGM visualizer rows whenever
+/// [`SettingsResource`] changes.
+///
+/// Kept apart from [`update_settings_display`] for the same reason as
+/// [`update_safe_mode_display`].
+#[allow(clippy::type_complexity)]
+pub fn update_hud_option_display(
+    settings: Res<SettingsResource>,
+    mut ghost_run_q: Query<&mut Text, (With<GhostRunValueText>, Without<VisualizerValueText>)>,
+    mut visualizer_q: Query<&mut Text, (With<VisualizerValueText>, Without<GhostRunValueText>)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let lang = settings.language;
+    for mut text in ghost_run_q.iter_mut() {
+        text.0 = on_off(settings.ghost_run, lang).to_string();
+    }
+    for mut text in visualizer_q.iter_mut() {
+        text.0 = on_off(settings.audio_visualizer, lang).to_string();
+    }
+}
+
+/// Updates the landing preview row whenever [`SettingsResource`] changes.
+///
+/// Kept apart from [`update_settings_display`] for the same reason as
+/// [`update_safe_mode_display`].
+pub fn update_landing_preview_display(
+    settings: Res<SettingsResource>,
+    mut landing_preview_q: Query<&mut Text, With<LandingPreviewValueText>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in landing_preview_q.iter_mut() {
+        text.0 = on_off(settings.landing_preview, settings.language).to_string();
+    }
+}
+
+/// Updates the touch controls row whenever [`SettingsResource`] changes.
+///
+/// Kept apart from [`update_settings_display`] for the same reason as
+/// [`update_safe_mode_display`].
+pub fn update_touch_controls_display(
+    settings: Res<SettingsResource>,
+    mut touch_controls_q: Query<&mut Text, With<TouchControlsValueText>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in touch_controls_q.iter_mut() {
+        text.0 = on_off(settings.touch_controls, settings.language).to_string();
+    }
+}
+
+/// Updates the streamer-safe and settings PIN rows whenever
+/// [`SettingsResource`] changes.
+///
+/// Kept apart from [`update_settings_display`] so its queries do not grow
+/// two more `Without` filters each.
+#[allow(clippy::type_complexity)]
+pub fn update_safe_mode_display(
+    settings: Res<SettingsResource>,
+    mut streamer_safe_q: Query<
+        &mut Text,
+        (With<StreamerSafeValueText>, Without<SettingsPinValueText>),
+    >,
+    mut pin_q: Query<&mut Text, (With<SettingsPinValueText>, Without<StreamerSafeValueText>)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let lang = settings.language;
+    for mut text in streamer_safe_q.iter_mut() {
+        text.0 = on_off(settings.streamer_safe, lang).to_string();
+    }
+    for mut text in pin_q.iter_mut() {
+        text.0 = on_off(!settings.settings_pin.is_empty(), lang).to_string();
+    }
+}
+
 /// Updates all [`TranslatableText`] nodes whenever [`SettingsResource`] changes.
 ///
 /// Queries every text entity tagged with [`TranslatableText`] (the settings
@@ -511,6 +1372,38 @@ pub fn update_translatable_texts(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_scroll_delta_brings_item_into_view() {
+        let view = (0.0, 600.0);
+        // Already visible: stays put
+        assert_eq!(scroll_delta(view, (100.0, 150.0), 20.0), 0.0);
+        // Below the view: scroll down until it clears the margin
+        assert_eq!(scroll_delta(view, (700.0, 750.0), 20.0), 170.0);
+        // Above the view: scroll up
+        assert_eq!(scroll_delta(view, (-80.0, -30.0), 20.0), -100.0);
+        // Taller than the view: align its top
+        assert_eq!(scroll_delta(view, (100.0, 900.0), 20.0), 80.0);
+    }
+
+    #[test]
+    fn test_arrow_layout_left_to_right() {
+        assert_eq!(arrow_layout(Language::English), [("◀", 0), ("▶", 1)]);
+        assert_eq!(arrow_layout(Language::Japanese), [("◀", 0), ("▶", 1)]);
+    }
+
+    #[test]
+    fn test_auto_drop_label() {
+        assert_eq!(auto_drop_label(5, Language::English), "5 s");
+        assert_eq!(auto_drop_label(5, Language::Japanese), "5秒");
+        assert_eq!(auto_drop_label(0, Language::English), "OFF");
+    }
+
+    #[test]
+    fn test_fps_cap_label() {
+        assert_eq!(fps_cap_label(144, Language::English), "144");
+        assert_eq!(fps_cap_label(0, Language::English), "Unlimited");
+    }
+
     #[test]
     fn test_gauge_string_vol_10() {
         let s = gauge_string(10);
@@ -0,0 +1,363 @@
+//! Stats screen — lifetime totals from [`StatsResource`].
+//!
+//! ```text
+//!                 これまでの記録 / Lifetime Stats
+//!
+//!  フルーツ別の合体数          プレイ回数        12
+//!  (●) 1,204                  総プレイ時間   1:02:45
+//!  (●)   873                  平均スコア     2,310
+//!  (●)   412
+//!   …                         スコア分布
+//!  (●)     1                   ▂ ▅ █ ▃ ▁ ▁
+//!                              0 500 …  4,500+
+//!
+//!                        [ もどる ]
+//! ```
+//!
+//! Fruit icons use the loaded [`FruitSprites`] and fall back to a circle in
+//! the fruit's placeholder colour.  All entities are tagged with
+//! [`DespawnOnExit`]`(`[`AppState::Stats`]`)`.
+
+use std::iter;
+
+use bevy::prelude::*;
+use suika_game_core::prelude::{AppState, FruitSprites, FruitType, StatsResource};
+use suika_game_core::resources::lifetime_stats::SCORE_BUCKET_COUNT;
+use suika_game_core::resources::settings::SettingsResource;
+
+use crate::components::{ButtonAction, KeyboardFocusIndex, spawn_button};
+use crate::i18n::t;
+use crate::number_format::format_score;
+use crate::styles::{
+    BG_COLOR, BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH, FONT_JP, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM,
+    FONT_SIZE_SMALL, PRIMARY_COLOR, SECONDARY_COLOR, TEXT_COLOR,
+};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Edge length of a fruit icon in the merge list (pixels).
+const ICON_SIZE: f32 = 24.0;
+
+/// Height of the tallest distribution bar (pixels).
+const CHART_HEIGHT: f32 = 140.0;
+
+/// Width of one distribution bar (pixels).
+const BAR_WIDTH: f32 = 28.0;
+
+/// Font size of the per-bar count and bucket labels.
+const CHART_LABEL_FONT_SIZE: f32 = 14.0;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the stats screen UI when entering [`AppState::Stats`].
+pub fn setup_stats_screen(
+    mut commands: Commands,
+    stats: Res<StatsResource>,
+    settings: Res<SettingsResource>,
+    fruit_sprites: Option<Res<FruitSprites>>,
+    asset_server: Res<AssetServer>,
+    mut keyboard_focus: ResMut<KeyboardFocusIndex>,
+) {
+    keyboard_focus.0 = 0;
+
+    let font: Handle<Font> = asset_server.load(FONT_JP);
+    let lang = settings.language;
+    let text_font = |size: f32| TextFont {
+        font: font.clone(),
+        font_size: size,
+        ..default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                overflow: Overflow::clip_y(),
+                ..default()
+            },
+            BackgroundColor(BG_COLOR),
+            DespawnOnExit(AppState::Stats),
+        ))
+        .with_children(|root| {
+            // Title
+            root.spawn((
+                Text::new(t("stats_title", lang)),
+                text_font(FONT_SIZE_LARGE),
+                TextColor(PRIMARY_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(24.0)),
+                    ..default()
+                },
+            ));
+
+            root.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(64.0),
+                margin: UiRect::bottom(Val::Px(32.0)),
+                ..default()
+            })
+            .with_children(|columns| {
+                // Left: merges per fruit
+                columns
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    })
+                    .with_children(|col| {
+                        col.spawn((
+                            Text::new(t("stats_merges", lang)),
+                            text_font(FONT_SIZE_SMALL),
+                            TextColor(SECONDARY_COLOR),
+                        ));
+                        for fruit in iter::successors(Some(FruitType::Cherry), FruitType::next) {
+                            col.spawn(Node {
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(12.0),
+                                ..default()
+                            })
+                            .with_children(|row| {
+                                spawn_fruit_icon(row, fruit, fruit_sprites.as_deref());
+                                row.spawn((
+                                    Text::new(format_score(stats.merges_of(fruit), &settings)),
+                                    text_font(FONT_SIZE_SMALL),
+                                    TextColor(TEXT_COLOR),
+                                ));
+                            });
+                        }
+                    });
+
+                // Right: totals and the score distribution
+                columns
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|col| {
+                        let average = stats
+                            .average_score()
+                            .map_or("-".into(), |average| format_score(average, &settings));
+                        let totals = [
+                            (
+                                "stats_games_played",
+                                format_score(stats.games_played, &settings),
+                            ),
+                            (
+                                "stats_total_playtime",
+                                format_playtime(stats.total_playtime as u64),
+                            ),
+                            ("stats_average_score", average),
+                        ];
+                        for (key, value) in totals {
+                            col.spawn(Node {
+                                justify_content: JustifyContent::SpaceBetween,
+                                column_gap: Val::Px(32.0),
+                                ..default()
+                            })
+                            .with_children(|row| {
+                                row.spawn((
+                                    Text::new(t(key, lang)),
+                                    text_font(FONT_SIZE_SMALL),
+                                    TextColor(TEXT_COLOR),
+                                ));
+                                row.spawn((
+                                    Text::new(value),
+                                    text_font(FONT_SIZE_SMALL),
+                                    TextColor(PRIMARY_COLOR),
+                                ));
+                            });
+                        }
+
+                        col.spawn((
+                            Text::new(t("stats_distribution", lang)),
+                            text_font(FONT_SIZE_SMALL),
+                            TextColor(SECONDARY_COLOR),
+                            Node {
+                                margin: UiRect::top(Val::Px(16.0)),
+                                ..default()
+                            },
+                        ));
+                        spawn_distribution_chart(col, &stats, &settings, &font);
+                    });
+            });
+
+            // Back button (index 0 — initial keyboard focus)
+            spawn_button(
+                root,
+                t("btn_back", lang),
+                ButtonAction::BackToTitle,
+                0,
+                FONT_SIZE_MEDIUM,
+                BUTTON_LARGE_WIDTH,
+                BUTTON_LARGE_HEIGHT,
+                font.clone(),
+            );
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Spawns `fruit`'s sprite, or a circle in its placeholder colour when no
+/// sprite is loaded.
+fn spawn_fruit_icon(
+    parent: &mut ChildSpawnerCommands,
+    fruit: FruitType,
+    fruit_sprites: Option<&FruitSprites>,
+) {
+    let node = Node {
+        width: Val::Px(ICON_SIZE),
+        height: Val::Px(ICON_SIZE),
+        ..default()
+    };
+    match fruit_sprites.and_then(|s| s.get(fruit)) {
+        Some(handle) => {
+            let image = match fruit_sprites.and_then(|s| s.atlas(fruit)) {
+                Some(atlas) => ImageNode::from_atlas_image(handle.clone(), atlas),
+                None => ImageNode::new(handle.clone()),
+            };
+            parent.spawn((node, image));
+        }
+        None => {
+            parent.spawn((
+                node,
+                BackgroundColor(fruit.placeholder_color()),
+                BorderRadius::all(Val::Percent(50.0)),
+            ));
+        }
+    }
+}
+
+/// Spawns one bar per score bucket, scaled against the fullest bucket, with
+/// the game count above and the bucket's lowest score below.
+fn spawn_distribution_chart(
+    parent: &mut ChildSpawnerCommands,
+    stats: &StatsResource,
+    settings: &SettingsResource,
+    font: &Handle<Font>,
+) {
+    let label_font = TextFont {
+        font: font.clone(),
+        font_size: CHART_LABEL_FONT_SIZE,
+        ..default()
+    };
+    let max = stats.score_distribution.iter().copied().max().unwrap_or(0);
+
+    parent
+        .spawn(Node {
+            align_items: AlignItems::FlexEnd,
+            column_gap: Val::Px(6.0),
+            ..default()
+        })
+        .with_children(|chart| {
+            for (bucket, &count) in stats.score_distribution.iter().enumerate() {
+                chart
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(2.0),
+                        ..default()
+                    })
+                    .with_children(|column| {
+                        column.spawn((
+                            Text::new(count.to_string()),
+                            label_font.clone(),
+                            TextColor(TEXT_COLOR),
+                        ));
+                        column.spawn((
+                            Node {
+                                width: Val::Px(BAR_WIDTH),
+                                height: Val::Px(bar_height(count, max)),
+                                ..default()
+                            },
+                            BackgroundColor(PRIMARY_COLOR),
+                            BorderRadius::top(Val::Px(3.0)),
+                        ));
+                        column.spawn((
+                            Text::new(bucket_label(bucket, settings)),
+                            label_font.clone(),
+                            TextColor(TEXT_COLOR),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Bar height for a bucket holding `count` of the fullest bucket's `max`
+/// games; empty buckets keep a 1 px baseline.
+fn bar_height(count: u32, max: u32) -> f32 {
+    if max == 0 {
+        return 1.0;
+    }
+    (CHART_HEIGHT * count as f32 / max as f32).max(1.0)
+}
+
+/// Lowest score of `bucket`, with a `+` on the open-ended last bucket.
+fn bucket_label(bucket: usize, settings: &SettingsResource) -> String {
+    let floor = format_score(StatsResource::bucket_floor(bucket), settings);
+    if bucket + 1 == SCORE_BUCKET_COUNT {
+        format!("{floor}+")
+    } else {
+        floor
+    }
+}
+
+/// Formats seconds as `H:MM:SS`.
+///
+/// # Examples
+///
+/// ```
+/// # use suika_game_ui::screens::stats::format_playtime;
+/// assert_eq!(format_playtime(0),    "0:00:00");
+/// assert_eq!(format_playtime(3725), "1:02:05");
+/// ```
+pub fn format_playtime(total_secs: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        total_secs / 3600,
+        total_secs / 60 % 60,
+        total_secs % 60
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_playtime() {
+        assert_eq!(format_playtime(59), "0:00:59");
+        assert_eq!(format_playtime(3600), "1:00:00");
+        assert_eq!(format_playtime(100 * 3600 + 61), "100:01:01");
+    }
+
+    #[test]
+    fn test_bar_height_scales_to_fullest_bucket() {
+        assert_eq!(bar_height(4, 4), CHART_HEIGHT);
+        assert_eq!(bar_height(2, 4), CHART_HEIGHT / 2.0);
+        assert_eq!(bar_height(0, 4), 1.0);
+        assert_eq!(bar_height(0, 0), 1.0);
+    }
+
+    #[test]
+    fn test_bucket_label_marks_open_ended_bucket() {
+        let settings = SettingsResource::default();
+        assert_eq!(bucket_label(0, &settings), "0");
+        assert_eq!(bucket_label(2, &settings), "1,000");
+        assert!(bucket_label(SCORE_BUCKET_COUNT - 1, &settings).ends_with('+'));
+    }
+}
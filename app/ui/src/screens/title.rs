@@ -2,40 +2,158 @@
 //!
 //! Spawns a full-screen layout containing:
 //! - The game title at the top center
-//! - **Start**, **Settings**, **How to Play**, and **Quit** buttons
-//! - The all-time highscore at the bottom
+//! - **Start**, **Sandbox**, **Settings**, **How to Play**, **Stats**, and
+//!   **Quit** buttons
+//! - A **seed code** field for replaying a friend's fruit sequence
+//! - The **profile** selector: ◀ / ▶ switch between player profiles and
+//!   **New** creates one (hidden when saving is disabled)
+//! - The all-time highscore, best combo and largest fruit at the bottom
+//! - The build version ([`BuildInfo`]) in the bottom-right corner
+//!
+//! The seed code field takes keyboard input after a click or Tab; Enter
+//! applies the code (an empty field clears it) and Esc leaves the field.
+//! In streamer-safe mode the code and the profile name are masked.  An
+//! applied code sets [`ChallengeSeed`] together with its [`GameMode`], so
+//! every following game uses the code's seed, mode and difficulty.
+//!
+//! When an unfinished session was saved ([`SessionAvailable`]), the Start
+//! button is replaced by **Continue** and **New Game**.
+//!
+//! When some RON configs could not be loaded and built-in defaults are in use
+//! ([`ConfigLoadStatus`]) or a sound file is missing ([`AssetLoadStatus`]), a
+//! warning banner is shown above the title.
 //!
 //! All entities are tagged with [`DespawnOnExit`]`(AppState::Title)` so Bevy
 //! automatically despawns them when the state transitions away from `Title`.
 
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
-use suika_game_core::prelude::{AppState, GameState, SettingsResource};
+use suika_game_core::constants::storage::DEFAULT_PROFILE;
+use suika_game_core::prelude::{
+    AppState, AssetLoadStatus, BuildInfo, ChallengeSeed, ConfigLoadStatus, CurrentProfile,
+    GameMode, GameState, LaunchOptions, SaveDir, ScoreStrategy, SeedCode, SeedCodeError,
+    SessionAvailable, SettingsResource,
+};
+use suika_game_core::resources::settings::Language;
+use suika_game_core::seed_code::SEED_CODE_LEN;
+use suika_game_core::systems::safe_mode::SafeMode;
 
-use crate::components::{ButtonAction, KeyboardFocusIndex, spawn_button};
+use crate::components::{ButtonAction, KeyboardFocusIndex, TextInputActive, spawn_button};
+use crate::focus::FocusCell;
 use crate::i18n::t;
+use crate::number_format::format_score;
+use crate::safe_mode::mask;
+use crate::screens::game_over::records_line;
 use crate::styles::{
-    BG_COLOR, BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH, FONT_JP, FONT_SIZE_HUGE, FONT_SIZE_LARGE,
-    FONT_SIZE_SMALL, PRIMARY_COLOR, TEXT_COLOR,
+    BG_COLOR, BUTTON_HOVER, BUTTON_LARGE_HEIGHT, BUTTON_LARGE_WIDTH, BUTTON_NORMAL, FONT_JP,
+    FONT_SIZE_HUGE, FONT_SIZE_LARGE, FONT_SIZE_SMALL, FONT_SYMBOL, PRIMARY_COLOR, TEXT_COLOR,
 };
 
+/// Text color of the load warning banner.
+const WARNING_COLOR: Color = Color::srgb(0.55, 0.1, 0.05);
+
+/// Background of the load warning banner.
+const WARNING_BG_COLOR: Color = Color::srgba(1.0, 0.85, 0.3, 0.9);
+
+/// Font size of the build version in the corner.
+const BUILD_INFO_FONT_SIZE: f32 = 14.0;
+
+/// Text color of the build version — muted so it does not compete with the menu.
+const BUILD_INFO_COLOR: Color = Color::srgba(0.1, 0.1, 0.1, 0.5);
+
+/// Width of the seed code field.
+const SEED_FIELD_WIDTH: f32 = 360.0;
+
+/// Font size of the seed code field and its status line.
+const SEED_FIELD_FONT_SIZE: f32 = 20.0;
+
+/// Placeholder / status text color of the seed code field.
+const SEED_HINT_COLOR: Color = Color::srgba(0.1, 0.1, 0.1, 0.6);
+
+/// Status text color for a rejected code.
+const SEED_ERROR_COLOR: Color = Color::srgb(0.8, 0.2, 0.2);
+
+/// Characters the field accepts: a full code plus its two dashes.
+const SEED_FIELD_MAX_LEN: usize = SEED_CODE_LEN + 2;
+
+/// Side of the profile selector's ◀ / ▶ buttons.
+const PROFILE_ARROW_SIZE: f32 = 40.0;
+
+/// Width of the profile name between the arrows.
+const PROFILE_NAME_WIDTH: f32 = 180.0;
+
+/// Width of the profile selector's **New** button.
+const PROFILE_NEW_WIDTH: f32 = 90.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// The seed code input box; holds the typed text and the last result.
+#[derive(Component, Debug, Default)]
+pub struct SeedCodeField {
+    /// Text typed so far, uppercased.
+    pub text: String,
+    /// Outcome of the last Enter press.
+    pub status: SeedCodeStatus,
+}
+
+/// Marker for the text inside [`SeedCodeField`].
+#[derive(Component, Debug)]
+pub struct SeedCodeText;
+
+/// Marker for the status line under [`SeedCodeField`].
+#[derive(Component, Debug)]
+pub struct SeedCodeStatusText;
+
+/// Feedback shown under the seed code field.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SeedCodeStatus {
+    /// Nothing entered yet: explains how to use the field.
+    #[default]
+    Hint,
+    /// The code is active for the following games.
+    Applied(SeedCode),
+    /// The field was emptied; games use random seeds again.
+    Cleared,
+    /// The typed code could not be decoded.
+    Invalid(SeedCodeError),
+}
+
 // ---------------------------------------------------------------------------
 // Systems
 // ---------------------------------------------------------------------------
 
 /// Spawns the title screen UI when entering [`AppState::Title`].
 ///
-/// Resets [`KeyboardFocusIndex`] to `0` so the Start button always has focus
-/// when (re-)entering this screen.
+/// Resets [`KeyboardFocusIndex`] to `0` so the first button (Start, or
+/// Continue when a saved session exists) always has focus when (re-)entering
+/// this screen.  The seed code field starts unfocused and shows the active
+/// [`ChallengeSeed`], if any.  The profile selector shows the
+/// [`CurrentProfile`]; switching profiles enters this screen again.
+#[allow(clippy::too_many_arguments)]
 pub fn setup_title_screen(
     mut commands: Commands,
     game_state: Res<GameState>,
     settings: Res<SettingsResource>,
+    session: Res<SessionAvailable>,
+    config_status: Res<ConfigLoadStatus>,
+    asset_status: Res<AssetLoadStatus>,
+    build_info: Res<BuildInfo>,
     asset_server: Res<AssetServer>,
+    challenge: Res<ChallengeSeed>,
+    profile: Res<CurrentProfile>,
+    save_dir: Res<SaveDir>,
+    safe_mode: Res<SafeMode>,
     mut keyboard_focus: ResMut<KeyboardFocusIndex>,
+    mut text_input: ResMut<TextInputActive>,
 ) {
     keyboard_focus.0 = 0;
+    text_input.0 = false;
 
     let font: Handle<Font> = asset_server.load(FONT_JP);
+    let symbol_font: Handle<Font> = asset_server.load(FONT_SYMBOL);
     let lang = settings.language;
 
     commands
@@ -52,6 +170,60 @@ pub fn setup_title_screen(
             DespawnOnExit(AppState::Title),
         ))
         .with_children(|parent| {
+            // Load warning (only when built-in configs are in use or a
+            // tracked asset such as a sound file is missing)
+            let mut warnings = Vec::new();
+            if !config_status.fallback.is_empty() {
+                warnings.push(format!(
+                    "{}\n{}",
+                    t("config_fallback_warning", lang),
+                    config_status.fallback.join(", ")
+                ));
+            }
+            if !asset_status.failed.is_empty() {
+                warnings.push(format!(
+                    "{}\n{}",
+                    t("asset_load_warning", lang),
+                    asset_status.failed.join(", ")
+                ));
+            }
+            if !warnings.is_empty() {
+                parent.spawn((
+                    Text::new(warnings.join("\n")),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: FONT_SIZE_SMALL,
+                        ..default()
+                    },
+                    TextColor(WARNING_COLOR),
+                    TextLayout::new_with_justify(Justify::Center),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(16.0),
+                        padding: UiRect::all(Val::Px(8.0)),
+                        ..default()
+                    },
+                    BackgroundColor(WARNING_BG_COLOR),
+                ));
+            }
+
+            // Build version in the bottom-right corner, for bug reports
+            parent.spawn((
+                Text::new(build_info.to_string()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: BUILD_INFO_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(BUILD_INFO_COLOR),
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(12.0),
+                    bottom: Val::Px(8.0),
+                    ..default()
+                },
+            ));
+
             // Game title
             parent.spawn((
                 Text::new(t("game_title", lang)),
@@ -67,60 +239,114 @@ pub fn setup_title_screen(
                 },
             ));
 
-            // Start button (index 0 — receives initial BUTTON_HOVER color)
-            spawn_button(
-                parent,
-                t("btn_start", lang),
-                ButtonAction::StartGame,
-                0,
-                FONT_SIZE_LARGE,
-                BUTTON_LARGE_WIDTH,
-                BUTTON_LARGE_HEIGHT,
-                font.clone(),
-            );
+            // Start buttons. With a saved session: Continue (index 0) + New Game
+            // (index 1); otherwise a single Start button (index 0).
+            let start_buttons: &[(&'static str, ButtonAction)] = if session.0 {
+                &[
+                    ("btn_continue", ButtonAction::ContinueGame),
+                    ("btn_new_game", ButtonAction::StartGame),
+                ]
+            } else {
+                &[("btn_start", ButtonAction::StartGame)]
+            };
 
-            // Settings button (index 1)
-            spawn_button(
-                parent,
-                t("btn_settings", lang),
-                ButtonAction::OpenSettings,
-                1,
-                FONT_SIZE_LARGE,
-                BUTTON_LARGE_WIDTH,
-                BUTTON_LARGE_HEIGHT,
-                font.clone(),
-            );
+            // Sandbox, settings, how-to-play, stats and quit follow the start
+            // buttons.
+            let menu_buttons = [
+                ("btn_sandbox", ButtonAction::StartSandbox),
+                ("btn_settings", ButtonAction::OpenSettings),
+                ("btn_how_to_play", ButtonAction::OpenHowToPlay),
+                ("btn_stats", ButtonAction::OpenStats),
+                ("btn_quit", ButtonAction::QuitGame),
+            ];
 
-            // How-to-play button (index 2)
-            spawn_button(
-                parent,
-                t("btn_how_to_play", lang),
-                ButtonAction::OpenHowToPlay,
-                2,
-                FONT_SIZE_LARGE,
-                BUTTON_LARGE_WIDTH,
-                BUTTON_LARGE_HEIGHT,
-                font.clone(),
-            );
+            for (index, &(key, action)) in
+                start_buttons.iter().chain(menu_buttons.iter()).enumerate()
+            {
+                spawn_button(
+                    parent,
+                    t(key, lang),
+                    action,
+                    index,
+                    FONT_SIZE_LARGE,
+                    BUTTON_LARGE_WIDTH,
+                    BUTTON_LARGE_HEIGHT,
+                    font.clone(),
+                );
+            }
 
-            // Quit button (index 3)
-            spawn_button(
-                parent,
-                t("btn_quit", lang),
-                ButtonAction::QuitGame,
-                3,
-                FONT_SIZE_LARGE,
-                BUTTON_LARGE_WIDTH,
-                BUTTON_LARGE_HEIGHT,
-                font.clone(),
-            );
+            // Seed code field and its status line
+            let field = match challenge.0 {
+                Some(code) => SeedCodeField {
+                    text: code.to_string(),
+                    status: SeedCodeStatus::Applied(code),
+                },
+                None => SeedCodeField::default(),
+            };
+            let label = field_text(&field, false, safe_mode.active, lang);
+            let (status, status_color) = status_text(&field.status, lang);
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(SEED_FIELD_WIDTH),
+                        margin: UiRect::top(Val::Px(40.0)),
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    BorderColor::all(BUTTON_NORMAL),
+                    BackgroundColor(Color::WHITE),
+                ))
+                .with_children(|field_node| {
+                    field_node.spawn((
+                        Text::new(label),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: SEED_FIELD_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR),
+                        SeedCodeText,
+                    ));
+                })
+                .insert(field);
+            parent.spawn((
+                Text::new(status),
+                TextFont {
+                    font: font.clone(),
+                    font_size: SEED_FIELD_FONT_SIZE * 0.8,
+                    ..default()
+                },
+                TextColor(status_color),
+                Node {
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+                SeedCodeStatusText,
+            ));
+
+            // Profile selector after the menu buttons; hidden when there is
+            // nowhere to keep profiles
+            if save_dir.path().is_some() {
+                spawn_profile_row(
+                    parent,
+                    &profile,
+                    safe_mode.active,
+                    start_buttons.len() + menu_buttons.len(),
+                    lang,
+                    font.clone(),
+                    symbol_font,
+                );
+            }
 
             // Highscore display
             parent.spawn((
                 Text::new(format!(
                     "{}: {}",
                     t("highscore", lang),
-                    format_score(game_state.highscore)
+                    format_score(game_state.highscore, &settings)
                 )),
                 TextFont {
                     font: font.clone(),
@@ -129,38 +355,292 @@ pub fn setup_title_screen(
                 },
                 TextColor(TEXT_COLOR),
                 Node {
-                    margin: UiRect::top(Val::Px(150.0)),
+                    margin: UiRect::top(Val::Px(60.0)),
+                    ..default()
+                },
+            ));
+
+            // Best combo and largest fruit records
+            parent.spawn((
+                Text::new(records_line(&game_state, lang)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(8.0)),
                     ..default()
                 },
             ));
         });
 }
 
+/// Edits the seed code field: click or Tab focuses it, typed letters and
+/// digits are appended, Backspace deletes, Esc leaves and Enter applies.
+///
+/// Applying sets [`ChallengeSeed`], [`GameMode`] and [`ScoreStrategy`]; an
+/// empty field goes back to random seeds and the launch mode.  The Enter
+/// press is consumed so it does not also confirm the focused menu button, so
+/// this must run before
+/// [`handle_keyboard_menu_navigation`](crate::components::handle_keyboard_menu_navigation).
+#[allow(clippy::too_many_arguments)]
+pub fn edit_seed_code(
+    mut key_events: MessageReader<KeyboardInput>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut text_input: ResMut<TextInputActive>,
+    mut field_query: Query<(Ref<Interaction>, &mut SeedCodeField)>,
+    mut challenge: ResMut<ChallengeSeed>,
+    mut mode: ResMut<GameMode>,
+    mut strategy: ResMut<ScoreStrategy>,
+    launch: Option<Res<LaunchOptions>>,
+) {
+    let Ok((interaction, mut field)) = field_query.single_mut() else {
+        key_events.clear();
+        return;
+    };
+    if interaction.is_changed() && *interaction == Interaction::Pressed {
+        text_input.0 = true;
+    }
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match (&event.logical_key, text_input.0) {
+            (Key::Tab, active) => text_input.0 = !active,
+            (_, false) => {}
+            (Key::Escape, true) => text_input.0 = false,
+            (Key::Backspace, true) => {
+                field.text.pop();
+            }
+            (Key::Enter, true) => {
+                keyboard.clear_just_pressed(KeyCode::Enter);
+                let default_mode = launch.as_ref().and_then(|l| l.mode).unwrap_or_default();
+                let status = apply_seed_input(&field.text, &mut challenge, default_mode);
+                let new_mode = challenge.0.map_or(default_mode, |code| code.mode);
+                *mode = new_mode;
+                *strategy = new_mode.score_strategy();
+                if let SeedCodeStatus::Applied(code) = status {
+                    field.text = code.to_string();
+                }
+                if !matches!(status, SeedCodeStatus::Invalid(_)) {
+                    text_input.0 = false;
+                }
+                field.status = status;
+            }
+            (Key::Character(chars), true) => {
+                for c in chars
+                    .chars()
+                    .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+                {
+                    if field.text.len() < SEED_FIELD_MAX_LEN {
+                        field.text.push(c.to_ascii_uppercase());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Redraws the seed code field and its status line.
+pub fn update_seed_code_field(
+    settings: Res<SettingsResource>,
+    text_input: Res<TextInputActive>,
+    safe_mode: Res<SafeMode>,
+    mut field_query: Query<(&SeedCodeField, &mut BorderColor)>,
+    mut text_query: Query<(&mut Text, &mut TextColor), With<SeedCodeText>>,
+    mut status_query: Query<
+        (&mut Text, &mut TextColor),
+        (With<SeedCodeStatusText>, Without<SeedCodeText>),
+    >,
+) {
+    let Ok((field, mut border)) = field_query.single_mut() else {
+        return;
+    };
+    let lang = settings.language;
+    let active = text_input.0;
+
+    border.set_all(if active { BUTTON_HOVER } else { BUTTON_NORMAL });
+    if let Ok((mut text, mut color)) = text_query.single_mut() {
+        text.0 = field_text(field, active, safe_mode.active, lang);
+        color.0 = if field.text.is_empty() && !active {
+            SEED_HINT_COLOR
+        } else {
+            TEXT_COLOR
+        };
+    }
+    if let Ok((mut text, mut color)) = status_query.single_mut() {
+        let (status, status_color) = status_text(&field.status, lang);
+        text.0 = status;
+        color.0 = status_color;
+    }
+}
+
+/// Stops the seed code field from swallowing keys after leaving the title.
+pub fn end_seed_code_input(mut text_input: ResMut<TextInputActive>) {
+    text_input.0 = false;
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
-/// Formats an integer with comma separators every three digits.
+/// Spawns the profile selector: label, ◀, profile name, ▶ and **New**.
 ///
-/// # Examples
+/// The three buttons take indices `first` to `first + 2`, side by side on
+/// keyboard-focus row `first`.
+fn spawn_profile_row(
+    parent: &mut bevy::ecs::hierarchy::ChildSpawnerCommands,
+    profile: &CurrentProfile,
+    masked: bool,
+    first: usize,
+    lang: Language,
+    font: Handle<Font>,
+    symbol_font: Handle<Font>,
+) {
+    let text_font = TextFont {
+        font: font.clone(),
+        font_size: FONT_SIZE_SMALL,
+        ..default()
+    };
+    parent
+        .spawn(Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(4.0),
+            margin: UiRect::top(Val::Px(24.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(t("profile", lang)),
+                text_font.clone(),
+                TextColor(TEXT_COLOR),
+            ));
+            spawn_button(
+                row,
+                "◀",
+                ButtonAction::PreviousProfile,
+                first,
+                FONT_SIZE_SMALL,
+                PROFILE_ARROW_SIZE,
+                PROFILE_ARROW_SIZE,
+                symbol_font.clone(),
+            )
+            .insert(FocusCell::new(first, 0));
+            row.spawn((
+                Text::new(profile_label(&profile.name, masked, lang)),
+                text_font,
+                TextColor(PRIMARY_COLOR),
+                TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    width: Val::Px(PROFILE_NAME_WIDTH),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+            ));
+            spawn_button(
+                row,
+                "▶",
+                ButtonAction::NextProfile,
+                first + 1,
+                FONT_SIZE_SMALL,
+                PROFILE_ARROW_SIZE,
+                PROFILE_ARROW_SIZE,
+                symbol_font,
+            )
+            .insert(FocusCell::new(first, 1));
+            spawn_button(
+                row,
+                t("btn_new_profile", lang),
+                ButtonAction::NewProfile,
+                first + 2,
+                FONT_SIZE_SMALL,
+                PROFILE_NEW_WIDTH,
+                PROFILE_ARROW_SIZE,
+                font,
+            )
+            .insert(FocusCell::new(first, 2));
+        });
+}
+
+/// Name shown for a profile; the default profile is "Player 1".  Other
+/// names are `masked` in streamer-safe mode.
+fn profile_label(name: &str, masked: bool, lang: Language) -> String {
+    if name == DEFAULT_PROFILE {
+        t("profile_default", lang).to_string()
+    } else if masked {
+        mask(name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Decodes `input` into `challenge`; an empty input clears it.
 ///
-/// ```
-/// # use suika_game_ui::screens::title::format_score;
-/// assert_eq!(format_score(0),       "0");
-/// assert_eq!(format_score(1000),    "1,000");
-/// assert_eq!(format_score(123456),  "123,456");
-/// assert_eq!(format_score(1234567), "1,234,567");
-/// ```
-pub fn format_score(n: u32) -> String {
-    let s = n.to_string();
-    let mut result = String::new();
-    for (i, c) in s.chars().rev().enumerate() {
-        if i > 0 && i % 3 == 0 {
-            result.push(',');
+/// A rejected code leaves `challenge` unchanged.
+fn apply_seed_input(
+    input: &str,
+    challenge: &mut ChallengeSeed,
+    default_mode: GameMode,
+) -> SeedCodeStatus {
+    if input.trim().is_empty() {
+        challenge.0 = None;
+        info!("Seed code cleared; playing {default_mode}");
+        return SeedCodeStatus::Cleared;
+    }
+    match SeedCode::decode(input) {
+        Ok(code) => {
+            challenge.0 = Some(code);
+            info!(
+                "Seed code {code} applied: seed {}, {}, difficulty {}",
+                code.seed, code.mode, code.difficulty
+            );
+            SeedCodeStatus::Applied(code)
         }
-        result.push(c);
+        Err(e) => SeedCodeStatus::Invalid(e),
+    }
+}
+
+/// Field contents: the typed text (with a cursor while focused) or a
+/// placeholder.  The text is `masked` in streamer-safe mode.
+fn field_text(field: &SeedCodeField, active: bool, masked: bool, lang: Language) -> String {
+    let text = if masked {
+        mask(&field.text)
+    } else {
+        field.text.clone()
+    };
+    if active {
+        format!("{text}_")
+    } else if text.is_empty() {
+        t("seed_code_placeholder", lang).to_string()
+    } else {
+        text
+    }
+}
+
+/// Status line text and color.
+fn status_text(status: &SeedCodeStatus, lang: Language) -> (String, Color) {
+    match status {
+        SeedCodeStatus::Hint => (t("seed_code_hint", lang).to_string(), SEED_HINT_COLOR),
+        SeedCodeStatus::Applied(code) => (
+            format!(
+                "{} {} / {} {}",
+                t("seed_code_applied", lang),
+                code.mode,
+                t("seed_code_difficulty", lang),
+                code.difficulty
+            ),
+            PRIMARY_COLOR,
+        ),
+        SeedCodeStatus::Cleared => (t("seed_code_cleared", lang).to_string(), SEED_HINT_COLOR),
+        SeedCodeStatus::Invalid(e) => (
+            format!("{}: {e}", t("seed_code_invalid", lang)),
+            SEED_ERROR_COLOR,
+        ),
     }
-    result.chars().rev().collect()
 }
 
 // ---------------------------------------------------------------------------
@@ -172,31 +652,64 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_format_score_zero() {
-        assert_eq!(format_score(0), "0");
-    }
+    fn test_apply_seed_input() {
+        let code = SeedCode::new(77, GameMode::NoCombo, 2);
+        let mut challenge = ChallengeSeed::default();
 
-    #[test]
-    fn test_format_score_below_thousand() {
-        assert_eq!(format_score(999), "999");
+        let status = apply_seed_input(&code.to_string(), &mut challenge, GameMode::Classic);
+        assert_eq!(status, SeedCodeStatus::Applied(code));
+        assert_eq!(challenge.0, Some(code));
+
+        let status = apply_seed_input("NOT-A-CODE", &mut challenge, GameMode::Classic);
+        assert!(matches!(status, SeedCodeStatus::Invalid(_)));
+        assert_eq!(challenge.0, Some(code));
+
+        let status = apply_seed_input("", &mut challenge, GameMode::Classic);
+        assert_eq!(status, SeedCodeStatus::Cleared);
+        assert_eq!(challenge.0, None);
     }
 
     #[test]
-    fn test_format_score_thousands() {
-        assert_eq!(format_score(1_000), "1,000");
-        assert_eq!(format_score(10_000), "10,000");
-        assert_eq!(format_score(100_000), "100,000");
+    fn test_profile_label_names_default_profile() {
+        assert_eq!(
+            profile_label(DEFAULT_PROFILE, false, Language::English),
+            "Player 1"
+        );
+        assert_eq!(
+            profile_label("Player 2", false, Language::Japanese),
+            "Player 2"
+        );
+        assert_eq!(
+            profile_label("Player 2", true, Language::Japanese),
+            "•••••• •"
+        );
+        assert_eq!(
+            profile_label(DEFAULT_PROFILE, true, Language::English),
+            "Player 1"
+        );
     }
 
     #[test]
-    fn test_format_score_millions() {
-        assert_eq!(format_score(1_000_000), "1,000,000");
-        assert_eq!(format_score(1_234_567), "1,234,567");
+    fn test_field_text_shows_cursor_or_placeholder() {
+        let mut field = SeedCodeField::default();
+        assert_eq!(
+            field_text(&field, false, false, Language::English),
+            t("seed_code_placeholder", Language::English)
+        );
+        field.text = "AB".to_string();
+        assert_eq!(field_text(&field, true, false, Language::English), "AB_");
+        assert_eq!(field_text(&field, false, false, Language::English), "AB");
     }
 
     #[test]
-    fn test_format_score_u32_max() {
-        // u32::MAX = 4,294,967,295
-        assert_eq!(format_score(u32::MAX), "4,294,967,295");
+    fn test_field_text_masked_in_safe_mode() {
+        let mut field = SeedCodeField::default();
+        assert_eq!(
+            field_text(&field, false, true, Language::English),
+            t("seed_code_placeholder", Language::English)
+        );
+        field.text = "AB".to_string();
+        assert_eq!(field_text(&field, true, true, Language::English), "••_");
+        assert_eq!(field_text(&field, false, true, Language::English), "••");
     }
 }
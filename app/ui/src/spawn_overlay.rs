@@ -0,0 +1,242 @@
+//! Spawn-probability debug overlay.
+//!
+//! A panel in the bottom-left corner, hidden by default, that compares the
+//! randomizer's configured odds with what actually came out this session —
+//! useful while tuning `spawnable_fruit_count` in `game_rules.ron`.  Toggled
+//! with [`SPAWN_OVERLAY_KEY`] or by flipping [`SpawnOverlayVisible`] from the
+//! debug inspector.
+//!
+//! - **Weight** — [`NextFruitType::biased_probabilities`] for the live
+//!   `GameRulesConfig` and [`AdaptiveSpawn`] bias, so hot-reloaded rules
+//!   and the adaptive spawn nudge show up immediately.
+//! - **Seen** — fruits dropped since the app started ([`SpawnTally`]), with
+//!   their share of all drops.
+//!
+//! ```text
+//! ┌──────────────────────────────────┐
+//! │ Fruit        Weight   Seen       │
+//! │ Cherry        20.0%     41 19.5% │
+//! │ Strawberry    20.0%     44 21.0% │
+//! │ …                                │
+//! │ total                  210       │
+//! └──────────────────────────────────┘
+//! ```
+
+use bevy::prelude::*;
+use suika_game_core::config::GameRulesParams;
+use suika_game_core::prelude::{AdaptiveSpawn, FruitDroppedEvent, FruitType, NextFruitType};
+use suika_game_core::systems::input::DEFAULT_SPAWNABLE_FRUIT_COUNT;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Key that shows / hides the overlay.
+pub const SPAWN_OVERLAY_KEY: KeyCode = KeyCode::F4;
+
+const PANEL_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+// ---------------------------------------------------------------------------
+// Resources / components
+// ---------------------------------------------------------------------------
+
+/// Whether the spawn overlay is shown.
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct SpawnOverlayVisible(pub bool);
+
+/// Dropped fruits per spawnable type since the app started.
+#[derive(Resource, Debug)]
+pub struct SpawnTally {
+    /// Count per entry of [`FruitType::spawnable_fruits`].
+    pub counts: Vec<u32>,
+}
+
+impl Default for SpawnTally {
+    fn default() -> Self {
+        Self {
+            counts: vec![0; FruitType::spawnable_fruits().len()],
+        }
+    }
+}
+
+impl SpawnTally {
+    /// Total number of recorded drops.
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+}
+
+/// Marks the overlay panel.
+#[derive(Component, Debug)]
+pub struct SpawnOverlay;
+
+/// Marks the overlay's table text.
+#[derive(Component, Debug)]
+pub struct SpawnOverlayText;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the hidden overlay panel.
+pub fn spawn_spawn_overlay(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(4.0),
+                left: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            GlobalZIndex(i32::MAX),
+            Visibility::Hidden,
+            SpawnOverlay,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                SpawnOverlayText,
+            ));
+        });
+}
+
+/// Counts every dropped fruit in [`SpawnTally`].
+pub fn tally_spawns(mut dropped: MessageReader<FruitDroppedEvent>, mut tally: ResMut<SpawnTally>) {
+    let spawnable = FruitType::spawnable_fruits();
+    for event in dropped.read() {
+        if let Some(i) = spawnable.iter().position(|&f| f == event.fruit_type) {
+            tally.counts[i] += 1;
+        }
+    }
+}
+
+/// Flips [`SpawnOverlayVisible`] on [`SPAWN_OVERLAY_KEY`].
+pub fn toggle_spawn_overlay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut visible: ResMut<SpawnOverlayVisible>,
+) {
+    if keyboard.just_pressed(SPAWN_OVERLAY_KEY) {
+        visible.0 = !visible.0;
+    }
+}
+
+/// Shows / hides the overlay and refreshes the table while visible.
+pub fn update_spawn_overlay(
+    visible: Res<SpawnOverlayVisible>,
+    tally: Res<SpawnTally>,
+    rules: GameRulesParams,
+    adaptive: Option<Res<AdaptiveSpawn>>,
+    mut panel_query: Query<&mut Visibility, With<SpawnOverlay>>,
+    mut text_query: Query<&mut Text, With<SpawnOverlayText>>,
+) {
+    let Ok(mut visibility) = panel_query.single_mut() else {
+        return;
+    };
+    visibility.set_if_neq(if visible.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    });
+    if !visible.0 {
+        return;
+    }
+
+    let spawnable_count = rules
+        .get()
+        .map(|r| r.spawnable_fruit_count)
+        .unwrap_or(DEFAULT_SPAWNABLE_FRUIT_COUNT);
+    let bias = adaptive.map_or(0.0, |a| a.bias);
+    if let Ok(mut text) = text_query.single_mut() {
+        text.0 = format_table(
+            &NextFruitType::biased_probabilities(spawnable_count, bias),
+            &tally,
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Renders the weight / seen table, one fruit per line.
+fn format_table(probabilities: &[(FruitType, f32)], tally: &SpawnTally) -> String {
+    let total = tally.total();
+    let mut table = format!("{:<12} {:>7} {:>6}", "Fruit", "Weight", "Seen");
+    for (&(fruit, probability), &count) in probabilities.iter().zip(&tally.counts) {
+        let share = if total > 0 {
+            format!("{:.1}%", count as f32 / total as f32 * 100.0)
+        } else {
+            "-".to_string()
+        };
+        table.push_str(&format!(
+            "\n{:<12} {:>6.1}% {count:>6} {share:>6}",
+            format!("{fruit:?}"),
+            probability * 100.0
+        ));
+    }
+    table.push_str(&format!("\n{:<12} {:>7} {total:>6}", "total", ""));
+    table
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tally_spawns_counts_by_type() {
+        let mut app = App::new();
+        app.add_message::<FruitDroppedEvent>()
+            .init_resource::<SpawnTally>()
+            .add_systems(Update, tally_spawns);
+
+        for fruit in [FruitType::Cherry, FruitType::Cherry, FruitType::Grape] {
+            app.world_mut().write_message(FruitDroppedEvent {
+                fruit_type: fruit,
+                position: Vec2::ZERO,
+            });
+        }
+        app.update();
+
+        let tally = app.world().resource::<SpawnTally>();
+        assert_eq!(tally.counts, [2, 0, 1, 0, 0]);
+        assert_eq!(tally.total(), 3);
+    }
+
+    #[test]
+    fn test_format_table() {
+        let tally = SpawnTally {
+            counts: vec![3, 1, 0, 0, 0],
+        };
+        let table = format_table(&NextFruitType::spawn_probabilities(2), &tally);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 1 + 5 + 1);
+        assert!(lines[1].starts_with("Cherry"));
+        assert!(lines[1].contains("50.0%"));
+        assert!(lines[1].contains("75.0%"));
+        assert!(lines[3].contains("0.0%"));
+        assert!(lines[6].starts_with("total"));
+        assert!(lines[6].ends_with('4'));
+    }
+
+    #[test]
+    fn test_format_table_without_drops() {
+        let table = format_table(
+            &NextFruitType::spawn_probabilities(5),
+            &SpawnTally::default(),
+        );
+        assert!(table.lines().nth(1).unwrap().ends_with('-'));
+    }
+}
@@ -0,0 +1,236 @@
+//! Free spectator camera.
+//!
+//! While the game is only being watched
+//! ([`ControlSource::is_spectating`] — a replay or a network game), `C`
+//! detaches the camera from the board:
+//!
+//! | Input                | Action            |
+//! |----------------------|-------------------|
+//! | `C`                  | toggle free camera |
+//! | Arrow keys / `WASD`  | pan               |
+//! | Mouse wheel          | zoom              |
+//! | `R`                  | recentre          |
+//!
+//! Attract-mode demos are excluded: any input there ends the demo.  The
+//! camera snaps back to the board as soon as the local player has control
+//! again.  While free, the camera ignores the merge shake.
+
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use suika_game_core::prelude::ControlSource;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Pan speed at zoom 1.0 (world pixels per second).
+const PAN_SPEED: f32 = 500.0;
+
+/// Zoom change per wheel line (fraction of the current zoom).
+const ZOOM_STEP: f32 = 0.1;
+
+/// Pixel-scrolling devices report this many pixels per wheel line.
+const PIXELS_PER_LINE: f32 = 40.0;
+
+/// Closest zoom (orthographic scale).
+const MIN_ZOOM: f32 = 0.4;
+
+/// Farthest zoom (orthographic scale).
+const MAX_ZOOM: f32 = 2.5;
+
+/// Farthest the camera may pan from the board centre (world pixels).
+const MAX_PAN: f32 = 800.0;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// State of the free spectator camera.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SpectatorCamera {
+    /// The camera is detached from the board
+    pub free: bool,
+    /// Camera position relative to the board centre (world pixels)
+    pub offset: Vec2,
+    /// Orthographic scale; above 1.0 shows more of the world
+    pub zoom: f32,
+}
+
+impl Default for SpectatorCamera {
+    fn default() -> Self {
+        Self {
+            free: false,
+            offset: Vec2::ZERO,
+            zoom: 1.0,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Toggles the free camera with `C` while spectating, and resets it once the
+/// local player has control again.
+///
+/// Registered with `run_if(not(attract_active))`.
+pub fn toggle_free_camera(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    source: Res<ControlSource>,
+    mut camera: ResMut<SpectatorCamera>,
+) {
+    if !source.is_spectating() {
+        if camera.free {
+            *camera = SpectatorCamera::default();
+        }
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        camera.free = !camera.free;
+        info!(
+            "Free spectator camera {}",
+            if camera.free { "on" } else { "off" }
+        );
+    }
+}
+
+/// Pans, zooms and recentres the free camera.
+pub fn drive_free_camera(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut wheel: MessageReader<MouseWheel>,
+    mut camera: ResMut<SpectatorCamera>,
+) {
+    let lines: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / PIXELS_PER_LINE,
+        })
+        .sum();
+    if !camera.free {
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyR) {
+        camera.offset = Vec2::ZERO;
+        camera.zoom = 1.0;
+        return;
+    }
+
+    let axis = |negative: [KeyCode; 2], positive: [KeyCode; 2]| {
+        let pressed = |keys: [KeyCode; 2]| f32::from(u8::from(keyboard.any_pressed(keys)));
+        pressed(positive) - pressed(negative)
+    };
+    let direction = Vec2::new(
+        axis(
+            [KeyCode::ArrowLeft, KeyCode::KeyA],
+            [KeyCode::ArrowRight, KeyCode::KeyD],
+        ),
+        axis(
+            [KeyCode::ArrowDown, KeyCode::KeyS],
+            [KeyCode::ArrowUp, KeyCode::KeyW],
+        ),
+    );
+    let step = PAN_SPEED * camera.zoom * time.delta_secs();
+    camera.offset = (camera.offset + direction.normalize_or_zero() * step)
+        .clamp(Vec2::splat(-MAX_PAN), Vec2::splat(MAX_PAN));
+    camera.zoom = zoom_after_scroll(camera.zoom, lines);
+}
+
+/// Writes the free camera to the game camera, after the merge shake.
+///
+/// When the free camera is switched off the camera returns to the board
+/// centre at zoom 1.0.
+pub fn apply_spectator_camera(
+    camera: Res<SpectatorCamera>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+    mut was_free: Local<bool>,
+) {
+    if !camera.free && !*was_free {
+        return;
+    }
+    *was_free = camera.free;
+
+    let Ok((mut transform, mut projection)) = camera_query.single_mut() else {
+        return;
+    };
+    let (offset, zoom) = if camera.free {
+        (camera.offset, camera.zoom)
+    } else {
+        (Vec2::ZERO, 1.0)
+    };
+    transform.translation.x = offset.x;
+    transform.translation.y = offset.y;
+    if let Projection::Orthographic(ortho) = projection.as_mut() {
+        ortho.scale = zoom;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Zoom after scrolling `lines` wheel lines; scrolling up zooms in.
+fn zoom_after_scroll(zoom: f32, lines: f32) -> f32 {
+    (zoom * (1.0 - ZOOM_STEP).powf(lines)).clamp(MIN_ZOOM, MAX_ZOOM)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_after_scroll() {
+        assert_eq!(zoom_after_scroll(1.0, 0.0), 1.0);
+        assert!(zoom_after_scroll(1.0, 1.0) < 1.0);
+        assert!(zoom_after_scroll(1.0, -1.0) > 1.0);
+        assert_eq!(zoom_after_scroll(1.0, 100.0), MIN_ZOOM);
+        assert_eq!(zoom_after_scroll(1.0, -100.0), MAX_ZOOM);
+    }
+
+    fn toggle_app(source: ControlSource) -> App {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<SpectatorCamera>()
+            .insert_resource(source)
+            .add_systems(Update, toggle_free_camera);
+        app
+    }
+
+    #[test]
+    fn test_free_camera_only_while_spectating() {
+        let mut app = toggle_app(ControlSource::Local);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyC);
+        app.update();
+        assert!(!app.world().resource::<SpectatorCamera>().free);
+
+        let mut app = toggle_app(ControlSource::Replay);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyC);
+        app.update();
+        assert!(app.world().resource::<SpectatorCamera>().free);
+    }
+
+    #[test]
+    fn test_local_control_resets_free_camera() {
+        let mut app = toggle_app(ControlSource::Local);
+        app.insert_resource(SpectatorCamera {
+            free: true,
+            offset: Vec2::new(50.0, 0.0),
+            zoom: 2.0,
+        });
+        app.update();
+        assert_eq!(
+            *app.world().resource::<SpectatorCamera>(),
+            SpectatorCamera::default()
+        );
+    }
+}
@@ -37,6 +37,9 @@ pub const BUTTON_HOVER: Color = Color::srgb(0.5, 0.8, 0.5);
 /// Button color while the mouse button is held down.
 pub const BUTTON_PRESSED: Color = Color::srgb(0.3, 0.5, 0.3);
 
+/// Outline drawn around the button that has keyboard / gamepad focus.
+pub const FOCUS_RING_COLOR: Color = SECONDARY_COLOR;
+
 // ---------------------------------------------------------------------------
 // Font sizes
 // ---------------------------------------------------------------------------
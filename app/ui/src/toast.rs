@@ -0,0 +1,466 @@
+//! Toast notifications.
+//!
+//! Short messages stacked at the bottom centre of the screen that fade out on
+//! their own.  Any system can show one by writing a [`ShowToast`] message;
+//! config and asset failures from core ([`ConfigErrorEvent`],
+//! [`AssetErrorEvent`]) are turned into error toasts here.  Save failures
+//! ([`SaveErrorEvent`]) open an error dialog instead, except during play
+//! where they fall back to a toast so the game is not interrupted.  Either
+//! way the player sees `save/settings.json line 4: invalid type ...` instead
+//! of settings silently resetting.
+//!
+//! In debug builds every successful config hot-reload
+//! ([`ConfigReloadedEvent`]) also shows an info toast, so someone editing a
+//! RON file sees the change was picked up without watching the terminal; a
+//! reload that fails to parse already arrives as an error toast.
+//!
+//! ```text
+//!            ┌──────────────────────────────────────┐
+//!            │ save/settings.json line 4: invalid … │  ← newest at the bottom
+//!            └──────────────────────────────────────┘
+//! ```
+//!
+//! At most [`MAX_TOASTS`] are shown; older ones are dropped first.
+
+use bevy::prelude::*;
+use suika_game_core::prelude::{
+    AppState, AssetErrorEvent, ConfigErrorEvent, ConfigReloadedEvent, SaveErrorEvent,
+};
+use suika_game_core::resources::settings::SettingsResource;
+
+use crate::components::{ButtonAction, Modal};
+use crate::i18n::t;
+use crate::styles::FONT_JP;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Maximum number of toasts visible at once.
+pub const MAX_TOASTS: usize = 4;
+
+/// Seconds an info toast stays on screen, including the fade.
+const INFO_DURATION_SECS: f32 = 3.0;
+
+/// Seconds a warning or error toast stays on screen, including the fade.
+const ERROR_DURATION_SECS: f32 = 8.0;
+
+/// Seconds of fade-out at the end of a toast's life.
+const FADE_SECS: f32 = 0.5;
+
+const TOAST_FONT_SIZE: f32 = 18.0;
+const INFO_COLOR: Color = Color::srgba(0.15, 0.15, 0.15, 0.85);
+const WARNING_COLOR: Color = Color::srgba(0.6, 0.45, 0.05, 0.9);
+const ERROR_COLOR: Color = Color::srgba(0.65, 0.12, 0.1, 0.9);
+
+// ---------------------------------------------------------------------------
+// Messages / components
+// ---------------------------------------------------------------------------
+
+/// Severity of a toast; decides its colour and how long it stays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ToastKind {
+    /// Neutral notice.
+    #[default]
+    Info,
+    /// Something the player may want to fix.
+    Warning,
+    /// Something failed.
+    Error,
+}
+
+impl ToastKind {
+    /// Background colour of the toast.
+    pub fn color(self) -> Color {
+        match self {
+            ToastKind::Info => INFO_COLOR,
+            ToastKind::Warning => WARNING_COLOR,
+            ToastKind::Error => ERROR_COLOR,
+        }
+    }
+
+    /// Seconds the toast stays on screen.
+    pub fn duration(self) -> f32 {
+        match self {
+            ToastKind::Info => INFO_DURATION_SECS,
+            ToastKind::Warning | ToastKind::Error => ERROR_DURATION_SECS,
+        }
+    }
+}
+
+/// Request to show a toast.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ShowToast {
+    /// Severity of the message.
+    pub kind: ToastKind,
+    /// Text shown to the player.
+    pub text: String,
+}
+
+impl ShowToast {
+    /// An info toast.
+    pub fn info(text: impl Into<String>) -> Self {
+        Self {
+            kind: ToastKind::Info,
+            text: text.into(),
+        }
+    }
+
+    /// A warning toast.
+    pub fn warning(text: impl Into<String>) -> Self {
+        Self {
+            kind: ToastKind::Warning,
+            text: text.into(),
+        }
+    }
+
+    /// An error toast.
+    pub fn error(text: impl Into<String>) -> Self {
+        Self {
+            kind: ToastKind::Error,
+            text: text.into(),
+        }
+    }
+}
+
+/// Marks the column that holds all toasts.
+#[derive(Component, Debug)]
+pub struct ToastStack;
+
+/// One visible toast.
+#[derive(Component, Debug)]
+pub struct Toast {
+    /// Seconds left before the toast is despawned.
+    pub remaining: f32,
+    /// Background colour at full opacity.
+    pub color: Color,
+}
+
+/// Marks a toast's text.
+#[derive(Component, Debug)]
+pub struct ToastText;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the empty toast column.
+pub fn spawn_toast_stack(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(24.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        GlobalZIndex(i32::MAX - 1),
+        ToastStack,
+    ));
+}
+
+/// Turns core config / asset failures into error toasts.
+pub fn toast_persistence_errors(
+    mut config_errors: MessageReader<ConfigErrorEvent>,
+    mut asset_errors: MessageReader<AssetErrorEvent>,
+    mut toasts: MessageWriter<ShowToast>,
+) {
+    for event in config_errors.read() {
+        toasts.write(ShowToast::error(event.message.clone()));
+    }
+    for event in asset_errors.read() {
+        toasts.write(ShowToast::error(event.message.clone()));
+    }
+}
+
+/// Turns successful config hot-reloads into info toasts.
+///
+/// Only registered in debug builds.
+pub fn toast_config_reloads(
+    mut reloads: MessageReader<ConfigReloadedEvent>,
+    mut toasts: MessageWriter<ShowToast>,
+) {
+    for event in reloads.read() {
+        toasts.write(ShowToast::info(format!("Reloaded {}", event.path)));
+    }
+}
+
+/// Shows the save failures of this frame in an error dialog, or as error
+/// toasts while [`AppState::Playing`].
+pub fn report_save_errors(
+    mut commands: Commands,
+    mut save_errors: MessageReader<SaveErrorEvent>,
+    mut toasts: MessageWriter<ShowToast>,
+    state: Res<State<AppState>>,
+    settings: Res<SettingsResource>,
+    asset_server: Res<AssetServer>,
+) {
+    let messages: Vec<&str> = save_errors.read().map(|e| e.message.as_str()).collect();
+    if messages.is_empty() {
+        return;
+    }
+
+    if *state.get() == AppState::Playing {
+        toasts.write_batch(messages.into_iter().map(ShowToast::error));
+        return;
+    }
+
+    let lang = settings.language;
+    Modal::new(t("save_error_title", lang))
+        .message(messages.join("\n"))
+        .button(t("btn_ok", lang), ButtonAction::CloseModal)
+        .spawn(&mut commands, asset_server.load(FONT_JP));
+}
+
+/// Adds a toast for every [`ShowToast`], dropping the oldest beyond [`MAX_TOASTS`].
+pub fn spawn_toasts(
+    mut commands: Commands,
+    mut requests: MessageReader<ShowToast>,
+    stack_query: Query<(Entity, Option<&Children>), With<ToastStack>>,
+) {
+    let Ok((stack, children)) = stack_query.single() else {
+        return;
+    };
+    let requests: Vec<&ShowToast> = requests.read().collect();
+    if requests.is_empty() {
+        return;
+    }
+
+    let existing: Vec<Entity> = children.map(|c| c.to_vec()).unwrap_or_default();
+    let overflow = (existing.len() + requests.len()).saturating_sub(MAX_TOASTS);
+    for &old in existing.iter().take(overflow) {
+        commands.entity(old).despawn();
+    }
+
+    let skip = requests.len().saturating_sub(MAX_TOASTS);
+    commands.entity(stack).with_children(|stack| {
+        for request in requests.into_iter().skip(skip) {
+            let color = request.kind.color();
+            stack
+                .spawn((
+                    Node {
+                        max_width: Val::Percent(80.0),
+                        padding: UiRect::axes(Val::Px(14.0), Val::Px(8.0)),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    Toast {
+                        remaining: request.kind.duration(),
+                        color,
+                    },
+                ))
+                .with_children(|toast| {
+                    toast.spawn((
+                        Text::new(request.text.clone()),
+                        TextFont {
+                            font_size: TOAST_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        ToastText,
+                    ));
+                });
+        }
+    });
+}
+
+/// Counts toasts down, fades them out and despawns expired ones.
+pub fn update_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toast_query: Query<(Entity, &mut Toast, &mut BackgroundColor, &Children)>,
+    mut text_query: Query<&mut TextColor, With<ToastText>>,
+) {
+    for (entity, mut toast, mut bg, children) in toast_query.iter_mut() {
+        toast.remaining -= time.delta_secs();
+        if toast.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let alpha = toast_alpha(toast.remaining);
+        bg.0 = toast.color.with_alpha(toast.color.alpha() * alpha);
+        for child in children.iter() {
+            if let Ok(mut text_color) = text_query.get_mut(child) {
+                text_color.0 = Color::WHITE.with_alpha(alpha);
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Opacity for a toast with `remaining` seconds left: 1 until the last
+/// [`FADE_SECS`], then linear down to 0.
+fn toast_alpha(remaining: f32) -> f32 {
+    (remaining / FADE_SECS).clamp(0.0, 1.0)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toast_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<ShowToast>()
+            .add_message::<SaveErrorEvent>()
+            .add_message::<ConfigErrorEvent>()
+            .add_message::<AssetErrorEvent>()
+            .add_systems(Startup, spawn_toast_stack)
+            .add_systems(
+                Update,
+                (toast_persistence_errors, spawn_toasts, update_toasts).chain(),
+            );
+        app.update();
+        app
+    }
+
+    /// Builds an app that reports save errors while in `state`.
+    fn save_error_app(state: AppState) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            bevy::asset::AssetPlugin::default(),
+            bevy::state::app::StatesPlugin,
+        ))
+        .init_asset::<Font>()
+        .insert_state(state)
+        .init_resource::<SettingsResource>()
+        .add_message::<ShowToast>()
+        .add_message::<SaveErrorEvent>()
+        .add_systems(Update, report_save_errors);
+        app.world_mut().write_message(SaveErrorEvent {
+            message: "save/settings.json line 4: invalid type".to_string(),
+        });
+        app.update();
+        app
+    }
+
+    fn toast_texts(app: &mut App) -> Vec<String> {
+        app.world_mut()
+            .query_filtered::<&Text, With<ToastText>>()
+            .iter(app.world())
+            .map(|t| t.0.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_toast_alpha_fades_at_the_end() {
+        assert_eq!(toast_alpha(5.0), 1.0);
+        assert_eq!(toast_alpha(FADE_SECS), 1.0);
+        assert_eq!(toast_alpha(FADE_SECS / 2.0), 0.5);
+        assert_eq!(toast_alpha(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_config_error_becomes_error_toast() {
+        let mut app = toast_app();
+        app.world_mut().write_message(ConfigErrorEvent {
+            message: "config/game_rules.ron: 4:1: Expected ...".to_string(),
+        });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            toast_texts(&mut app),
+            vec!["config/game_rules.ron: 4:1: Expected ...".to_string()]
+        );
+        let toast = app
+            .world_mut()
+            .query::<&Toast>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(toast.color, ERROR_COLOR);
+    }
+
+    #[test]
+    fn test_config_reload_becomes_info_toast() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<ShowToast>()
+            .add_message::<ConfigReloadedEvent>()
+            .add_systems(Startup, spawn_toast_stack)
+            .add_systems(Update, (toast_config_reloads, spawn_toasts).chain());
+        app.update();
+        app.world_mut().write_message(ConfigReloadedEvent {
+            path: "config/effects/dust.ron".to_string(),
+        });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            toast_texts(&mut app),
+            vec!["Reloaded config/effects/dust.ron".to_string()]
+        );
+        let toast = app
+            .world_mut()
+            .query::<&Toast>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(toast.color, INFO_COLOR);
+    }
+
+    #[test]
+    fn test_save_error_opens_dialog_outside_play() {
+        let mut app = save_error_app(AppState::Title);
+        let dialogs = app
+            .world_mut()
+            .query::<&crate::components::ModalDialog>()
+            .iter(app.world())
+            .count();
+        assert_eq!(dialogs, 1);
+        assert!(app.world().resource::<Messages<ShowToast>>().is_empty());
+    }
+
+    #[test]
+    fn test_save_error_during_play_becomes_toast() {
+        let mut app = save_error_app(AppState::Playing);
+        let dialogs = app
+            .world_mut()
+            .query::<&crate::components::ModalDialog>()
+            .iter(app.world())
+            .count();
+        assert_eq!(dialogs, 0);
+        assert_eq!(app.world().resource::<Messages<ShowToast>>().len(), 1);
+    }
+
+    #[test]
+    fn test_asset_error_becomes_error_toast() {
+        let mut app = toast_app();
+        app.world_mut().write_message(AssetErrorEvent {
+            message: "sounds/sfx/drop.wav: failed to load (SFX)".to_string(),
+        });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            toast_texts(&mut app),
+            vec!["sounds/sfx/drop.wav: failed to load (SFX)".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_oldest_toasts_are_dropped() {
+        let mut app = toast_app();
+        for i in 0..MAX_TOASTS + 2 {
+            app.world_mut()
+                .write_message(ShowToast::info(format!("toast {i}")));
+            app.update();
+        }
+        app.update();
+
+        let texts = toast_texts(&mut app);
+        assert_eq!(texts.len(), MAX_TOASTS);
+        assert!(!texts.contains(&"toast 0".to_string()));
+        assert!(texts.contains(&format!("toast {}", MAX_TOASTS + 1)));
+    }
+}
@@ -0,0 +1,156 @@
+//! Screen transition cover.
+//!
+//! Draws the [`ScreenTransition`] from core as one full-screen node:
+//!
+//! - [`TransitionStyle::Fade`] — the node's opacity follows
+//!   [`ScreenTransition::coverage`].
+//! - [`TransitionStyle::Wipe`] — an opaque band grows in from the left edge
+//!   and, once the state has switched, leaves through the right edge.
+//!
+//! The cover is hidden while no transition runs and blocks mouse input while
+//! shown, so buttons on the outgoing screen cannot be clicked mid-transition.
+//! It sits below dialogs ([`ModalDialog`](crate::components::ModalDialog))
+//! so an error reported during a transition stays readable.
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use suika_game_core::prelude::{ScreenTransition, TransitionStyle};
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Marks the transition cover node.
+#[derive(Component, Debug)]
+pub struct TransitionCover;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the (hidden) transition cover.
+pub fn spawn_transition_cover(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        FocusPolicy::Block,
+        GlobalZIndex(i32::MAX - 4),
+        Visibility::Hidden,
+        TransitionCover,
+    ));
+}
+
+/// Shapes the cover from the running [`ScreenTransition`].
+pub fn update_transition_cover(
+    transition: Res<ScreenTransition>,
+    mut cover_query: Query<
+        (&mut Node, &mut BackgroundColor, &mut Visibility),
+        With<TransitionCover>,
+    >,
+) {
+    let Ok((mut node, mut bg, mut visibility)) = cover_query.single_mut() else {
+        return;
+    };
+
+    if transition.is_idle() {
+        if *visibility != Visibility::Hidden {
+            *visibility = Visibility::Hidden;
+        }
+        return;
+    }
+
+    let (left, width, alpha) = cover_shape(
+        transition.style,
+        transition.coverage(),
+        transition.is_revealing(),
+    );
+    *visibility = Visibility::Inherited;
+    node.left = Val::Percent(left);
+    node.width = Val::Percent(width);
+    bg.0 = transition
+        .color
+        .with_alpha(transition.color.alpha() * alpha);
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Left edge (%), width (%) and opacity of the cover for `coverage`.
+///
+/// A wipe grows from the left while covering; while `revealing` it keeps
+/// moving right, so the band sweeps across the screen in one direction.
+fn cover_shape(style: TransitionStyle, coverage: f32, revealing: bool) -> (f32, f32, f32) {
+    let coverage = coverage.clamp(0.0, 1.0);
+    match style {
+        TransitionStyle::Fade => (0.0, 100.0, coverage),
+        TransitionStyle::Wipe if revealing => ((1.0 - coverage) * 100.0, coverage * 100.0, 1.0),
+        TransitionStyle::Wipe => (0.0, coverage * 100.0, 1.0),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use suika_game_core::prelude::AppState;
+
+    #[test]
+    fn test_fade_covers_whole_screen() {
+        assert_eq!(
+            cover_shape(TransitionStyle::Fade, 0.25, false),
+            (0.0, 100.0, 0.25)
+        );
+        assert_eq!(
+            cover_shape(TransitionStyle::Fade, 0.75, true),
+            (0.0, 100.0, 0.75)
+        );
+    }
+
+    #[test]
+    fn test_wipe_sweeps_left_to_right() {
+        assert_eq!(
+            cover_shape(TransitionStyle::Wipe, 0.5, false),
+            (0.0, 50.0, 1.0)
+        );
+        assert_eq!(
+            cover_shape(TransitionStyle::Wipe, 1.0, true),
+            (0.0, 100.0, 1.0)
+        );
+        assert_eq!(
+            cover_shape(TransitionStyle::Wipe, 0.25, true),
+            (75.0, 25.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_cover_hidden_while_idle() {
+        let mut app = App::new();
+        app.init_resource::<ScreenTransition>()
+            .add_systems(Startup, spawn_transition_cover)
+            .add_systems(Update, update_transition_cover);
+        app.update();
+
+        let visibility = |app: &mut App| {
+            *app.world_mut()
+                .query_filtered::<&Visibility, With<TransitionCover>>()
+                .single(app.world())
+                .unwrap()
+        };
+        assert_eq!(visibility(&mut app), Visibility::Hidden);
+
+        app.world_mut()
+            .resource_mut::<ScreenTransition>()
+            .start(AppState::Settings);
+        app.update();
+        assert_eq!(visibility(&mut app), Visibility::Inherited);
+    }
+}
@@ -0,0 +1,179 @@
+//! Update-available notification.
+//!
+//! With the `update-check` feature, the game asks a release JSON endpoint
+//! for the latest version once at startup.  The request runs on the
+//! [`IoTaskPool`] so a slow or offline network never stalls a frame; when
+//! the reported version is newer than [`BuildInfo::version`] an info toast
+//! names it.  Nothing is downloaded or installed.
+//!
+//! The endpoint must return an object with a `tag_name` field, the shape of
+//! GitHub's `releases/latest` API:
+//!
+//! ```json
+//! { "tag_name": "v0.2.0", ... }
+//! ```
+//!
+//! Failures (no network, rate limit, malformed JSON) are logged and
+//! otherwise ignored.  The check does not start in streamer-safe mode.
+
+use bevy::prelude::*;
+use bevy::tasks::{IoTaskPool, Task, block_on, poll_once};
+use serde::Deserialize;
+use suika_game_core::prelude::{BuildInfo, SettingsResource};
+
+use crate::i18n::t;
+use crate::toast::ShowToast;
+
+/// Endpoint queried when [`UpdateCheck::url`] is not overridden.
+pub const DEFAULT_RELEASE_URL: &str =
+    "https://api.github.com/repos/itsakeyfut/suika-game/releases/latest";
+
+/// Seconds before the request is abandoned.
+const REQUEST_TIMEOUT_SECS: u64 = 5;
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// Where to look for the latest release.
+///
+/// Insert before adding [`GameUIPlugin`](crate::GameUIPlugin) to point the
+/// check at another endpoint.
+#[derive(Resource, Debug, Clone)]
+pub struct UpdateCheck {
+    /// Release JSON endpoint.
+    pub url: String,
+}
+
+impl Default for UpdateCheck {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_RELEASE_URL.to_string(),
+        }
+    }
+}
+
+/// The in-flight request; removed once it has finished.
+#[derive(Resource)]
+pub struct UpdateCheckTask(Task<Result<String, String>>);
+
+/// The part of the release JSON the check reads.
+#[derive(Deserialize, Debug)]
+struct ReleaseInfo {
+    tag_name: String,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Starts the background request for the latest release.
+pub fn start_update_check(mut commands: Commands, check: Res<UpdateCheck>) {
+    let url = check.url.clone();
+    let task = IoTaskPool::get().spawn(async move { fetch_latest_version(&url) });
+    commands.insert_resource(UpdateCheckTask(task));
+}
+
+/// Waits for the request and shows a toast when a newer version exists.
+pub fn poll_update_check(
+    mut commands: Commands,
+    task: Option<ResMut<UpdateCheckTask>>,
+    build_info: Res<BuildInfo>,
+    settings: Res<SettingsResource>,
+    mut toasts: MessageWriter<ShowToast>,
+) {
+    let Some(mut task) = task else {
+        return;
+    };
+    let Some(result) = block_on(poll_once(&mut task.0)) else {
+        return;
+    };
+    commands.remove_resource::<UpdateCheckTask>();
+
+    match result {
+        Ok(latest) if is_newer(&latest, build_info.version) => {
+            info!(
+                "Update available: {latest} (running {})",
+                build_info.version
+            );
+            toasts.write(ShowToast::info(format!(
+                "{} {latest}",
+                t("update_available", settings.language)
+            )));
+        }
+        Ok(latest) => debug!("Up to date (latest release {latest})"),
+        Err(e) => info!("Update check skipped: {e}"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Fetches the latest release tag from `url`.  Blocking; run it on a task pool.
+fn fetch_latest_version(url: &str) -> Result<String, String> {
+    let release: ReleaseInfo = ureq::get(url)
+        .set("User-Agent", "suika-game")
+        .timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .call()
+        .map_err(|e| e.to_string())?
+        .into_json()
+        .map_err(|e| e.to_string())?;
+    Ok(release.tag_name)
+}
+
+/// Parses `v1.2.3` / `1.2.3` (pre-release suffix ignored) into its parts.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let core = version.trim().trim_start_matches('v');
+    let core = core.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|p| p.parse::<u32>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    Some((major, minor, patch))
+}
+
+/// Whether release `latest` is newer than `current`.
+///
+/// Unparsable versions never count as newer, so a strange tag cannot
+/// trigger a bogus notification.
+fn is_newer(latest: &str, current: &str) -> bool {
+    match (parse_version(latest), parse_version(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("v1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_version("0.10.0"), Some((0, 10, 0)));
+        assert_eq!(parse_version("v2.0"), Some((2, 0, 0)));
+        assert_eq!(parse_version("v1.2.3-beta.1"), Some((1, 2, 3)));
+        assert_eq!(parse_version("nightly"), None);
+    }
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("v0.2.0", "0.1.0"));
+        assert!(is_newer("v0.1.10", "0.1.9"));
+        assert!(!is_newer("v0.1.0", "0.1.0"));
+        assert!(!is_newer("v0.0.9", "0.1.0"));
+        assert!(!is_newer("latest", "0.1.0"));
+    }
+
+    #[test]
+    fn test_release_info_reads_tag_name() {
+        let json = r#"{ "tag_name": "v0.3.1", "name": "Suika 0.3.1", "draft": false }"#;
+        let release: ReleaseInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(release.tag_name, "v0.3.1");
+    }
+}
@@ -0,0 +1,589 @@
+//! # Suika Game scenario tests
+//!
+//! End-to-end tests that run the full [`GameCorePlugin`] (with real Rapier
+//! physics and the shipped config files) headlessly and drive it with a small
+//! scenario language:
+//!
+//! ```no_run
+//! use suika_game_tests::Scenario;
+//!
+//! Scenario::parse(
+//!     "drop cherry at x=0; step 2s; drop cherry at x=0; step 2s; expect 1 strawberry; expect score 10",
+//! )
+//! .unwrap()
+//! .run();
+//! ```
+//!
+//! ## Statements
+//!
+//! Statements are separated by `;` or newlines; `#` starts a comment.
+//!
+//! | Statement                        | Effect |
+//! |----------------------------------|--------|
+//! | `drop <fruit> at x=<X>`          | Drops a fruit from the spawn height at `X` |
+//! | `place <fruit> at x=<X> y=<Y>`   | Puts a landed fruit at `(X, Y)` |
+//! | `step <S>s` / `step <N> frames`  | Advances the game (60 frames per second) |
+//! | `expect <N> <fruit>`             | Exactly `N` fruits of that type are on the board |
+//! | `expect score <N>`               | Current score is `N` |
+//! | `expect combo <N>`               | Current combo count is `N` |
+//! | `expect state <state>`           | App state is `playing`, `paused`, `game_over`, … |
+//!
+//! Fruit names are case-insensitive and may be plural (`2 cherries`).
+//! Physics and rule tweaks that have no statement form are set with
+//! [`Scenario::with_physics`] / [`Scenario::with_rules`].
+//!
+//! Every scenario runs with a fixed [`SpawnRng`] seed, fixed 60 FPS time
+//! steps and persistence disabled, so runs are reproducible and never touch
+//! the save directory.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use bevy::asset::AssetPlugin;
+use bevy::input::InputPlugin;
+use bevy::mesh::MeshPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy_rapier2d::prelude::*;
+use suika_game_core::prelude::*;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Simulation rate used by every scenario.
+pub const FRAMES_PER_SECOND: u32 = 60;
+
+/// Seed used for the spawn sequence unless [`Scenario::with_seed`] is called.
+pub const DEFAULT_SEED: u64 = 0x5EED;
+
+/// Updates to wait for the config files before giving up.
+const MAX_LOADING_FRAMES: u32 = 600;
+
+/// Directory holding the shipped config RON files.
+fn shipped_config_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../app/suika-game/assets/config")
+}
+
+// ---------------------------------------------------------------------------
+// Scenario
+// ---------------------------------------------------------------------------
+
+/// One statement of a scenario.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    /// Drop `fruit` from the spawn height at `x`.
+    Drop { fruit: FruitType, x: f32 },
+    /// Spawn a landed `fruit` at `position`.
+    Place { fruit: FruitType, position: Vec2 },
+    /// Advance the game by this many frames.
+    Frames(u32),
+    /// Check the game state.
+    Expect(Expectation),
+}
+
+/// A condition checked by an `expect` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expectation {
+    /// Number of non-held fruits of a type.
+    Count(FruitType, usize),
+    /// Current score.
+    Score(u32),
+    /// Current combo count.
+    Combo(u32),
+    /// Current app state.
+    State(AppState),
+}
+
+type PhysicsOverride = Box<dyn Fn(&mut PhysicsConfig)>;
+type RulesOverride = Box<dyn Fn(&mut GameRulesConfig)>;
+
+/// A list of [`Step`]s plus the configuration they run with.
+///
+/// Build one with [`Scenario::parse`] or the builder methods, then call
+/// [`Scenario::run`], which panics on the first failed expectation.
+#[derive(Default)]
+pub struct Scenario {
+    steps: Vec<Step>,
+    seed: Option<u64>,
+    physics: Vec<PhysicsOverride>,
+    rules: Vec<RulesOverride>,
+}
+
+impl Scenario {
+    /// An empty scenario.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a scenario script (see the [crate docs](crate)).
+    pub fn parse(script: &str) -> Result<Self, ParseError> {
+        let mut scenario = Self::new();
+        let statements = script
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or(""))
+            .flat_map(|line| line.split(';'))
+            .map(str::trim)
+            .filter(|s| !s.is_empty());
+        for statement in statements {
+            scenario.steps.push(parse_statement(statement)?);
+        }
+        Ok(scenario)
+    }
+
+    /// Appends `step`.
+    pub fn then(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Appends a `drop` statement.
+    pub fn drop_fruit(self, fruit: FruitType, x: f32) -> Self {
+        self.then(Step::Drop { fruit, x })
+    }
+
+    /// Appends a `place` statement.
+    pub fn place(self, fruit: FruitType, x: f32, y: f32) -> Self {
+        self.then(Step::Place {
+            fruit,
+            position: Vec2::new(x, y),
+        })
+    }
+
+    /// Appends a `step <secs>s` statement.
+    pub fn step_secs(self, secs: f32) -> Self {
+        self.then(Step::Frames(secs_to_frames(secs)))
+    }
+
+    /// Appends an `expect` statement.
+    pub fn expect(self, expectation: Expectation) -> Self {
+        self.then(Step::Expect(expectation))
+    }
+
+    /// Uses `seed` for the spawn sequence instead of [`DEFAULT_SEED`].
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Edits `physics.ron` before the game starts.
+    pub fn with_physics(mut self, edit: impl Fn(&mut PhysicsConfig) + 'static) -> Self {
+        self.physics.push(Box::new(edit));
+        self
+    }
+
+    /// Edits `game_rules.ron` before the game starts.
+    pub fn with_rules(mut self, edit: impl Fn(&mut GameRulesConfig) + 'static) -> Self {
+        self.rules.push(Box::new(edit));
+        self
+    }
+
+    /// Runs every step, panicking on the first failed expectation.
+    ///
+    /// Returns the harness so callers can make further assertions.
+    pub fn run(self) -> ScenarioApp {
+        let mut game = ScenarioApp::new(self.seed.unwrap_or(DEFAULT_SEED));
+        if !self.physics.is_empty() {
+            game.edit_config::<PhysicsConfig, PhysicsConfigHandle>(|c| {
+                self.physics.iter().for_each(|edit| edit(c))
+            });
+        }
+        if !self.rules.is_empty() {
+            game.edit_config::<GameRulesConfig, GameRulesConfigHandle>(|c| {
+                self.rules.iter().for_each(|edit| edit(c))
+            });
+        }
+        game.start();
+
+        for (index, step) in self.steps.iter().enumerate() {
+            if let Err(failure) = game.apply(step) {
+                panic!("step {} ({step:?}) failed: {failure}", index + 1);
+            }
+        }
+        game
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+/// A statement that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The offending statement.
+    pub statement: String,
+    /// What was wrong with it.
+    pub reason: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}': {}", self.statement, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_statement(statement: &str) -> Result<Step, ParseError> {
+    let error = |reason: &str| ParseError {
+        statement: statement.to_string(),
+        reason: reason.to_string(),
+    };
+    let words: Vec<&str> = statement.split_whitespace().collect();
+
+    match words.as_slice() {
+        ["drop", fruit, "at", x] => Ok(Step::Drop {
+            fruit: parse_fruit(fruit).ok_or_else(|| error("unknown fruit"))?,
+            x: parse_coord("x", x).ok_or_else(|| error("expected x=<number>"))?,
+        }),
+        ["place", fruit, "at", x, y] => Ok(Step::Place {
+            fruit: parse_fruit(fruit).ok_or_else(|| error("unknown fruit"))?,
+            position: Vec2::new(
+                parse_coord("x", x).ok_or_else(|| error("expected x=<number>"))?,
+                parse_coord("y", y).ok_or_else(|| error("expected y=<number>"))?,
+            ),
+        }),
+        ["step", n, "frames" | "frame"] => n
+            .parse()
+            .map(Step::Frames)
+            .map_err(|_| error("expected a frame count")),
+        ["step", secs] => secs
+            .strip_suffix('s')
+            .and_then(|s| s.parse::<f32>().ok())
+            .map(|s| Step::Frames(secs_to_frames(s)))
+            .ok_or_else(|| error("expected a duration like 2s or 0.5s")),
+        ["expect", "score", n] => n
+            .parse()
+            .map(|n| Step::Expect(Expectation::Score(n)))
+            .map_err(|_| error("expected a score")),
+        ["expect", "combo", n] => n
+            .parse()
+            .map(|n| Step::Expect(Expectation::Combo(n)))
+            .map_err(|_| error("expected a combo count")),
+        ["expect", "state", state] => parse_state(state)
+            .map(|s| Step::Expect(Expectation::State(s)))
+            .ok_or_else(|| error("unknown state")),
+        ["expect", n, fruit] => Ok(Step::Expect(Expectation::Count(
+            parse_fruit(fruit).ok_or_else(|| error("unknown fruit"))?,
+            n.parse().map_err(|_| error("expected a count"))?,
+        ))),
+        _ => Err(error("unknown statement")),
+    }
+}
+
+/// All fruit types, smallest first.
+fn all_fruits() -> impl Iterator<Item = FruitType> {
+    std::iter::successors(Some(FruitType::Cherry), FruitType::next)
+}
+
+/// Matches `cherry`, `Cherry`, `cherries`, `peaches`, …
+fn parse_fruit(name: &str) -> Option<FruitType> {
+    let name = name.to_ascii_lowercase();
+    all_fruits().find(|fruit| {
+        let singular = format!("{fruit:?}").to_ascii_lowercase();
+        let plural = if let Some(stem) = singular.strip_suffix('y') {
+            format!("{stem}ies")
+        } else if singular.ends_with("ch") {
+            format!("{singular}es")
+        } else {
+            format!("{singular}s")
+        };
+        name == singular || name == plural
+    })
+}
+
+/// Parses `x=12.5` for `axis = "x"`.
+fn parse_coord(axis: &str, word: &str) -> Option<f32> {
+    word.strip_prefix(axis)?.strip_prefix('=')?.parse().ok()
+}
+
+/// Matches `game_over`, `GameOver`, `gameover`, …
+fn parse_state(name: &str) -> Option<AppState> {
+    let name = name.replace('_', "").to_ascii_lowercase();
+    [
+        AppState::Loading,
+        AppState::Title,
+        AppState::Playing,
+        AppState::Paused,
+        AppState::GameOver,
+    ]
+    .into_iter()
+    .find(|state| format!("{state:?}").to_ascii_lowercase() == name)
+}
+
+fn secs_to_frames(secs: f32) -> u32 {
+    (secs * FRAMES_PER_SECOND as f32).round().max(0.0) as u32
+}
+
+// ---------------------------------------------------------------------------
+// Harness
+// ---------------------------------------------------------------------------
+
+/// A headless game app driven one frame at a time.
+pub struct ScenarioApp {
+    app: App,
+}
+
+impl ScenarioApp {
+    /// Builds the app and waits until the shipped configs have loaded.
+    ///
+    /// The game is left on the title screen; call [`start`](Self::start) to
+    /// begin playing.
+    pub fn new(seed: u64) -> Self {
+        let launch = LaunchOptions {
+            config_dir: Some(shipped_config_dir()),
+            seed: Some(seed),
+            ..default()
+        };
+
+        let mut app = App::new();
+        launch.register_asset_sources(&mut app);
+        app.insert_resource(launch)
+            .add_plugins(MinimalPlugins)
+            .add_plugins((
+                TransformPlugin,
+                InputPlugin,
+                StatesPlugin,
+                AssetPlugin {
+                    watch_for_changes_override: Some(false),
+                    ..default()
+                },
+                MeshPlugin,
+            ))
+            .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0))
+            .add_plugins(GameConfigPlugin)
+            .add_plugins(
+                GameCorePlugin::default()
+                    .headless(true)
+                    .with_persistence(false),
+            )
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                1.0 / FRAMES_PER_SECOND as f64,
+            )));
+
+        let mut game = Self { app };
+        for _ in 0..MAX_LOADING_FRAMES {
+            game.app.update();
+            if game.state() != AppState::Loading {
+                break;
+            }
+            // Config files load on the IO task pool
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(game.state(), AppState::Title, "configs did not load");
+        assert!(
+            game.app
+                .world()
+                .resource::<ConfigLoadStatus>()
+                .fallback
+                .is_empty(),
+            "shipped configs failed to load"
+        );
+        game
+    }
+
+    /// Leaves the title screen and runs one frame of play.
+    pub fn start(&mut self) {
+        self.app
+            .world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Playing);
+        self.step_frames(1);
+    }
+
+    /// Applies a config edit in place (hot-reload systems see it as modified).
+    pub fn edit_config<C: Asset, H: Resource + ConfigHandle<C>>(
+        &mut self,
+        edit: impl FnOnce(&mut C),
+    ) {
+        let handle = self.app.world().resource::<H>().handle();
+        let mut assets = self.app.world_mut().resource_mut::<Assets<C>>();
+        edit(assets.get_mut(&handle).expect("config is loaded"));
+    }
+
+    /// Runs one scenario step.
+    pub fn apply(&mut self, step: &Step) -> Result<(), String> {
+        match step {
+            Step::Drop { fruit, x } => self.drop_fruit(*fruit, *x),
+            Step::Place { fruit, position } => self.place(*fruit, *position),
+            Step::Frames(n) => self.step_frames(*n),
+            Step::Expect(expectation) => return self.check(expectation),
+        }
+        Ok(())
+    }
+
+    /// Replaces the held fruit with a falling `fruit` at `x`.
+    pub fn drop_fruit(&mut self, fruit: FruitType, x: f32) {
+        self.app
+            .world_mut()
+            .run_system_cached_with(drop_fruit_system, (fruit, x))
+            .expect("drop system runs");
+        self.step_frames(1);
+    }
+
+    /// Spawns a landed `fruit` at `position`.
+    pub fn place(&mut self, fruit: FruitType, position: Vec2) {
+        self.app
+            .world_mut()
+            .run_system_cached_with(place_fruit_system, (fruit, position))
+            .expect("place system runs");
+        self.step_frames(1);
+    }
+
+    /// Advances the game by `frames` updates.
+    pub fn step_frames(&mut self, frames: u32) {
+        for _ in 0..frames {
+            self.app.update();
+        }
+    }
+
+    /// Number of fruits of `fruit` type that are not held.
+    pub fn count(&mut self, fruit: FruitType) -> usize {
+        self.app
+            .world_mut()
+            .query_filtered::<(&FruitType, &FruitSpawnState), With<Fruit>>()
+            .iter(self.app.world())
+            .filter(|(t, s)| **t == fruit && **s != FruitSpawnState::Held)
+            .count()
+    }
+
+    /// Current score.
+    pub fn score(&self) -> u32 {
+        self.app.world().resource::<GameState>().score
+    }
+
+    /// Current combo count.
+    pub fn combo(&self) -> u32 {
+        self.app.world().resource::<ComboTimer>().current_combo
+    }
+
+    /// Current app state.
+    pub fn state(&self) -> AppState {
+        *self.app.world().resource::<State<AppState>>().get()
+    }
+
+    /// The underlying app, for checks the scenario language does not cover.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    fn check(&mut self, expectation: &Expectation) -> Result<(), String> {
+        let (expected, actual) = match expectation {
+            Expectation::Count(fruit, n) => (n.to_string(), self.count(*fruit).to_string()),
+            Expectation::Score(n) => (n.to_string(), self.score().to_string()),
+            Expectation::Combo(n) => (n.to_string(), self.combo().to_string()),
+            Expectation::State(s) => (format!("{s:?}"), format!("{:?}", self.state())),
+        };
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(format!("expected {expected}, got {actual}"))
+        }
+    }
+}
+
+/// Config handle resources, so [`ScenarioApp::edit_config`] can be generic.
+pub trait ConfigHandle<C: Asset> {
+    /// The wrapped asset handle.
+    fn handle(&self) -> Handle<C>;
+}
+
+impl ConfigHandle<PhysicsConfig> for PhysicsConfigHandle {
+    fn handle(&self) -> Handle<PhysicsConfig> {
+        self.0.clone()
+    }
+}
+
+impl ConfigHandle<GameRulesConfig> for GameRulesConfigHandle {
+    fn handle(&self) -> Handle<GameRulesConfig> {
+        self.0.clone()
+    }
+}
+
+fn drop_fruit_system(
+    In((fruit, x)): In<(FruitType, f32)>,
+    mut commands: Commands,
+    mut board: BoardCommands,
+    held: Query<(Entity, &FruitSpawnState), With<Fruit>>,
+    physics: PhysicsParams,
+    mut dropped_events: MessageWriter<FruitDroppedEvent>,
+) {
+    // The held fruit would block the spawn; a new one appears once this lands
+    for (entity, state) in held.iter() {
+        if *state == FruitSpawnState::Held {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let config = physics.get().expect("physics config is loaded");
+    let position = Vec2::new(
+        x,
+        config.container_height / 2.0 - config.fruit_spawn_y_offset,
+    );
+    board
+        .spawn_fruit(fruit, position, FruitSpawnState::Falling)
+        .expect("fruits config is loaded");
+    dropped_events.write(FruitDroppedEvent {
+        fruit_type: fruit,
+        position,
+    });
+}
+
+fn place_fruit_system(In((fruit, position)): In<(FruitType, Vec2)>, mut board: BoardCommands) {
+    board
+        .spawn_fruit(fruit, position, FruitSpawnState::Landed)
+        .expect("fruits config is loaded");
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_example_script() {
+        let scenario =
+            Scenario::parse("drop cherry at x=0; step 2s; expect 1 strawberry; expect score 10")
+                .unwrap();
+        assert_eq!(
+            scenario.steps,
+            vec![
+                Step::Drop {
+                    fruit: FruitType::Cherry,
+                    x: 0.0
+                },
+                Step::Frames(120),
+                Step::Expect(Expectation::Count(FruitType::Strawberry, 1)),
+                Step::Expect(Expectation::Score(10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_comments_newlines_and_plurals() {
+        let scenario = Scenario::parse(
+            "# two cherries\nplace Cherry at x=-20 y=-380\nstep 3 frames\nexpect 2 cherries; expect state game_over",
+        )
+        .unwrap();
+        assert_eq!(scenario.steps.len(), 4);
+        assert_eq!(
+            scenario.steps[3],
+            Step::Expect(Expectation::State(AppState::GameOver))
+        );
+        assert_eq!(parse_fruit("peaches"), Some(FruitType::Peach));
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_statement() {
+        let err = Scenario::parse("drop banana at x=0").err().unwrap();
+        assert_eq!(err.statement, "drop banana at x=0");
+        assert!(Scenario::parse("step fast").is_err());
+        assert!(Scenario::parse("jump").is_err());
+    }
+}
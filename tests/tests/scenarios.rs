@@ -0,0 +1,167 @@
+//! End-to-end gameplay scenarios
+//!
+//! Each test runs the full core plugin headlessly with real physics; see the
+//! `suika_game_tests` crate docs for the statement syntax.
+
+use suika_game_core::prelude::*;
+use suika_game_tests::{Expectation, Scenario};
+
+fn run(script: &str) {
+    Scenario::parse(script).unwrap().run();
+}
+
+// ---------------------------------------------------------------------------
+// Merge chains
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_two_cherries_merge_into_strawberry() {
+    run("
+        drop cherry at x=0
+        step 2s
+        drop cherry at x=0
+        step 2s
+        expect 0 cherries
+        expect 1 strawberry
+        expect score 10
+    ");
+}
+
+#[test]
+fn test_different_fruits_do_not_merge() {
+    run("
+        drop cherry at x=0
+        step 2s
+        drop grape at x=0
+        step 2s
+        expect 1 cherry
+        expect 1 grape
+        expect score 0
+    ");
+}
+
+#[test]
+fn test_strawberries_chain_into_grape() {
+    // The second strawberry is created on top of the first and merges again.
+    // The merges land about 4 s apart, inside the 5 s combo window, and the
+    // last one is set off by a merge (chain depth 1):
+    // 10 + 10 × 1.1 + 20 × 1.2 × 1.5
+    run("
+        drop cherry at x=0;  step 2s
+        drop cherry at x=0;  step 2s
+        drop cherry at x=0;  step 2s
+        drop cherry at x=0;  step 3s
+        expect 0 cherries
+        expect 0 strawberries
+        expect 1 grape
+        expect score 57
+    ");
+}
+
+// ---------------------------------------------------------------------------
+// Combo timing
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_merges_within_combo_window_get_bonus() {
+    // Two pairs merging 1 s apart: 10 + 10 × 1.1
+    run("
+        place cherry at x=-100 y=-380
+        place cherry at x=-65 y=-380
+        step 1s
+        place cherry at x=100 y=-380
+        place cherry at x=135 y=-380
+        step 1s
+        expect 2 strawberries
+        expect combo 2
+        expect score 21
+    ");
+}
+
+#[test]
+fn test_combo_resets_after_window() {
+    Scenario::new()
+        .with_rules(|rules| rules.combo_window = 1.0)
+        .place(FruitType::Cherry, -100.0, -380.0)
+        .place(FruitType::Cherry, -65.0, -380.0)
+        .step_secs(2.0)
+        .place(FruitType::Cherry, 100.0, -380.0)
+        .place(FruitType::Cherry, 135.0, -380.0)
+        .step_secs(1.0)
+        .expect(Expectation::Combo(1))
+        .expect(Expectation::Score(20))
+        .run();
+}
+
+// ---------------------------------------------------------------------------
+// Chain bonus
+// ---------------------------------------------------------------------------
+
+/// Four cherries that end in a strawberry → grape merge set off by the
+/// second strawberry's birth.
+const GRAPE_CHAIN: &str = "
+    drop cherry at x=0;  step 2s
+    drop cherry at x=0;  step 2s
+    drop cherry at x=0;  step 2s
+    drop cherry at x=0;  step 3s
+    expect 1 grape
+";
+
+#[test]
+fn test_chain_merge_gets_chain_bonus() {
+    // Combos off, so only the chain bonus applies: 10 + 10 + 20 × 1.5
+    Scenario::parse(GRAPE_CHAIN)
+        .unwrap()
+        .with_rules(|rules| rules.combo_bonuses.clear())
+        .expect(Expectation::Score(50))
+        .run();
+}
+
+#[test]
+fn test_chain_bonus_follows_game_rules() {
+    // Without chain bonuses the same merges score their base points only
+    Scenario::parse(GRAPE_CHAIN)
+        .unwrap()
+        .with_rules(|rules| {
+            rules.combo_bonuses.clear();
+            rules.chain_bonuses.clear();
+        })
+        .expect(Expectation::Score(40))
+        .run();
+}
+
+// ---------------------------------------------------------------------------
+// Game over
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_dropped_fruit_passing_the_line_is_not_game_over() {
+    run("
+        drop persimmon at x=0
+        step 4s
+        expect 1 persimmon
+        expect state playing
+    ");
+}
+
+#[test]
+fn test_fruit_resting_above_line_ends_game() {
+    Scenario::new()
+        .with_physics(|physics| physics.boundary_line_y = -350.0)
+        .place(FruitType::Watermelon, 0.0, -270.0)
+        .step_secs(5.0)
+        .expect(Expectation::State(AppState::GameOver))
+        .run();
+}
+
+#[test]
+fn test_same_seed_gives_same_game() {
+    let script = "drop dekopon at x=-150; step 2s; drop dekopon at x=150; step 2s";
+    let mut a = Scenario::parse(script).unwrap().with_seed(7).run();
+    let mut b = Scenario::parse(script).unwrap().with_seed(7).run();
+    assert_eq!(
+        a.app_mut().world().resource::<NextFruitType>().get(),
+        b.app_mut().world().resource::<NextFruitType>().get()
+    );
+    assert_eq!(a.count(FruitType::Dekopon), b.count(FruitType::Dekopon));
+}